@@ -0,0 +1,153 @@
+use cgmath::*;
+
+/// The kd-tree of the points for the nearest neighbor searches
+///
+/// The tree is balanced by splitting the points at the medians of the coordinates in turn, and
+/// the queries return the indices of the points in the original order.
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, kd_tree::KdTree};
+/// let points: Vec<Point2> = (0..10)
+///     .flat_map(|i| (0..10).map(move |j| Point2::new(i as f64, j as f64)))
+///     .collect();
+/// let tree = KdTree::new(points);
+/// assert_eq!(tree.nearest(Point2::new(3.2, 4.9)), Some(35));
+/// assert_eq!(tree.k_nearest(Point2::new(0.1, 0.2), 3), vec![0, 1, 10]);
+/// assert_eq!(tree.within_radius(Point2::new(5.0, 5.0), 1.0), vec![45, 54, 55, 56, 65]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KdTree<P> {
+    points: Vec<P>,
+    // the indices of the points, whose medians of the ranges are the nodes
+    indices: Vec<usize>,
+}
+
+impl<P> KdTree<P>
+where
+    P: Array<Element = f64> + MetricSpace<Metric = f64> + Copy,
+{
+    /// Builds the tree of `points`.
+    pub fn new(points: Vec<P>) -> Self {
+        let mut indices: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut indices, 0);
+        Self { points, indices }
+    }
+
+    /// Returns the points in the original order.
+    #[inline(always)]
+    pub fn points(&self) -> &[P] { &self.points }
+
+    /// Returns the number of the points.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.points.len() }
+
+    /// Returns `true` if the tree has no points.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.points.is_empty() }
+
+    /// Returns the index of the nearest point to `point`. Returns `None` if the tree is empty.
+    #[inline(always)]
+    pub fn nearest(&self, point: P) -> Option<usize> { self.k_nearest(point, 1).pop() }
+
+    /// Returns the indices of the `k` nearest points to `point`, in the ascending order of the
+    /// distances. All the indices are returned if the tree has at most `k` points.
+    pub fn k_nearest(&self, point: P, k: usize) -> Vec<usize> {
+        let mut nearest = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.search_nearest(point, k, &self.indices, 0, &mut nearest);
+        }
+        nearest.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Returns the indices of the points whose distances to `point` are at most `radius`, in the
+    /// ascending order of the indices.
+    pub fn within_radius(&self, point: P, radius: f64) -> Vec<usize> {
+        let mut result = Vec::new();
+        if radius >= 0.0 {
+            self.search_radius(point, radius * radius, &self.indices, 0, &mut result);
+        }
+        result.sort_unstable();
+        result
+    }
+
+    fn search_nearest(
+        &self,
+        point: P,
+        k: usize,
+        indices: &[usize],
+        depth: usize,
+        nearest: &mut Vec<(f64, usize)>,
+    ) {
+        if indices.is_empty() {
+            return;
+        }
+        let (axis, mid) = (depth % P::len(), indices.len() / 2);
+        let index = indices[mid];
+        let dist2 = self.points[index].distance2(point);
+        if nearest.len() < k || dist2 < nearest[nearest.len() - 1].0 {
+            let position = nearest.partition_point(|(d, _)| *d <= dist2);
+            nearest.insert(position, (dist2, index));
+            nearest.truncate(k);
+        }
+        let diff = point[axis] - self.points[index][axis];
+        let (near, far) = match diff < 0.0 {
+            true => (&indices[..mid], &indices[mid + 1..]),
+            false => (&indices[mid + 1..], &indices[..mid]),
+        };
+        self.search_nearest(point, k, near, depth + 1, nearest);
+        if nearest.len() < k || diff * diff < nearest[nearest.len() - 1].0 {
+            self.search_nearest(point, k, far, depth + 1, nearest);
+        }
+    }
+
+    fn search_radius(
+        &self,
+        point: P,
+        radius2: f64,
+        indices: &[usize],
+        depth: usize,
+        result: &mut Vec<usize>,
+    ) {
+        if indices.is_empty() {
+            return;
+        }
+        let (axis, mid) = (depth % P::len(), indices.len() / 2);
+        let index = indices[mid];
+        if self.points[index].distance2(point) <= radius2 {
+            result.push(index);
+        }
+        let diff = point[axis] - self.points[index][axis];
+        if diff <= 0.0 || diff * diff <= radius2 {
+            self.search_radius(point, radius2, &indices[..mid], depth + 1, result);
+        }
+        if diff >= 0.0 || diff * diff <= radius2 {
+            self.search_radius(point, radius2, &indices[mid + 1..], depth + 1, result);
+        }
+    }
+}
+
+impl<P> FromIterator<P> for KdTree<P>
+where
+    P: Array<Element = f64> + MetricSpace<Metric = f64> + Copy,
+{
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Sorts `indices` into the tree: the median of the coordinate of the axis of `depth` is in the
+/// middle, and the smaller and the larger ones are in the subtrees before and after it.
+fn build<P>(points: &[P], indices: &mut [usize], depth: usize)
+where
+    P: Array<Element = f64>,
+{
+    if indices.len() <= 1 {
+        return;
+    }
+    let (axis, mid) = (depth % P::len(), indices.len() / 2);
+    indices.select_nth_unstable_by(mid, |i, j| points[*i][axis].total_cmp(&points[*j][axis]));
+    let (left, right) = indices.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
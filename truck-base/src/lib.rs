@@ -25,5 +25,9 @@ pub mod entry_map;
 pub mod hash;
 /// ID structure with `Copy`, `Hash` and `Eq` using raw pointers
 pub mod id;
+/// kd-tree for the nearest neighbor searches of the points
+pub mod kd_tree;
+/// Robust geometric predicates by the adaptive precision arithmetic
+pub mod predicates;
 /// Setting Tolerance
 pub mod tolerance;
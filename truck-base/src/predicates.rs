@@ -0,0 +1,175 @@
+use crate::cgmath64::*;
+
+// The bounds of the relative errors of the floating-point evaluations, by J. R. Shewchuk,
+// "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT2D_ERRBOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ORIENT3D_ERRBOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+
+/// Returns the orientation of the triangle `a`, `b`, `c`.
+///
+/// The sign of the returned value is exact: positive if `a`, `b` and `c` are in the
+/// counterclockwise order, negative if clockwise, and zero if they are collinear. The absolute
+/// value approximates twice the area of the triangle. The determinant is evaluated by the
+/// floating-point arithmetic, and only if its error can flip the sign, it is re-evaluated by the
+/// exact arithmetic. The coordinates are assumed not to cause overflow or underflow.
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, predicates::orient2d};
+/// let a = Point2::new(0.5, 0.5);
+/// let b = Point2::new(12.0, 12.0);
+/// let c = Point2::new(24.0, 24.0);
+/// assert_eq!(orient2d(a, b, c), 0.0);
+///
+/// // the naive evaluation rounds the determinant off to zero.
+/// let a = Point2::new(0.5 + f64::EPSILON / 2.0, 0.5);
+/// let naive = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+/// assert_eq!(naive, 0.0);
+/// assert!(orient2d(a, b, c) < 0.0);
+/// ```
+pub fn orient2d(a: Point2, b: Point2, c: Point2) -> f64 {
+    let det_left = (b.x - a.x) * (c.y - a.y);
+    let det_right = (b.y - a.y) * (c.x - a.x);
+    let det = det_left - det_right;
+    let permanent = f64::abs(det_left) + f64::abs(det_right);
+    if f64::abs(det) >= ORIENT2D_ERRBOUND * permanent {
+        return det;
+    }
+    let u = [two_diff(b.x, a.x), two_diff(b.y, a.y)];
+    let v = [two_diff(c.x, a.x), two_diff(c.y, a.y)];
+    let det = expansion_diff(
+        &expansion_product(&u[0], &v[1]),
+        &expansion_product(&u[1], &v[0]),
+    );
+    estimate(&det)
+}
+
+/// Returns the orientation of the tetrahedron `a`, `b`, `c`, `d`.
+///
+/// The sign of the returned value is exact: positive if `d` is on the side of the normal
+/// `(b - a).cross(c - a)` of the plane through `a`, `b` and `c`, negative if on the opposite
+/// side, and zero if they are coplanar. The absolute value approximates six times the volume of
+/// the tetrahedron. As in [`orient2d`], the exact arithmetic is used only if the floating-point
+/// evaluation cannot determine the sign.
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, predicates::orient3d};
+/// let a = Point3::new(0.0, 0.0, 0.0);
+/// let b = Point3::new(1.0, 0.0, 0.0);
+/// let c = Point3::new(0.0, 1.0, 0.0);
+/// assert_eq!(orient3d(a, b, c, Point3::new(0.3, 0.3, 1.0)), 1.0);
+/// assert_eq!(orient3d(a, b, c, Point3::new(0.3, 0.3, -1.0)), -1.0);
+/// assert_eq!(orient3d(a, b, c, Point3::new(0.3, 0.3, 0.0)), 0.0);
+/// ```
+pub fn orient3d(a: Point3, b: Point3, c: Point3, d: Point3) -> f64 {
+    let (u, v, w) = (b - a, c - a, d - a);
+    let (vw_x0, vw_x1) = (v.y * w.z, v.z * w.y);
+    let (vw_y0, vw_y1) = (v.z * w.x, v.x * w.z);
+    let (vw_z0, vw_z1) = (v.x * w.y, v.y * w.x);
+    let det = u.x * (vw_x0 - vw_x1) + u.y * (vw_y0 - vw_y1) + u.z * (vw_z0 - vw_z1);
+    let permanent = f64::abs(u.x) * (f64::abs(vw_x0) + f64::abs(vw_x1))
+        + f64::abs(u.y) * (f64::abs(vw_y0) + f64::abs(vw_y1))
+        + f64::abs(u.z) * (f64::abs(vw_z0) + f64::abs(vw_z1));
+    if f64::abs(det) >= ORIENT3D_ERRBOUND * permanent {
+        return det;
+    }
+    let u = [two_diff(b.x, a.x), two_diff(b.y, a.y), two_diff(b.z, a.z)];
+    let v = [two_diff(c.x, a.x), two_diff(c.y, a.y), two_diff(c.z, a.z)];
+    let w = [two_diff(d.x, a.x), two_diff(d.y, a.y), two_diff(d.z, a.z)];
+    let minor = |i: usize, j: usize| {
+        expansion_diff(
+            &expansion_product(&v[i], &w[j]),
+            &expansion_product(&v[j], &w[i]),
+        )
+    };
+    let det = expansion_sum(
+        &expansion_sum(
+            &expansion_product(&u[0], &minor(1, 2)),
+            &expansion_product(&u[1], &minor(2, 0)),
+        ),
+        &expansion_product(&u[2], &minor(0, 1)),
+    );
+    estimate(&det)
+}
+
+// An expansion is a sum of nonoverlapping floating-point numbers, sorted in the increasing order
+// of the magnitudes and without zeros. Its sign is the sign of the last component.
+
+/// `a + b` exactly, by the larger and the smaller components.
+#[inline(always)]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// `a - b` exactly, as an expansion.
+fn two_diff(a: f64, b: f64) -> Vec<f64> {
+    let (x, y) = two_sum(a, -b);
+    [y, x].into_iter().filter(|x| *x != 0.0).collect()
+}
+
+/// `a * b` exactly, by the larger and the smaller components.
+#[inline(always)]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, f64::mul_add(a, b, -x))
+}
+
+/// the sum of the expansion `e` and the number `b`
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut res = Vec::with_capacity(e.len() + 1);
+    let q = e.iter().fold(b, |q, x| {
+        let (q, h) = two_sum(q, *x);
+        if h != 0.0 {
+            res.push(h);
+        }
+        q
+    });
+    if q != 0.0 {
+        res.push(q);
+    }
+    res
+}
+
+/// the sum of the expansions `e` and `f`
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |res, x| grow_expansion(&res, *x))
+}
+
+/// the difference of the expansions `e` and `f`
+fn expansion_diff(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |res, x| grow_expansion(&res, -x))
+}
+
+/// the product of the expansion `e` and the number `b`
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut res = Vec::with_capacity(2 * e.len());
+    let mut push = |x: f64| {
+        if x != 0.0 {
+            res.push(x)
+        }
+    };
+    let q = e.iter().fold(0.0, |q, x| {
+        let (product1, product0) = two_product(*x, b);
+        let (sum, h) = two_sum(q, product0);
+        push(h);
+        let (q, h) = two_sum(product1, sum);
+        push(h);
+        q
+    });
+    push(q);
+    res
+}
+
+/// the product of the expansions `e` and `f`
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(Vec::new(), |res, x| {
+        expansion_sum(&res, &scale_expansion(e, *x))
+    })
+}
+
+/// the approximation of the expansion with the exact sign, the most significant component
+#[inline(always)]
+fn estimate(e: &[f64]) -> f64 { e.last().copied().unwrap_or(0.0) }
@@ -0,0 +1,49 @@
+use truck_base::{cgmath64::*, hash::HashGen, kd_tree::KdTree};
+
+fn brute_force<P: MetricSpace<Metric = f64> + Copy>(points: &[P], point: P) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    let distance = |i: &usize| points[*i].distance2(point);
+    indices.sort_by(|i, j| distance(i).total_cmp(&distance(j)));
+    indices
+}
+
+#[test]
+fn random_points3() {
+    let points: Vec<Point3> = (0..500)
+        .map(|i| Point3::from(HashGen::hash3(i as f64)))
+        .collect();
+    let tree: KdTree<Point3> = points.iter().copied().collect();
+    assert_eq!(tree.len(), 500);
+    (0..50).for_each(|i| {
+        let [x, y, z] = HashGen::hash3(i as f64 + 0.5);
+        let point = Point3::new(2.0 * x - 0.5, 2.0 * y - 0.5, 2.0 * z - 0.5);
+        let sorted = brute_force(&points, point);
+        assert_eq!(tree.nearest(point), Some(sorted[0]));
+        assert_eq!(tree.k_nearest(point, 10), sorted[..10]);
+
+        let mut within: Vec<usize> = sorted
+            .into_iter()
+            .filter(|j| points[*j].distance(point) <= 0.2)
+            .collect();
+        within.sort();
+        assert_eq!(tree.within_radius(point, 0.2), within);
+    });
+}
+
+#[test]
+fn degenerate_points2() {
+    // the duplicated points on a line
+    let points: Vec<Point2> = (0..20).map(|i| Point2::new((i / 2) as f64, 0.0)).collect();
+    let tree = KdTree::new(points);
+    let mut nearest = tree.k_nearest(Point2::new(3.1, 1.0), 2);
+    nearest.sort();
+    assert_eq!(nearest, vec![6, 7]);
+    assert_eq!(tree.k_nearest(Point2::origin(), 30).len(), 20);
+    let within = tree.within_radius(Point2::new(4.5, 0.0), 0.5);
+    assert_eq!(within, vec![8, 9, 10, 11]);
+    assert!(tree.within_radius(Point2::new(4.5, 1.0), 0.5).is_empty());
+
+    let empty = KdTree::<Point2>::new(Vec::new());
+    assert!(empty.is_empty());
+    assert_eq!(empty.nearest(Point2::origin()), None);
+}
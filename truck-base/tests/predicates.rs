@@ -0,0 +1,61 @@
+use truck_base::{cgmath64::*, predicates::*};
+
+/// the exact sign of `orient2d`, by the integer arithmetic
+fn exact_orient2d_sign(a: Point2, b: Point2, c: Point2) -> f64 {
+    let int = |x: f64| (x * (1_u64 << 53) as f64) as i128;
+    let (ax, ay) = (int(a.x), int(a.y));
+    let (bx, by) = (int(b.x), int(b.y));
+    let (cx, cy) = (int(c.x), int(c.y));
+    ((bx - ax) * (cy - ay) - (by - ay) * (cx - ax)).signum() as f64
+}
+
+#[inline(always)]
+fn sign(x: f64) -> f64 {
+    match x == 0.0 {
+        true => 0.0,
+        false => x.signum(),
+    }
+}
+
+#[test]
+fn nearly_collinear_points() {
+    let (b, c) = (Point2::new(12.0, 12.0), Point2::new(24.0, 24.0));
+    let ulp = f64::EPSILON / 2.0;
+    let mut naive_failures = 0;
+    for i in 0..64 {
+        for j in 0..64 {
+            let a = Point2::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+            let exact = exact_orient2d_sign(a, b, c);
+            assert_eq!(sign(orient2d(a, b, c)), exact, "{a:?}");
+            assert_eq!(sign(orient2d(b, c, a)), exact, "{a:?}");
+            assert_eq!(sign(orient2d(b, a, c)), -exact, "{a:?}");
+
+            let naive = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+            if sign(naive) != exact {
+                naive_failures += 1;
+            }
+
+            // lifted to the plane z = 0
+            let lift = |p: Point2| Point3::new(p.x, p.y, 0.0);
+            let top = Point3::new(0.0, 0.0, 1.0);
+            let (a, b, c) = (lift(a), lift(b), lift(c));
+            assert_eq!(sign(orient3d(a, b, c, top)), exact, "{a:?}");
+            assert_eq!(sign(orient3d(top, a, b, c)), -exact, "{a:?}");
+        }
+    }
+    // the filter is necessary in this configuration.
+    assert!(naive_failures > 0);
+}
+
+#[test]
+fn coplanar_points() {
+    let a = Point3::new(0.1, 0.2, 0.3);
+    let b = Point3::new(1.5, -0.25, 0.75);
+    let c = Point3::new(-0.5, 2.0, 1.25);
+    assert_eq!(orient3d(a, b, c, a), 0.0);
+    assert_eq!(orient3d(a, b, c, b), 0.0);
+    assert_eq!(orient3d(a, b, a, c), 0.0);
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(sign(orient3d(a, b, c, p)), -sign(orient3d(b, a, c, p)));
+    assert_eq!(sign(orient3d(a, b, c, p)), sign(orient3d(b, c, a, p)));
+}
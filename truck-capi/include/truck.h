@@ -0,0 +1,194 @@
+#ifndef TRUCK_CAPI_H
+#define TRUCK_CAPI_H
+
+/* Regenerate by `cbindgen --config cbindgen.toml --output include/truck.h` in truck-capi. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The type of the shape
+typedef enum TruckShapeType {
+  // vertex
+  TRUCK_SHAPE_TYPE_VERTEX = 0,
+  // edge
+  TRUCK_SHAPE_TYPE_EDGE = 1,
+  // wire
+  TRUCK_SHAPE_TYPE_WIRE = 2,
+  // face
+  TRUCK_SHAPE_TYPE_FACE = 3,
+  // shell
+  TRUCK_SHAPE_TYPE_SHELL = 4,
+  // solid
+  TRUCK_SHAPE_TYPE_SOLID = 5,
+} TruckShapeType;
+
+// The status returned by the fallible functions
+typedef enum TruckStatus {
+  // succeeded
+  TRUCK_STATUS_OK = 0,
+  // a required pointer is null
+  TRUCK_STATUS_NULL_POINTER = 1,
+  // the arguments are invalid, e.g. a degenerate geometry or an unexpected type of shape
+  TRUCK_STATUS_INVALID_ARGUMENT = 2,
+  // the operation failed on valid arguments, e.g. a boolean operation did not converge
+  TRUCK_STATUS_OPERATION_FAILED = 3,
+  // failed to write the file
+  TRUCK_STATUS_IO_ERROR = 4,
+} TruckStatus;
+
+// The opaque handle of a polygon mesh
+typedef struct TruckMesh TruckMesh;
+
+// The opaque handle of a vertex, an edge, a wire, a face, a shell or a solid
+typedef struct TruckShape TruckShape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a planar face bounded by the array of `len` wires.
+TruckStatus truck_attach_plane(const TruckShape *const *wires, size_t len, TruckShape **out);
+
+// Creates the intersection of `solid0` and `solid1` with the tolerance `tol`.
+TruckStatus truck_and(const TruckShape *solid0,
+                      const TruckShape *solid1,
+                      double tol,
+                      TruckShape **out);
+
+// Creates a circle arc from `vertex0` to `vertex1` via `transit`.
+TruckStatus truck_circle_arc(const TruckShape *vertex0,
+                             const TruckShape *vertex1,
+                             const double *transit,
+                             TruckShape **out);
+
+// Creates the cuboid whose diagonal corners are `corner0` and `corner1`.
+TruckStatus truck_cuboid(const double *corner0, const double *corner1, TruckShape **out);
+
+// Creates the cylinder standing on `bottom` along `axis` with `radius`.
+TruckStatus truck_cylinder(const double *bottom,
+                           const double *axis,
+                           double radius,
+                           TruckShape **out);
+
+// Returns the message of the last failure on the current thread.
+//
+// The string is owned by the library and valid until the next failure on the thread.
+const char *truck_last_error_message(void);
+
+// Creates a line from `vertex0` to `vertex1`.
+TruckStatus truck_line(const TruckShape *vertex0, const TruckShape *vertex1, TruckShape **out);
+
+// Copies the positions to `out`, the array of `3 * truck_mesh_position_count(mesh)` doubles.
+TruckStatus truck_mesh_copy_positions(const TruckMesh *mesh, double *out);
+
+// Copies the indices of the positions of the triangles to `out`, the array of
+// `3 * truck_mesh_triangle_count(mesh)` unsigned integers.
+TruckStatus truck_mesh_copy_triangles(const TruckMesh *mesh, uint32_t *out);
+
+// Releases the mesh. Does nothing if `mesh` is null.
+void truck_mesh_free(TruckMesh *mesh);
+
+// Returns the number of the positions of `mesh`. Returns zero if `mesh` is null.
+size_t truck_mesh_position_count(const TruckMesh *mesh);
+
+// Returns the number of the triangles of `mesh`. Returns zero if `mesh` is null.
+//
+// The polygons with more than three vertices are counted as the triangles of their fans.
+size_t truck_mesh_triangle_count(const TruckMesh *mesh);
+
+// Writes `mesh` to the OBJ file at `path`.
+TruckStatus truck_mesh_write_obj(const TruckMesh *mesh, const char *path);
+
+// Writes `mesh` to the STL file at `path`, in the binary format if `binary` is true or
+// in the ASCII format otherwise.
+TruckStatus truck_mesh_write_stl(const TruckMesh *mesh, const char *path, bool binary);
+
+// Creates the complement of `solid`.
+TruckStatus truck_not(const TruckShape *solid, TruckShape **out);
+
+// Creates the union of `solid0` and `solid1` with the tolerance `tol`.
+TruckStatus truck_or(const TruckShape *solid0,
+                     const TruckShape *solid1,
+                     double tol,
+                     TruckShape **out);
+
+// Creates the shape rotated around the axis through `origin` by `angle` radians.
+TruckStatus truck_rotated(const TruckShape *shape,
+                          const double *origin,
+                          const double *axis,
+                          double angle,
+                          TruckShape **out);
+
+// Sweeps a vertex, an edge, a wire, or a face around the axis through `origin` by `angle` radians.
+//
+// The closed shape is created if `angle` is no less than 2π.
+TruckStatus truck_rsweep(const TruckShape *shape,
+                         const double *origin,
+                         const double *axis,
+                         double angle,
+                         TruckShape **out);
+
+// Creates the shape scaled by `scalars` with the center `origin`.
+TruckStatus truck_scaled(const TruckShape *shape,
+                         const double *origin,
+                         const double *scalars,
+                         TruckShape **out);
+
+// Creates the handle of the same shape as `shape`.
+//
+// The topology is shared, the modification of the geometry in one handle affects the other.
+TruckStatus truck_shape_clone(const TruckShape *shape, TruckShape **out);
+
+// Writes the number of the faces of `shape` to `out`.
+//
+// `shape` must be a face, a shell or a solid.
+TruckStatus truck_shape_face_count(const TruckShape *shape, size_t *out);
+
+// Releases the shape. Does nothing if `shape` is null.
+void truck_shape_free(TruckShape *shape);
+
+// Creates the copy of `shape` with the new topology and the cloned geometries.
+TruckStatus truck_shape_topological_clone(const TruckShape *shape, TruckShape **out);
+
+// Writes the type of `shape` to `out`.
+TruckStatus truck_shape_type(const TruckShape *shape, TruckShapeType *out);
+
+// Creates the sphere with `center` and `radius`.
+TruckStatus truck_sphere(const double *center, double radius, TruckShape **out);
+
+// Tessellates the face, the shell or the solid with the tolerance `tol`.
+TruckStatus truck_tessellate(const TruckShape *shape, double tol, TruckMesh **out);
+
+// Creates the torus around `axis` through `center`.
+TruckStatus truck_torus(const double *center,
+                        const double *axis,
+                        double major_radius,
+                        double minor_radius,
+                        TruckShape **out);
+
+// Creates the translated shape.
+TruckStatus truck_translated(const TruckShape *shape, const double *vector, TruckShape **out);
+
+// Sweeps a vertex, an edge, a wire, or a face by `vector`.
+TruckStatus truck_tsweep(const TruckShape *shape, const double *vector, TruckShape **out);
+
+// Creates a vertex at `point`.
+TruckStatus truck_vertex(const double *point, TruckShape **out);
+
+// Writes the point of `vertex` to `out`, the array of three `double`s.
+TruckStatus truck_vertex_point(const TruckShape *vertex, double *out);
+
+// Creates a wire by the array of `len` edges.
+TruckStatus truck_wire(const TruckShape *const *edges, size_t len, TruckShape **out);
+
+// Writes the shell or the solid to the STEP file at `path`.
+TruckStatus truck_write_step(const TruckShape *shape, const char *path);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif // TRUCK_CAPI_H
@@ -0,0 +1,232 @@
+use crate::*;
+
+#[inline(always)]
+fn invalid(error: impl Display) -> Failure { failure(TruckStatus::InvalidArgument, error) }
+
+unsafe fn shapes<'a>(ptr: *const *const TruckShape, len: usize) -> Result<Vec<&'a TruckShape>> {
+    match ptr.is_null() && len > 0 {
+        true => Err(null_pointer()),
+        false => (0..len).map(|i| deref(*ptr.add(i))).collect(),
+    }
+}
+
+/// Creates a vertex at `point`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_vertex(point: *const f64, out: *mut *mut TruckShape) -> TruckStatus {
+    run(|| output(out, TruckShape::from(builder::vertex(point3(point)?))))
+}
+
+/// Creates a line from `vertex0` to `vertex1`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_line(
+    vertex0: *const TruckShape,
+    vertex1: *const TruckShape,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (v0, v1) = (deref(vertex0)?.as_vertex()?, deref(vertex1)?.as_vertex()?);
+        let edge = builder::try_line(v0, v1).map_err(invalid)?;
+        output(out, TruckShape::from(edge))
+    })
+}
+
+/// Creates a circle arc from `vertex0` to `vertex1` via `transit`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_circle_arc(
+    vertex0: *const TruckShape,
+    vertex1: *const TruckShape,
+    transit: *const f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (v0, v1) = (deref(vertex0)?.as_vertex()?, deref(vertex1)?.as_vertex()?);
+        let edge = builder::try_circle_arc(v0, v1, point3(transit)?).map_err(invalid)?;
+        output(out, TruckShape::from(edge))
+    })
+}
+
+/// Creates a wire by the array of `len` edges.
+#[no_mangle]
+pub unsafe extern "C" fn truck_wire(
+    edges: *const *const TruckShape,
+    len: usize,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let edges = shapes(edges, len)?.into_iter().map(TruckShape::as_edge);
+        let wire = edges.map(|edge| edge.cloned()).collect::<Result<Wire>>()?;
+        output(out, TruckShape::from(wire))
+    })
+}
+
+/// Creates a planar face bounded by the array of `len` wires.
+#[no_mangle]
+pub unsafe extern "C" fn truck_attach_plane(
+    wires: *const *const TruckShape,
+    len: usize,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let wires = shapes(wires, len)?.into_iter();
+        let wires = wires
+            .map(|wire| wire.as_wire().cloned())
+            .collect::<Result<Vec<_>>>()?;
+        let face = builder::try_attach_plane(&wires).map_err(invalid)?;
+        output(out, TruckShape::from(face))
+    })
+}
+
+/// Creates the cuboid whose diagonal corners are `corner0` and `corner1`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_cuboid(
+    corner0: *const f64,
+    corner1: *const f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let solid = builder::try_cuboid(point3(corner0)?, point3(corner1)?).map_err(invalid)?;
+        output(out, TruckShape::from(solid))
+    })
+}
+
+/// Creates the sphere with `center` and `radius`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_sphere(
+    center: *const f64,
+    radius: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let solid = builder::try_sphere(point3(center)?, radius).map_err(invalid)?;
+        output(out, TruckShape::from(solid))
+    })
+}
+
+/// Creates the cylinder standing on `bottom` along `axis` with `radius`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_cylinder(
+    bottom: *const f64,
+    axis: *const f64,
+    radius: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (bottom, axis) = (point3(bottom)?, vector3(axis)?);
+        let solid = builder::try_cylinder(bottom, axis, radius).map_err(invalid)?;
+        output(out, TruckShape::from(solid))
+    })
+}
+
+/// Creates the torus around `axis` through `center`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_torus(
+    center: *const f64,
+    axis: *const f64,
+    major_radius: f64,
+    minor_radius: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (center, axis) = (point3(center)?, vector3(axis)?);
+        let solid =
+            builder::try_torus(center, axis, major_radius, minor_radius).map_err(invalid)?;
+        output(out, TruckShape::from(solid))
+    })
+}
+
+/// Creates the translated shape.
+#[no_mangle]
+pub unsafe extern "C" fn truck_translated(
+    shape: *const TruckShape,
+    vector: *const f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let vector = vector3(vector)?;
+        let translated = derive_all_shape!(deref(shape)?, builder::translated, (vector));
+        output(out, translated)
+    })
+}
+
+/// Creates the shape rotated around the axis through `origin` by `angle` radians.
+#[no_mangle]
+pub unsafe extern "C" fn truck_rotated(
+    shape: *const TruckShape,
+    origin: *const f64,
+    axis: *const f64,
+    angle: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (origin, axis, angle) = (point3(origin)?, vector3(axis)?, Rad(angle));
+        if axis.so_small() {
+            return Err(invalid("the axis of rotation is the zero vector."));
+        }
+        let axis = axis.normalize();
+        let rotated = derive_all_shape!(deref(shape)?, builder::rotated, (origin, axis, angle));
+        output(out, rotated)
+    })
+}
+
+/// Creates the shape scaled by `scalars` with the center `origin`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_scaled(
+    shape: *const TruckShape,
+    origin: *const f64,
+    scalars: *const f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (origin, scalars) = (point3(origin)?, vector3(scalars)?);
+        let scaled = derive_all_shape!(deref(shape)?, builder::scaled, (origin, scalars));
+        output(out, scaled)
+    })
+}
+
+macro_rules! derive_all_sweepable {
+    ($shape: expr, $function: expr, ($($arg: expr),*)) => {
+        match &$shape.0 {
+            Shape::Vertex(x) => $function(x, $($arg),*).map(TruckShape::from),
+            Shape::Edge(x) => $function(x, $($arg),*).map(TruckShape::from),
+            Shape::Wire(x) => $function(x, $($arg),*).map(TruckShape::from),
+            Shape::Face(x) => $function(x, $($arg),*).map(TruckShape::from),
+            _ => {
+                let message = "sweep is only implemented to Vertex, Edge, Wire and Face.";
+                return Err(invalid(message));
+            }
+        }
+    };
+}
+
+/// Sweeps a vertex, an edge, a wire, or a face by `vector`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_tsweep(
+    shape: *const TruckShape,
+    vector: *const f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let vector = vector3(vector)?;
+        let swept = derive_all_sweepable!(deref(shape)?, builder::try_tsweep, (vector));
+        output(out, swept.map_err(invalid)?)
+    })
+}
+
+/// Sweeps a vertex, an edge, a wire, or a face around the axis through `origin` by `angle` radians.
+///
+/// The closed shape is created if `angle` is no less than 2π.
+#[no_mangle]
+pub unsafe extern "C" fn truck_rsweep(
+    shape: *const TruckShape,
+    origin: *const f64,
+    axis: *const f64,
+    angle: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (origin, axis, angle) = (point3(origin)?, vector3(axis)?, Rad(angle));
+        let shape = deref(shape)?;
+        let swept = derive_all_sweepable!(shape, builder::try_rsweep, (origin, axis, angle));
+        output(out, swept.map_err(invalid)?)
+    })
+}
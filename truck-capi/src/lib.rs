@@ -0,0 +1,169 @@
+//! C API of truck
+//!
+//! The shapes and the meshes are passed to C as the opaque handles [`TruckShape`] and
+//! [`TruckMesh`]. They are created by the functions of this crate and released by
+//! [`truck_shape_free`] and [`truck_mesh_free`]. Points and vectors are arrays of three `double`s.
+//!
+//! The fallible functions return [`TruckStatus`] and write the result to the output pointer.
+//! The message of the last failure on the current thread is returned by
+//! [`truck_last_error_message`]. Panics are caught and never unwind into the host application.
+//!
+//! The header `include/truck.h` is generated by [cbindgen](https://github.com/mozilla/cbindgen)
+//! with `cbindgen.toml`.
+//! # Safety
+//! All pointer arguments must be null or valid: the handles must be created by this library and
+//! not yet released, the arrays must have the documented lengths, and the strings must be
+//! null-terminated. Null pointers are reported by `TRUCK_STATUS_NULL_POINTER`.
+
+#![cfg_attr(not(debug_assertions), deny(warnings))]
+#![deny(clippy::all, rust_2018_idioms)]
+#![allow(clippy::missing_safety_doc)]
+#![warn(
+    missing_docs,
+    missing_debug_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unstable_features,
+    unused_import_braces,
+    unused_qualifications
+)]
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::fmt::Display;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use truck_modeling::*;
+
+/// The status returned by the fallible functions
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruckStatus {
+    /// succeeded
+    Ok = 0,
+    /// a required pointer is null
+    NullPointer = 1,
+    /// the arguments are invalid, e.g. a degenerate geometry or an unexpected type of shape
+    InvalidArgument = 2,
+    /// the operation failed on valid arguments, e.g. a boolean operation did not converge
+    OperationFailed = 3,
+    /// failed to write the file
+    IoError = 4,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// Returns the message of the last failure on the current thread.
+///
+/// The string is owned by the library and valid until the next failure on the thread.
+#[no_mangle]
+pub extern "C" fn truck_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|error| error.borrow().as_ptr())
+}
+
+/// the status and the message of a failure
+type Failure = (TruckStatus, String);
+type Result<T> = std::result::Result<T, Failure>;
+
+#[inline(always)]
+fn failure(status: TruckStatus, message: impl Display) -> Failure { (status, message.to_string()) }
+
+#[inline(always)]
+fn null_pointer() -> Failure { failure(TruckStatus::NullPointer, "a null pointer is passed.") }
+
+#[inline(always)]
+fn io_failure(error: impl Display) -> Failure { failure(TruckStatus::IoError, error) }
+
+/// Runs `f`, records its failure, and catches the panic so that it does not unwind into C.
+fn run(f: impl FnOnce() -> Result<()>) -> TruckStatus {
+    let (status, message) = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return TruckStatus::Ok,
+        Ok(Err(fail)) => fail,
+        Err(payload) => {
+            let message = match payload.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => match payload.downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => "the operation panicked.".to_string(),
+                },
+            };
+            (TruckStatus::OperationFailed, message)
+        }
+    };
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|error| *error.borrow_mut() = message);
+    status
+}
+
+unsafe fn deref<'a, T>(ptr: *const T) -> Result<&'a T> { ptr.as_ref().ok_or_else(null_pointer) }
+
+unsafe fn point3(ptr: *const f64) -> Result<Point3> {
+    match ptr.is_null() {
+        true => Err(null_pointer()),
+        false => Ok(Point3::new(*ptr, *ptr.add(1), *ptr.add(2))),
+    }
+}
+
+unsafe fn vector3(ptr: *const f64) -> Result<Vector3> { point3(ptr).map(|p| p.to_vec()) }
+
+unsafe fn string<'a>(ptr: *const c_char) -> Result<&'a str> {
+    match ptr.is_null() {
+        true => Err(null_pointer()),
+        false => CStr::from_ptr(ptr)
+            .to_str()
+            .map_err(|e| failure(TruckStatus::InvalidArgument, e)),
+    }
+}
+
+unsafe fn create_file(path: *const c_char) -> Result<std::fs::File> {
+    std::fs::File::create(string(path)?).map_err(io_failure)
+}
+
+unsafe fn write<T>(out: *mut T, value: T) -> Result<()> {
+    match out.is_null() {
+        true => Err(null_pointer()),
+        false => {
+            *out = value;
+            Ok(())
+        }
+    }
+}
+
+unsafe fn write_slice<T: Copy>(out: *mut T, values: &[T]) -> Result<()> {
+    match out.is_null() {
+        true => Err(null_pointer()),
+        false => {
+            std::slice::from_raw_parts_mut(out, values.len()).copy_from_slice(values);
+            Ok(())
+        }
+    }
+}
+
+unsafe fn output<T>(out: *mut *mut T, value: T) -> Result<()> {
+    write(out, Box::into_raw(Box::new(value)))
+}
+
+macro_rules! derive_all_shape {
+    ($shape: expr, $function: expr, ($($arg: expr),*)) => {
+        match &$shape.0 {
+            Shape::Vertex(x) => TruckShape::from($function(x, $($arg),*)),
+            Shape::Edge(x) => TruckShape::from($function(x, $($arg),*)),
+            Shape::Wire(x) => TruckShape::from($function(x, $($arg),*)),
+            Shape::Face(x) => TruckShape::from($function(x, $($arg),*)),
+            Shape::Shell(x) => TruckShape::from($function(x, $($arg),*)),
+            Shape::Solid(x) => TruckShape::from($function(x, $($arg),*)),
+        }
+    };
+}
+
+mod shape;
+pub use shape::*;
+mod builder;
+pub use builder::*;
+mod shapeops;
+pub use shapeops::*;
+mod mesh;
+pub use mesh::*;
+mod step;
+pub use step::*;
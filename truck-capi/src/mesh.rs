@@ -0,0 +1,122 @@
+use crate::*;
+use std::io::BufWriter;
+use truck_meshalgo::prelude::*;
+
+/// The opaque handle of a polygon mesh
+#[derive(Clone, Debug)]
+pub struct TruckMesh(pub(crate) PolygonMesh);
+
+unsafe fn create(path: *const c_char) -> Result<BufWriter<std::fs::File>> {
+    Ok(BufWriter::new(create_file(path)?))
+}
+
+/// Tessellates the face, the shell or the solid with the tolerance `tol`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_tessellate(
+    shape: *const TruckShape,
+    tol: f64,
+    out: *mut *mut TruckMesh,
+) -> TruckStatus {
+    run(|| {
+        if tol <= 0.0 {
+            let message = format!("the tolerance must be positive, but {tol}.");
+            return Err(failure(TruckStatus::InvalidArgument, message));
+        }
+        let shape = deref(shape)?;
+        let mesh = match &shape.0 {
+            Shape::Face(face) => {
+                let shell: Shell = vec![face.clone()].into();
+                shell.triangulation(tol).to_polygon()
+            }
+            Shape::Shell(shell) => shell.triangulation(tol).to_polygon(),
+            _ => shape.as_solid()?.triangulation(tol).to_polygon(),
+        };
+        output(out, TruckMesh(mesh))
+    })
+}
+
+/// Releases the mesh. Does nothing if `mesh` is null.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_free(mesh: *mut TruckMesh) {
+    if !mesh.is_null() {
+        drop(Box::from_raw(mesh));
+    }
+}
+
+/// Returns the number of the positions of `mesh`. Returns zero if `mesh` is null.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_position_count(mesh: *const TruckMesh) -> usize {
+    mesh.as_ref().map_or(0, |mesh| mesh.0.positions().len())
+}
+
+/// Returns the number of the triangles of `mesh`. Returns zero if `mesh` is null.
+///
+/// The polygons with more than three vertices are counted as the triangles of their fans.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_triangle_count(mesh: *const TruckMesh) -> usize {
+    let count = |mesh: &TruckMesh| mesh.0.faces().triangle_iter().len();
+    mesh.as_ref().map_or(0, count)
+}
+
+/// Copies the positions to `out`, the array of `3 * truck_mesh_position_count(mesh)` doubles.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_copy_positions(
+    mesh: *const TruckMesh,
+    out: *mut f64,
+) -> TruckStatus {
+    run(|| {
+        let positions = deref(mesh)?.0.positions();
+        let positions = positions
+            .iter()
+            .flat_map(|p| [p.x, p.y, p.z])
+            .collect::<Vec<_>>();
+        write_slice(out, &positions)
+    })
+}
+
+/// Copies the indices of the positions of the triangles to `out`, the array of
+/// `3 * truck_mesh_triangle_count(mesh)` unsigned integers.
+///
+/// Returns `TruckStatus::OperationFailed` if an index does not fit in the unsigned integer.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_copy_triangles(
+    mesh: *const TruckMesh,
+    out: *mut u32,
+) -> TruckStatus {
+    run(|| {
+        let faces = deref(mesh)?.0.faces().triangle_iter();
+        let indices = faces
+            .flatten()
+            .map(|v| u32::try_from(v.pos))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| failure(TruckStatus::OperationFailed, e))?;
+        write_slice(out, &indices)
+    })
+}
+
+/// Writes `mesh` to the STL file at `path`, in the binary format if `binary` is true or
+/// in the ASCII format otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_write_stl(
+    mesh: *const TruckMesh,
+    path: *const c_char,
+    binary: bool,
+) -> TruckStatus {
+    run(|| {
+        let mesh = &deref(mesh)?.0;
+        let stl_type = match binary {
+            true => stl::StlType::Binary,
+            false => stl::StlType::Ascii,
+        };
+        stl::write(mesh, create(path)?, stl_type).map_err(io_failure)
+    })
+}
+
+/// Writes `mesh` to the OBJ file at `path`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_mesh_write_obj(
+    mesh: *const TruckMesh,
+    path: *const c_char,
+) -> TruckStatus {
+    run(|| obj::write(&deref(mesh)?.0, create(path)?).map_err(io_failure))
+}
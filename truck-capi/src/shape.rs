@@ -0,0 +1,151 @@
+use crate::*;
+
+/// The opaque handle of a vertex, an edge, a wire, a face, a shell or a solid
+#[derive(Clone, Debug)]
+pub struct TruckShape(pub(crate) Shape);
+
+#[derive(Clone, Debug)]
+pub(crate) enum Shape {
+    Vertex(Vertex),
+    Edge(Edge),
+    Wire(Wire),
+    Face(Face),
+    Shell(Shell),
+    Solid(Solid),
+}
+
+/// The type of the shape
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruckShapeType {
+    /// vertex
+    Vertex = 0,
+    /// edge
+    Edge = 1,
+    /// wire
+    Wire = 2,
+    /// face
+    Face = 3,
+    /// shell
+    Shell = 4,
+    /// solid
+    Solid = 5,
+}
+
+macro_rules! shape_conversions {
+    ($($type: ident, $as: ident),*) => {
+        $(
+        impl From<$type> for TruckShape {
+            #[inline(always)]
+            fn from(shape: $type) -> TruckShape { TruckShape(Shape::$type(shape)) }
+        }
+        )*
+
+        impl TruckShape {
+            /// Returns the type of the shape.
+            #[inline(always)]
+            pub fn shape_type(&self) -> TruckShapeType {
+                match &self.0 {
+                    $(Shape::$type(_) => TruckShapeType::$type,)*
+                }
+            }
+            $(
+            pub(crate) fn $as(&self) -> Result<&$type> {
+                match &self.0 {
+                    Shape::$type(shape) => Ok(shape),
+                    _ => Err(failure(
+                        TruckStatus::InvalidArgument,
+                        format!(
+                            "the shape is not {}, but {:?}.",
+                            stringify!($type),
+                            self.shape_type(),
+                        ),
+                    )),
+                }
+            }
+            )*
+        }
+    };
+}
+
+#[rustfmt::skip]
+shape_conversions!(
+    Vertex, as_vertex,
+    Edge, as_edge,
+    Wire, as_wire,
+    Face, as_face,
+    Shell, as_shell,
+    Solid, as_solid
+);
+
+/// Releases the shape. Does nothing if `shape` is null.
+#[no_mangle]
+pub unsafe extern "C" fn truck_shape_free(shape: *mut TruckShape) {
+    if !shape.is_null() {
+        drop(Box::from_raw(shape));
+    }
+}
+
+/// Writes the type of `shape` to `out`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_shape_type(
+    shape: *const TruckShape,
+    out: *mut TruckShapeType,
+) -> TruckStatus {
+    run(|| write(out, deref(shape)?.shape_type()))
+}
+
+/// Creates the handle of the same shape as `shape`.
+///
+/// The topology is shared, the modification of the geometry in one handle affects the other.
+#[no_mangle]
+pub unsafe extern "C" fn truck_shape_clone(
+    shape: *const TruckShape,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| output(out, deref(shape)?.clone()))
+}
+
+/// Creates the copy of `shape` with the new topology and the cloned geometries.
+#[no_mangle]
+pub unsafe extern "C" fn truck_shape_topological_clone(
+    shape: *const TruckShape,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| output(out, derive_all_shape!(deref(shape)?, builder::clone, ())))
+}
+
+/// Writes the point of `vertex` to `out`, the array of three `double`s.
+#[no_mangle]
+pub unsafe extern "C" fn truck_vertex_point(
+    vertex: *const TruckShape,
+    out: *mut f64,
+) -> TruckStatus {
+    run(|| {
+        let point: [f64; 3] = deref(vertex)?.as_vertex()?.point().into();
+        write_slice(out, &point)
+    })
+}
+
+/// Writes the number of the faces of `shape` to `out`.
+///
+/// `shape` must be a face, a shell or a solid.
+#[no_mangle]
+pub unsafe extern "C" fn truck_shape_face_count(
+    shape: *const TruckShape,
+    out: *mut usize,
+) -> TruckStatus {
+    run(|| {
+        let shape = deref(shape)?;
+        let count = match &shape.0 {
+            Shape::Face(_) => 1,
+            Shape::Shell(shell) => shell.len(),
+            Shape::Solid(solid) => solid.boundaries().iter().map(Shell::len).sum(),
+            _ => {
+                let message = format!("{:?} has no faces.", shape.shape_type());
+                return Err(failure(TruckStatus::InvalidArgument, message));
+            }
+        };
+        write(out, count)
+    })
+}
@@ -0,0 +1,50 @@
+use crate::*;
+
+#[inline(always)]
+fn boolean_failure() -> Failure {
+    let message = "the boolean operation failed. Try another tolerance.";
+    failure(TruckStatus::OperationFailed, message)
+}
+
+/// Creates the intersection of `solid0` and `solid1` with the tolerance `tol`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_and(
+    solid0: *const TruckShape,
+    solid1: *const TruckShape,
+    tol: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (solid0, solid1) = (deref(solid0)?.as_solid()?, deref(solid1)?.as_solid()?);
+        let solid = truck_shapeops::and(solid0, solid1, tol).ok_or_else(boolean_failure)?;
+        output(out, TruckShape::from(solid))
+    })
+}
+
+/// Creates the union of `solid0` and `solid1` with the tolerance `tol`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_or(
+    solid0: *const TruckShape,
+    solid1: *const TruckShape,
+    tol: f64,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let (solid0, solid1) = (deref(solid0)?.as_solid()?, deref(solid1)?.as_solid()?);
+        let solid = truck_shapeops::or(solid0, solid1, tol).ok_or_else(boolean_failure)?;
+        output(out, TruckShape::from(solid))
+    })
+}
+
+/// Creates the complement of `solid`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_not(
+    solid: *const TruckShape,
+    out: *mut *mut TruckShape,
+) -> TruckStatus {
+    run(|| {
+        let mut solid = deref(solid)?.as_solid()?.clone();
+        solid.not();
+        output(out, TruckShape::from(solid))
+    })
+}
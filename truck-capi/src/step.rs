@@ -0,0 +1,27 @@
+use crate::*;
+use std::io::Write;
+use truck_stepio::out;
+
+/// Writes the shell or the solid to the STEP file at `path`.
+#[no_mangle]
+pub unsafe extern "C" fn truck_write_step(
+    shape: *const TruckShape,
+    path: *const c_char,
+) -> TruckStatus {
+    run(|| {
+        let shape = deref(shape)?;
+        let header = out::StepHeaderDescriptor::default();
+        let step = match &shape.0 {
+            Shape::Shell(shell) => {
+                let compressed = shell.compress();
+                out::CompleteStepDisplay::new(out::StepModel::from(&compressed), header).to_string()
+            }
+            _ => {
+                let compressed = shape.as_solid()?.compress();
+                out::CompleteStepDisplay::new(out::StepModel::from(&compressed), header).to_string()
+            }
+        };
+        let mut file = create_file(path)?;
+        file.write_all(step.as_bytes()).map_err(io_failure)
+    })
+}
@@ -0,0 +1,107 @@
+use std::ffi::CStr;
+use std::ptr::null_mut;
+use truck_capi::*;
+
+unsafe fn last_error() -> String {
+    CStr::from_ptr(truck_last_error_message())
+        .to_string_lossy()
+        .into_owned()
+}
+
+unsafe fn check(status: TruckStatus) { assert_eq!(status, TruckStatus::Ok, "{}", last_error()) }
+
+#[test]
+fn sweep_cube() {
+    unsafe {
+        let (mut v, mut e, mut f, mut cube) = (null_mut(), null_mut(), null_mut(), null_mut());
+        check(truck_vertex([0.0, 0.0, 0.0].as_ptr(), &mut v));
+        check(truck_tsweep(v, [1.0, 0.0, 0.0].as_ptr(), &mut e));
+        check(truck_tsweep(e, [0.0, 1.0, 0.0].as_ptr(), &mut f));
+        check(truck_tsweep(f, [0.0, 0.0, 1.0].as_ptr(), &mut cube));
+
+        let mut shape_type = TruckShapeType::Vertex;
+        check(truck_shape_type(cube, &mut shape_type));
+        assert_eq!(shape_type, TruckShapeType::Solid);
+        let mut count = 0;
+        check(truck_shape_face_count(cube, &mut count));
+        assert_eq!(count, 6);
+
+        let mut mesh = null_mut();
+        check(truck_tessellate(cube, 0.01, &mut mesh));
+        let len = truck_mesh_position_count(mesh);
+        let mut positions = vec![0.0; 3 * len];
+        check(truck_mesh_copy_positions(mesh, positions.as_mut_ptr()));
+        assert!(positions.iter().all(|x| (0.0..=1.0).contains(x)));
+        let len = truck_mesh_triangle_count(mesh);
+        let mut indices = vec![0; 3 * len];
+        check(truck_mesh_copy_triangles(mesh, indices.as_mut_ptr()));
+        assert_eq!(len, 12);
+        let len = truck_mesh_position_count(mesh);
+        assert!(indices.iter().all(|&i| (i as usize) < len));
+
+        truck_mesh_free(mesh);
+        for shape in [v, e, f, cube] {
+            truck_shape_free(shape);
+        }
+    }
+}
+
+#[test]
+fn boolean_and_export() {
+    unsafe {
+        let (mut cube, mut cylinder, mut hole) = (null_mut(), null_mut(), null_mut());
+        let (corner0, corner1) = ([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        check(truck_cuboid(corner0.as_ptr(), corner1.as_ptr(), &mut cube));
+        let (bottom, axis) = ([0.5, 0.5, -0.5], [0.0, 0.0, 2.0]);
+        let status = truck_cylinder(bottom.as_ptr(), axis.as_ptr(), 0.25, &mut cylinder);
+        check(status);
+        check(truck_not(cylinder, &mut hole));
+        let mut punched = null_mut();
+        check(truck_and(cube, hole, 0.05, &mut punched));
+
+        let dir = std::env::temp_dir();
+        let path = dir.join("truck-capi-punched-cube.step");
+        let cpath = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        check(truck_write_step(punched, cpath.as_ptr()));
+        let step = std::fs::read_to_string(&path).unwrap();
+        assert!(step.starts_with("ISO-10303-21;"));
+
+        let mut mesh = null_mut();
+        check(truck_tessellate(punched, 0.01, &mut mesh));
+        let path = dir.join("truck-capi-punched-cube.stl");
+        let cpath = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        check(truck_mesh_write_stl(mesh, cpath.as_ptr(), true));
+        let triangles = truck_mesh_triangle_count(mesh);
+        assert_eq!(std::fs::read(&path).unwrap().len(), 84 + 50 * triangles);
+
+        truck_mesh_free(mesh);
+        for shape in [cube, cylinder, hole, punched] {
+            truck_shape_free(shape);
+        }
+    }
+}
+
+#[test]
+fn report_failures() {
+    unsafe {
+        let mut v = null_mut();
+        check(truck_vertex([0.0, 0.0, 0.0].as_ptr(), &mut v));
+        let mut edge = null_mut();
+        assert_eq!(truck_line(v, v, &mut edge), TruckStatus::InvalidArgument);
+        assert!(edge.is_null());
+        assert!(!last_error().is_empty());
+
+        let mut shape = null_mut();
+        let status = truck_sphere(std::ptr::null(), 1.0, &mut shape);
+        assert_eq!(status, TruckStatus::NullPointer);
+        let status = truck_sphere([0.0, 0.0, 0.0].as_ptr(), -1.0, &mut shape);
+        assert_eq!(status, TruckStatus::InvalidArgument);
+        let mut mesh = null_mut();
+        let status = truck_tessellate(v, 0.01, &mut mesh);
+        assert_eq!(status, TruckStatus::InvalidArgument);
+        assert_eq!(last_error(), "the shape is not Solid, but Vertex.");
+
+        truck_shape_free(v);
+        truck_shape_free(null_mut());
+    }
+}
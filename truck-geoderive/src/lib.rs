@@ -464,7 +464,9 @@ pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
                 fn subs(&self, t: f64) -> Self::Point,
                 fn der(&self, t: f64) -> Self::Vector,
                 fn der2(&self, t: f64) -> Self::Vector,
+                fn der_n(&self, n: usize, t: f64) -> Self::Vector,
                 fn period(&self,) -> Option<f64>,
+                fn try_range(&self,) -> Option<(f64, f64)>,
             );
             quote! {
                 #[automatically_derived]
@@ -493,7 +495,11 @@ pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
                     #[inline(always)]
                     fn der2(&self, t: f64) -> Self::Vector { self.0.der2(t) }
                     #[inline(always)]
+                    fn der_n(&self, n: usize, t: f64) -> Self::Vector { self.0.der_n(n, t) }
+                    #[inline(always)]
                     fn period(&self) -> Option<f64> { self.0.period() }
+                    #[inline(always)]
+                    fn try_range(&self) -> Option<(f64, f64)> { self.0.try_range() }
                 }
             }
         }
@@ -522,8 +528,10 @@ pub fn derive_parametric_surface(input: TokenStream) -> TokenStream {
                 fn uuder(&self, s: f64, t: f64) -> Self::Vector,
                 fn uvder(&self, s: f64, t: f64) -> Self::Vector,
                 fn vvder(&self, s: f64, t: f64) -> Self::Vector,
+                fn der_mn(&self, m: usize, n: usize, s: f64, t: f64) -> Self::Vector,
                 fn u_period(&self,) -> Option<f64>,
                 fn v_period(&self,) -> Option<f64>,
+                fn try_range(&self,) -> (Option<(f64, f64)>, Option<(f64, f64)>),
             );
             quote! {
                 #[automatically_derived]
@@ -558,9 +566,17 @@ pub fn derive_parametric_surface(input: TokenStream) -> TokenStream {
                     #[inline(always)]
                     fn vvder(&self, s: f64, t: f64) -> Self::Vector { self.0.vvder(s, t) }
                     #[inline(always)]
+                    fn der_mn(&self, m: usize, n: usize, s: f64, t: f64) -> Self::Vector {
+                        self.0.der_mn(m, n, s, t)
+                    }
+                    #[inline(always)]
                     fn u_period(&self) -> Option<f64> { self.0.u_period() }
                     #[inline(always)]
                     fn v_period(&self) -> Option<f64> { self.0.v_period() }
+                    #[inline(always)]
+                    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+                        self.0.try_range()
+                    }
                 }
             }
         }
@@ -589,11 +605,16 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                 fn uuder(&self, s: f64, t: f64) -> Self::Vector,
                 fn uvder(&self, s: f64, t: f64) -> Self::Vector,
                 fn vvder(&self, s: f64, t: f64) -> Self::Vector,
+                fn der_mn(&self, m: usize, n: usize, s: f64, t: f64) -> Self::Vector,
+                fn try_range(&self,) -> (Option<(f64, f64)>, Option<(f64, f64)>),
             );
             let methods1 = methods!(
                 variants,
                 trait_name1,
                 fn normal(&self, u: f64, v: f64) -> Vector3,
+                fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64),
+                fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64),
+                fn shape_operator(&self, u: f64, v: f64) -> Option<Matrix2>,
             );
             quote! {
                 #[automatically_derived]
@@ -631,11 +652,31 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                     fn uvder(&self, s: f64, t: f64) -> Self::Vector { self.0.uvder(s, t) }
                     #[inline(always)]
                     fn vvder(&self, s: f64, t: f64) -> Self::Vector { self.0.vvder(s, t) }
+                    #[inline(always)]
+                    fn der_mn(&self, m: usize, n: usize, s: f64, t: f64) -> Self::Vector {
+                        self.0.der_mn(m, n, s, t)
+                    }
+                    #[inline(always)]
+                    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+                        self.0.try_range()
+                    }
                 }
                 #[automatically_derived]
                 impl #gen truck_geotrait::#trait_name1 for #ty {
                     #[inline(always)]
                     fn normal(&self, u: f64, v: f64) -> Vector3 { self.0.normal(u, v) }
+                    #[inline(always)]
+                    fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+                        self.0.first_fundamental_form(u, v)
+                    }
+                    #[inline(always)]
+                    fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+                        self.0.second_fundamental_form(u, v)
+                    }
+                    #[inline(always)]
+                    fn shape_operator(&self, u: f64, v: f64) -> Option<Matrix2> {
+                        self.0.shape_operator(u, v)
+                    }
                 }
             }
         }
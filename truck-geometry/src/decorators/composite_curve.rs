@@ -0,0 +1,355 @@
+use super::*;
+use std::cmp::Ordering;
+
+impl<C: BoundedCurve> CompositeCurve<C> {
+    /// Creates the composite curve of `segments`.
+    /// # Panics
+    /// Panics if `segments` is empty or the segments are not connected.
+    #[inline(always)]
+    pub fn new(segments: Vec<C>) -> Self
+    where C::Point: Tolerance {
+        Self::try_new(segments).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Creates the composite curve of `segments`.
+    /// # Failures
+    /// - Returns [`Error::EmptySegments`] if `segments` is empty.
+    /// - Returns [`Error::DisconnectedSegments`] if the end point of a segment is not near the
+    /// start point of the next segment.
+    pub fn try_new(segments: Vec<C>) -> Result<Self>
+    where C::Point: Tolerance {
+        let front = match segments.first() {
+            Some(segment) => segment.parameter_range().0,
+            None => return Err(Error::EmptySegments),
+        };
+        let disconnected = segments
+            .windows(2)
+            .position(|a| !a[0].back().near(&a[1].front()));
+        match disconnected {
+            Some(i) => Err(Error::DisconnectedSegments(i)),
+            None => Ok(Self { segments, front }),
+        }
+    }
+
+    /// Returns the segments.
+    #[inline(always)]
+    pub fn segments(&self) -> &[C] { &self.segments }
+
+    /// Returns the vector of the segments.
+    #[inline(always)]
+    pub fn into_segments(self) -> Vec<C> { self.segments }
+
+    /// Returns the iterator on the parameter ranges of the segments in the composite curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line0 = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+    /// let line1 = Line(Point2::new(1.0, 0.0), Point2::new(1.0, 2.0));
+    /// let curve = CompositeCurve::new(vec![line0, line1]);
+    /// let ranges: Vec<_> = curve.segment_ranges().collect();
+    /// assert_eq!(ranges, vec![(0.0, 1.0), (1.0, 2.0)]);
+    /// ```
+    pub fn segment_ranges(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let mut front = self.front;
+        self.segments.iter().map(move |segment| {
+            let (t0, t1) = segment.parameter_range();
+            let range = (front, front + t1 - t0);
+            front = range.1;
+            range
+        })
+    }
+
+    /// Returns the index of the segment including `t` and the corresponding parameter of the
+    /// segment. The parameters out of the range are assigned to the first or last segment.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line0 = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+    /// let line1 = Line(Point2::new(1.0, 0.0), Point2::new(1.0, 2.0));
+    /// let curve = CompositeCurve::new(vec![line0, line1]);
+    /// assert_eq!(curve.locate(0.25), (0, 0.25));
+    /// assert_eq!(curve.locate(1.75), (1, 0.75));
+    /// assert_eq!(curve.locate(3.0), (1, 2.0));
+    /// ```
+    pub fn locate(&self, t: f64) -> (usize, f64) {
+        let last = self.segments.len() - 1;
+        let (i, (t0, _)) = self
+            .segment_ranges()
+            .enumerate()
+            .find(|(i, (_, t1))| t <= *t1 || *i == last)
+            .expect("the composite curve has no segments");
+        (i, t - t0 + self.segments[i].parameter_range().0)
+    }
+
+    /// Returns the difference between the parameters of the composite curve and those of each
+    /// segment.
+    #[inline(always)]
+    fn offsets(&self) -> impl Iterator<Item = (&C, (f64, f64), f64)> + '_ {
+        self.segments
+            .iter()
+            .zip(self.segment_ranges())
+            .map(|(segment, range)| (segment, range, range.0 - segment.parameter_range().0))
+    }
+}
+
+/// Translates the hint of the composite curve into that of the segment.
+fn segment_hint(hint: SPHint1D, (s0, s1): (f64, f64), offset: f64) -> SPHint1D {
+    match hint {
+        SPHint1D::Parameter(t) if s0 <= t && t <= s1 => SPHint1D::Parameter(t - offset),
+        SPHint1D::Range(t0, t1) if f64::max(t0, s0) < f64::min(t1, s1) => {
+            SPHint1D::Range(f64::max(t0, s0) - offset, f64::min(t1, s1) - offset)
+        }
+        _ => SPHint1D::Range(s0 - offset, s1 - offset),
+    }
+}
+
+impl<C: BoundedCurve> ParametricCurve for CompositeCurve<C> {
+    type Point = C::Point;
+    type Vector = C::Vector;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Self::Point {
+        let (i, t) = self.locate(t);
+        self.segments[i].subs(t)
+    }
+    #[inline(always)]
+    fn der(&self, t: f64) -> Self::Vector {
+        let (i, t) = self.locate(t);
+        self.segments[i].der(t)
+    }
+    #[inline(always)]
+    fn der2(&self, t: f64) -> Self::Vector {
+        let (i, t) = self.locate(t);
+        self.segments[i].der2(t)
+    }
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        let (i, t) = self.locate(t);
+        self.segments[i].der_n(n, t)
+    }
+    /// The range from the front of the first segment to the back of the last segment.
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> {
+        let t1 = self.segment_ranges().last().map(|(_, t1)| t1)?;
+        Some((self.front, t1))
+    }
+}
+
+impl<C: BoundedCurve> BoundedCurve for CompositeCurve<C> {
+    #[inline(always)]
+    fn parameter_range(&self) -> (f64, f64) {
+        let length = self.segments.iter().fold(0.0, |sum, segment| {
+            let (t0, t1) = segment.parameter_range();
+            sum + t1 - t0
+        });
+        (self.front, self.front + length)
+    }
+}
+
+impl<C: BoundedCurve + Invertible> Invertible for CompositeCurve<C> {
+    /// Reverses the order of the segments and inverts each segment. The parameter range is
+    /// preserved if the inversion of each segment preserves its parameter range.
+    #[inline(always)]
+    fn invert(&mut self) {
+        self.segments.reverse();
+        self.segments.iter_mut().for_each(Invertible::invert);
+    }
+}
+
+impl<C: Cut> Cut for CompositeCurve<C> {
+    /// Cuts the segment including `t`, or splits between the segments if `t` is at their joint.
+    /// The parameters of the latter curve start at `t`.
+    fn cut(&mut self, t: f64) -> Self {
+        let last = self.segments.len() - 1;
+        let joint = self.segment_ranges().take(last).position(|(_, t1)| t.near(&t1));
+        let segments = match joint {
+            Some(i) => self.segments.split_off(i + 1),
+            None => {
+                let (i, s) = self.locate(t);
+                let mut segments = self.segments.split_off(i + 1);
+                segments.insert(0, self.segments[i].cut(s));
+                segments
+            }
+        };
+        Self { segments, front: t }
+    }
+}
+
+impl<C> ParameterDivision1D for CompositeCurve<C>
+where C: BoundedCurve + ParameterDivision1D<Point = <C as ParametricCurve>::Point>
+{
+    type Point = <C as ParametricCurve>::Point;
+    fn parameter_division(&self, (t0, t1): (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
+        let (mut params, mut pts) = (Vec::new(), Vec::new());
+        self.offsets().for_each(|(segment, (s0, s1), offset)| {
+            let (a0, a1) = (f64::max(t0, s0), f64::min(t1, s1));
+            if a0 < a1 {
+                let range = (a0 - offset, a1 - offset);
+                let (seg_params, seg_pts) = segment.parameter_division(range, tol);
+                // the joint of the segments is shared
+                let skip = !params.is_empty() as usize;
+                params.extend(seg_params.into_iter().skip(skip).map(|s| s + offset));
+                pts.extend(seg_pts.into_iter().skip(skip));
+            }
+        });
+        (params, pts)
+    }
+}
+
+impl<C> SearchNearestParameter<D1> for CompositeCurve<C>
+where
+    C: BoundedCurve + SearchNearestParameter<D1, Point = <C as ParametricCurve>::Point>,
+    <C as ParametricCurve>::Point: MetricSpace<Metric = f64> + Copy,
+{
+    type Point = <C as ParametricCurve>::Point;
+    /// Searches the nearest parameter on each segment, and returns the nearest one.
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = hint.into();
+        self.offsets()
+            .filter_map(|(segment, range, offset)| {
+                let hint = segment_hint(hint, range, offset);
+                let t = segment.search_nearest_parameter(point, hint, trials)?;
+                let (r0, r1) = segment.parameter_range();
+                let t = f64::clamp(t, r0, r1);
+                Some((t + offset, segment.subs(t).distance(point)))
+            })
+            .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal))
+            .map(|(t, _)| t)
+    }
+}
+
+impl<C> SearchParameter<D1> for CompositeCurve<C>
+where
+    C: BoundedCurve + SearchParameter<D1, Point = <C as ParametricCurve>::Point>,
+    <C as ParametricCurve>::Point: Copy,
+{
+    type Point = <C as ParametricCurve>::Point;
+    fn search_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = hint.into();
+        self.offsets().find_map(|(segment, range, offset)| {
+            let hint = segment_hint(hint, range, offset);
+            let t = segment.search_parameter(point, hint, trials)?;
+            let (r0, r1) = segment.parameter_range();
+            match r0 - TOLERANCE <= t && t <= r1 + TOLERANCE {
+                true => Some(t + offset),
+                false => None,
+            }
+        })
+    }
+}
+
+impl<C: Transformed<T>, T: Copy> Transformed<T> for CompositeCurve<C> {
+    #[inline(always)]
+    fn transform_by(&mut self, trans: T) {
+        self.segments
+            .iter_mut()
+            .for_each(|segment| segment.transform_by(trans))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bspcurves() -> Vec<BSplineCurve<Point3>> {
+        let curve0 = BSplineCurve::new(
+            KnotVec::bezier_knot(2),
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(2.0, 0.0, 0.0),
+            ],
+        );
+        let mut curve1 = BSplineCurve::new(
+            KnotVec::uniform_knot(2, 2),
+            vec![
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(3.0, -1.0, 1.0),
+                Point3::new(4.0, 0.0, 1.0),
+                Point3::new(5.0, 1.0, 0.0),
+            ],
+        );
+        curve1.knot_translate(3.0);
+        vec![curve0, curve1]
+    }
+
+    #[test]
+    fn composite_curve_subs() {
+        let segments = bspcurves();
+        let curve = CompositeCurve::new(segments.clone());
+        assert_eq!(curve.parameter_range(), (0.0, 2.0));
+        const N: usize = 100;
+        for i in 0..=N {
+            let t = i as f64 / N as f64;
+            assert_near!(curve.subs(t), segments[0].subs(t));
+            assert_near!(curve.der(t), segments[0].der(t));
+            assert_near!(curve.subs(1.0 + t), segments[1].subs(3.0 + t));
+            assert_near!(curve.der2(1.0 + t), segments[1].der2(3.0 + t));
+            assert_near!(curve.der_n(2, t), segments[0].der_n(2, t));
+            assert_near!(curve.der_n(1, 1.0 + t), segments[1].der_n(1, 3.0 + t));
+        }
+        assert_eq!(curve.try_range(), Some((0.0, 2.0)));
+    }
+
+    #[test]
+    fn composite_curve_invert_and_cut() {
+        let curve = CompositeCurve::new(bspcurves());
+        let inverse = curve.inverse();
+        assert_eq!(inverse.parameter_range(), (0.0, 2.0));
+        let mut former = curve.clone();
+        let latter = former.cut(0.7);
+        assert_eq!(former.parameter_range(), (0.0, 0.7));
+        assert_eq!(latter.parameter_range(), (0.7, 2.0));
+        const N: usize = 100;
+        for i in 0..=N {
+            let t = 2.0 * i as f64 / N as f64;
+            assert_near!(inverse.subs(t), curve.subs(2.0 - t));
+            match t < 0.7 {
+                true => assert_near!(former.subs(t), curve.subs(t)),
+                false => assert_near!(latter.subs(t), curve.subs(t)),
+            }
+        }
+    }
+
+    #[test]
+    fn composite_curve_cut_at_joint() {
+        let segments = bspcurves();
+        let mut former = CompositeCurve::new(segments.clone());
+        let latter = former.cut(1.0);
+        assert_eq!(former.segments().len(), 1);
+        assert_eq!(latter.segments().len(), 1);
+        assert_eq!(former.parameter_range(), (0.0, 1.0));
+        assert_eq!(latter.parameter_range(), (1.0, 2.0));
+        assert_eq!(former.segments()[0], segments[0]);
+        assert_eq!(latter.segments()[0], segments[1]);
+    }
+
+    #[test]
+    fn composite_curve_division_and_search() {
+        let curve = CompositeCurve::new(bspcurves());
+        let (params, pts) = curve.parameter_division((0.5, 1.5), 0.01);
+        assert_eq!(params[0], 0.5);
+        assert_eq!(params[params.len() - 1], 1.5);
+        assert!(params.contains(&1.0));
+        assert!(params.windows(2).all(|a| a[0] < a[1]));
+        params
+            .iter()
+            .zip(&pts)
+            .for_each(|(t, pt)| assert_near!(curve.subs(*t), *pt));
+
+        let t = curve.search_parameter(curve.subs(1.3), None, 100).unwrap();
+        assert_near!(t, 1.3);
+        let pt = curve.subs(0.4) + Vector3::new(0.0, 0.0, 0.01);
+        let t = curve.search_nearest_parameter(pt, None, 100).unwrap();
+        assert_near!(curve.subs(t), curve.subs(0.4));
+    }
+}
@@ -38,7 +38,20 @@ where
     #[inline(always)]
     fn vvder(&self, _: f64, _: f64) -> C::Vector { C::Vector::zero() }
     #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> C::Vector {
+        match (m, n) {
+            (0, 0) => C::Vector::zero(),
+            (0, 1) => self.vector,
+            (_, 0) => self.curve.der_n(m, u),
+            _ => C::Vector::zero(),
+        }
+    }
+    #[inline(always)]
     fn u_period(&self) -> Option<f64> { self.curve.period() }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+        (self.curve.try_range(), Some((0.0, 1.0)))
+    }
 }
 
 impl<C: ParametricCurve3D> ParametricSurface3D for ExtrudedCurve<C, Vector3> {
@@ -185,3 +198,49 @@ fn extruded_curve_test() {
         }
     }
 }
+
+#[test]
+fn extruded_curve_der_mn_test() {
+    let cpts = vec![
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+        Vector4::new(0.0, 2.0, 0.0, 2.0),
+        Vector4::new(1.0, 0.0, 1.0, 0.5),
+        Vector4::new(2.0, 1.0, 0.0, 1.0),
+    ];
+    let curve = NurbsCurve::new(BSplineCurve::new(KnotVec::bezier_knot(3), cpts));
+    let surface = ExtrudedCurve::by_extrusion(curve, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(surface.try_range(), (Some((0.0, 1.0)), Some((0.0, 1.0))));
+    const EPS: f64 = 1.0e-5;
+    const N: usize = 10;
+    // the positions stand for the derivations of order zero in the central differences
+    let der_mn = |m: usize, n: usize, u: f64, v: f64| match m + n {
+        0 => surface.subs(u, v).to_vec(),
+        _ => surface.der_mn(m, n, u, v),
+    };
+    for i in 1..N {
+        for j in 0..=N {
+            let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+            assert_near!(surface.der_mn(0, 0, u, v), Vector3::zero());
+            for m in 0..=5 {
+                for n in 0..=2 {
+                    let der = surface.der_mn(m, n, u, v);
+                    let approx = match (m, n) {
+                        (0, 0) => continue,
+                        (_, 0) => {
+                            let der0 = der_mn(m - 1, n, u + EPS, v);
+                            let der1 = der_mn(m - 1, n, u - EPS, v);
+                            (der0 - der1) / (2.0 * EPS)
+                        }
+                        _ => {
+                            let der0 = der_mn(m, n - 1, u, v + EPS);
+                            let der1 = der_mn(m, n - 1, u, v - EPS);
+                            (der0 - der1) / (2.0 * EPS)
+                        }
+                    };
+                    let err = (der - approx).magnitude() / f64::max(1.0, der.magnitude());
+                    assert!(err < 1.0e-4, "order: ({m}, {n}), {der:?} != {approx:?}");
+                }
+            }
+        }
+    }
+}
@@ -203,8 +203,7 @@ where
     ) -> Option<f64> {
         let t = self
             .leader()
-            .search_nearest_parameter(point, hint, trials)
-            .unwrap();
+            .search_nearest_parameter(point, hint, trials)?;
         let pt = self.subs(t);
         match pt.near(&point) {
             true => Some(t),
@@ -134,10 +134,82 @@ pub struct TrimmedCurve<C> {
     range: (f64, f64),
 }
 
+/// curve concatenating the bounded curves as one curve
+///
+/// The `i`-th segment is assigned to the parameters following those of the `(i - 1)`-th
+/// segment, shifted but not scaled, so that the derivations of the segments are preserved.
+/// The parameter range starts at the front parameter of the first segment.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let line0 = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+/// let line1 = Line(Point2::new(1.0, 0.0), Point2::new(1.0, 2.0));
+/// let curve = CompositeCurve::new(vec![line0, line1]);
+/// assert_eq!(curve.parameter_range(), (0.0, 2.0));
+/// assert_near!(curve.subs(0.5), Point2::new(0.5, 0.0));
+/// assert_near!(curve.subs(1.5), Point2::new(1.0, 1.0));
+/// assert_near!(curve.der(1.5), Vector2::new(0.0, 2.0));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositeCurve<C> {
+    segments: Vec<C>,
+    front: f64,
+}
+
+/// curve reparameterized by a monotone increasing function
+///
+/// The function is given by the one-dimensional curve `f`, and the curve maps `t` to
+/// `curve(f(t))`. The image of `f` must be included in the parameter range of `curve`.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let line = Line(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0));
+/// // f(t) = t^2
+/// let function = BSplineCurve::new(
+///     KnotVec::bezier_knot(2),
+///     vec![Point1::new(0.0), Point1::new(0.0), Point1::new(1.0)],
+/// );
+/// let curve = ReparameterizedCurve::new(line, function);
+/// assert_eq!(curve.parameter_range(), (0.0, 1.0));
+/// assert_near!(curve.subs(0.5), line.subs(0.25));
+/// assert_near!(curve.der(0.5), line.der(0.25));
+/// assert_near!(curve.der2(0.5), Vector2::new(4.0, 4.0));
+/// ```
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ReparameterizedCurve<C, F> {
+    curve: C,
+    function: F,
+}
+
+/// surface offset from the base surface by the signed distance along its normal
+///
+/// The normal of the base surface is the normalized cross product of the derivations, so that
+/// the positive distance moves to the front side of the surface. The 2nd-order derivations of the
+/// normal require the 3rd-order derivations of the base surface, so the 2nd-order derivations of
+/// the offset surface are the central differences of the exact 1st-order derivations of the normal.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 2.0);
+/// let offset = OffsetSurface::new(sphere, 0.5);
+/// let larger = Sphere::new(Point3::new(1.0, 2.0, 3.0), 2.5);
+/// assert_near!(offset.subs(0.5, 1.0), larger.subs(0.5, 1.0));
+/// assert_near!(offset.uder(0.5, 1.0), larger.uder(0.5, 1.0));
+/// assert_near!(offset.normal(0.5, 1.0), sphere.normal(0.5, 1.0));
+/// ```
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct OffsetSurface<S> {
+    surface: S,
+    distance: f64,
+}
+
+mod composite_curve;
 mod curve_on_surface;
 mod extruded_curve;
 mod intersection_curve;
+mod offset_surface;
 mod processor;
+mod reparameterized_curve;
 mod revolved_curve;
 mod trimmied_curve;
 pub use intersection_curve::double_projection;
@@ -0,0 +1,215 @@
+use super::*;
+
+impl<S> OffsetSurface<S> {
+    /// Creates the surface offset from `surface` by `distance`.
+    #[inline(always)]
+    pub const fn new(surface: S, distance: f64) -> Self { Self { surface, distance } }
+    /// Returns the base surface.
+    #[inline(always)]
+    pub const fn surface(&self) -> &S { &self.surface }
+    /// Into the base surface.
+    #[inline(always)]
+    pub fn into_surface(self) -> S { self.surface }
+    /// Returns the signed distance from the base surface.
+    #[inline(always)]
+    pub const fn distance(&self) -> f64 { self.distance }
+}
+
+/// the step of the central differences for the 2nd-order derivations
+const DIFFERENCE_STEP: f64 = 1.0e-4;
+
+impl<S: ParametricSurface3D> OffsetSurface<S> {
+    #[inline(always)]
+    fn base_normal(&self, u: f64, v: f64) -> Vector3 {
+        let (uder, vder) = (self.surface.uder(u, v), self.surface.vder(u, v));
+        uder.cross(vder).normalize()
+    }
+
+    /// Returns the derivations of the unit normal of the base surface by `u` and `v`.
+    fn normal_ders(&self, u: f64, v: f64) -> (Vector3, Vector3) {
+        let (uder, vder) = (self.surface.uder(u, v), self.surface.vder(u, v));
+        let uuder = self.surface.uuder(u, v);
+        let uvder = self.surface.uvder(u, v);
+        let vvder = self.surface.vvder(u, v);
+        let cross = uder.cross(vder);
+        let length = cross.magnitude();
+        let normal = cross / length;
+        let cross_uder = uuder.cross(vder) + uder.cross(uvder);
+        let cross_vder = uvder.cross(vder) + uder.cross(vvder);
+        (
+            (cross_uder - normal * normal.dot(cross_uder)) / length,
+            (cross_vder - normal * normal.dot(cross_vder)) / length,
+        )
+    }
+}
+
+impl<S: ParametricSurface3D> ParametricSurface for OffsetSurface<S> {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        self.surface.subs(u, v) + self.distance * self.base_normal(u, v)
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        self.surface.uder(u, v) + self.distance * self.normal_ders(u, v).0
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        self.surface.vder(u, v) + self.distance * self.normal_ders(u, v).1
+    }
+    /// central difference of `uder` of the normal in `u`
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        let h = DIFFERENCE_STEP;
+        let diff = self.normal_ders(u + h, v).0 - self.normal_ders(u - h, v).0;
+        self.surface.uuder(u, v) + self.distance * diff / (2.0 * h)
+    }
+    /// central difference of `uder` of the normal in `v`
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        let h = DIFFERENCE_STEP;
+        let diff = self.normal_ders(u, v + h).0 - self.normal_ders(u, v - h).0;
+        self.surface.uvder(u, v) + self.distance * diff / (2.0 * h)
+    }
+    /// central difference of `vder` of the normal in `v`
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        let h = DIFFERENCE_STEP;
+        let diff = self.normal_ders(u, v + h).1 - self.normal_ders(u, v - h).1;
+        self.surface.vvder(u, v) + self.distance * diff / (2.0 * h)
+    }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> { self.surface.u_period() }
+    #[inline(always)]
+    fn v_period(&self) -> Option<f64> { self.surface.v_period() }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) { self.surface.try_range() }
+}
+
+impl<S: ParametricSurface3D> ParametricSurface3D for OffsetSurface<S> {}
+
+impl<S: BoundedSurface + ParametricSurface3D> BoundedSurface for OffsetSurface<S> {
+    #[inline(always)]
+    fn parameter_range(&self) -> ((f64, f64), (f64, f64)) { self.surface.parameter_range() }
+}
+
+impl<S: ParametricSurface3D> ParameterDivision2D for OffsetSurface<S> {
+    #[inline(always)]
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        algo::surface::parameter_division(self, range, tol)
+    }
+}
+
+impl<S: BoundedSurface + ParametricSurface3D> SearchParameter<D2> for OffsetSurface<S> {
+    type Point = Point3;
+    #[inline(always)]
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = match hint.into() {
+            SPHint2D::Parameter(x, y) => (x, y),
+            SPHint2D::Range(range0, range1) => {
+                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
+            }
+            SPHint2D::None => {
+                algo::surface::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::surface::search_parameter3d(self, point, hint, trials)
+    }
+}
+
+impl<S: BoundedSurface + ParametricSurface3D> SearchNearestParameter<D2> for OffsetSurface<S> {
+    type Point = Point3;
+    #[inline(always)]
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = match hint.into() {
+            SPHint2D::Parameter(x, y) => (x, y),
+            SPHint2D::Range(range0, range1) => {
+                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
+            }
+            SPHint2D::None => {
+                algo::surface::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::surface::search_nearest_parameter(self, point, hint, trials)
+    }
+}
+
+impl<S: Invertible> Invertible for OffsetSurface<S> {
+    /// Inverts the base surface and the sign of the distance, so that the offset surface is
+    /// preserved as the point set.
+    #[inline(always)]
+    fn invert(&mut self) {
+        self.surface.invert();
+        self.distance = -self.distance;
+    }
+}
+
+#[test]
+fn offset_surface_test() {
+    use std::f64::consts::PI;
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let offset = OffsetSurface::new(Sphere::new(center, 2.0), -0.5);
+    let sphere = Sphere::new(center, 1.5);
+    const N: usize = 20;
+    for i in 1..N {
+        for j in 0..=N {
+            let u = PI * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            assert_near!(offset.subs(u, v), sphere.subs(u, v));
+            assert_near!(offset.uder(u, v), sphere.uder(u, v));
+            assert_near!(offset.vder(u, v), sphere.vder(u, v));
+            assert_near!(offset.uuder(u, v), sphere.uuder(u, v));
+            assert_near!(offset.uvder(u, v), sphere.uvder(u, v));
+            assert_near!(offset.vvder(u, v), sphere.vvder(u, v));
+            assert_near!(offset.normal(u, v), sphere.normal(u, v));
+        }
+    }
+
+    let bspsurface = BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
+        vec![
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(0.0, 0.5, 0.5),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            vec![
+                Point3::new(0.5, 0.0, 0.5),
+                Point3::new(0.5, 0.5, 1.0),
+                Point3::new(0.5, 1.0, 0.5),
+            ],
+            vec![
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 0.5, 0.5),
+                Point3::new(1.0, 1.0, 0.0),
+            ],
+        ],
+    );
+    let offset = OffsetSurface::new(bspsurface.clone(), 0.1);
+    let inverse = offset.inverse();
+    for i in 0..=N {
+        for j in 0..=N {
+            let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+            let pt = offset.subs(u, v);
+            assert_near!(pt.distance(bspsurface.subs(u, v)), 0.1);
+            assert!(offset.uder(u, v).dot(bspsurface.normal(u, v)).so_small());
+            assert!(offset.vder(u, v).dot(bspsurface.normal(u, v)).so_small());
+            assert_near!(inverse.subs(v, u), pt);
+        }
+    }
+    let pt = offset.subs(0.3, 0.6);
+    let (u, v) = offset.search_parameter(pt, None, 100).unwrap();
+    assert_near!(Point2::new(u, v), Point2::new(0.3, 0.6));
+}
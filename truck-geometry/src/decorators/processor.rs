@@ -113,7 +113,18 @@ where
         self.transform.transform_vector(self.entity.der2(t))
     }
     #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        let t = self.get_curve_parameter(t);
+        let sign = match n % 2 {
+            0 => 1.0,
+            _ => self.sign(),
+        };
+        self.transform.transform_vector(self.entity.der_n(n, t)) * sign
+    }
+    #[inline(always)]
     fn period(&self) -> Option<f64> { self.entity.period() }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { Some(self.parameter_range()) }
 }
 
 impl<C, T> BoundedCurve for Processor<C, T>
@@ -178,6 +189,14 @@ where
         }
     }
     #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector {
+        let der = match self.orientation {
+            true => self.entity.der_mn(m, n, u, v),
+            false => self.entity.der_mn(n, m, v, u),
+        };
+        self.transform.transform_vector(der)
+    }
+    #[inline(always)]
     fn u_period(&self) -> Option<f64> {
         match self.orientation {
             true => self.entity.u_period(),
@@ -191,6 +210,14 @@ where
             false => self.entity.u_period(),
         }
     }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+        let (urange, vrange) = self.entity.try_range();
+        match self.orientation {
+            true => (urange, vrange),
+            false => (vrange, urange),
+        }
+    }
 }
 
 impl<S, T> ParametricSurface3D for Processor<S, T>
@@ -0,0 +1,254 @@
+use super::*;
+
+impl<C, F> ReparameterizedCurve<C, F> {
+    /// Creates the curve `curve` reparameterized by `function`.
+    /// # Remarks
+    /// `function` must be monotone increasing, and its image must be included in the parameter
+    /// range of `curve`. This constructor does not check them.
+    #[inline(always)]
+    pub const fn new(curve: C, function: F) -> Self { Self { curve, function } }
+
+    /// Returns the reference to the original curve
+    #[inline(always)]
+    pub const fn curve(&self) -> &C { &self.curve }
+
+    /// Returns the reference to the reparameterization function
+    #[inline(always)]
+    pub const fn function(&self) -> &F { &self.function }
+
+    /// Returns the original curve and the reparameterization function.
+    #[inline(always)]
+    pub fn into_parts(self) -> (C, F) { (self.curve, self.function) }
+}
+
+impl<C, F> ReparameterizedCurve<C, F>
+where F: BoundedCurve<Point = Point1, Vector = Vector1>
+{
+    /// Returns the parameter `s` of `self` such that the function maps `s` to `t`.
+    /// Returns `None` if `t` is not in the image of the function.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line = Line(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0));
+    /// let function = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point1::new(0.0), Point1::new(0.0), Point1::new(1.0)],
+    /// );
+    /// let curve = ReparameterizedCurve::new(line, function);
+    /// assert_near!(curve.inverse_parameter(0.25).unwrap(), 0.5);
+    /// assert!(curve.inverse_parameter(1.5).is_none());
+    /// ```
+    pub fn inverse_parameter(&self, t: f64) -> Option<f64> {
+        let (mut s0, mut s1) = self.function.parameter_range();
+        let (t0, t1) = (self.function.subs(s0).x, self.function.subs(s1).x);
+        if t < t0 - TOLERANCE || t1 + TOLERANCE < t {
+            return None;
+        }
+        // bisection keeps the solution in the range, Newton's method makes it converge fast.
+        let mut s = s0 + (s1 - s0) * (t - t0) * inv_or_zero(t1 - t0);
+        for _ in 0..INVERSE_TRIALS {
+            let err = self.function.subs(s).x - t;
+            if err.so_small() {
+                return Some(s);
+            }
+            match err < 0.0 {
+                true => s0 = s,
+                false => s1 = s,
+            }
+            let der = self.function.der(s).x;
+            let next = s - err * inv_or_zero(der);
+            s = match s0 < next && next < s1 {
+                true => next,
+                false => (s0 + s1) / 2.0,
+            };
+        }
+        None
+    }
+
+    #[inline(always)]
+    fn original_parameter(&self, s: f64) -> f64 { self.function.subs(s).x }
+}
+
+const INVERSE_TRIALS: usize = 100;
+
+impl<C, F> ParametricCurve for ReparameterizedCurve<C, F>
+where
+    C: ParametricCurve,
+    C::Vector: VectorSpace<Scalar = f64>,
+    F: ParametricCurve<Point = Point1, Vector = Vector1>,
+{
+    type Point = C::Point;
+    type Vector = C::Vector;
+    #[inline(always)]
+    fn subs(&self, s: f64) -> Self::Point { self.curve.subs(self.function.subs(s).x) }
+    #[inline(always)]
+    fn der(&self, s: f64) -> Self::Vector {
+        let t = self.function.subs(s).x;
+        self.curve.der(t) * self.function.der(s).x
+    }
+    #[inline(always)]
+    fn der2(&self, s: f64) -> Self::Vector {
+        let t = self.function.subs(s).x;
+        let (der, der2) = (self.function.der(s).x, self.function.der2(s).x);
+        self.curve.der2(t) * (der * der) + self.curve.der(t) * der2
+    }
+}
+
+impl<C, F> BoundedCurve for ReparameterizedCurve<C, F>
+where
+    F: BoundedCurve<Point = Point1, Vector = Vector1>,
+    Self: ParametricCurve,
+{
+    #[inline(always)]
+    fn parameter_range(&self) -> (f64, f64) { self.function.parameter_range() }
+}
+
+impl<C, F> ParameterTransform for ReparameterizedCurve<C, F>
+where
+    F: ParameterTransform<Point = Point1, Vector = Vector1>,
+    Self: ParametricCurve,
+{
+    #[inline(always)]
+    fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
+        self.function.parameter_transform(scalar, r#move);
+        self
+    }
+}
+
+impl<C, F> Cut for ReparameterizedCurve<C, F>
+where
+    C: Clone,
+    F: Cut<Point = Point1, Vector = Vector1>,
+    Self: ParametricCurve,
+{
+    #[inline(always)]
+    fn cut(&mut self, s: f64) -> Self {
+        Self {
+            curve: self.curve.clone(),
+            function: self.function.cut(s),
+        }
+    }
+}
+
+impl<C, F> SearchParameter<D1> for ReparameterizedCurve<C, F>
+where
+    C: SearchParameter<D1>,
+    F: BoundedCurve<Point = Point1, Vector = Vector1>,
+{
+    type Point = C::Point;
+    fn search_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = match hint.into() {
+            SPHint1D::Parameter(s) => SPHint1D::Parameter(self.original_parameter(s)),
+            SPHint1D::Range(s0, s1) => {
+                SPHint1D::Range(self.original_parameter(s0), self.original_parameter(s1))
+            }
+            SPHint1D::None => SPHint1D::None,
+        };
+        let t = self.curve.search_parameter(point, hint, trials)?;
+        self.inverse_parameter(t)
+    }
+}
+
+impl<C, F> SearchNearestParameter<D1> for ReparameterizedCurve<C, F>
+where
+    Self: BoundedCurve,
+    <Self as ParametricCurve>::Point: EuclideanSpace<Scalar = f64, Diff = <Self as ParametricCurve>::Vector>
+        + MetricSpace<Metric = f64>,
+    <Self as ParametricCurve>::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    type Point = <Self as ParametricCurve>::Point;
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = match hint.into() {
+            SPHint1D::Parameter(hint) => hint,
+            SPHint1D::Range(x, y) => {
+                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
+            }
+            SPHint1D::None => {
+                algo::curve::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::curve::search_nearest_parameter(self, point, hint, trials)
+    }
+}
+
+impl<C, F> ParameterDivision1D for ReparameterizedCurve<C, F>
+where
+    Self: ParametricCurve,
+    <Self as ParametricCurve>::Point:
+        EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    type Point = <Self as ParametricCurve>::Point;
+    fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
+        algo::curve::parameter_division(self, range, tol)
+    }
+}
+
+impl<C: Transformed<T>, F: Clone, T> Transformed<T> for ReparameterizedCurve<C, F> {
+    #[inline(always)]
+    fn transform_by(&mut self, trans: T) { self.curve.transform_by(trans) }
+}
+
+#[test]
+fn reparameterized_curve_test() {
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(2.0, 0.0),
+        ],
+    );
+    // f(s) = (s^3 + s) / 2
+    let mut function = BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Point1::new(0.0),
+            Point1::new(1.0 / 6.0),
+            Point1::new(1.0 / 3.0),
+            Point1::new(1.0),
+        ],
+    );
+    function.knot_translate(1.0);
+    let rcurve = ReparameterizedCurve::new(curve.clone(), function.clone());
+    assert_eq!(rcurve.parameter_range(), (1.0, 2.0));
+
+    const N: usize = 100;
+    for i in 0..=N {
+        let s = i as f64 / N as f64;
+        let t = (s * s * s + s) / 2.0;
+        assert_near!(function.subs(1.0 + s).x, t);
+        assert_near!(rcurve.subs(1.0 + s), curve.subs(t));
+        assert_near!(rcurve.der(1.0 + s), curve.der(t) * (1.5 * s * s + 0.5));
+        assert_near!(
+            rcurve.der2(1.0 + s),
+            curve.der2(t) * (1.5 * s * s + 0.5) * (1.5 * s * s + 0.5) + curve.der(t) * 3.0 * s,
+        );
+        assert_near!(rcurve.inverse_parameter(t).unwrap(), 1.0 + s);
+    }
+
+    let s = 1.675;
+    let pt = rcurve.subs(s);
+    assert_near!(rcurve.search_parameter(pt, None, 100).unwrap(), s);
+    let pt = pt + Vector2::new(0.01, 0.06);
+    assert!(rcurve.search_parameter(pt, None, 100).is_none());
+    let s = rcurve.search_nearest_parameter(pt, None, 100).unwrap();
+    assert!(rcurve.der(s).dot(rcurve.subs(s) - pt).so_small());
+
+    let mut former = rcurve.clone();
+    let latter = former.cut(1.4);
+    assert_eq!(former.parameter_range(), (1.0, 1.4));
+    assert_eq!(latter.parameter_range(), (1.4, 2.0));
+    assert_near!(latter.subs(1.7), rcurve.subs(1.7));
+
+    truck_geotrait::parameter_transform_random_test(&rcurve, 10);
+}
@@ -221,6 +221,10 @@ impl<C: ParametricCurve3D> ParametricSurface for RevolutedCurve<C> {
     fn u_period(&self) -> Option<f64> { self.curve.period() }
     #[inline(always)]
     fn v_period(&self) -> Option<f64> { Some(2.0 * PI) }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+        (self.curve.try_range(), Some((0.0, 2.0 * PI)))
+    }
 }
 
 impl<C: ParametricCurve3D + BoundedCurve> ParametricSurface3D for RevolutedCurve<C> {
@@ -21,6 +21,10 @@ impl<C: ParametricCurve> ParametricCurve for TrimmedCurve<C> {
     fn der(&self, t: f64) -> Self::Vector { self.curve.der(t) }
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { self.curve.der2(t) }
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector { self.curve.der_n(n, t) }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { Some(self.range) }
 }
 
 impl<C: ParametricCurve> BoundedCurve for TrimmedCurve<C> {
@@ -169,6 +169,73 @@ the number of control points: {1}"
     /// ```
     #[error("The vector of control points and the one of weights have different length.")]
     DifferentLength,
+    /// The curves and the points do not form a compatible curve network.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let curve = |pt0: Point2, pt1: Point2| BSplineCurve::new(KnotVec::bezier_knot(1), vec![pt0, pt1]);
+    /// let u_curves = vec![
+    ///     curve(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)),
+    ///     curve(Point2::new(0.0, 1.0), Point2::new(1.0, 1.0)),
+    /// ];
+    /// let v_curves = vec![
+    ///     curve(Point2::new(0.0, 0.0), Point2::new(0.0, 1.0)),
+    ///     curve(Point2::new(1.0, 0.0), Point2::new(1.0, 1.0)),
+    /// ];
+    /// // the intersection points are not on the curves
+    /// let points = vec![
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)],
+    ///     vec![Point2::new(0.0, 1.0), Point2::new(1.0, 2.0)],
+    /// ];
+    /// assert!(matches!(
+    ///     BSplineSurface::try_gordon(u_curves, v_curves, &points),
+    ///     Err(Error::IncompatibleCurveNetwork),
+    /// ));
+    /// ```
+    #[error("The curves and the points do not form a compatible curve network.")]
+    IncompatibleCurveNetwork,
+    /// Creating `CompositeCurve` is failed if there are no segments.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let segments: Vec<Line<Point2>> = Vec::new();
+    /// assert!(matches!(CompositeCurve::try_new(segments), Err(Error::EmptySegments)));
+    /// ```
+    #[error("The composite curve must have at least one segment.")]
+    EmptySegments,
+    /// Creating `CompositeCurve` is failed if the end point of the segment is not the start point
+    /// of the next segment.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let segments = vec![
+    ///     Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)),
+    ///     Line(Point2::new(1.0, 1.0), Point2::new(2.0, 1.0)),
+    /// ];
+    /// assert!(matches!(CompositeCurve::try_new(segments), Err(Error::DisconnectedSegments(0))));
+    /// ```
+    #[error("The end point of the {0}th segment is not the start point of the next segment.")]
+    DisconnectedSegments(usize),
+    /// Merging two B-spline surfaces is failed if the surfaces do not abut with C1 continuity.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let plane = |x: f64| {
+    ///     let ctrl_pts = vec![
+    ///         vec![Point2::new(x, 0.0), Point2::new(x, 1.0)],
+    ///         vec![Point2::new(x + 1.0, 0.0), Point2::new(x + 1.0, 1.0)],
+    ///     ];
+    ///     BSplineSurface::new((KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)), ctrl_pts)
+    /// };
+    /// // the surfaces are disjoint
+    /// assert!(matches!(plane(0.0).try_merge(&plane(2.0)), Err(Error::CannotMerge)));
+    /// ```
+    #[error("The surfaces do not abut with C1 continuity.")]
+    CannotMerge,
 }
 
 #[test]
@@ -186,5 +253,9 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::IncompatibleCurveNetwork).unwrap();
+    writeln!(stderr, "{}\n", Error::EmptySegments).unwrap();
+    writeln!(stderr, "{}\n", Error::DisconnectedSegments(3)).unwrap();
+    writeln!(stderr, "{}\n", Error::CannotMerge).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
@@ -18,7 +18,9 @@ use std::fmt::Debug;
 use truck_base::bounding_box::Bounded;
 
 const INCLUDE_CURVE_TRIALS: usize = 100;
+const RECOGNITION_SAMPLES: usize = 100;
 const PRESEARCH_DIVISION: usize = 50;
+const ISOLATION_DEPTH: usize = 4;
 
 /// re-export `truck_base`
 pub mod base {
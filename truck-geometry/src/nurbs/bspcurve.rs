@@ -373,6 +373,38 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
             * k as f64
             * (k - 1) as f64
     }
+    /// Substitutes to the `n`th-ord derived B-spline curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(0.0, 1.0),
+    ///     Vector2::new(1.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // bpscurve = (4t^3 - 6t^2 + 3t, -3t^2 + 3t), derived3 = (24, 0)
+    /// const N : usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let t = 1.0 / (N as f64) * (i as f64);
+    ///     assert_near2!(bspcurve.der_n(2, t), bspcurve.der2(t));
+    ///     assert_near2!(bspcurve.der_n(3, t), Vector2::new(24.0, 0.0));
+    ///     assert_near2!(bspcurve.der_n(4, t), Vector2::zero());
+    /// }
+    /// ```
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> P::Diff {
+        let basis = basis_derivatives(&self.knot_vec, self.degree(), n, t);
+        self.control_points
+            .iter()
+            .zip(basis)
+            .fold(P::Diff::zero(), |sum, (pt, b)| sum + pt.to_vec() * b)
+    }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { Some(self.parameter_range()) }
 }
 
 impl<P: ControlPoint<f64>> BoundedCurve for BSplineCurve<P> {
@@ -520,12 +552,25 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert_eq!(bspcurve.try_remove_knot(2), Err(Error::CannotRemoveKnot(2)));
     /// ```
     pub fn try_remove_knot(&mut self, idx: usize) -> Result<&mut BSplineCurve<P>> {
+        let new_points = self
+            .knot_removal_points(idx)
+            .ok_or(Error::CannotRemoveKnot(idx))?;
+        if !new_points.last().unwrap().near(self.control_point(idx)) {
+            return Err(Error::CannotRemoveKnot(idx));
+        }
+        self.apply_knot_removal(idx, new_points);
+        Ok(self)
+    }
+
+    /// Returns the new control points for removing the knot `idx` from the front side.
+    /// The last point is the one that should coincide with the control point `idx`.
+    fn knot_removal_points(&self, idx: usize) -> Option<Vec<P>> {
         let k = self.degree();
         let n = self.control_points.len();
         let knot_vec = &self.knot_vec;
 
         if idx < k + 1 || idx >= n {
-            return Err(Error::CannotRemoveKnot(idx));
+            return None;
         }
 
         let mut new_points = Vec::with_capacity(k + 1);
@@ -541,18 +586,25 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
                 new_points.push(p);
             }
         }
+        Some(new_points)
+    }
 
-        if !new_points.last().unwrap().near(self.control_point(idx)) {
-            return Err(Error::CannotRemoveKnot(idx));
-        }
-
+    fn apply_knot_removal(&mut self, idx: usize, new_points: Vec<P>) {
+        let k = self.degree();
         for (i, vec) in new_points.into_iter().skip(1).enumerate() {
             self.control_points[idx - k + i] = vec;
         }
-
         self.control_points.remove(idx);
         self.knot_vec.remove(idx);
-        Ok(self)
+    }
+
+    /// Returns the curve whose knot `idx` is removed regardless of the change of the shape.
+    /// Returns `None` if the index is out of the range of the removable knots.
+    pub(super) fn knot_removed(&self, idx: usize) -> Option<Self> {
+        let new_points = self.knot_removal_points(idx)?;
+        let mut curve = self.clone();
+        curve.apply_knot_removal(idx, new_points);
+        Some(curve)
     }
 
     /// elevate 1 degree for bezier curve.
@@ -621,6 +673,129 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Extends the curve to the parameter `t` by the polynomial of the end span, so that the
+    /// extended curve is as smooth at the original end as in the end span.
+    /// Does nothing if `t` is in the parameter range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.5, 1.0),
+    ///     Point2::new(1.5, 1.0),
+    ///     Point2::new(2.0, 0.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.extend(1.5).extend(-0.5);
+    /// assert_eq!(bspcurve.parameter_range(), (-0.5, 1.5));
+    /// // the inner knot is preserved
+    /// assert_eq!(bspcurve.knot_vec().len(), org_curve.knot_vec().len());
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     assert_near!(bspcurve.subs(t), org_curve.subs(t));
+    /// }
+    /// // the polynomial of the end span is extended.
+    /// let end_span = BSplineCurve::new(
+    ///     KnotVec::from(vec![0.5, 0.5, 0.5, 1.0, 1.0, 1.0]),
+    ///     vec![Point2::new(1.0, 1.0), Point2::new(1.5, 1.0), Point2::new(2.0, 0.0)],
+    /// );
+    /// let mut extended_span = end_span.clone();
+    /// extended_span.extend(1.5);
+    /// assert_near!(bspcurve.subs(1.25), extended_span.subs(1.25));
+    /// assert_near!(bspcurve.der(1.0), end_span.der(1.0));
+    /// assert_near!(bspcurve.der2(1.0), end_span.der2(1.0));
+    /// ```
+    pub fn extend(&mut self, t: f64) -> &mut Self {
+        if let Some((x, inserted)) = self.sub_extend(t) {
+            (0..inserted).for_each(|_| {
+                let idx = self.knot_vec.floor(x).unwrap();
+                self.remove_knot(idx);
+            });
+        }
+        self
+    }
+
+    /// Extends the curve without removing the knots inserted in order to extrapolate.
+    /// Returns the inserted knot and the number of insertions.
+    pub(super) fn sub_extend(&mut self, t: f64) -> Option<(f64, usize)> {
+        let (t0, t1) = self.parameter_range();
+        if t0 <= t && t <= t1 {
+            return None;
+        }
+        self.clamp();
+        let k = self.degree();
+        let back = t1 < t;
+        let (end, x) = match back {
+            true => (t1, *self.knot_vec.iter().rfind(|x| !x.near(&t1))?),
+            false => (t0, *self.knot_vec.iter().find(|x| !x.near(&t0))?),
+        };
+        // makes the end span the Bezier curve
+        let idx = self.knot_vec.floor(x)?;
+        let inserted = k.saturating_sub(self.knot_vec.multiplicity(idx));
+        (0..inserted).for_each(|_| {
+            self.add_knot(x);
+        });
+        // de Casteljau's algorithm with the parameter out of the span
+        let n = self.control_points.len();
+        match back {
+            true => {
+                let u = (t - x) / (end - x);
+                let points = &mut self.control_points[n - k - 1..];
+                (1..=k).for_each(|r| {
+                    (r..=k).rev().for_each(|i| {
+                        points[i] = points[i - 1] + (points[i] - points[i - 1]) * u;
+                    })
+                });
+            }
+            false => {
+                let u = (t - end) / (x - end);
+                let points = &mut self.control_points[..=k];
+                (1..=k).for_each(|r| {
+                    (0..=k - r).for_each(|i| {
+                        let delta = (points[i + 1] - points[i]) * u;
+                        points[i] += delta;
+                    })
+                });
+            }
+        }
+        (0..=k).for_each(|_| {
+            let idx = if back { self.knot_vec.len() - 1 } else { 0 };
+            self.knot_vec.remove(idx);
+        });
+        (0..=k).for_each(|_| {
+            self.knot_vec.add_knot(t);
+        });
+        Some((x, inserted))
+    }
+
+    /// Extends the curve from the both ends by the approximate lengths `front` and `back`.
+    /// cf. [`BSplineCurve::extend`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)],
+    /// );
+    /// let mut bspcurve = line.clone();
+    /// bspcurve.extend_by_length(0.5, 1.0);
+    /// assert_eq!(bspcurve.parameter_range(), (-0.25, 1.5));
+    /// assert_near!(bspcurve.front(), Point2::new(-0.5, 0.0));
+    /// assert_near!(bspcurve.back(), Point2::new(3.0, 0.0));
+    /// ```
+    pub fn extend_by_length(&mut self, front: f64, back: f64) -> &mut Self
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let (t0, t1) = self.parameter_range();
+        let front_speed = self.der(t0).magnitude();
+        let back_speed = self.der(t1).magnitude();
+        self.extend(t0 - front * inv_or_zero(front_speed));
+        self.extend(t1 + back * inv_or_zero(back_speed))
+    }
+
     /// Repeats `Self::try_remove_knot()` from the back knot in turn until the knot cannot be removed.
     /// # Examples
     /// ```
@@ -899,6 +1074,73 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     }
 }
 
+impl<P> BSplineCurve<P>
+where P: ControlPoint<f64> + Tolerance + MetricSpace<Metric = f64>
+{
+    /// Removes knots in order from the back while the distance from the original curve is
+    /// within `tolerance`. The parameter range of the curve is preserved.
+    ///
+    /// Unlike [`optimize`](Self::optimize), the shape may change, so that the redundant data
+    /// generated by approximations and concatenations can be reduced. The distance is bounded on
+    /// the whole range, not only at sample points: the difference of the curves is a B-spline on
+    /// the knot vector of the original curve, whose control points bound it by the convex hull
+    /// property.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let circle = UnitCircle::<Point2>::new();
+    /// let org_curve =
+    ///     BSplineCurve::cubic_approximation(&circle, (-PI, PI), 1.0e-4, 1.0e-4, 10).unwrap();
+    ///
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.approximate_self(1.0e-2);
+    /// assert!(bspcurve.control_points().len() < org_curve.control_points().len());
+    /// assert_eq!(bspcurve.parameter_range(), org_curve.parameter_range());
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = -PI + 2.0 * PI * i as f64 / N as f64;
+    ///     assert!(bspcurve.subs(t).distance(org_curve.subs(t)) < 1.0e-2);
+    /// }
+    /// ```
+    pub fn approximate_self(&mut self, tolerance: f64) -> &mut Self {
+        let original = self.clone();
+        loop {
+            let n = self.knot_vec.len();
+            let removed = (1..=n).fold(false, |flag, i| {
+                let curve = match self.knot_removed(n - i) {
+                    Some(curve) => curve,
+                    None => return flag,
+                };
+                match original.deviation_bound(&curve) <= tolerance {
+                    true => {
+                        *self = curve;
+                        true
+                    }
+                    false => flag,
+                }
+            });
+            if !removed {
+                break;
+            }
+        }
+        self
+    }
+
+    /// the maximum distance of the control points of `self` and `other` on the common knot
+    /// vector, which bounds the distance of the curves
+    fn deviation_bound(&self, other: &Self) -> f64 {
+        let (mut curve0, mut curve1) = (self.clone(), other.clone());
+        curve0.syncro_knots(&mut curve1);
+        curve0
+            .control_points
+            .iter()
+            .zip(&curve1.control_points)
+            .fold(0.0, |max, (p, q)| f64::max(max, p.distance(*q)))
+    }
+}
+
 impl<P: ControlPoint<f64>> ParameterTransform for BSplineCurve<P> {
     #[inline(always)]
     fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
@@ -924,6 +1166,14 @@ fn parameter_transform_random_test() {
     truck_geotrait::parameter_transform_random_test(&curve, 10);
 }
 
+#[test]
+#[should_panic]
+fn parameter_transform_to_decreasing_range() {
+    let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)];
+    let mut curve = BSplineCurve::new(KnotVec::bezier_knot(1), ctrl_pts);
+    curve.parameter_transform_to_range((1.0, 0.0));
+}
+
 impl<P: ControlPoint<f64> + Tolerance> Cut for BSplineCurve<P> {
     fn cut(&mut self, mut t: f64) -> BSplineCurve<P> {
         let degree = self.degree();
@@ -1216,7 +1466,10 @@ impl<P> SearchParameter<D1> for BSplineCurve<P>
 where
     P: ControlPoint<f64>
         + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
-        + MetricSpace<Metric = f64>,
+        + MetricSpace<Metric = f64>
+        + Index<usize, Output = f64>
+        + Bounded<f64>
+        + Tolerance,
     <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
 {
     type Point = P;
@@ -1227,14 +1480,38 @@ where
             SPHint1D::Range(x, y) => {
                 algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
             }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION)
-            }
+            SPHint1D::None => return self.search_parameter_with_isolation(point, trial),
         };
         algo::curve::search_parameter(self, point, hint, trial)
     }
 }
 
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>
+        + Index<usize, Output = f64>
+        + Bounded<f64>
+        + Tolerance,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    /// Searches the parameter from the presearch, and then from the isolated ranges if failed.
+    fn search_parameter_with_isolation(&self, point: P, trial: usize) -> Option<f64> {
+        if !near_bounding_box(&self.roughly_bounding_box(), point) {
+            return None;
+        }
+        let hint = algo::curve::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION);
+        algo::curve::search_parameter(self, point, hint, trial).or_else(|| {
+            self.isolate_parameters(point, ISOLATION_DEPTH)
+                .into_iter()
+                .find_map(|(t0, t1)| {
+                    algo::curve::search_parameter(self, point, (t0 + t1) / 2.0, trial)
+                })
+        })
+    }
+}
+
 impl<P> BSplineCurve<P>
 where P: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> + Copy
 {
@@ -1243,6 +1520,91 @@ where P: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> +
     pub fn roughly_bounding_box(&self) -> BoundingBox<P> { self.control_points.iter().collect() }
 }
 
+impl<P> BSplineCurve<P>
+where P: ControlPoint<f64>
+        + MetricSpace<Metric = f64>
+        + Index<usize, Output = f64>
+        + Bounded<f64>
+        + Tolerance
+{
+    /// Returns the bounding box enclosing the part of the curve in the parameter range.
+    ///
+    /// The knots `t0` and `t1` are inserted into the clone of the curve, and the box includes
+    /// the control points whose basis functions do not vanish in the range. By the convex hull
+    /// property, the part of the curve is enclosed in the box, and the box shrinks to the curve
+    /// as the range shrinks.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let bbx = bspcurve.interval_bounding_box((0.0, 0.25));
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let p = bspcurve.subs(0.25 * i as f64 / N as f64);
+    ///     assert!(bbx.min()[0] <= p[0] && p[0] <= bbx.max()[0]);
+    ///     assert!(bbx.min()[1] <= p[1] && p[1] <= bbx.max()[1]);
+    /// }
+    /// // tighter than the box of all control points
+    /// assert!(bbx.max()[1] < 1.0);
+    /// ```
+    pub fn interval_bounding_box(&self, (t0, t1): (f64, f64)) -> BoundingBox<P> {
+        let degree = self.degree();
+        let mut part = self.clone();
+        for t in [t0, t1] {
+            for _ in 0..lacking_multiplicity(&part.knot_vec, degree, t) {
+                part.add_knot(t);
+            }
+        }
+        let range = nonvanishing_bases(&part.knot_vec, degree, (t0, t1));
+        let mut bbx: BoundingBox<P> = part.control_points[range].iter().collect();
+        bbx.push(&self.subs(t0));
+        bbx.push(&self.subs(t1));
+        bbx
+    }
+
+    /// Returns the parameter ranges which may include the parameter of `point`.
+    ///
+    /// The parameter range is recursively bisected `depth` times, and the ranges are rejected if
+    /// `point` is not near the [`interval_bounding_box`](Self::interval_bounding_box). The
+    /// rejection is verified, i.e. if the curve passes `point`, the parameter is included in
+    /// one of the returned ranges.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // the curve passes (0.5, 0.75) at t = 0.25.
+    /// let ranges = bspcurve.isolate_parameters(Point2::new(0.5, 0.75), 4);
+    /// assert!(!ranges.is_empty());
+    /// assert!(ranges.iter().all(|(t0, t1)| t1 - t0 == 1.0 / 16.0));
+    /// assert!(ranges.iter().any(|(t0, t1)| *t0 <= 0.25 && 0.25 <= *t1));
+    ///
+    /// // the curve is far from (1.0, 3.0).
+    /// assert!(bspcurve.isolate_parameters(Point2::new(1.0, 3.0), 4).is_empty());
+    /// ```
+    pub fn isolate_parameters(&self, point: P, depth: usize) -> Vec<(f64, f64)> {
+        let near =
+            |range: &(f64, f64)| near_bounding_box(&self.interval_bounding_box(*range), point);
+        let range = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        let ranges = Some(range).into_iter().filter(near).collect::<Vec<_>>();
+        (0..depth).fold(ranges, |ranges, _| {
+            ranges
+                .into_iter()
+                .flat_map(|(t0, t1)| {
+                    let t = (t0 + t1) / 2.0;
+                    [(t0, t), (t, t1)]
+                })
+                .filter(near)
+                .collect()
+        })
+    }
+}
+
 impl<P: Clone> Invertible for BSplineCurve<P> {
     #[inline(always)]
     fn invert(&mut self) { self.invert(); }
@@ -1471,6 +1833,139 @@ where
             },
         )
     }
+
+    /// Returns the cubic approximation of `self` reparameterized with the constant speed, i.e.
+    /// the parameter is proportional to the arc length. The parameter range is preserved.
+    ///
+    /// The points and the derivatives relative to the speed are approximated within `tolerance`.
+    /// Returns `None` if the approximation fails or the speed of `self` vanishes at the ends.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 4.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// let uniform = bspcurve.quasi_uniform_speed(1.0e-4).unwrap();
+    /// assert_eq!(uniform.parameter_range(), (0.0, 1.0));
+    /// assert_near!(uniform.front(), bspcurve.front());
+    /// assert_near!(uniform.back(), bspcurve.back());
+    ///
+    /// // the length of the curve
+    /// const N: usize = 1000;
+    /// let length: f64 = (0..N)
+    ///     .map(|i| {
+    ///         let t0 = i as f64 / N as f64;
+    ///         let t1 = (i + 1) as f64 / N as f64;
+    ///         bspcurve.subs(t0).distance(bspcurve.subs(t1))
+    ///     })
+    ///     .sum();
+    ///
+    /// // the speed of the original curve is not uniform.
+    /// assert!(bspcurve.der(0.0).magnitude() > 1.5 * bspcurve.der(0.5).magnitude());
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert!(f64::abs(uniform.der(t).magnitude() - length) < 1.0e-2 * length);
+    /// }
+    /// ```
+    pub fn quasi_uniform_speed(&self, tolerance: f64) -> Option<Self> {
+        let curve = UniformSpeed::new(self)?;
+        let range = self.parameter_range();
+        let d_tol = tolerance * curve.speed;
+        Self::cubic_approximation(&curve, range, tolerance, d_tol, 20)
+    }
+}
+
+/// The curve reparameterized with the constant speed by the table of the arc lengths.
+struct UniformSpeed<'a, P> {
+    curve: &'a BSplineCurve<P>,
+    // the pairs of the original parameters and the arc lengths
+    table: Vec<(f64, f64)>,
+    front: f64,
+    speed: f64,
+}
+
+impl<'a, P> UniformSpeed<'a, P>
+where
+    P: ControlPoint<f64> + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    const DIVISION: usize = 8;
+    const NEWTON_TRIALS: usize = 4;
+
+    fn new(curve: &'a BSplineCurve<P>) -> Option<Self> {
+        let (t0, t1) = curve.parameter_range();
+        let knots = curve.knot_vec.iter().filter(|t| t0 < **t && **t < t1);
+        let mut knots: Vec<f64> = std::iter::once(t0).chain(knots.copied()).collect();
+        knots.push(t1);
+        knots.dedup();
+        let division = Self::DIVISION * (curve.degree() + 1);
+        let mut table = vec![(t0, 0.0)];
+        knots.windows(2).for_each(|a| {
+            (1..=division).for_each(|i| {
+                let (t, s) = *table.last().unwrap();
+                let next = a[0] + (a[1] - a[0]) * i as f64 / division as f64;
+                table.push((next, s + arc_length(curve, (t, next))));
+            })
+        });
+        let length = table.last().unwrap().1;
+        match length.so_small() || curve.der(t0).so_small() || curve.der(t1).so_small() {
+            true => None,
+            false => Some(Self {
+                curve,
+                table,
+                front: t0,
+                speed: length / (t1 - t0),
+            }),
+        }
+    }
+
+    /// Returns the original parameter whose arc length is the same as `u`.
+    fn original_parameter(&self, u: f64) -> f64 {
+        let s = (u - self.front) * self.speed;
+        let idx = self.table.partition_point(|(_, s0)| *s0 < s);
+        let idx = usize::clamp(idx, 1, self.table.len() - 1);
+        let ((t0, s0), (t1, s1)) = (self.table[idx - 1], self.table[idx]);
+        let mut t = t0 + (t1 - t0) * (s - s0) * inv_or_zero(s1 - s0);
+        for _ in 0..Self::NEWTON_TRIALS {
+            let speed = self.curve.der(t).magnitude();
+            if speed.so_small() {
+                break;
+            }
+            let err = s0 + arc_length(self.curve, (t0, t)) - s;
+            t -= err / speed;
+        }
+        t
+    }
+}
+
+/// Simpson's rule on the speed of `curve`.
+fn arc_length<P>(curve: &BSplineCurve<P>, (t0, t1): (f64, f64)) -> f64
+where
+    P: ControlPoint<f64> + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64>, {
+    let speed = |t: f64| curve.der(t).magnitude();
+    (t1 - t0) / 6.0 * (speed(t0) + 4.0 * speed((t0 + t1) / 2.0) + speed(t1))
+}
+
+impl<P> ParametricCurve for UniformSpeed<'_, P>
+where
+    P: ControlPoint<f64> + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    type Point = P;
+    type Vector = <P as ControlPoint<f64>>::Diff;
+    fn subs(&self, u: f64) -> P { self.curve.subs(self.original_parameter(u)) }
+    fn der(&self, u: f64) -> Self::Vector {
+        let der = self.curve.der(self.original_parameter(u));
+        der * (self.speed * inv_or_zero(der.magnitude()))
+    }
+    fn der2(&self, u: f64) -> Self::Vector {
+        let t = self.original_parameter(u);
+        let (der, der2) = (self.curve.der(t), self.curve.der2(t));
+        let inv = inv_or_zero(der.magnitude());
+        let dt = self.speed * inv;
+        let ddt = -dt * dt * der.dot(der2) * inv * inv;
+        der2 * (dt * dt) + der * ddt
+    }
 }
 
 #[test]
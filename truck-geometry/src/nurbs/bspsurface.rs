@@ -838,6 +838,51 @@ impl<P: ControlPoint<f64>> ParametricSurface for BSplineSurface<P> {
             * degree0 as f64
             * degree1 as f64
     }
+
+    /// Substitutes the derived B-spline surface `m` times by `u` and `n` times by `v`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(3), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0)],
+    ///     vec![Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(2.0, 0.0), Vector2::new(2.0, 1.0)],
+    ///     vec![Vector2::new(3.0, 0.0), Vector2::new(3.0, 2.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// // bspsurface: (3u, v(1 + u^3)), der_mn(3, 1): (0, 6)
+    /// const N: usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let u = (i as f64) / (N as f64);
+    ///     for j in 0..=N {
+    ///         let v = (j as f64) / (N as f64);
+    ///         assert_near2!(bspsurface.der_mn(2, 0, u, v), Vector2::new(0.0, 6.0 * u * v));
+    ///         assert_near2!(bspsurface.der_mn(3, 1, u, v), Vector2::new(0.0, 6.0));
+    ///         assert_near2!(bspsurface.der_mn(4, 0, u, v), Vector2::new(0.0, 0.0));
+    ///     }
+    /// }
+    /// ```
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> P::Diff {
+        let (degree0, degree1) = self.degrees();
+        let (uknot_vec, vknot_vec) = self.knot_vecs();
+        let basis0 = basis_derivatives(uknot_vec, degree0, m, u);
+        let basis1 = basis_derivatives(vknot_vec, degree1, n, v);
+        let closure = |sum: P::Diff, (vec, b0): (&Vec<P>, f64)| {
+            let closure = |sum: P::Diff, (pt, b1): (&P, &f64)| sum + pt.to_vec() * (b0 * b1);
+            vec.iter().zip(&basis1).fold(sum, closure)
+        };
+        self.control_points
+            .iter()
+            .zip(basis0)
+            .fold(P::Diff::zero(), closure)
+    }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+        let (urange, vrange) = self.parameter_range();
+        (Some(urange), Some(vrange))
+    }
 }
 
 impl<V: Tolerance> BSplineSurface<V> {
@@ -1194,6 +1239,64 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Extends the surface along the u-direction to the parameter `u`.
+    /// cf. [`BSplineCurve::extend`]
+    #[inline(always)]
+    pub fn extend_u(&mut self, u: f64) -> &mut Self {
+        self.swap_axes().extend_v(u).swap_axes()
+    }
+
+    /// Extends the surface along the v-direction to the parameter `v`.
+    /// cf. [`BSplineCurve::extend`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // S(u, v) = (u, 2v, 2v(1 - v))
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///         Point3::new(0.0, 1.0, 1.0),
+    ///         Point3::new(0.0, 2.0, 0.0),
+    ///     ],
+    ///     vec![
+    ///         Point3::new(1.0, 0.0, 0.0),
+    ///         Point3::new(1.0, 1.0, 1.0),
+    ///         Point3::new(1.0, 2.0, 0.0),
+    ///     ],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// bspsurface.extend_u(1.5).extend_v(-0.5);
+    /// assert_eq!(bspsurface.parameter_range(), ((0.0, 1.5), (-0.5, 1.0)));
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let u = 1.5 * i as f64 / N as f64;
+    ///         let v = -0.5 + 1.5 * j as f64 / N as f64;
+    ///         let pt = Point3::new(u, 2.0 * v, 2.0 * v * (1.0 - v));
+    ///         assert_near!(bspsurface.subs(u, v), pt);
+    ///     }
+    /// }
+    /// ```
+    pub fn extend_v(&mut self, v: f64) -> &mut Self {
+        let knot_vec = self.knot_vecs.1.clone();
+        let mut removal = None;
+        for points in &mut self.control_points {
+            let mut curve = BSplineCurve::new_unchecked(knot_vec.clone(), std::mem::take(points));
+            removal = curve.sub_extend(v);
+            self.knot_vecs.1 = curve.knot_vec;
+            *points = curve.control_points;
+        }
+        if let Some((x, inserted)) = removal {
+            (0..inserted).for_each(|_| {
+                let idx = self.knot_vecs.1.floor(x).unwrap();
+                self.remove_vknot(idx);
+            });
+        }
+        self
+    }
+
     /// Elevates the vdegree.
     /// # Examples
     /// ```
@@ -1342,6 +1445,101 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Makes two surfaces having the same udegrees and the same vdegrees.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs0 = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts0 = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let mut bspsurface0 = BSplineSurface::new(knot_vecs0, ctrl_pts0);
+    /// let knot_vecs1 = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts1 = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.5, 1.0), Vector2::new(1.5, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(1.0, 2.0)],
+    /// ];
+    /// let mut bspsurface1 = BSplineSurface::new(knot_vecs1, ctrl_pts1);
+    /// let org_surface0 = bspsurface0.clone();
+    /// let org_surface1 = bspsurface1.clone();
+    ///
+    /// bspsurface0.syncro_degrees(&mut bspsurface1);
+    /// assert_eq!(bspsurface0.degrees(), (2, 2));
+    /// assert_eq!(bspsurface1.degrees(), (2, 2));
+    /// assert!(bspsurface0.near2_as_surface(&org_surface0));
+    /// assert!(bspsurface1.near2_as_surface(&org_surface1));
+    /// ```
+    pub fn syncro_degrees(&mut self, other: &mut Self) {
+        let (udegree0, udegree1) = (self.udegree(), other.udegree());
+        let (vdegree0, vdegree1) = (self.vdegree(), other.vdegree());
+        for _ in udegree0..udegree1 {
+            self.elevate_udegree();
+        }
+        for _ in udegree1..udegree0 {
+            other.elevate_udegree();
+        }
+        for _ in vdegree0..vdegree1 {
+            self.elevate_vdegree();
+        }
+        for _ in vdegree1..vdegree0 {
+            other.elevate_vdegree();
+        }
+    }
+
+    /// Makes two surfaces having the same normalized uknot vectors and the same normalized
+    /// vknot vectors. The degrees are not changed, so the control points of two surfaces
+    /// correspond to each other one by one if the degrees are also synchronized by
+    /// [`BSplineSurface::syncro_degrees`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let uknot_vec0 = KnotVec::from(vec![0.0, 0.0, 0.5, 1.0, 1.0]);
+    /// let knot_vecs0 = (uknot_vec0, KnotVec::bezier_knot(1));
+    /// let ctrl_pts0 = vec![
+    ///     vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)],
+    ///     vec![Vector3::new(1.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 0.0)],
+    ///     vec![Vector3::new(2.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0)],
+    /// ];
+    /// let mut bspsurface0 = BSplineSurface::new(knot_vecs0, ctrl_pts0);
+    /// let vknot_vec1 = KnotVec::from(vec![0.0, 0.0, 0.0, 2.0, 4.0, 4.0, 4.0]);
+    /// let knot_vecs1 = (KnotVec::bezier_knot(1), vknot_vec1);
+    /// let ctrl_pts1 = vec![
+    ///     vec![
+    ///         Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 1.0),
+    ///         Vector3::new(0.0, 2.0, 1.0), Vector3::new(0.0, 3.0, 0.0),
+    ///     ],
+    ///     vec![
+    ///         Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, -1.0),
+    ///         Vector3::new(1.0, 2.0, -1.0), Vector3::new(1.0, 3.0, 0.0),
+    ///     ],
+    /// ];
+    /// let mut bspsurface1 = BSplineSurface::new(knot_vecs1, ctrl_pts1);
+    /// let mut org_surface0 = bspsurface0.clone();
+    /// let mut org_surface1 = bspsurface1.clone();
+    ///
+    /// bspsurface0.syncro_knots(&mut bspsurface1);
+    /// assert_eq!(bspsurface0.uknot_vec(), bspsurface1.uknot_vec());
+    /// assert_eq!(bspsurface0.vknot_vec(), bspsurface1.vknot_vec());
+    /// assert_eq!(
+    ///     bspsurface0.vknot_vec().as_slice(),
+    ///     &[0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0],
+    /// );
+    /// // The degrees are not changed.
+    /// assert_eq!(bspsurface0.degrees(), org_surface0.degrees());
+    /// assert_eq!(bspsurface1.degrees(), org_surface1.degrees());
+    /// // The knot vectors are normalized, however, the shapes of surfaces are not changed.
+    /// assert!(bspsurface0.near2_as_surface(org_surface0.knot_normalize()));
+    /// assert!(bspsurface1.near2_as_surface(org_surface1.knot_normalize()));
+    /// ```
+    pub fn syncro_knots(&mut self, other: &mut Self) {
+        self.knot_normalize();
+        other.knot_normalize();
+        self.add_lacking_uknots(other);
+        self.add_lacking_vknots(other);
+    }
+
     /// Cuts the surface into two surfaces at the parameter `u`
     /// # Examples
     /// ```
@@ -1694,6 +1892,157 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         BSplineSurface::new(knot_vecs, control_points)
     }
 
+    /// Creates the Coons patch by four boundary curves.
+    ///
+    /// The boundary of the patch is `curve0`, `curve1`, `curve2` and `curve3` in this order, i.e.
+    /// the point `surface.subs(u, v)` runs on `curve0` if `v == 0`, on `curve1` if `u == 1`, on
+    /// the inverse of `curve2` if `v == 1` and on the inverse of `curve3` if `u == 0`. The end
+    /// points of the curves are expected to be connected.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, -0.2, 0.3), Point3::new(1.0, 0.0, 0.0)],
+    /// );
+    /// let curve1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    /// );
+    /// let curve2 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(3),
+    ///     vec![
+    ///         Point3::new(1.0, 1.0, 0.0),
+    ///         Point3::new(0.7, 1.2, 0.2),
+    ///         Point3::new(0.3, 1.2, -0.2),
+    ///         Point3::new(0.0, 1.0, 0.0),
+    ///     ],
+    /// );
+    /// let curve3 = BSplineCurve::new(
+    ///     KnotVec::uniform_knot(2, 2),
+    ///     vec![
+    ///         Point3::new(0.0, 1.0, 0.0),
+    ///         Point3::new(-0.2, 0.7, 0.1),
+    ///         Point3::new(-0.2, 0.3, 0.1),
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///     ],
+    /// );
+    /// let surface = BSplineSurface::coons([
+    ///     curve0.clone(),
+    ///     curve1.clone(),
+    ///     curve2.clone(),
+    ///     curve3.clone(),
+    /// ]);
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     assert_near!(surface.subs(t, 0.0), curve0.subs(t));
+    ///     assert_near!(surface.subs(1.0, t), curve1.subs(t));
+    ///     assert_near!(surface.subs(1.0 - t, 1.0), curve2.subs(t));
+    ///     assert_near!(surface.subs(0.0, 1.0 - t), curve3.subs(t));
+    /// }
+    /// ```
+    pub fn coons(curves: [BSplineCurve<P>; 4]) -> BSplineSurface<P> {
+        let [curve0, curve1, mut curve2, mut curve3] = curves;
+        curve2.invert();
+        curve3.invert();
+        let points = vec![
+            vec![curve0.front(), curve0.back()],
+            vec![curve2.front(), curve2.back()],
+        ];
+        Self::gordon_surface(
+            vec![curve0, curve2],
+            vec![curve3, curve1],
+            &points,
+            &[0.0, 1.0],
+            &[0.0, 1.0],
+        )
+    }
+
+    /// Creates the Gordon surface by the compatible curve network, blending the curves linearly.
+    /// The knot vectors of all curves are normalized. `u_curves` and `v_curves` have the
+    /// intersection at `u_params[j]` and `v_params[i]`, and `points[i][j]` is the intersection
+    /// of `u_curves[i]` and `v_curves[j]`.
+    fn gordon_surface(
+        mut u_curves: Vec<BSplineCurve<P>>,
+        mut v_curves: Vec<BSplineCurve<P>>,
+        points: &[Vec<P>],
+        u_params: &[f64],
+        v_params: &[f64],
+    ) -> BSplineSurface<P> {
+        syncro_curves(&mut u_curves);
+        syncro_curves(&mut v_curves);
+        let linear_knot_vec = |params: &[f64]| {
+            let mut knots = vec![params[0]];
+            knots.extend(params);
+            knots.push(params[params.len() - 1]);
+            KnotVec::from(knots)
+        };
+        let uknot_vec = u_curves[0].knot_vec().clone();
+        let vknot_vec = v_curves[0].knot_vec().clone();
+        let (ulinear, vlinear) = (linear_knot_vec(u_params), linear_knot_vec(v_params));
+
+        // skinning `u_curves` linearly
+        let control_points = (0..u_curves[0].control_points().len())
+            .map(|k| u_curves.iter().map(|curve| curve.control_points[k]).collect())
+            .collect();
+        let mut surface0 =
+            BSplineSurface::new_unchecked((uknot_vec, vlinear.clone()), control_points);
+        // skinning `v_curves` linearly
+        let control_points = v_curves.into_iter().map(|curve| curve.control_points).collect();
+        let mut surface1 =
+            BSplineSurface::new_unchecked((ulinear.clone(), vknot_vec), control_points);
+        // the bilinear interpolation of the intersections
+        let control_points = (0..u_params.len())
+            .map(|j| points.iter().map(|vec| vec[j]).collect())
+            .collect();
+        let mut surface2 = BSplineSurface::new_unchecked((ulinear, vlinear), control_points);
+
+        Self::syncro_surfaces(&mut surface0, &mut surface1);
+        Self::syncro_surfaces(&mut surface0, &mut surface2);
+        Self::syncro_surfaces(&mut surface0, &mut surface1);
+        surface0
+            .control_points
+            .iter_mut()
+            .flatten()
+            .zip(surface1.control_points.iter().flatten())
+            .zip(surface2.control_points.iter().flatten())
+            .for_each(|((pt0, pt1), pt2)| *pt0 += *pt1 - *pt2);
+        surface0
+    }
+
+    /// Makes two surfaces having the same degrees and the same knot vectors.
+    fn syncro_surfaces(surface0: &mut Self, surface1: &mut Self) {
+        surface0.syncro_degrees(surface1);
+        surface0.add_lacking_uknots(surface1);
+        surface0.add_lacking_vknots(surface1);
+    }
+
+    /// Adds the uknots of each surface lacked in the other one.
+    fn add_lacking_uknots(&mut self, other: &mut Self) {
+        let uknots0 = lacking_knots(&self.knot_vecs.0, &other.knot_vecs.0);
+        let uknots1 = lacking_knots(&other.knot_vecs.0, &self.knot_vecs.0);
+        uknots0.into_iter().for_each(|x| {
+            self.add_uknot(x);
+        });
+        uknots1.into_iter().for_each(|x| {
+            other.add_uknot(x);
+        });
+    }
+
+    /// Adds the vknots of each surface lacked in the other one.
+    fn add_lacking_vknots(&mut self, other: &mut Self) {
+        let vknots0 = lacking_knots(&self.knot_vecs.1, &other.knot_vecs.1);
+        let vknots1 = lacking_knots(&other.knot_vecs.1, &self.knot_vecs.1);
+        vknots0.into_iter().for_each(|x| {
+            self.add_vknot(x);
+        });
+        vknots1.into_iter().for_each(|x| {
+            other.add_vknot(x);
+        });
+    }
+
     /// Normalizes the knot vectors
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -1841,6 +2190,313 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     }
 }
 
+impl<P> BSplineSurface<P>
+where P: ControlPoint<f64> + Tolerance + MetricSpace<Metric = f64>
+{
+    /// Returns the surface whose uknot `idx` is removed regardless of the change of the shape.
+    fn uknot_removed(&self, idx: usize) -> Option<Self> {
+        let curves = (0..self.control_points[0].len())
+            .map(|j| {
+                let points = self.ctrl_pts_row_iter(j).cloned().collect();
+                BSplineCurve::new_unchecked(self.knot_vecs.0.clone(), points).knot_removed(idx)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let control_points = (0..curves[0].control_points.len())
+            .map(|i| curves.iter().map(|curve| curve.control_points[i]).collect())
+            .collect();
+        let knot_vecs = (curves[0].knot_vec.clone(), self.knot_vecs.1.clone());
+        Some(Self::new_unchecked(knot_vecs, control_points))
+    }
+
+    /// Returns the surface whose vknot `idx` is removed regardless of the change of the shape.
+    fn vknot_removed(&self, idx: usize) -> Option<Self> {
+        let curves = self
+            .control_points
+            .iter()
+            .map(|points| {
+                BSplineCurve::new_unchecked(self.knot_vecs.1.clone(), points.clone())
+                    .knot_removed(idx)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let knot_vecs = (self.knot_vecs.0.clone(), curves[0].knot_vec.clone());
+        let control_points = curves
+            .into_iter()
+            .map(|curve| curve.control_points)
+            .collect();
+        Some(Self::new_unchecked(knot_vecs, control_points))
+    }
+
+    /// Removes uknots and vknots in order from the back while the distance from the original
+    /// surface is within `tolerance`. As [`BSplineCurve::approximate_self`], the distance is
+    /// bounded on the whole domain by the control points of the difference.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.5, -1.0), Point2::new(1.0, 0.0)],
+    ///     vec![Point2::new(0.0, 1.0), Point2::new(0.5, 2.0), Point2::new(1.0, 1.0)],
+    ///     vec![Point2::new(0.0, 2.0), Point2::new(0.5, -1.0), Point2::new(1.0, 2.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// bspsurface.add_uknot(0.3).add_uknot(0.6).add_vknot(0.5);
+    /// // a small noise making the knots unremovable by `optimize`
+    /// *bspsurface.control_point_mut(2, 2) += Vector2::new(1.0e-4, 0.0);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.approximate_self(1.0e-2);
+    /// assert_eq!(bspsurface.uknot_vec(), &KnotVec::bezier_knot(2));
+    /// assert_eq!(bspsurface.vknot_vec(), &KnotVec::bezier_knot(2));
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert!(bspsurface.subs(u, v).distance(org_surface.subs(u, v)) < 1.0e-2);
+    ///     }
+    /// }
+    /// ```
+    pub fn approximate_self(&mut self, tolerance: f64) -> &mut Self {
+        let original = self.clone();
+        let try_update = |surface: Option<Self>, this: &mut Self| match surface {
+            Some(surface) if original.deviation_bound(&surface) <= tolerance => {
+                *this = surface;
+                true
+            }
+            _ => false,
+        };
+        loop {
+            let (n0, n1) = (self.knot_vecs.0.len(), self.knot_vecs.1.len());
+            let mut removed = false;
+            for i in 1..=n0 {
+                let surface = self.uknot_removed(n0 - i);
+                removed = try_update(surface, self) || removed;
+            }
+            for j in 1..=n1 {
+                let surface = self.vknot_removed(n1 - j);
+                removed = try_update(surface, self) || removed;
+            }
+            if !removed {
+                break;
+            }
+        }
+        self
+    }
+
+    /// the maximum distance of the control points of `self` and `other` on the common knot
+    /// vectors, which bounds the distance of the surfaces
+    fn deviation_bound(&self, other: &Self) -> f64 {
+        let (mut surface0, mut surface1) = (self.clone(), other.clone());
+        surface0.syncro_knots(&mut surface1);
+        surface0
+            .control_points
+            .iter()
+            .flatten()
+            .zip(surface1.control_points.iter().flatten())
+            .fold(0.0, |max, (p, q)| f64::max(max, p.distance(*q)))
+    }
+
+    /// Merges two abutting surfaces into one surface if they are joined with C1 continuity.
+    ///
+    /// The surfaces are merged if the back of `self` and the front of `other` abut in the u or v
+    /// direction, or vice versa. The knot vector of the latter surface is scaled so that the
+    /// derivatives across the boundary are continuous, and the knot vector in the other direction
+    /// is normalized. The merged surface has the knot vector of the former surface in the merged
+    /// direction. It is useful to reduce the number of faces, e.g. created by lofting.
+    /// # Failures
+    /// Returns [`Error::CannotMerge`] if the surfaces do not abut, are not clamped in the direction
+    /// of the abutting boundary, or are not C1 on the boundary.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 0.5)],
+    ///     vec![Point3::new(2.0, 0.0, -1.0), Point3::new(2.0, 1.0, 0.0)],
+    ///     vec![Point3::new(3.0, 0.0, 0.0), Point3::new(3.0, 1.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut part0 = bspsurface.clone();
+    /// let part1 = part0.ucut(0.3);
+    ///
+    /// // the parts of a surface are merged back.
+    /// let merged = part1.try_merge(&part0).unwrap();
+    /// assert_eq!(merged.uknot_vec().len(), bspsurface.uknot_vec().len());
+    /// assert!(merged.near_as_surface(&bspsurface));
+    ///
+    /// // the parts with the kink on the boundary are not merged.
+    /// let mut kinked = part1.clone();
+    /// *kinked.control_point_mut(1, 0) += Vector3::new(0.0, 0.0, 1.0);
+    /// assert_eq!(part0.try_merge(&kinked), Err(Error::CannotMerge));
+    /// ```
+    pub fn try_merge(&self, other: &Self) -> Result<Self> {
+        let merged_v = |surface0: &Self, surface1: &Self| {
+            let (mut surface0, mut surface1) = (surface0.clone(), surface1.clone());
+            surface0.swap_axes();
+            surface1.swap_axes();
+            let mut merged = surface0.merged_u(&surface1)?;
+            merged.swap_axes();
+            Some(merged)
+        };
+        self.merged_u(other)
+            .or_else(|| other.merged_u(self))
+            .or_else(|| merged_v(self, other))
+            .or_else(|| merged_v(other, self))
+            .ok_or(Error::CannotMerge)
+    }
+
+    /// Returns the surface concatenating `other` after `self` in the u direction if the
+    /// concatenation is C1.
+    fn merged_u(&self, other: &Self) -> Option<Self> {
+        let (mut surface0, mut surface1) = (self.clone(), other.clone());
+        surface0.syncro_degrees(&mut surface1);
+        surface0.knot_vecs.1.try_normalize().ok()?;
+        surface1.knot_vecs.1.try_normalize().ok()?;
+        surface0.add_lacking_vknots(&mut surface1);
+
+        let (k, n) = (surface0.udegree(), surface0.control_points.len());
+        // the piecewise constant surfaces are not C1.
+        if k == 0 {
+            return None;
+        }
+        let (rows0, rows1) = (&surface0.control_points, &surface1.control_points);
+        let abutting = rows0[n - 1].iter().zip(&rows1[0]).all(|(p, q)| p.near(q));
+        if !abutting {
+            return None;
+        }
+        // the magnitudes of the derivatives on the boundary except the common factor the degree
+        let speed = |row0: &[P], row1: &[P], delta: f64| {
+            let sum: f64 = row0.iter().zip(row1).map(|(p, q)| p.distance(*q)).sum();
+            sum * inv_or_zero(delta)
+        };
+        let (knot_vec0, knot_vec1) = (&surface0.knot_vecs.0, &surface1.knot_vecs.0);
+        let delta0 = knot_vec0[n + k] - knot_vec0[n - 1];
+        let speed0 = speed(&rows0[n - 2], &rows0[n - 1], delta0);
+        let speed1 = speed(&rows1[0], &rows1[1], knot_vec1[k + 1] - knot_vec1[0]);
+        if speed0.so_small() || speed1.so_small() {
+            return None;
+        }
+        let scale = speed1 / speed0;
+        let translation = knot_vec0[n + k] - knot_vec1[0] * scale;
+        surface1.knot_vecs.0.transform(scale, translation);
+
+        let uknot_vec = &mut surface0.knot_vecs.0;
+        uknot_vec.try_concat(&surface1.knot_vecs.0, k).ok()?;
+        surface0.control_points.extend(surface1.control_points);
+        // C0 if one of the multiple knots on the boundary is removed, C1 if two are removed.
+        let removed = (0..=k)
+            .take_while(|_| surface0.try_remove_uknot(n).is_ok())
+            .count();
+        match removed >= 2 {
+            true => Some(surface0),
+            false => None,
+        }
+    }
+}
+
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>
+        + Tolerance,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    /// Creates the Gordon surface by the curve network.
+    ///
+    /// `points[i][j]` is the intersection of `u_curves[i]` and `v_curves[j]`. The surface
+    /// interpolates all curves: `u_curves[i]` is the iso-curve at `v = v_i` and `v_curves[j]`
+    /// is the one at `u = u_j`, where `u_j` and `v_i` are the parameters of the intersections
+    /// on the normalized curves. The network curves are blended linearly, so the surface is
+    /// only continuous across the curves.
+    /// # Failures
+    /// Returns `Error::IncompatibleCurveNetwork` if
+    /// * the number of the curves in each direction is less than 2,
+    /// * the matrix of `points` does not match the numbers of curves,
+    /// * the first and the last curves do not bound the network, or
+    /// * the curves in one direction pass the intersections at different parameters.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the network of the paraboloid z = x^2 - y^2
+    /// let params = [0.0, 0.5, 1.0];
+    /// let u_curves = params
+    ///     .iter()
+    ///     .map(|v| {
+    ///         BSplineCurve::new(
+    ///             KnotVec::bezier_knot(2),
+    ///             vec![
+    ///                 Point3::new(0.0, *v, -v * v),
+    ///                 Point3::new(0.5, *v, -v * v),
+    ///                 Point3::new(1.0, *v, 1.0 - v * v),
+    ///             ],
+    ///         )
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let v_curves = params
+    ///     .iter()
+    ///     .map(|u| {
+    ///         BSplineCurve::new(
+    ///             KnotVec::bezier_knot(2),
+    ///             vec![
+    ///                 Point3::new(*u, 0.0, u * u),
+    ///                 Point3::new(*u, 0.5, u * u),
+    ///                 Point3::new(*u, 1.0, u * u - 1.0),
+    ///             ],
+    ///         )
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let points = params
+    ///     .iter()
+    ///     .map(|v| params.iter().map(|u| Point3::new(*u, *v, u * u - v * v)).collect())
+    ///     .collect::<Vec<Vec<_>>>();
+    ///
+    /// let surface = BSplineSurface::try_gordon(u_curves, v_curves, &points).unwrap();
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert_near!(surface.subs(u, v), Point3::new(u, v, u * u - v * v));
+    ///     }
+    /// }
+    /// ```
+    pub fn try_gordon(
+        mut u_curves: Vec<BSplineCurve<P>>,
+        mut v_curves: Vec<BSplineCurve<P>>,
+        points: &[Vec<P>],
+    ) -> Result<BSplineSurface<P>> {
+        let (n, m) = (u_curves.len(), v_curves.len());
+        if n < 2 || m < 2 || points.len() != n || points.iter().any(|vec| vec.len() != m) {
+            return Err(Error::IncompatibleCurveNetwork);
+        }
+        u_curves
+            .iter_mut()
+            .chain(&mut v_curves)
+            .for_each(|curve| {
+                curve.knot_normalize();
+            });
+        let u_params = network_parameters(&u_curves, m, |i, j| points[i][j])?;
+        let v_params = network_parameters(&v_curves, n, |j, i| points[i][j])?;
+        Ok(Self::gordon_surface(u_curves, v_curves, points, &u_params, &v_params))
+    }
+
+    /// Creates the Gordon surface by the curve network.
+    ///
+    /// cf. [`BSplineSurface::try_gordon`]
+    /// # Panics
+    /// Panic occurs if the curve network is not compatible.
+    #[inline(always)]
+    pub fn gordon(
+        u_curves: Vec<BSplineCurve<P>>,
+        v_curves: Vec<BSplineCurve<P>>,
+        points: &[Vec<P>],
+    ) -> BSplineSurface<P> {
+        Self::try_gordon(u_curves, v_curves, points).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
 impl<V> BSplineSurface<V>
 where V: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> + Copy
 {
@@ -1851,6 +2507,140 @@ where V: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> +
     }
 }
 
+impl<P> BSplineSurface<P>
+where P: ControlPoint<f64>
+        + MetricSpace<Metric = f64>
+        + Index<usize, Output = f64>
+        + Bounded<f64>
+        + Tolerance
+{
+    /// Returns the bounding box enclosing the part of the surface in the parameter box.
+    ///
+    /// As [`BSplineCurve::interval_bounding_box`], the box includes the control points of the
+    /// part of the surface, so the part is enclosed in the box.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 2.0), Point3::new(1.0, 1.0, 2.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// let bbx = bspsurface.interval_bounding_box((0.0, 0.25), (0.5, 1.0));
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let u = 0.25 * i as f64 / N as f64;
+    ///         let v = 0.5 + 0.5 * j as f64 / N as f64;
+    ///         let p = bspsurface.subs(u, v);
+    ///         assert!((0..3).all(|k| bbx.min()[k] <= p[k] && p[k] <= bbx.max()[k]));
+    ///     }
+    /// }
+    /// // tighter than the box of all control points
+    /// assert!(bbx.max()[2] < 1.0);
+    /// assert_near!(bbx.min()[1], 0.5);
+    /// ```
+    pub fn interval_bounding_box(
+        &self,
+        (u0, u1): (f64, f64),
+        (v0, v1): (f64, f64),
+    ) -> BoundingBox<P> {
+        let (udegree, vdegree) = (self.udegree(), self.vdegree());
+        let mut part = self.clone();
+        for u in [u0, u1] {
+            for _ in 0..lacking_multiplicity(part.uknot_vec(), udegree, u) {
+                part.add_uknot(u);
+            }
+        }
+        for v in [v0, v1] {
+            for _ in 0..lacking_multiplicity(part.vknot_vec(), vdegree, v) {
+                part.add_vknot(v);
+            }
+        }
+        let urange = nonvanishing_bases(part.uknot_vec(), udegree, (u0, u1));
+        let vrange = nonvanishing_bases(part.vknot_vec(), vdegree, (v0, v1));
+        let mut bbx: BoundingBox<P> = part.control_points[urange]
+            .iter()
+            .flat_map(|vec| &vec[vrange.clone()])
+            .collect();
+        [(u0, v0), (u0, v1), (u1, v0), (u1, v1)]
+            .into_iter()
+            .for_each(|(u, v)| bbx.push(&self.subs(u, v)));
+        bbx
+    }
+
+    /// Returns the parameter boxes which may include the parameter of `point`.
+    ///
+    /// The parameter box is recursively divided into four boxes `depth` times, and the boxes are
+    /// rejected if `point` is not near the
+    /// [`interval_bounding_box`](Self::interval_bounding_box). As
+    /// [`BSplineCurve::isolate_parameters`], the rejection is verified.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 2.0), Point3::new(1.0, 1.0, 2.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// let point = bspsurface.subs(0.3, 0.6);
+    /// let boxes = bspsurface.isolate_parameters(point, 3);
+    /// assert!(boxes.iter().any(|((u0, u1), (v0, v1))| {
+    ///     *u0 <= 0.3 && 0.3 <= *u1 && *v0 <= 0.6 && 0.6 <= *v1
+    /// }));
+    /// // the boxes are narrower than the whole box.
+    /// assert!(boxes.len() < 64);
+    ///
+    /// assert!(bspsurface.isolate_parameters(Point3::new(1.0, 0.5, 3.0), 3).is_empty());
+    /// ```
+    pub fn isolate_parameters(&self, point: P, depth: usize) -> Vec<((f64, f64), (f64, f64))> {
+        let near = |(urange, vrange): &((f64, f64), (f64, f64))| {
+            near_bounding_box(&self.interval_bounding_box(*urange, *vrange), point)
+        };
+        let boxes = Some(self.parameter_range())
+            .into_iter()
+            .filter(near)
+            .collect::<Vec<_>>();
+        (0..depth).fold(boxes, |boxes, _| {
+            boxes
+                .into_iter()
+                .flat_map(|((u0, u1), (v0, v1))| {
+                    let (u, v) = ((u0 + u1) / 2.0, (v0 + v1) / 2.0);
+                    [
+                        ((u0, u), (v0, v)),
+                        ((u, u1), (v0, v)),
+                        ((u0, u), (v, v1)),
+                        ((u, u1), (v, v1)),
+                    ]
+                })
+                .filter(near)
+                .collect()
+        })
+    }
+
+    /// Searches the parameter from the presearch by `search`, and then from the isolated boxes
+    /// if failed.
+    fn search_parameter_with_isolation<F>(&self, point: P, search: F) -> Option<(f64, f64)>
+    where F: Fn((f64, f64)) -> Option<(f64, f64)> {
+        if !near_bounding_box(&self.roughly_bounding_box(), point) {
+            return None;
+        }
+        let range = self.parameter_range();
+        let hint = algo::surface::presearch(self, point, range, PRESEARCH_DIVISION);
+        search(hint).or_else(|| {
+            self.isolate_parameters(point, ISOLATION_DEPTH)
+                .into_iter()
+                .find_map(|((u0, u1), (v0, v1))| search(((u0 + u1) / 2.0, (v0 + v1) / 2.0)))
+        })
+    }
+}
+
 impl<P: ControlPoint<f64>> ParameterDivision2D for BSplineSurface<P>
 where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
         + MetricSpace<Metric = f64>
@@ -1894,7 +2684,9 @@ impl SearchParameter<D2> for BSplineSurface<Point2> {
                 algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
             }
             SPHint2D::None => {
-                algo::surface::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION)
+                return self.search_parameter_with_isolation(point, |hint| {
+                    algo::surface::search_parameter2d(self, point, hint, trials)
+                })
             }
         };
         algo::surface::search_parameter2d(self, point, hint, trials)
@@ -1915,7 +2707,9 @@ impl SearchParameter<D2> for BSplineSurface<Point3> {
                 algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
             }
             SPHint2D::None => {
-                algo::surface::presearch(self, point, self.parameter_range(), PRESEARCH_DIVISION)
+                return self.search_parameter_with_isolation(point, |hint| {
+                    algo::surface::search_parameter3d(self, point, hint, trials)
+                })
             }
         };
         algo::surface::search_parameter3d(self, point, hint, trials)
@@ -2139,6 +2933,65 @@ fn combinatorial(n: usize) -> Vec<usize> {
     res
 }
 
+/// Makes all curves having the same degree and the same normalized knot vector.
+fn syncro_curves<P: ControlPoint<f64> + Tolerance>(curves: &mut [BSplineCurve<P>]) {
+    let (first, others) = curves.split_first_mut().unwrap();
+    // the first curve has the maximum degree and all knots at the first loop.
+    (0..2).for_each(|_| others.iter_mut().for_each(|curve| first.syncro_degree(curve)));
+    (0..2).for_each(|_| others.iter_mut().for_each(|curve| first.syncro_knots(curve)));
+}
+
+/// Returns the knots to be added to `knot_vec0` for containing `knot_vec1`.
+fn lacking_knots(knot_vec0: &KnotVec, knot_vec1: &KnotVec) -> Vec<f64> {
+    let (knots0, mults0) = knot_vec0.to_single_multi();
+    let (knots1, mults1) = knot_vec1.to_single_multi();
+    knots1
+        .into_iter()
+        .zip(mults1)
+        .flat_map(|(x, mult)| {
+            let (x, mult0) = match knots0.iter().position(|y| y.near(&x)) {
+                Some(idx) => (knots0[idx], mults0[idx]),
+                None => (x, 0),
+            };
+            std::iter::repeat(x).take(mult.saturating_sub(mult0))
+        })
+        .collect()
+}
+
+/// Returns the parameters of the intersections `point(i, j)` on the `i`th curve of the network.
+fn network_parameters<P>(
+    curves: &[BSplineCurve<P>],
+    len: usize,
+    point: impl Fn(usize, usize) -> P,
+) -> Result<Vec<f64>>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>
+        + Tolerance,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let mut params = (0..len)
+        .map(|j| curves[0].search_nearest_parameter(point(0, j), None, 100))
+        .collect::<Option<Vec<f64>>>()
+        .ok_or(Error::IncompatibleCurveNetwork)?;
+    let compatible = params[0].so_small()
+        && params[len - 1].near(&1.0)
+        && params.windows(2).all(|a| a[0] < a[1])
+        && curves.iter().enumerate().all(|(i, curve)| {
+            params
+                .iter()
+                .enumerate()
+                .all(|(j, t)| curve.subs(*t).near(&point(i, j)))
+        });
+    if !compatible {
+        return Err(Error::IncompatibleCurveNetwork);
+    }
+    params[0] = 0.0;
+    params[len - 1] = 1.0;
+    Ok(params)
+}
+
 #[test]
 fn test_include_bspcurve2() {
     let knot_vec = KnotVec::uniform_knot(2, 3);
@@ -2236,3 +3089,81 @@ fn test_include_bspcurve3() {
     *curve.control_point_mut(2) += Vector3::new(0.0, 0.0, 0.001);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn test_fundamental_forms() {
+    // the paraboloid (u, v, u^2 + v^2)
+    let knot_vec = KnotVec::bezier_knot(2);
+    let heights = [0.0, 0.0, 1.0];
+    let ctrl_pts = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| Point3::new(i as f64 / 2.0, j as f64 / 2.0, heights[i] + heights[j]))
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+    const N: usize = 10;
+    for i in 0..=N {
+        for j in 0..=N {
+            let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+            let (e, f, g) = surface.first_fundamental_form(u, v);
+            assert_near!(e, 1.0 + 4.0 * u * u);
+            assert_near!(f, 4.0 * u * v);
+            assert_near!(g, 1.0 + 4.0 * v * v);
+            let w = f64::sqrt(1.0 + 4.0 * u * u + 4.0 * v * v);
+            let (l, m, n) = surface.second_fundamental_form(u, v);
+            assert_near!(l, 2.0 / w);
+            assert!(m.so_small());
+            assert_near!(n, 2.0 / w);
+            // the Gaussian curvature of the paraboloid
+            let shape = surface.shape_operator(u, v).unwrap();
+            assert_near!(shape.determinant(), 4.0 / (w * w * w * w));
+        }
+    }
+}
+
+#[test]
+fn merge_surfaces_test() {
+    let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(3));
+    let ctrl_pts = (0..3)
+        .map(|i| {
+            (0..4)
+                .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64))
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let mut part0 = surface.clone();
+    let mut part1 = part0.vcut(0.4);
+    // the parameter and the degree of the part are changed.
+    part1.knot_normalize().elevate_udegree();
+    let merged = part1.try_merge(&part0).unwrap();
+    assert_eq!(merged.degrees(), (3, 3));
+    assert!(merged.near_as_surface(&surface));
+
+    // the planes abutting with C1 continuity, and folding on the boundary
+    let plane = |pt0: Point3, pt1: Point3, pt2: Point3| {
+        let ctrl_pts = vec![vec![pt0, pt0 + (pt2 - pt1)], vec![pt1, pt2]];
+        BSplineSurface::new((KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)), ctrl_pts)
+    };
+    let plane0 = plane(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+    );
+    let plane1 = plane(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(3.0, 0.0, 0.0),
+        Point3::new(3.0, 1.0, 0.0),
+    );
+    let plane2 = plane(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    );
+    let merged = plane0.try_merge(&plane1).unwrap();
+    assert_eq!(merged.control_points().len(), 2);
+    assert_near!(merged.subs(2.0, 0.5), Point3::new(2.0, 0.5, 0.0));
+    assert_eq!(plane0.try_merge(&plane2), Err(Error::CannotMerge));
+}
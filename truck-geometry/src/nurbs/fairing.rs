@@ -0,0 +1,234 @@
+use super::*;
+use truck_geotrait::algo::{gauss_legendre_nodes, solve_linear};
+
+impl<P: ControlPoint<f64>> BSplineCurve<P> {
+    /// Fairs the curve by minimizing the bending energy with fixing the two control points on
+    /// each end, e.g. in order to smooth the sketched or the fitted curves.
+    ///
+    /// The moved control points minimize the sum of the squared displacements of the control
+    /// points and the bending energy `∫ |C''|^2 dt` multiplied by `weight`. The end points and
+    /// the derivatives at the ends are kept if the knot vector is clamped. The curve with at most
+    /// four control points is not changed.
+    /// # Panics
+    /// Panics if `weight` is negative.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::uniform_knot(3, 5);
+    /// // the noisy line
+    /// let noise = [0.0, 0.0, 0.02, -0.02, 0.02, -0.02, 0.0, 0.0];
+    /// let ctrl_pts = (0..8)
+    ///     .map(|i| Point2::new(i as f64 / 7.0, noise[i]))
+    ///     .collect();
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let ends = (bspcurve.front(), bspcurve.back());
+    /// let ders = (bspcurve.der(0.0), bspcurve.der(1.0));
+    ///
+    /// bspcurve.fair(0.01);
+    /// assert!(bspcurve.control_points().iter().all(|pt| f64::abs(pt.y) < 0.005));
+    /// assert_eq!((bspcurve.front(), bspcurve.back()), ends);
+    /// assert_eq!((bspcurve.der(0.0), bspcurve.der(1.0)), ders);
+    /// ```
+    pub fn fair(&mut self, weight: f64) -> &mut Self {
+        assert!(weight >= 0.0, "the weight must be non-negative.");
+        let n = self.control_points.len();
+        let energy = gram_matrix(&self.knot_vec, self.degree(), 2);
+        let free: Vec<usize> = (2..n.saturating_sub(2)).collect();
+        let matrix = free
+            .iter()
+            .map(|i| {
+                let row = free.iter().map(|j| weight * energy[*i][*j]);
+                let row = row.zip(&free).map(|(a, j)| match i == j {
+                    true => a + 1.0,
+                    false => a,
+                });
+                row.collect()
+            })
+            .collect();
+        let rhs = free
+            .iter()
+            .map(|i| {
+                let pts = self.control_points.iter().zip(&energy[*i]);
+                pts.fold(P::Diff::zero(), |sum, (pt, a)| {
+                    sum - pt.to_vec() * (weight * a)
+                })
+            })
+            .collect();
+        // the matrix is positive definite.
+        let displacements = solve_linear(matrix, rhs).unwrap();
+        free.into_iter()
+            .zip(displacements)
+            .for_each(|(i, delta)| self.control_points[i] += delta);
+        self
+    }
+}
+
+impl<P: ControlPoint<f64>> BSplineSurface<P> {
+    /// Fairs the surface by minimizing the thin-plate energy with fixing the boundary control
+    /// points, e.g. in order to smooth the wiggles of the fitted surfaces.
+    ///
+    /// The moved interior control points minimize the sum of the squared displacements of the
+    /// control points and the thin-plate energy
+    /// `∫∫ |S_uu|^2 + 2|S_uv|^2 + |S_vv|^2 du dv` multiplied by `weight`. The larger `weight`
+    /// makes the surface nearer to the thin plate spanned by the boundary. The boundary curves
+    /// are kept if the knot vectors are clamped.
+    /// # Panics
+    /// Panics if `weight` is negative.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::uniform_knot(3, 3);
+    /// let ctrl_pts = (0..6)
+    ///     .map(|i| {
+    ///         (0..6)
+    ///             .map(|j| Point3::new(i as f64 / 5.0, j as f64 / 5.0, 0.0))
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let mut bspsurface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+    /// // the wiggle
+    /// *bspsurface.control_point_mut(2, 2) += Vector3::new(0.0, 0.0, 0.1);
+    /// *bspsurface.control_point_mut(3, 3) -= Vector3::new(0.0, 0.0, 0.1);
+    /// let boundary = bspsurface.boundary();
+    ///
+    /// bspsurface.fair(1.0);
+    /// let height = |surface: &BSplineSurface<Point3>| {
+    ///     surface
+    ///         .control_points()
+    ///         .iter()
+    ///         .flatten()
+    ///         .fold(0.0, |max, pt| f64::max(max, f64::abs(pt.z)))
+    /// };
+    /// assert!(height(&bspsurface) < 0.01);
+    /// assert_eq!(bspsurface.boundary(), boundary);
+    /// ```
+    pub fn fair(&mut self, weight: f64) -> &mut Self {
+        assert!(weight >= 0.0, "the weight must be non-negative.");
+        let (n0, n1) = (self.control_points.len(), self.control_points[0].len());
+        let (degree0, degree1) = self.degrees();
+        let udersq = |r| gram_matrix(&self.knot_vecs.0, degree0, r);
+        let vdersq = |r| gram_matrix(&self.knot_vecs.1, degree1, r);
+        let a = [udersq(0), udersq(1), udersq(2)];
+        let b = [vdersq(0), vdersq(1), vdersq(2)];
+        let energy = |(i, j): (usize, usize), (k, l): (usize, usize)| {
+            a[2][i][k] * b[0][j][l] + 2.0 * a[1][i][k] * b[1][j][l] + a[0][i][k] * b[2][j][l]
+        };
+        let free: Vec<(usize, usize)> = (1..n0.saturating_sub(1))
+            .flat_map(|i| (1..n1 - 1).map(move |j| (i, j)))
+            .collect();
+        let matrix = free
+            .iter()
+            .map(|x| {
+                let row = free.iter().map(|y| weight * energy(*x, *y));
+                let row = row.zip(&free).map(|(a, y)| match x == y {
+                    true => a + 1.0,
+                    false => a,
+                });
+                row.collect()
+            })
+            .collect();
+        let rhs = free
+            .iter()
+            .map(|x| {
+                let pts = self.control_points.iter().enumerate();
+                pts.fold(P::Diff::zero(), |sum, (k, row)| {
+                    row.iter().enumerate().fold(sum, |sum, (l, pt)| {
+                        sum - pt.to_vec() * (weight * energy(*x, (k, l)))
+                    })
+                })
+            })
+            .collect();
+        // the matrix is positive definite.
+        let displacements = solve_linear(matrix, rhs).unwrap();
+        free.into_iter()
+            .zip(displacements)
+            .for_each(|((i, j), delta)| self.control_points[i][j] += delta);
+        self
+    }
+}
+
+/// Returns the matrix of the integrals of the products of the `rank`th derivatives of the
+/// B-spline basis functions. The integrals are exact if the degree is at most four.
+fn gram_matrix(knot_vec: &KnotVec, degree: usize, rank: usize) -> Vec<Vec<f64>> {
+    let n = knot_vec.len() - degree - 1;
+    let mut matrix = vec![vec![0.0; n]; n];
+    knot_vec
+        .windows(2)
+        .filter(|span| span[0] < span[1])
+        .flat_map(|span| gauss_legendre_nodes((span[0], span[1]), 1))
+        .for_each(|(t, w)| {
+            let bases = basis_derivatives(knot_vec, degree, rank, t);
+            (0..n).for_each(|i| (0..n).for_each(|j| matrix[i][j] += w * bases[i] * bases[j]))
+        });
+    matrix
+}
+
+#[test]
+fn curve_fairing_test() {
+    // the control points are fixed if there are no other points than the ends.
+    let ctrl_pts = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(2.0, -2.0),
+        Point2::new(3.0, 0.0),
+    ];
+    let bezier = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+    assert_eq!(bezier.clone().fair(1.0), &bezier);
+
+    let knot_vec = KnotVec::uniform_knot(3, 6);
+    let ctrl_pts = (0..9)
+        .map(|i| Vector3::new(i as f64, 0.0, (i % 2) as f64 * 0.1))
+        .collect();
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let energy = gram_matrix(bspcurve.knot_vec(), 3, 2);
+    let bending = |curve: &BSplineCurve<Vector3>| {
+        let pts = curve.control_points();
+        let mut sum = 0.0;
+        for (p, row) in pts.iter().zip(&energy) {
+            for (q, a) in pts.iter().zip(row) {
+                sum += a * p.dot(*q);
+            }
+        }
+        sum
+    };
+    let faired: Vec<BSplineCurve<Vector3>> = [0.0, 0.01, 0.1, 1.0]
+        .into_iter()
+        .map(|weight| bspcurve.clone().fair(weight).clone())
+        .collect();
+    assert_eq!(faired[0], bspcurve);
+    // the curves keep in the plane, and the bending energies decrease with the weights.
+    let pts = faired.iter().flat_map(BSplineCurve::control_points);
+    assert!(pts.clone().all(|pt| pt.y == 0.0));
+    assert!(faired.windows(2).all(|a| bending(&a[0]) > bending(&a[1])));
+}
+
+#[test]
+fn fairing_test() {
+    // the affine surfaces have no thin-plate energy.
+    let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(3));
+    let ctrl_pts = (0..3)
+        .map(|i| {
+            (0..4)
+                .map(|j| Point3::new(i as f64 / 2.0, j as f64 / 3.0, i as f64 - j as f64))
+                .collect()
+        })
+        .collect();
+    let affine = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let mut faired = affine.clone();
+    faired.fair(100.0);
+    assert!(faired.near_as_surface(&affine));
+
+    let knot_vec = KnotVec::uniform_knot(3, 4);
+    let ctrl_pts = (0..7)
+        .map(|i| (0..7).map(|j| Vector2::new(i as f64, j as f64)).collect())
+        .collect();
+    let mut bspsurface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+    *bspsurface.control_point_mut(3, 3) += Vector2::new(0.5, 0.5);
+    let center = Vector2::new(3.0, 3.0);
+    let bump = |surface: &BSplineSurface<Vector2>| surface.subs(0.5, 0.5).distance(center);
+    let heights: Vec<f64> = [0.0, 0.01, 0.1, 1.0]
+        .into_iter()
+        .map(|weight| bump(bspsurface.clone().fair(weight)))
+        .collect();
+    assert!(heights.windows(2).all(|a| a[0] > a[1]), "{heights:?}");
+}
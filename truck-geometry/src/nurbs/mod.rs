@@ -1,4 +1,5 @@
 use crate::{prelude::*, *};
+use std::ops::{Index, Range};
 use truck_base::cgmath64::control_point::ControlPoint;
 
 /// knot vector
@@ -120,6 +121,7 @@ pub struct NurbsSurface<V>(BSplineSurface<V>);
 
 mod bspcurve;
 mod bspsurface;
+mod fairing;
 mod knot_vec;
 mod nurbscurve;
 mod nurbssurface;
@@ -133,3 +135,52 @@ pub fn inv_or_zero(delta: f64) -> f64 {
         1.0 / delta
     }
 }
+
+/// Returns the `rank`th derivatives of the B-spline basis functions at `t`.
+fn basis_derivatives(knot_vec: &KnotVec, degree: usize, rank: usize, t: f64) -> Vec<f64> {
+    let n = knot_vec.len() - degree - 1;
+    match (rank, degree) {
+        (0, _) => knot_vec.bspline_basis_functions(degree, t),
+        (_, 0) => vec![0.0; n],
+        _ => {
+            let lower = basis_derivatives(knot_vec, degree - 1, rank - 1, t);
+            let coef = |i: usize| inv_or_zero(knot_vec[i + degree] - knot_vec[i]);
+            let p = degree as f64;
+            (0..n)
+                .map(|i| p * (lower[i] * coef(i) - lower[i + 1] * coef(i + 1)))
+                .collect()
+        }
+    }
+}
+
+/// the binomial coefficient `a` choose `b`
+fn binomial(a: usize, b: usize) -> f64 {
+    (0..b).fold(1.0, |c, i| c * (a - i) as f64 / (i + 1) as f64)
+}
+
+/// the range of the indices of the B-spline bases which do not vanish in the parameter range
+fn nonvanishing_bases(knot_vec: &KnotVec, degree: usize, (t0, t1): (f64, f64)) -> Range<usize> {
+    let (s0, s1) = (knot_vec[0], knot_vec[knot_vec.len() - 1]);
+    let t0 = if t0 <= s0 { f64::NEG_INFINITY } else { t0 };
+    let t1 = if t1 >= s1 { f64::INFINITY } else { t1 };
+    let n = knot_vec.len() - degree - 1;
+    let start = (0..n).find(|i| t0 < knot_vec[i + degree + 1]).unwrap_or(n);
+    let end = (0..n).rfind(|i| knot_vec[*i] < t1).map_or(0, |i| i + 1);
+    start..usize::max(start, end)
+}
+
+/// the number of the knots to be inserted in order to make the multiplicity of `t` `degree`
+fn lacking_multiplicity(knot_vec: &KnotVec, degree: usize, t: f64) -> usize {
+    let (s0, s1) = (knot_vec[0], knot_vec[knot_vec.len() - 1]);
+    match s0 < t && t < s1 {
+        true => degree.saturating_sub(knot_vec.iter().filter(|s| **s == t).count()),
+        false => 0,
+    }
+}
+
+/// whether `point` is near the bounding box `bbx` in the sense of [`Tolerance::near`].
+fn near_bounding_box<P>(bbx: &BoundingBox<P>, point: P) -> bool
+where P: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> + Tolerance + Copy {
+    let nearest = Bounded::min(&Bounded::max(&point, bbx.min()), bbx.max());
+    nearest.near(&point)
+}
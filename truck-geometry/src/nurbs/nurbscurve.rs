@@ -544,6 +544,21 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricCurve for Nurb
         let der2 = self.0.der2(t);
         pt.rat_der2(der, der2)
     }
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        let ders: Vec<V> = (0..=n).map(|i| self.0.der_n(i, t)).collect();
+        // the derivations of the rational curve C = A / w by the Leibniz rule of A = w C
+        let mut rat_ders = Vec::<Self::Vector>::with_capacity(n + 1);
+        (0..=n).for_each(|k| {
+            let sum = (1..=k).fold(ders[k].truncate(), |sum, i| {
+                sum - rat_ders[k - i] * (binomial(k, i) * ders[i].weight())
+            });
+            rat_ders.push(sum / ders[0].weight());
+        });
+        rat_ders[n]
+    }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { Some(self.parameter_range()) }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> BoundedCurve for NurbsCurve<V> {
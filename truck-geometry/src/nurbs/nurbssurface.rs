@@ -228,6 +228,49 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NurbsSurface<V> {
         let uvd = self.0.uvder(u, v);
         pt.rat_cross_der(ud, vd, uvd)
     }
+    /// Substitutes the derived NURBS surface `m` times by `u` and `n` times by `v`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 1.0)],
+    ///     vec![Vector3::new(2.0, 0.0, 2.0), Vector3::new(2.0, 2.0, 2.0)],
+    /// ];
+    /// let surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, ctrl_pts));
+    ///
+    /// // surface: (2u / (1 + u), v), der_mn(3, 0): (12 / (1 + u)^4, 0)
+    /// const N: usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let u = (i as f64) / (N as f64);
+    ///     for j in 0..=N {
+    ///         let v = (j as f64) / (N as f64);
+    ///         assert_near!(surface.der_mn(2, 0, u, v), surface.uuder(u, v));
+    ///         assert_near!(surface.der_mn(1, 1, u, v), surface.uvder(u, v));
+    ///         let der = Vector2::new(12.0 / f64::powi(1.0 + u, 4), 0.0);
+    ///         assert_near!(surface.der_mn(3, 0, u, v), der);
+    ///         assert_near!(surface.der_mn(2, 1, u, v), Vector2::zero());
+    ///     }
+    /// }
+    /// ```
+    pub fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> <V::Point as EuclideanSpace>::Diff {
+        let ders: Vec<Vec<V>> = (0..=m)
+            .map(|i| (0..=n).map(|j| self.0.der_mn(i, j, u, v)).collect())
+            .collect();
+        // the derivations of the rational surface S = A / w by the Leibniz rule of A = w S
+        let mut rat_ders = vec![vec![<V::Point as EuclideanSpace>::Diff::zero(); n + 1]; m + 1];
+        (0..=m).for_each(|k| {
+            (0..=n).for_each(|l| {
+                let indices = (0..=k).flat_map(|i| (0..=l).map(move |j| (i, j)));
+                let sum = indices.skip(1).fold(ders[k][l].truncate(), |sum, (i, j)| {
+                    let coef = binomial(k, i) * binomial(l, j) * ders[i][j].weight();
+                    sum - rat_ders[k - i][l - j] * coef
+                });
+                rat_ders[k][l] = sum / ders[0][0].weight();
+            })
+        });
+        rat_ders[m][n]
+    }
     /// Returns the closure of substitution.
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64, f64) -> V::Point + '_ { move |u, v| self.subs(u, v) }
@@ -617,6 +660,12 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricSurface for Nu
     fn uvder(&self, u: f64, v: f64) -> Self::Vector { self.uvder(u, v) }
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Self::Vector { self.vvder(u, v) }
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector {
+        self.der_mn(m, n, u, v)
+    }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) { self.0.try_range() }
 }
 
 impl ParametricSurface3D for NurbsSurface<Vector4> {
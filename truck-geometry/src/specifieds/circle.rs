@@ -41,6 +41,95 @@ impl BoundedCurve for UnitCircle<Point3> {
     fn parameter_range(&self) -> (f64, f64) { (0.0, 2.0 * PI) }
 }
 
+impl CircleArc {
+    /// Returns the circle arc coinciding with `curve` within `tol`, e.g. in order to clean up
+    /// the imported B-spline or NURBS curves.
+    ///
+    /// The arc starts at the front of `curve` from the parameter `0.0`, and goes around the
+    /// normal of the plane of the arc. The closed curves are recognized as the whole circles.
+    /// The curve is checked at the sampling points, and the parameterization is not kept. The
+    /// whole curve is recognized, and the arcs in the sub-curves are not searched. Returns `None`
+    /// if the curve is not an arc within `tol`, e.g. if it is a line.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // the exact quarter circle
+    /// let ctrl_pts = vec![
+    ///     Vector4::new(1.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(1.0, 1.0, 0.0, 1.0) * f64::sqrt(0.5),
+    ///     Vector4::new(0.0, 1.0, 0.0, 1.0),
+    /// ];
+    /// let nurbs = NurbsCurve::new(BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts));
+    /// let arc = CircleArc::recognize(&nurbs, 1.0e-6).unwrap();
+    /// let (t0, t1) = arc.parameter_range();
+    /// assert_near!(t0, 0.0);
+    /// assert_near!(t1, PI / 2.0);
+    /// assert_near!(arc.subs(PI / 4.0), Point3::new(f64::sqrt(0.5), f64::sqrt(0.5), 0.0));
+    ///
+    /// // the parabola is not an arc.
+    /// let ctrl_pts = vec![
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// assert!(CircleArc::recognize(&bspcurve, 1.0e-3).is_none());
+    /// ```
+    pub fn recognize<C: BoundedCurve<Point = Point3>>(curve: &C, tol: f64) -> Option<Self> {
+        let (t0, t1) = curve.parameter_range();
+        let at = |t: f64| curve.subs(t0 * (1.0 - t) + t1 * t);
+        let (p0, p1) = (curve.front(), curve.back());
+        let closed = p0.distance(p1) <= tol;
+        let (p, q) = match closed {
+            true => (at(1.0 / 3.0), at(2.0 / 3.0)),
+            false => (at(0.5), p1),
+        };
+        // the circle through the three points
+        let (u, v) = (p - p0, q - p0);
+        let normal = u.cross(v);
+        if normal.magnitude() <= tol * v.magnitude() {
+            return None;
+        }
+        let vec = (v * u.magnitude2() - u * v.magnitude2()).cross(normal);
+        let center = p0 + vec / (2.0 * normal.magnitude2());
+        let radius = p0.distance(center);
+        let normal = normal.normalize();
+        let x = (p0 - center) / radius;
+        let y = normal.cross(x);
+        let angle = |vec: Vector3| f64::atan2(vec.dot(y), vec.dot(x));
+        let end = match closed {
+            true => 2.0 * PI,
+            false => angle(p1 - center).rem_euclid(2.0 * PI),
+        };
+        let mut last = 0.0;
+        for i in 1..=RECOGNITION_SAMPLES {
+            let vec = at(i as f64 / RECOGNITION_SAMPLES as f64) - center;
+            let height = vec.dot(normal);
+            let distance = (vec - normal * height).magnitude() - radius;
+            // the angle increased from the last one by less than `PI`
+            let next = last + (angle(vec) - last + PI).rem_euclid(2.0 * PI) - PI;
+            if f64::hypot(distance, height) > tol || next < last - tol / radius {
+                return None;
+            }
+            last = f64::max(last, next);
+        }
+        if f64::abs(last - end) > tol / radius {
+            return None;
+        }
+        let matrix = Matrix4::from_cols(
+            (x * radius).extend(0.0),
+            (y * radius).extend(0.0),
+            normal.extend(0.0),
+            center.to_homogeneous(),
+        );
+        let mut arc = Processor::new(TrimmedCurve::new(UnitCircle::new(), (0.0, end)));
+        arc.transform_by(matrix);
+        Some(arc)
+    }
+}
+
 impl<P> ParameterDivision1D for UnitCircle<P>
 where UnitCircle<P>: ParametricCurve<Point = P>
 {
@@ -131,3 +220,33 @@ fn parameter_division() {
         assert!(p.to_vec().magnitude() > 0.95);
     }
 }
+
+#[test]
+fn recognize_arcs() {
+    let circle = UnitCircle::<Point3>::new();
+    let mut bspcurve =
+        BSplineCurve::cubic_approximation(&circle, (0.0, 2.0 * PI), 1.0e-5, 1.0e-5, 10).unwrap();
+    let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 1.0).normalize(), Rad(1.0))
+        * Matrix4::from_scale(2.0);
+    bspcurve.transform_by(matrix);
+
+    let arc = CircleArc::recognize(&bspcurve, 1.0e-3).unwrap();
+    assert_near!(arc.parameter_range().1, 2.0 * PI);
+    (0..=10).for_each(|i| {
+        let t = 2.0 * PI * i as f64 / 10.0;
+        let pt = matrix.transform_point(circle.subs(t));
+        assert!(arc.subs(t).distance(pt) < 1.0e-3);
+    });
+
+    let mut half = bspcurve.clone();
+    half.cut(PI);
+    let arc = CircleArc::recognize(&half.inverse(), 1.0e-3).unwrap();
+    assert!(f64::abs(arc.parameter_range().1 - PI) < 1.0e-3);
+    assert!(arc.front().distance(half.back()) < 1.0e-3);
+    assert!(arc.subs(PI / 2.0).distance(half.subs(PI / 2.0)) < 1.0e-3);
+
+    let line = Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    assert!(CircleArc::recognize(&line, 1.0e-3).is_none());
+    assert!(CircleArc::recognize(&bspcurve, 1.0e-7).is_none());
+}
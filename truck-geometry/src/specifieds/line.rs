@@ -15,6 +15,58 @@ impl<P: Copy> Line<P> {
     }
 }
 
+impl<P> Line<P>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Returns the segment between the ends of `curve` if `curve` goes along the segment from
+    /// the front to the back within `tol`, e.g. in order to clean up the imported B-spline
+    /// curves. The curves turning back on the segment are not recognized.
+    ///
+    /// The curve is checked at the sampling points, and the parameterization is not kept. The
+    /// whole curve is recognized, and the segments in the sub-curves are not searched.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.2, 0.0),
+    ///     Point2::new(0.7, 1.0e-5),
+    ///     Point2::new(1.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+    /// let line = Line::recognize(&bspcurve, 1.0e-4).unwrap();
+    /// assert_eq!(line, Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)));
+    /// assert!(Line::recognize(&bspcurve, 1.0e-6).is_none());
+    ///
+    /// // the curve overshooting the back
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(2.0, 0.0), Point2::new(1.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// assert!(Line::recognize(&bspcurve, 1.0e-4).is_none());
+    /// ```
+    pub fn recognize<C: BoundedCurve<Point = P>>(curve: &C, tol: f64) -> Option<Self> {
+        let (t0, t1) = curve.parameter_range();
+        let (p0, p1) = (curve.front(), curve.back());
+        let length = p0.distance(p1);
+        if length <= tol {
+            return None;
+        }
+        let dir = (p1 - p0) / length;
+        let mut last = 0.0;
+        for i in 1..RECOGNITION_SAMPLES {
+            let t = i as f64 / RECOGNITION_SAMPLES as f64;
+            let vec = curve.subs(t0 * (1.0 - t) + t1 * t) - p0;
+            let s = vec.dot(dir);
+            if (vec - dir * s).magnitude() > tol || s < last - tol || s > length + tol {
+                return None;
+            }
+            last = f64::max(last, s);
+        }
+        Some(Line(p0, p1))
+    }
+}
+
 impl<P: ControlPoint<f64>> ParametricCurve for Line<P> {
     type Point = P;
     type Vector = P::Diff;
@@ -24,6 +76,16 @@ impl<P: ControlPoint<f64>> ParametricCurve for Line<P> {
     fn der(&self, _: f64) -> Self::Vector { self.1 - self.0 }
     #[inline]
     fn der2(&self, _: f64) -> Self::Vector { Self::Vector::zero() }
+    #[inline]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        match n {
+            0 => self.subs(t).to_vec(),
+            1 => self.der(t),
+            _ => Self::Vector::zero(),
+        }
+    }
+    #[inline]
+    fn try_range(&self) -> Option<(f64, f64)> { Some((0.0, 1.0)) }
 }
 
 impl<P: ControlPoint<f64>> BoundedCurve for Line<P> {
@@ -14,6 +14,21 @@ pub struct Line<P>(pub P, pub P);
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct UnitCircle<P>(std::marker::PhantomData<P>);
 
+/// circle arc: the unit circle trimmed by the range of the angle and placed by the matrix
+///
+/// The whole curves coinciding with the arcs are recognized by [`CircleArc::recognize`], and the
+/// arcs in the spans of curves by [`recognize_spans`].
+pub type CircleArc = Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>;
+
+/// the analytic curve recognized in a span of a curve by [`recognize_spans`]
+#[derive(Clone, Copy, Debug)]
+pub enum AnalyticSpan {
+    /// line segment
+    Line(Line<Point3>),
+    /// circle arc
+    CircleArc(CircleArc),
+}
+
 /// unit hyperbola
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct UnitHyperbola<P>(std::marker::PhantomData<P>);
@@ -90,6 +105,8 @@ mod hyperbola;
 mod line;
 mod parabola;
 mod plane;
+mod recognition;
+pub use recognition::recognize_spans;
 mod sphere;
 
 macro_rules! always_true {
@@ -141,11 +141,21 @@ impl ParametricSurface for Plane {
     fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
     #[inline(always)]
     fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        match (m, n) {
+            (1, 0) => self.p - self.o,
+            (0, 1) => self.q - self.o,
+            _ => Vector3::zero(),
+        }
+    }
 }
 
 impl ParametricSurface3D for Plane {
     #[inline(always)]
     fn normal(&self, _: f64, _: f64) -> Vector3 { self.normal() }
+    #[inline(always)]
+    fn second_fundamental_form(&self, _: f64, _: f64) -> (f64, f64, f64) { (0.0, 0.0, 0.0) }
 }
 
 impl BoundedSurface for Plane {
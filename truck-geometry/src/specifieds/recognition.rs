@@ -0,0 +1,82 @@
+use super::*;
+
+/// Divides `curve` at the parameters `breaks` into the spans of the lines, the circle arcs and
+/// the remaining free curves within `tol`, e.g. in order to clean up the imported B-spline
+/// curves whose knot spans are partly lines or arcs.
+///
+/// The spans start at `breaks[0]`, end at the last break, and are separated at some of the
+/// `breaks`, which should be sorted in the ascending order, e.g. the knots of B-spline curves.
+/// From each break, the longest span recognized by [`Line::recognize`] or
+/// [`CircleArc::recognize`] is taken, so the recognitions are tried `O(breaks.len()^2)` times in
+/// the worst case. The adjacent free spans are unified and returned with `None`.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+///
+/// // the segment from (-1, 0, 0) to the origin, followed by the quarter circle to (1, 1, 0)
+/// // and the parabola to (2, 3, 0)
+/// let w = f64::sqrt(0.5);
+/// let ctrl_pts = vec![
+///     Vector4::new(-1.0, 0.0, 0.0, 1.0),
+///     Vector4::new(-0.5, 0.0, 0.0, 1.0),
+///     Vector4::new(0.0, 0.0, 0.0, 1.0),
+///     Vector4::new(w, 0.0, 0.0, w),
+///     Vector4::new(1.0, 1.0, 0.0, 1.0),
+///     Vector4::new(2.0, 2.0, 0.0, 1.0),
+///     Vector4::new(2.0, 3.0, 0.0, 1.0),
+/// ];
+/// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0]);
+/// let curve = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+///
+/// let spans = recognize_spans(&curve, &[0.0, 1.0, 2.0, 3.0], 1.0e-6);
+/// assert_eq!(spans.len(), 3);
+/// let (range, line) = &spans[0];
+/// assert_eq!(*range, (0.0, 1.0));
+/// assert!(matches!(line, Some(AnalyticSpan::Line(line)) if line.1.near(&Point3::origin())));
+/// let (range, arc) = &spans[1];
+/// assert_eq!(*range, (1.0, 2.0));
+/// match arc {
+///     Some(AnalyticSpan::CircleArc(arc)) => {
+///         assert_near!(arc.parameter_range().1, std::f64::consts::PI / 2.0);
+///         assert_near!(arc.back(), Point3::new(1.0, 1.0, 0.0));
+///     }
+///     _ => panic!("the arc is not recognized: {arc:?}"),
+/// }
+/// assert_eq!(spans[2].0, (2.0, 3.0));
+/// assert!(spans[2].1.is_none());
+/// ```
+pub fn recognize_spans<C>(
+    curve: &C,
+    breaks: &[f64],
+    tol: f64,
+) -> Vec<((f64, f64), Option<AnalyticSpan>)>
+where C: ParametricCurve<Point = Point3> {
+    let recognize = |range: (f64, f64)| {
+        let span = TrimmedCurve::new(curve, range);
+        match Line::recognize(&span, tol) {
+            Some(line) => Some(AnalyticSpan::Line(line)),
+            None => CircleArc::recognize(&span, tol).map(AnalyticSpan::CircleArc),
+        }
+    };
+    let mut res = Vec::<((f64, f64), Option<AnalyticSpan>)>::new();
+    let mut i = 0;
+    while i + 1 < breaks.len() {
+        let longest = (i + 1..breaks.len())
+            .rev()
+            .find_map(|j| Some((j, recognize((breaks[i], breaks[j]))?)));
+        match longest {
+            Some((j, span)) => {
+                res.push(((breaks[i], breaks[j]), Some(span)));
+                i = j;
+            }
+            None => {
+                match res.last_mut() {
+                    Some(((_, t1), None)) => *t1 = breaks[i + 1],
+                    _ => res.push(((breaks[i], breaks[i + 1]), None)),
+                }
+                i += 1;
+            }
+        }
+    }
+    res
+}
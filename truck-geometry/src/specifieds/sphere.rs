@@ -44,8 +44,26 @@ impl ParametricSurface for Sphere {
     fn vvder(&self, u: f64, v: f64) -> Vector3 {
         -self.radius * f64::sin(u) * Vector3::new(f64::cos(v), f64::sin(v), 0.0)
     }
+    /// The `k`th order derivations of sine and cosine are the shifts by `k` quarter periods.
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        if m + n == 0 {
+            return Vector3::zero();
+        }
+        let (sin_u, cos_u) = f64::sin_cos(u + m as f64 * PI / 2.0);
+        let (sin_v, cos_v) = f64::sin_cos(v + n as f64 * PI / 2.0);
+        let z = match n {
+            0 => cos_u,
+            _ => 0.0,
+        };
+        self.radius * Vector3::new(sin_u * cos_v, sin_u * sin_v, z)
+    }
     #[inline(always)]
     fn v_period(&self) -> Option<f64> { Some(2.0 * PI) }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+        (Some((0.0, PI)), Some((0.0, 2.0 * PI)))
+    }
 }
 
 impl ParametricSurface3D for Sphere {
@@ -57,6 +75,15 @@ impl ParametricSurface3D for Sphere {
             f64::cos(u),
         )
     }
+    #[inline(always)]
+    fn first_fundamental_form(&self, u: f64, _: f64) -> (f64, f64, f64) {
+        let r2 = self.radius * self.radius;
+        (r2, 0.0, r2 * f64::sin(u) * f64::sin(u))
+    }
+    #[inline(always)]
+    fn second_fundamental_form(&self, u: f64, _: f64) -> (f64, f64, f64) {
+        (-self.radius, 0.0, -self.radius * f64::sin(u) * f64::sin(u))
+    }
 }
 
 #[test]
@@ -76,6 +103,57 @@ fn sphere_derivation_test() {
     }
 }
 
+#[test]
+fn sphere_fundamental_forms_test() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 4.56);
+    const N: usize = 100;
+    for i in 1..N {
+        for j in 0..N {
+            let u = PI * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            let (uder, vder) = (sphere.uder(u, v), sphere.vder(u, v));
+            let (e, f, g) = sphere.first_fundamental_form(u, v);
+            assert_near!(e, uder.dot(uder));
+            assert_near!(f, uder.dot(vder));
+            assert_near!(g, vder.dot(vder));
+            let normal = sphere.normal(u, v);
+            let (l, m, n) = sphere.second_fundamental_form(u, v);
+            assert_near!(l, sphere.uuder(u, v).dot(normal));
+            assert_near!(m, sphere.uvder(u, v).dot(normal));
+            assert_near!(n, sphere.vvder(u, v).dot(normal));
+            // the principal curvatures are -1 / radius in all directions.
+            let shape = sphere.shape_operator(u, v).unwrap();
+            assert_near!(shape, Matrix2::identity() * (-1.0 / 4.56));
+        }
+    }
+}
+
+#[test]
+fn sphere_der_mn_test() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 4.56);
+    const N: usize = 100;
+    let h = 1.0e-4;
+    for i in 0..N {
+        for j in 0..N {
+            let u = PI * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            assert_near!(sphere.der_mn(0, 0, u, v), Vector3::zero());
+            assert_near!(sphere.der_mn(1, 0, u, v), sphere.uder(u, v));
+            assert_near!(sphere.der_mn(0, 1, u, v), sphere.vder(u, v));
+            assert_near!(sphere.der_mn(2, 0, u, v), sphere.uuder(u, v));
+            assert_near!(sphere.der_mn(1, 1, u, v), sphere.uvder(u, v));
+            assert_near!(sphere.der_mn(0, 2, u, v), sphere.vvder(u, v));
+            // the higher order derivations by the central differences
+            let der = (sphere.uuder(u + h, v) - sphere.uuder(u - h, v)) / (2.0 * h);
+            assert_near!(sphere.der_mn(3, 0, u, v), der);
+            let der = (sphere.uvder(u + h, v) - sphere.uvder(u - h, v)) / (2.0 * h);
+            assert_near!(sphere.der_mn(2, 1, u, v), der);
+            let der = (sphere.vvder(u, v + h) - sphere.vvder(u, v - h)) / (2.0 * h);
+            assert_near!(sphere.der_mn(0, 3, u, v), der);
+        }
+    }
+}
+
 impl BoundedSurface for Sphere {
     #[inline(always)]
     fn parameter_range(&self) -> ((f64, f64), (f64, f64)) { ((0.0, PI), (0.0, 2.0 * PI)) }
@@ -22,12 +22,30 @@ where
 }
 
 /// Searches the nearest parameter by Newton's method.
+#[inline(always)]
 pub fn search_nearest_parameter<C>(
     curve: &C,
     point: C::Point,
-    mut hint: f64,
+    hint: f64,
     trials: usize,
 ) -> Option<f64>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    damped_newton(curve, point, hint, trials, 1.0).0
+}
+
+/// Newton's method with the damping factor.
+/// Returns the solution, the number of the iterations and the last residual.
+fn damped_newton<C>(
+    curve: &C,
+    point: C::Point,
+    mut hint: f64,
+    trials: usize,
+    damping: f64,
+) -> (Option<f64>, usize, f64)
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
@@ -35,7 +53,8 @@ where
 {
     #[cfg(all(test, debug_assertions))]
     let mut log = Vec::new();
-    for _ in 0..=trials {
+    let mut residual = f64::INFINITY;
+    for iteration in 0..=trials {
         #[cfg(all(test, debug_assertions))]
         log.push(hint);
         let pt = curve.subs(hint);
@@ -44,15 +63,145 @@ where
         let f = der.dot(pt - point);
         let fprime = der2.dot(pt - point) + der.magnitude2();
         let dermag = f64::min(der.magnitude(), 1.0);
+        residual = f64::abs(f);
         if f64::abs(f) < TOLERANCE * dermag || fprime.so_small() {
-            return Some(hint);
+            trace_newton_iteration!(iteration);
+            return (Some(hint), iteration, residual);
         } else {
-            hint -= f / fprime;
+            hint -= damping * f / fprime;
         }
     }
     #[cfg(all(test, debug_assertions))]
     newton_log_error!(log);
-    None
+    trace_newton_failure!(trials);
+    (None, trials + 1, residual)
+}
+
+/// Bisection method on the bracket `(a, b)` of the derivative of the distance.
+/// Returns the solution, the number of the iterations and the last residual.
+fn bisection<C>(curve: &C, point: C::Point, (mut a, mut b): (f64, f64)) -> (f64, usize, f64)
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let mut res = (a, 0, f64::INFINITY);
+    // The bracket is narrowed to the precision of `f64` in this number of iterations.
+    for iteration in 1..=BISECTION_TRIALS {
+        let t = (a + b) / 2.0;
+        let der = curve.der(t);
+        let f = der.dot(curve.subs(t) - point);
+        res = (t, iteration, f64::abs(f));
+        if f64::abs(f) < TOLERANCE * f64::min(der.magnitude(), 1.0) || t == a || t == b {
+            break;
+        }
+        match f < 0.0 {
+            true => a = t,
+            false => b = t,
+        }
+    }
+    res
+}
+
+/// Searches the nearest parameter by the solver configured by `config`.
+///
+/// First, Newton's method damped by `config.damping` starts from `hint`. If it does not
+/// converge, it restarts from the seeds in `range`, and the nearest one of the solutions is
+/// adopted. If Newton's method fails from all seeds and `config.bisection` is true, the
+/// parameters at which the derivative of the distance changes from negative to positive are
+/// bracketed by the seeds, and refined by the bisection method.
+/// # Examples
+/// ```
+/// use truck_geotrait::{algo::*, *};
+/// use truck_base::cgmath64::*;
+///
+/// // the parabola (t, t^2)
+/// #[derive(Clone, Debug)]
+/// struct Parabola;
+/// impl ParametricCurve for Parabola {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, t * t) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(1.0, 2.0 * t) }
+///     fn der2(&self, _: f64) -> Vector2 { Vector2::new(0.0, 2.0) }
+/// }
+///
+/// let point = Point2::new(0.5, 0.25);
+/// // Newton's method cannot converge without any iterations.
+/// let config = SolverConfig {
+///     max_iterations: 0,
+///     damping: 1.0,
+///     seeds: 4,
+///     bisection: true,
+/// };
+/// let report = curve::search_nearest_parameter_with_config(
+///     &Parabola,
+///     point,
+///     -1.0,
+///     (-1.0, 2.0),
+///     config,
+/// );
+/// assert!(f64::abs(report.parameter.unwrap() - 0.5) < 1.0e-6);
+/// assert!(report.bisection);
+/// assert_eq!(report.starts, 5);
+/// ```
+pub fn search_nearest_parameter_with_config<C>(
+    curve: &C,
+    point: C::Point,
+    hint: f64,
+    (t0, t1): (f64, f64),
+    config: SolverConfig,
+) -> SolverReport<f64>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let mut report = SolverReport::new();
+    let distance2 = |t: f64| (curve.subs(t) - point).magnitude2();
+    let nearest = |x: &(f64, f64), y: &(f64, f64)| distance2(x.0).total_cmp(&distance2(y.0));
+    let newton = |hint: f64, report: &mut SolverReport<f64>| {
+        let (res, iterations, residual) =
+            damped_newton(curve, point, hint, config.max_iterations, config.damping);
+        report.iterations += iterations;
+        report.starts += 1;
+        report.residual = f64::min(report.residual, residual);
+        res.map(|t| (t, residual))
+    };
+    let solution = newton(hint, &mut report).or_else(|| {
+        (0..config.seeds)
+            .map(|i| t0 + (t1 - t0) * (i as f64 + 0.5) / config.seeds as f64)
+            .filter_map(|t| newton(t, &mut report))
+            .min_by(nearest)
+    });
+    let solution = match (solution, config.bisection) {
+        (None, true) => {
+            let division = usize::max(config.seeds, 1);
+            let samples = (0..=division)
+                .map(|i| {
+                    let t = t0 + (t1 - t0) * i as f64 / division as f64;
+                    (t, curve.der(t).dot(curve.subs(t) - point))
+                })
+                .collect::<Vec<_>>();
+            let solution = samples
+                .windows(2)
+                .filter(|x| x[0].1 <= 0.0 && 0.0 <= x[1].1)
+                .map(|x| {
+                    let (t, iterations, residual) = bisection(curve, point, (x[0].0, x[1].0));
+                    report.iterations += iterations;
+                    (t, residual)
+                })
+                .min_by(nearest);
+            report.bisection = solution.is_some();
+            solution
+        }
+        (solution, _) => solution,
+    };
+    if let Some((t, residual)) = solution {
+        report.parameter = Some(t);
+        report.residual = residual;
+    }
+    report
 }
 
 /// Searches the parameter by Newton's method.
@@ -69,6 +218,29 @@ where
     })
 }
 
+/// Searches the parameter by the solver configured by `config`.
+///
+/// The solution of [`search_nearest_parameter_with_config`] is rejected if the curve does not
+/// pass `point`, and the diagnostics are reported in both cases.
+pub fn search_parameter_with_config<C>(
+    curve: &C,
+    point: C::Point,
+    hint: f64,
+    range: (f64, f64),
+    config: SolverConfig,
+) -> SolverReport<f64>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let mut report = search_nearest_parameter_with_config(curve, point, hint, range, config);
+    report.parameter = report
+        .parameter
+        .filter(|t| point.to_vec().near(&curve.subs(*t).to_vec()));
+    report
+}
+
 /// Creates the curve division
 ///
 /// # Panics
@@ -128,3 +300,157 @@ where
         (params, pts)
     }
 }
+
+/// The maximum depth of the bisections in [`chordal_division`].
+const CHORDAL_DIVISION_DEPTH: usize = 16;
+
+/// Divides the curve into the segments whose chordal deviations from the curve are less than `tol`.
+///
+/// Each segment is bisected until the distances between the chord and the points of the curve
+/// at the quarter, the middle and the three quarters of the parameter range are less than
+/// `tol`. Unlike [`parameter_division`], the deviation is measured from the chord itself, not
+/// from the point on the chord at the same parameter, so that the division does not depend on
+/// the speed of the parametrization.
+/// # Examples
+/// ```
+/// use truck_geotrait::{algo::*, *};
+/// use truck_base::cgmath64::*;
+///
+/// // the parabola (t, t^2)
+/// #[derive(Clone, Debug)]
+/// struct Parabola;
+/// impl ParametricCurve for Parabola {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, t * t) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(1.0, 2.0 * t) }
+///     fn der2(&self, _: f64) -> Vector2 { Vector2::new(0.0, 2.0) }
+/// }
+///
+/// let (params, pts) = curve::chordal_division(&Parabola, (-1.0, 1.0), 0.01);
+/// assert_eq!(params.len(), pts.len());
+/// assert_eq!((params[0], params[params.len() - 1]), (-1.0, 1.0));
+/// params.windows(2).for_each(|a| {
+///     let (p, q) = (Parabola.subs(a[0]), Parabola.subs(a[1]));
+///     let vec = Parabola.subs((a[0] + a[1]) / 2.0) - p;
+///     let dir = (q - p).normalize();
+///     assert!((vec - dir * vec.dot(dir)).magnitude() < 0.01);
+/// });
+/// ```
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn chordal_division<C>(curve: &C, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<C::Point>)
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    nonpositive_tolerance!(tol);
+    let ends = (curve.subs(range.0), curve.subs(range.1));
+    let (mut params, mut pts) = (vec![range.0], vec![ends.0]);
+    sub_chordal_division(
+        curve,
+        range,
+        ends,
+        tol,
+        CHORDAL_DIVISION_DEPTH,
+        (&mut params, &mut pts),
+    );
+    (params, pts)
+}
+
+/// Returns the distance between `pt` and the segment from `ends.0` to `ends.1`.
+fn chord_distance<P>(pt: P, ends: (P, P)) -> f64
+where
+    P: EuclideanSpace<Scalar = f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let (chord, vec) = (ends.1 - ends.0, pt - ends.0);
+    let len2 = chord.magnitude2();
+    let t = match len2 < TOLERANCE2 {
+        true => 0.0,
+        false => f64::clamp(vec.dot(chord) / len2, 0.0, 1.0),
+    };
+    (vec - chord * t).magnitude()
+}
+
+fn sub_chordal_division<C>(
+    curve: &C,
+    (t0, t1): (f64, f64),
+    ends: (C::Point, C::Point),
+    tol: f64,
+    depth: usize,
+    (params, pts): (&mut Vec<f64>, &mut Vec<C::Point>),
+) where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    let samples = [0.25, 0.5, 0.75].map(|s| curve.subs(t0 + (t1 - t0) * s));
+    let flat = samples.iter().all(|pt| chord_distance(*pt, ends) < tol);
+    if flat || depth == 0 {
+        params.push(t1);
+        pts.push(ends.1);
+    } else {
+        let t = (t0 + t1) / 2.0;
+        let (mid, next) = (samples[1], depth - 1);
+        sub_chordal_division(curve, (t0, t), (ends.0, mid), tol, next, (params, pts));
+        sub_chordal_division(curve, (t, t1), (mid, ends.1), tol, next, (params, pts));
+    }
+}
+
+const ARC_LENGTH_DEPTH: usize = 16;
+
+/// Returns the length of `curve` on `range`, integrating the speed by the adaptive
+/// Gauss-Legendre quadrature until the difference from the bisected intervals is less than
+/// `tol`.
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+/// #[derive(Clone, Copy, Debug)]
+/// struct Circle;
+/// impl ParametricCurve for Circle {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(f64::cos(t), f64::sin(t)) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(-f64::sin(t), f64::cos(t)) }
+///     fn der2(&self, t: f64) -> Vector2 { Vector2::new(-f64::cos(t), -f64::sin(t)) }
+/// }
+///
+/// let length = algo::curve::arc_length(&Circle, (0.0, 1.5), 1.0e-10);
+/// assert!(f64::abs(length - 1.5) < 1.0e-10);
+/// ```
+/// # Panics
+///
+/// `tol` must be positive.
+pub fn arc_length<C>(curve: &C, range: (f64, f64), tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    assert!(tol > 0.0, "tolerance must be positive");
+    let speed = |t: f64| curve.der(t).magnitude();
+    let whole = super::gauss_legendre(speed, range, 1);
+    sub_arc_length(&speed, range, whole, tol, ARC_LENGTH_DEPTH)
+}
+
+fn sub_arc_length(
+    speed: &impl Fn(f64) -> f64,
+    (t0, t1): (f64, f64),
+    whole: f64,
+    tol: f64,
+    depth: usize,
+) -> f64 {
+    let t = (t0 + t1) / 2.0;
+    let left = super::gauss_legendre(speed, (t0, t), 1);
+    let right = super::gauss_legendre(speed, (t, t1), 1);
+    match depth == 0 || f64::abs(left + right - whole) < tol {
+        true => left + right,
+        false => {
+            let next = depth - 1;
+            sub_arc_length(speed, (t0, t), left, tol / 2.0, next)
+                + sub_arc_length(speed, (t, t1), right, tol / 2.0, next)
+        }
+    }
+}
@@ -1,16 +1,198 @@
 #![allow(clippy::many_single_char_names)]
 
 use crate::traits::*;
+use std::ops::{Div, Mul, Sub};
 use truck_base::{cgmath64::*, hash::HashGen, tolerance::*};
 
+const BISECTION_TRIALS: usize = 64;
+
 #[cfg(all(test, debug_assertions))]
 macro_rules! newton_log_error {
     ($log: expr) => {
-        eprintln!("Newton method is not converges");
+        eprintln!("Newton method does not converge");
         $log.into_iter().for_each(|t| eprintln!("{:?}", t));
     };
 }
 
+/// Records the number of iterations of Newton method if the feature `tracing` is enabled.
+macro_rules! trace_newton_iteration {
+    ($iteration: expr) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iteration = $iteration, "Newton method converges");
+        #[cfg(not(feature = "tracing"))]
+        let _ = $iteration;
+    };
+}
+
+/// Records the failure of Newton method if the feature `tracing` is enabled.
+macro_rules! trace_newton_failure {
+    ($trials: expr) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(trials = $trials, "Newton method does not converge");
+    };
+}
+
+/// The configuration of the solvers of the nearest parameters, e.g.
+/// [`curve::search_nearest_parameter_with_config`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolverConfig {
+    /// the maximum number of the iterations of Newton method from each seed
+    pub max_iterations: usize,
+    /// the damping factor multiplied to each step of Newton method, in `(0, 1]`.
+    /// `1.0` means the pure Newton method.
+    pub damping: f64,
+    /// the number of the seeds placed at regular intervals in the parameter range, in each
+    /// direction. If Newton method from the hint does not converge, it restarts from the seeds.
+    pub seeds: usize,
+    /// If true, the sign changes of the derivatives of the distance are bracketed by the
+    /// seeds, and the bisection method is applied if Newton method fails from all seeds.
+    /// The solvers for surfaces apply it coordinate-wise from the nearest point on the grid.
+    pub bisection: bool,
+}
+
+impl Default for SolverConfig {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            max_iterations: 100,
+            damping: 1.0,
+            seeds: 8,
+            bisection: true,
+        }
+    }
+}
+
+impl SolverConfig {
+    /// The pure Newton method from the hint, the same as
+    /// [`curve::search_nearest_parameter`] with `trials = max_iterations`.
+    #[inline(always)]
+    pub const fn newton(max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            damping: 1.0,
+            seeds: 0,
+            bisection: false,
+        }
+    }
+}
+
+/// The solution and the convergence diagnostics of the solvers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolverReport<T> {
+    /// the solution, `None` if the solver does not converge
+    pub parameter: Option<T>,
+    /// the total number of the iterations of Newton method and the bisection method
+    pub iterations: usize,
+    /// the number of the starts of Newton method, including the start from the hint
+    pub starts: usize,
+    /// whether the solution is found by the bisection method
+    pub bisection: bool,
+    /// the norm of the gradient of the half squared distance at the solution, or the minimum
+    /// one at the last iterates if the solver does not converge
+    pub residual: f64,
+}
+
+impl<T> SolverReport<T> {
+    #[inline(always)]
+    fn new() -> Self {
+        Self {
+            parameter: None,
+            iterations: 0,
+            starts: 0,
+            bisection: false,
+            residual: f64::INFINITY,
+        }
+    }
+}
+
+/// the nodes in `[0, 1]` and the weights of the five-point Gauss-Legendre quadrature
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.04691007703066802, 0.11846344252809454),
+    (0.23076534494715845, 0.23931433524968324),
+    (0.5, 0.28444444444444444),
+    (0.7692346550528415, 0.23931433524968324),
+    (0.953089922969332, 0.11846344252809454),
+];
+
+/// Returns the nodes and the weights of the composite five-point Gauss-Legendre quadrature on
+/// `division` equal intervals of `range`.
+/// # Examples
+/// ```
+/// use truck_geotrait::algo;
+/// let nodes: Vec<(f64, f64)> = algo::gauss_legendre_nodes((1.0, 3.0), 2).collect();
+/// assert_eq!(nodes.len(), 10);
+/// assert!(nodes.iter().all(|(t, _)| 1.0 < *t && *t < 3.0));
+/// let sum: f64 = nodes.iter().map(|(_, w)| w).sum();
+/// assert!(f64::abs(sum - 2.0) < 1.0e-10);
+/// ```
+pub fn gauss_legendre_nodes(
+    (t0, t1): (f64, f64),
+    division: usize,
+) -> impl Iterator<Item = (f64, f64)> {
+    let h = (t1 - t0) / division as f64;
+    (0..division).flat_map(move |i| {
+        let nodes = GAUSS_LEGENDRE.iter();
+        nodes.map(move |(x, w)| (t0 + h * (i as f64 + x), w * h))
+    })
+}
+
+/// Integrates `f` on `range` by the composite five-point Gauss-Legendre quadrature on `division`
+/// equal intervals, which is exact for the polynomials of degree at most nine on each interval.
+/// # Examples
+/// ```
+/// use truck_geotrait::algo;
+/// let integral = algo::gauss_legendre(|t| t.powi(9) + t * t, (0.0, 2.0), 1);
+/// assert!(f64::abs(integral - (102.4 + 8.0 / 3.0)) < 1.0e-10);
+/// let integral = algo::gauss_legendre(f64::sin, (0.0, std::f64::consts::PI), 4);
+/// assert!(f64::abs(integral - 2.0) < 1.0e-10);
+/// ```
+pub fn gauss_legendre(mut f: impl FnMut(f64) -> f64, range: (f64, f64), division: usize) -> f64 {
+    let nodes = gauss_legendre_nodes(range, division);
+    nodes.fold(0.0, |sum, (t, w)| sum + f(t) * w)
+}
+
+/// Solves the linear equation `matrix * x = rhs` by the Gaussian elimination with the partial
+/// pivoting, e.g. the normal equations of the least squares of the control points.
+///
+/// Returns `None` if the matrix is singular, i.e. a pivot is less than `1.0e-12`.
+/// # Examples
+/// ```
+/// use truck_geotrait::algo;
+/// use truck_base::cgmath64::*;
+/// let matrix = vec![vec![0.0, 2.0], vec![1.0, 1.0]];
+/// let rhs = vec![Vector2::new(2.0, 4.0), Vector2::new(3.0, 2.0)];
+/// let x = algo::solve_linear(matrix, rhs).unwrap();
+/// assert_eq!(x, vec![Vector2::new(2.0, 0.0), Vector2::new(1.0, 2.0)]);
+///
+/// let singular = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+/// assert!(algo::solve_linear(singular, vec![1.0, 2.0]).is_none());
+/// ```
+pub fn solve_linear<V>(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<V>) -> Option<Vec<V>>
+where V: Copy + Sub<Output = V> + Mul<f64, Output = V> + Div<f64, Output = V> {
+    let len = rhs.len();
+    for k in 0..len {
+        let magnitude = |i: &usize| f64::abs(matrix[*i][k]);
+        let pivot = (k..len).max_by(|i, j| magnitude(i).total_cmp(&magnitude(j)))?;
+        if magnitude(&pivot) < 1.0e-12 {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        rhs.swap(k, pivot);
+        let (row, target) = (matrix[k].clone(), rhs[k]);
+        (k + 1..len).for_each(|i| {
+            let ratio = matrix[i][k] / row[k];
+            let line = matrix[i].iter_mut().zip(&row);
+            line.for_each(|(a, b)| *a -= ratio * b);
+            rhs[i] = rhs[i] - target * ratio;
+        });
+    }
+    (0..len).rev().for_each(|i| {
+        let sum = (i + 1..len).fold(rhs[i], |sum, j| sum - rhs[j] * matrix[i][j]);
+        rhs[i] = sum / matrix[i][i];
+    });
+    Some(rhs)
+}
+
 /// curve algorithms
 pub mod curve;
 /// surface algorithms
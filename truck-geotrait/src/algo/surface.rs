@@ -32,12 +32,30 @@ where
 }
 
 /// Searches the nearest parameter by Newton's method.
+#[inline(always)]
 pub fn search_nearest_parameter<S>(
     surface: &S,
     point: S::Point,
-    mut hint: (f64, f64),
+    hint: (f64, f64),
     trials: usize,
 ) -> Option<(f64, f64)>
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    damped_newton(surface, point, hint, trials, 1.0).0
+}
+
+/// Newton's method with the damping factor.
+/// Returns the solution, the number of the iterations and the last residual.
+fn damped_newton<S>(
+    surface: &S,
+    point: S::Point,
+    mut hint: (f64, f64),
+    trials: usize,
+    damping: f64,
+) -> (Option<(f64, f64)>, usize, f64)
 where
     S: ParametricSurface,
     S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
@@ -45,7 +63,8 @@ where
 {
     #[cfg(all(test, debug_assertions))]
     let mut log = Vec::new();
-    for _ in 0..=trials {
+    let mut residual = f64::INFINITY;
+    for iteration in 0..=trials {
         #[cfg(all(test, debug_assertions))]
         log.push(hint);
         let (u0, v0) = hint;
@@ -62,15 +81,198 @@ where
         let fprime = Matrix2::new(a, c, c, b);
         let dermag2 = f64::min(1.0, ud.magnitude2());
         let dermag2 = f64::min(dermag2, vd.magnitude2());
+        residual = f.magnitude();
         if f.magnitude2() < TOLERANCE2 * dermag2 || fprime.determinant().so_small() {
-            return Some(hint);
+            trace_newton_iteration!(iteration);
+            return (Some(hint), iteration, residual);
         } else {
-            hint = (Vector2::from(hint) - fprime.invert()? * f).into();
+            let inv = match fprime.invert() {
+                Some(inv) => inv,
+                None => return (None, iteration + 1, residual),
+            };
+            hint = (Vector2::from(hint) - inv * f * damping).into();
         }
     }
     #[cfg(all(test, debug_assertions))]
     newton_log_error!(log);
-    None
+    trace_newton_failure!(trials);
+    (None, trials + 1, residual)
+}
+
+/// Returns the partial derivatives of the half squared distance at `(u, v)`, and the squared
+/// magnitudes of the derivatives of the surface clamped by `1.0`.
+fn gradient<S>(surface: &S, point: S::Point, (u, v): (f64, f64)) -> (Vector2, Vector2)
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let s = surface.subs(u, v) - point;
+    let (ud, vd) = (surface.uder(u, v), surface.vder(u, v));
+    let dermag2 = Vector2::new(ud.magnitude2(), vd.magnitude2()).map(|x| f64::min(x, 1.0));
+    (Vector2::new(ud.dot(s), vd.dot(s)), dermag2)
+}
+
+/// Bisection method on the bracket `(a, b)` of the partial derivative of the distance by the
+/// `axis`-th parameter, the other parameter fixed to that of `uv`.
+/// Returns the solution, the number of the iterations and the last residual.
+fn bisection<S>(
+    surface: &S,
+    point: S::Point,
+    mut uv: (f64, f64),
+    axis: usize,
+    (mut a, mut b): (f64, f64),
+) -> ((f64, f64), usize, f64)
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let mut res = (uv, 0, f64::INFINITY);
+    // The bracket is narrowed to the precision of `f64` in this number of iterations.
+    for iteration in 1..=BISECTION_TRIALS {
+        let t = (a + b) / 2.0;
+        match axis {
+            0 => uv.0 = t,
+            _ => uv.1 = t,
+        }
+        let (f, dermag2) = gradient(surface, point, uv);
+        res = (uv, iteration, f64::abs(f[axis]));
+        if f[axis] * f[axis] < TOLERANCE2 * dermag2[axis] || t == a || t == b {
+            break;
+        }
+        match f[axis] < 0.0 {
+            true => a = t,
+            false => b = t,
+        }
+    }
+    res
+}
+
+/// Coordinate-wise bracketed search from `uv`. The parameters are refined by turns by the
+/// bisection method on the brackets of the partial derivatives of the distance, sampled at
+/// `division + 1` points on the lines through `uv` in the parameter box.
+/// Returns the solution, the number of the iterations and the last residual.
+fn coordinate_bisection<S>(
+    surface: &S,
+    point: S::Point,
+    mut uv: (f64, f64),
+    ranges: [(f64, f64); 2],
+    division: usize,
+) -> (Option<(f64, f64)>, usize, f64)
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let distance2 = |(u, v): (f64, f64)| (surface.subs(u, v) - point).magnitude2();
+    let converged = |uv: (f64, f64)| {
+        let (f, dermag2) = gradient(surface, point, uv);
+        let converged = f.magnitude2() < TOLERANCE2 * f64::min(dermag2.x, dermag2.y);
+        (converged, f.magnitude())
+    };
+    let mut iterations = 0;
+    for _ in 0..BISECTION_TRIALS {
+        if converged(uv).0 {
+            break;
+        }
+        let prev = uv;
+        for (axis, (t0, t1)) in ranges.into_iter().enumerate() {
+            let samples = (0..=division)
+                .map(|i| {
+                    let t = t0 + (t1 - t0) * i as f64 / division as f64;
+                    let at = match axis {
+                        0 => (t, uv.1),
+                        _ => (uv.0, t),
+                    };
+                    (t, gradient(surface, point, at).0[axis])
+                })
+                .collect::<Vec<_>>();
+            let nearest = samples
+                .windows(2)
+                .filter(|x| x[0].1 <= 0.0 && 0.0 <= x[1].1)
+                .map(|x| {
+                    let (uv, its, _) = bisection(surface, point, uv, axis, (x[0].0, x[1].0));
+                    iterations += its;
+                    uv
+                })
+                .min_by(|x, y| distance2(*x).total_cmp(&distance2(*y)));
+            uv = nearest.unwrap_or(uv);
+        }
+        if uv == prev {
+            break;
+        }
+    }
+    let (converged, residual) = converged(uv);
+    (converged.then_some(uv), iterations, residual)
+}
+
+/// Searches the nearest parameter by the solver configured by `config`.
+///
+/// First, Newton's method damped by `config.damping` starts from `hint`. If it does not
+/// converge, it restarts from the seeds on the grid in the parameter box, and the nearest one
+/// of the solutions is adopted. If Newton's method fails from all seeds and `config.bisection`
+/// is true, the coordinate-wise bracketed search starts from the nearest point on the grid
+/// dividing the parameter box into `config.seeds` parts in each direction: the parameters are
+/// refined by turns by the bisection method on the brackets of the partial derivatives.
+pub fn search_nearest_parameter_with_config<S>(
+    surface: &S,
+    point: S::Point,
+    hint: (f64, f64),
+    ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+    config: SolverConfig,
+) -> SolverReport<(f64, f64)>
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let mut report = SolverReport::new();
+    let distance2 = |(u, v): (f64, f64)| (surface.subs(u, v) - point).magnitude2();
+    let newton = |hint: (f64, f64), report: &mut SolverReport<(f64, f64)>| {
+        let (res, iterations, residual) =
+            damped_newton(surface, point, hint, config.max_iterations, config.damping);
+        report.iterations += iterations;
+        report.starts += 1;
+        report.residual = f64::min(report.residual, residual);
+        res.map(|uv| (uv, residual))
+    };
+    let n = config.seeds;
+    let solution = newton(hint, &mut report).or_else(|| {
+        (0..n * n)
+            .map(|k| {
+                let (p, q) = ((k / n) as f64 + 0.5, (k % n) as f64 + 0.5);
+                (u0 + (u1 - u0) * p / n as f64, v0 + (v1 - v0) * q / n as f64)
+            })
+            .filter_map(|uv| newton(uv, &mut report))
+            .min_by(|x, y| distance2(x.0).total_cmp(&distance2(y.0)))
+    });
+    let solution = match (solution, config.bisection) {
+        (None, true) => {
+            let division = usize::max(n, 1);
+            let step = |i: usize| i as f64 / division as f64;
+            let solution = (0..=division)
+                .flat_map(|i| (0..=division).map(move |j| (i, j)))
+                .map(|(i, j)| (u0 + (u1 - u0) * step(i), v0 + (v1 - v0) * step(j)))
+                .min_by(|x, y| distance2(*x).total_cmp(&distance2(*y)))
+                .and_then(|uv| {
+                    let ranges = [(u0, u1), (v0, v1)];
+                    let (res, iterations, residual) =
+                        coordinate_bisection(surface, point, uv, ranges, division);
+                    report.iterations += iterations;
+                    report.residual = f64::min(report.residual, residual);
+                    res.map(|uv| (uv, residual))
+                });
+            report.bisection = solution.is_some();
+            solution
+        }
+        (solution, _) => solution,
+    };
+    if let Some((uv, residual)) = solution {
+        report.parameter = Some(uv);
+        report.residual = residual;
+    }
+    report
 }
 
 /// Searches the parameter by Newton's method.
@@ -83,7 +285,7 @@ pub fn search_parameter2d<S: ParametricSurface<Point = Point2, Vector = Vector2>
 ) -> Option<(f64, f64)> {
     #[cfg(all(test, debug_assertions))]
     let mut log = Vec::new();
-    for _ in 0..=trials {
+    for iteration in 0..=trials {
         #[cfg(all(test, debug_assertions))]
         log.push(hint);
         let (u0, v0) = hint;
@@ -93,6 +295,7 @@ pub fn search_parameter2d<S: ParametricSurface<Point = Point2, Vector = Vector2>
         let dermag2 = f64::min(0.05, uder.magnitude2());
         let dermag2 = f64::min(dermag2, vder.magnitude2());
         if pt.distance2(point) < TOLERANCE2 * dermag2 {
+            trace_newton_iteration!(iteration);
             return Some(hint);
         }
         let inv = Matrix2::from_cols(uder, vder).invert()?;
@@ -100,6 +303,7 @@ pub fn search_parameter2d<S: ParametricSurface<Point = Point2, Vector = Vector2>
     }
     #[cfg(all(test, debug_assertions))]
     newton_log_error!(log);
+    trace_newton_failure!(trials);
     None
 }
 
@@ -161,6 +365,12 @@ impl<'a, S: ParametricSurface3D> ParametricSurface for ProjectedSurface<'a, S> {
     fn uvder(&self, u: f64, v: f64) -> Vector2 { self.vector_proj(self.surface.uvder(u, v)) }
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Vector2 { self.vector_proj(self.surface.vvder(u, v)) }
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector2 {
+        self.vector_proj(self.surface.der_mn(m, n, u, v))
+    }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) { self.surface.try_range() }
 }
 
 /// Searches the parameter by Newton's method.
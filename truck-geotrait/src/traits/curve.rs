@@ -18,9 +18,29 @@ pub trait ParametricCurve: Clone {
     fn der(&self, t: f64) -> Self::Vector;
     /// Returns the 2nd-order derivation.
     fn der2(&self, t: f64) -> Self::Vector;
+    /// Returns the `n`th-order derivation.
+    ///
+    /// In the default implementation, only the first and second orders are given by `der` and
+    /// `der2`, respectively.
+    /// # Panics
+    /// The default implementation panics if `n` is neither `1` nor `2`.
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        match n {
+            1 => self.der(t),
+            2 => self.der2(t),
+            _ => panic!("the derivation of the order {n} is not implemented."),
+        }
+    }
     /// `None` in default implementation; `Some(period)` if periodic.
     #[inline(always)]
     fn period(&self) -> Option<f64> { None }
+    /// The range of the parameter if the curve specifies it.
+    ///
+    /// `None` in default implementation. The bounded curves return `Some(parameter_range)`,
+    /// so that the decorators of any curves, e.g. the extruded surfaces, can find the range.
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { None }
 }
 
 /// bounded parametric curves
@@ -77,7 +97,11 @@ impl<'a, C: ParametricCurve> ParametricCurve for &'a C {
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { (*self).der2(t) }
     #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector { (*self).der_n(n, t) }
+    #[inline(always)]
     fn period(&self) -> Option<f64> { (*self).period() }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { (*self).try_range() }
 }
 
 impl<'a, C: BoundedCurve> BoundedCurve for &'a C {
@@ -98,7 +122,11 @@ impl<C: ParametricCurve> ParametricCurve for Box<C> {
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { (**self).der2(t) }
     #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector { (**self).der_n(n, t) }
+    #[inline(always)]
     fn period(&self) -> Option<f64> { (**self).period() }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { (**self).try_range() }
 }
 
 impl<C: BoundedCurve> BoundedCurve for Box<C> {
@@ -169,6 +197,16 @@ pub trait ParameterTransform: BoundedCurve {
         let b = -t0 * a;
         self.parameter_transform(a, b)
     }
+    /// Makes the parameter range `range` by the affine transformation.
+    /// # Panics
+    /// Panic occurs if `range.0 >= range.1`.
+    fn parameter_transform_to_range(&mut self, range: (f64, f64)) -> &mut Self {
+        assert!(range.0 < range.1, "The range {range:?} is not increasing.");
+        let (t0, t1) = self.parameter_range();
+        let a = (range.1 - range.0) / (t1 - t0);
+        let b = range.0 - t0 * a;
+        self.parameter_transform(a, b)
+    }
 }
 
 impl<C: ParameterTransform> ParameterTransform for Box<C> {
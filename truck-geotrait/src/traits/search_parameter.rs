@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use truck_base::tolerance::*;
+
 /// Dimension for search nearest parameter
 pub trait SPDimension {
     /// dimension
@@ -8,6 +11,39 @@ pub trait SPDimension {
     type Hint;
 }
 
+/// Dimension with the domains of parameters, for searching all the solutions in a domain
+pub trait SPDomain: SPDimension {
+    /// domain type, curve => (f64, f64), surface => ((f64, f64), (f64, f64))
+    type Domain: Copy;
+    /// Returns the hint of searching from `parameter`.
+    fn parameter_hint(parameter: &Self::Parameter) -> Self::Hint;
+    /// Returns the hint of searching in `domain`.
+    fn domain_hint(domain: Self::Domain) -> Self::Hint;
+    /// Returns the parameters sampled on the grid of `domain`, the initial hints for searching
+    /// all the solutions.
+    fn sample_parameters(domain: Self::Domain) -> Vec<Self::Parameter>;
+    /// Returns whether `parameter` is in `domain` within `TOLERANCE`.
+    fn domain_contains(domain: Self::Domain, parameter: &Self::Parameter) -> bool;
+    /// Returns whether the two parameters are near within `TOLERANCE`.
+    fn near_parameters(parameter0: &Self::Parameter, parameter1: &Self::Parameter) -> bool;
+    /// Compares the two parameters in the lexicographic order.
+    fn cmp_parameters(parameter0: &Self::Parameter, parameter1: &Self::Parameter) -> Ordering;
+}
+
+// the numbers of the divisions of the domains for each direction in searching all the solutions
+const SAMPLE_DIVISION_1D: usize = 16;
+const SAMPLE_DIVISION_2D: usize = 8;
+
+#[inline(always)]
+fn sample_range((t0, t1): (f64, f64), division: usize) -> impl Iterator<Item = f64> {
+    (0..=division).map(move |i| t0 + (t1 - t0) * i as f64 / division as f64)
+}
+
+#[inline(always)]
+fn range_contains((t0, t1): (f64, f64), t: f64) -> bool {
+    t0 - TOLERANCE <= t && t <= t1 + TOLERANCE
+}
+
 /// curve geometry
 #[derive(Clone, Copy, Debug)]
 pub enum D1 {}
@@ -18,7 +54,26 @@ impl SPDimension for D1 {
     type Hint = SPHint1D;
 }
 
-/// curve geometry
+impl SPDomain for D1 {
+    type Domain = (f64, f64);
+    #[inline(always)]
+    fn parameter_hint(t: &f64) -> SPHint1D { (*t).into() }
+    #[inline(always)]
+    fn domain_hint(domain: (f64, f64)) -> SPHint1D { domain.into() }
+    fn sample_parameters(domain: (f64, f64)) -> Vec<f64> {
+        sample_range(domain, SAMPLE_DIVISION_1D).collect()
+    }
+    #[inline(always)]
+    fn domain_contains(domain: (f64, f64), t: &f64) -> bool { range_contains(domain, *t) }
+    #[inline(always)]
+    fn near_parameters(t0: &f64, t1: &f64) -> bool { t0.near(t1) }
+    #[inline(always)]
+    fn cmp_parameters(t0: &f64, t1: &f64) -> Ordering {
+        t0.partial_cmp(t1).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// surface geometry
 #[derive(Clone, Copy, Debug)]
 pub enum D2 {}
 
@@ -28,6 +83,31 @@ impl SPDimension for D2 {
     type Hint = SPHint2D;
 }
 
+impl SPDomain for D2 {
+    type Domain = ((f64, f64), (f64, f64));
+    #[inline(always)]
+    fn parameter_hint(uv: &(f64, f64)) -> SPHint2D { (*uv).into() }
+    #[inline(always)]
+    fn domain_hint(domain: Self::Domain) -> SPHint2D { domain.into() }
+    fn sample_parameters((urange, vrange): Self::Domain) -> Vec<(f64, f64)> {
+        sample_range(urange, SAMPLE_DIVISION_2D)
+            .flat_map(|u| sample_range(vrange, SAMPLE_DIVISION_2D).map(move |v| (u, v)))
+            .collect()
+    }
+    #[inline(always)]
+    fn domain_contains((urange, vrange): Self::Domain, (u, v): &(f64, f64)) -> bool {
+        range_contains(urange, *u) && range_contains(vrange, *v)
+    }
+    #[inline(always)]
+    fn near_parameters((u0, v0): &(f64, f64), (u1, v1): &(f64, f64)) -> bool {
+        u0.near(u1) && v0.near(v1)
+    }
+    #[inline(always)]
+    fn cmp_parameters(uv0: &(f64, f64), uv1: &(f64, f64)) -> Ordering {
+        uv0.partial_cmp(uv1).unwrap_or(Ordering::Equal)
+    }
+}
+
 /// hint for searching parameter for curve
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SPHint1D {
@@ -97,6 +177,59 @@ pub trait SearchParameter<Dim: SPDimension> {
         hint: H,
         trials: usize,
     ) -> Option<Dim::Parameter>;
+
+    /// Search parameter `t` in `domain` such that `self.subs(t)` is near point.
+    /// Returns `None` if could not find such parameter in `domain`.
+    ///
+    /// The default implementation searches with the hint of `domain` at first, and falls back
+    /// to [`SearchParameter::search_parameters`] if the solution is out of `domain`.
+    fn search_parameter_in_domain(
+        &self,
+        point: Self::Point,
+        domain: Dim::Domain,
+        trials: usize,
+    ) -> Option<Dim::Parameter>
+    where
+        Dim: SPDomain,
+        Self::Point: Clone, {
+        let t = self.search_parameter(point.clone(), Dim::domain_hint(domain), trials);
+        match t.filter(|t| Dim::domain_contains(domain, t)) {
+            Some(t) => Some(t),
+            None => self.search_parameters(point, domain, trials).into_iter().next(),
+        }
+    }
+
+    /// Search all the parameters `t` in `domain` such that `self.subs(t)` is near point, e.g. the
+    /// two parameters of the point at which a curve intersects itself. The solutions are sorted
+    /// in the ascending order, and the ones near to each other within `TOLERANCE` are unified.
+    ///
+    /// The default implementation starts searching from the hint of `domain` and from the
+    /// parameters on the grid of `domain`, so the solutions nearer to each other than the grid
+    /// interval may be missed.
+    fn search_parameters(
+        &self,
+        point: Self::Point,
+        domain: Dim::Domain,
+        trials: usize,
+    ) -> Vec<Dim::Parameter>
+    where
+        Dim: SPDomain,
+        Self::Point: Clone, {
+        let samples = Dim::sample_parameters(domain);
+        let hints = samples.iter().map(Dim::parameter_hint);
+        let hints = std::iter::once(Dim::domain_hint(domain)).chain(hints);
+        let mut res = Vec::<Dim::Parameter>::new();
+        hints
+            .filter_map(|hint| self.search_parameter(point.clone(), hint, trials))
+            .filter(|t| Dim::domain_contains(domain, t))
+            .for_each(|t| {
+                if !res.iter().any(|s| Dim::near_parameters(s, &t)) {
+                    res.push(t);
+                }
+            });
+        res.sort_by(Dim::cmp_parameters);
+        res
+    }
 }
 
 impl<'a, Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for &'a T {
@@ -109,6 +242,28 @@ impl<'a, Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for &'a
     ) -> Option<Dim::Parameter> {
         T::search_parameter(*self, point, hint, trials)
     }
+    fn search_parameter_in_domain(
+        &self,
+        point: Self::Point,
+        domain: Dim::Domain,
+        trials: usize,
+    ) -> Option<Dim::Parameter>
+    where
+        Dim: SPDomain,
+        Self::Point: Clone, {
+        T::search_parameter_in_domain(*self, point, domain, trials)
+    }
+    fn search_parameters(
+        &self,
+        point: Self::Point,
+        domain: Dim::Domain,
+        trials: usize,
+    ) -> Vec<Dim::Parameter>
+    where
+        Dim: SPDomain,
+        Self::Point: Clone, {
+        T::search_parameters(*self, point, domain, trials)
+    }
 }
 
 impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for Box<T> {
@@ -121,6 +276,28 @@ impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for Box<T>
     ) -> Option<Dim::Parameter> {
         T::search_parameter(&**self, point, hint, trials)
     }
+    fn search_parameter_in_domain(
+        &self,
+        point: Self::Point,
+        domain: Dim::Domain,
+        trials: usize,
+    ) -> Option<Dim::Parameter>
+    where
+        Dim: SPDomain,
+        Self::Point: Clone, {
+        T::search_parameter_in_domain(&**self, point, domain, trials)
+    }
+    fn search_parameters(
+        &self,
+        point: Self::Point,
+        domain: Dim::Domain,
+        trials: usize,
+    ) -> Vec<Dim::Parameter>
+    where
+        Dim: SPDomain,
+        Self::Point: Clone, {
+        T::search_parameters(&**self, point, domain, trials)
+    }
 }
 
 /// Search parameter `t` such that `self.subs(t)` is nearest point.
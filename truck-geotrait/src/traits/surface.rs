@@ -18,12 +18,70 @@ pub trait ParametricSurface: Clone {
     fn uvder(&self, u: f64, v: f64) -> Self::Vector;
     /// Returns the 2nd-order derivation by `v`.
     fn vvder(&self, u: f64, v: f64) -> Self::Vector;
+    /// Returns the derivation of `m`th order by `u` and `n`th order by `v`. `m + n` must be
+    /// positive.
+    ///
+    /// The default implementation gives the derivations up to the 2nd order by the above
+    /// methods, and the surfaces with the higher order derivations override it.
+    /// # Panics
+    /// The default implementation panics if `m + n` is zero or more than two.
+    /// # Examples
+    /// ```
+    /// use truck_base::cgmath64::*;
+    /// use truck_geotrait::*;
+    ///
+    /// // the surface (u, v, u^3 v)
+    /// #[derive(Clone, Debug)]
+    /// struct Cubic;
+    /// impl ParametricSurface for Cubic {
+    ///     type Point = Point3;
+    ///     type Vector = Vector3;
+    ///     fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, u * u * u * v) }
+    ///     fn uder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(1.0, 0.0, 3.0 * u * u * v) }
+    ///     fn vder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(0.0, 1.0, u * u * u) }
+    ///     fn uuder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(0.0, 0.0, 6.0 * u * v) }
+    ///     fn uvder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(0.0, 0.0, 3.0 * u * u) }
+    ///     fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    ///     fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+    ///         match (m, n) {
+    ///             (3, 0) => Vector3::new(0.0, 0.0, 6.0 * v),
+    ///             (3, 1) => Vector3::new(0.0, 0.0, 6.0),
+    ///             (2, 1) => Vector3::new(0.0, 0.0, 6.0 * u),
+    ///             (1, 0) => self.uder(u, v),
+    ///             (0, 1) => self.vder(u, v),
+    ///             (2, 0) => self.uuder(u, v),
+    ///             (1, 1) => self.uvder(u, v),
+    ///             _ => Vector3::zero(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let surface = Cubic;
+    /// assert_eq!(surface.der_mn(1, 1, 2.0, 1.0), surface.uvder(2.0, 1.0));
+    /// assert_eq!(surface.der_mn(3, 1, 2.0, 1.0), Vector3::new(0.0, 0.0, 6.0));
+    /// ```
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector {
+        match (m, n) {
+            (1, 0) => self.uder(u, v),
+            (0, 1) => self.vder(u, v),
+            (2, 0) => self.uuder(u, v),
+            (1, 1) => self.uvder(u, v),
+            (0, 2) => self.vvder(u, v),
+            _ => panic!("the derivation of the order ({m}, {n}) is not implemented."),
+        }
+    }
     /// `None` in default; `Some(period)` if periodic w.r.t. parameter u.
     #[inline(always)]
     fn u_period(&self) -> Option<f64> { None }
     /// `None` in default; `Some(period)` if periodic w.r.t. parameter v.
     #[inline(always)]
     fn v_period(&self) -> Option<f64> { None }
+    /// Returns the ranges of the parameters `u` and `v` if they are bounded.
+    ///
+    /// `(None, None)` in default, i.e. the surface is unbounded or the range is not specified
+    /// by the surface itself, e.g. the plane.
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) { (None, None) }
 }
 
 impl<'a, S: ParametricSurface> ParametricSurface for &'a S {
@@ -42,9 +100,15 @@ impl<'a, S: ParametricSurface> ParametricSurface for &'a S {
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Self::Vector { (*self).vvder(u, v) }
     #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector {
+        (*self).der_mn(m, n, u, v)
+    }
+    #[inline(always)]
     fn u_period(&self) -> Option<f64> { (*self).u_period() }
     #[inline(always)]
     fn v_period(&self) -> Option<f64> { (*self).v_period() }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) { (*self).try_range() }
 }
 
 impl<S: ParametricSurface> ParametricSurface for Box<S> {
@@ -63,9 +127,15 @@ impl<S: ParametricSurface> ParametricSurface for Box<S> {
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Self::Vector { (**self).vvder(u, v) }
     #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector {
+        (**self).der_mn(m, n, u, v)
+    }
+    #[inline(always)]
     fn u_period(&self) -> Option<f64> { (**self).u_period() }
     #[inline(always)]
     fn v_period(&self) -> Option<f64> { (**self).v_period() }
+    #[inline(always)]
+    fn try_range(&self) -> (Option<(f64, f64)>, Option<(f64, f64)>) { (**self).try_range() }
 }
 
 /// 2D parametric surface
@@ -79,16 +149,94 @@ pub trait ParametricSurface3D: ParametricSurface<Point = Point3, Vector = Vector
     fn normal(&self, u: f64, v: f64) -> Vector3 {
         self.uder(u, v).cross(self.vder(u, v)).normalize()
     }
+    /// Returns the coefficients `(E, F, G)` of the first fundamental form at `(u, v)`.
+    #[inline(always)]
+    fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        let (uder, vder) = (self.uder(u, v), self.vder(u, v));
+        (uder.dot(uder), uder.dot(vder), vder.dot(vder))
+    }
+    /// Returns the coefficients `(L, M, N)` of the second fundamental form at `(u, v)` with
+    /// respect to [`ParametricSurface3D::normal`].
+    #[inline(always)]
+    fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        let normal = self.normal(u, v);
+        (
+            self.uuder(u, v).dot(normal),
+            self.uvder(u, v).dot(normal),
+            self.vvder(u, v).dot(normal),
+        )
+    }
+    /// Returns the shape operator at `(u, v)`: the matrix of the Weingarten map on the basis
+    /// `uder` and `vder`, that is, the inverse of the first fundamental form multiplied by the
+    /// second one. Returns `None` if the first fundamental form is degenerate, e.g. at the poles.
+    ///
+    /// The eigenvalues are the principal curvatures, which are negative if the surface bends
+    /// away from the normal, and the determinant and the half of the trace are the Gaussian and
+    /// the mean curvatures.
+    /// # Examples
+    /// ```
+    /// use truck_base::cgmath64::*;
+    /// use truck_geotrait::*;
+    ///
+    /// // the paraboloid z = (u^2 + 4v^2) / 2
+    /// #[derive(Clone, Debug)]
+    /// struct Paraboloid;
+    /// impl ParametricSurface for Paraboloid {
+    ///     type Point = Point3;
+    ///     type Vector = Vector3;
+    ///     fn subs(&self, u: f64, v: f64) -> Point3 {
+    ///         Point3::new(u, v, (u * u + 4.0 * v * v) / 2.0)
+    ///     }
+    ///     fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(1.0, 0.0, u) }
+    ///     fn vder(&self, _: f64, v: f64) -> Vector3 { Vector3::new(0.0, 1.0, 4.0 * v) }
+    ///     fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::new(0.0, 0.0, 1.0) }
+    ///     fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    ///     fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::new(0.0, 0.0, 4.0) }
+    /// }
+    /// impl ParametricSurface3D for Paraboloid {}
+    ///
+    /// assert_eq!(Paraboloid.first_fundamental_form(0.0, 0.0), (1.0, 0.0, 1.0));
+    /// assert_eq!(Paraboloid.second_fundamental_form(0.0, 0.0), (1.0, 0.0, 4.0));
+    /// let shape = Paraboloid.shape_operator(0.0, 0.0).unwrap();
+    /// assert_eq!(shape, Matrix2::new(1.0, 0.0, 0.0, 4.0));
+    /// // the Gaussian curvature
+    /// assert_eq!(shape.determinant(), 4.0);
+    /// ```
+    fn shape_operator(&self, u: f64, v: f64) -> Option<Matrix2> {
+        let (e, f, g) = self.first_fundamental_form(u, v);
+        let (l, m, n) = self.second_fundamental_form(u, v);
+        Some(Matrix2::new(e, f, f, g).invert()? * Matrix2::new(l, m, m, n))
+    }
 }
 
 impl<'a, S: ParametricSurface3D> ParametricSurface3D for &'a S {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 { (*self).normal(u, v) }
+    #[inline(always)]
+    fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        (*self).first_fundamental_form(u, v)
+    }
+    #[inline(always)]
+    fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        (*self).second_fundamental_form(u, v)
+    }
+    #[inline(always)]
+    fn shape_operator(&self, u: f64, v: f64) -> Option<Matrix2> { (*self).shape_operator(u, v) }
 }
 
 impl<S: ParametricSurface3D> ParametricSurface3D for Box<S> {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 { (**self).normal(u, v) }
+    #[inline(always)]
+    fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        (**self).first_fundamental_form(u, v)
+    }
+    #[inline(always)]
+    fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        (**self).second_fundamental_form(u, v)
+    }
+    #[inline(always)]
+    fn shape_operator(&self, u: f64, v: f64) -> Option<Matrix2> { (**self).shape_operator(u, v) }
 }
 
 /// Bounded surface with parametric range
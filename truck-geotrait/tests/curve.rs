@@ -38,6 +38,28 @@ fn polycurve_presearch() {
     assert_eq!(t, 0.0);
 }
 
+#[test]
+fn polycurve_search_parameters() {
+    // the nodal cubic `(t^2 - 1, t^3 - t)` passing through the origin twice
+    let coef = vec![
+        Vector2::new(-1.0, 0.0),
+        Vector2::new(0.0, -1.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let poly = PolyCurve::<Point2>(coef);
+    let origin = Point2::origin();
+    let ts = poly.search_parameters(origin, (-2.0, 2.0), 100);
+    assert_eq!(ts.len(), 2, "{ts:?}");
+    assert!(ts[0].near(&-1.0) && ts[1].near(&1.0), "{ts:?}");
+    let t = poly.search_parameter_in_domain(origin, (0.0, 2.0), 100);
+    assert!(matches!(t, Some(t) if t.near(&1.0)), "{t:?}");
+    let t = poly.search_parameter_in_domain(origin, (-0.5, 0.5), 100);
+    assert_eq!(t, None);
+    let ts = poly.search_parameters(poly.subs(1.5), (-2.0, 2.0), 100);
+    assert_eq!(ts.len(), 1, "{ts:?}");
+}
+
 fn exec_polycurve_snp_on_curve() -> bool {
     let coef: Vec<Vector3> = (0..5)
         .map(|_| {
@@ -107,3 +129,76 @@ fn polycurve_division() {
     println!("division error: {}", 100 - count);
     assert!(count > 98);
 }
+
+#[test]
+fn polycurve_snp_with_config() {
+    let coef = vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let poly = PolyCurve::<Point3>(coef);
+    let pt = poly.subs(0.7);
+    let config = algo::SolverConfig::newton(100);
+    let pure =
+        algo::curve::search_nearest_parameter_with_config(&poly, pt, 0.2, (0.0, 1.0), config);
+    assert!(pure.parameter.unwrap().near(&0.7));
+    assert_eq!(pure.starts, 1);
+    assert!(!pure.bisection);
+    assert_eq!(
+        pure.parameter,
+        algo::curve::search_nearest_parameter(&poly, pt, 0.2, 100)
+    );
+
+    let config = algo::SolverConfig {
+        damping: 0.5,
+        ..config
+    };
+    let damped =
+        algo::curve::search_nearest_parameter_with_config(&poly, pt, 0.2, (0.0, 1.0), config);
+    assert!(damped.parameter.unwrap().near(&0.7));
+    assert!(damped.iterations > pure.iterations);
+
+    // the nearest point is not on the curve.
+    let pt = Point3::new(0.7, 0.49, 1.0);
+    let config = algo::SolverConfig::default();
+    let report = algo::curve::search_parameter_with_config(&poly, pt, 0.2, (0.0, 1.0), config);
+    assert!(report.parameter.is_none());
+    assert!(report.residual.is_finite());
+}
+
+fn exec_polycurve_chordal_division() -> bool {
+    let coef: Vec<Vector3> = (0..5)
+        .map(|_| {
+            Vector3::new(
+                20.0 * rand::random::<f64>() - 10.0,
+                20.0 * rand::random::<f64>() - 10.0,
+                20.0 * rand::random::<f64>() - 10.0,
+            )
+        })
+        .collect();
+    let poly = PolyCurve::<Point3>(coef);
+    let (division, pts) = algo::curve::chordal_division(&poly, (-1.0, 1.0), 0.05);
+    assert_eq!(division.len(), pts.len());
+    division.windows(2).zip(pts).all(|(a, pt)| {
+        let pt0 = poly.subs(a[0]);
+        assert_eq!(pt0, pt);
+        let pt1 = poly.subs(a[1]);
+        let dir = (pt1 - pt0).normalize();
+        (1..8).all(|i| {
+            let t = i as f64 / 8.0;
+            let vec = poly.subs(a[0] * (1.0 - t) + a[1] * t) - pt0;
+            (vec - dir * vec.dot(dir)).magnitude() < 0.1
+        })
+    })
+}
+
+#[test]
+fn polycurve_chordal_division() {
+    let count = (0..100)
+        .filter(|_| exec_polycurve_chordal_division())
+        .count();
+    println!("division error: {}", 100 - count);
+    assert!(count > 98);
+}
@@ -1,4 +1,4 @@
-use truck_base::cgmath64::*;
+use truck_base::{cgmath64::*, tolerance::Tolerance};
 use truck_geotrait::*;
 
 // polynomial curve
@@ -41,6 +41,25 @@ impl<P: EuclideanSpace<Scalar = f64>> BoundedCurve for PolyCurve<P> {
     fn parameter_range(&self) -> (f64, f64) { (-100.0, 100.0) }
 }
 
+impl<P> SearchParameter<D1> for PolyCurve<P>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    P::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    type Point = P;
+    fn search_parameter<H: Into<SPHint1D>>(&self, point: P, hint: H, trials: usize) -> Option<f64> {
+        let range = match hint.into() {
+            SPHint1D::Parameter(hint) => {
+                return algo::curve::search_parameter(self, point, hint, trials)
+            }
+            SPHint1D::Range(x, y) => (x, y),
+            SPHint1D::None => self.parameter_range(),
+        };
+        let hint = algo::curve::presearch(self, point, range, 100);
+        algo::curve::search_parameter(self, point, hint, trials)
+    }
+}
+
 impl<P> ParameterDivision1D for PolyCurve<P>
 where P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + truck_base::hash::HashGen<f64>
 {
@@ -213,3 +213,55 @@ fn polysurface_division() {
     let count = (0..10).filter(|_| exec_polysurface_division()).count();
     assert!(count > 8, "wrong answer: {:?}", 10 - count);
 }
+
+#[test]
+fn polysurface_snp_with_config() {
+    let coef0 = vec![Vector3::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0)];
+    let coef1 = vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)];
+    let poly = PolySurface(PolyCurve(coef0), PolyCurve(coef1));
+    let pt = Point3::new(0.2, 0.3, 1.0);
+    let range = ((0.0, 1.0), (0.0, 1.0));
+    let hint = (0.9, 0.9);
+
+    let config = algo::SolverConfig::newton(100);
+    let pure = algo::surface::search_nearest_parameter_with_config(&poly, pt, hint, range, config);
+    let (u, v) = pure.parameter.unwrap();
+    assert!(Vector2::new(u, v).near(&Vector2::new(0.2, 0.3)));
+    assert_eq!((pure.iterations, pure.starts), (1, 1));
+
+    let config = algo::SolverConfig {
+        damping: 0.5,
+        ..config
+    };
+    let damped =
+        algo::surface::search_nearest_parameter_with_config(&poly, pt, hint, range, config);
+    let (u, v) = damped.parameter.unwrap();
+    assert!(Vector2::new(u, v).near(&Vector2::new(0.2, 0.3)));
+    assert!(damped.iterations > pure.iterations);
+
+    // Newton's method cannot converge without any iterations.
+    let config = algo::SolverConfig {
+        max_iterations: 0,
+        seeds: 4,
+        bisection: false,
+        ..Default::default()
+    };
+    let report =
+        algo::surface::search_nearest_parameter_with_config(&poly, pt, hint, range, config);
+    assert!(report.parameter.is_none());
+    assert_eq!((report.iterations, report.starts), (17, 17));
+    assert!(report.residual.is_finite());
+
+    // The bracketed search finds the solution instead.
+    let config = algo::SolverConfig {
+        bisection: true,
+        ..config
+    };
+    let report =
+        algo::surface::search_nearest_parameter_with_config(&poly, pt, hint, range, config);
+    let (u, v) = report.parameter.unwrap();
+    assert!(Vector2::new(u, v).near(&Vector2::new(0.2, 0.3)));
+    assert!(report.bisection);
+    assert_eq!(report.starts, 17);
+    assert!(report.iterations > 17);
+}
@@ -0,0 +1,284 @@
+use super::flattening::triangles;
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// The discrete curvatures of a mesh at a vertex
+///
+/// The curvatures are positive if the surface bends toward the normal, in the same convention as
+/// [`ParametricSurface3D::shape_operator`], e.g. negative on the sphere with the outward normals.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VertexCurvature {
+    /// the unit normal: the normalized sum of the area vectors of the adjacent triangles
+    pub normal: Vector3,
+    /// the mean curvature
+    pub mean: f64,
+    /// the Gaussian curvature
+    pub gaussian: f64,
+    /// the maximum and the minimum principal curvatures
+    pub principal: [f64; 2],
+    /// the unit principal directions of the maximum and the minimum curvatures
+    pub directions: [Vector3; 2],
+}
+
+/// Estimation of curvatures and extraction of feature lines of meshes
+pub trait Curvature {
+    /// Returns the discrete curvatures at the positions of the mesh.
+    ///
+    /// The mean curvature is by the cotangent Laplacian, and the Gaussian curvature is by the
+    /// angle defect, both divided by the mixed Voronoi areas of the vertices. The principal
+    /// curvatures are derived from them, and the principal directions are the eigenvectors of the
+    /// least squares fitting of the normal curvatures along the edges. The curvatures of the
+    /// positions out of the triangles are zero. The positions should be shared among the faces,
+    /// e.g. by [`OptimizingFilter::put_together_same_attrs`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    ///
+    /// // the half cylinder of the radius 2 around the z-axis
+    /// let positions: Vec<Point3> = (0..=16)
+    ///     .flat_map(|i| (0..=16).map(move |j| (i as f64 / 16.0, j as f64 / 16.0)))
+    ///     .map(|(u, v)| {
+    ///         let t = std::f64::consts::PI * u;
+    ///         Point3::new(2.0 * f64::cos(t), 2.0 * f64::sin(t), 2.0 * v)
+    ///     })
+    ///     .collect();
+    /// let faces = (0..16).flat_map(|i| (0..16).map(move |j| i * 17 + j));
+    /// let faces = Faces::from_iter(faces.map(|k| [k, k + 17, k + 18, k + 1]));
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// // the vertex at (0, 2, 1)
+    /// let curvature = mesh.vertex_curvatures()[8 * 17 + 8];
+    /// assert!(curvature.normal.near(&Vector3::unit_y()));
+    /// // the cylinder bends away from the outward normal.
+    /// assert!(f64::abs(curvature.mean + 0.25) < 1.0e-6);
+    /// assert!(f64::abs(curvature.gaussian) < 1.0e-6);
+    /// assert!(f64::abs(curvature.principal[1] + 0.5) < 1.0e-6);
+    /// assert!(f64::abs(curvature.directions[0].z) > 0.999);
+    /// ```
+    fn vertex_curvatures(&self) -> Vec<VertexCurvature>;
+
+    /// Returns the feature lines: the chains of the edges whose dihedral angles, the angles
+    /// between the normals of the adjacent triangles, are more than `angle`, and of the boundary
+    /// and the non-manifold edges.
+    ///
+    /// The chains are divided at the vertices where the feature lines do not simply pass. The
+    /// closed chains end at their first points.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    /// let mut mesh = cube.triangulation(0.01).to_polygon();
+    /// mesh.put_together_same_attrs();
+    ///
+    /// let lines = mesh.feature_lines(Rad(0.5));
+    /// assert_eq!(lines.len(), 12);
+    /// // the feature lines are usable as the edges of the wires.
+    /// let edges: Vec<Edge> = lines
+    ///     .into_iter()
+    ///     .map(|line| {
+    ///         let v0 = Vertex::new(line[0]);
+    ///         let v1 = Vertex::new(line[line.len() - 1]);
+    ///         Edge::new(&v0, &v1, Curve::Polyline(line))
+    ///     })
+    ///     .collect();
+    /// assert!(edges.iter().all(|edge| edge.is_geometric_consistent()));
+    /// ```
+    fn feature_lines(&self, angle: Rad<f64>) -> Vec<PolylineCurve<Point3>>;
+}
+
+/// Returns the non-degenerate triangles by the positions and their area vectors.
+fn area_vectors(mesh: &PolygonMesh) -> Vec<([usize; 3], Vector3)> {
+    let positions = mesh.positions();
+    triangles(mesh)
+        .into_iter()
+        .map(|[i, j, k]| {
+            let normal = (positions[j] - positions[i]).cross(positions[k] - positions[i]);
+            ([i, j, k], normal / 2.0)
+        })
+        .collect()
+}
+
+#[inline(always)]
+fn edge_key(i: usize, j: usize) -> [usize; 2] { [usize::min(i, j), usize::max(i, j)] }
+
+/// Returns the unit vectors perpendicular to each other and to `normal`.
+fn tangent_basis(normal: Vector3) -> [Vector3; 2] {
+    let axis = match f64::abs(normal.x) < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u = normal.cross(axis).normalize();
+    [u, normal.cross(u)]
+}
+
+/// Returns the principal directions of maximum and minimum normal curvatures fitted to the
+/// edges from `center` to `neighbors`.
+fn principal_directions(center: Point3, neighbors: &[Point3], normal: Vector3) -> [Vector3; 2] {
+    let [u, v] = tangent_basis(normal);
+    let zero = (Matrix3::zero(), Vector3::zero());
+    let (matrix, rhs) = neighbors.iter().fold(zero, |(matrix, rhs), p| {
+        let e = *p - center;
+        let curvature = 2.0 * e.dot(normal) / e.magnitude2();
+        let d = e - normal * e.dot(normal);
+        let (x, y) = (d.dot(u), d.dot(v));
+        let len2 = x * x + y * y;
+        let row = Vector3::new(x * x, 2.0 * x * y, y * y) / len2;
+        let outer = Matrix3::from_cols(row * row.x, row * row.y, row * row.z);
+        (matrix + outer, rhs + row * curvature)
+    });
+    match matrix.invert() {
+        Some(inverse) if neighbors.len() >= 3 => {
+            let tensor = inverse * rhs;
+            let angle = f64::atan2(2.0 * tensor.y, tensor.x - tensor.z) / 2.0;
+            let max = u * f64::cos(angle) + v * f64::sin(angle);
+            [max, normal.cross(max)]
+        }
+        _ => [u, v],
+    }
+}
+
+impl Curvature for PolygonMesh {
+    fn vertex_curvatures(&self) -> Vec<VertexCurvature> {
+        let positions = self.positions();
+        let len = positions.len();
+        let triangles = area_vectors(self);
+        let mut normals = vec![Vector3::zero(); len];
+        let mut laplacians = vec![Vector3::zero(); len];
+        let mut angles = vec![0.0; len];
+        let mut areas = vec![0.0; len];
+        let mut neighbors = vec![Vec::<usize>::new(); len];
+        let mut edge_count = HashMap::<[usize; 2], usize>::default();
+        triangles.iter().for_each(|(tri, normal)| {
+            let area = normal.magnitude();
+            let p = tri.map(|i| positions[i]);
+            (0..3).for_each(|n| {
+                let (i, j, k) = (tri[n], tri[(n + 1) % 3], tri[(n + 2) % 3]);
+                let (a, b, c) = (p[n], p[(n + 1) % 3], p[(n + 2) % 3]);
+                normals[i] += *normal;
+                let (e0, e1) = (b - a, c - a);
+                angles[i] += e0.angle(e1).0;
+                // the cotangents of the angles at `b` and `c` weight the opposite edges.
+                let cot_b = (a - b).dot(c - b) / (2.0 * area);
+                let cot_c = (a - c).dot(b - c) / (2.0 * area);
+                laplacians[i] += (a - b) * cot_c + (a - c) * cot_b;
+                // the mixed Voronoi area
+                areas[i] += match (e0.dot(e1) < 0.0, cot_b < 0.0 || cot_c < 0.0) {
+                    (true, _) => area / 2.0,
+                    (false, true) => area / 4.0,
+                    (false, false) => (e0.magnitude2() * cot_c + e1.magnitude2() * cot_b) / 8.0,
+                };
+                *edge_count.entry(edge_key(i, j)).or_default() += 1;
+                [j, k].into_iter().for_each(|j| {
+                    if !neighbors[i].contains(&j) {
+                        neighbors[i].push(j);
+                    }
+                });
+            })
+        });
+        let mut boundary = vec![false; len];
+        edge_count.into_iter().for_each(|([i, j], count)| {
+            if count != 2 {
+                boundary[i] = true;
+                boundary[j] = true;
+            }
+        });
+
+        (0..len)
+            .map(|i| {
+                if areas[i] == 0.0 {
+                    return VertexCurvature {
+                        normal: Vector3::zero(),
+                        mean: 0.0,
+                        gaussian: 0.0,
+                        principal: [0.0; 2],
+                        directions: [Vector3::zero(); 2],
+                    };
+                }
+                let normal = normals[i].normalize();
+                let mean = -laplacians[i].dot(normal) / (4.0 * areas[i]);
+                let full = match boundary[i] {
+                    true => std::f64::consts::PI,
+                    false => 2.0 * std::f64::consts::PI,
+                };
+                let gaussian = (full - angles[i]) / areas[i];
+                let disc = f64::sqrt(f64::max(mean * mean - gaussian, 0.0));
+                let points: Vec<Point3> = neighbors[i].iter().map(|j| positions[*j]).collect();
+                VertexCurvature {
+                    normal,
+                    mean,
+                    gaussian,
+                    principal: [mean + disc, mean - disc],
+                    directions: principal_directions(positions[i], &points, normal),
+                }
+            })
+            .collect()
+    }
+
+    fn feature_lines(&self, angle: Rad<f64>) -> Vec<PolylineCurve<Point3>> {
+        let positions = self.positions();
+        let mut edge_map = HashMap::<[usize; 2], Vec<Vector3>>::default();
+        area_vectors(self).into_iter().for_each(|(tri, normal)| {
+            (0..3).for_each(|n| {
+                let entry = edge_map.entry(edge_key(tri[n], tri[(n + 1) % 3]));
+                entry.or_default().push(normal.normalize());
+            })
+        });
+        let mut features: Vec<[usize; 2]> = edge_map
+            .into_iter()
+            .filter(|(_, normals)| normals.len() != 2 || normals[0].angle(normals[1]) > angle)
+            .map(|(edge, _)| edge)
+            .collect();
+        features.sort();
+        let mut adjacency = HashMap::<usize, Vec<(usize, usize)>>::default();
+        features.iter().enumerate().for_each(|(e, [i, j])| {
+            adjacency.entry(*i).or_default().push((*j, e));
+            adjacency.entry(*j).or_default().push((*i, e));
+        });
+
+        let mut visited = vec![false; features.len()];
+        let walk = |start: usize, e: usize, visited: &mut [bool]| {
+            let mut chain = vec![start];
+            let (mut current, mut edge) = (start, e);
+            while !visited[edge] {
+                visited[edge] = true;
+                let [i, j] = features[edge];
+                current = match i == current {
+                    true => j,
+                    false => i,
+                };
+                chain.push(current);
+                let next = &adjacency[&current];
+                if next.len() != 2 {
+                    break;
+                }
+                edge = match next[0].1 == edge {
+                    true => next[1].1,
+                    false => next[0].1,
+                };
+            }
+            PolylineCurve(chain.into_iter().map(|i| positions[i]).collect())
+        };
+        let mut vertices: Vec<&usize> = adjacency.keys().collect();
+        vertices.sort();
+        let mut lines = Vec::new();
+        // the open chains from the ends and the branches, and then the closed chains
+        let sorted = vertices.iter().filter(|i| adjacency[**i].len() != 2);
+        let sorted = sorted.chain(vertices.iter().filter(|i| adjacency[**i].len() == 2));
+        sorted.for_each(|i| {
+            adjacency[*i].iter().for_each(|(_, e)| {
+                if !visited[*e] {
+                    lines.push(walk(**i, *e, &mut visited));
+                }
+            })
+        });
+        lines
+    }
+}
@@ -0,0 +1,443 @@
+use super::primitive::{axial_coordinate, recognize};
+use super::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::f64::consts::PI;
+use truck_topology::{Edge, Face, FaceID, Solid};
+
+const EDGE_DIVISION: usize = 4;
+const FACE_DIVISION: usize = 4;
+const SEARCH_TRIALS: usize = 100;
+/// the cosine of the maximum angle between the normals of tangent faces
+const TANGENT_COSINE: f64 = 0.999;
+/// the maximum angular gap of the samples of the cylinder surrounding its axis
+const MAX_ANGULAR_GAP: f64 = PI / 2.0;
+
+/// Machining features recognized on solids.
+#[derive(Clone, Debug)]
+pub enum MachiningFeature<S> {
+    /// cylindrical hole
+    Hole {
+        /// the side faces, and the bottom faces if the hole is blind
+        faces: Vec<FaceID<S>>,
+        /// the center of the entrance of the hole
+        origin: Point3,
+        /// the unit direction of the axis, from the entrance to the inside of the hole
+        axis: Vector3,
+        /// the diameter of the hole
+        diameter: f64,
+        /// the length of the cylindrical part of the hole
+        depth: f64,
+        /// `true` if the hole penetrates the solid, `false` if the hole has bottom faces
+        through: bool,
+    },
+    /// cylindrical or toroidal blend face tangent to its neighbors
+    Fillet {
+        /// the faces of the fillet
+        faces: Vec<FaceID<S>>,
+        /// the radius of the fillet
+        radius: f64,
+    },
+}
+
+/// Recognizes machining features on solids.
+pub trait FeatureRecognition<S> {
+    /// Recognizes holes and fillets with tolerance `tol`.
+    ///
+    /// The surface of each face is recognized as a primitive by sampling its boundary and parameter
+    /// domain, and adjacent faces on the same cylinder or torus are gathered into one feature.
+    /// - A concave cylinder surrounding its axis is a hole. It is blind if one of its neighbors is
+    /// a plane perpendicular to the axis within the hole, or a cone on the axis.
+    /// - The other cylinders and tori tangent to at least two neighbor faces are fillets.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// // a unit cube with a hole through it
+    /// let v = builder::vertex(Point3::new(-0.5, -0.5, 0.0));
+    /// let edge = builder::tsweep(&v, Vector3::unit_x());
+    /// let mut face = builder::tsweep(&edge, Vector3::unit_y());
+    /// let v = builder::vertex(Point3::new(0.2, 0.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    /// face.add_boundary(circle.inverse());
+    /// let solid = builder::tsweep(&face, Vector3::unit_z());
+    ///
+    /// let features = solid.recognize_features(1.0e-3);
+    /// assert_eq!(features.len(), 1);
+    /// match &features[0] {
+    ///     MachiningFeature::Hole {
+    ///         faces,
+    ///         diameter,
+    ///         depth,
+    ///         through,
+    ///         ..
+    ///     } => {
+    ///         assert_eq!(faces.len(), 2);
+    ///         assert!(f64::abs(diameter - 0.4) < 1.0e-3);
+    ///         assert!(f64::abs(depth - 1.0) < 1.0e-3);
+    ///         assert!(through);
+    ///     }
+    ///     got => panic!("wrong feature: {got:?}"),
+    /// }
+    /// ```
+    fn recognize_features(&self, tol: f64) -> Vec<MachiningFeature<S>>;
+}
+
+/// sampled points and oriented normals of a face
+#[derive(Clone, Debug)]
+struct FaceSamples {
+    boundary: Vec<(Point3, Vector3)>,
+    points: Vec<(Point3, Vector3)>,
+    primitive: Option<Primitive>,
+}
+
+impl<C, S> FeatureRecognition<S> for Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    fn recognize_features(&self, tol: f64) -> Vec<MachiningFeature<S>> {
+        nonpositive_tolerance!(tol);
+        let faces = self.face_iter().collect::<Vec<_>>();
+        let samples = faces
+            .iter()
+            .map(|face| sample_face(face, tol))
+            .collect::<Vec<_>>();
+        let mut edge_faces = HashMap::<_, Vec<usize>>::default();
+        faces.iter().enumerate().for_each(|(i, face)| {
+            face.edge_iter()
+                .for_each(|edge| edge_faces.entry(edge.id()).or_default().push(i))
+        });
+        let adjacency = faces
+            .iter()
+            .enumerate()
+            .map(|(i, face)| {
+                face.edge_iter()
+                    .flat_map(|edge| {
+                        edge_faces[&edge.id()]
+                            .iter()
+                            .filter(move |j| **j != i)
+                            .map(move |j| (*j, edge.clone()))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let recognizer = Recognizer {
+            faces: &faces,
+            samples: &samples,
+            adjacency: &adjacency,
+            tol,
+        };
+        recognizer
+            .revolution_groups()
+            .into_iter()
+            .filter_map(|group| recognizer.classify(&group))
+            .collect()
+    }
+}
+
+fn sample_face<C, S>(face: &Face<Point3, C, S>, tol: f64) -> FaceSamples
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>, {
+    let surface = face.surface();
+    let oriented = |n: Vector3| match face.orientation() {
+        true => n,
+        false => -n,
+    };
+    let params = face
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.parameter_range();
+            (0..EDGE_DIVISION)
+                .map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / EDGE_DIVISION as f64))
+        })
+        .filter_map(|p| {
+            let (u, v) = surface.search_nearest_parameter(p, None, SEARCH_TRIALS)?;
+            Some((p, (u, v)))
+        })
+        .collect::<Vec<_>>();
+    let boundary = params
+        .iter()
+        .map(|(p, (u, v))| (*p, oriented(surface.normal(*u, *v))))
+        .collect::<Vec<_>>();
+    let mut points = boundary.clone();
+    if !params.is_empty() {
+        let bdd = params
+            .iter()
+            .map(|(_, (u, v))| Point2::new(*u, *v))
+            .collect::<BoundingBox<Point2>>();
+        let (min, max) = (bdd.min(), bdd.max());
+        points.extend(
+            (1..FACE_DIVISION)
+                .flat_map(|i| (1..FACE_DIVISION).map(move |j| (i, j)))
+                .map(|(i, j)| {
+                    let u = min.x + (max.x - min.x) * i as f64 / FACE_DIVISION as f64;
+                    let v = min.y + (max.y - min.y) * j as f64 / FACE_DIVISION as f64;
+                    (surface.subs(u, v), oriented(surface.normal(u, v)))
+                }),
+        );
+    }
+    let (positions, normals): (Vec<_>, Vec<_>) = points.iter().copied().unzip();
+    let primitive = recognize(&positions, &normals, tol);
+    FaceSamples {
+        boundary,
+        points,
+        primitive,
+    }
+}
+
+struct Recognizer<'a, C, S> {
+    faces: &'a [&'a Face<Point3, C, S>],
+    samples: &'a [FaceSamples],
+    adjacency: &'a [Vec<(usize, Edge<Point3, C>)>],
+    tol: f64,
+}
+
+impl<'a, C, S> Recognizer<'a, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// the connected components of the faces on the same cylinders or tori
+    fn revolution_groups(&self) -> Vec<Vec<usize>> {
+        let mut checked = vec![false; self.faces.len()];
+        let mut groups = Vec::new();
+        for i in 0..self.faces.len() {
+            let primitive = match self.samples[i].primitive {
+                Some(primitive @ Primitive::Cylinder { .. })
+                | Some(primitive @ Primitive::Torus { .. }) => primitive,
+                _ => continue,
+            };
+            if checked[i] {
+                continue;
+            }
+            checked[i] = true;
+            let mut group = vec![i];
+            let mut stack = vec![i];
+            while let Some(j) = stack.pop() {
+                self.adjacency[j].iter().for_each(|(k, _)| {
+                    let same = matches!(
+                        &self.samples[*k].primitive,
+                        Some(other) if same_revolution(&primitive, other, self.tol)
+                    );
+                    if same && !checked[*k] {
+                        checked[*k] = true;
+                        group.push(*k);
+                        stack.push(*k);
+                    }
+                });
+            }
+            groups.push(group);
+        }
+        groups
+    }
+
+    fn classify(&self, group: &[usize]) -> Option<MachiningFeature<S>> {
+        let points = group
+            .iter()
+            .flat_map(|i| &self.samples[*i].points)
+            .collect::<Vec<_>>();
+        match self.samples[group[0]].primitive? {
+            Primitive::Cylinder {
+                origin,
+                axis,
+                radius,
+            } => {
+                let concave = points.iter().all(|(p, n)| {
+                    let vec = *p - origin;
+                    n.dot(vec - axis * vec.dot(axis)) < 0.0
+                });
+                match concave && surrounds_axis(&points, origin, axis) {
+                    true => Some(self.hole(group, &points, origin, axis, radius)),
+                    false => self.fillet(group, radius),
+                }
+            }
+            Primitive::Torus { minor_radius, .. } => self.fillet(group, minor_radius),
+            _ => None,
+        }
+    }
+
+    fn hole(
+        &self,
+        group: &[usize],
+        points: &[&(Point3, Vector3)],
+        origin: Point3,
+        axis: Vector3,
+        radius: f64,
+    ) -> MachiningFeature<S> {
+        let (h0, h1) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(h0, h1), (p, _)| {
+            let h = (*p - origin).dot(axis);
+            (f64::min(h0, h), f64::max(h1, h))
+        });
+        let mut bottoms = self
+            .neighbors(group)
+            .into_iter()
+            .filter(|i| self.is_bottom(*i, origin, axis, radius))
+            .collect::<Vec<_>>();
+        bottoms.sort_unstable();
+        let (entrance, direction) = match bottoms.first() {
+            Some(i) => {
+                let boundary = &self.samples[*i].boundary;
+                let h = boundary
+                    .iter()
+                    .fold(0.0, |sum, (p, _)| sum + (*p - origin).dot(axis))
+                    / boundary.len() as f64;
+                match h - h0 > h1 - h {
+                    true => (h0, axis),
+                    false => (h1, -axis),
+                }
+            }
+            None => (h0, axis),
+        };
+        MachiningFeature::Hole {
+            faces: group
+                .iter()
+                .chain(&bottoms)
+                .map(|i| self.faces[*i].id())
+                .collect(),
+            origin: origin + axis * entrance,
+            axis: direction,
+            diameter: 2.0 * radius,
+            depth: h1 - h0,
+            through: bottoms.is_empty(),
+        }
+    }
+
+    fn is_bottom(&self, i: usize, origin: Point3, axis: Vector3, radius: f64) -> bool {
+        let tol = self.tol;
+        match self.samples[i].primitive {
+            Some(Primitive::Plane { normal, .. }) => {
+                normal.cross(axis).magnitude() < tol
+                    && self.samples[i]
+                        .boundary
+                        .iter()
+                        .all(|(p, _)| axial_coordinate(*p, origin, axis).1 < radius + tol)
+            }
+            Some(Primitive::Cone {
+                apex, axis: other, ..
+            }) => other.cross(axis).magnitude() < tol && axial_coordinate(apex, origin, axis).1 < tol,
+            _ => false,
+        }
+    }
+
+    fn fillet(&self, group: &[usize], radius: f64) -> Option<MachiningFeature<S>> {
+        let members = group.iter().collect::<HashSet<_>>();
+        let tangents = group
+            .iter()
+            .flat_map(|i| self.adjacency[*i].iter().map(move |(j, edge)| (*i, *j, edge)))
+            .filter(|(_, j, _)| !members.contains(j))
+            .filter(|(i, j, edge)| self.tangent_along(*i, *j, edge))
+            .map(|(_, j, _)| j)
+            .collect::<HashSet<_>>();
+        match tangents.len() >= 2 {
+            true => Some(MachiningFeature::Fillet {
+                faces: group.iter().map(|i| self.faces[*i].id()).collect(),
+                radius,
+            }),
+            false => None,
+        }
+    }
+
+    fn neighbors(&self, group: &[usize]) -> HashSet<usize> {
+        let members = group.iter().collect::<HashSet<_>>();
+        group
+            .iter()
+            .flat_map(|i| &self.adjacency[*i])
+            .map(|(j, _)| *j)
+            .filter(|j| !members.contains(j))
+            .collect()
+    }
+
+    fn tangent_along(&self, i: usize, j: usize, edge: &Edge<Point3, C>) -> bool {
+        let curve = edge.curve();
+        let (t0, t1) = curve.parameter_range();
+        let point = curve.subs((t0 + t1) / 2.0);
+        match (
+            oriented_normal(self.faces[i], point),
+            oriented_normal(self.faces[j], point),
+        ) {
+            (Some(n0), Some(n1)) => n0.dot(n1) > TANGENT_COSINE,
+            _ => false,
+        }
+    }
+}
+
+fn oriented_normal<C, S>(face: &Face<Point3, C, S>, point: Point3) -> Option<Vector3>
+where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3> {
+    let surface = face.surface();
+    let (u, v) = surface.search_nearest_parameter(point, None, SEARCH_TRIALS)?;
+    let normal = surface.normal(u, v);
+    match face.orientation() {
+        true => Some(normal),
+        false => Some(-normal),
+    }
+}
+
+fn same_revolution(primitive0: &Primitive, primitive1: &Primitive, tol: f64) -> bool {
+    match (*primitive0, *primitive1) {
+        (
+            Primitive::Cylinder {
+                origin: origin0,
+                axis: axis0,
+                radius: radius0,
+            },
+            Primitive::Cylinder {
+                origin: origin1,
+                axis: axis1,
+                radius: radius1,
+            },
+        ) => {
+            axis0.cross(axis1).magnitude() < tol
+                && f64::abs(radius0 - radius1) < tol
+                && axial_coordinate(origin1, origin0, axis0).1 < tol
+        }
+        (
+            Primitive::Torus {
+                center: center0,
+                axis: axis0,
+                major_radius: major0,
+                minor_radius: minor0,
+            },
+            Primitive::Torus {
+                center: center1,
+                axis: axis1,
+                major_radius: major1,
+                minor_radius: minor1,
+            },
+        ) => {
+            center0.distance(center1) < tol
+                && axis0.cross(axis1).magnitude() < tol
+                && f64::abs(major0 - major1) < tol
+                && f64::abs(minor0 - minor1) < tol
+        }
+        _ => false,
+    }
+}
+
+/// whether the samples go around the axis without large gaps
+fn surrounds_axis(points: &[&(Point3, Vector3)], origin: Point3, axis: Vector3) -> bool {
+    let e0 = match axis.x.abs() < 0.5 {
+        true => axis.cross(Vector3::unit_x()).normalize(),
+        false => axis.cross(Vector3::unit_y()).normalize(),
+    };
+    let e1 = axis.cross(e0);
+    let mut angles = points
+        .iter()
+        .map(|(p, _)| {
+            let vec = *p - origin;
+            f64::atan2(vec.dot(e1), vec.dot(e0))
+        })
+        .collect::<Vec<_>>();
+    angles.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    match (angles.first(), angles.last()) {
+        (Some(first), Some(last)) => {
+            let gap = angles
+                .windows(2)
+                .fold(first + 2.0 * PI - last, |gap, a| f64::max(gap, a[1] - a[0]));
+            gap < MAX_ANGULAR_GAP
+        }
+        _ => false,
+    }
+}
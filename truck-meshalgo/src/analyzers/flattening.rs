@@ -0,0 +1,284 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::collections::VecDeque;
+
+/// the lower bound of the cotangent weights of the half edges, keeping the Laplacian positive
+/// definite on the obtuse triangles
+const MIN_WEIGHT: f64 = 1.0e-3;
+
+/// Flattening of triangle meshes onto the plane
+pub trait Flattening {
+    /// Unfolds the triangles onto the plane one by one, preserving the lengths of their edges.
+    ///
+    /// The triangles are unfolded across the shared edges in the breadth-first order from the
+    /// first triangle, and each position is placed by the first triangle reaching it. The
+    /// unfolding is isometric for the meshes of the developable surfaces, e.g. cylinders and
+    /// cones, since they have no angle defects.
+    ///
+    /// Returns the planar coordinates of the positions, where the positions out of the triangles
+    /// are at the origin. Returns `None` if the triangles are not connected by the edges.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_meshalgo::prelude::*;
+    ///
+    /// // the half cylinder of the radius 1 and the height 2
+    /// let positions: Vec<Point3> = (0..=8)
+    ///     .flat_map(|i| {
+    ///         let t = PI * i as f64 / 8.0;
+    ///         [0.0, 2.0].map(|z| Point3::new(f64::cos(t), f64::sin(t), z))
+    ///     })
+    ///     .collect();
+    /// let faces = Faces::from_iter((0..8).map(|i| [2 * i, 2 * i + 2, 2 * i + 3, 2 * i + 1]));
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// let coords = mesh.unfolded().unwrap();
+    /// assert!(mesh.strain(&coords) < 1.0e-10);
+    /// // the width is the length of the polygonal section.
+    /// let chord = 2.0 * f64::sin(PI / 16.0);
+    /// assert_near!(coords[0].distance(coords[16]), 8.0 * chord);
+    /// assert_near!(coords[0].distance(coords[1]), 2.0);
+    /// ```
+    fn unfolded(&self) -> Option<Vec<Point2>>;
+
+    /// Flattens the mesh with the minimal strain by `iterations` as-rigid-as-possible (ARAP)
+    /// iterations from [`Flattening::unfolded`].
+    ///
+    /// Each iteration rotates the isometric copies of the triangles to fit the current
+    /// flattening, and places the positions by the least squares of the distortions with the
+    /// cotangent weights. Returns `None` if the triangles are not connected by the edges.
+    fn arap_flattened(&self, iterations: usize) -> Option<Vec<Point2>>;
+
+    /// Returns the strain of the flattening `coords`: the maximum relative error of the lengths
+    /// of the edges of the triangles.
+    fn strain(&self, coords: &[Point2]) -> f64;
+}
+
+/// Returns the non-degenerate triangles by the indices of the positions.
+pub(super) fn triangles(mesh: &PolygonMesh) -> Vec<[usize; 3]> {
+    let positions = mesh.positions();
+    let triangles = mesh.faces().triangle_iter().map(|tri| tri.map(|v| v.pos));
+    triangles
+        .filter(|[i, j, k]| {
+            let normal = (positions[*j] - positions[*i]).cross(positions[*k] - positions[*i]);
+            !normal.so_small()
+        })
+        .collect()
+}
+
+/// Returns the isometric copy of the triangle on the plane: the first vertex is at the origin,
+/// the second one is on the positive x-axis, and the third one is in the upper half plane.
+pub(super) fn local_triangle(positions: &[Point3], [i, j, k]: [usize; 3]) -> [Vector2; 3] {
+    let (e0, e1) = (positions[j] - positions[i], positions[k] - positions[i]);
+    let len = e0.magnitude();
+    let (x, y) = (e1.dot(e0) / len, e0.cross(e1).magnitude() / len);
+    [Vector2::zero(), Vector2::new(len, 0.0), Vector2::new(x, y)]
+}
+
+/// Returns the point on the left of `a`-`b` at the distances `r0` from `a` and `r1` from `b`.
+fn apex(a: Point2, b: Point2, r0: f64, r1: f64) -> Point2 {
+    let len = a.distance(b);
+    let e = (b - a) / len;
+    let x = (len * len + r0 * r0 - r1 * r1) / (2.0 * len);
+    let y = f64::sqrt(f64::max(r0 * r0 - x * x, 0.0));
+    a + e * x + Vector2::new(-e.y, e.x) * y
+}
+
+/// Solves `apply(x) = rhs` by the conjugate gradient method starting from `x`, where `apply` is
+/// a positive semidefinite linear map. The fixed components, on which `apply` does not depend and
+/// where `apply` and `rhs` vanish, are kept.
+pub(super) fn conjugate_gradient<F>(apply: F, rhs: &[f64], x: &mut [f64])
+where F: Fn(&[f64], &mut [f64]) {
+    let dot = |x: &[f64], y: &[f64]| x.iter().zip(y).fold(0.0, |sum, (a, b)| sum + a * b);
+    let len = x.len();
+    let mut ap = vec![0.0; len];
+    apply(x, &mut ap);
+    let mut r: Vec<f64> = rhs.iter().zip(&ap).map(|(b, ax)| b - ax).collect();
+    let mut p = r.clone();
+    let mut rr = dot(&r, &r);
+    let threshold = f64::max(dot(rhs, rhs), 1.0) * 1.0e-20;
+    for _ in 0..2 * len {
+        if rr <= threshold {
+            break;
+        }
+        apply(&p, &mut ap);
+        let alpha = rr / dot(&p, &ap);
+        x.iter_mut().zip(&p).for_each(|(x, p)| *x += alpha * p);
+        r.iter_mut().zip(&ap).for_each(|(r, ap)| *r -= alpha * ap);
+        let new_rr = dot(&r, &r);
+        let beta = new_rr / rr;
+        p.iter_mut().zip(&r).for_each(|(p, r)| *p = r + beta * *p);
+        rr = new_rr;
+    }
+}
+
+/// Relaxes the flattening `coords` of `triangles` by `iterations` ARAP iterations. The first
+/// vertex of the first triangle is fixed.
+pub(super) fn arap(
+    positions: &[Point3],
+    triangles: &[[usize; 3]],
+    mut coords: Vec<Point2>,
+    iterations: usize,
+) -> Vec<Point2> {
+    if triangles.is_empty() {
+        return coords;
+    }
+    let locals: Vec<[Vector2; 3]> = triangles
+        .iter()
+        .map(|tri| local_triangle(positions, *tri))
+        .collect();
+    // the weight of the half edge from the `n`th vertex is the cotangent of the opposite angle
+    let weights: Vec<[f64; 3]> = locals
+        .iter()
+        .map(|x| {
+            [0, 1, 2].map(|n| {
+                let (u, v) = (x[n] - x[(n + 2) % 3], x[(n + 1) % 3] - x[(n + 2) % 3]);
+                let cot = u.dot(v) / f64::abs(u.x * v.y - u.y * v.x);
+                f64::max(cot / 2.0, MIN_WEIGHT)
+            })
+        })
+        .collect();
+    let half_edges = |t: usize| {
+        let tri = triangles[t];
+        (0..3).map(move |n| (n, tri[n], tri[(n + 1) % 3]))
+    };
+
+    let len = positions.len();
+    let mut laplacian = vec![Vec::new(); len];
+    (0..triangles.len()).for_each(|t| {
+        half_edges(t).for_each(|(n, i, j)| {
+            laplacian[i].push((j, weights[t][n]));
+            laplacian[j].push((i, weights[t][n]));
+        })
+    });
+    let free: Vec<bool> = (0..len)
+        .map(|i| !laplacian[i].is_empty() && i != triangles[0][0])
+        .collect();
+    let apply = |x: &[f64], res: &mut [f64]| {
+        laplacian.iter().enumerate().for_each(|(i, row)| {
+            res[i] = match free[i] {
+                true => row.iter().fold(0.0, |sum, (j, w)| match free[*j] {
+                    true => sum + w * (x[i] - x[*j]),
+                    false => sum + w * x[i],
+                }),
+                false => 0.0,
+            }
+        })
+    };
+
+    for _ in 0..iterations {
+        let mut rhs = vec![Vector2::zero(); len];
+        (0..triangles.len()).for_each(|t| {
+            let x = &locals[t];
+            let s = half_edges(t).fold(Matrix2::zero(), |s, (n, i, j)| {
+                let (du, dx) = (coords[i] - coords[j], x[n] - x[(n + 1) % 3]);
+                let outer = Matrix2::from_cols(du * dx.x, du * dx.y);
+                s + outer * weights[t][n]
+            });
+            let angle = f64::atan2(s[0][1] - s[1][0], s[0][0] + s[1][1]);
+            let rotation = Matrix2::from_angle(Rad(angle));
+            half_edges(t).for_each(|(n, i, j)| {
+                let rotated = rotation * (x[n] - x[(n + 1) % 3]) * weights[t][n];
+                rhs[i] += rotated;
+                rhs[j] -= rotated;
+            });
+        });
+        (0..2).for_each(|k| {
+            // the fixed positions are moved to the right hand side.
+            let fixed = |i: usize| {
+                let row = laplacian[i].iter().filter(|(j, _)| !free[*j]);
+                row.fold(rhs[i][k], |sum, (j, w)| sum + w * coords[*j][k])
+            };
+            let rhs: Vec<f64> = (0..len)
+                .map(|i| match free[i] {
+                    true => fixed(i),
+                    false => 0.0,
+                })
+                .collect();
+            let mut x: Vec<f64> = coords.iter().map(|p| p[k]).collect();
+            conjugate_gradient(&apply, &rhs, &mut x);
+            coords.iter_mut().zip(x).for_each(|(p, x)| p[k] = x);
+        });
+    }
+    coords
+}
+
+impl Flattening for PolygonMesh {
+    fn unfolded(&self) -> Option<Vec<Point2>> {
+        let positions = self.positions();
+        let triangles = triangles(self);
+        let first = *triangles.first()?;
+        let key = |i: usize, j: usize| [usize::min(i, j), usize::max(i, j)];
+        let mut edge_map = HashMap::<[usize; 2], Vec<usize>>::default();
+        triangles.iter().enumerate().for_each(|(t, tri)| {
+            (0..3).for_each(|n| {
+                let entry = edge_map.entry(key(tri[n], tri[(n + 1) % 3]));
+                entry.or_default().push(t);
+            })
+        });
+
+        let mut coords = vec![None; positions.len()];
+        let local = local_triangle(positions, first);
+        let placed = first.into_iter().zip(local);
+        placed.for_each(|(i, p)| coords[i] = Some(Point2::from_vec(p)));
+        let mut visited = vec![false; triangles.len()];
+        visited[0] = true;
+        let mut queue = VecDeque::from([0]);
+        while let Some(t) = queue.pop_front() {
+            let tri = triangles[t];
+            (0..3).for_each(|n| {
+                let (i, j) = (tri[n], tri[(n + 1) % 3]);
+                edge_map[&key(i, j)].iter().for_each(|s| {
+                    if visited[*s] {
+                        return;
+                    }
+                    visited[*s] = true;
+                    queue.push_back(*s);
+                    let other = triangles[*s];
+                    let k = other.into_iter().find(|k| *k != i && *k != j).unwrap();
+                    if coords[k].is_some() {
+                        return;
+                    }
+                    // the apex is on the left of the shared edge in the order of `other`
+                    let position = other.iter().position(|v| *v == j).unwrap();
+                    let (a, b) = match other[(position + 1) % 3] == i {
+                        true => (j, i),
+                        false => (i, j),
+                    };
+                    let r0 = positions[k].distance(positions[a]);
+                    let r1 = positions[k].distance(positions[b]);
+                    coords[k] = Some(apex(coords[a].unwrap(), coords[b].unwrap(), r0, r1));
+                })
+            })
+        }
+        let coords = coords.into_iter().map(|p| p.unwrap_or(Point2::origin()));
+        match visited.into_iter().all(|visited| visited) {
+            true => Some(coords.collect()),
+            false => None,
+        }
+    }
+
+    fn arap_flattened(&self, iterations: usize) -> Option<Vec<Point2>> {
+        let coords = self.unfolded()?;
+        Some(arap(self.positions(), &triangles(self), coords, iterations))
+    }
+
+    fn strain(&self, coords: &[Point2]) -> f64 {
+        let positions = self.positions();
+        let triangles = triangles(self);
+        let edges = triangles
+            .iter()
+            .flat_map(|tri| (0..3).map(move |n| (tri[n], tri[(n + 1) % 3])));
+        edges.fold(0.0, |max, (i, j)| {
+            let length = positions[i].distance(positions[j]);
+            let error = f64::abs(coords[i].distance(coords[j]) - length);
+            f64::max(max, error / length)
+        })
+    }
+}
@@ -1,29 +1,45 @@
 use super::*;
 use derive_more::{Deref, DerefMut};
-
-#[derive(Clone, Copy, Debug)]
-struct Ray {
-    origin: Point3,
-    direction: Vector3,
-}
+use truck_base::predicates::orient3d;
 
 #[derive(Clone, Copy, Debug, Deref, DerefMut)]
 struct Triangle([Point3; 3]);
 
-impl Triangle {
-    #[inline(always)]
-    fn normal(self) -> Vector3 { (self[1] - self[0]).cross(self[2] - self[0]).normalize() }
+// the generic directions of the infinitesimal translations of the rays breaking the ties
+const PERTURBATIONS: [Vector3; 2] = [
+    Vector3::new(1.0, 0.2718281828459045, 0.5772156649015329),
+    Vector3::new(0.3141592653589793, 1.0, 0.1414213562373095),
+];
 
-    fn is_crossing(self, ray: Ray) -> bool {
-        let a = self[0] - self[1];
-        let b = self[0] - self[2];
-        let mat = Matrix3::from_cols(a, b, ray.direction);
-        if mat.determinant().so_small() {
-            false
-        } else {
-            let inv = mat.invert().unwrap();
-            let uvt = inv * (self[0] - ray.origin);
-            uvt[0] > 0.0 && uvt[1] > 0.0 && uvt[0] + uvt[1] < 1.0 && uvt[2] > 0.0
+impl Triangle {
+    /// Returns the sign of the dot product of the ray and the normal if the ray from `origin`
+    /// through `target` is crossing the triangle, otherwise returns `0`.
+    ///
+    /// The signs are judged by the exact predicates. If the ray passes through an edge or a
+    /// vertex, the ray is regarded as translated by `e * PERTURBATIONS[0] + e^2 *
+    /// PERTURBATIONS[1]` for an infinitesimal `e`, so the crossing is counted by exactly one of
+    /// the triangles sharing the edge or the vertex. The triangles parallel to the ray are not
+    /// crossed.
+    fn crossing_sign(self, origin: Point3, target: Point3) -> isize {
+        let sign = |x: f64| (x > 0.0) as isize - (x < 0.0) as isize;
+        let dir = target - origin;
+        // the side of the ray to the line of the edge from `a` to `b`
+        let side = |a: Point3, b: Point3| match sign(orient3d(origin, target, a, b)) {
+            // the first nonzero term of the expansion in `e`, antisymmetric in `a` and `b`
+            0 => PERTURBATIONS
+                .iter()
+                .map(|vec| -sign(dir.cross(*vec).dot(b - a)))
+                .find(|s| *s != 0)
+                .unwrap_or(0),
+            s => s,
+        };
+        let s0 = side(self[0], self[1]);
+        let s1 = side(self[1], self[2]);
+        let s2 = side(self[2], self[0]);
+        let p = sign(orient3d(self[0], self[1], self[2], origin));
+        match s0 != 0 && s0 == s1 && s1 == s2 && p == -s0 {
+            true => s0,
+            false => 0,
         }
     }
 }
@@ -90,10 +106,7 @@ pub trait IncludingPointInDomain {
 
 impl IncludingPointInDomain for PolygonMesh {
     fn signed_crossing_faces(&self, point: Point3, ray_direction: Vector3) -> isize {
-        let ray = Ray {
-            origin: point,
-            direction: ray_direction,
-        };
+        let target = point + ray_direction;
         self.face_iter().fold(0, |mut counter, face| {
             for i in 2..face.len() {
                 let tri = Triangle([
@@ -101,9 +114,7 @@ impl IncludingPointInDomain for PolygonMesh {
                     self.positions()[face[i - 1].pos],
                     self.positions()[face[i].pos],
                 ]);
-                if tri.is_crossing(ray) {
-                    counter += f64::signum(tri.normal().dot(ray.direction)) as isize;
-                }
+                counter += tri.crossing_sign(point, target);
             }
             counter
         })
@@ -138,3 +149,47 @@ fn inside100() {
         assert!(!simplex.inside(Point3::new(-0.1, 0.1, 0.1)));
     }
 }
+
+#[test]
+fn ray_through_vertices_and_edges() {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter([
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ]);
+    let cube = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let center = Point3::new(0.5, 0.5, 0.5);
+    // through the vertex (1, 1, 1) shared by the six triangles
+    assert_eq!(
+        cube.signed_crossing_faces(center, Vector3::new(1.0, 1.0, 1.0)),
+        1
+    );
+    // through the edge from (1, 1, 0) to (1, 1, 1)
+    assert_eq!(
+        cube.signed_crossing_faces(center, Vector3::new(1.0, 1.0, 0.0)),
+        1
+    );
+    // through the diagonals of the quadrangles
+    assert_eq!(cube.signed_crossing_faces(center, Vector3::unit_x()), 1);
+    assert_eq!(cube.signed_crossing_faces(center, -Vector3::unit_z()), 1);
+    // the outer rays through the two opposite vertices and the diagonals of the quadrangles
+    let outer = Point3::new(2.0, 2.0, 2.0);
+    assert_eq!(
+        cube.signed_crossing_faces(outer, Vector3::new(-1.0, -1.0, -1.0)),
+        0
+    );
+    let outer = Point3::new(-1.0, 0.5, 0.5);
+    assert_eq!(cube.signed_crossing_faces(outer, Vector3::unit_x()), 0);
+}
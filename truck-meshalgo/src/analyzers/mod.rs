@@ -1,14 +1,41 @@
 use crate::*;
 
 mod collision;
+mod curvature;
+mod features;
+mod flattening;
 mod in_out_judge;
+mod parameterization;
 mod point_cloud;
+mod primitive;
+mod registration;
+mod slicing;
 mod splitting;
+mod symmetry;
+mod thickness;
+mod toolpath;
+mod triangle_grid;
 mod topology;
 
 pub use collision::Collision;
+pub use curvature::{Curvature, VertexCurvature};
+pub use features::{FeatureRecognition, MachiningFeature};
+pub use flattening::Flattening;
 pub use in_out_judge::IncludingPointInDomain;
+pub use parameterization::{Parameterization, ParameterizationMethod};
 pub use point_cloud::WithPointCloud;
+pub use primitive::{recognize_surface_primitive, Primitive, PrimitiveRecognition};
+pub use registration::{Registration, RigidMatching};
+#[cfg(feature = "tessellation")]
+pub use slicing::SolidSlicing;
+pub use slicing::{gcode_skeleton, Island, Layer, Slicing};
 pub use splitting::ExperimentalSplitters;
 pub use splitting::Splitting;
+pub use symmetry::{Symmetry, SymmetryDetection};
+#[cfg(feature = "tessellation")]
+pub use thickness::SolidThickness;
+pub use thickness::WallThickness;
+#[cfg(feature = "tessellation")]
+pub use toolpath::SolidMachining;
+pub use toolpath::{toolpath_gcode, Machining};
 pub use topology::Topology;
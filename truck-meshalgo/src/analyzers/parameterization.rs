@@ -0,0 +1,197 @@
+use super::flattening::{arap, conjugate_gradient, local_triangle, triangles};
+use super::*;
+
+/// The methods of the parameterizations of the triangle meshes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterizationMethod {
+    /// the least squares conformal maps (LSCM), preserving the angles
+    Lscm,
+    /// the as-rigid-as-possible (ARAP) iterations from LSCM, preserving the lengths
+    Arap {
+        /// the number of the iterations
+        iterations: usize,
+    },
+}
+
+/// Parameterization of triangle meshes with disk topology
+pub trait Parameterization {
+    /// Returns the UV coordinates of the positions by `method`.
+    ///
+    /// The coordinates are in the scale of the lengths of the mesh. LSCM fixes the two farthest
+    /// points on the longest boundary on the u-axis, and ARAP relaxes the LSCM with the rigid
+    /// motions of the triangles. Returns `None` if the mesh has no boundaries or the triangles are
+    /// not connected.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    ///
+    /// // the paraboloid of the revolution
+    /// let positions: Vec<Point3> = (0..=8)
+    ///     .flat_map(|i| (0..=8).map(move |j| (i as f64 / 8.0 - 0.5, j as f64 / 8.0 - 0.5)))
+    ///     .map(|(x, y)| Point3::new(x, y, x * x + y * y))
+    ///     .collect();
+    /// let faces = (0..8).flat_map(|i| (0..8).map(move |j| i * 9 + j));
+    /// let faces = Faces::from_iter(faces.map(|k| [k, k + 9, k + 10, k + 1]));
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// let uv = mesh.parameterization(ParameterizationMethod::Lscm).unwrap();
+    /// assert!(mesh.conformal_distortion(&uv) < 0.5);
+    /// assert!(mesh.parameterize(ParameterizationMethod::Arap { iterations: 10 }));
+    /// assert_eq!(mesh.uv_coords().len(), mesh.positions().len());
+    /// ```
+    fn parameterization(&self, method: ParameterizationMethod) -> Option<Vec<Vector2>>;
+
+    /// Sets the UV coordinates of the positions by `method` to the vertices.
+    ///
+    /// Returns `false` and does nothing if [`Parameterization::parameterization`] fails.
+    fn parameterize(&mut self, method: ParameterizationMethod) -> bool;
+
+    /// Returns the conformal distortion of the UV coordinates `coords` of the positions: the
+    /// maximum of the ratios of the singular values of the Jacobians of the triangles minus 1.
+    /// Returns the infinity if a triangle is flipped.
+    fn conformal_distortion(&self, coords: &[Vector2]) -> f64;
+}
+
+/// Returns `true` if the triangles are connected by the vertices.
+fn is_connected(triangles: &[[usize; 3]], len: usize) -> bool {
+    let mut parents: Vec<usize> = (0..len).collect();
+    fn root(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+    triangles.iter().for_each(|[i, j, k]| {
+        let r = root(&mut parents, *i);
+        [*j, *k].into_iter().for_each(|j| {
+            let s = root(&mut parents, j);
+            parents[s] = r;
+        })
+    });
+    let first = root(&mut parents, triangles[0][0]);
+    let mut vertices = triangles.iter().flatten();
+    vertices.all(|i| root(&mut parents, *i) == first)
+}
+
+/// Returns the two farthest indices on `boundary` by the double sweeps.
+fn farthest_pair(positions: &[Point3], boundary: &[usize]) -> [usize; 2] {
+    let farthest = |i: usize| {
+        let distance = |j: &&usize| positions[i].distance2(positions[**j]);
+        let compare = |j: &&usize, k: &&usize| distance(j).total_cmp(&distance(k));
+        *boundary.iter().max_by(compare).unwrap()
+    };
+    let i = farthest(boundary[0]);
+    [i, farthest(i)]
+}
+
+/// Returns the least squares conformal map of `triangles`, fixing `i` at the origin and `j` on
+/// the positive u-axis.
+fn lscm(positions: &[Point3], triangles: &[[usize; 3]], [i, j]: [usize; 2]) -> Vec<Vector2> {
+    let len = positions.len();
+    // the opposite edges of the vertices as the complex numbers, weighted by the areas
+    let edges: Vec<([Vector2; 3], f64)> = triangles
+        .iter()
+        .map(|tri| {
+            let x = local_triangle(positions, *tri);
+            let area = x[1].x * x[2].y / 2.0;
+            let edges = [0, 1, 2].map(|n| x[(n + 2) % 3] - x[(n + 1) % 3]);
+            (edges, 1.0 / area)
+        })
+        .collect();
+    let mut free = vec![true; 2 * len];
+    [i, j].into_iter().for_each(|i| {
+        free[2 * i] = false;
+        free[2 * i + 1] = false;
+    });
+    // the normal equation of the conformal energy of the components in `mask`
+    let normal_map = |x: &[f64], mask: bool, res: &mut [f64]| {
+        res.iter_mut().for_each(|r| *r = 0.0);
+        triangles.iter().zip(&edges).for_each(|(tri, (e, w))| {
+            let value = |k: usize| if free[k] == mask { x[k] } else { 0.0 };
+            let (re, im) = tri.iter().zip(e).fold((0.0, 0.0), |(re, im), (i, e)| {
+                let (u, v) = (value(2 * i), value(2 * i + 1));
+                (re + e.x * u - e.y * v, im + e.y * u + e.x * v)
+            });
+            tri.iter().zip(e).for_each(|(i, e)| {
+                res[2 * i] += w * (e.x * re + e.y * im);
+                res[2 * i + 1] += w * (e.x * im - e.y * re);
+            });
+        });
+        res.iter_mut().zip(&free).for_each(|(r, free)| {
+            if !free {
+                *r = 0.0;
+            }
+        });
+    };
+    let mut x = vec![0.0; 2 * len];
+    x[2 * j] = positions[i].distance(positions[j]);
+    let mut rhs = vec![0.0; 2 * len];
+    normal_map(&x, false, &mut rhs);
+    rhs.iter_mut().for_each(|r| *r = -*r);
+    conjugate_gradient(|x, res| normal_map(x, true, res), &rhs, &mut x);
+    let coords = x.chunks(2).map(|uv| Vector2::new(uv[0], uv[1]));
+    coords.collect()
+}
+
+impl Parameterization for PolygonMesh {
+    fn parameterization(&self, method: ParameterizationMethod) -> Option<Vec<Vector2>> {
+        let positions = self.positions();
+        let triangles = triangles(self);
+        let boundaries = self.extract_boundaries();
+        let boundary = boundaries.iter().max_by_key(|boundary| boundary.len())?;
+        if triangles.is_empty() || !is_connected(&triangles, positions.len()) {
+            return None;
+        }
+        let coords = lscm(positions, &triangles, farthest_pair(positions, boundary));
+        match method {
+            ParameterizationMethod::Lscm => Some(coords),
+            ParameterizationMethod::Arap { iterations } => {
+                let coords = coords.into_iter().map(Point2::from_vec).collect();
+                let coords = arap(positions, &triangles, coords, iterations);
+                Some(coords.into_iter().map(|p| p.to_vec()).collect())
+            }
+        }
+    }
+
+    fn parameterize(&mut self, method: ParameterizationMethod) -> bool {
+        let coords = match self.parameterization(method) {
+            Some(coords) => coords,
+            None => return false,
+        };
+        let mut editor = self.editor();
+        let PolygonMeshEditor {
+            attributes, faces, ..
+        } = &mut editor;
+        attributes.uv_coords = coords;
+        let vertices = faces.face_iter_mut().flatten();
+        vertices.for_each(|v| v.uv = Some(v.pos));
+        true
+    }
+
+    fn conformal_distortion(&self, coords: &[Vector2]) -> f64 {
+        let positions = self.positions();
+        triangles(self).into_iter().fold(0.0, |max, tri| {
+            let x = local_triangle(positions, tri);
+            let inverse = match Matrix2::from_cols(x[1], x[2]).invert() {
+                Some(inverse) => inverse,
+                None => return max,
+            };
+            let [u0, u1, u2] = tri.map(|i| coords[i]);
+            let jacobian = Matrix2::from_cols(u1 - u0, u2 - u0) * inverse;
+            let det = jacobian.determinant();
+            if det <= 0.0 {
+                return f64::INFINITY;
+            }
+            let norm2 = jacobian.x.magnitude2() + jacobian.y.magnitude2();
+            let disc = f64::sqrt(f64::max(norm2 * norm2 - 4.0 * det * det, 0.0));
+            f64::max(max, f64::sqrt((norm2 + disc) / (norm2 - disc)) - 1.0)
+        })
+    }
+}
@@ -0,0 +1,529 @@
+use super::*;
+
+const RANSAC_TRIALS: usize = 200;
+const TORUS_SAMPLES: usize = 8;
+const SURFACE_DIVISION: usize = 16;
+
+/// Analytic surfaces recognized from meshes or point clouds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Primitive {
+    /// plane
+    Plane {
+        /// a point on the plane
+        origin: Point3,
+        /// the unit normal of the plane
+        normal: Vector3,
+    },
+    /// sphere
+    Sphere {
+        /// the center of the sphere
+        center: Point3,
+        /// the radius of the sphere
+        radius: f64,
+    },
+    /// cylinder
+    Cylinder {
+        /// a point on the axis
+        origin: Point3,
+        /// the unit direction of the axis
+        axis: Vector3,
+        /// the radius of the cylinder
+        radius: f64,
+    },
+    /// cone
+    Cone {
+        /// the apex of the cone
+        apex: Point3,
+        /// the unit direction of the axis, from the apex to the opening
+        axis: Vector3,
+        /// the angle between the axis and the generating lines
+        half_angle: f64,
+    },
+    /// torus
+    Torus {
+        /// the center of the torus
+        center: Point3,
+        /// the unit direction of the axis
+        axis: Vector3,
+        /// the distance from the center to the center of the tube
+        major_radius: f64,
+        /// the radius of the tube
+        minor_radius: f64,
+    },
+}
+
+impl Primitive {
+    /// Returns the distance between `point` and the surface.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let cylinder = Primitive::Cylinder {
+    ///     origin: Point3::origin(),
+    ///     axis: Vector3::unit_z(),
+    ///     radius: 1.0,
+    /// };
+    /// assert!(cylinder.distance(Point3::new(3.0, 0.0, 5.0)).near(&2.0));
+    /// ```
+    pub fn distance(&self, point: Point3) -> f64 {
+        match *self {
+            Primitive::Plane { origin, normal } => f64::abs((point - origin).dot(normal)),
+            Primitive::Sphere { center, radius } => f64::abs(point.distance(center) - radius),
+            Primitive::Cylinder {
+                origin,
+                axis,
+                radius,
+            } => {
+                let (_, r) = axial_coordinate(point, origin, axis);
+                f64::abs(r - radius)
+            }
+            Primitive::Cone {
+                apex,
+                axis,
+                half_angle,
+            } => {
+                let (h, r) = axial_coordinate(point, apex, axis);
+                let (sin, cos) = half_angle.sin_cos();
+                match r * sin + h * cos >= 0.0 {
+                    true => f64::abs(r * cos - h * sin),
+                    false => point.distance(apex),
+                }
+            }
+            Primitive::Torus {
+                center,
+                axis,
+                major_radius,
+                minor_radius,
+            } => {
+                let (h, r) = axial_coordinate(point, center, axis);
+                f64::abs(f64::sqrt((r - major_radius) * (r - major_radius) + h * h) - minor_radius)
+            }
+        }
+    }
+}
+
+/// Recognizes the analytic primitive surfaces.
+pub trait PrimitiveRecognition {
+    /// Recognizes the primitive which all points of `self` are within the distance `tol` from.
+    ///
+    /// Candidates are searched by RANSAC with the points and normals, and refined by the least
+    /// squares method. The primitives are tried in the order of plane, sphere, cylinder, cone,
+    /// and torus, and the first one fitting all points is returned.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use std::f64::consts::PI;
+    /// // a part of the sphere with center (1, 2, 3) and radius 2
+    /// let center = Point3::new(1.0, 2.0, 3.0);
+    /// let (positions, normals): (Vec<_>, Vec<_>) = (0..10)
+    ///     .flat_map(|i| (1..10).map(move |j| (i, j)))
+    ///     .map(|(i, j)| {
+    ///         let (u, v) = (PI * i as f64 / 10.0, PI * j as f64 / 20.0);
+    ///         let n = Vector3::new(u.cos() * v.sin(), u.sin() * v.sin(), v.cos());
+    ///         (center + 2.0 * n, n)
+    ///     })
+    ///     .unzip();
+    /// let cloud = PointCloud::new(positions, Some(normals), None);
+    ///
+    /// match cloud.recognize_primitive(1.0e-3) {
+    ///     Some(Primitive::Sphere { center: c, radius }) => {
+    ///         assert!(c.distance(center) < 1.0e-3);
+    ///         assert!(f64::abs(radius - 2.0) < 1.0e-3);
+    ///     }
+    ///     got => panic!("wrong primitive: {got:?}"),
+    /// }
+    /// ```
+    fn recognize_primitive(&self, tol: f64) -> Option<Primitive>;
+}
+
+impl PrimitiveRecognition for PointCloud {
+    fn recognize_primitive(&self, tol: f64) -> Option<Primitive> {
+        let normals = self.normals()?;
+        recognize(self.positions(), normals, tol)
+    }
+}
+
+impl PrimitiveRecognition for PolygonMesh {
+    fn recognize_primitive(&self, tol: f64) -> Option<Primitive> {
+        let normals = vertex_normals(self);
+        recognize(self.positions(), &normals, tol)
+    }
+}
+
+/// Recognizes the primitive of the bounded surface, e.g. a B-spline surface of a face.
+///
+/// The surface is sampled by the regular grid on its parameter range.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(1.0, 0.0, 1.0),
+///     Point3::new(0.0, 1.0, 1.0),
+/// );
+/// let bsp = plane.into_bspline();
+/// match recognize_surface_primitive(&bsp, 1.0e-6) {
+///     Some(Primitive::Plane { origin, normal }) => {
+///         assert!(origin.z.near(&1.0));
+///         assert!(normal.z.abs().near(&1.0));
+///     }
+///     got => panic!("wrong primitive: {got:?}"),
+/// }
+/// ```
+pub fn recognize_surface_primitive<S>(surface: &S, tol: f64) -> Option<Primitive>
+where S: ParametricSurface3D + BoundedSurface {
+    let ((u0, u1), (v0, v1)) = surface.parameter_range();
+    let (positions, normals): (Vec<_>, Vec<_>) = (0..=SURFACE_DIVISION)
+        .flat_map(|i| (0..=SURFACE_DIVISION).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let u = u0 + (u1 - u0) * i as f64 / SURFACE_DIVISION as f64;
+            let v = v0 + (v1 - v0) * j as f64 / SURFACE_DIVISION as f64;
+            (surface.subs(u, v), surface.normal(u, v))
+        })
+        .filter(|(p, n)| p.x.is_finite() && n.x.is_finite())
+        .unzip();
+    recognize(&positions, &normals, tol)
+}
+
+pub(super) fn vertex_normals(mesh: &PolygonMesh) -> Vec<Vector3> {
+    let positions = mesh.positions();
+    let mut normals = vec![Vector3::zero(); positions.len()];
+    mesh.faces().triangle_iter().for_each(|tri| {
+        let given = [0, 1, 2].map(|i| tri[i].nor.and_then(|j| mesh.normals().get(j)));
+        let [p0, p1, p2] = [0, 1, 2].map(|i| positions[tri[i].pos]);
+        let normal = (p1 - p0).cross(p2 - p0);
+        (0..3).for_each(|i| match given[i] {
+            Some(n) => normals[tri[i].pos] += *n * normal.magnitude(),
+            None => normals[tri[i].pos] += normal,
+        });
+    });
+    normals
+        .into_iter()
+        .map(|n| match n.so_small() {
+            true => n,
+            false => n.normalize(),
+        })
+        .collect()
+}
+
+/// deterministic pseudo random number generator (xorshift64)
+#[derive(Clone, Copy, Debug)]
+struct Xorshift(u64);
+
+impl Xorshift {
+    #[inline(always)]
+    fn next(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Returns `(h, r)`: the coordinate along the axis and the distance from the axis.
+#[inline(always)]
+pub(super) fn axial_coordinate(point: Point3, origin: Point3, axis: Vector3) -> (f64, f64) {
+    let vec = point - origin;
+    let h = vec.dot(axis);
+    (h, (vec - axis * h).magnitude())
+}
+
+pub(super) fn recognize(points: &[Point3], normals: &[Vector3], tol: f64) -> Option<Primitive> {
+    nonpositive_tolerance!(tol);
+    let samples = points
+        .iter()
+        .zip(normals)
+        .filter(|(_, n)| !n.so_small())
+        .map(|(p, n)| (*p, *n))
+        .collect::<Vec<_>>();
+    if samples.len() < 3 {
+        return None;
+    }
+    let fits = |primitive: &Primitive| points.iter().all(|p| primitive.distance(*p) <= tol);
+    type Hypothesis = fn(&[(Point3, Vector3)], &mut Xorshift) -> Option<Primitive>;
+    type Refinement = fn(&[(Point3, Vector3)]) -> Option<Primitive>;
+    let methods: [(Hypothesis, Refinement); 5] = [
+        (plane_hypothesis, plane_refinement),
+        (sphere_hypothesis, sphere_refinement),
+        (cylinder_hypothesis, cylinder_refinement),
+        (cone_hypothesis, cone_refinement),
+        (torus_hypothesis, torus_refinement),
+    ];
+    methods.into_iter().find_map(|(hypothesis, refinement)| {
+        let mut rng = Xorshift(0x2545_f491_4f6c_dd1d);
+        let count = |primitive: &Primitive| {
+            samples
+                .iter()
+                .filter(|(p, _)| primitive.distance(*p) <= tol)
+                .count()
+        };
+        let (best, _) = (0..RANSAC_TRIALS)
+            .filter_map(|_| hypothesis(&samples, &mut rng))
+            .fold((None, 0), |(best, max), primitive| {
+                let c = count(&primitive);
+                match c > max {
+                    true => (Some(primitive), c),
+                    false => (best, max),
+                }
+            });
+        let best = best?;
+        let inliers = samples
+            .iter()
+            .filter(|(p, _)| best.distance(*p) <= tol)
+            .copied()
+            .collect::<Vec<_>>();
+        match refinement(&inliers) {
+            Some(refined) if fits(&refined) => Some(refined),
+            _ if fits(&best) => Some(best),
+            _ => None,
+        }
+    })
+}
+
+fn plane_hypothesis(samples: &[(Point3, Vector3)], rng: &mut Xorshift) -> Option<Primitive> {
+    let (origin, normal) = samples[rng.next(samples.len())];
+    let normal = normal.normalize();
+    Some(Primitive::Plane { origin, normal })
+}
+
+fn plane_refinement(samples: &[(Point3, Vector3)]) -> Option<Primitive> {
+    let origin = centroid(samples.iter().map(|(p, _)| *p))?;
+    let mut cov = [[0.0; 3]; 3];
+    samples.iter().for_each(|(p, _)| {
+        let d = *p - origin;
+        (0..3).for_each(|i| (0..3).for_each(|j| cov[i][j] += d[i] * d[j]));
+    });
+    let (_, vectors) = symmetric_eigen(cov);
+    let normal = Vector3::new(vectors[0][0], vectors[1][0], vectors[2][0]);
+    Some(Primitive::Plane { origin, normal })
+}
+
+fn sphere_hypothesis(samples: &[(Point3, Vector3)], rng: &mut Xorshift) -> Option<Primitive> {
+    let (p0, n0) = samples[rng.next(samples.len())];
+    let (p1, n1) = samples[rng.next(samples.len())];
+    let (c0, c1) = nearest_points_of_lines((p0, n0), (p1, n1))?;
+    let center = c0.midpoint(c1);
+    let radius = (p0.distance(center) + p1.distance(center)) / 2.0;
+    Some(Primitive::Sphere { center, radius })
+}
+
+fn sphere_refinement(samples: &[(Point3, Vector3)]) -> Option<Primitive> {
+    // |p|^2 = 2 c.p + (r^2 - |c|^2)
+    let mut mat = Matrix4::zero();
+    let mut vec = Vector4::zero();
+    samples.iter().for_each(|(p, _)| {
+        let row = Vector4::new(2.0 * p.x, 2.0 * p.y, 2.0 * p.z, 1.0);
+        mat += outer4(row, row);
+        vec += row * p.to_vec().magnitude2();
+    });
+    let sol = mat.invert()? * vec;
+    let center = Point3::new(sol.x, sol.y, sol.z);
+    let radius2 = sol.w + center.to_vec().magnitude2();
+    match radius2 > 0.0 {
+        true => Some(Primitive::Sphere {
+            center,
+            radius: radius2.sqrt(),
+        }),
+        false => None,
+    }
+}
+
+fn cylinder_hypothesis(samples: &[(Point3, Vector3)], rng: &mut Xorshift) -> Option<Primitive> {
+    let (p0, n0) = samples[rng.next(samples.len())];
+    let (p1, n1) = samples[rng.next(samples.len())];
+    let axis = n0.cross(n1);
+    if axis.so_small() {
+        return None;
+    }
+    let axis = axis.normalize();
+    let project = |n: Vector3| n - axis * n.dot(axis);
+    let (c0, _) = nearest_points_of_lines((p0, project(n0)), (p1, project(n1)))?;
+    let radius = (axial_coordinate(p0, c0, axis).1 + axial_coordinate(p1, c0, axis).1) / 2.0;
+    Some(Primitive::Cylinder {
+        origin: c0,
+        axis,
+        radius,
+    })
+}
+
+fn cylinder_refinement(samples: &[(Point3, Vector3)]) -> Option<Primitive> {
+    let mut cov = [[0.0; 3]; 3];
+    samples.iter().for_each(|(_, n)| {
+        (0..3).for_each(|i| (0..3).for_each(|j| cov[i][j] += n[i] * n[j]));
+    });
+    let (_, vectors) = symmetric_eigen(cov);
+    let axis = Vector3::new(vectors[0][0], vectors[1][0], vectors[2][0]);
+    let e0 = Vector3::new(vectors[0][1], vectors[1][1], vectors[2][1]);
+    let e1 = axis.cross(e0);
+    let coords = samples
+        .iter()
+        .map(|(p, _)| Point2::new(p.to_vec().dot(e0), p.to_vec().dot(e1)))
+        .collect::<Vec<_>>();
+    let (center, radius) = circle_fitting(&coords)?;
+    let origin = Point3::from_vec(e0 * center.x + e1 * center.y);
+    Some(Primitive::Cylinder {
+        origin,
+        axis,
+        radius,
+    })
+}
+
+fn cone_hypothesis(samples: &[(Point3, Vector3)], rng: &mut Xorshift) -> Option<Primitive> {
+    let [(p0, n0), (p1, n1), (p2, n2)] = [0, 1, 2].map(|_| samples[rng.next(samples.len())]);
+    let mat = Matrix3::from_cols(n0, n1, n2).transpose();
+    let rhs = Vector3::new(
+        n0.dot(p0.to_vec()),
+        n1.dot(p1.to_vec()),
+        n2.dot(p2.to_vec()),
+    );
+    let apex = Point3::from_vec(mat.invert()? * rhs);
+    let dirs = [p0, p1, p2].map(|p| p - apex);
+    if dirs.iter().any(|d| d.so_small()) {
+        return None;
+    }
+    let [u0, u1, u2] = dirs.map(|d| d.normalize());
+    let axis = (u1 - u0).cross(u2 - u0);
+    if axis.so_small() {
+        return None;
+    }
+    let axis = match axis.dot(u0) < 0.0 {
+        true => -axis.normalize(),
+        false => axis.normalize(),
+    };
+    let half_angle = (axis.angle(u0).0 + axis.angle(u1).0 + axis.angle(u2).0) / 3.0;
+    Some(Primitive::Cone {
+        apex,
+        axis,
+        half_angle,
+    })
+}
+
+fn cone_refinement(samples: &[(Point3, Vector3)]) -> Option<Primitive> {
+    let (origin, axis) = common_axis(samples)?;
+    let coords = samples
+        .iter()
+        .map(|(p, _)| axial_coordinate(*p, origin, axis))
+        .collect::<Vec<_>>();
+    // r = a h + b
+    let n = coords.len() as f64;
+    let (sh, sr, shh, shr) = coords.iter().fold((0.0, 0.0, 0.0, 0.0), |s, (h, r)| {
+        (s.0 + h, s.1 + r, s.2 + h * h, s.3 + h * r)
+    });
+    let det = n * shh - sh * sh;
+    if det.so_small() {
+        return None;
+    }
+    let a = (n * shr - sh * sr) / det;
+    let b = (sr - a * sh) / n;
+    if a.so_small() {
+        return None;
+    }
+    let apex = origin - axis * (b / a);
+    let axis = match a > 0.0 {
+        true => axis,
+        false => -axis,
+    };
+    Some(Primitive::Cone {
+        apex,
+        axis,
+        half_angle: f64::atan(a.abs()),
+    })
+}
+
+fn torus_hypothesis(samples: &[(Point3, Vector3)], rng: &mut Xorshift) -> Option<Primitive> {
+    let subset = (0..TORUS_SAMPLES)
+        .map(|_| samples[rng.next(samples.len())])
+        .collect::<Vec<_>>();
+    torus_refinement(&subset)
+}
+
+fn torus_refinement(samples: &[(Point3, Vector3)]) -> Option<Primitive> {
+    let (origin, axis) = common_axis(samples)?;
+    let coords = samples
+        .iter()
+        .map(|(p, _)| {
+            let (h, r) = axial_coordinate(*p, origin, axis);
+            Point2::new(r, h)
+        })
+        .collect::<Vec<_>>();
+    let (center, minor_radius) = circle_fitting(&coords)?;
+    match center.x > minor_radius {
+        true => Some(Primitive::Torus {
+            center: origin + axis * center.y,
+            axis,
+            major_radius: center.x,
+            minor_radius,
+        }),
+        false => None,
+    }
+}
+
+#[inline(always)]
+fn centroid(iter: impl IntoIterator<Item = Point3>) -> Option<Point3> {
+    let (sum, n) = iter
+        .into_iter()
+        .fold((Vector3::zero(), 0), |(sum, n), p| (sum + p.to_vec(), n + 1));
+    match n {
+        0 => None,
+        _ => Some(Point3::from_vec(sum / n as f64)),
+    }
+}
+
+#[inline(always)]
+fn outer4(a: Vector4, b: Vector4) -> Matrix4 { Matrix4::from_cols(a * b[0], a * b[1], a * b[2], a * b[3]) }
+
+/// the nearest points of two lines `p0 + s n0` and `p1 + t n1`.
+fn nearest_points_of_lines(
+    (p0, n0): (Point3, Vector3),
+    (p1, n1): (Point3, Vector3),
+) -> Option<(Point3, Point3)> {
+    let (a, b, c) = (n0.dot(n0), n0.dot(n1), n1.dot(n1));
+    let w = p0 - p1;
+    let (d, e) = (n0.dot(w), n1.dot(w));
+    let det = a * c - b * b;
+    if det.so_small() {
+        return None;
+    }
+    let s = (b * e - c * d) / det;
+    let t = (a * e - b * d) / det;
+    Some((p0 + n0 * s, p1 + n1 * t))
+}
+
+/// the line intersecting all normal lines, in the least squares sense.
+/// The normal lines of the surfaces of revolution intersect the axis.
+fn common_axis(samples: &[(Point3, Vector3)]) -> Option<(Point3, Vector3)> {
+    // the Plücker coordinates (d, m) of the axis satisfy d.m_i + m.n_i = 0,
+    // where (n_i, m_i) is the Plücker coordinates of the i-th normal line.
+    let mut mat = [[0.0; 6]; 6];
+    samples.iter().for_each(|(p, n)| {
+        let m = p.to_vec().cross(*n);
+        let row = [m.x, m.y, m.z, n.x, n.y, n.z];
+        (0..6).for_each(|i| (0..6).for_each(|j| mat[i][j] += row[i] * row[j]));
+    });
+    let (_, vectors) = symmetric_eigen(mat);
+    let d = Vector3::new(vectors[0][0], vectors[1][0], vectors[2][0]);
+    let m = Vector3::new(vectors[3][0], vectors[4][0], vectors[5][0]);
+    if d.so_small() {
+        return None;
+    }
+    let origin = Point3::from_vec(d.cross(m) / d.magnitude2());
+    Some((origin, d.normalize()))
+}
+
+/// algebraic circle fitting: |p|^2 = 2 c.p + (r^2 - |c|^2)
+fn circle_fitting(points: &[Point2]) -> Option<(Point2, f64)> {
+    let mut mat = Matrix3::zero();
+    let mut vec = Vector3::zero();
+    points.iter().for_each(|p| {
+        let row = Vector3::new(2.0 * p.x, 2.0 * p.y, 1.0);
+        mat += Matrix3::from_cols(row * row[0], row * row[1], row * row[2]);
+        vec += row * p.to_vec().magnitude2();
+    });
+    let sol = mat.invert()? * vec;
+    let center = Point2::new(sol.x, sol.y);
+    let radius2 = sol.z + center.to_vec().magnitude2();
+    match radius2 > 0.0 {
+        true => Some((center, radius2.sqrt())),
+        false => None,
+    }
+}
@@ -0,0 +1,392 @@
+use super::*;
+use super::triangle_grid::TriangleGrid;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_base::kd_tree::KdTree;
+use truck_topology::Solid;
+
+/// Registration of point clouds to polygon meshes.
+pub trait Registration {
+    /// Registers `point_cloud` to the polygon mesh `self` by the point-to-point ICP
+    /// (iterative closest point) method.
+    ///
+    /// Returns the rigid transformation which moves `point_cloud` onto the mesh,
+    /// and the root mean square of the distances between the moved points and the mesh.
+    /// The iteration stops if the improvement of the error is less than `tol` or
+    /// the number of iterations reaches `max_iter`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // unit cube
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: positions.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 2, 3, 1],
+    ///         [4, 5, 7, 6],
+    ///         [0, 1, 5, 4],
+    ///         [2, 6, 7, 3],
+    ///         [0, 4, 6, 2],
+    ///         [1, 3, 7, 5],
+    ///     ]),
+    /// );
+    ///
+    /// let mat = Matrix4::from_translation(Vector3::new(0.05, 0.03, -0.02))
+    ///     * Matrix4::from_angle_z(Rad(0.04));
+    /// let mut cloud = PointCloud::from_positions(positions);
+    /// cloud.transform_by(mat);
+    ///
+    /// let (transform, error) = mesh.icp_registration(&cloud, 100, 1.0e-12);
+    /// assert!(error < 1.0e-6);
+    /// cloud.transform_by(transform);
+    /// assert!(cloud.positions()[1].distance(Point3::new(1.0, 0.0, 0.0)) < 1.0e-4);
+    /// ```
+    fn icp_registration(
+        &self,
+        point_cloud: &PointCloud,
+        max_iter: usize,
+        tol: f64,
+    ) -> (Matrix4, f64);
+}
+
+impl Registration for PolygonMesh {
+    fn icp_registration(
+        &self,
+        point_cloud: &PointCloud,
+        max_iter: usize,
+        tol: f64,
+    ) -> (Matrix4, f64) {
+        let grid = TriangleGrid::from_mesh(self);
+        let mut points = point_cloud.positions().clone();
+        let mut transform = Matrix4::identity();
+        let mut error = f64::INFINITY;
+        if grid.triangles().is_empty() || points.is_empty() {
+            return (transform, error);
+        }
+        for _ in 0..max_iter {
+            let targets = points
+                .iter()
+                .map(|p| grid.closest_point(*p))
+                .collect::<Vec<_>>();
+            let new_error = rms(&points, &targets);
+            if error - new_error < tol {
+                error = f64::min(error, new_error);
+                break;
+            }
+            error = new_error;
+            let step = best_rigid_transform(&points, &targets);
+            points.iter_mut().for_each(|p| *p = step.transform_point(*p));
+            transform = step * transform;
+        }
+        (transform, error)
+    }
+}
+
+/// Detection of the rigid transformations between congruent solids.
+pub trait RigidMatching {
+    /// Computes the rigid transformation which moves `self` onto the congruent solid `other`.
+    ///
+    /// The vertices and the middle points of the edges are sampled, and each sample is described
+    /// by its distance from the centroid of the vertices and the lengths of the incident chords.
+    /// The feature-matched triples of the samples give the candidate transformations, and the
+    /// first one moving every sample of `self` within `tol` of a sample of `other` is refined by
+    /// the least squares over all the correspondences.
+    ///
+    /// Returns the transformation and the root mean square of the distances between the moved
+    /// samples and their correspondences. Returns `None` if the numbers of the vertices or the
+    /// edges differ, or if no transformation is found. Mirror images are not congruent here.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let solid = builder::cuboid(Point3::origin(), Point3::new(3.0, 1.0, 2.0));
+    /// let mat = Matrix4::from_translation(Vector3::new(1.0, -2.0, 0.5))
+    ///     * Matrix4::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Rad(0.5));
+    /// let moved = builder::transformed(&solid, mat);
+    ///
+    /// let (transform, error) = solid.rigid_transform_to(&moved, 1.0e-6).unwrap();
+    /// assert!(error < 1.0e-6);
+    /// let p = Point3::new(3.0, 1.0, 0.0);
+    /// assert!(transform.transform_point(p).near(&mat.transform_point(p)));
+    ///
+    /// let other = builder::cuboid(Point3::origin(), Point3::new(3.0, 1.5, 2.0));
+    /// assert!(solid.rigid_transform_to(&other, 1.0e-6).is_none());
+    /// ```
+    fn rigid_transform_to(&self, other: &Self, tol: f64) -> Option<(Matrix4, f64)>;
+}
+
+impl<C, S> RigidMatching for Solid<Point3, C, S>
+where C: BoundedCurve<Point = Point3> + Clone
+{
+    fn rigid_transform_to(&self, other: &Self, tol: f64) -> Option<(Matrix4, f64)> {
+        nonpositive_tolerance!(tol);
+        let (samples0, samples1) = (Samples::new(self), Samples::new(other));
+        if samples0.vertices != samples1.vertices || samples0.len() != samples1.len() {
+            return None;
+        }
+        if samples0.len() == 0 {
+            return Some((Matrix4::identity(), 0.0));
+        }
+        let tree = PointTree::new(&samples1.points, tol);
+        let close = |x: f64, y: f64| f64::abs(x - y) <= 2.0 * tol;
+        let candidates = |i: usize| samples1.candidates(&samples0, i, tol);
+        let [a, b, c] = samples0.base_triple(|i| candidates(i).count())?;
+        let (p0, p1) = (&samples0.points, &samples1.points);
+        let dist0 = |i: usize, j: usize| p0[i].distance(p0[j]);
+        let dist1 = |i: usize, j: usize| p1[i].distance(p1[j]);
+        for a1 in candidates(a) {
+            let bs = candidates(b).filter(|b1| close(dist0(a, b), dist1(a1, *b1)));
+            for b1 in bs {
+                let cs = candidates(c).filter(|c1| {
+                    close(dist0(a, c), dist1(a1, *c1)) && close(dist0(b, c), dist1(b1, *c1))
+                });
+                for c1 in cs {
+                    let targets = [p1[a1], p1[b1], p1[c1]];
+                    let mat = best_rigid_transform(&[p0[a], p0[b], p0[c]], &targets);
+                    let correspondence = p0
+                        .iter()
+                        .enumerate()
+                        .map(|(i, p)| {
+                            let q = mat.transform_point(*p);
+                            tree.find(q, |j| samples0.is_vertex(i) == samples1.is_vertex(j))
+                        })
+                        .collect::<Option<Vec<_>>>();
+                    if let Some(correspondence) = correspondence {
+                        let targets = correspondence.iter().map(|j| p1[*j]).collect::<Vec<_>>();
+                        let mat = best_rigid_transform(p0, &targets);
+                        let moved = p0.iter().map(|p| mat.transform_point(*p));
+                        return Some((mat, rms(&moved.collect::<Vec<_>>(), &targets)));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// the sample points with their features invariant under rigid transformations
+///
+/// The last feature of each sample is the distance from the centroid.
+#[derive(Clone, Debug)]
+pub(super) struct Samples {
+    /// the vertices are the first `vertices` points
+    vertices: usize,
+    pub(super) points: Vec<Point3>,
+    features: Vec<Vec<f64>>,
+    pub(super) centroid: Point3,
+    /// the indices of the samples sorted by the distances from the centroid
+    order: Vec<usize>,
+}
+
+impl Samples {
+    /// the vertices and the middle points of the edges of the solid
+    pub(super) fn new<C, S>(solid: &Solid<Point3, C, S>) -> Self
+    where C: BoundedCurve<Point = Point3> + Clone {
+        let mut indices = HashMap::default();
+        let mut points = Vec::new();
+        solid.vertex_iter().for_each(|v| {
+            indices.entry(v.id()).or_insert_with(|| {
+                points.push(v.point());
+                points.len() - 1
+            });
+        });
+        let vertices = points.len();
+        let centroid = match vertices {
+            0 => Point3::origin(),
+            _ => Point3::centroid(&points),
+        };
+        let mut features = vec![Vec::new(); vertices];
+        let mut visited = HashSet::default();
+        solid.edge_iter().for_each(|edge| {
+            if !visited.insert(edge.id()) {
+                return;
+            }
+            let curve = edge.curve();
+            let (t0, t1) = curve.parameter_range();
+            let mid = curve.subs((t0 + t1) / 2.0);
+            let (front, back) = edge.absolute_ends();
+            let (i, j) = (indices[&front.id()], indices[&back.id()]);
+            let mut halves = [mid.distance(points[i]), mid.distance(points[j])];
+            halves.sort_by(f64::total_cmp);
+            features[i].push(halves[0] + halves[1]);
+            features[j].push(halves[0] + halves[1]);
+            features.push(vec![halves[0], halves[1]]);
+            points.push(mid);
+        });
+        features.iter_mut().zip(&points).for_each(|(feature, p)| {
+            feature.sort_by(f64::total_cmp);
+            feature.push(p.distance(centroid));
+        });
+        Samples::with_features(vertices, points, features, centroid)
+    }
+
+    /// the points whose features are only the distances from the centroid
+    pub(super) fn from_points(points: Vec<Point3>) -> Self {
+        let centroid = match points.is_empty() {
+            true => Point3::origin(),
+            false => Point3::centroid(&points),
+        };
+        let features = points.iter().map(|p| vec![p.distance(centroid)]).collect();
+        Samples::with_features(points.len(), points, features, centroid)
+    }
+
+    fn with_features(
+        vertices: usize,
+        points: Vec<Point3>,
+        features: Vec<Vec<f64>>,
+        centroid: Point3,
+    ) -> Self {
+        let mut order = (0..points.len()).collect::<Vec<_>>();
+        order.sort_by(|i, j| Self::radius(&features[*i]).total_cmp(&Self::radius(&features[*j])));
+        Samples {
+            vertices,
+            points,
+            features,
+            centroid,
+            order,
+        }
+    }
+
+    #[inline(always)]
+    fn radius(feature: &[f64]) -> f64 { feature[feature.len() - 1] }
+
+    #[inline(always)]
+    pub(super) fn len(&self) -> usize { self.points.len() }
+
+    #[inline(always)]
+    pub(super) fn is_vertex(&self, i: usize) -> bool { i < self.vertices }
+
+    /// Returns the samples of `self` whose features are within `2 * tol` of the `i`-th sample
+    /// of `other`.
+    pub(super) fn candidates<'b>(
+        &'b self,
+        other: &'b Samples,
+        i: usize,
+        tol: f64,
+    ) -> impl Iterator<Item = usize> + 'b {
+        let feature = &other.features[i];
+        let radius = Self::radius(feature);
+        let radius_of = |j: &usize| Self::radius(&self.features[*j]);
+        let order = &self.order;
+        let start = order.partition_point(|j| radius_of(j) < radius - 2.0 * tol);
+        let end = order.partition_point(|j| radius_of(j) <= radius + 2.0 * tol);
+        order[start..end].iter().copied().filter(move |j| {
+            self.is_vertex(*j) == other.is_vertex(i)
+                && self.features[*j].len() == feature.len()
+                && self.features[*j]
+                    .iter()
+                    .zip(feature)
+                    .all(|(x, y)| f64::abs(x - y) <= 2.0 * tol)
+        })
+    }
+
+    /// Returns the samples with the fewest candidates among at most `MAX_ANCHORS` evenly spaced
+    /// samples, in ascending order of the numbers of the candidates.
+    pub(super) fn anchors(&self, candidates: impl Fn(usize) -> usize) -> Vec<usize> {
+        const MAX_ANCHORS: usize = 64;
+        let step = usize::max(self.len() / MAX_ANCHORS, 1);
+        let mut anchors = (0..self.len())
+            .step_by(step)
+            .map(|i| (candidates(i), i))
+            .collect::<Vec<_>>();
+        anchors.sort();
+        anchors.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// The first sample has the fewest candidates, the second one is the farthest from the first,
+    /// and the third one is the farthest from the line through them.
+    fn base_triple(&self, candidates: impl Fn(usize) -> usize) -> Option<[usize; 3]> {
+        let max_by = |f: &dyn Fn(&Point3) -> f64| {
+            let iter = self.points.iter().enumerate();
+            let (i, _) = iter.max_by(|(_, p), (_, q)| f(p).total_cmp(&f(q)))?;
+            Some(i)
+        };
+        let a = *self.anchors(candidates).first()?;
+        let a_point = self.points[a];
+        let b = max_by(&|p| p.distance2(a_point))?;
+        let dir = self.points[b] - a_point;
+        let c = max_by(&|p| dir.cross(p - a_point).magnitude2())?;
+        let area2 = dir.cross(self.points[c] - a_point).magnitude2();
+        match area2 > TOLERANCE2 * dir.magnitude2() {
+            true => Some([a, b, c]),
+            false => None,
+        }
+    }
+}
+
+/// kd-tree of points for searching the points within the tolerance
+#[derive(Clone, Debug)]
+pub(super) struct PointTree {
+    tree: KdTree<Point3>,
+    tol: f64,
+}
+
+impl PointTree {
+    #[inline(always)]
+    pub(super) fn new(points: &[Point3], tol: f64) -> Self {
+        let tree = KdTree::new(points.to_vec());
+        PointTree { tree, tol }
+    }
+
+    /// Returns the nearest point within the tolerance satisfying `filter`.
+    pub(super) fn find(&self, p: Point3, filter: impl Fn(usize) -> bool) -> Option<usize> {
+        let points = self.tree.points();
+        let candidates = self.tree.within_radius(p, self.tol).into_iter();
+        candidates
+            .filter(|i| filter(*i))
+            .map(|i| (i, points[i].distance2(p)))
+            .min_by(|(_, d0), (_, d1)| d0.total_cmp(d1))
+            .map(|(i, _)| i)
+    }
+}
+
+#[inline(always)]
+fn rms(points: &[Point3], targets: &[Point3]) -> f64 {
+    let sum = points
+        .iter()
+        .zip(targets)
+        .fold(0.0, |sum, (p, q)| sum + p.distance2(*q));
+    f64::sqrt(sum / points.len() as f64)
+}
+
+/// Horn's closed form solution by unit quaternions
+fn best_rigid_transform(points: &[Point3], targets: &[Point3]) -> Matrix4 {
+    let n = points.len() as f64;
+    let centroid = |pts: &[Point3]| pts.iter().fold(Vector3::zero(), |s, p| s + p.to_vec()) / n;
+    let (p0, q0) = (centroid(points), centroid(targets));
+    let mut s = [[0.0; 3]; 3];
+    points.iter().zip(targets).for_each(|(p, q)| {
+        let (p, q) = (p.to_vec() - p0, q.to_vec() - q0);
+        (0..3).for_each(|i| (0..3).for_each(|j| s[i][j] += p[i] * q[j]));
+    });
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+    let mat = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+    let (_, vectors) = symmetric_eigen(mat);
+    let quat = Vector4::new(vectors[0][3], vectors[1][3], vectors[2][3], vectors[3][3]).normalize();
+    let (w, x, y, z) = (quat[0], quat[1], quat[2], quat[3]);
+    let rotation = Matrix3::new(
+        1.0 - 2.0 * (y * y + z * z),
+        2.0 * (x * y + w * z),
+        2.0 * (x * z - w * y),
+        2.0 * (x * y - w * z),
+        1.0 - 2.0 * (x * x + z * z),
+        2.0 * (y * z + w * x),
+        2.0 * (x * z + w * y),
+        2.0 * (y * z - w * x),
+        1.0 - 2.0 * (x * x + y * y),
+    );
+    let translation = q0 - rotation * p0;
+    Matrix4::from_translation(translation) * Matrix4::from(rotation)
+}
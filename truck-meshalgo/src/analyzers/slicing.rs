@@ -0,0 +1,327 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::fmt::Write;
+use truck_polymesh::planar::{self, JoinType};
+#[cfg(feature = "tessellation")]
+use crate::tessellation::*;
+#[cfg(feature = "tessellation")]
+use truck_topology::Solid;
+
+/// A connected region of a layer: the outer contour and its holes.
+///
+/// The outer contour is counter-clockwise and the holes are clockwise,
+/// i.e. the inside of the region is always on the left side of contours.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Island {
+    outer: Vec<Point2>,
+    holes: Vec<Vec<Point2>>,
+}
+
+/// A planar section of the shape.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Layer {
+    height: f64,
+    islands: Vec<Island>,
+}
+
+/// Slices polygon meshes by horizontal planes.
+pub trait Slicing {
+    /// Slices the mesh into layers with thickness `layer_height` along the z-axis.
+    ///
+    /// Each layer is the section by the plane at the middle height of the layer.
+    /// The mesh is assumed to be watertight and oriented outward. Positions at the same place are
+    /// regarded as the same vertex, and the sections which are not closed are removed.
+    /// # Panics
+    /// `layer_height` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the unit cube
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 2, 3, 1],
+    ///         [4, 5, 7, 6],
+    ///         [0, 1, 5, 4],
+    ///         [2, 6, 7, 3],
+    ///         [0, 4, 6, 2],
+    ///         [1, 3, 7, 5],
+    ///     ]),
+    /// );
+    ///
+    /// let layers = mesh.slice(0.25);
+    /// assert_eq!(layers.len(), 4);
+    /// assert!(layers[0].height().near(&0.125));
+    /// let island = &layers[0].islands()[0];
+    /// assert!(island.holes().is_empty());
+    /// assert!(island.area().near(&1.0));
+    ///
+    /// let perimeters = island.perimeters(0.15, 3);
+    /// assert_eq!(perimeters.len(), 3);
+    /// // the second perimeter is the square with size 0.55
+    /// perimeters[1].iter().for_each(|p| {
+    ///     assert!(f64::abs(p.x - 0.5).near(&0.275) || f64::abs(p.y - 0.5).near(&0.275));
+    /// });
+    /// // the fourth perimeter collapses
+    /// assert_eq!(island.perimeters(0.15, 4).len(), 3);
+    ///
+    /// let gcode = gcode_skeleton(&layers, 0.1, 2);
+    /// assert_eq!(gcode.lines().filter(|line| line.starts_with("; layer")).count(), 4);
+    /// assert!(gcode.contains("G1 X"));
+    /// ```
+    fn slice(&self, layer_height: f64) -> Vec<Layer>;
+}
+
+/// Slices solids by horizontal planes.
+#[cfg(feature = "tessellation")]
+pub trait SolidSlicing {
+    /// Tessellates the solid with tolerance `tol`, and slices the mesh by [`Slicing::slice`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// // a unit cube with a hole of radius 0.2 through it
+    /// let v = builder::vertex(Point3::new(-0.5, -0.5, 0.0));
+    /// let edge = builder::tsweep(&v, Vector3::unit_x());
+    /// let mut face = builder::tsweep(&edge, Vector3::unit_y());
+    /// let v = builder::vertex(Point3::new(0.2, 0.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    /// face.add_boundary(circle.inverse());
+    /// let solid = builder::tsweep(&face, Vector3::unit_z());
+    ///
+    /// let layers = solid.slice(0.1, 0.001);
+    /// assert_eq!(layers.len(), 10);
+    /// layers.iter().for_each(|layer| {
+    ///     assert_eq!(layer.islands().len(), 1);
+    ///     let island = &layer.islands()[0];
+    ///     assert_eq!(island.holes().len(), 1);
+    ///     let area = 1.0 - std::f64::consts::PI * 0.04;
+    ///     assert!(f64::abs(island.area() - area) < 0.01);
+    /// });
+    /// ```
+    fn slice(&self, layer_height: f64, tol: f64) -> Vec<Layer>;
+}
+
+impl Island {
+    /// Returns the outer contour.
+    #[inline(always)]
+    pub fn outer(&self) -> &Vec<Point2> { &self.outer }
+    /// Returns the holes.
+    #[inline(always)]
+    pub fn holes(&self) -> &Vec<Vec<Point2>> { &self.holes }
+    /// Returns the area of the island.
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        self.holes
+            .iter()
+            .fold(planar::signed_area(&self.outer), |sum, hole| {
+                sum + planar::signed_area(hole)
+            })
+    }
+    /// Returns the contours of the perimeters with extrusion width `width`.
+    ///
+    /// The `i`-th perimeter is obtained by offsetting all contours inward by `(i + 0.5) * width`.
+    /// The offset is done by [`planar::offset`] with the miter joins, so the collapsed parts are
+    /// removed and the colliding parts are merged.
+    /// # Examples
+    /// See the example of [`Slicing::slice`].
+    pub fn perimeters(&self, width: f64, count: usize) -> Vec<Vec<Point2>> {
+        let contours = std::iter::once(&self.outer)
+            .chain(&self.holes)
+            .cloned()
+            .collect::<Vec<_>>();
+        (0..count)
+            .flat_map(|i| {
+                let distance = (i as f64 + 0.5) * width;
+                planar::offset(&contours, -distance, JoinType::Miter)
+            })
+            .collect()
+    }
+}
+
+impl Layer {
+    /// Returns the height of the section plane.
+    #[inline(always)]
+    pub fn height(&self) -> f64 { self.height }
+    /// Returns the islands of the layer.
+    #[inline(always)]
+    pub fn islands(&self) -> &Vec<Island> { &self.islands }
+}
+
+/// Outputs the skeleton of G-code which traces the perimeters of the layers.
+///
+/// The perimeters are computed by [`Island::perimeters`] with `width` and `count`.
+/// The value of the axis `E` is the accumulated length of the extruded paths. It should be scaled
+/// according to the actual filament and nozzle.
+/// # Examples
+/// See the example of [`Slicing::slice`].
+pub fn gcode_skeleton(layers: &[Layer], width: f64, count: usize) -> String {
+    let mut gcode = String::from("G21\nG90\n");
+    let mut extrusion = 0.0;
+    layers.iter().enumerate().for_each(|(i, layer)| {
+        writeln!(gcode, "; layer {i}").unwrap();
+        writeln!(gcode, "G0 Z{:.4}", layer.height).unwrap();
+        layer.islands.iter().for_each(|island| {
+            island.perimeters(width, count).into_iter().for_each(|contour| {
+                writeln!(gcode, "G0 X{:.4} Y{:.4}", contour[0].x, contour[0].y).unwrap();
+                contour
+                    .iter()
+                    .zip(contour.iter().cycle().skip(1))
+                    .for_each(|(p, q)| {
+                        extrusion += p.distance(*q);
+                        writeln!(gcode, "G1 X{:.4} Y{:.4} E{:.5}", q.x, q.y, extrusion).unwrap();
+                    });
+            })
+        })
+    });
+    gcode
+}
+
+impl Slicing for PolygonMesh {
+    fn slice(&self, layer_height: f64) -> Vec<Layer> {
+        nonpositive_tolerance!(layer_height);
+        // identify the positions at the same place
+        let mut indices = HashMap::<[i64; 3], usize>::default();
+        let welded = self
+            .positions()
+            .iter()
+            .map(|p| {
+                let key = [0, 1, 2].map(|i| f64::round(p[i] / TOLERANCE) as i64);
+                let len = indices.len();
+                *indices.entry(key).or_insert(len)
+            })
+            .collect::<Vec<_>>();
+        let triangles = self
+            .faces()
+            .triangle_iter()
+            .map(|tri| [tri[0].pos, tri[1].pos, tri[2].pos])
+            .collect::<Vec<_>>();
+        let bdd = self.bounding_box();
+        if triangles.is_empty() {
+            return Vec::new();
+        }
+        let (z0, z1) = (bdd.min().z, bdd.max().z);
+        let number = f64::ceil((z1 - z0) / layer_height - TOLERANCE) as usize;
+        (0..number)
+            .map(|k| {
+                let height = z0 + (k as f64 + 0.5) * layer_height;
+                let contours = section(self.positions(), &welded, &triangles, height);
+                Layer {
+                    height,
+                    islands: islands(contours),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "tessellation")]
+impl<C: PolylineableCurve, S: MeshableSurface> SolidSlicing for Solid<Point3, C, S> {
+    fn slice(&self, layer_height: f64, tol: f64) -> Vec<Layer> {
+        self.triangulation(tol).to_polygon().slice(layer_height)
+    }
+}
+
+/// the closed section contours of triangles by the plane `z = height`
+fn section(
+    positions: &[Point3],
+    welded: &[usize],
+    triangles: &[[usize; 3]],
+    height: f64,
+) -> Vec<Vec<Point2>> {
+    let above = |i: usize| positions[i].z > height;
+    let crossing = |i: usize, j: usize| {
+        let (p, q) = (positions[i], positions[j]);
+        let t = (height - p.z) / (q.z - p.z);
+        let key = (
+            usize::min(welded[i], welded[j]),
+            usize::max(welded[i], welded[j]),
+        );
+        (key, Point2::new(p.x + (q.x - p.x) * t, p.y + (q.y - p.y) * t))
+    };
+    // the map from the start of each segment to its end
+    let mut segments = HashMap::default();
+    triangles.iter().for_each(|tri| {
+        let crossings = (0..3)
+            .filter(|i| above(tri[*i]) != above(tri[(*i + 1) % 3]))
+            .map(|i| crossing(tri[i], tri[(i + 1) % 3]))
+            .collect::<Vec<_>>();
+        if crossings.len() != 2 {
+            return;
+        }
+        let [p0, p1, p2] = tri.map(|i| positions[i]);
+        let normal = (p1 - p0).cross(p2 - p0);
+        // the inside of the section is on the left side of the contour
+        let dir = Vector2::new(-normal.y, normal.x);
+        let (a, b) = (crossings[0], crossings[1]);
+        match (b.1 - a.1).dot(dir) >= 0.0 {
+            true => segments.insert(a.0, b),
+            false => segments.insert(b.0, a),
+        };
+    });
+    let mut contours = Vec::new();
+    while let Some(start) = segments.keys().next().copied() {
+        let mut contour = Vec::new();
+        let mut cursor = start;
+        let closed = loop {
+            match segments.remove(&cursor) {
+                Some((next, point)) => {
+                    let duplicated = matches!(
+                        contour.last(),
+                        Some(last) if Point2::distance(*last, point) <= TOLERANCE
+                    );
+                    if !duplicated {
+                        contour.push(point);
+                    }
+                    cursor = next;
+                    if cursor == start {
+                        break true;
+                    }
+                }
+                None => break false,
+            }
+        };
+        if closed && contour.len() > 2 {
+            if contour[0].distance(contour[contour.len() - 1]) <= TOLERANCE {
+                contour.pop();
+            }
+            contours.push(contour);
+        }
+    }
+    contours
+}
+
+/// classifies contours into outer contours and holes, and gathers holes into the smallest outer contours
+fn islands(contours: Vec<Vec<Point2>>) -> Vec<Island> {
+    let (outers, holes): (Vec<_>, Vec<_>) = contours
+        .into_iter()
+        .filter(|contour| contour.len() > 2)
+        .partition(|contour| planar::signed_area(contour) > 0.0);
+    let mut islands = outers
+        .into_iter()
+        .map(|outer| Island {
+            outer,
+            holes: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+    holes.into_iter().for_each(|hole| {
+        let parent = islands
+            .iter_mut()
+            .filter(|island| planar::contains(std::slice::from_ref(&island.outer), hole[0]))
+            .min_by(|a, b| {
+                let (a, b) = (planar::signed_area(&a.outer), planar::signed_area(&b.outer));
+                a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+            });
+        if let Some(island) = parent {
+            island.holes.push(hole);
+        }
+    });
+    islands
+}
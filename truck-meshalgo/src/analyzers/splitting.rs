@@ -1,4 +1,5 @@
 use super::*;
+use rustc_hash::FxHashMap as HashMap;
 use std::f64::consts::PI;
 
 /// Splitting the faces into several clusters.
@@ -128,6 +129,47 @@ pub trait Splitting {
     /// assert_eq!(components.len(), 1);
     /// ```
     fn components(&self, use_normal: bool) -> Vec<Vec<usize>>;
+    /// Segments the faces into the near-planar and the cylindrical regions by region growing,
+    /// and returns the region IDs of the faces in the order of the faces.
+    /// # Details
+    /// Two faces sharing an edge are smoothly connected if the angle between their normals is
+    /// less than `angle`. The curvature of a face is the maximum variation of the vertex normals
+    /// per length along its edges: zero on the planes and the inverse of the radius on the
+    /// cylinders. The curvatures are not estimated for the faces touching the boundaries or the
+    /// sharp edges, which join the regions of their neighbors.
+    ///
+    /// The regions grow from the faces of the least curvatures across the smooth edges to the
+    /// faces whose curvatures are within `curvature_tol` from the seeds, so the IDs of the
+    /// near-planar regions come first. The positions should be shared among the faces, e.g. by
+    /// [`OptimizingFilter::put_together_same_attrs`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    /// let mut mesh = cylinder.triangulation(0.01).to_polygon();
+    /// mesh.put_together_same_attrs();
+    ///
+    /// // the top, the bottom, and the side
+    /// let labels = mesh.region_segmentation(Rad(0.5), 0.5);
+    /// assert_eq!(labels.len(), mesh.faces().len());
+    /// assert_eq!(labels.iter().max(), Some(&2));
+    ///
+    /// // the faces of each region are on the same surface.
+    /// let kind = |i: usize| {
+    ///     let face = &mesh.faces()[i];
+    ///     let p: Vec<Point3> = face.iter().map(|v| mesh.positions()[v.pos]).collect();
+    ///     let normal = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
+    ///     f64::round(normal.z) as i32
+    /// };
+    /// (0..3).for_each(|region| {
+    ///     let mut kinds = (0..labels.len()).filter(|i| labels[*i] == region).map(kind);
+    ///     let first = kinds.next().unwrap();
+    ///     assert!(kinds.all(|k| k == first));
+    /// });
+    /// ```
+    fn region_segmentation(&self, angle: Rad<f64>, curvature_tol: f64) -> Vec<usize>;
 }
 
 impl Splitting for PolygonMesh {
@@ -157,6 +199,101 @@ impl Splitting for PolygonMesh {
         let face_adjacency = self.faces().face_adjacency(use_normal);
         get_components(&face_adjacency)
     }
+
+    fn region_segmentation(&self, angle: Rad<f64>, curvature_tol: f64) -> Vec<usize> {
+        let positions = self.positions();
+        let normals: Vec<Vector3> = self
+            .face_iter()
+            .map(|face| area_vector(positions, face))
+            .collect();
+        let mut edge_map = HashMap::<[usize; 2], Vec<usize>>::default();
+        self.face_iter().enumerate().for_each(|(i, face)| {
+            let edges = face
+                .iter()
+                .zip(face.iter().cycle().skip(1))
+                .map(|(v, w)| [usize::min(v.pos, w.pos), usize::max(v.pos, w.pos)]);
+            edges.for_each(|edge| edge_map.entry(edge).or_default().push(i));
+        });
+        let len = normals.len();
+        let mut adjacency = vec![Vec::<usize>::new(); len];
+        let mut sharp = vec![false; positions.len()];
+        let mut vertex_normals = vec![Vector3::zero(); positions.len()];
+        edge_map.into_iter().for_each(|([i, j], faces)| {
+            let smooth = match faces[..] {
+                [f, g] if f != g && i != j => normals[f].angle(normals[g]) < angle,
+                _ => false,
+            };
+            match smooth {
+                true => {
+                    adjacency[faces[0]].push(faces[1]);
+                    adjacency[faces[1]].push(faces[0]);
+                }
+                false => {
+                    sharp[i] = true;
+                    sharp[j] = true;
+                }
+            }
+        });
+        for (face, normal) in self.face_iter().zip(&normals) {
+            face.iter().for_each(|v| vertex_normals[v.pos] += *normal);
+        }
+        let vertex_normals: Vec<Vector3> = vertex_normals
+            .into_iter()
+            .map(|normal| match normal.so_small() {
+                true => normal,
+                false => normal.normalize(),
+            })
+            .collect();
+
+        let curvatures: Vec<Option<f64>> = self
+            .face_iter()
+            .map(|face| {
+                if face.iter().any(|v| sharp[v.pos]) {
+                    return None;
+                }
+                let edges = face.iter().zip(face.iter().cycle().skip(1));
+                let curvatures = edges.filter_map(|(v, w)| {
+                    let dist = positions[v.pos].distance(positions[w.pos]);
+                    let diff = vertex_normals[v.pos].distance(vertex_normals[w.pos]);
+                    match dist.so_small() {
+                        true => None,
+                        false => Some(diff / dist),
+                    }
+                });
+                curvatures.reduce(f64::max)
+            })
+            .collect();
+
+        // the faces without the curvatures are seeds only after all the others.
+        let mut seeds: Vec<usize> = (0..len).collect();
+        seeds.sort_by(|i, j| match (curvatures[*i], curvatures[*j]) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (a, b) => a.is_none().cmp(&b.is_none()),
+        });
+        let mut labels = vec![usize::MAX; len];
+        let mut region = 0;
+        seeds.into_iter().for_each(|seed| {
+            if labels[seed] != usize::MAX {
+                return;
+            }
+            labels[seed] = region;
+            let mut stack = vec![seed];
+            while let Some(cursor) = stack.pop() {
+                adjacency[cursor].iter().for_each(|i| {
+                    let similar = match (curvatures[seed], curvatures[*i]) {
+                        (Some(a), Some(b)) => f64::abs(a - b) <= curvature_tol,
+                        _ => true,
+                    };
+                    if labels[*i] == usize::MAX && similar {
+                        labels[*i] = region;
+                        stack.push(*i);
+                    }
+                });
+            }
+            region += 1;
+        });
+        labels
+    }
 }
 
 #[doc(hidden)]
@@ -268,6 +405,16 @@ fn get_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
     }
 }
 
+/// Returns the area vector of the polygon: the normal whose magnitude is the area.
+fn area_vector(positions: &[Point3], face: &[Vertex]) -> Vector3 {
+    let origin = positions[face[0].pos];
+    (2..face.len()).fold(Vector3::zero(), |sum, i| {
+        let vec0 = positions[face[i - 1].pos] - origin;
+        let vec1 = positions[face[i].pos] - origin;
+        sum + vec0.cross(vec1) / 2.0
+    })
+}
+
 fn is_in_the_plane(positions: &[Point3], normals: &[Vector3], face: &[Vertex], tol2: f64) -> bool {
     let n = FaceNormal::new(positions, face, 0).normal;
     for v in face {
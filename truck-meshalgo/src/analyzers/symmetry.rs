@@ -0,0 +1,279 @@
+use super::registration::{PointTree, Samples};
+use super::*;
+use std::f64::consts::PI;
+use truck_topology::Solid;
+
+/// Symmetry of solids and meshes. The symmetry elements pass the centroid of the vertices.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Symmetry {
+    /// the reflection across the plane
+    Mirror {
+        /// a point on the plane
+        origin: Point3,
+        /// the unit normal of the plane
+        normal: Vector3,
+    },
+    /// the rotation around the axis by `2π / order`
+    Rotation {
+        /// a point on the axis
+        origin: Point3,
+        /// the unit direction of the axis
+        axis: Vector3,
+        /// the maximum order of the rotation
+        order: usize,
+    },
+}
+
+impl Symmetry {
+    /// Returns the transformation generating the symmetry.
+    pub fn transform(&self) -> Matrix4 {
+        let (origin, mat) = match *self {
+            Symmetry::Mirror { origin, normal } => {
+                let [x, y, z] = [normal.x, normal.y, normal.z];
+                let outer = Matrix3::from_cols(x * normal, y * normal, z * normal);
+                (origin, Matrix4::from(Matrix3::identity() - 2.0 * outer))
+            }
+            Symmetry::Rotation {
+                origin,
+                axis,
+                order,
+            } => {
+                let angle = Rad(2.0 * PI / order as f64);
+                (origin, Matrix4::from_axis_angle(axis, angle))
+            }
+        };
+        Matrix4::from_translation(origin.to_vec())
+            * mat
+            * Matrix4::from_translation(-origin.to_vec())
+    }
+}
+
+/// Detection of the mirror and rotational symmetries.
+pub trait SymmetryDetection {
+    /// Detects the mirror planes and the rotation axes with tolerance `tol`.
+    ///
+    /// The samples are the positions of meshes, or the vertices and the middle points of the
+    /// edges of solids. A symmetry is detected if it moves every sample within `tol` of another
+    /// sample. The candidates of the planes and the axes are the principal axes of the samples and
+    /// the ones determined by the samples with the same features. For each axis, the order is the
+    /// maximum one. The mirror planes come first.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let solid = builder::cuboid(Point3::origin(), Point3::new(3.0, 2.0, 1.0));
+    /// let symmetries = solid.symmetries(1.0e-6);
+    /// let mirrors = symmetries
+    ///     .iter()
+    ///     .filter(|symmetry| matches!(symmetry, Symmetry::Mirror { .. }))
+    ///     .count();
+    /// assert_eq!(mirrors, 3);
+    /// let z_axis = symmetries.iter().find_map(|symmetry| match symmetry {
+    ///     Symmetry::Rotation { origin, axis, order } if axis.z.abs().near(&1.0) => {
+    ///         Some((*origin, *order))
+    ///     }
+    ///     _ => None,
+    /// });
+    /// let (origin, order) = z_axis.unwrap();
+    /// assert!(origin.near(&Point3::new(1.5, 1.0, 0.5)));
+    /// assert_eq!(order, 2);
+    /// ```
+    fn symmetries(&self, tol: f64) -> Vec<Symmetry>;
+}
+
+impl SymmetryDetection for PolygonMesh {
+    fn symmetries(&self, tol: f64) -> Vec<Symmetry> {
+        nonpositive_tolerance!(tol);
+        detect(&Samples::from_points(self.positions().clone()), tol)
+    }
+}
+
+impl<C, S> SymmetryDetection for Solid<Point3, C, S>
+where C: BoundedCurve<Point = Point3> + Clone
+{
+    fn symmetries(&self, tol: f64) -> Vec<Symmetry> {
+        nonpositive_tolerance!(tol);
+        detect(&Samples::new(self), tol)
+    }
+}
+
+fn detect(samples: &Samples, tol: f64) -> Vec<Symmetry> {
+    let (points, origin) = (&samples.points, samples.centroid);
+    let distances = points.iter().map(|p| p.distance(origin));
+    let radius = distances.fold(0.0, f64::max);
+    if radius < tol {
+        return Vec::new();
+    }
+    let tree = PointTree::new(points, tol);
+    let is_symmetry = |symmetry: &Symmetry| {
+        let mat = symmetry.transform();
+        points.iter().enumerate().all(|(i, p)| {
+            let filter = |j: usize| samples.is_vertex(i) == samples.is_vertex(j);
+            tree.find(mat.transform_point(*p), filter).is_some()
+        })
+    };
+    // the angle within which two unit vectors are assumed to be parallel
+    let angle = tol / radius;
+    let mut directions = principal_axes(points, origin);
+    let anchors = independent_anchors(samples, tol);
+    let images = anchors
+        .iter()
+        .map(|a| samples.candidates(samples, *a, tol).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut normals = directions.clone();
+    for (a, images) in anchors.iter().zip(&images) {
+        let p = points[*a];
+        for q in images.iter().map(|b| points[*b]) {
+            let normal = (q - p).normalize();
+            if p.distance(q) > tol && f64::abs((p.midpoint(q) - origin).dot(normal)) <= tol {
+                normals.push(normal);
+            }
+        }
+    }
+    let mirrors = unique_directions(normals, angle)
+        .into_iter()
+        .map(|normal| Symmetry::Mirror { origin, normal })
+        .filter(|mirror| is_symmetry(mirror))
+        .collect::<Vec<_>>();
+
+    let mirror_normals = mirrors
+        .iter()
+        .filter_map(|mirror| match mirror {
+            Symmetry::Mirror { normal, .. } => Some(*normal),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    mirror_normals.iter().enumerate().for_each(|(i, n)| {
+        directions.extend(mirror_normals[i + 1..].iter().map(|m| n.cross(*m)));
+    });
+    directions.extend(anchors.iter().map(|a| points[*a] - origin));
+    (0..anchors.len()).for_each(|k| {
+        (k + 1..anchors.len()).for_each(|l| {
+            let (a0, a1) = (anchors[k], anchors[l]);
+            let dist = points[a0].distance(points[a1]);
+            images[k].iter().for_each(|b0| {
+                let displacement0 = points[*b0] - points[a0];
+                images[l]
+                    .iter()
+                    .filter(|b1| f64::abs(points[*b0].distance(points[**b1]) - dist) <= 2.0 * tol)
+                    .for_each(|b1| directions.push(displacement0.cross(points[*b1] - points[a1])));
+            });
+        });
+    });
+    let directions = directions
+        .into_iter()
+        .filter(|dir| dir.magnitude() > tol)
+        .map(|dir| dir.normalize())
+        .collect();
+    let rotations = unique_directions(directions, angle)
+        .into_iter()
+        .filter_map(|axis| rotation_order(samples, origin, axis, tol, &is_symmetry))
+        .collect::<Vec<_>>();
+    mirrors.into_iter().chain(rotations).collect()
+}
+
+/// the eigenvectors of the covariance matrix
+fn principal_axes(points: &[Point3], origin: Point3) -> Vec<Vector3> {
+    let mut covariance = [[0.0; 3]; 3];
+    points.iter().for_each(|p| {
+        let v = p - origin;
+        (0..3).for_each(|i| (0..3).for_each(|j| covariance[i][j] += v[i] * v[j]));
+    });
+    let (_, vectors) = symmetric_eigen(covariance);
+    (0..3)
+        .map(|i| Vector3::new(vectors[0][i], vectors[1][i], vectors[2][i]))
+        .collect()
+}
+
+/// The anchors with the fewest candidates such that no symmetry fixes all of them: the first
+/// one is apart from the centroid, the second one is not on the line through the centroid and
+/// the first one, and the third one is not on the plane through the centroid and them.
+fn independent_anchors(samples: &Samples, tol: f64) -> Vec<usize> {
+    let (points, origin) = (&samples.points, samples.centroid);
+    let mut anchors = Vec::<usize>::new();
+    let candidates = samples.anchors(|i| samples.candidates(samples, i, tol).count());
+    for i in candidates {
+        let v = points[i] - origin;
+        let independent = match anchors.as_slice() {
+            [] => v.magnitude() > tol,
+            [a] => (points[*a] - origin).normalize().cross(v).magnitude() > tol,
+            [a, b] => {
+                let normal = (points[*a] - origin).cross(points[*b] - origin).normalize();
+                f64::abs(normal.dot(v)) > tol
+            }
+            _ => break,
+        };
+        if independent {
+            anchors.push(i);
+        }
+    }
+    anchors
+}
+
+/// Removes the parallel unit vectors.
+fn unique_directions(directions: Vec<Vector3>, angle: f64) -> Vec<Vector3> {
+    let mut unique = Vec::<Vector3>::new();
+    directions.into_iter().for_each(|dir| {
+        if unique.iter().all(|u| u.cross(dir).magnitude() > angle) {
+            unique.push(dir);
+        }
+    });
+    unique
+}
+
+/// Returns the rotation with the maximum order around the axis. The order is searched by the
+/// angles from the sample farthest from the axis to its candidates around the axis.
+fn rotation_order(
+    samples: &Samples,
+    origin: Point3,
+    axis: Vector3,
+    tol: f64,
+    is_symmetry: &impl Fn(&Symmetry) -> bool,
+) -> Option<Symmetry> {
+    let points = &samples.points;
+    let radial = |p: Point3| {
+        let v = p - origin;
+        v - axis * v.dot(axis)
+    };
+    let (r, radius) = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, radial(*p).magnitude()))
+        .max_by(|(_, x), (_, y)| x.total_cmp(y))?;
+    if radius < tol {
+        return None;
+    }
+    let (p, v) = (points[r], radial(points[r]));
+    let mut angles = samples
+        .candidates(samples, r, tol)
+        .filter(|i| f64::abs((points[*i] - p).dot(axis)) <= tol)
+        .map(|i| {
+            let w = radial(points[i]);
+            let angle = f64::atan2(v.cross(w).dot(axis), v.dot(w));
+            match angle < 0.0 {
+                true => angle + 2.0 * PI,
+                false => angle,
+            }
+        })
+        .filter(|angle| *angle * radius > tol)
+        .collect::<Vec<_>>();
+    angles.sort_by(f64::total_cmp);
+    angles.into_iter().find_map(|angle| {
+        let order = f64::round(2.0 * PI / angle) as usize;
+        let error = f64::abs(order as f64 * angle - 2.0 * PI) * radius;
+        let consistent = order >= 2 && error <= order as f64 * tol;
+        let symmetry = Symmetry::Rotation {
+            origin,
+            axis,
+            order,
+        };
+        match consistent && is_symmetry(&symmetry) {
+            true => Some(symmetry),
+            false => None,
+        }
+    })
+}
@@ -0,0 +1,159 @@
+use super::primitive::vertex_normals;
+use super::triangle_grid::TriangleGrid;
+use super::*;
+#[cfg(feature = "tessellation")]
+use crate::tessellation::*;
+#[cfg(feature = "tessellation")]
+use truck_topology::Solid;
+
+/// Wall thickness analysis of polygon meshes.
+pub trait WallThickness {
+    /// Returns the wall thickness at each position by the ray probing.
+    ///
+    /// The ray is cast from each position in the inverse direction of the vertex normal, and the
+    /// distance to the first hit triangle is the thickness. The normals of the mesh have to be
+    /// oriented outward. If the ray hits nothing, the thickness is `f64::INFINITY`.
+    ///
+    /// The returned vector can be used as the scalar data of vertices, e.g. for coloring vertices.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    ///
+    /// // a box with thickness 0.25
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let plate = builder::tsweep(&f, Vector3::new(0.0, 0.0, 0.25));
+    /// let mesh = plate.triangulation(0.01).to_polygon();
+    ///
+    /// let thickness = mesh.thickness_map();
+    /// mesh.positions().iter().zip(thickness).for_each(|(p, t)| {
+    ///     if p.z.so_small() {
+    ///         assert!(t.near(&0.25) || t.near(&1.0));
+    ///     }
+    /// });
+    /// ```
+    fn thickness_map(&self) -> Vec<f64>;
+}
+
+impl WallThickness for PolygonMesh {
+    fn thickness_map(&self) -> Vec<f64> {
+        let positions = self.positions();
+        // in the same order as the triangles of the grid
+        let triangles = self
+            .faces()
+            .triangle_iter()
+            .map(|tri| [tri[0].pos, tri[1].pos, tri[2].pos])
+            .collect::<Vec<_>>();
+        let grid = TriangleGrid::from_mesh(self);
+        vertex_normals(self)
+            .into_iter()
+            .enumerate()
+            .map(|(i, normal)| {
+                if normal.so_small() {
+                    return f64::INFINITY;
+                }
+                let (origin, dir) = (positions[i], -normal);
+                let hit = |j: usize| match triangles[j].contains(&i) {
+                    true => None,
+                    false => ray_triangle(origin, dir, grid.triangles()[j]),
+                };
+                let t = grid.cast_ray(origin, dir, |j| hit(j).filter(|t| *t > TOLERANCE));
+                t.unwrap_or(f64::INFINITY)
+            })
+            .collect()
+    }
+}
+
+/// Wall thickness analysis of solids.
+#[cfg(feature = "tessellation")]
+pub trait SolidThickness {
+    /// Returns the minimum wall thickness of each face.
+    ///
+    /// The solid is tessellated with tolerance `resolution`, and the thickness is computed
+    /// at each vertex of the mesh by [`WallThickness::thickness_map`].
+    /// The order of the returned vector is the same as `face_iter`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    ///
+    /// // a box with thickness 0.25
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let plate = builder::tsweep(&f, Vector3::new(0.0, 0.0, 0.25));
+    ///
+    /// let thickness = plate.thickness_map(0.01);
+    /// assert_eq!(thickness.len(), 6);
+    /// // the bottom and the ceiling
+    /// assert!(thickness[0].near(&0.25));
+    /// assert!(thickness[5].near(&0.25));
+    /// // the side faces
+    /// assert!(thickness[1..5].iter().all(|t| t.near(&1.0)));
+    /// ```
+    fn thickness_map(&self, resolution: f64) -> Vec<f64>;
+}
+
+#[cfg(feature = "tessellation")]
+impl<C: PolylineableCurve, S: MeshableSurface> SolidThickness for Solid<Point3, C, S> {
+    fn thickness_map(&self, resolution: f64) -> Vec<f64> {
+        let meshed = self.triangulation(resolution);
+        let mut polygon = PolygonMesh::default();
+        let ranges = meshed
+            .face_iter()
+            .map(|face| {
+                let start = polygon.positions().len();
+                if let Some(mut poly) = face.surface() {
+                    if !face.orientation() {
+                        poly.invert();
+                    }
+                    polygon.merge(poly);
+                }
+                start..polygon.positions().len()
+            })
+            .collect::<Vec<_>>();
+        let thickness = polygon.thickness_map();
+        ranges
+            .into_iter()
+            .map(|range| thickness[range].iter().fold(f64::INFINITY, |min, t| f64::min(min, *t)))
+            .collect()
+    }
+}
+
+/// the parameter `t` of the intersection point `origin + t * dir` with the triangle (Möller–Trumbore)
+fn ray_triangle(origin: Point3, dir: Vector3, [a, b, c]: [Point3; 3]) -> Option<f64> {
+    let (ab, ac) = (b - a, c - a);
+    let p = dir.cross(ac);
+    let det = ab.dot(p);
+    if det.abs() <= f64::EPSILON * ab.magnitude() * ac.magnitude() {
+        return None;
+    }
+    let s = origin - a;
+    let u = s.dot(p) / det;
+    let q = s.cross(ab);
+    let v = dir.dot(q) / det;
+    // the boundaries of triangles are slightly extended to avoid rays passing through seams
+    match u >= -TOLERANCE && v >= -TOLERANCE && u + v <= 1.0 + TOLERANCE {
+        true => Some(ac.dot(q) / det),
+        false => None,
+    }
+}
+
+#[test]
+fn ray_triangle_test() {
+    let triangle = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let t = ray_triangle(Point3::new(0.2, 0.2, 1.0), -Vector3::unit_z(), triangle);
+    assert!(t.unwrap().near(&1.0));
+    let t = ray_triangle(Point3::new(0.2, 0.2, 1.0), Vector3::unit_z(), triangle);
+    assert!(t.unwrap().near(&-1.0));
+    let t = ray_triangle(Point3::new(1.0, 1.0, 1.0), -Vector3::unit_z(), triangle);
+    assert!(t.is_none());
+    let t = ray_triangle(Point3::new(0.0, 0.0, 1.0), Vector3::unit_x(), triangle);
+    assert!(t.is_none());
+}
@@ -0,0 +1,329 @@
+use super::*;
+use super::triangle_grid::TriangleGrid;
+use std::fmt::Write;
+use truck_polymesh::planar::{self, JoinType};
+#[cfg(feature = "tessellation")]
+use crate::tessellation::*;
+#[cfg(feature = "tessellation")]
+use truck_topology::Solid;
+
+/// Generates the toolpaths of the 3-axis machining by a ball end mill.
+///
+/// Each toolpath is a polyline of the positions of the tool tip, i.e. the bottom of the ball.
+/// The tool radius is compensated by the offset surface of the mesh: the center of the ball
+/// moves on the surface offset by the tool radius.
+pub trait Machining {
+    /// Returns the parallel-plane toolpaths for the finishing.
+    ///
+    /// The paths are the sections by the planes parallel to the zx-plane with interval
+    /// `stepover` in the y-direction, and the tool positions are sampled by the interval
+    /// `sampling` along the x-axis. The tool is dropped onto the mesh at each sample position,
+    /// and the tool tip does not go below the bottom of the bounding box. The directions of
+    /// the paths alternate, so the paths constitute the zigzag pattern.
+    /// # Panics
+    /// `stepover` and `sampling` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the unit cube
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 2, 3, 1],
+    ///         [4, 5, 7, 6],
+    ///         [0, 1, 5, 4],
+    ///         [2, 6, 7, 3],
+    ///         [0, 4, 6, 2],
+    ///         [1, 3, 7, 5],
+    ///     ]),
+    /// );
+    ///
+    /// let paths = mesh.parallel_toolpath(0.1, 0.25, 0.05);
+    /// assert_eq!(paths.len(), 5);
+    /// paths.iter().flatten().for_each(|p| {
+    ///     // the tool is on the top face
+    ///     if 0.0 <= p.x && p.x <= 1.0 {
+    ///         assert!(p.z.near(&1.0));
+    ///     }
+    ///     // the tool tip goes down along the quarter circle at the edge
+    ///     if 1.0 < p.x && p.x < 1.1 {
+    ///         let dx = p.x - 1.0;
+    ///         assert!(p.z.near(&(f64::sqrt(0.01 - dx * dx) + 0.9)));
+    ///     }
+    /// });
+    ///
+    /// let paths = mesh.waterline_toolpath(0.1, 0.25);
+    /// assert_eq!(paths.len(), 4);
+    /// assert!(paths[0][0].z.near(&0.875));
+    /// paths.iter().for_each(|path| {
+    ///     assert_eq!(path[0], path[path.len() - 1]);
+    ///     path.iter().for_each(|p| {
+    ///         let (dx, dy) = (f64::abs(p.x - 0.5), f64::abs(p.y - 0.5));
+    ///         assert!(dx < 0.6 + TOLERANCE && dy < 0.6 + TOLERANCE);
+    ///         assert!(dx > 0.5 - TOLERANCE || dy > 0.5 - TOLERANCE);
+    ///     });
+    /// });
+    /// ```
+    fn parallel_toolpath(&self, tool_radius: f64, stepover: f64, sampling: f64)
+        -> Vec<Vec<Point3>>;
+
+    /// Returns the waterline toolpaths for the roughing.
+    ///
+    /// The mesh is sliced by [`Slicing::slice`] with the layer height `step_down`, and each
+    /// contour is offset outward by `tool_radius` with the round joins. The paths are closed
+    /// polylines, i.e. the last point is the same as the first one, and ordered from the top
+    /// layer to the bottom one. The offset of the section is the exact radius compensation for
+    /// the vertical walls, and an approximation for the slanted ones.
+    /// # Panics
+    /// `step_down` must be more than `TOLERANCE`.
+    /// # Examples
+    /// See the example of [`Machining::parallel_toolpath`].
+    fn waterline_toolpath(&self, tool_radius: f64, step_down: f64) -> Vec<Vec<Point3>>;
+}
+
+/// Generates the toolpaths of solids.
+#[cfg(feature = "tessellation")]
+pub trait SolidMachining {
+    /// Tessellates the solid with tolerance `tol`, and generates the toolpaths by
+    /// [`Machining::parallel_toolpath`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let paths = cube.parallel_toolpath(0.1, 0.5, 0.1, 0.01);
+    /// assert_eq!(paths.len(), 3);
+    /// assert!(paths[1].iter().any(|p| p.z.near(&1.0)));
+    /// ```
+    fn parallel_toolpath(
+        &self,
+        tool_radius: f64,
+        stepover: f64,
+        sampling: f64,
+        tol: f64,
+    ) -> Vec<Vec<Point3>>;
+
+    /// Tessellates the solid with tolerance `tol`, and generates the toolpaths by
+    /// [`Machining::waterline_toolpath`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let paths = cube.waterline_toolpath(0.1, 0.5, 0.01);
+    /// assert_eq!(paths.len(), 2);
+    /// ```
+    fn waterline_toolpath(&self, tool_radius: f64, step_down: f64, tol: f64) -> Vec<Vec<Point3>>;
+}
+
+/// Outputs the basic G-code which traces the toolpaths.
+///
+/// The tool rapidly moves at `safe_height` between paths, plunges to the start of each path, and
+/// cuts along the path with the feed rate `feed_rate`.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let paths = vec![
+///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.5)],
+///     vec![Point3::new(1.0, 1.0, 0.5), Point3::new(0.0, 1.0, 0.0)],
+/// ];
+/// let gcode = toolpath_gcode(&paths, 2.0, 600.0);
+/// assert_eq!(gcode.lines().filter(|line| line.starts_with("G0 Z2.0000")).count(), 3);
+/// assert!(gcode.contains("G1 X1.0000 Y0.0000 Z0.5000"));
+/// ```
+pub fn toolpath_gcode(paths: &[Vec<Point3>], safe_height: f64, feed_rate: f64) -> String {
+    let mut gcode = String::from("G21\nG90\n");
+    writeln!(gcode, "F{feed_rate:.1}").unwrap();
+    paths
+        .iter()
+        .filter(|path| !path.is_empty())
+        .for_each(|path| {
+            writeln!(gcode, "G0 Z{safe_height:.4}").unwrap();
+            writeln!(gcode, "G0 X{:.4} Y{:.4}", path[0].x, path[0].y).unwrap();
+            writeln!(gcode, "G1 Z{:.4}", path[0].z).unwrap();
+            path.iter().skip(1).for_each(|p| {
+                writeln!(gcode, "G1 X{:.4} Y{:.4} Z{:.4}", p.x, p.y, p.z).unwrap();
+            });
+        });
+    writeln!(gcode, "G0 Z{safe_height:.4}").unwrap();
+    gcode
+}
+
+impl Machining for PolygonMesh {
+    fn parallel_toolpath(
+        &self,
+        tool_radius: f64,
+        stepover: f64,
+        sampling: f64,
+    ) -> Vec<Vec<Point3>> {
+        nonpositive_tolerance!(stepover);
+        nonpositive_tolerance!(sampling);
+        let grid = TriangleGrid::from_mesh(self);
+        if grid.triangles().is_empty() {
+            return Vec::new();
+        }
+        let bdd = self.bounding_box();
+        let (min, max) = (bdd.min(), bdd.max());
+        let floor = min.z;
+        let (x0, x1) = (min.x - tool_radius, max.x + tool_radius);
+        let rows = f64::floor((max.y - min.y) / stepover + TOLERANCE) as usize;
+        let columns = f64::ceil((x1 - x0) / sampling - TOLERANCE) as usize;
+        (0..=rows)
+            .map(|i| {
+                let y = min.y + i as f64 * stepover;
+                let mut path = (0..=columns)
+                    .map(|j| {
+                        let x = f64::min(x0 + j as f64 * sampling, x1);
+                        let z = drop_cutter(&grid, Point2::new(x, y), tool_radius)
+                            .map_or(floor, |center| f64::max(center - tool_radius, floor));
+                        Point3::new(x, y, z)
+                    })
+                    .collect::<Vec<_>>();
+                if i % 2 == 1 {
+                    path.reverse();
+                }
+                path
+            })
+            .collect()
+    }
+
+    fn waterline_toolpath(&self, tool_radius: f64, step_down: f64) -> Vec<Vec<Point3>> {
+        self.slice(step_down)
+            .into_iter()
+            .rev()
+            .flat_map(|layer| {
+                let height = layer.height();
+                layer
+                    .islands()
+                    .iter()
+                    .flat_map(|island| {
+                        let contours = std::iter::once(island.outer())
+                            .chain(island.holes())
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        planar::offset(&contours, tool_radius, JoinType::Round)
+                    })
+                    .map(|contour| {
+                        contour
+                            .iter()
+                            .chain(contour.first())
+                            .map(|p| Point3::new(p.x, p.y, height))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "tessellation")]
+impl<C: PolylineableCurve, S: MeshableSurface> SolidMachining for Solid<Point3, C, S> {
+    fn parallel_toolpath(
+        &self,
+        tool_radius: f64,
+        stepover: f64,
+        sampling: f64,
+        tol: f64,
+    ) -> Vec<Vec<Point3>> {
+        let mesh = self.triangulation(tol).to_polygon();
+        mesh.parallel_toolpath(tool_radius, stepover, sampling)
+    }
+    fn waterline_toolpath(&self, tool_radius: f64, step_down: f64, tol: f64) -> Vec<Vec<Point3>> {
+        let mesh = self.triangulation(tol).to_polygon();
+        mesh.waterline_toolpath(tool_radius, step_down)
+    }
+}
+
+/// the highest height of the center of the ball which touches the triangles at `xy`
+fn drop_cutter(grid: &TriangleGrid, xy: Point2, radius: f64) -> Option<f64> {
+    let triangles = grid.triangles();
+    grid.column(xy, radius)
+        .into_iter()
+        .filter_map(|i| drop_onto_triangle(triangles[i], xy, radius))
+        .reduce(f64::max)
+}
+
+fn drop_onto_triangle(tri: [Point3; 3], xy: Point2, radius: f64) -> Option<f64> {
+    let vertices = tri.iter().filter_map(|p| drop_onto_vertex(*p, xy, radius));
+    let edges = (0..3).filter_map(|i| drop_onto_edge(tri[i], tri[(i + 1) % 3], xy, radius));
+    let facet = drop_onto_facet(tri, xy, radius);
+    vertices.chain(edges).chain(facet).reduce(f64::max)
+}
+
+fn drop_onto_vertex(p: Point3, xy: Point2, radius: f64) -> Option<f64> {
+    let dist2 = (p.x - xy.x) * (p.x - xy.x) + (p.y - xy.y) * (p.y - xy.y);
+    match dist2 <= radius * radius {
+        true => Some(p.z + f64::sqrt(radius * radius - dist2)),
+        false => None,
+    }
+}
+
+fn drop_onto_edge(p: Point3, q: Point3, xy: Point2, radius: f64) -> Option<f64> {
+    let length = p.distance(q);
+    if length.so_small() {
+        return None;
+    }
+    let dir = (q - p) / length;
+    let coef = 1.0 - dir.z * dir.z;
+    if coef.so_small() {
+        return None;
+    }
+    // the center c = (xy, p.z + s) is at the distance `radius` from the line:
+    // |c - p|^2 - ((c - p) . dir)^2 = radius^2
+    let w = Vector3::new(xy.x - p.x, xy.y - p.y, 0.0);
+    let wd = w.dot(dir);
+    let b = -2.0 * dir.z * wd;
+    let c = w.magnitude2() - wd * wd - radius * radius;
+    let det = b * b - 4.0 * coef * c;
+    if det < 0.0 {
+        return None;
+    }
+    let s = (-b + f64::sqrt(det)) / (2.0 * coef);
+    // the foot of the perpendicular has to be on the segment
+    let foot = wd + s * dir.z;
+    match -TOLERANCE <= foot && foot <= length + TOLERANCE {
+        true => Some(p.z + s),
+        false => None,
+    }
+}
+
+fn drop_onto_facet(tri: [Point3; 3], xy: Point2, radius: f64) -> Option<f64> {
+    let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]);
+    if normal.so_small() {
+        return None;
+    }
+    let mut normal = normal.normalize();
+    if normal.z < 0.0 {
+        normal = -normal;
+    }
+    if normal.z.so_small() {
+        return None;
+    }
+    // the contact point is on the facet just below the center of the ball
+    let contact = Point2::new(xy.x - radius * normal.x, xy.y - radius * normal.y);
+    let [p0, p1, p2] = tri.map(|p| Point2::new(p.x, p.y));
+    let det = (p1 - p0).perp_dot(p2 - p0);
+    let u = (contact - p0).perp_dot(p2 - p0) / det;
+    let v = (p1 - p0).perp_dot(contact - p0) / det;
+    if u < -TOLERANCE || v < -TOLERANCE || u + v > 1.0 + TOLERANCE {
+        return None;
+    }
+    let z = tri[0].z - (normal.x * (contact.x - p0.x) + normal.y * (contact.y - p0.y)) / normal.z;
+    Some(z + radius * normal.z)
+}
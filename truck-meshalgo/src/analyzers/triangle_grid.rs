@@ -0,0 +1,262 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// uniform grid of triangles shared by the spatial queries of the analyzers
+#[derive(Clone, Debug)]
+pub(crate) struct TriangleGrid {
+    triangles: Vec<[Point3; 3]>,
+    cells: HashMap<[i64; 3], Vec<usize>>,
+    min: Point3,
+    cell_size: f64,
+    range: [i64; 3],
+}
+
+impl TriangleGrid {
+    /// Creates the grid whose cells are about the size of `triangles.len()^(-1/3)` of the box.
+    pub(crate) fn new(triangles: Vec<[Point3; 3]>) -> Self {
+        let bdd = triangles.iter().flatten().collect::<BoundingBox<Point3>>();
+        let mut grid = TriangleGrid {
+            cells: HashMap::default(),
+            min: Point3::origin(),
+            cell_size: TOLERANCE,
+            range: [0; 3],
+            triangles: Vec::new(),
+        };
+        if triangles.is_empty() {
+            return grid;
+        }
+        let diag = bdd.diagonal();
+        let division = f64::max(f64::cbrt(triangles.len() as f64), 1.0);
+        grid.min = *bdd.min();
+        grid.cell_size = f64::max(f64::max(diag.x, diag.y).max(diag.z) / division, TOLERANCE);
+        grid.range = grid.index(*bdd.max());
+        triangles.iter().enumerate().for_each(|(i, tri)| {
+            let bdd = tri.iter().collect::<BoundingBox<Point3>>();
+            let (min, max) = (grid.index(*bdd.min()), grid.index(*bdd.max()));
+            for x in min[0]..=max[0] {
+                for y in min[1]..=max[1] {
+                    for z in min[2]..=max[2] {
+                        grid.cells.entry([x, y, z]).or_default().push(i);
+                    }
+                }
+            }
+        });
+        grid.triangles = triangles;
+        grid
+    }
+
+    /// Creates the grid of the triangles of `mesh`.
+    pub(crate) fn from_mesh(mesh: &PolygonMesh) -> Self {
+        let positions = mesh.positions();
+        let triangles = mesh
+            .faces()
+            .triangle_iter()
+            .map(|tri| {
+                [
+                    positions[tri[0].pos],
+                    positions[tri[1].pos],
+                    positions[tri[2].pos],
+                ]
+            })
+            .collect::<Vec<_>>();
+        Self::new(triangles)
+    }
+
+    /// the registered triangles
+    #[inline(always)]
+    pub(crate) fn triangles(&self) -> &[[Point3; 3]] { &self.triangles }
+
+    #[inline(always)]
+    fn index(&self, point: Point3) -> [i64; 3] {
+        let vec = (point - self.min) / self.cell_size;
+        [
+            vec.x.floor() as i64,
+            vec.y.floor() as i64,
+            vec.z.floor() as i64,
+        ]
+    }
+
+    /// the nearest point to `point` on the triangles
+    pub(crate) fn closest_point(&self, point: Point3) -> Point3 {
+        let idx = self.index(point);
+        let clamped = [0, 1, 2].map(|i| idx[i].clamp(0, self.range[i]));
+        let max_radius = (0..3)
+            .map(|i| i64::max(clamped[i], self.range[i] - clamped[i]))
+            .max()
+            .unwrap_or(0);
+        let mut best = (f64::INFINITY, point);
+        for r in 0..=max_radius {
+            let lower = [0, 1, 2].map(|i| clamped[i] - r);
+            let upper = [0, 1, 2].map(|i| clamped[i] + r);
+            for x in lower[0]..=upper[0] {
+                for y in lower[1]..=upper[1] {
+                    for z in lower[2]..=upper[2] {
+                        let on_shell = [x, y, z]
+                            .iter()
+                            .zip(lower.iter().zip(&upper))
+                            .any(|(a, (l, u))| a == l || a == u);
+                        if !on_shell {
+                            continue;
+                        }
+                        if let Some(cell) = self.cells.get(&[x, y, z]) {
+                            cell.iter().for_each(|i| {
+                                let q = closest_point_on_triangle(point, self.triangles[*i]);
+                                let dist2 = point.distance2(q);
+                                if dist2 < best.0 {
+                                    best = (dist2, q);
+                                }
+                            });
+                        }
+                    }
+                }
+            }
+            let covered = r as f64 * self.cell_size;
+            if best.0 <= covered * covered {
+                break;
+            }
+        }
+        best.1
+    }
+
+    /// the indices of the triangles in the cells over the disk of `radius` around `xy`
+    /// on the xy-plane, sorted without duplicates
+    pub(crate) fn column(&self, xy: Point2, radius: f64) -> Vec<usize> {
+        let lower = self.index(Point3::new(xy.x - radius, xy.y - radius, self.min.z));
+        let upper = self.index(Point3::new(xy.x + radius, xy.y + radius, self.min.z));
+        let mut res = Vec::new();
+        for x in i64::max(lower[0], 0)..=i64::min(upper[0], self.range[0]) {
+            for y in i64::max(lower[1], 0)..=i64::min(upper[1], self.range[1]) {
+                for z in 0..=self.range[2] {
+                    if let Some(cell) = self.cells.get(&[x, y, z]) {
+                        res.extend(cell);
+                    }
+                }
+            }
+        }
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+
+    /// Returns the least parameter `t` of the hits on the ray `origin + t * dir`.
+    ///
+    /// The cells are visited in the order along the ray, and `hit` returns the parameter of the
+    /// hit on the `i`-th triangle if the triangle is hit. The search stops at the first cell
+    /// which contains the hit point.
+    pub(crate) fn cast_ray(
+        &self,
+        origin: Point3,
+        dir: Vector3,
+        mut hit: impl FnMut(usize) -> Option<f64>,
+    ) -> Option<f64> {
+        if self.triangles.is_empty() {
+            return None;
+        }
+        let (mut t_enter, mut t_exit) = (0.0, f64::INFINITY);
+        for i in 0..3 {
+            let min = self.min[i];
+            let max = self.min[i] + (self.range[i] + 1) as f64 * self.cell_size;
+            if dir[i] == 0.0 {
+                if origin[i] < min || max < origin[i] {
+                    return None;
+                }
+                continue;
+            }
+            let (t0, t1) = ((min - origin[i]) / dir[i], (max - origin[i]) / dir[i]);
+            t_enter = f64::max(t_enter, f64::min(t0, t1));
+            t_exit = f64::min(t_exit, f64::max(t0, t1));
+        }
+        if t_enter > t_exit {
+            return None;
+        }
+        let idx = self.index(origin + dir * t_enter);
+        let mut cell = [0, 1, 2].map(|i| idx[i].clamp(0, self.range[i]));
+        let step = [0, 1, 2].map(|i| match dir[i] > 0.0 {
+            true => 1,
+            false => -1,
+        });
+        let mut t_max = [0, 1, 2].map(|i| {
+            if dir[i] == 0.0 {
+                return f64::INFINITY;
+            }
+            let boundary = cell[i] + i64::max(step[i], 0);
+            (self.min[i] + boundary as f64 * self.cell_size - origin[i]) / dir[i]
+        });
+        let delta = [0, 1, 2].map(|i| self.cell_size / f64::abs(dir[i]));
+        let mut best = f64::INFINITY;
+        loop {
+            if let Some(triangles) = self.cells.get(&cell) {
+                triangles
+                    .iter()
+                    .filter_map(|i| hit(*i))
+                    .for_each(|t| best = f64::min(best, t));
+            }
+            let axis = (1..3).fold(0, |a, i| if t_max[i] < t_max[a] { i } else { a });
+            if best <= t_max[axis] {
+                break;
+            }
+            cell[axis] += step[axis];
+            t_max[axis] += delta[axis];
+            if cell[axis] < 0 || self.range[axis] < cell[axis] {
+                break;
+            }
+        }
+        match best.is_finite() {
+            true => Some(best),
+            false => None,
+        }
+    }
+}
+
+// Real-Time Collision Detection, Christer Ericson, 5.1.5
+fn closest_point_on_triangle(p: Point3, [a, b, c]: [Point3; 3]) -> Point3 {
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denom = va + vb + vc;
+    if denom.so_small() {
+        return a;
+    }
+    a + ab * (vb / denom) + ac * (vc / denom)
+}
+
+#[test]
+fn closest_point_on_triangle_test() {
+    let triangle = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let closest = closest_point_on_triangle(Point3::new(0.25, 0.25, 3.0), triangle);
+    assert!(closest.near(&Point3::new(0.25, 0.25, 0.0)));
+    let closest = closest_point_on_triangle(Point3::new(-1.0, -1.0, 1.0), triangle);
+    assert!(closest.near(&Point3::new(0.0, 0.0, 0.0)));
+    let closest = closest_point_on_triangle(Point3::new(1.0, 1.0, 0.0), triangle);
+    assert!(closest.near(&Point3::new(0.5, 0.5, 0.0)));
+    let closest = closest_point_on_triangle(Point3::new(0.5, -1.0, 0.0), triangle);
+    assert!(closest.near(&Point3::new(0.5, 0.0, 0.0)));
+}
@@ -0,0 +1,67 @@
+/// Eigen decomposition of the symmetric matrix by the cyclic Jacobi method.
+///
+/// Returns the eigenvalues and the matrix whose `i`-th column is the eigenvector
+/// corresponding to the `i`-th eigenvalue. The eigenvalues are sorted in ascending order.
+pub fn symmetric_eigen<const N: usize>(mut a: [[f64; N]; N]) -> ([f64; N], [[f64; N]; N]) {
+    let mut v = [[0.0; N]; N];
+    (0..N).for_each(|i| v[i][i] = 1.0);
+    for _ in 0..50 {
+        let off = (0..N)
+            .flat_map(|i| (0..N).filter(move |j| *j != i).map(move |j| (i, j)))
+            .fold(0.0, |sum, (i, j)| sum + a[i][j] * a[i][j]);
+        if off < f64::EPSILON * f64::EPSILON {
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q].abs() < f64::MIN_POSITIVE {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = f64::signum(theta) / (theta.abs() + f64::sqrt(theta * theta + 1.0));
+                let c = 1.0 / f64::sqrt(t * t + 1.0);
+                let s = t * c;
+                for row in &mut a {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                for k in 0..N {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+                for row in &mut v {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    let mut order = [0; N];
+    (0..N).for_each(|i| order[i] = i);
+    order.sort_by(|i, j| a[*i][*i].partial_cmp(&a[*j][*j]).unwrap_or(std::cmp::Ordering::Equal));
+    let mut values = [0.0; N];
+    let mut vectors = [[0.0; N]; N];
+    for (i, k) in order.into_iter().enumerate() {
+        values[i] = a[k][k];
+        (0..N).for_each(|j| vectors[j][i] = v[j][k]);
+    }
+    (values, vectors)
+}
+
+#[test]
+fn symmetric_eigen_test() {
+    let a = [[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]];
+    let (values, vectors) = symmetric_eigen(a);
+    assert!(f64::abs(values[0] - 1.0) < 1.0e-10);
+    assert!(f64::abs(values[1] - 3.0) < 1.0e-10);
+    assert!(f64::abs(values[2] - 5.0) < 1.0e-10);
+    for i in 0..3 {
+        for j in 0..3 {
+            let av = (0..3).fold(0.0, |sum, k| sum + a[j][k] * vectors[k][i]);
+            assert!(f64::abs(av - values[i] * vectors[j][i]) < 1.0e-10);
+        }
+    }
+}
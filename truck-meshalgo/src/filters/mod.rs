@@ -3,10 +3,14 @@ use polygon_mesh::PolygonMeshEditor;
 
 mod normal_filters;
 mod optimizing;
+mod point_cloud;
+mod reconstruction;
 mod structuring;
 mod subdivision;
 
 pub use normal_filters::NormalFilters;
 pub use optimizing::OptimizingFilter;
+pub use point_cloud::PointCloudFilters;
+pub use reconstruction::SurfaceReconstruction;
 pub use structuring::StructuringFilter;
 pub use subdivision::Subdivision;
@@ -0,0 +1,145 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use truck_base::kd_tree::KdTree;
+
+/// Filters for point clouds: downsampling and normal estimation.
+pub trait PointCloudFilters {
+    /// Downsamples the point cloud by the voxel grid with edge length `voxel_size`.
+    ///
+    /// All points in the same voxel are replaced by their centroid.
+    /// Normals and colors are also averaged.
+    /// # Panics
+    /// `voxel_size` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut cloud = PointCloud::from_positions(vec![
+    ///     Point3::new(0.1, 0.1, 0.1),
+    ///     Point3::new(0.3, 0.3, 0.3),
+    ///     Point3::new(1.5, 0.5, 0.5),
+    /// ]);
+    /// cloud.voxel_downsample(1.0);
+    /// assert_eq!(cloud.len(), 2);
+    /// assert!(cloud.positions()[0].near(&Point3::new(0.2, 0.2, 0.2)));
+    /// ```
+    fn voxel_downsample(&mut self, voxel_size: f64) -> &mut Self;
+    /// Estimates the normals by the principal component analysis of the neighborhood
+    /// with radius `radius` of each point.
+    ///
+    /// The normals are oriented outward from the center of the bounding box. If the point
+    /// cloud already has normals, the estimated normals are oriented along them instead.
+    /// The normal of a point whose neighborhood has fewer than three points is set to be the zero vector.
+    /// # Panics
+    /// `radius` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = (0..100)
+    ///     .map(|i| Point3::new((i % 10) as f64, (i / 10) as f64, 1.0))
+    ///     .collect::<Vec<_>>();
+    /// let mut cloud = PointCloud::from_positions(positions);
+    /// cloud.estimate_normals(1.5);
+    /// cloud.normals().unwrap().iter().for_each(|n| {
+    ///     assert!(n.z.abs().near(&1.0));
+    /// });
+    /// ```
+    fn estimate_normals(&mut self, radius: f64) -> &mut Self;
+}
+
+#[inline(always)]
+fn voxel_index(point: Point3, size: f64) -> [i64; 3] {
+    [
+        f64::floor(point.x / size) as i64,
+        f64::floor(point.y / size) as i64,
+        f64::floor(point.z / size) as i64,
+    ]
+}
+
+impl PointCloudFilters for PointCloud {
+    fn voxel_downsample(&mut self, voxel_size: f64) -> &mut Self {
+        nonpositive_tolerance!(voxel_size);
+        let mut indices = HashMap::<[i64; 3], usize>::default();
+        let mut counts = Vec::<usize>::new();
+        let mut positions = Vec::<Vector3>::new();
+        let mut normals = Vec::<Vector3>::new();
+        let mut colors = Vec::<Vector3>::new();
+        self.positions().iter().enumerate().for_each(|(i, p)| {
+            let len = counts.len();
+            let idx = *indices.entry(voxel_index(*p, voxel_size)).or_insert(len);
+            if idx == len {
+                counts.push(0);
+                positions.push(Vector3::zero());
+                normals.push(Vector3::zero());
+                colors.push(Vector3::zero());
+            }
+            counts[idx] += 1;
+            positions[idx] += p.to_vec();
+            if let Some(vec) = self.normals() {
+                normals[idx] += vec[i];
+            }
+            if let Some(vec) = self.colors() {
+                colors[idx] += vec[i];
+            }
+        });
+        let positions = positions
+            .into_iter()
+            .zip(&counts)
+            .map(|(p, c)| Point3::from_vec(p / *c as f64))
+            .collect();
+        let normals = self.normals().map(|_| {
+            normals
+                .into_iter()
+                .map(|n| match n.so_small() {
+                    true => n,
+                    false => n.normalize(),
+                })
+                .collect()
+        });
+        let colors = self.colors().map(|_| {
+            colors
+                .into_iter()
+                .zip(&counts)
+                .map(|(c, n)| c / *n as f64)
+                .collect()
+        });
+        *self = PointCloud::new_unchecked(positions, normals, colors);
+        self
+    }
+
+    fn estimate_normals(&mut self, radius: f64) -> &mut Self {
+        nonpositive_tolerance!(radius);
+        let tree = KdTree::new(self.positions().clone());
+        let center = self.bounding_box().center();
+        let normals = self
+            .positions()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let neighbors = tree.within_radius(*p, radius).into_iter();
+                let neighbors = neighbors.map(|j| self.positions()[j]).collect::<Vec<_>>();
+                if neighbors.len() < 3 {
+                    return Vector3::zero();
+                }
+                let mean = neighbors.iter().fold(Vector3::zero(), |sum, q| sum + q.to_vec())
+                    / neighbors.len() as f64;
+                let mut cov = [[0.0; 3]; 3];
+                neighbors.iter().for_each(|q| {
+                    let d = q.to_vec() - mean;
+                    (0..3).for_each(|j| (0..3).for_each(|k| cov[j][k] += d[j] * d[k]));
+                });
+                let (_, vectors) = symmetric_eigen(cov);
+                let normal = Vector3::new(vectors[0][0], vectors[1][0], vectors[2][0]).normalize();
+                let reference = match self.normals() {
+                    Some(normals) => normals[i],
+                    None => *p - center,
+                };
+                match normal.dot(reference) < 0.0 {
+                    true => -normal,
+                    false => normal,
+                }
+            })
+            .collect();
+        self.set_normals(normals).unwrap();
+        self
+    }
+}
@@ -0,0 +1,261 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Surface reconstruction from point clouds.
+pub trait SurfaceReconstruction {
+    /// Reconstructs the closed surface from the oriented point cloud by the Poisson method.
+    ///
+    /// The indicator function of the solid is computed on the regular grid whose longest axis
+    /// is divided into `resolution` cells, and its level set is extracted by marching tetrahedra.
+    /// The normals of the point cloud have to be oriented outward.
+    /// The faces of the returned mesh are all triangles and oriented outward.
+    ///
+    /// Returns `None` if the point cloud has no normals, no points, or `resolution == 0`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use std::f64::consts::PI;
+    /// // oriented points on the unit sphere
+    /// let (positions, normals): (Vec<_>, Vec<_>) = (0..40)
+    ///     .flat_map(|i| (1..20).map(move |j| (i, j)))
+    ///     .map(|(i, j)| {
+    ///         let (u, v) = (2.0 * PI * i as f64 / 40.0, PI * j as f64 / 20.0);
+    ///         let n = Vector3::new(u.cos() * v.sin(), u.sin() * v.sin(), v.cos());
+    ///         (Point3::from_vec(n), n)
+    ///     })
+    ///     .unzip();
+    /// let cloud = PointCloud::new(positions, Some(normals), None);
+    ///
+    /// let mesh = cloud.poisson_reconstruction(20).unwrap();
+    /// assert!(!mesh.faces().is_empty());
+    /// mesh.positions().iter().for_each(|p| {
+    ///     assert!(f64::abs(p.to_vec().magnitude() - 1.0) < 0.1);
+    /// });
+    /// ```
+    fn poisson_reconstruction(&self, resolution: usize) -> Option<PolygonMesh>;
+}
+
+/// the number of cells added outside of the bounding box
+const PADDING: usize = 3;
+
+/// the decomposition of the unit cube into six tetrahedra, compatible with the neighbor cubes.
+/// The `i`-th vertex of the cube is `(i & 1, (i >> 1) & 1, (i >> 2) & 1)`.
+const CUBE_TETRAHEDRA: [[usize; 4]; 6] = [
+    [0, 1, 3, 7],
+    [0, 1, 5, 7],
+    [0, 2, 3, 7],
+    [0, 2, 6, 7],
+    [0, 4, 5, 7],
+    [0, 4, 6, 7],
+];
+
+#[derive(Clone, Copy, Debug)]
+struct Grid {
+    dims: [usize; 3],
+    min: Point3,
+    spacing: f64,
+}
+
+impl Grid {
+    #[inline(always)]
+    fn len(&self) -> usize { self.dims[0] * self.dims[1] * self.dims[2] }
+    #[inline(always)]
+    fn index(&self, [i, j, k]: [usize; 3]) -> usize { (i * self.dims[1] + j) * self.dims[2] + k }
+    #[inline(always)]
+    fn position(&self, [i, j, k]: [usize; 3]) -> Point3 {
+        self.min + self.spacing * Vector3::new(i as f64, j as f64, k as f64)
+    }
+    /// the indices of the eight nodes around `point` and their trilinear weights
+    fn weights(&self, point: Point3) -> [(usize, f64); 8] {
+        let vec = (point - self.min) / self.spacing;
+        let mut cell = [0; 3];
+        let mut local = [0.0; 3];
+        (0..3).for_each(|i| {
+            let x = f64::clamp(vec[i], 0.0, (self.dims[i] - 1) as f64);
+            cell[i] = usize::min(x as usize, self.dims[i] - 2);
+            local[i] = x - cell[i] as f64;
+        });
+        let mut res = [(0, 0.0); 8];
+        res.iter_mut().enumerate().for_each(|(n, res)| {
+            let bits = [n & 1, (n >> 1) & 1, (n >> 2) & 1];
+            let idx = [0, 1, 2].map(|i| cell[i] + bits[i]);
+            let weight = (0..3).fold(1.0, |w, i| match bits[i] {
+                0 => w * (1.0 - local[i]),
+                _ => w * local[i],
+            });
+            *res = (self.index(idx), weight);
+        });
+        res
+    }
+    #[inline(always)]
+    fn interpolate(&self, values: &[f64], point: Point3) -> f64 {
+        self.weights(point)
+            .iter()
+            .fold(0.0, |sum, (idx, w)| sum + values[*idx] * w)
+    }
+    #[inline(always)]
+    fn interior_iter(&self) -> impl Iterator<Item = [usize; 3]> {
+        let [d0, d1, d2] = self.dims;
+        (1..d0 - 1)
+            .flat_map(move |i| (1..d1 - 1).map(move |j| [i, j]))
+            .flat_map(move |[i, j]| (1..d2 - 1).map(move |k| [i, j, k]))
+    }
+    #[inline(always)]
+    fn neighbors(&self, [i, j, k]: [usize; 3]) -> [[usize; 2]; 3] {
+        let (di, dj) = (self.dims[1] * self.dims[2], self.dims[2]);
+        let idx = self.index([i, j, k]);
+        [[idx - di, idx + di], [idx - dj, idx + dj], [idx - 1, idx + 1]]
+    }
+}
+
+impl SurfaceReconstruction for PointCloud {
+    fn poisson_reconstruction(&self, resolution: usize) -> Option<PolygonMesh> {
+        let normals = self.normals()?;
+        if self.is_empty() || resolution == 0 {
+            return None;
+        }
+        let bdd = self.bounding_box();
+        let diag = bdd.diagonal();
+        let size = f64::max(f64::max(diag.x, diag.y), diag.z);
+        if size.so_small() {
+            return None;
+        }
+        let spacing = size / resolution as f64;
+        let grid = Grid {
+            dims: [0, 1, 2].map(|i| f64::ceil(diag[i] / spacing) as usize + 2 * PADDING + 1),
+            min: bdd.min() - Vector3::new(1.0, 1.0, 1.0) * (PADDING as f64 * spacing),
+            spacing,
+        };
+
+        let mut field = vec![Vector3::zero(); grid.len()];
+        self.positions().iter().zip(normals).for_each(|(p, n)| {
+            grid.weights(*p)
+                .iter()
+                .for_each(|(idx, w)| field[*idx] += *n * *w)
+        });
+        let mut rhs = vec![0.0; grid.len()];
+        grid.interior_iter().for_each(|node| {
+            let neighbors = grid.neighbors(node);
+            let div = (0..3).fold(0.0, |sum, i| {
+                let [prev, next] = neighbors[i];
+                sum + (field[next][i] - field[prev][i]) / (2.0 * spacing)
+            });
+            rhs[grid.index(node)] = -div * spacing * spacing;
+        });
+
+        let chi = solve_laplacian(&grid, &rhs);
+        let iso = self
+            .positions()
+            .iter()
+            .fold(0.0, |sum, p| sum + grid.interpolate(&chi, *p))
+            / self.len() as f64;
+        Some(marching_tetrahedra(&grid, &chi, iso))
+    }
+}
+
+/// Solves `(6 - adjacency) x = rhs` for the interior nodes by the conjugate gradient method,
+/// with the Dirichlet boundary condition `x = 0`.
+fn solve_laplacian(grid: &Grid, rhs: &[f64]) -> Vec<f64> {
+    let apply = |x: &[f64], res: &mut [f64]| {
+        grid.interior_iter().for_each(|node| {
+            let neighbors = grid.neighbors(node);
+            let sum = neighbors.iter().flatten().fold(0.0, |sum, idx| sum + x[*idx]);
+            let idx = grid.index(node);
+            res[idx] = 6.0 * x[idx] - sum;
+        })
+    };
+    let dot = |x: &[f64], y: &[f64]| x.iter().zip(y).fold(0.0, |sum, (a, b)| sum + a * b);
+    let mut x = vec![0.0; grid.len()];
+    let mut r = rhs.to_vec();
+    let mut p = r.clone();
+    let mut ap = vec![0.0; grid.len()];
+    let mut rr = dot(&r, &r);
+    let threshold = rr * 1.0e-16;
+    let max_iter = 10 * grid.dims.into_iter().max().unwrap_or(0);
+    for _ in 0..max_iter {
+        if rr <= threshold {
+            break;
+        }
+        apply(&p, &mut ap);
+        let alpha = rr / dot(&p, &ap);
+        x.iter_mut().zip(&p).for_each(|(x, p)| *x += alpha * p);
+        r.iter_mut().zip(&ap).for_each(|(r, ap)| *r -= alpha * ap);
+        let new_rr = dot(&r, &r);
+        let beta = new_rr / rr;
+        p.iter_mut().zip(&r).for_each(|(p, r)| *p = r + beta * *p);
+        rr = new_rr;
+    }
+    x
+}
+
+fn marching_tetrahedra(grid: &Grid, values: &[f64], iso: f64) -> PolygonMesh {
+    let mut positions = Vec::<Point3>::new();
+    let mut vertices = HashMap::<(usize, usize), usize>::default();
+    let mut tri_faces = Vec::<[usize; 3]>::new();
+    let [d0, d1, d2] = grid.dims;
+    let cells = (0..d0 - 1)
+        .flat_map(move |i| (0..d1 - 1).map(move |j| [i, j]))
+        .flat_map(move |[i, j]| (0..d2 - 1).map(move |k| [i, j, k]));
+    for [i, j, k] in cells {
+        let corners = [0, 1, 2, 3, 4, 5, 6, 7].map(|n| {
+            let node = [i + (n & 1), j + ((n >> 1) & 1), k + ((n >> 2) & 1)];
+            (grid.index(node), grid.position(node))
+        });
+        for tetra in CUBE_TETRAHEDRA {
+            let tetra = tetra.map(|n| corners[n]);
+            let (inside, outside): (Vec<&(usize, Point3)>, Vec<&(usize, Point3)>) =
+                tetra.iter().partition(|(idx, _)| values[*idx] < iso);
+            if inside.is_empty() || outside.is_empty() {
+                continue;
+            }
+            let mut vertex = |(a, pa): (usize, Point3), (b, pb): (usize, Point3)| {
+                let key = (usize::min(a, b), usize::max(a, b));
+                *vertices.entry(key).or_insert_with(|| {
+                    let t = (iso - values[a]) / (values[b] - values[a]);
+                    positions.push(pa + (pb - pa) * t);
+                    positions.len() - 1
+                })
+            };
+            let polygon = match (inside.len(), outside.len()) {
+                (1, 3) => vec![
+                    vertex(*inside[0], *outside[0]),
+                    vertex(*inside[0], *outside[1]),
+                    vertex(*inside[0], *outside[2]),
+                ],
+                (3, 1) => vec![
+                    vertex(*inside[0], *outside[0]),
+                    vertex(*inside[1], *outside[0]),
+                    vertex(*inside[2], *outside[0]),
+                ],
+                _ => vec![
+                    vertex(*inside[0], *outside[0]),
+                    vertex(*inside[0], *outside[1]),
+                    vertex(*inside[1], *outside[1]),
+                    vertex(*inside[1], *outside[0]),
+                ],
+            };
+            let center = |vec: &[&(usize, Point3)]| {
+                vec.iter().fold(Vector3::zero(), |sum, (_, p)| sum + p.to_vec()) / vec.len() as f64
+            };
+            let dir = center(&outside) - center(&inside);
+            let mut triangle = |a: usize, b: usize, c: usize| {
+                let (pa, pb, pc) = (positions[a], positions[b], positions[c]);
+                match (pb - pa).cross(pc - pa).dot(dir) < 0.0 {
+                    true => tri_faces.push([a, c, b]),
+                    false => tri_faces.push([a, b, c]),
+                }
+            };
+            triangle(polygon[0], polygon[1], polygon[2]);
+            if polygon.len() == 4 {
+                triangle(polygon[0], polygon[2], polygon[3]);
+            }
+        }
+    }
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(tri_faces),
+    )
+}
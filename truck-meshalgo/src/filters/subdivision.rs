@@ -1,8 +1,12 @@
 use super::*;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::f64::consts::PI;
 
 /// subdivision surface algorithms
+///
+/// The creases are the edges kept sharp by the subdivision, represented by the pairs of the
+/// indices of positions. The methods with creases replace them by the subdivided edges,
+/// so that they can be passed to the next subdivision.
 pub trait Subdivision {
     /// Extended Loop method
     ///
@@ -12,6 +16,25 @@ pub trait Subdivision {
     /// - `self.shell_condition()` is `Oriented` or `Closed` before use.
     /// This method does NOT check these conditions.
     fn loop_subdivision(&mut self) -> &mut Self;
+    /// Extended Loop method with crease edges
+    ///
+    /// # Remarks
+    /// The conditions same as [`Subdivision::loop_subdivision`] are required.
+    fn loop_subdivision_with_creases(&mut self, creases: &mut Vec<[usize; 2]>) -> &mut Self;
+    /// Catmull-Clark method. All faces, including n-gons, are divided into quadrangles.
+    ///
+    /// # Remarks
+    /// Confirm `self.shell_condition()` is `Oriented` or `Closed` before use.
+    /// This method does NOT check the condition.
+    fn catmull_clark_subdivision(&mut self) -> &mut Self;
+    /// Catmull-Clark method with crease edges
+    ///
+    /// # Remarks
+    /// The condition same as [`Subdivision::catmull_clark_subdivision`] is required.
+    fn catmull_clark_subdivision_with_creases(
+        &mut self,
+        creases: &mut Vec<[usize; 2]>,
+    ) -> &mut Self;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -42,7 +65,11 @@ impl EdgeInfo {
 }
 
 impl Subdivision for PolygonMesh {
+    #[inline(always)]
     fn loop_subdivision(&mut self) -> &mut Self {
+        self.loop_subdivision_with_creases(&mut Vec::new())
+    }
+    fn loop_subdivision_with_creases(&mut self, creases: &mut Vec<[usize; 2]>) -> &mut Self {
         let mut edges = HashMap::default();
         let mut vertex_adjacency = vec![Vec::new(); self.positions().len()];
         self.tri_faces().iter().for_each(|face| {
@@ -51,11 +78,16 @@ impl Subdivision for PolygonMesh {
             add_vertex_edge(&mut edges, &mut vertex_adjacency, face[2], face[0], face[1]);
             add_vertex_edge(&mut edges, &mut vertex_adjacency, face[0], face[1], face[2]);
         });
+        let crease_set = creases.iter().map(|[v0, v1]| Edge::new(*v0, *v1)).collect();
+        let sharp = SharpEdges {
+            edges: &edges,
+            creases: &crease_set,
+        };
         let positions = vertex_adjacency
             .iter()
             .enumerate()
-            .map(|t| calc_new_position(t.0, t.1, &edges, self.positions()))
-            .chain(edge_positions(&edges, &vertex_adjacency, self.positions()))
+            .map(|t| calc_new_position(t.0, t.1, sharp, self.positions()))
+            .chain(edge_positions(sharp, &vertex_adjacency, self.positions()))
             .collect::<Vec<_>>();
         let tri_faces = self
             .tri_faces()
@@ -75,6 +107,8 @@ impl Subdivision for PolygonMesh {
                 ]
             })
             .collect::<Vec<_>>();
+        let len = self.positions().len();
+        *creases = subdivided_creases(creases, len, |edge| Some(edges.get(&edge)?.idx));
         {
             let editor = self.debug_editor();
             editor.attributes.positions = positions;
@@ -82,6 +116,152 @@ impl Subdivision for PolygonMesh {
         }
         self
     }
+    #[inline(always)]
+    fn catmull_clark_subdivision(&mut self) -> &mut Self {
+        self.catmull_clark_subdivision_with_creases(&mut Vec::new())
+    }
+    fn catmull_clark_subdivision_with_creases(
+        &mut self,
+        creases: &mut Vec<[usize; 2]>,
+    ) -> &mut Self {
+        let positions = self.positions();
+        let len = positions.len();
+        let faces = self
+            .face_iter()
+            .map(|face| face.iter().map(|v| v.pos).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // the map from edges to the indices and the adjacent faces
+        let mut edges = HashMap::<Edge, (usize, Vec<usize>)>::default();
+        let mut vertex_adjacency = vec![Vec::new(); len];
+        let mut vertex_faces = vec![Vec::new(); len];
+        faces.iter().enumerate().for_each(|(i, face)| {
+            (0..face.len()).for_each(|k| {
+                let (v0, v1) = (face[k], face[(k + 1) % face.len()]);
+                let idx = edges.len();
+                let (_, wings) = edges.entry(Edge::new(v0, v1)).or_insert_with(|| {
+                    vertex_adjacency[v0].push(v1);
+                    vertex_adjacency[v1].push(v0);
+                    (idx, Vec::new())
+                });
+                wings.push(i);
+                vertex_faces[v0].push(i);
+            })
+        });
+        let crease_set: HashSet<Edge> = creases
+            .iter()
+            .map(|[v0, v1]| Edge::new(*v0, *v1))
+            .collect();
+        let is_sharp = |edge: &Edge| edges[edge].1.len() != 2 || crease_set.contains(edge);
+
+        let face_points = faces
+            .iter()
+            .map(|face| {
+                let sum = face.iter().map(|v| positions[*v].to_vec()).sum::<Vector3>();
+                Point3::from_vec(sum / face.len() as f64)
+            })
+            .collect::<Vec<_>>();
+        let mut edge_points = vec![Point3::origin(); edges.len()];
+        edges.iter().for_each(|(edge, (idx, wings))| {
+            let Edge(v0, v1) = *edge;
+            let middle = positions[v0].midpoint(positions[v1]);
+            edge_points[*idx] = match is_sharp(edge) {
+                true => middle,
+                false => middle.midpoint(face_points[wings[0]].midpoint(face_points[wings[1]])),
+            };
+        });
+        let vertex_points = (0..len).map(|v| {
+            let (point, adjacency) = (positions[v], &vertex_adjacency[v]);
+            let sharp = adjacency
+                .iter()
+                .copied()
+                .filter(|w| is_sharp(&Edge::new(v, *w)))
+                .collect::<Vec<_>>();
+            match sharp.len() {
+                0 | 1 if !adjacency.is_empty() => {
+                    let n = adjacency.len() as f64;
+                    let f = vertex_faces[v]
+                        .iter()
+                        .map(|i| face_points[*i].to_vec())
+                        .sum::<Vector3>()
+                        / vertex_faces[v].len() as f64;
+                    let r = adjacency
+                        .iter()
+                        .map(|w| point.midpoint(positions[*w]).to_vec())
+                        .sum::<Vector3>()
+                        / n;
+                    Point3::from_vec((f + r * 2.0 + point.to_vec() * (n - 3.0)) / n)
+                }
+                2 if adjacency.len() > 2 => {
+                    point + ((positions[sharp[0]] - point) + (positions[sharp[1]] - point)) / 8.0
+                }
+                _ => point,
+            }
+        });
+        let positions = vertex_points
+            .chain(edge_points)
+            .chain(face_points)
+            .collect::<Vec<_>>();
+
+        let edge_index = |v0: usize, v1: usize| len + edges[&Edge::new(v0, v1)].0;
+        let face_offset = len + edges.len();
+        let quad_faces = faces
+            .iter()
+            .enumerate()
+            .flat_map(|(i, face)| {
+                let k = face.len();
+                (0..k).map(move |j| {
+                    [
+                        face[j],
+                        edge_index(face[j], face[(j + 1) % k]),
+                        face_offset + i,
+                        edge_index(face[(j + k - 1) % k], face[j]),
+                    ]
+                    .map(StandardVertex::from)
+                })
+            })
+            .collect::<Vec<_>>();
+        *creases = subdivided_creases(creases, len, |edge| Some(edges.get(&edge)?.0));
+        {
+            let editor = self.debug_editor();
+            editor.attributes.positions = positions;
+            *editor.faces = Faces::from_tri_and_quad_faces(Vec::new(), quad_faces);
+        }
+        self
+    }
+}
+
+/// the creases divided at the new points on the edges
+fn subdivided_creases(
+    creases: &[[usize; 2]],
+    len: usize,
+    edge_index: impl Fn(Edge) -> Option<usize>,
+) -> Vec<[usize; 2]> {
+    creases
+        .iter()
+        .filter_map(|[v0, v1]| {
+            let idx = len + edge_index(Edge::new(*v0, *v1))?;
+            Some([[*v0, idx], [idx, *v1]])
+        })
+        .flatten()
+        .collect()
+}
+
+/// the boundary edges and the crease edges
+#[derive(Clone, Copy, Debug)]
+struct SharpEdges<'a> {
+    edges: &'a HashMap<Edge, EdgeInfo>,
+    creases: &'a HashSet<Edge>,
+}
+
+impl<'a> SharpEdges<'a> {
+    #[inline(always)]
+    fn info(&self, edge: Edge) -> &'a EdgeInfo { self.edges.get(&edge).unwrap() }
+    #[inline(always)]
+    fn is_boundary(&self, edge: Edge) -> bool { self.info(edge).second_wing.is_none() }
+    #[inline(always)]
+    fn is_sharp(&self, edge: Edge) -> bool {
+        self.is_boundary(edge) || self.creases.contains(&edge)
+    }
 }
 
 fn add_vertex_edge(
@@ -104,29 +284,29 @@ fn add_vertex_edge(
 
 #[derive(Clone, Copy, Debug)]
 enum VertexBoundaryCondition {
-    Corner(usize, usize),
+    Corner,
     Boundary(usize, usize),
+    Crease(usize, usize),
     Inner,
 }
 
 impl VertexBoundaryCondition {
-    fn new(v: usize, adjacency: &[usize], edges: &HashMap<Edge, EdgeInfo>) -> Self {
-        let binfo = adjacency.iter().copied().fold((None, None), |binfo, w| {
-            let edge = edges.get(&Edge::new(v, w)).unwrap();
-            match (edge.second_wing, binfo) {
-                (None, (None, _)) => (Some(w), None),
-                (None, (Some(x), None)) => (Some(x), Some(w)),
-                _ => binfo,
-            }
-        });
-        if let (Some(x), Some(y)) = binfo {
-            if adjacency.len() == 2 {
-                Self::Corner(x, y)
-            } else {
-                Self::Boundary(x, y)
+    fn new(v: usize, adjacency: &[usize], sharp: SharpEdges<'_>) -> Self {
+        let sharp_adjacency = adjacency
+            .iter()
+            .copied()
+            .filter(|w| sharp.is_sharp(Edge::new(v, *w)))
+            .collect::<Vec<_>>();
+        match sharp_adjacency.len() {
+            0 | 1 => Self::Inner,
+            2 if adjacency.len() > 2 => {
+                let (x, y) = (sharp_adjacency[0], sharp_adjacency[1]);
+                match sharp.is_boundary(Edge::new(v, x)) && sharp.is_boundary(Edge::new(v, y)) {
+                    true => Self::Boundary(x, y),
+                    false => Self::Crease(x, y),
+                }
             }
-        } else {
-            Self::Inner
+            _ => Self::Corner,
         }
     }
 }
@@ -134,14 +314,16 @@ impl VertexBoundaryCondition {
 fn calc_new_position(
     v: usize,
     adjacency: &[usize],
-    edges: &HashMap<Edge, EdgeInfo>,
+    sharp: SharpEdges<'_>,
     positions: &[Point3],
 ) -> Point3 {
     use VertexBoundaryCondition::*;
     let point = positions[v];
-    match VertexBoundaryCondition::new(v, adjacency, edges) {
-        Corner(_, _) => point,
-        Boundary(w0, w1) => point + ((positions[w0] - point) + (positions[w1] - point)) / 8.0,
+    match VertexBoundaryCondition::new(v, adjacency, sharp) {
+        Corner => point,
+        Boundary(w0, w1) | Crease(w0, w1) => {
+            point + ((positions[w0] - point) + (positions[w1] - point)) / 8.0
+        }
         Inner => {
             let alpha = 3.0 / 8.0 + f64::cos(2.0 * PI / adjacency.len() as f64) / 4.0;
             let alpha = (5.0 / 8.0 - alpha * alpha) / adjacency.len() as f64;
@@ -157,13 +339,13 @@ fn calc_new_position(
 }
 
 fn edge_positions(
-    edges: &HashMap<Edge, EdgeInfo>,
+    sharp: SharpEdges<'_>,
     vertex_adjacency: &[Vec<usize>],
     positions: &[Point3],
 ) -> Vec<Point3> {
     use VertexBoundaryCondition::*;
-    let mut res = vec![Point3::origin(); edges.len()];
-    edges.iter().for_each(|tuple| {
+    let mut res = vec![Point3::origin(); sharp.edges.len()];
+    sharp.edges.iter().for_each(|tuple| {
         let Edge(v0, v1) = *tuple.0;
         let EdgeInfo {
             idx,
@@ -171,26 +353,24 @@ fn edge_positions(
             second_wing,
         } = *tuple.1;
         res[idx] = match second_wing {
-            Some(second_wing) => {
-                let gamma = match VertexBoundaryCondition::new(v0, &vertex_adjacency[v0], edges) {
-                    Boundary(_, _) => {
+            Some(second_wing) if !sharp.creases.contains(tuple.0) => {
+                let condition0 = VertexBoundaryCondition::new(v0, &vertex_adjacency[v0], sharp);
+                let condition1 = VertexBoundaryCondition::new(v1, &vertex_adjacency[v1], sharp);
+                let gamma = match (condition0, condition1) {
+                    (Boundary(_, _), _) => {
                         5.0 / 8.0 - f64::cos(PI / vertex_adjacency[v0].len() as f64) / 4.0
                     }
-                    Inner => match VertexBoundaryCondition::new(v1, &vertex_adjacency[v1], edges) {
-                        Boundary(_, _) => {
-                            3.0 / 8.0 + f64::cos(PI / vertex_adjacency[v1].len() as f64) / 4.0
-                        }
-                        Inner => 0.5,
-                        Corner(_, _) => unreachable!(),
-                    },
-                    Corner(_, _) => unreachable!(),
+                    (_, Boundary(_, _)) => {
+                        3.0 / 8.0 + f64::cos(PI / vertex_adjacency[v1].len() as f64) / 4.0
+                    }
+                    _ => 0.5,
                 };
                 positions[v0]
                     + (positions[v1] - positions[v0]) * gamma
                     + (positions[first_wing] - positions[v0]) / 8.0
                     + (positions[second_wing] - positions[v1]) / 8.0
             }
-            None => positions[v0].midpoint(positions[v1]),
+            _ => positions[v0].midpoint(positions[v1]),
         };
     });
     res
@@ -26,10 +26,22 @@ use truck_polymesh::{StandardVertex as Vertex, *};
 /// - determines topological properties: connectivity, boundary extraction, or shell conditions (closed or oriented)
 /// - detects collisions between two meshes and extracts interference lines
 /// - investigates positional relations between mesh and point clouds.
+/// - registers point clouds to meshes by ICP.
+/// - detects rigid transformations between congruent solids.
+/// - detects mirror and rotational symmetries of meshes and solids.
+/// - recognizes analytic primitive surfaces: planes, spheres, cylinders, cones, and tori.
+/// - recognizes machining features of solids: holes and fillets.
+/// - computes wall thickness of meshes and solids.
+/// - slices meshes and solids into layers for 3D printing.
+/// - generates toolpaths of 3-axis machining: parallel-plane finishing and waterline roughing.
+/// - flattens meshes onto the plane by unfolding and as-rigid-as-possible relaxation.
+/// - parameterizes meshes with disk topology by LSCM and ARAP into UV coordinates.
+/// - estimates discrete curvatures and extracts feature lines by dihedral angles.
+/// - segments meshes into near-planar and cylindrical regions by region growing.
 #[cfg(feature = "analyzers")]
 pub mod analyzers;
 mod common;
-/// Edits meshes. Add normals, optimizing data, and so on.
+/// Edits meshes and point clouds. Add normals, optimizing data, and so on.
 #[cfg(feature = "filters")]
 pub mod filters;
 /// Tessellates shapes.
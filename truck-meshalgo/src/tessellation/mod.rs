@@ -1,5 +1,6 @@
 use crate::*;
 use spade::{iterators::*, *};
+use std::sync::atomic::{AtomicBool, Ordering};
 use truck_topology::{compress::*, *};
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -92,34 +93,88 @@ pub use meshables_traits::*;
 
 type PolylineCurve = truck_polymesh::PolylineCurve<Point3>;
 
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Switches the deterministic mode of the tessellation.
+///
+/// In the deterministic mode, the edges and the faces are tessellated one by one in order
+/// instead of in parallel by rayon, so that the meshes, and the boolean operations of
+/// `truck-shapeops` using them, are bit-identical across runs and thread counts.
+/// # Examples
+/// ```
+/// use truck_meshalgo::{prelude::*, tessellation};
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// tessellation::set_deterministic(true);
+/// assert!(tessellation::is_deterministic());
+/// let mesh = cube.triangulation(0.01).to_polygon();
+/// assert_eq!(cube.triangulation(0.01).to_polygon(), mesh);
+/// tessellation::set_deterministic(false);
+/// ```
+#[inline(always)]
+pub fn set_deterministic(deterministic: bool) {
+    DETERMINISTIC.store(deterministic, Ordering::Relaxed)
+}
+
+/// Returns whether the tessellation is in the deterministic mode.
+/// cf. [`set_deterministic`]
+#[inline(always)]
+pub fn is_deterministic() -> bool { DETERMINISTIC.load(Ordering::Relaxed) }
+
 /// Trait for converting tessellated shape into polygon.
 pub trait MeshedShape {
     /// Converts tessellated shape into polygon.
     fn to_polygon(&self) -> PolygonMesh;
+    /// Converts tessellated shape into the polygons of the faces, in the order of the faces.
+    ///
+    /// The polygons are oriented as the faces, and the faces failed to be tessellated are
+    /// skipped. The default implementation returns the whole polygon as one face.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    /// let meshed = cube.triangulation(0.01);
+    /// let faces = meshed.to_face_polygons();
+    /// assert_eq!(faces.len(), 6);
+    ///
+    /// let count = faces.iter().map(|face| face.faces().len()).sum::<usize>();
+    /// assert_eq!(count, meshed.to_polygon().faces().len());
+    /// ```
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> { vec![self.to_polygon()] }
+}
+
+/// Merges the polygons of the faces into one polygon.
+fn merge_polygons(polygons: Vec<PolygonMesh>) -> PolygonMesh {
+    let mut polygon = PolygonMesh::default();
+    polygons.into_iter().for_each(|poly| polygon.merge(poly));
+    polygon
 }
 
 impl MeshedShape for Shell<Point3, PolylineCurve, PolygonMesh> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.face_iter().for_each(|face| {
-            polygon.merge(face.oriented_surface());
-        });
-        polygon
+    fn to_polygon(&self) -> PolygonMesh { merge_polygons(self.to_face_polygons()) }
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> {
+        self.face_iter().map(|face| face.oriented_surface()).collect()
     }
 }
 
 impl MeshedShape for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.face_iter().for_each(|face| {
-            if let Some(mut poly) = face.surface() {
-                if !face.orientation() {
-                    poly.invert();
-                }
-                polygon.merge(poly);
+    fn to_polygon(&self) -> PolygonMesh { merge_polygons(self.to_face_polygons()) }
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> {
+        let closure = |face: &Face<Point3, PolylineCurve, Option<PolygonMesh>>| {
+            let mut poly = face.surface()?;
+            if !face.orientation() {
+                poly.invert();
             }
-        });
-        polygon
+            Some(poly)
+        };
+        self.face_iter().filter_map(closure).collect()
     }
 }
 
@@ -133,31 +188,34 @@ where Shell<P, C, S>: MeshedShape
         });
         polygon
     }
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> {
+        let shells = self.boundaries().iter();
+        shells.flat_map(|shell| shell.to_face_polygons()).collect()
+    }
 }
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.faces.iter().for_each(|face| match face.orientation {
-            true => polygon.merge(face.surface.clone()),
-            false => polygon.merge(face.surface.inverse()),
-        });
-        polygon
+    fn to_polygon(&self) -> PolygonMesh { merge_polygons(self.to_face_polygons()) }
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> {
+        let closure = |face: &CompressedFace<PolygonMesh>| match face.orientation {
+            true => face.surface.clone(),
+            false => face.surface.inverse(),
+        };
+        self.faces.iter().map(closure).collect()
     }
 }
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.faces.iter().for_each(|face| {
-            if let Some(surface) = &face.surface {
-                match face.orientation {
-                    true => polygon.merge(surface.clone()),
-                    false => polygon.merge(surface.inverse()),
-                }
-            }
-        });
-        polygon
+    fn to_polygon(&self) -> PolygonMesh { merge_polygons(self.to_face_polygons()) }
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> {
+        let closure = |face: &CompressedFace<Option<PolygonMesh>>| {
+            let surface = face.surface.as_ref()?;
+            Some(match face.orientation {
+                true => surface.clone(),
+                false => surface.inverse(),
+            })
+        };
+        self.faces.iter().filter_map(closure).collect()
     }
 }
 
@@ -171,6 +229,10 @@ where CompressedShell<P, C, S>: MeshedShape
         });
         polygon
     }
+    fn to_face_polygons(&self) -> Vec<PolygonMesh> {
+        let shells = self.boundaries.iter();
+        shells.flat_map(|shell| shell.to_face_polygons()).collect()
+    }
 }
 
 /// Trait for tessellating `Shell` and `Solid`.
@@ -189,6 +251,10 @@ pub trait MeshableShape {
     /// - The tessellated mesh is not necessarily closed even if `self` is `Solid`.
     /// If you want to get closed mesh, use [`OptimizingFilter::put_together_same_attrs`].
     /// - This method requires that the curve ride strictly on a surface. If not, try [`RobustMeshableShape`].
+    /// - The edges and faces are tessellated in parallel, however, the result is bit-identical
+    /// regardless of the number of threads, since each of them is tessellated independently and
+    /// the results are collected in order. In order to tessellate them one by one, turn on
+    /// [`set_deterministic`].
     ///
     /// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
     ///
@@ -301,38 +367,114 @@ pub trait RobustMeshableShape {
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape;
 }
 
+/// Trait for tessellating `Shell`, `Solid` and `CompressedShell` with [`MeshingPreferences`].
+///
+/// The faces of `Shell` and `Solid` are keyed by [`FaceID`], and the faces of `CompressedShell`
+/// by their indices.
+pub trait PreferredMeshableShape<K>: MeshableShape {
+    /// Tessellates shapes with the density of each face given by `preferences`.
+    ///
+    /// The edges are divided by the finest density of the adjacent faces, so the meshes of the
+    /// faces share the boundary points as [`MeshableShape::triangulation`].
+    ///
+    /// # Panics
+    ///
+    /// The chordal tolerances of the densities must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    /// let shell = &cylinder.boundaries()[0];
+    ///
+    /// // the coarse preset, and the dense one for one of the two faces of the side
+    /// let dense = MeshingDensity {
+    ///     max_edge_length: Some(0.05),
+    ///     ..MeshingDensity::coarse()
+    /// };
+    /// let preferences = MeshingPreferences::new(MeshingDensity::coarse())
+    ///     .with_face(shell[1].id(), dense);
+    /// let meshed = shell.preferred_triangulation(&preferences);
+    ///
+    /// let count = |i: usize| meshed[i].surface().unwrap().positions().len();
+    /// assert!(count(1) > 10 * count(2));
+    /// ```
+    fn preferred_triangulation(&self, preferences: &MeshingPreferences<K>) -> Self::MeshedShape;
+}
+
 impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Shell<Point3, C, S> {
     type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     fn triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
         #[cfg(not(target_arch = "wasm32"))]
-        let res = triangulation::shell_tessellation(self, tol, triangulation::by_search_parameter);
+        let res = triangulation::shell_tessellation(
+            self,
+            &MeshingDensity::chordal(tol).into(),
+            triangulation::by_search_parameter,
+        );
         #[cfg(target_arch = "wasm32")]
         let res = triangulation::shell_tessellation_single_thread(
             self,
-            tol,
+            &MeshingDensity::chordal(tol).into(),
             triangulation::by_search_parameter,
         );
+        #[cfg(feature = "tracing")]
+        trace_meshed_shell(&res);
         res
     }
 }
 
 impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape for Shell<Point3, C, S> {
     type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
         #[cfg(not(target_arch = "wasm32"))]
         let res = triangulation::shell_tessellation(
             self,
-            tol,
+            &MeshingDensity::chordal(tol).into(),
             triangulation::by_search_nearest_parameter,
         );
         #[cfg(target_arch = "wasm32")]
         let res = triangulation::shell_tessellation_single_thread(
             self,
-            tol,
+            &MeshingDensity::chordal(tol).into(),
             triangulation::by_search_nearest_parameter,
         );
+        #[cfg(feature = "tracing")]
+        trace_meshed_shell(&res);
+        res
+    }
+}
+
+impl<C, S> PreferredMeshableShape<FaceID<S>> for Shell<Point3, C, S>
+where
+    C: PolylineableCurve,
+    S: MeshableSurface,
+{
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    fn preferred_triangulation(
+        &self,
+        preferences: &MeshingPreferences<FaceID<S>>,
+    ) -> Self::MeshedShape {
+        nonpositive_preferences(preferences);
+        #[cfg(not(target_arch = "wasm32"))]
+        let res = triangulation::shell_tessellation(
+            self,
+            preferences,
+            triangulation::by_search_parameter,
+        );
+        #[cfg(target_arch = "wasm32")]
+        let res = triangulation::shell_tessellation_single_thread(
+            self,
+            preferences,
+            triangulation::by_search_parameter,
+        );
+        #[cfg(feature = "tracing")]
+        trace_meshed_shell(&res);
         res
     }
 }
@@ -349,6 +491,24 @@ impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Solid<Point3, C
     }
 }
 
+impl<C, S> PreferredMeshableShape<FaceID<S>> for Solid<Point3, C, S>
+where
+    C: PolylineableCurve,
+    S: MeshableSurface,
+{
+    fn preferred_triangulation(
+        &self,
+        preferences: &MeshingPreferences<FaceID<S>>,
+    ) -> Self::MeshedShape {
+        let boundaries = self
+            .boundaries()
+            .iter()
+            .map(|shell| shell.preferred_triangulation(preferences))
+            .collect::<Vec<_>>();
+        Solid::new(boundaries)
+    }
+}
+
 impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape for Solid<Point3, C, S> {
     type MeshedShape = Solid<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
@@ -365,7 +525,23 @@ impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for CompressedShell
     type MeshedShape = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
-        triangulation::cshell_tessellation(self, tol, triangulation::by_search_parameter)
+        let preferences = MeshingDensity::chordal(tol).into();
+        triangulation::cshell_tessellation(self, &preferences, triangulation::by_search_parameter)
+    }
+}
+
+impl<C, S> PreferredMeshableShape<usize> for CompressedShell<Point3, C, S>
+where
+    C: PolylineableCurve,
+    S: MeshableSurface,
+{
+    fn preferred_triangulation(
+        &self,
+        preferences: &MeshingPreferences<usize>,
+    ) -> Self::MeshedShape {
+        nonpositive_preferences(preferences);
+        let sp = triangulation::by_search_parameter;
+        triangulation::cshell_tessellation(self, preferences, sp)
     }
 }
 
@@ -375,7 +551,9 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     type MeshedShape = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
-        triangulation::cshell_tessellation(self, tol, triangulation::by_search_nearest_parameter)
+        let preferences = MeshingDensity::chordal(tol).into();
+        let sp = triangulation::by_search_nearest_parameter;
+        triangulation::cshell_tessellation(self, &preferences, sp)
     }
 }
 
@@ -405,4 +583,92 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+/// Panics if the chordal tolerances of `preferences` are not more than `TOLERANCE`.
+fn nonpositive_preferences<K>(preferences: &MeshingPreferences<K>) {
+    nonpositive_tolerance!(preferences.density.chordal_tolerance);
+    preferences
+        .overrides
+        .values()
+        .for_each(|density| nonpositive_tolerance!(density.chordal_tolerance));
+}
+
+/// Records the numbers of the generated triangles and the faces failed to be tessellated.
+#[cfg(feature = "tracing")]
+fn trace_meshed_shell(shell: &Shell<Point3, PolylineCurve, Option<PolygonMesh>>) {
+    let (mut triangles, mut failures) = (0, 0);
+    shell.face_iter().for_each(|face| match face.surface() {
+        Some(poly) => triangles += poly.faces().triangle_iter().count(),
+        None => failures += 1,
+    });
+    if failures > 0 {
+        tracing::warn!(failures, "some faces are not tessellated");
+    }
+    tracing::debug!(faces = shell.len(), triangles, "tessellated shell");
+}
+
+/// Trait for creating the iso-parameter lines of `Shell`, `Solid` and `CompressedShell`.
+pub trait IsolineShape {
+    /// Creates the u- and v-iso-parameter lines of the faces, trimmed by the boundaries.
+    ///
+    /// The parameter domain of each face is divided into `division.0` parts in the u-direction
+    /// and `division.1` parts in the v-direction, and the lines are placed on the inner division
+    /// points. The lines are approximated by polylines with the tolerance `tol`, and can be
+    /// overlaid on the tessellated mesh as wireframes.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// // modeling a unit cube
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// // the center lines of the faces
+    /// let isolines = cube.isolines((2, 2), 0.01);
+    /// assert_eq!(isolines.len(), 12);
+    /// isolines.iter().for_each(|line| {
+    ///     let (p, q) = (line[0], line[line.len() - 1]);
+    ///     assert!(p.distance(q).near(&1.0));
+    ///     let mid = p.midpoint(q);
+    ///     let on_center = [mid.x, mid.y, mid.z].into_iter().filter(|x| x.near(&0.5)).count();
+    ///     assert_eq!(on_center, 2);
+    /// });
+    /// ```
+    fn isolines(&self, division: (usize, usize), tol: f64) -> Vec<PolylineCurve>;
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> IsolineShape for Shell<Point3, C, S> {
+    fn isolines(&self, division: (usize, usize), tol: f64) -> Vec<PolylineCurve> {
+        nonpositive_tolerance!(tol);
+        triangulation::shell_isolines(self, division, tol)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> IsolineShape for Solid<Point3, C, S> {
+    fn isolines(&self, division: (usize, usize), tol: f64) -> Vec<PolylineCurve> {
+        self.boundaries()
+            .iter()
+            .flat_map(|shell| shell.isolines(division, tol))
+            .collect()
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> IsolineShape for CompressedShell<Point3, C, S> {
+    fn isolines(&self, division: (usize, usize), tol: f64) -> Vec<PolylineCurve> {
+        nonpositive_tolerance!(tol);
+        triangulation::cshell_isolines(self, division, tol)
+    }
+}
+
+mod preferences;
+pub use preferences::{MeshingDensity, MeshingPreferences};
+mod referenced;
+pub use referenced::{ReferencedMeshableShape, ReferencedPolygonMesh};
 mod triangulation;
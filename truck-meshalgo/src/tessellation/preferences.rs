@@ -0,0 +1,254 @@
+use super::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The maximum depth of the bisections of the division intervals by the angular tolerance and
+/// the maximum edge length
+const MAX_REFINEMENT_DEPTH: usize = 16;
+
+/// The density of the tessellation
+///
+/// The chordal tolerance is passed to [`ParameterDivision1D`] and [`ParameterDivision2D`], and
+/// the divisions are bisected until the other conditions are satisfied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshingDensity {
+    /// the maximum distance between the mesh and the shape
+    pub chordal_tolerance: f64,
+    /// the maximum angle between the tangents of curves or the normals of surfaces at the ends
+    /// of each division interval
+    pub angular_tolerance: Option<Rad<f64>>,
+    /// the maximum length of the polyline segments and the spacing of the division grids of
+    /// surfaces
+    pub max_edge_length: Option<f64>,
+}
+
+impl MeshingDensity {
+    /// Creates the density only by the chordal tolerance `tol`, which is the same as
+    /// [`MeshableShape::triangulation`].
+    #[inline(always)]
+    pub const fn chordal(tol: f64) -> Self {
+        Self {
+            chordal_tolerance: tol,
+            angular_tolerance: None,
+            max_edge_length: None,
+        }
+    }
+    /// the preset for previewing: chordal tolerance 0.05 and angular tolerance 45 degrees.
+    #[inline(always)]
+    pub const fn coarse() -> Self {
+        Self {
+            chordal_tolerance: 0.05,
+            angular_tolerance: Some(Rad(std::f64::consts::FRAC_PI_4)),
+            max_edge_length: None,
+        }
+    }
+    /// the preset for interactive rendering: chordal tolerance 0.01 and angular tolerance
+    /// 22.5 degrees.
+    #[inline(always)]
+    pub const fn medium() -> Self {
+        Self {
+            chordal_tolerance: 0.01,
+            angular_tolerance: Some(Rad(std::f64::consts::FRAC_PI_8)),
+            max_edge_length: None,
+        }
+    }
+    /// the preset for the final output: chordal tolerance 0.001 and angular tolerance
+    /// 11.25 degrees.
+    #[inline(always)]
+    pub const fn fine() -> Self {
+        Self {
+            chordal_tolerance: 0.001,
+            angular_tolerance: Some(Rad(std::f64::consts::FRAC_PI_8 / 2.0)),
+            max_edge_length: None,
+        }
+    }
+    /// Returns the density satisfying both of `self` and `other`.
+    pub fn finer(&self, other: &Self) -> Self {
+        let min = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => Some(f64::min(a, b)),
+            _ => a.or(b),
+        };
+        let angle = |density: &Self| density.angular_tolerance.map(|a| a.0);
+        let angular_tolerance = min(angle(self), angle(other));
+        Self {
+            chordal_tolerance: f64::min(self.chordal_tolerance, other.chordal_tolerance),
+            angular_tolerance: angular_tolerance.map(Rad),
+            max_edge_length: min(self.max_edge_length, other.max_edge_length),
+        }
+    }
+}
+
+impl Default for MeshingDensity {
+    #[inline(always)]
+    fn default() -> Self { Self::medium() }
+}
+
+/// The preferences of the tessellation: the global density and the overrides of the faces
+///
+/// The faces are keyed by [`FaceID`] for [`Shell`] and [`Solid`], and by the indices of
+/// [`CompressedShell::faces`] for [`CompressedShell`]. The edges are tessellated by the finest
+/// density of the adjacent faces, so that the meshes of the faces share the boundary points.
+#[derive(Clone, Debug)]
+pub struct MeshingPreferences<K> {
+    /// the density of the faces which are not overridden
+    pub density: MeshingDensity,
+    /// the densities of the specific faces
+    pub overrides: HashMap<K, MeshingDensity>,
+}
+
+impl<K> MeshingPreferences<K> {
+    /// Creates the preferences with the global `density` and no overrides.
+    #[inline(always)]
+    pub fn new(density: MeshingDensity) -> Self {
+        Self {
+            density,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> MeshingPreferences<K> {
+    /// Overrides the density of the face with `key`.
+    #[inline(always)]
+    pub fn with_face(mut self, key: K, density: MeshingDensity) -> Self {
+        self.overrides.insert(key, density);
+        self
+    }
+    /// Returns the density of the face with `key`.
+    #[inline(always)]
+    pub fn face_density(&self, key: &K) -> MeshingDensity {
+        self.overrides.get(key).copied().unwrap_or(self.density)
+    }
+}
+
+impl<S> MeshingPreferences<FaceID<S>> {
+    /// Returns the finest densities of the faces adjacent to the edges of `shell`. The map is
+    /// empty if no face is overridden, that is, all the edges have the global density.
+    pub(super) fn edge_densities<C>(
+        &self,
+        shell: &Shell<Point3, C, S>,
+    ) -> HashMap<EdgeID<C>, MeshingDensity> {
+        let mut res = HashMap::<EdgeID<C>, MeshingDensity>::new();
+        if self.overrides.is_empty() {
+            return res;
+        }
+        shell.face_iter().for_each(|face| {
+            let density = self.face_density(&face.id());
+            face.edge_iter().for_each(|edge| {
+                res.entry(edge.id())
+                    .and_modify(|d| *d = d.finer(&density))
+                    .or_insert(density);
+            });
+        });
+        res
+    }
+}
+
+impl MeshingPreferences<usize> {
+    /// Returns the finest densities of the faces adjacent to the edges of `shell`, in the order
+    /// of [`CompressedShell::edges`].
+    pub(super) fn compressed_edge_densities<C, S>(
+        &self,
+        shell: &CompressedShell<Point3, C, S>,
+    ) -> Vec<MeshingDensity> {
+        let mut res = vec![None::<MeshingDensity>; shell.edges.len()];
+        shell.faces.iter().enumerate().for_each(|(i, face)| {
+            let density = self.face_density(&i);
+            face.boundaries.iter().flatten().for_each(|edge_idx| {
+                if let Some(d) = res.get_mut(edge_idx.index) {
+                    *d = Some(d.map_or(density, |d| d.finer(&density)));
+                }
+            });
+        });
+        res.into_iter().map(|d| d.unwrap_or(self.density)).collect()
+    }
+}
+
+impl<K> Default for MeshingPreferences<K> {
+    #[inline(always)]
+    fn default() -> Self { Self::new(MeshingDensity::default()) }
+}
+
+impl<K> From<MeshingDensity> for MeshingPreferences<K> {
+    #[inline(always)]
+    fn from(density: MeshingDensity) -> Self { Self::new(density) }
+}
+
+/// Returns `true` if the angle between `a` and `b` is larger than `angle`. Degenerate vectors
+/// are not compared.
+fn angle_exceeds(a: Vector3, b: Vector3, angle: Option<Rad<f64>>) -> bool {
+    match angle {
+        Some(angle) if !a.so_small() && !b.so_small() => a.angle(b) > angle,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the segment `[a, b]` is longer than `length`.
+fn length_exceeds(a: Point3, b: Point3, length: Option<f64>) -> bool {
+    length.map_or(false, |length| a.distance2(b) > length * length)
+}
+
+/// Inserts the midpoints into the intervals of `division` recursively while `split` returns
+/// `true` for the ends of the interval.
+fn bisect(division: Vec<f64>, split: impl Fn(f64, f64) -> bool) -> Vec<f64> {
+    fn sub(t0: f64, t1: f64, depth: usize, split: &impl Fn(f64, f64) -> bool, res: &mut Vec<f64>) {
+        if depth < MAX_REFINEMENT_DEPTH && split(t0, t1) {
+            let t = (t0 + t1) / 2.0;
+            sub(t0, t, depth + 1, split, res);
+            sub(t, t1, depth + 1, split, res);
+        } else {
+            res.push(t1);
+        }
+    }
+    let mut res = Vec::with_capacity(division.len());
+    if let Some(t) = division.first() {
+        res.push(*t);
+    }
+    division
+        .windows(2)
+        .for_each(|w| sub(w[0], w[1], 0, &split, &mut res));
+    res
+}
+
+/// Divides `curve` into the polyline with `density`.
+pub(super) fn curve_division<C>(curve: &C, density: MeshingDensity) -> PolylineCurve
+where C: PolylineableCurve {
+    let range = curve.parameter_range();
+    let (division, points) = curve.parameter_division(range, density.chordal_tolerance);
+    if density.angular_tolerance.is_none() && density.max_edge_length.is_none() {
+        return PolylineCurve(points);
+    }
+    let division = bisect(division, |t0, t1| {
+        angle_exceeds(curve.der(t0), curve.der(t1), density.angular_tolerance)
+            || length_exceeds(curve.subs(t0), curve.subs(t1), density.max_edge_length)
+    });
+    division.into_iter().map(|t| curve.subs(t)).collect()
+}
+
+/// Divides the parameter `range` of `surface` with `density`.
+pub(super) fn surface_division<S>(
+    surface: &S,
+    range: ((f64, f64), (f64, f64)),
+    density: MeshingDensity,
+) -> (Vec<f64>, Vec<f64>)
+where
+    S: PreMeshableSurface,
+{
+    let (udiv, vdiv) = surface.parameter_division(range, density.chordal_tolerance);
+    if density.angular_tolerance.is_none() && density.max_edge_length.is_none() {
+        return (udiv, vdiv);
+    }
+    let split = |(u0, v0): (f64, f64), (u1, v1): (f64, f64)| {
+        let (n0, n1) = (surface.normal(u0, v0), surface.normal(u1, v1));
+        let (p0, p1) = (surface.subs(u0, v0), surface.subs(u1, v1));
+        angle_exceeds(n0, n1, density.angular_tolerance)
+            || length_exceeds(p0, p1, density.max_edge_length)
+    };
+    let udiv = bisect(udiv, |u0, u1| {
+        vdiv.iter().any(|v| split((u0, *v), (u1, *v)))
+    });
+    let vdiv = bisect(vdiv, |v0, v1| {
+        udiv.iter().any(|u| split((*u, v0), (*u, v1)))
+    });
+    (udiv, vdiv)
+}
@@ -0,0 +1,205 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Polygon mesh of a tessellated shape with the references to the original faces and edges.
+#[derive(Clone, Debug)]
+pub struct ReferencedPolygonMesh<C, S> {
+    polygon: PolygonMesh,
+    triangle_faces: Vec<FaceID<S>>,
+    vertex_edges: Vec<Option<EdgeID<C>>>,
+}
+
+/// Trait for tessellating `Shell` and `Solid` with the references to the original topology.
+pub trait ReferencedMeshableShape<C, S> {
+    /// Tessellates the shape by [`MeshableShape::triangulation`], and converts it into a polygon
+    /// mesh with the references to the original faces and edges.
+    ///
+    /// The references can be used for mapping the mesh entities back to the B-rep entities,
+    /// e.g. for picking or assigning boundary conditions of the finite element method.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// // modeling a unit cube
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let mesh = cube.referenced_triangulation(0.01);
+    /// let polygon = mesh.polygon();
+    /// assert_eq!(mesh.triangle_faces().len(), polygon.faces().triangle_iter().count());
+    /// assert_eq!(mesh.vertex_edges().len(), polygon.positions().len());
+    ///
+    /// // the triangles on the bottom
+    /// let bottom = cube.boundaries()[0][0].id();
+    /// polygon
+    ///     .faces()
+    ///     .triangle_iter()
+    ///     .zip(mesh.triangle_faces())
+    ///     .filter(|(_, id)| **id == bottom)
+    ///     .for_each(|(tri, _)| {
+    ///         tri.iter().for_each(|v| assert!(polygon.positions()[v.pos].z.so_small()))
+    ///     });
+    ///
+    /// // the positions on the edges of the cube refer to the edges
+    /// polygon
+    ///     .positions()
+    ///     .iter()
+    ///     .zip(mesh.vertex_edges())
+    ///     .for_each(|(p, edge)| {
+    ///         let on_edge = [p.x, p.y, p.z]
+    ///             .into_iter()
+    ///             .filter(|x| x.so_small() || x.near(&1.0))
+    ///             .count()
+    ///             >= 2;
+    ///         assert_eq!(on_edge, edge.is_some());
+    ///     });
+    /// ```
+    fn referenced_triangulation(&self, tol: f64) -> ReferencedPolygonMesh<C, S>;
+}
+
+impl<C, S> ReferencedPolygonMesh<C, S> {
+    /// Returns the polygon mesh.
+    #[inline(always)]
+    pub fn polygon(&self) -> &PolygonMesh { &self.polygon }
+    /// Returns the original face of each triangle, in the order of `triangle_iter`.
+    #[inline(always)]
+    pub fn triangle_faces(&self) -> &Vec<FaceID<S>> { &self.triangle_faces }
+    /// Returns the original edge of each position.
+    ///
+    /// The positions in the interior of faces are `None`. The positions of the vertices of the shape
+    /// refer to one of the incident edges.
+    #[inline(always)]
+    pub fn vertex_edges(&self) -> &Vec<Option<EdgeID<C>>> { &self.vertex_edges }
+    /// Returns the polygon mesh, dropping the references.
+    #[inline(always)]
+    pub fn into_polygon(self) -> PolygonMesh { self.polygon }
+    /// Creates the references from `shell` and its mesh `meshed` with the same topology,
+    /// e.g. the mesh given by [`MeshableShape::triangulation`] and then modified into quadrangles.
+    ///
+    /// The faces of the meshes may be triangles, quadrangles and the other polygons, and each
+    /// triangle of `triangle_iter` refers to its original face.
+    pub fn from_meshed_shell<P>(
+        shell: &Shell<P, C, S>,
+        meshed: &Shell<Point3, PolylineCurve, Option<PolygonMesh>>,
+    ) -> Self {
+        let mut builder = ReferenceBuilder::default();
+        builder.push_shell(shell, meshed);
+        builder.build()
+    }
+}
+
+impl<C, S> Default for ReferencedPolygonMesh<C, S> {
+    fn default() -> Self {
+        Self {
+            polygon: PolygonMesh::default(),
+            triangle_faces: Vec::new(),
+            vertex_edges: Vec::new(),
+        }
+    }
+}
+
+/// the references of the faces of each kind of polygons, since `PolygonMesh::merge`
+/// concatenates the triangles, the quadrangles and the other polygons separately.
+struct ReferenceBuilder<C, S> {
+    polygon: PolygonMesh,
+    tri_faces: Vec<FaceID<S>>,
+    quad_faces: Vec<FaceID<S>>,
+    // the face and the number of the triangles of each n-gon
+    other_faces: Vec<(FaceID<S>, usize)>,
+    vertex_edges: Vec<Option<EdgeID<C>>>,
+}
+
+impl<C, S> Default for ReferenceBuilder<C, S> {
+    fn default() -> Self {
+        Self {
+            polygon: PolygonMesh::default(),
+            tri_faces: Vec::new(),
+            quad_faces: Vec::new(),
+            other_faces: Vec::new(),
+            vertex_edges: Vec::new(),
+        }
+    }
+}
+
+impl<C, S> ReferenceBuilder<C, S> {
+    fn push_shell<P>(
+        &mut self,
+        shell: &Shell<P, C, S>,
+        meshed: &Shell<Point3, PolylineCurve, Option<PolygonMesh>>,
+    ) {
+        // The order of faces and edges are preserved by the tessellation.
+        let mut faces = shell.face_iter().zip(meshed.face_iter());
+        faces.for_each(|(face, meshed_face)| self.push_face(face, meshed_face));
+    }
+
+    fn push_face<P>(
+        &mut self,
+        face: &Face<P, C, S>,
+        meshed_face: &Face<Point3, PolylineCurve, Option<PolygonMesh>>,
+    ) {
+        let mut poly = match meshed_face.surface() {
+            Some(poly) => poly,
+            None => return,
+        };
+        if !meshed_face.orientation() {
+            poly.invert();
+        }
+        let key = |p: &Point3| [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()];
+        let mut edge_map = HashMap::default();
+        face.absolute_boundaries()
+            .iter()
+            .zip(meshed_face.absolute_boundaries())
+            .flat_map(|(wire, meshed_wire)| wire.edge_iter().zip(meshed_wire.edge_iter()))
+            .for_each(|(edge, meshed_edge)| {
+                meshed_edge.curve().iter().for_each(|p| {
+                    edge_map.entry(key(p)).or_insert_with(|| edge.id());
+                })
+            });
+        let (faces, id) = (poly.faces(), face.id());
+        let repeat = |len: usize| std::iter::repeat(id).take(len);
+        self.tri_faces.extend(repeat(faces.tri_faces().len()));
+        self.quad_faces.extend(repeat(faces.quad_faces().len()));
+        let others = faces.other_faces().iter().map(|face| (id, face.len() - 2));
+        self.other_faces.extend(others);
+        let edges = poly.positions().iter().map(|p| edge_map.get(&key(p)).copied());
+        self.vertex_edges.extend(edges);
+        self.polygon.merge(poly);
+    }
+
+    /// the references in the order of `triangle_iter` of the merged polygon
+    fn build(self) -> ReferencedPolygonMesh<C, S> {
+        let quads = self.quad_faces.into_iter().flat_map(|id| [id, id]);
+        let others = self.other_faces.into_iter();
+        let others = others.flat_map(|(id, len)| std::iter::repeat(id).take(len));
+        let mut triangle_faces = self.tri_faces;
+        triangle_faces.extend(quads.chain(others));
+        ReferencedPolygonMesh {
+            polygon: self.polygon,
+            triangle_faces,
+            vertex_edges: self.vertex_edges,
+        }
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> ReferencedMeshableShape<C, S>
+    for Shell<Point3, C, S>
+{
+    fn referenced_triangulation(&self, tol: f64) -> ReferencedPolygonMesh<C, S> {
+        ReferencedPolygonMesh::from_meshed_shell(self, &self.triangulation(tol))
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> ReferencedMeshableShape<C, S>
+    for Solid<Point3, C, S>
+{
+    fn referenced_triangulation(&self, tol: f64) -> ReferencedPolygonMesh<C, S> {
+        let mut builder = ReferenceBuilder::default();
+        self.boundaries()
+            .iter()
+            .for_each(|shell| builder.push_shell(shell, &shell.triangulation(tol)));
+        builder.build()
+    }
+}
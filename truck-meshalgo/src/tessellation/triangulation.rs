@@ -1,10 +1,13 @@
 #![allow(clippy::many_single_char_names)]
 
+use super::preferences::{curve_division, surface_division};
 use super::*;
 use crate::filters::NormalFilters;
 use crate::Point2;
-use rustc_hash::FxHashMap as HashMap;
-use truck_base::entry_map::FxEntryMap as EntryMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use spade::handles::{FixedFaceHandle, FixedVertexHandle, InnerTag};
+use std::collections::VecDeque;
+use truck_base::{entry_map::FxEntryMap as EntryMap, predicates::orient2d};
 use truck_topology::Vertex as TVertex;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -15,6 +18,10 @@ type Cdt = ConstrainedDelaunayTriangulation<SPoint2>;
 type MeshedShell = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
 type MeshedCShell = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
 
+/// The interior division points closer to the boundary than this ratio of the division widths
+/// are not inserted, since they make sliver triangles.
+const SLIVER_RATIO: f64 = 0.3;
+
 pub(super) fn by_search_parameter<S>(
     surface: &S,
     point: Point3,
@@ -45,7 +52,7 @@ where
 #[cfg(not(target_arch = "wasm32"))]
 pub(super) fn shell_tessellation<'a, C, S, F>(
     shell: &Shell<Point3, C, S>,
-    tol: f64,
+    preferences: &MeshingPreferences<FaceID<S>>,
     sp: F,
 ) -> MeshedShell
 where
@@ -53,18 +60,22 @@ where
     S: PreMeshableSurface + 'a,
     F: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> + Sync + Send,
 {
+    if is_deterministic() {
+        return shell_tessellation_single_thread(shell, preferences, sp);
+    }
     let vmap: HashMap<_, _> = shell
         .vertex_par_iter()
         .map(|v| (v.id(), v.mapped(Point3::clone)))
         .collect();
     let eset: HashMap<_, _> = shell.edge_par_iter().map(move |e| (e.id(), e)).collect();
+    let edge_densities = preferences.edge_densities(shell);
     let edge_map: HashMap<_, _> = eset
         .into_par_iter()
-        .map(move |(id, edge)| {
+        .map(|(id, edge)| {
             let v0 = vmap.get(&edge.absolute_front().id()).unwrap();
             let v1 = vmap.get(&edge.absolute_back().id()).unwrap();
-            let curve = edge.curve();
-            let poly = PolylineCurve::from_curve(&curve, curve.parameter_range(), tol);
+            let density = edge_densities.get(&id).copied();
+            let poly = curve_division(&edge.curve(), density.unwrap_or(preferences.density));
             (id, Edge::debug_new(v0, v1, poly))
         })
         .collect();
@@ -79,7 +90,7 @@ where
         |wire: &Wire<Point3, C>| -> Wire<_, _> { wire.edge_iter().map(create_edge).collect() };
     shell
         .face_par_iter()
-        .map(move |face| {
+        .map(|face| {
             let wires: Vec<_> = face
                 .absolute_boundaries()
                 .iter()
@@ -90,7 +101,10 @@ where
             let polygon = match wires.iter().all(|wire: &Wire<_, _>| {
                 polyline.add_wire(&surface, wire.iter().map(Edge::oriented_curve), &sp)
             }) {
-                true => Some(trimming_tessellation(&surface, &polyline, tol)),
+                true => {
+                    let density = preferences.face_density(&face.id());
+                    Some(trimming_tessellation(&surface, &polyline, density))
+                }
                 false => None,
             };
             let mut new_face = Face::debug_new(wires, polygon);
@@ -103,10 +117,9 @@ where
 }
 
 /// Tessellates faces
-#[allow(dead_code)]
 pub(super) fn shell_tessellation_single_thread<'a, C, S, F>(
     shell: &Shell<Point3, C, S>,
-    tol: f64,
+    preferences: &MeshingPreferences<FaceID<S>>,
     sp: F,
 ) -> MeshedShell
 where
@@ -118,6 +131,7 @@ where
         move |v: &TVertex<Point3>| v.id(),
         move |v| v.mapped(Point3::clone),
     );
+    let edge_densities = preferences.edge_densities(shell);
     let mut edge_map = EntryMap::new(
         move |edge: &Edge<Point3, C>| edge.id(),
         move |edge| {
@@ -125,8 +139,8 @@ where
             let v0 = vmap.entry_or_insert(vf).clone();
             let vb = edge.absolute_back();
             let v1 = vmap.entry_or_insert(vb).clone();
-            let curve = edge.curve();
-            let poly = PolylineCurve::from_curve(&curve, curve.parameter_range(), tol);
+            let density = edge_densities.get(&edge.id()).copied();
+            let poly = curve_division(&edge.curve(), density.unwrap_or(preferences.density));
             Edge::debug_new(&v0, &v1, poly)
         },
     );
@@ -153,7 +167,10 @@ where
             let polygon = match wires.iter().all(|wire: &Wire<_, _>| {
                 polyline.add_wire(&surface, wire.iter().map(|edge| edge.oriented_curve()), &sp)
             }) {
-                true => Some(trimming_tessellation(&surface, &polyline, tol)),
+                true => {
+                    let density = preferences.face_density(&face.id());
+                    Some(trimming_tessellation(&surface, &polyline, density))
+                }
                 false => None,
             };
             let mut new_face = Face::debug_new(wires, polygon);
@@ -168,7 +185,7 @@ where
 /// Tessellates faces
 pub(super) fn cshell_tessellation<'a, C, S, F>(
     shell: &CompressedShell<Point3, C, S>,
-    tol: f64,
+    preferences: &MeshingPreferences<usize>,
     sp: F,
 ) -> MeshedCShell
 where
@@ -177,18 +194,28 @@ where
     F: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> + Sync + Send,
 {
     let vertices = shell.vertices.clone();
-    let tessellate_edge = |edge: &CompressedEdge<C>| {
-        let curve = &edge.curve;
-        CompressedEdge {
-            vertices: edge.vertices,
-            curve: PolylineCurve::from_curve(curve, curve.parameter_range(), tol),
-        }
+    let edge_densities = preferences.compressed_edge_densities(shell);
+    let tessellate_edge = |(edge, density): (&CompressedEdge<C>, &MeshingDensity)| CompressedEdge {
+        vertices: edge.vertices,
+        curve: curve_division(&edge.curve, *density),
+    };
+    let parallel = cfg!(not(target_arch = "wasm32")) && !is_deterministic();
+    let edges: Vec<_> = match parallel {
+        #[cfg(not(target_arch = "wasm32"))]
+        true => shell
+            .edges
+            .par_iter()
+            .zip(&edge_densities)
+            .map(tessellate_edge)
+            .collect(),
+        _ => shell
+            .edges
+            .iter()
+            .zip(&edge_densities)
+            .map(tessellate_edge)
+            .collect(),
     };
-    #[cfg(not(target_arch = "wasm32"))]
-    let edges: Vec<_> = shell.edges.par_iter().map(tessellate_edge).collect();
-    #[cfg(target_arch = "wasm32")]
-    let edges: Vec<_> = shell.edges.iter().map(tessellate_edge).collect();
-    let tessellate_face = |face: &CompressedFace<S>| {
+    let tessellate_face = |(i, face): (usize, &CompressedFace<S>)| {
         let boundaries = face.boundaries.clone();
         let surface = &face.surface;
         let mut polyline = Polyline::default();
@@ -201,7 +228,10 @@ where
                 });
             polyline.add_wire(surface, wire_iter, &sp)
         }) {
-            true => Some(trimming_tessellation(surface, &polyline, tol)),
+            true => {
+                let density = preferences.face_density(&i);
+                Some(trimming_tessellation(surface, &polyline, density))
+            }
             false => None,
         };
         CompressedFace {
@@ -210,10 +240,21 @@ where
             surface: polygon,
         }
     };
-    #[cfg(not(target_arch = "wasm32"))]
-    let faces = shell.faces.par_iter().map(tessellate_face).collect();
-    #[cfg(target_arch = "wasm32")]
-    let faces = shell.faces.iter().map(tessellate_face).collect();
+    let faces = match parallel {
+        #[cfg(not(target_arch = "wasm32"))]
+        true => shell
+            .faces
+            .par_iter()
+            .enumerate()
+            .map(tessellate_face)
+            .collect(),
+        _ => shell
+            .faces
+            .iter()
+            .enumerate()
+            .map(tessellate_face)
+            .collect(),
+    };
     MeshedCShell {
         vertices,
         edges,
@@ -225,6 +266,8 @@ where
 #[derive(Debug, Default, Clone)]
 struct Polyline {
     positions: Vec<Point2>,
+    /// the points of the edge polylines corresponding to `positions`
+    points: Vec<Point3>,
     indices: Vec<[usize; 2]>,
 }
 
@@ -279,7 +322,11 @@ impl Polyline {
                     }
                 }
                 previous = hint;
-                hint.map(|hint| self.positions.push(hint.into())).is_some()
+                hint.map(|hint| {
+                    self.positions.push(hint.into());
+                    self.points.push(pt);
+                })
+                .is_some()
             })
         });
         self.indices
@@ -290,15 +337,14 @@ impl Polyline {
     /// whether `c` is included in the domain with boundary = `self`.
     fn include(&self, c: Point2) -> bool {
         let t = 2.0 * std::f64::consts::PI * HashGen::hash1(c);
-        let r = Vector2::new(f64::cos(t), f64::sin(t));
+        let d = c + Vector2::new(f64::cos(t), f64::sin(t));
         self.indices
             .iter()
             .try_fold(0_i32, move |counter, edge| {
-                let a = self.positions[edge[0]] - c;
-                let b = self.positions[edge[1]] - c;
-                let s0 = r[0] * a[1] - r[1] * a[0]; // v times a
-                let s1 = r[0] * b[1] - r[1] * b[0]; // v times b
-                let s2 = a[0] * b[1] - a[1] * b[0]; // a times b
+                let (a, b) = (self.positions[edge[0]], self.positions[edge[1]]);
+                let s0 = orient2d(c, d, a); // v times a
+                let s1 = orient2d(c, d, b); // v times b
+                let s2 = orient2d(c, a, b); // a times b
                 let x = s2 / (s1 - s0);
                 if x.so_small() && s0 * s1 < 0.0 {
                     None
@@ -314,69 +360,336 @@ impl Polyline {
             .unwrap_or(false)
     }
 
+    /// the edges which meet the strip `x0 <= p[0] <= x1`
+    fn strip(&self, x0: f64, x1: f64) -> Vec<[usize; 2]> {
+        self.indices
+            .iter()
+            .filter(|edge| {
+                let (a, b) = (self.positions[edge[0]].x, self.positions[edge[1]].x);
+                f64::min(a, b) <= x1 && x0 <= f64::max(a, b)
+            })
+            .copied()
+            .collect()
+    }
+
+    /// whether `c` is closer to `edges` than `radius`, measured in the scale of each axis.
+    fn near_boundary(&self, edges: &[[usize; 2]], c: Point2, radius: Vector2) -> bool {
+        let scale = |p: Point2| Vector2::new((p.x - c.x) / radius.x, (p.y - c.y) / radius.y);
+        edges.iter().any(|edge| {
+            let (a, b) = (scale(self.positions[edge[0]]), scale(self.positions[edge[1]]));
+            let ab = b - a;
+            let t = match ab.so_small() {
+                true => 0.0,
+                false => f64::clamp(-a.dot(ab) / ab.magnitude2(), 0.0, 1.0),
+            };
+            (a + ab * t).magnitude2() < 1.0
+        })
+    }
+
+    /// the intervals of the parameters of the line `p[axis] == x` in the domain bounded by `self`.
+    fn intervals(&self, x: f64, axis: usize) -> Vec<(f64, f64)> {
+        let other = 1 - axis;
+        let mut params = self
+            .indices
+            .iter()
+            .filter_map(|edge| {
+                let (a, b) = (self.positions[edge[0]], self.positions[edge[1]]);
+                match (a[axis] <= x) != (b[axis] <= x) {
+                    true => {
+                        let t = (x - a[axis]) / (b[axis] - a[axis]);
+                        Some(a[other] + (b[other] - a[other]) * t)
+                    }
+                    false => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        params.sort_by(f64::total_cmp);
+        params.chunks_exact(2).map(|a| (a[0], a[1])).collect()
+    }
+
     /// Inserts points and adds constraint into triangulation.
-    fn insert_to(&self, triangulation: &mut Cdt) {
+    ///
+    /// Returns the pair of the following:
+    /// - `true` if the constraints are the boundary of the domain exactly, i.e. all edges
+    /// are added as constraints, and no edge is shared by the polyline more than once.
+    /// - the map from the inserted vertices to the points of the edge polylines.
+    fn insert_to(&self, triangulation: &mut Cdt) -> (bool, HashMap<FixedVertexHandle, Point3>) {
+        let mut boundary = HashMap::default();
+        // The points are recorded at each insertion, since the failed insertions are skipped.
         let poly2tri: Vec<_> = self
             .positions
             .iter()
-            .filter_map(|pt| triangulation.insert(SPoint2::from([pt.x, pt.y])).ok())
+            .zip(&self.points)
+            .filter_map(|(pt, point)| {
+                let handle = triangulation.insert(SPoint2::from([pt.x, pt.y])).ok()?;
+                boundary.entry(handle).or_insert(*point);
+                Some(handle)
+            })
             .collect();
         let mut prev: Option<usize> = None;
+        let mut exact = poly2tri.len() == self.positions.len();
+        let mut counter = HashMap::<(usize, usize), usize>::default();
+        let mut add_constraint = |triangulation: &mut Cdt, i: usize, j: usize| {
+            let (v0, v1) = (poly2tri[i], poly2tri[j]);
+            triangulation.add_constraint(v0, v1);
+            let key = (
+                usize::min(v0.index(), v1.index()),
+                usize::max(v0.index(), v1.index()),
+            );
+            *counter.entry(key).or_insert(0) += 1;
+        };
         self.indices.iter().for_each(|a| {
             if let Some(p) = prev {
                 if triangulation.can_add_constraint(poly2tri[p], poly2tri[a[1]]) {
-                    triangulation.add_constraint(poly2tri[p], poly2tri[a[1]]);
+                    add_constraint(triangulation, p, a[1]);
                     prev = None;
                 }
             } else if triangulation.can_add_constraint(poly2tri[a[0]], poly2tri[a[1]]) {
-                triangulation.add_constraint(poly2tri[a[0]], poly2tri[a[1]]);
+                add_constraint(triangulation, a[0], a[1]);
             } else {
                 prev = Some(a[0]);
+                exact = false;
             }
         });
+        let exact = exact && counter.values().all(|count| *count == 1);
+        (exact, boundary)
     }
 }
 
 /// Tessellates one surface trimmed by polyline.
-fn trimming_tessellation<S>(surface: &S, polyline: &Polyline, tol: f64) -> PolygonMesh
-where S: PreMeshableSurface {
+fn trimming_tessellation<S>(
+    surface: &S,
+    polyline: &Polyline,
+    density: MeshingDensity,
+) -> PolygonMesh
+where
+    S: PreMeshableSurface,
+{
     let mut triangulation = Cdt::new();
-    polyline.insert_to(&mut triangulation);
-    insert_surface(&mut triangulation, surface, polyline, tol);
+    let (exact, boundary) = polyline.insert_to(&mut triangulation);
+    insert_surface(&mut triangulation, surface, polyline, density);
+    // If all constraints are added, the domain is decided exactly by the trimming loops.
+    let domain = match exact {
+        true => Some(trimmed_domain(&triangulation)),
+        false => None,
+    };
     let mut mesh = triangulation_into_polymesh(
         triangulation.vertices(),
         triangulation.inner_faces(),
         surface,
         polyline,
+        domain.as_ref(),
+        &boundary,
     );
     mesh.make_face_compatible_to_normal();
     mesh
 }
 
+/// Creates the iso-parameter lines of the faces.
+pub(super) fn shell_isolines<C, S>(
+    shell: &Shell<Point3, C, S>,
+    division: (usize, usize),
+    tol: f64,
+) -> Vec<PolylineCurve>
+where
+    C: PolylineableCurve,
+    S: MeshableSurface,
+{
+    shell
+        .face_iter()
+        .flat_map(|face| {
+            let surface = face.surface();
+            let mut polyline = Polyline::default();
+            let added = face.absolute_boundaries().iter().all(|wire| {
+                let curves = wire.edge_iter().map(|edge| {
+                    let curve = edge.oriented_curve();
+                    PolylineCurve::from_curve(&curve, curve.parameter_range(), tol)
+                });
+                polyline.add_wire(&surface, curves, by_search_parameter)
+            });
+            match added {
+                true => trimmed_isolines(&surface, &polyline, division, tol),
+                false => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Creates the iso-parameter lines of the faces of the compressed shell.
+pub(super) fn cshell_isolines<C, S>(
+    shell: &CompressedShell<Point3, C, S>,
+    division: (usize, usize),
+    tol: f64,
+) -> Vec<PolylineCurve>
+where
+    C: PolylineableCurve,
+    S: MeshableSurface,
+{
+    let edges: Vec<PolylineCurve> = shell
+        .edges
+        .iter()
+        .map(|edge| PolylineCurve::from_curve(&edge.curve, edge.curve.parameter_range(), tol))
+        .collect();
+    shell
+        .faces
+        .iter()
+        .flat_map(|face| {
+            let mut polyline = Polyline::default();
+            let added = face.boundaries.iter().all(|wire| {
+                let curves = wire.iter().filter_map(|edge_idx| {
+                    let curve = edges.get(edge_idx.index)?;
+                    match edge_idx.orientation {
+                        true => Some(curve.clone()),
+                        false => Some(curve.inverse()),
+                    }
+                });
+                polyline.add_wire(&face.surface, curves, by_search_parameter)
+            });
+            match added {
+                true => trimmed_isolines(&face.surface, &polyline, division, tol),
+                false => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Creates the iso-parameter lines of one surface trimmed by polyline.
+fn trimmed_isolines<S>(
+    surface: &S,
+    polyline: &Polyline,
+    (udiv, vdiv): (usize, usize),
+    tol: f64,
+) -> Vec<PolylineCurve>
+where
+    S: PreMeshableSurface,
+{
+    let bdb: BoundingBox<Point2> = polyline.positions.iter().collect();
+    if bdb.is_empty() {
+        return Vec::new();
+    }
+    let range = ((bdb.min()[0], bdb.max()[0]), (bdb.min()[1], bdb.max()[1]));
+    let (udivision, vdivision) = surface.parameter_division(range, tol);
+    let (udivision, vdivision) = (&udivision, &vdivision);
+    // the division points of the surface in the interval are inserted for precision.
+    let sample = |(t0, t1): (f64, f64), division: &[f64]| -> Vec<f64> {
+        let inner = division.iter().copied().filter(|t| t0 < *t && *t < t1);
+        std::iter::once(t0).chain(inner).chain(std::iter::once(t1)).collect()
+    };
+    let params = |(t0, t1): (f64, f64), n: usize| {
+        (1..n).map(move |i| t0 + (t1 - t0) * i as f64 / n as f64)
+    };
+    let ulines = params(range.0, udiv).flat_map(|u| {
+        polyline.intervals(u, 0).into_iter().map(move |interval| {
+            sample(interval, vdivision)
+                .into_iter()
+                .map(|v| surface.subs(u, v))
+                .collect::<PolylineCurve>()
+        })
+    });
+    let vlines = params(range.1, vdiv).flat_map(|v| {
+        polyline.intervals(v, 1).into_iter().map(move |interval| {
+            sample(interval, udivision)
+                .into_iter()
+                .map(|u| surface.subs(u, v))
+                .collect::<PolylineCurve>()
+        })
+    });
+    ulines.chain(vlines).collect()
+}
+
 /// Inserts parameter divisions into triangulation.
 fn insert_surface(
     triangulation: &mut Cdt,
     surface: &impl PreMeshableSurface,
     polyline: &Polyline,
-    tol: f64,
+    density: MeshingDensity,
 ) {
     let bdb: BoundingBox<Point2> = polyline.positions.iter().collect();
     let range = ((bdb.min()[0], bdb.max()[0]), (bdb.min()[1], bdb.max()[1]));
-    let (udiv, vdiv) = surface.parameter_division(range, tol);
+    let (udiv, vdiv) = surface_division(surface, range, density);
+    let (ugaps, vgaps) = (division_gaps(&udiv), division_gaps(&vdiv));
     udiv.into_iter()
-        .flat_map(|u| vdiv.iter().map(move |v| Point2::new(u, *v)))
-        .filter(|pt| polyline.include(*pt))
-        .for_each(|pt| {
+        .zip(ugaps)
+        .flat_map(|(u, du)| {
+            // only the edges in the strip of the column can be near the points on the column
+            let edges = polyline.strip(u - du * SLIVER_RATIO, u + du * SLIVER_RATIO);
+            vdiv.iter()
+                .zip(&vgaps)
+                .map(move |(v, dv)| (Point2::new(u, *v), Vector2::new(du, *dv) * SLIVER_RATIO))
+                .filter(move |(pt, _)| polyline.include(*pt))
+                .filter(move |(pt, radius)| {
+                    let near = || polyline.near_boundary(&edges, *pt, *radius);
+                    radius.x.so_small() || radius.y.so_small() || !near()
+                })
+        })
+        .for_each(|(pt, _)| {
             let _ = triangulation.insert(SPoint2::from([pt.x, pt.y]));
         });
 }
 
+/// the widths between each division point and its nearest neighbor
+fn division_gaps(division: &[f64]) -> Vec<f64> {
+    let gap = |i: usize, j: usize| f64::abs(division[j] - division[i]);
+    (0..division.len())
+        .map(|i| match (i > 0, i + 1 < division.len()) {
+            (true, true) => f64::min(gap(i - 1, i), gap(i, i + 1)),
+            (true, false) => gap(i - 1, i),
+            (false, true) => gap(i, i + 1),
+            (false, false) => 0.0,
+        })
+        .collect()
+}
+
+/// The inner faces in the domain bounded by the constraint edges.
+///
+/// The faces are decided by the parity of the number of the constraint edges crossed from the
+/// outside of the convex hull, with the 0-1 breadth-first search.
+fn trimmed_domain(triangulation: &Cdt) -> HashSet<FixedFaceHandle<InnerTag>> {
+    let mut depth = HashMap::<FixedFaceHandle<InnerTag>, usize>::default();
+    let mut queue = VecDeque::new();
+    triangulation.convex_hull().for_each(|edge| {
+        let constraint = triangulation.is_constraint_edge(edge.as_undirected().fix());
+        [edge, edge.rev()]
+            .into_iter()
+            .filter_map(|edge| edge.face().as_inner())
+            .for_each(|face| match constraint {
+                true => queue.push_back((face, 1)),
+                false => queue.push_front((face, 0)),
+            });
+    });
+    while let Some((face, d)) = queue.pop_front() {
+        if depth.contains_key(&face.fix()) {
+            continue;
+        }
+        depth.insert(face.fix(), d);
+        face.adjacent_edges().into_iter().for_each(|edge| {
+            if let Some(next) = edge.rev().face().as_inner() {
+                if !depth.contains_key(&next.fix()) {
+                    match triangulation.is_constraint_edge(edge.as_undirected().fix()) {
+                        true => queue.push_back((next, d + 1)),
+                        false => queue.push_front((next, d)),
+                    }
+                }
+            }
+        });
+    }
+    depth
+        .into_iter()
+        .filter_map(|(face, d)| match d % 2 == 1 {
+            true => Some(face),
+            false => None,
+        })
+        .collect()
+}
+
 /// Converts triangulation into `PolygonMesh`.
 fn triangulation_into_polymesh<'a>(
     vertices: VertexIterator<'a, SPoint2, (), CdtEdge<()>, ()>,
     triangles: InnerFaceIterator<'a, SPoint2, (), CdtEdge<()>, ()>,
     surface: &impl ParametricSurface3D,
     polyline: &Polyline,
+    domain: Option<&HashSet<FixedFaceHandle<InnerTag>>>,
+    boundary: &HashMap<FixedVertexHandle, Point3>,
 ) -> PolygonMesh {
     let mut positions = Vec::<Point3>::new();
     let mut uv_coords = Vec::<Vector2>::new();
@@ -386,22 +699,31 @@ fn triangulation_into_polymesh<'a>(
         .map(|(i, v)| {
             let p = *v.as_ref();
             let uv = Vector2::new(p.x, p.y);
-            positions.push(surface.subs(uv[0], uv[1]));
+            // The boundary points are the same as those of the edges, for sharing with the adjacent faces.
+            let position = match boundary.get(&v.fix()) {
+                Some(point) => *point,
+                None => surface.subs(uv[0], uv[1]),
+            };
+            positions.push(position);
             uv_coords.push(uv);
             normals.push(surface.normal(uv[0], uv[1]));
             (v.fix(), i)
         })
         .collect();
-    let tri_faces: Vec<[StandardVertex; 3]> = triangles
-        .map(|tri| tri.vertices())
-        .filter(|tri| {
-            let tri = [*tri[0].as_ref(), *tri[1].as_ref(), *tri[2].as_ref()];
-            let c = Point2::new(
-                (tri[0].x + tri[1].x + tri[2].x) / 3.0,
-                (tri[0].y + tri[1].y + tri[2].y) / 3.0,
-            );
-            polyline.include(c)
+    let mut tri_faces: Vec<[StandardVertex; 3]> = triangles
+        .filter(|face| match domain {
+            Some(domain) => domain.contains(&face.fix()),
+            None => {
+                let tri = face.vertices();
+                let tri = [*tri[0].as_ref(), *tri[1].as_ref(), *tri[2].as_ref()];
+                let c = Point2::new(
+                    (tri[0].x + tri[1].x + tri[2].x) / 3.0,
+                    (tri[0].y + tri[1].y + tri[2].y) / 3.0,
+                );
+                polyline.include(c)
+            }
         })
+        .map(|face| face.vertices())
         .map(|tri| {
             let idcs = [
                 vmap[&tri[0].fix()],
@@ -415,6 +737,27 @@ fn triangulation_into_polymesh<'a>(
             ]
         })
         .collect();
+    // The normals are degenerate at the singular points, e.g. the poles of spheres. Each corner
+    // at the points has the limit of the normals from the inside of its triangle.
+    tri_faces.iter_mut().for_each(|tri| {
+        let idcs = [tri[0].pos, tri[1].pos, tri[2].pos];
+        let centroid = (uv_coords[idcs[0]] + uv_coords[idcs[1]] + uv_coords[idcs[2]]) / 3.0;
+        (0..3).for_each(|k| {
+            if !is_degenerate(normals[idcs[k]]) {
+                return;
+            }
+            let uv = uv_coords[idcs[k]];
+            let normal = [1.0e-4, 1.0e-2, 0.5]
+                .into_iter()
+                .map(|t| uv + (centroid - uv) * t)
+                .map(|uv| surface.normal(uv[0], uv[1]))
+                .find(|normal| !is_degenerate(*normal));
+            if let Some(normal) = normal {
+                normals.push(normal);
+                tri[k].nor = Some(normals.len() - 1);
+            }
+        });
+    });
     PolygonMesh::debug_new(
         StandardAttributes {
             positions,
@@ -425,6 +768,10 @@ fn triangulation_into_polymesh<'a>(
     )
 }
 
+/// Returns `true` if `normal` is not a unit vector, e.g. the normalized zero vector.
+#[inline(always)]
+fn is_degenerate(normal: Vector3) -> bool { !f64::abs(normal.magnitude2() - 1.0).so_small() }
+
 #[test]
 #[ignore]
 #[cfg(not(target_arch = "wasm32"))]
@@ -437,16 +784,47 @@ fn par_bench() {
     ));
     let solid: Solid = serde_json::from_str(JSON).unwrap();
     let shell = solid.into_boundaries().pop().unwrap();
+    let preferences = MeshingPreferences::new(MeshingDensity::chordal(0.01));
 
     let instant = Instant::now();
     (0..100).for_each(|_| {
-        let _shell = shell_tessellation(&shell, 0.01, by_search_parameter);
+        let _shell = shell_tessellation(&shell, &preferences, by_search_parameter);
     });
     println!("{}ms", instant.elapsed().as_millis());
 
     let instant = Instant::now();
     (0..100).for_each(|_| {
-        let _shell = shell_tessellation_single_thread(&shell, 0.01, by_search_parameter);
+        let _shell = shell_tessellation_single_thread(&shell, &preferences, by_search_parameter);
     });
     println!("{}ms", instant.elapsed().as_millis());
 }
+
+#[test]
+fn boundary_after_failed_insertion() {
+    let positions = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(f64::NAN, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ];
+    let points: Vec<Point3> = (0..4).map(|i| Point3::new(i as f64, 0.0, 0.0)).collect();
+    let polyline = Polyline {
+        positions,
+        points: points.clone(),
+        indices: Vec::new(),
+    };
+    let mut triangulation = Cdt::new();
+    let (exact, boundary) = polyline.insert_to(&mut triangulation);
+    assert!(!exact);
+    assert_eq!(boundary.len(), 3);
+    boundary.iter().for_each(|(handle, point)| {
+        let p = triangulation.vertex(*handle).position();
+        // the NaN position is skipped
+        let i = match (p.x == 1.0, p.y == 1.0) {
+            (false, false) => 0,
+            (true, false) => 2,
+            _ => 3,
+        };
+        assert_eq!(*point, points[i]);
+    });
+}
@@ -0,0 +1,60 @@
+use super::common::shapes::grid_mesh;
+use super::*;
+
+#[test]
+fn spherical_patch_curvature() {
+    // the patch of the sphere of the radius 2 with the outward normals
+    let mesh = grid_mesh(16, |u, v| {
+        let p = Vector3::new(u - 0.5, v - 0.5, 1.0);
+        Point3::from_vec(p.normalize() * 2.0)
+    });
+    let curvatures = mesh.vertex_curvatures();
+    let interior = (1..16).flat_map(|i| (1..16).map(move |j| i * 17 + j));
+    interior.for_each(|i| {
+        let curvature = curvatures[i];
+        let normal = mesh.positions()[i].to_vec() / 2.0;
+        assert!(curvature.normal.distance(normal) < 0.01);
+        assert!(f64::abs(curvature.mean + 0.5) < 0.01, "{curvature:?}");
+        assert!(f64::abs(curvature.gaussian - 0.25) < 0.01, "{curvature:?}");
+        assert!(curvature.principal.iter().all(|k| f64::abs(k + 0.5) < 0.05));
+        let [d0, d1] = curvature.directions;
+        assert!(d0.dot(d1).so_small() && d0.dot(normal).so_small());
+    });
+}
+
+#[test]
+fn saddle_curvature() {
+    // z = xy has the principal curvatures +1 and -1 along the diagonals at the origin, bending
+    // toward the upward normal along x = y.
+    let mesh = grid_mesh(16, |u, v| {
+        let (x, y) = (u - 0.5, v - 0.5);
+        Point3::new(x, y, x * y)
+    });
+    let curvature = mesh.vertex_curvatures()[8 * 17 + 8];
+    assert!(f64::abs(curvature.mean) < 0.01, "{curvature:?}");
+    assert!(f64::abs(curvature.gaussian + 1.0) < 0.05, "{curvature:?}");
+    assert!(f64::abs(curvature.principal[0] - 1.0) < 0.05);
+    assert!(f64::abs(curvature.principal[1] + 1.0) < 0.05);
+    let diagonal = Vector3::new(1.0, 1.0, 0.0).normalize();
+    assert!(f64::abs(curvature.directions[0].dot(diagonal)) > 0.99);
+}
+
+#[test]
+fn tube_feature_lines() {
+    // the open tube: the boundary circles and the sharp crease at the half height
+    let mut mesh = grid_mesh(16, |u, v| {
+        let t = 2.0 * std::f64::consts::PI * u;
+        let r = 1.0 + f64::min(v, 1.0 - v);
+        Point3::new(r * f64::cos(t), r * f64::sin(t), v)
+    });
+    mesh.put_together_same_attrs();
+    let lines = mesh.feature_lines(Rad(0.5));
+    assert_eq!(lines.len(), 3);
+    lines.iter().for_each(|line| {
+        assert_eq!(line.len(), 17);
+        assert!(line[0].near(&line[16]));
+    });
+    let heights: Vec<f64> = lines.iter().map(|line| line[0].z).collect();
+    assert!(heights.contains(&0.0) && heights.contains(&0.5) && heights.contains(&1.0));
+    assert_eq!(mesh.feature_lines(Rad(2.0)).len(), 2);
+}
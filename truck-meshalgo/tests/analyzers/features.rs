@@ -0,0 +1,87 @@
+use super::*;
+use truck_modeling::{builder, Rad, Shell, Solid, Wire};
+
+// a unit cube with a hole of radius 0.2 and depth 0.5 from the top
+fn blind_hole() -> Solid {
+    let v = builder::vertex(Point3::new(-0.5, -0.5, 0.5));
+    let edge = builder::tsweep(&v, Vector3::unit_x());
+    let mut face = builder::tsweep(&edge, Vector3::unit_y());
+    let v = builder::vertex(Point3::new(0.2, 0.0, 0.5));
+    let circle = builder::rsweep(&v, Point3::new(0.0, 0.0, 0.5), Vector3::unit_z(), Rad(7.0));
+    face.add_boundary(circle.inverse());
+    let outer = face.boundaries()[0].clone();
+
+    let mut shell = builder::tsweep(&face, Vector3::new(0.0, 0.0, 0.5))
+        .into_boundaries()
+        .pop()
+        .unwrap();
+    // replace the bottom face by the bottom of the hole and the lower half of the cube
+    shell.remove(0);
+    shell.push(builder::try_attach_plane(&[circle]).unwrap());
+    let mut walls: Shell = builder::tsweep(&outer.inverse(), Vector3::new(0.0, 0.0, -0.5));
+    let bottom = walls
+        .extract_boundaries()
+        .into_iter()
+        .find(|wire| wire.vertex_iter().all(|v| v.point().z.so_small()))
+        .unwrap();
+    shell.append(&mut walls);
+    shell.push(builder::try_attach_plane(&[bottom.inverse()]).unwrap());
+    Solid::new(vec![shell])
+}
+
+#[test]
+fn recognize_blind_hole() {
+    let features = blind_hole().recognize_features(1.0e-3);
+    assert_eq!(features.len(), 1);
+    match &features[0] {
+        MachiningFeature::Hole {
+            faces,
+            origin,
+            axis,
+            diameter,
+            depth,
+            through,
+        } => {
+            assert_eq!(faces.len(), 3);
+            assert!(origin.distance(Point3::new(0.0, 0.0, 1.0)) < 1.0e-3);
+            assert!(axis.dot(-Vector3::unit_z()) > 1.0 - 1.0e-3);
+            assert!(f64::abs(diameter - 0.4) < 1.0e-3);
+            assert!(f64::abs(depth - 0.5) < 1.0e-3);
+            assert!(!through);
+        }
+        got => panic!("wrong feature: {got:?}"),
+    }
+}
+
+#[test]
+fn recognize_fillet() {
+    // a unit cube whose vertical edge at (1, 1) is rounded with radius 0.2
+    let v = [
+        builder::vertex(Point3::new(0.0, 0.0, 0.0)),
+        builder::vertex(Point3::new(1.0, 0.0, 0.0)),
+        builder::vertex(Point3::new(1.0, 0.8, 0.0)),
+        builder::vertex(Point3::new(0.8, 1.0, 0.0)),
+        builder::vertex(Point3::new(0.0, 1.0, 0.0)),
+    ];
+    let s = 0.2 * std::f64::consts::FRAC_1_SQRT_2;
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::line(&v[1], &v[2]),
+        builder::circle_arc(&v[2], &v[3], Point3::new(0.8 + s, 0.8 + s, 0.0)),
+        builder::line(&v[3], &v[4]),
+        builder::line(&v[4], &v[0]),
+    ]
+    .into();
+    let face = builder::try_attach_plane(&[wire]).unwrap();
+    let solid = builder::tsweep(&face, Vector3::unit_z());
+
+    let features = solid.recognize_features(1.0e-3);
+    assert_eq!(features.len(), 1);
+    match &features[0] {
+        MachiningFeature::Fillet { faces, radius } => {
+            assert_eq!(faces.len(), 1);
+            assert!(f64::abs(radius - 0.2) < 1.0e-3);
+        }
+        got => panic!("wrong feature: {got:?}"),
+    }
+}
@@ -0,0 +1,40 @@
+use super::common::shapes::grid_mesh;
+use super::*;
+
+#[test]
+fn unfold_cone() {
+    // the truncated cone of the apex at the origin
+    let mesh = grid_mesh(16, |u, v| {
+        let (t, r) = (std::f64::consts::PI * u, 1.0 + v);
+        Point3::new(r * f64::cos(t), r * f64::sin(t), r)
+    });
+    let coords = mesh.unfolded().unwrap();
+    assert!(mesh.strain(&coords) < 1.0e-10);
+    // the segment of the ruling keeps its length.
+    assert_near!(coords[0].distance(coords[16]), f64::sqrt(2.0));
+    // ARAP does not break the isometric unfolding.
+    let coords = mesh.arap_flattened(10).unwrap();
+    assert!(mesh.strain(&coords) < 1.0e-6);
+}
+
+#[test]
+fn flatten_spherical_patch() {
+    let mesh = grid_mesh(12, |u, v| {
+        let vec = Vector3::new(u - 0.5, v - 0.5, 1.0).normalize();
+        Point3::from_vec(vec)
+    });
+    let unfolded = mesh.unfolded().unwrap();
+    let flattened = mesh.arap_flattened(30).unwrap();
+    let (before, after) = (mesh.strain(&unfolded), mesh.strain(&flattened));
+    assert!(after < before, "{after} {before}");
+    assert!(after < 0.1, "{after}");
+}
+
+#[test]
+fn flatten_disconnected() {
+    let mut mesh = grid_mesh(2, |u, v| Point3::new(u, v, 0.0));
+    let other = grid_mesh(2, |u, v| Point3::new(u, v, 1.0));
+    mesh.merge(other);
+    assert!(mesh.unfolded().is_none());
+    assert!(mesh.arap_flattened(1).is_none());
+}
@@ -1,6 +1,19 @@
 use truck_meshalgo::prelude::*;
 
+#[path = "../common/mod.rs"]
+mod common;
+
 mod collision;
+mod curvature;
+mod features;
+mod flattening;
+mod parameterization;
 mod point_cloud;
+mod primitive;
+mod registration;
+mod slicing;
 mod splitting;
+mod symmetry;
+mod thickness;
+mod toolpath;
 mod topology;
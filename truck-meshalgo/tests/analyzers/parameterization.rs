@@ -0,0 +1,61 @@
+use super::common::shapes::grid_mesh;
+use super::*;
+
+#[test]
+fn planar_lscm() {
+    // the irregular grid on the plane is mapped by a congruence.
+    let mesh = grid_mesh(8, |u, v| Point3::new(u + u * u, v + u * v / 2.0, 0.0));
+    let uv = mesh.parameterization(ParameterizationMethod::Lscm).unwrap();
+    assert!(mesh.conformal_distortion(&uv) < 1.0e-4);
+    let positions = mesh.positions();
+    positions.iter().zip(&uv).for_each(|(p, w)| {
+        let error = (w - uv[0]).magnitude() - p.distance(positions[0]);
+        assert!(f64::abs(error) < 1.0e-4, "{error}");
+    });
+}
+
+#[test]
+fn arap_cylinder() {
+    let mut mesh = grid_mesh(12, |u, v| {
+        let t = std::f64::consts::PI * u;
+        Point3::new(f64::cos(t), f64::sin(t), 2.0 * v)
+    });
+    let lscm = mesh.parameterization(ParameterizationMethod::Lscm).unwrap();
+    let method = ParameterizationMethod::Arap { iterations: 20 };
+    let arap = mesh.parameterization(method).unwrap();
+    // ARAP recovers the lengths, which LSCM does up to the local scales.
+    let strain = |uv: &[Vector2]| {
+        let coords: Vec<Point2> = uv.iter().map(|uv| Point2::from_vec(*uv)).collect();
+        mesh.strain(&coords)
+    };
+    assert!(strain(&arap) < 1.0e-3, "{}", strain(&arap));
+    assert!(strain(&arap) <= strain(&lscm));
+    assert!(mesh.conformal_distortion(&arap) < 0.01);
+
+    assert!(mesh.parameterize(method));
+    assert_eq!(mesh.uv_coords().len(), mesh.positions().len());
+    let vertices = mesh.faces().face_iter().flatten();
+    vertices.for_each(|v| assert_eq!(v.uv, Some(v.pos)));
+}
+
+#[test]
+fn closed_tetrahedron() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let faces = Faces::from_iter([[0, 2, 1], [0, 1, 3], [1, 2, 3], [0, 3, 2]]);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let lscm = mesh.parameterization(ParameterizationMethod::Lscm);
+    assert!(lscm.is_none());
+    assert!(!mesh.parameterize(ParameterizationMethod::Arap { iterations: 1 }));
+    assert!(mesh.uv_coords().is_empty());
+}
@@ -0,0 +1,91 @@
+use super::*;
+use std::f64::consts::PI;
+
+fn cloud_from<F: Fn(f64, f64) -> (Point3, Vector3)>(f: F) -> PointCloud {
+    let (positions, normals): (Vec<_>, Vec<_>) = (0..12)
+        .flat_map(|i| (0..12).map(move |j| (i as f64 / 11.0, j as f64 / 11.0)))
+        .map(|(u, v)| f(u, v))
+        .unzip();
+    PointCloud::new(positions, Some(normals), None)
+}
+
+#[test]
+fn recognize_plane() {
+    let normal = Vector3::new(-1.0, -1.0, 1.0).normalize();
+    let cloud = cloud_from(|u, v| (Point3::new(u, v, u + v), normal));
+    match cloud.recognize_primitive(1.0e-6) {
+        Some(Primitive::Plane { origin, normal }) => {
+            assert!(origin.z.near(&(origin.x + origin.y)));
+            assert!(normal.dot(Vector3::new(1.0, 1.0, -1.0).normalize()).abs().near(&1.0));
+        }
+        got => panic!("wrong primitive: {got:?}"),
+    }
+}
+
+#[test]
+fn recognize_cylinder() {
+    let cloud = cloud_from(|u, v| {
+        let n = Vector3::new(f64::cos(PI * u), 0.0, f64::sin(PI * u));
+        (Point3::new(1.0, 3.0 * v, 2.0) + 1.5 * n, n)
+    });
+    match cloud.recognize_primitive(1.0e-6) {
+        Some(Primitive::Cylinder {
+            origin,
+            axis,
+            radius,
+        }) => {
+            assert!(axis.y.abs().near(&1.0));
+            assert!(origin.x.near(&1.0) && origin.z.near(&2.0));
+            assert!(radius.near(&1.5));
+        }
+        got => panic!("wrong primitive: {got:?}"),
+    }
+}
+
+#[test]
+fn recognize_cone() {
+    let half_angle = PI / 6.0;
+    let (sin, cos) = half_angle.sin_cos();
+    let cloud = cloud_from(|u, v| {
+        let (s, c) = f64::sin_cos(PI * u);
+        let t = 1.0 + v;
+        let p = Point3::new(sin * c * t, sin * s * t, cos * t + 1.0);
+        (p, Vector3::new(cos * c, cos * s, -sin))
+    });
+    match cloud.recognize_primitive(1.0e-6) {
+        Some(Primitive::Cone {
+            apex,
+            axis,
+            half_angle: angle,
+        }) => {
+            assert!(apex.distance(Point3::new(0.0, 0.0, 1.0)) < 1.0e-4);
+            assert!(axis.dot(Vector3::unit_z()) > 1.0 - 1.0e-6);
+            assert!(f64::abs(angle - half_angle) < 1.0e-4);
+        }
+        got => panic!("wrong primitive: {got:?}"),
+    }
+}
+
+#[test]
+fn recognize_torus() {
+    let cloud = cloud_from(|u, v| {
+        let (s, c) = f64::sin_cos(PI * u);
+        let (t, d) = f64::sin_cos(1.5 * PI * v);
+        let n = Vector3::new(c * d, s * d, t);
+        (Point3::new(3.0 * c, 3.0 * s, 0.0) + n, n)
+    });
+    match cloud.recognize_primitive(1.0e-6) {
+        Some(Primitive::Torus {
+            center,
+            axis,
+            major_radius,
+            minor_radius,
+        }) => {
+            assert!(center.distance(Point3::origin()) < 1.0e-4);
+            assert!(axis.z.abs() > 1.0 - 1.0e-6);
+            assert!(f64::abs(major_radius - 3.0) < 1.0e-4);
+            assert!(f64::abs(minor_radius - 1.0) < 1.0e-4);
+        }
+        got => panic!("wrong primitive: {got:?}"),
+    }
+}
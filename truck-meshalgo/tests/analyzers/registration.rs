@@ -0,0 +1,27 @@
+use super::*;
+use truck_modeling::{builder, Rad, Solid};
+
+#[test]
+fn rigid_transform_of_cylinder() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::new(0.0, 0.0, 2.0), 0.5);
+    let mat = Matrix4::from_translation(Vector3::new(-1.0, 3.0, 0.25))
+        * Matrix4::from_axis_angle(Vector3::new(0.0, 1.0, 1.0).normalize(), Rad(2.0));
+    let mut shell = builder::transformed(&cylinder, mat)
+        .into_boundaries()
+        .pop()
+        .unwrap();
+    shell.reverse();
+    let moved = Solid::new(vec![shell]);
+
+    let (transform, error) = cylinder.rigid_transform_to(&moved, 1.0e-6).unwrap();
+    assert!(error < 1.0e-6);
+    cylinder.vertex_iter().for_each(|v| {
+        let p = transform.transform_point(v.point());
+        assert!(moved.vertex_iter().any(|w| w.point().near(&p)));
+    });
+    let axis = transform.transform_vector(Vector3::unit_z());
+    assert!(axis.near(&mat.transform_vector(Vector3::unit_z())));
+
+    let longer = builder::cylinder(Point3::origin(), Vector3::new(0.0, 0.0, 2.5), 0.5);
+    assert!(cylinder.rigid_transform_to(&longer, 1.0e-6).is_none());
+}
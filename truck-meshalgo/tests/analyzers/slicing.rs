@@ -0,0 +1,35 @@
+use super::*;
+use std::f64::consts::PI;
+#[path = "../common/mod.rs"]
+mod common;
+
+#[test]
+fn slice_hollow_sphere() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    let mut inner = common::shapes::sphere(Point3::origin(), 0.5, 64, 32);
+    inner.invert();
+    mesh.merge(inner);
+
+    let layers = mesh.slice(0.25);
+    assert_eq!(layers.len(), 8);
+    layers.iter().for_each(|layer| {
+        let z = layer.height();
+        assert_eq!(layer.islands().len(), 1);
+        let island = &layer.islands()[0];
+        let (area, holes) = match z.abs() < 0.5 {
+            true => (PI * (1.0 - z * z) - PI * (0.25 - z * z), 1),
+            false => (PI * (1.0 - z * z), 0),
+        };
+        assert_eq!(island.holes().len(), holes);
+        assert!(f64::abs(island.area() - area) < area * 0.05);
+    });
+}
+
+#[test]
+fn two_islands() {
+    let mut mesh = common::shapes::sphere(Point3::new(-2.0, 0.0, 0.0), 1.0, 32, 16);
+    mesh.merge(common::shapes::sphere(Point3::new(2.0, 0.0, 0.0), 1.0, 32, 16));
+    let layers = mesh.slice(0.5);
+    assert_eq!(layers.len(), 4);
+    layers.iter().for_each(|layer| assert_eq!(layer.islands().len(), 2));
+}
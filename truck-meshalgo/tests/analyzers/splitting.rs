@@ -90,3 +90,67 @@ fn into_component_test() {
     let components = mesh.components(false);
     assert_eq!(components.len(), 1);
 }
+
+#[test]
+fn region_segmentation_test() {
+    // the half cylinder of the radius 1 standing on the plane, extruded along the y-axis
+    let profile: Vec<(f64, f64)> = (0..=8)
+        .map(|i| (-3.0 + 2.0 * i as f64 / 8.0, 0.0))
+        .chain((1..16).map(|i| {
+            let t = std::f64::consts::PI * (1.0 - i as f64 / 16.0);
+            (f64::cos(t), f64::sin(t))
+        }))
+        .chain((0..=8).map(|i| (1.0 + 2.0 * i as f64 / 8.0, 0.0)))
+        .collect();
+    let n = profile.len();
+    let positions = profile
+        .iter()
+        .flat_map(|(x, z)| (0..=8).map(move |j| Point3::new(*x, j as f64 / 4.0, *z)))
+        .collect();
+    let faces = (0..n - 1).flat_map(|i| (0..8).map(move |j| i * 9 + j));
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(faces.map(|k| [k, k + 1, k + 10, k + 9])),
+    );
+
+    let labels = mesh.region_segmentation(Rad(0.5), 0.2);
+    // the left plane, the right plane, and the half cylinder
+    (0..n - 1).for_each(|i| {
+        let region = match i {
+            _ if i < 8 => 0,
+            _ if i >= n - 9 => 1,
+            _ => 2,
+        };
+        let column = &labels[i * 8..(i + 1) * 8];
+        assert!(column.iter().all(|l| *l == region), "{i}");
+    });
+
+    // the sharp edges are smooth by the larger angle.
+    let labels = mesh.region_segmentation(Rad(2.0), f64::INFINITY);
+    assert!(labels.iter().all(|l| *l == 0));
+}
+
+#[test]
+fn cube_segmentation() {
+    let cube = truck_modeling::builder::cuboid(Point3::origin(), Point3::new(2.0, 1.0, 1.0));
+    let mut mesh = cube.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs();
+    let labels = mesh.region_segmentation(Rad(0.5), 0.1);
+    assert_eq!(labels.iter().max(), Some(&5));
+    let normals: Vec<Vector3> = mesh
+        .face_iter()
+        .map(|face| {
+            let p: Vec<Point3> = face.iter().map(|v| mesh.positions()[v.pos]).collect();
+            (p[1] - p[0]).cross(p[2] - p[0]).normalize()
+        })
+        .collect();
+    (0..labels.len()).for_each(|i| {
+        (0..labels.len()).for_each(|j| {
+            let same = normals[i].near(&normals[j]);
+            assert_eq!(labels[i] == labels[j], same);
+        })
+    });
+}
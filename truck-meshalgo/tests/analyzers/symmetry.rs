@@ -0,0 +1,63 @@
+use super::*;
+use std::f64::consts::PI;
+use truck_modeling::builder;
+#[path = "../common/mod.rs"]
+mod common;
+
+fn count(symmetries: &[Symmetry]) -> (usize, Vec<usize>) {
+    let mirrors = symmetries
+        .iter()
+        .filter(|symmetry| matches!(symmetry, Symmetry::Mirror { .. }))
+        .count();
+    let mut orders = symmetries
+        .iter()
+        .filter_map(|symmetry| match symmetry {
+            Symmetry::Rotation { order, .. } => Some(*order),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    orders.sort();
+    (mirrors, orders)
+}
+
+#[test]
+fn symmetries_of_sphere_mesh() {
+    let mesh = common::shapes::sphere(Point3::new(1.0, 2.0, 3.0), 1.0, 8, 5);
+    let symmetries = mesh.symmetries(1.0e-6);
+    assert_eq!(count(&symmetries), (9, vec![2, 2, 2, 2, 2, 2, 2, 2, 8]));
+    symmetries.iter().for_each(|symmetry| match symmetry {
+        Symmetry::Rotation {
+            origin,
+            axis,
+            order,
+        } => {
+            assert!(origin.near(&Point3::new(1.0, 2.0, 3.0)));
+            assert_eq!(*order == 8, axis.z.abs().near(&1.0));
+        }
+        Symmetry::Mirror { normal, .. } => {
+            let transformed = symmetry.transform().transform_vector(*normal);
+            assert!(transformed.near(&-normal));
+        }
+    });
+}
+
+#[test]
+fn symmetries_of_hexagonal_prism() {
+    let points = (0..6)
+        .map(|i| {
+            let angle = PI / 3.0 * i as f64;
+            Point3::new(f64::cos(angle), f64::sin(angle), 0.0)
+        })
+        .collect::<Vec<_>>();
+    let prism = builder::try_prism(&points, Vector3::new(0.0, 0.0, 0.5)).unwrap();
+    let symmetries = prism.symmetries(1.0e-6);
+    assert_eq!(count(&symmetries), (7, vec![2, 2, 2, 2, 2, 2, 6]));
+
+    // a cuboid on the top breaks the symmetries except the four ones.
+    let cube = builder::cuboid(Point3::new(-0.5, -0.2, 0.5), Point3::new(0.5, 0.2, 1.0));
+    let solid = truck_modeling::Solid::new(vec![
+        prism.into_boundaries().pop().unwrap(),
+        cube.into_boundaries().pop().unwrap(),
+    ]);
+    assert_eq!(count(&solid.symmetries(1.0e-6)), (2, vec![2]));
+}
@@ -0,0 +1,12 @@
+use super::*;
+
+#[test]
+fn hollow_sphere_thickness() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 32, 16);
+    let mut inner = common::shapes::sphere(Point3::origin(), 0.8, 32, 16);
+    inner.invert();
+    mesh.merge(inner);
+    let thickness = mesh.thickness_map();
+    assert_eq!(thickness.len(), mesh.positions().len());
+    thickness.into_iter().for_each(|t| assert!(f64::abs(t - 0.2) < 0.05, "{t}"));
+}
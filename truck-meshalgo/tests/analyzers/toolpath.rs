@@ -0,0 +1,53 @@
+use super::*;
+#[path = "../common/mod.rs"]
+mod common;
+
+#[test]
+fn parallel_toolpath_on_sphere() {
+    let mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    let paths = mesh.parallel_toolpath(0.1, 0.25, 0.05);
+    assert_eq!(paths.len(), 9);
+    paths.iter().enumerate().for_each(|(i, path)| {
+        // zigzag
+        assert_eq!(path[0].x < path[path.len() - 1].x, i % 2 == 0);
+        path.iter().for_each(|p| {
+            let rho2 = p.x * p.x + p.y * p.y;
+            if rho2 < 1.0 {
+                // the center of the tool is on the sphere with radius 1.1
+                let z = f64::sqrt(1.21 - rho2) - 0.1;
+                assert!(f64::abs(p.z - z) < 0.01, "{p:?} {z}");
+            } else if rho2 > 1.21 {
+                assert!(p.z.near(&-1.0));
+            }
+        });
+    });
+}
+
+#[test]
+fn waterline_toolpath_on_sphere() {
+    let mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    let paths = mesh.waterline_toolpath(0.1, 0.25);
+    assert_eq!(paths.len(), 8);
+    paths
+        .windows(2)
+        .for_each(|w| assert!(w[0][0].z > w[1][0].z));
+    paths.iter().for_each(|path| {
+        let z = path[0].z;
+        let radius = f64::sqrt(1.0 - z * z) + 0.1;
+        path.iter().for_each(|p| {
+            assert_eq!(p.z, z);
+            let rho = f64::sqrt(p.x * p.x + p.y * p.y);
+            assert!(f64::abs(rho - radius) < 0.01, "{p:?} {radius}");
+        });
+    });
+
+    let gcode = toolpath_gcode(&paths, 1.5, 1000.0);
+    assert_eq!(
+        gcode
+            .lines()
+            .filter(|line| line.starts_with("G1 Z"))
+            .count(),
+        8
+    );
+    assert!(gcode.ends_with("G0 Z1.5000\n"));
+}
@@ -1,3 +1,6 @@
+// The fixtures are shared by the test crates, each of which uses a part of them.
+#![allow(dead_code)]
+
 use truck_meshalgo::prelude::*;
 
 pub mod shapes;
@@ -29,3 +29,21 @@ pub fn sphere(center: Point3, radius: f64, udiv: usize, vdiv: usize) -> PolygonM
         faces,
     )
 }
+
+/// the grid of the quadrangles whose vertices are `position(i / division, j / division)`
+pub fn grid_mesh(division: usize, position: impl Fn(f64, f64) -> Point3) -> PolygonMesh {
+    let n = division as f64;
+    let positions = (0..=division)
+        .flat_map(|i| (0..=division).map(move |j| (i as f64 / n, j as f64 / n)))
+        .map(|(u, v)| position(u, v))
+        .collect();
+    let faces = (0..division).flat_map(|i| (0..division).map(move |j| i * (division + 1) + j));
+    let faces = faces.map(|k| [k, k + division + 1, k + division + 2, k + 1]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(faces),
+    )
+}
@@ -1,4 +1,5 @@
 mod normal_filter;
 mod optimizing;
+mod reconstruction;
 mod structuring;
 mod subdivision;
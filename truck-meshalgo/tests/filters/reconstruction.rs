@@ -0,0 +1,24 @@
+use truck_meshalgo::prelude::*;
+use truck_topology::shell::ShellCondition;
+#[path = "../common/mod.rs"]
+mod common;
+
+#[test]
+fn poisson_sphere() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let sphere = common::shapes::sphere(center, 2.0, 40, 20);
+    let normals = sphere
+        .positions()
+        .iter()
+        .map(|p| (*p - center).normalize())
+        .collect();
+    let cloud = PointCloud::new(sphere.positions().clone(), Some(normals), None);
+    let mesh = cloud.poisson_reconstruction(24).unwrap();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    mesh.positions().iter().for_each(|p| {
+        assert!(f64::abs(p.distance(center) - 2.0) < 0.2, "{p:?}");
+    });
+
+    let cloud = PointCloud::from_positions(sphere.positions().clone());
+    assert!(cloud.poisson_reconstruction(24).is_none());
+}
@@ -38,3 +38,97 @@ fn loop_subdivision() {
         ],
     );
 }
+
+#[test]
+fn loop_subdivision_with_creases() {
+    let positions = vec![
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, -1.0),
+        Point3::new(1.0, 0.0, 0.0),
+    ];
+    let mut polygon = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [3, 2, 1]]),
+    );
+    let mut creases = vec![[1, 2]];
+    polygon.loop_subdivision_with_creases(&mut creases);
+    assert_eq!(polygon.positions().len(), 9);
+    // the ends of the crease are corners
+    assert_eq!(polygon.positions()[1], Point3::new(0.0, 1.0, 1.0));
+    assert_eq!(polygon.positions()[2], Point3::new(0.0, 1.0, -1.0));
+    // the crease is divided at the midpoint
+    assert_eq!(polygon.positions()[4], Point3::new(0.0, 1.0, 0.0));
+    assert_eq!(creases, vec![[1, 4], [4, 2]]);
+}
+
+fn cube() -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| {
+            let coord = |k: usize| ((i >> k) & 1) as f64 * 2.0 - 1.0;
+            Point3::new(coord(0), coord(1), coord(2))
+        })
+        .collect();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+        ]),
+    )
+}
+
+#[test]
+fn catmull_clark_subdivision() {
+    let mut polygon = cube();
+    polygon.catmull_clark_subdivision();
+    assert_eq!(polygon.positions().len(), 26);
+    assert_eq!(polygon.quad_faces().len(), 24);
+    assert!(polygon.tri_faces().is_empty());
+    let corner = Point3::new(-5.0, -5.0, -5.0) / 9.0;
+    assert!(polygon.positions()[0].near(&corner));
+    let edge_point = Point3::new(-0.75, -0.75, 0.0);
+    assert!(polygon.positions().iter().any(|p| p.near(&edge_point)));
+    let face_point = Point3::new(0.0, 0.0, -1.0);
+    assert!(polygon.positions().iter().any(|p| p.near(&face_point)));
+
+    // all edges are sharp
+    let mut polygon = cube();
+    let mut creases = vec![
+        [0, 1],
+        [2, 3],
+        [4, 5],
+        [6, 7],
+        [0, 2],
+        [1, 3],
+        [4, 6],
+        [5, 7],
+        [0, 4],
+        [1, 5],
+        [2, 6],
+        [3, 7],
+    ];
+    polygon.catmull_clark_subdivision_with_creases(&mut creases);
+    assert_eq!(creases.len(), 24);
+    assert_eq!(polygon.positions()[0], Point3::new(-1.0, -1.0, -1.0));
+    creases.iter().for_each(|[v0, v1]| {
+        let (p, q) = (polygon.positions()[*v0], polygon.positions()[*v1]);
+        // the new edges are on the edges of the cube
+        let on_edge = [p.x, p.y, p.z]
+            .iter()
+            .zip([q.x, q.y, q.z])
+            .filter(|(a, b)| a.abs().near(&1.0) && **a == *b)
+            .count();
+        assert_eq!(on_edge, 2);
+    });
+}
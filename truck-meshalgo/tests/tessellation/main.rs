@@ -1,4 +1,6 @@
 use truck_meshalgo::prelude::*;
 use truck_modeling::*;
 
+mod preferences;
+mod referenced;
 mod triangulation;
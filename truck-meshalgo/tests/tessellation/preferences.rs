@@ -0,0 +1,84 @@
+use super::*;
+use truck_topology::shell::ShellCondition;
+
+#[test]
+fn chordal_density_is_triangulation() {
+    let torus = builder::torus(Point3::origin(), Vector3::unit_z(), 1.0, 0.3);
+    let preferences = MeshingPreferences::new(MeshingDensity::chordal(0.01));
+    let res = torus.preferred_triangulation(&preferences).to_polygon();
+    let ans = torus.triangulation(0.01).to_polygon();
+    assert_eq!(res.positions(), ans.positions());
+    assert_eq!(res.tri_faces(), ans.tri_faces());
+}
+
+#[test]
+fn presets_on_sphere() {
+    let sphere = builder::sphere(Point3::origin(), 1.0);
+    let count = |density: MeshingDensity| {
+        let preferences = MeshingPreferences::new(density);
+        let mesh = sphere.preferred_triangulation(&preferences).to_polygon();
+        mesh.faces().triangle_iter().count()
+    };
+    let coarse = count(MeshingDensity::coarse());
+    let medium = count(MeshingDensity::medium());
+    let fine = count(MeshingDensity::fine());
+    assert!(coarse < medium && medium < fine, "{coarse} {medium} {fine}");
+}
+
+#[test]
+fn max_edge_length_on_cube() {
+    let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let density = MeshingDensity {
+        max_edge_length: Some(0.1),
+        ..MeshingDensity::chordal(0.01)
+    };
+    let mesh = cube
+        .preferred_triangulation(&MeshingPreferences::new(density))
+        .to_polygon();
+    // the division grid is refined, and the diagonals and the edges near the boundary are
+    // longer than the spacing.
+    mesh.faces().triangle_iter().for_each(|tri| {
+        (0..3).for_each(|k| {
+            let p = mesh.positions()[tri[k].pos];
+            let q = mesh.positions()[tri[(k + 1) % 3].pos];
+            assert!(p.distance(q) < 0.2, "{p:?} {q:?}");
+        })
+    });
+}
+
+#[test]
+fn overridden_face_shares_boundary() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    let side = cylinder.boundaries()[0][1].id();
+    let dense = MeshingDensity {
+        max_edge_length: Some(0.02),
+        ..MeshingDensity::coarse()
+    };
+    let preferences = MeshingPreferences::new(MeshingDensity::coarse()).with_face(side, dense);
+    let meshed = cylinder.preferred_triangulation(&preferences);
+    let shell = &meshed.boundaries()[0];
+    let count = |i: usize| shell[i].surface().unwrap().positions().len();
+    assert!(count(1) > 10 * count(2));
+
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn overridden_compressed_face() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    let shell = &cylinder.boundaries()[0];
+    let dense = MeshingDensity {
+        max_edge_length: Some(0.02),
+        ..MeshingDensity::coarse()
+    };
+    let preferences = MeshingPreferences::new(MeshingDensity::coarse()).with_face(1, dense);
+    let meshed = shell.compress().preferred_triangulation(&preferences);
+    let count = |i: usize| meshed.faces[i].surface.as_ref().unwrap().positions().len();
+    assert!(count(1) > 10 * count(2));
+
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+/// Divides each triangle into three quadrangles by the midpoints of the edges and the centroid.
+fn quadrangulate(poly: &PolygonMesh) -> PolygonMesh {
+    let mut positions = poly.positions().clone();
+    let mut quad_faces = Vec::new();
+    poly.faces().triangle_iter().for_each(|tri| {
+        let p = tri.map(|v| poly.positions()[v.pos]);
+        let len = positions.len();
+        positions.push(Point3::centroid(&p));
+        (0..3).for_each(|i| positions.push(p[i].midpoint(p[(i + 1) % 3])));
+        (0..3).for_each(|i| {
+            let face = [tri[i].pos, len + 1 + i, len, len + 1 + (i + 2) % 3];
+            quad_faces.push(face.map(StandardVertex::from));
+        });
+    });
+    let attrs = StandardAttributes {
+        positions,
+        ..Default::default()
+    };
+    PolygonMesh::new(
+        attrs,
+        Faces::from_tri_and_quad_faces(Vec::new(), quad_faces),
+    )
+}
+
+#[test]
+fn quadrangles_refer_to_faces() {
+    let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let shell = &cube.boundaries()[0];
+    let meshed = shell.triangulation(0.01);
+    // Only the bottom and the top consist of quadrangles.
+    meshed.face_iter().for_each(|face| {
+        let poly = face.surface().unwrap();
+        let z0 = poly.positions()[0].z;
+        if poly.positions().iter().all(|p| p.z.near(&z0)) {
+            face.set_surface(Some(quadrangulate(&poly)));
+        }
+    });
+    let mesh = ReferencedPolygonMesh::from_meshed_shell(shell, &meshed);
+    let polygon = mesh.polygon();
+    assert!(!polygon.tri_faces().is_empty() && !polygon.quad_faces().is_empty());
+    assert_eq!(
+        mesh.triangle_faces().len(),
+        polygon.faces().triangle_iter().count()
+    );
+
+    // the coordinate constant on each face of the cube
+    let planes: Vec<_> = shell
+        .face_iter()
+        .map(|face| {
+            let points: Vec<Point3> = face.vertex_iter().map(|v| v.point()).collect();
+            let k = (0..3)
+                .find(|k| points.iter().all(|p| p[*k].near(&points[0][*k])))
+                .unwrap();
+            (face.id(), k, points[0][k])
+        })
+        .collect();
+    polygon
+        .faces()
+        .triangle_iter()
+        .zip(mesh.triangle_faces())
+        .for_each(|(tri, id)| {
+            let (_, k, c) = planes.iter().find(|(face_id, _, _)| face_id == id).unwrap();
+            tri.iter()
+                .for_each(|v| assert!(polygon.positions()[v.pos][*k].near(c)));
+        });
+}
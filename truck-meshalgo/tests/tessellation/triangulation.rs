@@ -89,3 +89,133 @@ fn special_cylinder() {
         .remove_unused_attrs();
     assert!(matches!(mesh.shell_condition(), ShellCondition::Closed));
 }
+
+#[test]
+fn punched_plate_area() {
+    // a unit cube with a hole of radius 0.2 through it
+    let v = builder::vertex(Point3::new(-0.5, -0.5, 0.0));
+    let edge = builder::tsweep(&v, Vector3::unit_x());
+    let mut face = builder::tsweep(&edge, Vector3::unit_y());
+    let v = builder::vertex(Point3::new(0.2, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    face.add_boundary(circle.inverse());
+    let solid = builder::tsweep(&face, Vector3::unit_z());
+
+    let mut poly = solid.triangulation(0.001).to_polygon();
+    poly.put_together_same_attrs()
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+    let area = poly
+        .faces()
+        .triangle_iter()
+        .map(|tri| {
+            let [p, q, r] = [0, 1, 2].map(|i| poly.positions()[tri[i].pos]);
+            (q - p).cross(r - p).magnitude() / 2.0
+        })
+        .sum::<f64>();
+    let answer = 6.0 - 2.0 * std::f64::consts::PI * (0.04 - 0.2);
+    assert!(f64::abs(area - answer) < 0.01, "{area} {answer}");
+}
+
+#[test]
+fn punched_plate_isolines() {
+    let v = builder::vertex(Point3::new(-0.5, -0.5, 0.0));
+    let edge = builder::tsweep(&v, Vector3::unit_x());
+    let mut face = builder::tsweep(&edge, Vector3::unit_y());
+    let v = builder::vertex(Point3::new(0.2, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    face.add_boundary(circle.inverse());
+    let solid = builder::tsweep(&face, Vector3::unit_z());
+
+    let isolines = solid.isolines((4, 4), 0.001);
+    assert!(!isolines.is_empty());
+    isolines.iter().flat_map(|line| line.iter()).for_each(|p| {
+        // the lines do not go through the hole
+        assert!(p.x * p.x + p.y * p.y > 0.04 - 0.001, "{p:?}");
+        assert!(-TOLERANCE < p.z && p.z < 1.0 + TOLERANCE, "{p:?}");
+    });
+    // the compressed shell has the same lines.
+    let compressed = solid.boundaries()[0].compress().isolines((4, 4), 0.001);
+    assert_eq!(compressed.len(), isolines.len());
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn independent_of_thread_counts() {
+    let solids: Vec<Solid> = read_jsons()
+        .into_iter()
+        .map(|json| serde_json::from_reader(json.as_slice()).unwrap())
+        .collect();
+    let meshing = |num_threads: usize| -> Vec<PolygonMesh> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            solids
+                .iter()
+                .map(|solid| solid.triangulation(0.02).to_polygon())
+                .collect()
+        })
+    };
+    let answer = meshing(1);
+    [2, 4, 8]
+        .into_iter()
+        .for_each(|num_threads| assert_eq!(meshing(num_threads), answer, "{num_threads} threads"));
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn deterministic_mode() {
+    let solids: Vec<Solid> = read_jsons()
+        .into_iter()
+        .map(|json| serde_json::from_reader(json.as_slice()).unwrap())
+        .collect();
+    let meshing = |num_threads: usize| -> Vec<PolygonMesh> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            solids
+                .iter()
+                .map(|solid| solid.compress().triangulation(0.02).to_polygon())
+                .collect()
+        })
+    };
+    truck_meshalgo::tessellation::set_deterministic(true);
+    let answer = meshing(1);
+    let results = [2, 4, 8].map(meshing);
+    truck_meshalgo::tessellation::set_deterministic(false);
+    results
+        .iter()
+        .for_each(|result| assert_eq!(result, &answer));
+}
+
+#[test]
+fn shared_boundary_points() {
+    for (i, json) in read_jsons().into_iter().enumerate() {
+        let solid: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+        let meshed = solid.triangulation(0.02);
+        meshed.boundaries()[0].face_iter().for_each(|face| {
+            let poly = face.surface().unwrap();
+            face.edge_iter().for_each(|edge| {
+                edge.curve().iter().for_each(|p| {
+                    assert!(poly.positions().contains(p), "not shared: file no. {i}");
+                })
+            })
+        });
+    }
+}
+
+#[test]
+fn exact_normals_on_sphere() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let poly = builder::sphere(center, 2.0).triangulation(0.01).to_polygon();
+    poly.face_iter().flatten().for_each(|v| {
+        let (p, n) = (poly.positions()[v.pos], poly.normals()[v.nor.unwrap()]);
+        let ans = (p - center) / 2.0;
+        assert!((n - ans).magnitude() < 1.0e-2, "{p:?} {n:?}");
+    });
+}
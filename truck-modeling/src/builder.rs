@@ -14,6 +14,37 @@ const PI: Rad<f64> = Rad(std::f64::consts::PI);
 #[inline(always)]
 pub fn vertex(pt: Point3) -> Vertex { Vertex::new(pt) }
 
+/// Try creating a line from `vertex0` to `vertex1`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let vertex0 = builder::vertex(Point3::new(1.0, 2.0, 3.0));
+/// let vertex1 = builder::vertex(Point3::new(6.0, 5.0, 4.0));
+/// assert!(builder::try_line(&vertex0, &vertex1).is_ok());
+/// ```
+/// # Failures
+/// If `vertex0` and `vertex1` are the same vertex, then returns the topological error
+/// `SameVertex`. If the points of the vertices are near, then returns `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let vertex0 = builder::vertex(Point3::new(1.0, 2.0, 3.0));
+/// let vertex1 = builder::vertex(Point3::new(1.0, 2.0, 3.0));
+/// assert_eq!(builder::try_line(&vertex0, &vertex1).unwrap_err(), Error::DegenerateProfile);
+/// assert!(matches!(
+///     builder::try_line(&vertex0, &vertex0),
+///     Err(Error::FromTopology(_)),
+/// ));
+/// ```
+pub fn try_line(vertex0: &Vertex, vertex1: &Vertex) -> Result<Edge> {
+    let pt0 = vertex0.point();
+    let pt1 = vertex1.point();
+    if vertex0 != vertex1 && pt0.near(&pt1) {
+        return Err(Error::DegenerateProfile);
+    }
+    let curve = Curve::Line(Line(pt0, pt1));
+    Ok(Edge::try_new(vertex0, vertex1, curve)?)
+}
+
 /// Returns a line from `vertex0` to `vertex1`.
 /// # Examples
 /// ```
@@ -39,6 +70,38 @@ pub fn line(vertex0: &Vertex, vertex1: &Vertex) -> Edge {
     Edge::new(vertex0, vertex1, Curve::Line(Line(pt0, pt1)))
 }
 
+/// Try creating a circle arc from `vertex0` to `vertex1` via `transit`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let vertex0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let vertex1 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+/// let transit = Point3::new(0.0, 1.0, 0.0);
+/// assert!(builder::try_circle_arc(&vertex0, &vertex1, transit).is_ok());
+/// ```
+/// # Failures
+/// If `vertex0` and `vertex1` are the same vertex, then returns the topological error
+/// `SameVertex`. If the three points are on one line, then returns `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let vertex0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let vertex1 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+/// let transit = Point3::new(0.0, 0.0, 0.0);
+/// assert_eq!(
+///     builder::try_circle_arc(&vertex0, &vertex1, transit).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+pub fn try_circle_arc(vertex0: &Vertex, vertex1: &Vertex, transit: Point3) -> Result<Edge> {
+    let pt0 = vertex0.point();
+    let pt1 = vertex1.point();
+    if vertex0 != vertex1 && (pt0 - transit).cross(pt1 - transit).so_small() {
+        return Err(Error::DegenerateProfile);
+    }
+    let curve = geom_impls::circle_arc_by_three_points(pt0, pt1, transit);
+    Ok(Edge::try_new(vertex0, vertex1, curve.into())?)
+}
+
 /// Returns a circle arc from `vertex0` to `vertex1` via `transit`.
 /// # Examples
 /// ```
@@ -66,6 +129,35 @@ pub fn circle_arc(vertex0: &Vertex, vertex1: &Vertex, transit: Point3) -> Edge {
     Edge::new(vertex0, vertex1, curve.into())
 }
 
+/// Try creating a Bezier curve from `vertex0` to `vertex1` with inter control points
+/// `inter_points`.
+/// # Failures
+/// If `vertex0` and `vertex1` are the same vertex, then returns the topological error
+/// `SameVertex`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let vertex = builder::vertex(Point3::origin());
+/// let inter_points = vec![Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, -1.0, 0.0)];
+/// assert!(matches!(
+///     builder::try_bezier(&vertex, &vertex, inter_points),
+///     Err(Error::FromTopology(_)),
+/// ));
+/// ```
+pub fn try_bezier(
+    vertex0: &Vertex,
+    vertex1: &Vertex,
+    mut inter_points: Vec<Point3>,
+) -> Result<Edge> {
+    let pt0 = vertex0.point();
+    let pt1 = vertex1.point();
+    let mut ctrl_pts = vec![pt0];
+    ctrl_pts.append(&mut inter_points);
+    ctrl_pts.push(pt1);
+    let knot_vec = KnotVec::bezier_knot(ctrl_pts.len() - 1);
+    let curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    Ok(Edge::try_new(vertex0, vertex1, Curve::BSplineCurve(curve))?)
+}
+
 /// Returns a Bezier curve from `vertex0` to `vertex1` with inter control points `inter_points`.
 /// # Examples
 /// ```
@@ -96,6 +188,87 @@ pub fn bezier(vertex0: &Vertex, vertex1: &Vertex, mut inter_points: Vec<Point3>)
     Edge::new(vertex0, vertex1, Curve::BSplineCurve(curve))
 }
 
+/// Divides `edge` into the edges of the lines, the exact circle arcs and the free curves
+/// recognized within `tol` by [`Curve::analytic_spans`], e.g. in order to clean up the imported
+/// edges. The returned wire goes from the front to the back of `edge`, and the vertices between
+/// the spans are created.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let vertex0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let vertex1 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+/// let arc = builder::circle_arc(&vertex0, &vertex1, Point3::new(0.0, 1.0, 0.0));
+///
+/// // the B-spline approximation of the semicircle
+/// let bspcurve = BSplineCurve::cubic_approximation(
+///     &arc.oriented_curve(),
+///     arc.oriented_curve().parameter_range(),
+///     1.0e-8,
+///     1.0e-8,
+///     10,
+/// )
+/// .unwrap();
+/// let edge = Edge::new(&vertex0, &vertex1, Curve::BSplineCurve(bspcurve));
+///
+/// let wire = builder::analytic_edges(&edge, 1.0e-6);
+/// assert_eq!(wire.len(), 1);
+/// assert_eq!(wire.front_vertex(), Some(&vertex0));
+/// assert_eq!(wire.back_vertex(), Some(&vertex1));
+/// let curve = wire[0].oriented_curve();
+/// assert!(matches!(curve, Curve::NurbsCurve(_)));
+/// assert_near!(curve.subs(0.5), Point3::new(0.0, 1.0, 0.0));
+/// ```
+pub fn analytic_edges(edge: &Edge, tol: f64) -> Wire {
+    let curves = edge.oriented_curve().analytic_spans(tol);
+    let n = curves.len();
+    let mut front = edge.front().clone();
+    curves
+        .into_iter()
+        .enumerate()
+        .map(|(i, curve)| {
+            let back = match i + 1 == n {
+                true => edge.back().clone(),
+                false => vertex(curve.back()),
+            };
+            let edge = Edge::new(&front, &back, curve);
+            front = back;
+            edge
+        })
+        .collect()
+}
+
+/// Try creating a homotopic face from `edge0` to `edge1`.
+/// # Failures
+/// If the edges share an end vertex, then the boundary cannot be constructed and returns the
+/// topological error.
+/// ```
+/// use truck_modeling::*;
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let line0 = builder::line(&v0, &v1);
+/// let line1 = builder::line(&v2, &v1);
+/// assert!(builder::try_homotopy(&line0, &line1).is_err());
+/// ```
+pub fn try_homotopy(edge0: &Edge, edge1: &Edge) -> Result<Face> {
+    check_curves(edge0)?;
+    check_curves(edge1)?;
+    let wire: Wire = vec![
+        edge0.clone(),
+        try_line(edge0.back(), edge1.back())?,
+        edge1.inverse(),
+        try_line(edge1.front(), edge0.front())?,
+    ]
+    .into();
+    let curve0 = edge0.oriented_curve().lift_up();
+    let curve1 = edge1.oriented_curve().lift_up();
+    let surface = BSplineSurface::homotopy(curve0, curve1);
+    Ok(Face::try_new(
+        vec![wire],
+        Surface::NurbsSurface(NurbsSurface::new(surface)),
+    )?)
+}
+
 /// Returns a homotopic face from `edge0` to `edge1`.
 /// # Examples
 /// ```
@@ -230,6 +403,8 @@ pub fn try_wire_homotopy(wire0: &Wire, wire1: &Wire) -> Result<Shell> {
     if wire0.len() != wire1.len() {
         return Err(Error::NotSameNumberOfEdges);
     }
+    check_curves(wire0)?;
+    check_curves(wire1)?;
     let mut vemap = truck_base::entry_map::FxEntryMap::new(
         |(v0, v1): (&Vertex, &Vertex)| (v0.id(), v1.id()),
         |(v0, v1)| line(v0, v1),
@@ -255,6 +430,273 @@ pub fn try_wire_homotopy(wire0: &Wire, wire1: &Wire) -> Result<Shell> {
     Ok(shell)
 }
 
+/// Returns a Coons patch bounded by a closed wire with four edges.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(1.0, 1.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::bezier(&v0, &v1, vec![Point3::new(0.5, 0.0, 0.5)]),
+///     builder::line(&v1, &v2),
+///     builder::circle_arc(&v2, &v3, Point3::new(0.5, 1.0, -0.5)),
+///     builder::line(&v3, &v0),
+/// ]
+/// .into();
+/// let face = builder::try_patch(&wire).unwrap();
+///
+/// // the surface is bounded by the edges
+/// let surface = face.oriented_surface();
+/// let curve0 = face.boundaries()[0][0].oriented_curve();
+/// let curve2 = face.boundaries()[0][2].oriented_curve();
+/// let ((s0, s1), (t0, t1)) = (curve0.parameter_range(), curve2.parameter_range());
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     let t = i as f64 / N as f64;
+///     assert!(surface.subs(t, 0.0).near(&curve0.subs(s0 + (s1 - s0) * t)));
+///     assert!(surface.subs(1.0 - t, 1.0).near(&curve2.subs(t0 + (t1 - t0) * t)));
+/// }
+/// ```
+/// # Failures
+/// If the wire does not consist of four edges, then returns `Error::NotFourSidedWire`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v0),
+/// ]
+/// .into();
+/// assert!(matches!(builder::try_patch(&wire), Err(Error::NotFourSidedWire)));
+/// ```
+#[inline(always)]
+pub fn try_patch(wire: &Wire) -> Result<Face> {
+    check_curves(wire)?;
+    let curves: Vec<_> = wire
+        .edge_iter()
+        .map(|edge| edge.oriented_curve().lift_up())
+        .collect();
+    let curves: [_; 4] = match curves.try_into() {
+        Ok(curves) => curves,
+        Err(_) => return Err(Error::NotFourSidedWire),
+    };
+    let surface = BSplineSurface::coons(curves);
+    Ok(Face::try_new(
+        vec![wire.clone()],
+        Surface::NurbsSurface(NurbsSurface::new(surface)),
+    )?)
+}
+
+/// Returns a Coons patch bounded by a closed wire with four edges.
+///
+/// cf. [`try_patch`]
+/// # Panics
+/// Panic occurs if the wire is not closed or does not consist of four edges.
+#[inline(always)]
+pub fn patch(wire: &Wire) -> Face { try_patch(wire).unwrap_or_else(|e| panic!("{}", e)) }
+
+/// Fills the hole of `shell` bounded by `wire` with smooth faces.
+///
+/// The hole is divided into the fan of patches around the centroid of the boundary, i.e. each
+/// edge of `wire` bounds one patch. If an edge of `wire` is shared with a face of `shell`, the
+/// patch is adjusted to be tangent to the face along the edge, otherwise, the patch is ruled
+/// toward the centroid. The tangency also holds at the end points of the edge if the faces
+/// meeting at the end point share the tangent plane there. In order to extend the returned faces
+/// into `shell`, `wire` should be oriented in the opposite direction to the boundary of `shell`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use shell::ShellCondition;
+///
+/// // a box without the top face
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+/// let mut shell = cube.into_boundaries().pop().unwrap();
+/// shell.pop();
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+///
+/// let boundary = shell.extract_boundaries();
+/// let filling = builder::try_fill_hole(&shell, &boundary[0].inverse()).unwrap();
+/// assert_eq!(filling.len(), 4);
+///
+/// shell.extend(filling);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// ```
+/// # Failures
+/// If `wire` is not closed, then returns `Error::FromTopology(NotClosedWire)`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![builder::line(&v0, &v1), builder::line(&v1, &v2)].into();
+/// assert_eq!(
+///     builder::try_fill_hole(&Shell::new(), &wire).unwrap_err(),
+///     Error::FromTopology(truck_topology::errors::Error::NotClosedWire),
+/// );
+/// ```
+pub fn try_fill_hole(shell: &Shell, wire: &Wire) -> Result<Shell> {
+    if wire.is_empty() || !wire.is_closed() {
+        return Err(truck_topology::errors::Error::NotClosedWire.into());
+    }
+    check_curves(wire)?;
+    let points = wire
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.parameter_range();
+            [curve.subs(t0), curve.subs((t0 + t1) / 2.0)]
+        })
+        .collect::<Vec<_>>();
+    let center = Point3::centroid(&points);
+    let n = wire.len();
+    let corner_offsets = (0..n)
+        .map(|i| hole_filling_corner_offset(shell, &wire[(i + n - 1) % n], &wire[i], center))
+        .collect::<Vec<_>>();
+    if n == 1 {
+        let offsets = [corner_offsets[0], corner_offsets[0]];
+        let surface = hole_filling_surface(shell, &wire[0], center, offsets);
+        return Ok(vec![Face::try_new(vec![wire.clone()], surface)?].into());
+    }
+    let center_vertex = vertex(center);
+    let inner_edges = wire
+        .edge_iter()
+        .zip(&corner_offsets)
+        .map(|(edge, offset)| {
+            let point = edge.front().point();
+            let point1 = point + *offset;
+            let control_points = vec![point, point1, point1.midpoint(center), center];
+            let curve = BSplineCurve::new(KnotVec::bezier_knot(3), control_points);
+            Edge::new(edge.front(), &center_vertex, Curve::BSplineCurve(curve))
+        })
+        .collect::<Vec<_>>();
+    wire.edge_iter()
+        .enumerate()
+        .map(|(i, edge)| {
+            let boundary: Wire = vec![
+                edge.clone(),
+                inner_edges[(i + 1) % n].clone(),
+                inner_edges[i].inverse(),
+            ]
+            .into();
+            let offsets = [corner_offsets[i], corner_offsets[(i + 1) % n]];
+            let surface = hole_filling_surface(shell, edge, center, offsets);
+            Ok(Face::try_new(vec![boundary], surface)?)
+        })
+        .collect()
+}
+
+/// Returns the oriented surface of the face of `shell` containing `edge`.
+fn hole_filling_neighbor(shell: &Shell, edge: &Edge) -> Option<Surface> {
+    shell
+        .face_iter()
+        .find(|face| face.edge_iter().any(|e| e.id() == edge.id()))
+        .map(|face| face.oriented_surface())
+}
+
+/// Returns the offset of the control point next to the corner between `edge0` and `edge1`.
+///
+/// The offset is projected to the tangent plane if the neighbors of both edges share the tangent
+/// plane at the corner, otherwise, it is ruled toward `center`.
+fn hole_filling_corner_offset(
+    shell: &Shell,
+    edge0: &Edge,
+    edge1: &Edge,
+    center: Point3,
+) -> Vector3 {
+    let point = edge1.front().point();
+    let ruled = (center - point) / 3.0;
+    let normals = [edge0, edge1]
+        .into_iter()
+        .filter_map(|edge| {
+            let surface = hole_filling_neighbor(shell, edge)?;
+            let (u, v) = surface.search_parameter(point, None, 100)?;
+            Some(surface.normal(u, v))
+        })
+        .collect::<Vec<_>>();
+    let smooth = normals.windows(2).all(|n| n[0].cross(n[1]).so_small());
+    match normals.first() {
+        Some(normal) if smooth => {
+            let dir = ruled - normal * normal.dot(ruled);
+            match dir.so_small() {
+                true => ruled,
+                false => dir.normalize() * ruled.magnitude(),
+            }
+        }
+        _ => ruled,
+    }
+}
+
+/// Returns the patch bounded by `edge` and degenerated at `center`.
+///
+/// The offsets of the control points next to the end points of `edge` are given by `end_offsets`.
+fn hole_filling_surface(
+    shell: &Shell,
+    edge: &Edge,
+    center: Point3,
+    end_offsets: [Vector3; 2],
+) -> Surface {
+    let curve = edge.oriented_curve();
+    let neighbor = hole_filling_neighbor(shell, edge);
+    // the direction tangent to the neighbor and orthogonal to the edge
+    let cross_direction = |surface: &Surface, t: f64| -> Option<Vector3> {
+        let (u, v) = surface.search_parameter(curve.subs(t), None, 100)?;
+        let dir = surface.normal(u, v).cross(curve.der(t));
+        match dir.so_small() {
+            true => None,
+            false => Some(dir.normalize()),
+        }
+    };
+    let mut bspcurve = curve.clone().lift_up();
+    while bspcurve.degree() < 3 {
+        bspcurve.elevate_degree();
+    }
+    let (knot_vec, degree) = (bspcurve.knot_vec(), bspcurve.degree());
+    let len = bspcurve.control_points().len();
+    let control_points = bspcurve
+        .control_points()
+        .iter()
+        .enumerate()
+        .map(|(k, pt)| {
+            let (point, weight) = (pt.to_point(), pt.weight());
+            let ruled = (center - point) / 3.0;
+            let offset = match (&neighbor, k) {
+                (_, 0) => end_offsets[0],
+                (_, k) if k + 1 == len => end_offsets[1],
+                (Some(surface), _) => {
+                    // the Greville abscissa
+                    let t = (1..=degree).map(|l| knot_vec[k + l]).sum::<f64>() / degree as f64;
+                    match cross_direction(surface, t) {
+                        Some(dir) if dir.dot(ruled) < 0.0 => -dir * ruled.magnitude(),
+                        Some(dir) => dir * ruled.magnitude(),
+                        None => ruled,
+                    }
+                }
+                (None, _) => ruled,
+            };
+            let point1 = point + offset;
+            let point2 = point1.midpoint(center);
+            [point, point1, point2, center]
+                .into_iter()
+                .map(|pt| Vector4::from_point_weight(pt, weight))
+                .collect()
+        })
+        .collect();
+    let knot_vecs = (knot_vec.clone(), KnotVec::bezier_knot(3));
+    let surface = BSplineSurface::new(knot_vecs, control_points);
+    Surface::NurbsSurface(NurbsSurface::new(surface))
+}
+
 /// Creates a cone by R-sweeping.
 /// # Examples
 /// ```
@@ -464,6 +906,27 @@ pub fn scaled<T: Mapped<Point3, Curve, Surface>>(elem: &T, origin: Point3, scala
     transformed(elem, mat2 * mat1 * mat0)
 }
 
+/// Try sweeping a vertex, an edge, a wire, a face, or a shell by a vector.
+/// # Failures
+/// If `vector` is so small, then returns `Error::ZeroLengthSweepVector`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertex(Point3::origin());
+/// assert!(builder::try_tsweep(&v, Vector3::unit_x()).is_ok());
+/// assert_eq!(
+///     builder::try_tsweep(&v, Vector3::zero()).unwrap_err(),
+///     Error::ZeroLengthSweepVector,
+/// );
+/// ```
+pub fn try_tsweep<T>(elem: &T, vector: Vector3) -> Result<T::Swept>
+where T: Sweep<Point3, Curve, Surface> + Mapped<Point3, Curve, Surface> {
+    if vector.so_small() {
+        return Err(Error::ZeroLengthSweepVector);
+    }
+    check_curves(elem)?;
+    Ok(tsweep(elem, vector))
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by a vector.
 /// # Examples
 /// ```
@@ -522,17 +985,337 @@ pub fn tsweep<T: Sweep<Point3, Curve, Surface>>(elem: &T, vector: Vector3) -> T:
                 ))
                 .into()
             }
+            (Curve::Polyline(curve0), Curve::Polyline(curve1)) => {
+                let curve0 = geometry::polyline_to_bspline(curve0);
+                let curve1 = geometry::polyline_to_bspline(curve1);
+                BSplineSurface::homotopy(curve0, curve1).into()
+            }
             (Curve::IntersectionCurve(_), Curve::IntersectionCurve(_)) => unimplemented!(),
             _ => unreachable!(),
         },
     )
 }
 
+/// The options of the extrusions by [`try_tsweep_with_options`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExtrusionOptions {
+    /// the draft angle between the side faces and the sweep vector. The profile is enlarged
+    /// along the sweep by positive angles, and shrunk by negative ones.
+    pub draft: Option<Rad<f64>>,
+    /// the twist angle per unit length around the axis through the center of the profile
+    pub twist_per_length: Option<Rad<f64>>,
+}
+
+/// Try sweeping a face by a vector with the draft and the twist.
+///
+/// The boundaries of a drafted face are offset outward in the plane, and the vertices are moved
+/// along the miters of the adjacent edges. A twisted face is swept in the sections twisted by at
+/// most 22.5 degrees, which are connected by ruled surfaces.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use builder::ExtrusionOptions;
+///
+/// // a frustum of the square pyramid
+/// let v = builder::vertex(Point3::new(-1.0, -1.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::new(2.0, 0.0, 0.0));
+/// let square = builder::tsweep(&e, Vector3::new(0.0, 2.0, 0.0));
+/// let options = ExtrusionOptions {
+///     draft: Some(Deg(-30.0).into()),
+///     ..Default::default()
+/// };
+/// let frustum = builder::try_tsweep_with_options(&square, Vector3::unit_z(), options).unwrap();
+/// assert!(frustum.is_geometric_consistent());
+/// let top = frustum.boundaries()[0].vertex_iter().find(|v| v.point().z > 0.5).unwrap();
+/// assert_near!(f64::abs(top.point().x), 1.0 - f64::tan(std::f64::consts::PI / 6.0));
+///
+/// // the square twisted by 45 degrees
+/// let options = ExtrusionOptions {
+///     twist_per_length: Some(Deg(45.0).into()),
+///     ..Default::default()
+/// };
+/// let twisted = builder::try_tsweep_with_options(&square, Vector3::unit_z(), options).unwrap();
+/// assert!(twisted.is_geometric_consistent());
+/// let top = Point3::new(f64::sqrt(2.0), 0.0, 1.0);
+/// assert!(twisted.boundaries()[0].vertex_iter().any(|v| v.point().near(&top)));
+/// ```
+/// # Failures
+/// - If `vector` is so small, then returns `Error::ZeroLengthSweepVector`.
+/// - If the draft is specified for a face which is not planar or bounded by the curves other than
+/// lines and circle arcs, or the draft angle is not less than 90 degrees, then returns
+/// `Error::UnsupportedDraftProfile`.
+/// - If the face is parallel to `vector` or collapsed by the draft, then returns
+/// `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// use builder::ExtrusionOptions;
+/// let v = builder::vertex(Point3::new(-1.0, -1.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::new(2.0, 0.0, 0.0));
+/// let square = builder::tsweep(&e, Vector3::new(0.0, 2.0, 0.0));
+/// let options = ExtrusionOptions {
+///     draft: Some(Deg(-60.0).into()),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     builder::try_tsweep_with_options(&square, Vector3::unit_z(), options).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+#[inline(always)]
+pub fn try_tsweep_with_options(
+    face: &Face,
+    vector: Vector3,
+    options: ExtrusionOptions,
+) -> Result<Solid> {
+    extrusion::try_tsweep_with_options(face, vector, options)
+}
+
+/// Sweeps a face by a vector with the draft and the twist.
+///
+/// See [`try_tsweep_with_options`] for the details.
+/// # Panics
+/// Panic occurs if [`try_tsweep_with_options`] fails.
+#[inline(always)]
+pub fn tsweep_with_options(face: &Face, vector: Vector3, options: ExtrusionOptions) -> Solid {
+    try_tsweep_with_options(face, vector, options).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Returns the rotation-minimizing frames of `curve` at `division + 1` points of the equal
+/// parameter steps, computed by the double reflection method. The columns of the frames are
+/// the two normal axes, the unit tangent, and the point on the curve.
+fn rotation_minimizing_frames(curve: &Curve, division: usize) -> Result<Vec<Matrix4>> {
+    let (t0, t1) = curve.parameter_range();
+    let samples = (0..=division)
+        .map(|k| {
+            let t = t0 + (t1 - t0) * k as f64 / division as f64;
+            match curve.der(t).so_small() {
+                true => Err(Error::ZeroLengthSweepVector),
+                false => Ok((curve.subs(t), curve.der(t).normalize())),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let frame = |(pt, tangent): (Point3, Vector3), axis: Vector3| {
+        let (axis0, axis1) = (axis.extend(0.0), tangent.cross(axis).extend(0.0));
+        Matrix4::from_cols(axis0, axis1, tangent.extend(0.0), pt.to_homogeneous())
+    };
+    let reflect = |vec: Vector3, normal: Vector3| match normal.so_small() {
+        true => vec,
+        false => vec - normal * (2.0 * vec.dot(normal) / normal.dot(normal)),
+    };
+    let mut axis = geom_impls::take_one_axis_by_normal(samples[0].1);
+    let mut frames = vec![frame(samples[0], axis)];
+    samples.windows(2).for_each(|pair| {
+        let ((pt0, tangent0), (pt1, tangent1)) = (pair[0], pair[1]);
+        let reflected_axis = reflect(axis, pt1 - pt0);
+        let reflected_tangent = reflect(tangent0, pt1 - pt0);
+        axis = reflect(reflected_axis, tangent1 - reflected_tangent).normalize();
+        frames.push(frame(pair[1], axis));
+    });
+    Ok(frames)
+}
+
+/// Try sweeping a vertex, an edge, a wire, or a face along `path`, scaling the profile by `law`.
+///
+/// The profile is placed at the front of `path`. The sweep is divided into `division` sections
+/// at the equal parameter steps of `path`, which are moved by the rotation-minimizing frames of
+/// `path` and connected by ruled surfaces. The section is scaled in the normal plane of `path`
+/// around the point on `path` by the ratio of the value of `law` to the one at the front, where
+/// the parameter range of `law` is mapped to the one of `path` linearly.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a tapered pipe
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// let disk = builder::try_attach_plane(&[circle]).unwrap();
+/// let path = builder::line(
+///     &builder::vertex(Point3::origin()),
+///     &builder::vertex(Point3::new(0.0, 0.0, 2.0)),
+/// );
+/// let law = BSplineCurve::new(
+///     KnotVec::bezier_knot(1),
+///     vec![Point1::new(1.0), Point1::new(0.5)],
+/// );
+/// let pipe = builder::try_law_sweep(&disk, &path, &law, 4).unwrap();
+/// assert!(pipe.is_geometric_consistent());
+/// pipe.boundaries()[0].vertex_iter().for_each(|v| {
+///     let pt = v.point();
+///     assert_near!(f64::hypot(pt.x, pt.y), 1.0 - pt.z / 4.0);
+/// });
+/// ```
+/// # Failures
+/// - If the derivation of `path` vanishes at a section, then returns
+/// `Error::ZeroLengthSweepVector`.
+/// - If the value of `law` is not positive at a section, then returns `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let path = builder::line(
+///     &builder::vertex(Point3::origin()),
+///     &builder::vertex(Point3::new(0.0, 0.0, 2.0)),
+/// );
+/// let law = BSplineCurve::new(
+///     KnotVec::bezier_knot(1),
+///     vec![Point1::new(1.0), Point1::new(0.0)],
+/// );
+/// assert_eq!(
+///     builder::try_law_sweep(&v, &path, &law, 4).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+pub fn try_law_sweep<T, L>(elem: &T, path: &Edge, law: &L, division: usize) -> Result<T::Swept>
+where
+    T: SectionSweep<Point3, Curve, Surface> + Mapped<Point3, Curve, Surface>,
+    L: BoundedCurve<Point = Point1>, {
+    check_curves(elem)?;
+    let division = usize::max(division, 1);
+    let frames = rotation_minimizing_frames(&path.oriented_curve(), division)?;
+    let (t0, t1) = law.parameter_range();
+    let scalars = (0..=division)
+        .map(|k| {
+            let scalar = law.subs(t0 + (t1 - t0) * k as f64 / division as f64).x;
+            match scalar > TOLERANCE {
+                true => Ok(scalar),
+                false => Err(Error::DegenerateProfile),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let inverse = frames[0].invert().unwrap();
+    let matrices: Vec<Matrix4> = frames
+        .iter()
+        .zip(&scalars)
+        .map(|(frame, scalar)| {
+            let ratio = scalar / scalars[0];
+            frame * Matrix4::from_nonuniform_scale(ratio, ratio, 1.0) * inverse
+        })
+        .collect();
+    Ok(elem.section_sweep(
+        &|k: usize, pt: &Point3| matrices[k].transform_point(*pt),
+        &|k: usize, curve: &Curve| curve.transformed(matrices[k]),
+        &|k: usize, surface: &Surface| surface.transformed(matrices[k]),
+        &|pt0: &Point3, pt1: &Point3| Curve::Line(Line(*pt0, *pt1)),
+        &extrusion::ruled_surface,
+        division,
+    ))
+}
+
+/// Sweeps a vertex, an edge, a wire, or a face along `path`, scaling the profile by `law`.
+///
+/// See [`try_law_sweep`] for the details.
+/// # Panics
+/// Panic occurs if [`try_law_sweep`] fails.
+#[inline(always)]
+pub fn law_sweep<T, L>(elem: &T, path: &Edge, law: &L, division: usize) -> T::Swept
+where
+    T: SectionSweep<Point3, Curve, Surface> + Mapped<Point3, Curve, Surface>,
+    L: BoundedCurve<Point = Point1>, {
+    try_law_sweep(elem, path, law, division).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Try blending `profile0` into `profile1` along the two rails.
+///
+/// The rails connect two vertices of `profile0` to the vertices of `profile1` at the same
+/// positions in the wires, and the edges of the profiles are corresponded in the order of the
+/// wires. At each of the `division + 1` sections, the profiles are moved by the frames whose
+/// origin is on the first rail and whose first axis is the chord to the second rail, and are
+/// blended linearly. Each edge sweeps a Gordon surface interpolating the sections and the
+/// trajectories of the vertices. The edges other than lines, B-spline curves, and polylines are
+/// approximated by cubic B-spline curves.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a frustum of the square pyramid
+/// let square = |size: f64, height: f64| -> Wire {
+///     let points = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+///     let v = points.map(|(x, y)| builder::vertex(Point3::new(x * size, y * size, height)));
+///     (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect()
+/// };
+/// let (profile0, profile1) = (square(1.0, 0.0), square(0.5, 2.0));
+/// let line = |p: Point3, q: Point3| builder::line(&builder::vertex(p), &builder::vertex(q));
+/// let rail0 = line(Point3::new(-1.0, -1.0, 0.0), Point3::new(-0.5, -0.5, 2.0));
+/// let rail1 = line(Point3::new(1.0, 1.0, 0.0), Point3::new(0.5, 0.5, 2.0));
+///
+/// let shell = builder::try_rail_blend(&profile0, &profile1, [&rail0, &rail1], 4).unwrap();
+/// assert_eq!(shell.len(), 4);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// assert!(shell.is_geometric_consistent());
+/// // the middle of the side face
+/// let surface = shell[0].surface();
+/// assert_near!(surface.subs(0.5, 0.5), Point3::new(0.0, -0.75, 1.0));
+/// ```
+/// # Failures
+/// - If the profiles have the different numbers of edges, or only one of them is closed, then
+/// returns `Error::NotSameNumberOfEdges`.
+/// - If the rails do not connect the corresponding vertices, or connect the same vertices, then
+/// returns `Error::RailsNotOnProfiles`.
+/// - If the rails meet, or their chord is parallel to their derivations, then returns
+/// `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertex(Point3::origin());
+/// let center = Point3::new(1.0, 0.0, 0.0);
+/// let profile0: Wire = builder::rsweep(&v, center, Vector3::unit_z(), Rad(7.0));
+/// let profile1 = builder::translated(&profile0, Vector3::unit_z());
+/// let line = |p: Point3, q: Point3| builder::line(&builder::vertex(p), &builder::vertex(q));
+/// let rail0 = line(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0));
+/// let rail1 = line(Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0));
+/// assert_eq!(
+///     builder::try_rail_blend(&profile0, &profile1, [&rail0, &rail1], 4).unwrap_err(),
+///     Error::RailsNotOnProfiles,
+/// );
+/// ```
+#[inline(always)]
+pub fn try_rail_blend(
+    profile0: &Wire,
+    profile1: &Wire,
+    rails: [&Edge; 2],
+    division: usize,
+) -> Result<Shell> {
+    rail_blend::try_rail_blend(profile0, profile1, rails, division)
+}
+
+/// Blends `profile0` into `profile1` along the two rails.
+///
+/// See [`try_rail_blend`] for the details.
+/// # Panics
+/// Panic occurs if [`try_rail_blend`] fails.
+#[inline(always)]
+pub fn rail_blend(profile0: &Wire, profile1: &Wire, rails: [&Edge; 2], division: usize) -> Shell {
+    try_rail_blend(profile0, profile1, rails, division).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Try sweeping a vertex, an edge, a wire, a face, or a shell by the rotation.
+///
+/// Different from [`rsweep`], `axis` does not have to be normalized.
+/// # Failures
+/// If `axis` is so small, then returns `Error::ZeroLengthAxis`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle = builder::try_rsweep(&v, Point3::origin(), Vector3::new(0.0, 0.0, 2.0), Rad(7.0));
+/// assert_eq!(circle.unwrap().len(), 2);
+/// assert_eq!(
+///     builder::try_rsweep(&v, Point3::origin(), Vector3::zero(), Rad(7.0)).unwrap_err(),
+///     Error::ZeroLengthAxis,
+/// );
+/// ```
+pub fn try_rsweep<T, R>(elem: &T, origin: Point3, axis: Vector3, angle: R) -> Result<T::Swept>
+where
+    T: ClosedSweep<Point3, Curve, Surface> + Mapped<Point3, Curve, Surface>,
+    R: Into<Rad<f64>>, {
+    nonzero_axis(axis)?;
+    check_curves(elem)?;
+    Ok(rsweep(elem, origin, axis.normalize(), angle))
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by the rotation.
 /// # Details
 /// If the absolute value of `angle` is more than 2π rad, then the result is closed shape.
 /// For example, the result of sweeping a disk is a bent cylinder if `angle` is less than 2π rad
 /// and a solid torus if `angle` is more than 2π rad.
+/// The partial revolution of a wire is an open shell; [`capped_rsweep`] closes it by the caps.
 /// # Remarks
 /// `axis` must be normalized. If not, panics occurs in debug mode.
 /// # Examples
@@ -640,6 +1423,145 @@ pub fn rsweep<T: ClosedSweep<Point3, Curve, Surface>, R: Into<Rad<f64>>>(
     }
 }
 
+/// Try revolving a planar wire into a solid, capping the ends of the partial revolution by the
+/// planar faces.
+///
+/// An open wire is closed by the line between its ends. If both ends are on the axis, the
+/// degenerate edges at the poles are removed as [`cone`], and the line on the axis bounds the
+/// both caps. The wire is inverted if its plane faces against the revolution, so the boundary
+/// of the solid is oriented outward. As [`rsweep`], the result of the revolution by more than
+/// 2π rad is closed without the caps.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // a quarter of the ring with the square section
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let square = builder::tsweep(&e, Vector3::unit_z());
+/// let profile = square.boundaries()[0].clone();
+/// let axis = Vector3::unit_z();
+/// let ring = builder::try_capped_rsweep(&profile, Point3::origin(), axis, Rad(PI / 2.0)).unwrap();
+/// assert!(ring.is_geometric_consistent());
+/// // the four side faces and two caps
+/// assert_eq!(ring.boundaries()[0].len(), 6);
+///
+/// // a semi-ball by the semicircle from the north pole to the south pole
+/// let north = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+/// let south = builder::vertex(Point3::new(0.0, 0.0, -1.0));
+/// let arc: Wire = vec![builder::circle_arc(&north, &south, Point3::new(1.0, 0.0, 0.0))].into();
+/// let ball = builder::try_capped_rsweep(&arc, Point3::origin(), Vector3::unit_z(), Rad(PI))
+///     .unwrap();
+/// assert!(ball.is_geometric_consistent());
+/// // all edges are on the sphere or the caps on the plane y = 0.
+/// ball.edge_iter().for_each(|edge| {
+///     let curve = edge.curve();
+///     let (t0, t1) = curve.parameter_range();
+///     let pt = curve.subs((t0 + t1) / 2.0);
+///     assert!(pt.y.so_small() || pt.to_vec().magnitude().near(&1.0));
+/// });
+/// ```
+/// # Failures
+/// - If `axis` is so small, then returns `Error::ZeroLengthAxis`.
+/// - If the wire is empty, the ends of the open wire coincide, or the wire is on the axis, then
+/// returns `Error::DegenerateProfile`.
+/// - If the wire is not planar, then returns `Error::WireNotInOnePlane`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let profile: Wire = vec![e].into();
+/// assert_eq!(
+///     builder::try_capped_rsweep(&profile, Point3::origin(), Vector3::zero(), Rad(1.0))
+///         .unwrap_err(),
+///     Error::ZeroLengthAxis,
+/// );
+/// ```
+pub fn try_capped_rsweep<R: Into<Rad<f64>>>(
+    wire: &Wire,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+) -> Result<Solid> {
+    if axis.so_small() {
+        return Err(Error::ZeroLengthAxis);
+    }
+    let (axis, angle) = (axis.normalize(), angle.into());
+    let (front, back) = wire.ends_vertices().ok_or(Error::DegenerateProfile)?;
+    let on_axis = |v: &Vertex| (v.point() - origin).cross(axis).so_small();
+    let closed = wire.is_closed();
+    let poles = !closed && on_axis(front) && on_axis(back);
+    let mut boundary = wire.clone();
+    if !closed {
+        boundary.push_back(try_line(back, front)?);
+    }
+    let face = try_attach_plane(&[boundary])?;
+
+    // the direction of the revolution at the middle point of the edges farthest from the axis
+    let distance = |pt: &Point3| (*pt - origin).cross(axis).magnitude();
+    let farthest = face.boundaries()[0]
+        .edge_iter()
+        .map(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.parameter_range();
+            curve.subs((t0 + t1) / 2.0)
+        })
+        .max_by(|pt0, pt1| distance(pt0).total_cmp(&distance(pt1)))
+        .unwrap();
+    if distance(&farthest).so_small() {
+        return Err(Error::DegenerateProfile);
+    }
+    let direction = axis.cross(farthest - origin) * angle.0.signum();
+    let outward = face.oriented_surface().normal(0.0, 0.0).dot(direction) > 0.0;
+    if !poles {
+        let face = match outward {
+            true => face,
+            false => face.inverse(),
+        };
+        return Ok(rsweep(&face, origin, axis, angle));
+    }
+
+    let profile = match outward {
+        true => wire.clone(),
+        false => wire.inverse(),
+    };
+    let mut shell = cone(&profile, axis, angle);
+    if angle.0.abs() >= 2.0 * PI.0 {
+        return Ok(Solid::try_new(vec![shell])?);
+    }
+    // the boundary of the shell is split at the poles into the ends of the revolution.
+    let mut edges: Vec<Edge> = shell.extract_boundaries().into_iter().flatten().collect();
+    let start = edges.iter().position(|edge| on_axis(edge.front())).unwrap();
+    edges.rotate_left(start);
+    let end = edges.iter().position(|edge| on_axis(edge.back())).unwrap() + 1;
+    let (chain0, chain1) = edges.split_at(end);
+    let axis_edge = line(chain0[end - 1].back(), chain0[0].front());
+    let cap = |chain: &[Edge], edge: Edge| {
+        let wire: Wire = chain.iter().cloned().chain(Some(edge)).collect();
+        try_attach_plane(&[wire.inverse()])
+    };
+    shell.push(cap(chain0, axis_edge.clone())?);
+    shell.push(cap(chain1, axis_edge.inverse())?);
+    Ok(Solid::try_new(vec![shell])?)
+}
+
+/// Revolves a planar wire into a solid, capping the ends of the partial revolution by the planar
+/// faces.
+///
+/// See [`try_capped_rsweep`] for the details.
+/// # Panics
+/// Panic occurs if [`try_capped_rsweep`] fails.
+#[inline(always)]
+pub fn capped_rsweep<R: Into<Rad<f64>>>(
+    wire: &Wire,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+) -> Solid {
+    try_capped_rsweep(wire, origin, axis, angle).unwrap_or_else(|e| panic!("{}", e))
+}
+
 fn partial_rsweep<T: MultiSweep<Point3, Curve, Surface>>(
     elem: &T,
     origin: Point3,
@@ -692,6 +1614,310 @@ fn whole_rsweep<T: ClosedSweep<Point3, Curve, Surface>>(
     )
 }
 
+/// Try creating a cuboid whose opposite corners are `corner0` and `corner1`.
+/// # Failures
+/// If the cuboid is flat, i.e. the corners have the same coordinate on some axis, then returns
+/// `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let corner0 = Point3::new(0.0, 0.0, 0.0);
+/// let corner1 = Point3::new(1.0, 2.0, 0.0);
+/// assert_eq!(builder::try_cuboid(corner0, corner1).unwrap_err(), Error::DegenerateProfile);
+/// ```
+pub fn try_cuboid(corner0: Point3, corner1: Point3) -> Result<Solid> {
+    let diag = corner1 - corner0;
+    if diag.x.so_small() || diag.y.so_small() || diag.z.so_small() {
+        return Err(Error::DegenerateProfile);
+    }
+    Ok(cuboid(corner0, corner1))
+}
+
+/// Creates a cuboid whose faces are parallel to the coordinate planes and whose opposite
+/// corners are `corner0` and `corner1`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let cuboid = builder::cuboid(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+/// let shell = &cuboid.boundaries()[0];
+/// assert_eq!(shell.len(), 6);
+/// assert_eq!(shell.vertex_iter().count(), 24);
+/// assert!(cuboid.is_geometric_consistent());
+/// ```
+pub fn cuboid(corner0: Point3, corner1: Point3) -> Solid {
+    let min = Point3::new(
+        f64::min(corner0.x, corner1.x),
+        f64::min(corner0.y, corner1.y),
+        f64::min(corner0.z, corner1.z),
+    );
+    let diag = Point3::new(
+        f64::max(corner0.x, corner1.x),
+        f64::max(corner0.y, corner1.y),
+        f64::max(corner0.z, corner1.z),
+    ) - min;
+    let v = vertex(min);
+    let e = tsweep(&v, diag.x * Vector3::unit_x());
+    let f = tsweep(&e, diag.y * Vector3::unit_y());
+    tsweep(&f, diag.z * Vector3::unit_z())
+}
+
+/// Try creating a sphere with `center` and `radius`.
+/// # Failures
+/// If `radius` is not positive, then returns `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// assert!(builder::try_sphere(Point3::origin(), 1.0).is_ok());
+/// assert_eq!(
+///     builder::try_sphere(Point3::origin(), 0.0).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+pub fn try_sphere(center: Point3, radius: f64) -> Result<Solid> {
+    positive_radius(radius)?;
+    Ok(sphere(center, radius))
+}
+
+/// Creates a sphere with `center` and `radius`.
+///
+/// The boundary consists of the faces of revolution of a semicircle, whose boundaries degenerate
+/// to the poles on the z-axis through `center`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let center = Point3::new(1.0, 2.0, 3.0);
+/// let sphere = builder::sphere(center, 2.0);
+/// assert!(sphere.is_geometric_consistent());
+/// sphere.edge_iter().for_each(|edge| {
+///     let curve = edge.curve();
+///     let (t0, t1) = curve.parameter_range();
+///     let pt = curve.subs((t0 + t1) / 2.0);
+///     assert_near!(pt.distance(center), 2.0);
+/// });
+/// ```
+pub fn sphere(center: Point3, radius: f64) -> Solid {
+    let north = vertex(center + radius * Vector3::unit_z());
+    let south = vertex(center - radius * Vector3::unit_z());
+    let arc = circle_arc(&north, &south, center + radius * Vector3::unit_x());
+    let shell = cone(&vec![arc].into(), Vector3::unit_z(), Rad(7.0));
+    Solid::new(vec![shell])
+}
+
+/// Try creating a cylinder with `radius` whose bottom is centered at `bottom` and whose axis is
+/// `axis`.
+/// # Failures
+/// If `axis` is so small, then returns `Error::ZeroLengthAxis`. If `radius` is not positive,
+/// then returns `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// assert_eq!(
+///     builder::try_cylinder(Point3::origin(), Vector3::zero(), 1.0).unwrap_err(),
+///     Error::ZeroLengthAxis,
+/// );
+/// assert_eq!(
+///     builder::try_cylinder(Point3::origin(), Vector3::unit_z(), -1.0).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+pub fn try_cylinder(bottom: Point3, axis: Vector3, radius: f64) -> Result<Solid> {
+    nonzero_axis(axis)?;
+    positive_radius(radius)?;
+    Ok(cylinder(bottom, axis, radius))
+}
+
+/// Creates a cylinder with `radius` whose bottom is centered at `bottom` and whose axis is
+/// `axis`. The height of the cylinder is the magnitude of `axis`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let cylinder = builder::cylinder(Point3::origin(), Vector3::new(0.0, 0.0, 2.0), 0.5);
+/// let shell = &cylinder.boundaries()[0];
+/// // the bottom, the two faces of the side, and the top
+/// assert_eq!(shell.len(), 4);
+/// assert!(cylinder.is_geometric_consistent());
+/// ```
+pub fn cylinder(bottom: Point3, axis: Vector3, radius: f64) -> Solid {
+    let (n, a) = axis_frame(axis);
+    let v = vertex(bottom + radius * a);
+    let circle = rsweep(&v, bottom, n, Rad(7.0));
+    let disk = try_attach_plane(&[circle]).unwrap();
+    tsweep(&disk, axis)
+}
+
+/// Try creating a circular cone with `radius` whose base is centered at `base` and whose apex is
+/// `base + axis`.
+/// # Failures
+/// If `axis` is so small, then returns `Error::ZeroLengthAxis`. If `radius` is not positive,
+/// then returns `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// assert_eq!(
+///     builder::try_circular_cone(Point3::origin(), Vector3::unit_z(), 0.0).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+pub fn try_circular_cone(base: Point3, axis: Vector3, radius: f64) -> Result<Solid> {
+    nonzero_axis(axis)?;
+    positive_radius(radius)?;
+    Ok(circular_cone(base, axis, radius))
+}
+
+/// Creates a circular cone with `radius` whose base is centered at `base` and whose apex is
+/// `base + axis`.
+///
+/// cf. [`cone`], creating a shell by revolution of a wire.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let cone = builder::circular_cone(Point3::origin(), Vector3::new(0.0, 0.0, 2.0), 1.0);
+/// assert!(cone.is_geometric_consistent());
+/// let apex = Point3::new(0.0, 0.0, 2.0);
+/// assert!(cone.vertex_iter().any(|v| v.point().near(&apex)));
+/// ```
+pub fn circular_cone(base: Point3, axis: Vector3, radius: f64) -> Solid {
+    let (n, a) = axis_frame(axis);
+    let apex = vertex(base + axis);
+    let rim = vertex(base + radius * a);
+    let center = vertex(base);
+    let wire: Wire = vec![line(&apex, &rim), line(&rim, &center)].into();
+    Solid::new(vec![cone(&wire, n, Rad(7.0))])
+}
+
+/// Try creating a torus centered at `center` around `axis`.
+/// # Failures
+/// If `axis` is so small, then returns `Error::ZeroLengthAxis`. If `minor_radius` is not
+/// positive, or the tube reaches the axis, i.e. `major_radius <= minor_radius`, then returns
+/// `Error::DegenerateProfile`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// assert_eq!(
+///     builder::try_torus(Point3::origin(), Vector3::unit_z(), 1.0, 1.0).unwrap_err(),
+///     Error::DegenerateProfile,
+/// );
+/// ```
+pub fn try_torus(
+    center: Point3,
+    axis: Vector3,
+    major_radius: f64,
+    minor_radius: f64,
+) -> Result<Solid> {
+    nonzero_axis(axis)?;
+    positive_radius(minor_radius)?;
+    positive_radius(major_radius - minor_radius)?;
+    Ok(torus(center, axis, major_radius, minor_radius))
+}
+
+/// Creates a torus centered at `center` around `axis`.
+///
+/// `major_radius` is the radius of the circle of the centers of the tube, and `minor_radius` is
+/// the radius of the tube.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let torus = builder::torus(Point3::origin(), Vector3::unit_z(), 2.0, 0.5);
+/// assert!(torus.is_geometric_consistent());
+/// let shell = &torus.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// ```
+pub fn torus(center: Point3, axis: Vector3, major_radius: f64, minor_radius: f64) -> Solid {
+    let (n, a) = axis_frame(axis);
+    let tube_center = center + major_radius * a;
+    let v = vertex(tube_center + minor_radius * n);
+    let circle = rsweep(&v, tube_center, n.cross(a), Rad(7.0));
+    Solid::new(vec![rsweep(&circle, center, n, Rad(7.0))])
+}
+
+/// Try creating a prism by sweeping the polygon with `points` along `vector`.
+///
+/// The polygon is closed by the segment from the last point to the first point, and the side
+/// faces are planes. The orientation of the solid does not depend on the order of the points.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let points = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let prism = builder::try_prism(&points, Vector3::new(0.0, 0.0, 1.0)).unwrap();
+/// assert_eq!(prism.boundaries()[0].len(), 5);
+/// assert!(prism.is_geometric_consistent());
+/// ```
+/// # Failures
+/// If the points are less than three or not in one plane, then returns
+/// `Error::WireNotInOnePlane`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let points = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+/// ];
+/// assert_eq!(
+///     builder::try_prism(&points, Vector3::new(0.0, 0.0, 1.0)).unwrap_err(),
+///     Error::WireNotInOnePlane,
+/// );
+/// ```
+pub fn try_prism(points: &[Point3], vector: Vector3) -> Result<Solid> {
+    if points.len() < 3 {
+        return Err(Error::WireNotInOnePlane);
+    }
+    let v: Vec<Vertex> = points.iter().map(|pt| vertex(*pt)).collect();
+    let wire: Wire = (0..v.len())
+        .map(|i| line(&v[i], &v[(i + 1) % v.len()]))
+        .collect();
+    let face = try_attach_plane(&[wire])?;
+    let face = match face.oriented_surface().normal(0.0, 0.0).dot(vector) < 0.0 {
+        true => face.inverse(),
+        false => face,
+    };
+    Ok(tsweep(&face, vector))
+}
+
+/// Creates a prism by sweeping the polygon with `points` along `vector`.
+/// # Panics
+/// Panic occurs if the points are less than three or not in one plane.
+#[inline(always)]
+pub fn prism(points: &[Point3], vector: Vector3) -> Solid {
+    try_prism(points, vector).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// the unit vector of `axis` and a unit vector perpendicular to `axis`
+fn axis_frame(axis: Vector3) -> (Vector3, Vector3) {
+    let n = axis.normalize();
+    (n, geom_impls::take_one_axis_by_normal(n))
+}
+
+/// Returns `Error::UnsupportedIntersectionCurve` if `elem` has an edge of an intersection curve.
+pub(super) fn check_curves<T: Mapped<Point3, Curve, Surface>>(elem: &T) -> Result<()> {
+    let found = std::cell::Cell::new(false);
+    elem.mapped(
+        &Point3::clone,
+        &|curve: &Curve| {
+            found.set(found.get() || matches!(curve, Curve::IntersectionCurve(_)));
+            curve.clone()
+        },
+        &Surface::clone,
+    );
+    match found.get() {
+        true => Err(Error::UnsupportedIntersectionCurve),
+        false => Ok(()),
+    }
+}
+
+fn nonzero_axis(axis: Vector3) -> Result<()> {
+    match axis.so_small() {
+        true => Err(Error::ZeroLengthAxis),
+        false => Ok(()),
+    }
+}
+
+fn positive_radius(radius: f64) -> Result<()> {
+    match radius > TOLERANCE {
+        true => Ok(()),
+        false => Err(Error::DegenerateProfile),
+    }
+}
+
 #[test]
 fn partial_torus() {
     let v = vertex(Point3::new(0.5, 0.0, 0.0));
@@ -706,3 +1932,60 @@ fn partial_torus() {
     let torus = rsweep(&face, Point3::origin(), Vector3::unit_z(), Rad(-5.0));
     assert!(torus.is_geometric_consistent());
 }
+
+#[test]
+fn intersection_curve_errors() {
+    let points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    let v = points.map(|(x, y)| vertex(Point3::new(x, y, 0.0)));
+    // the first edge is the intersection of the planes z = 0 and y = 0.
+    let (o, x) = (Point3::origin(), Point3::new(1.0, 0.0, 0.0));
+    let plane0 = Plane::new(o, x, Point3::new(0.0, 1.0, 0.0));
+    let plane1 = Plane::new(o, x, Point3::new(0.0, 0.0, 1.0));
+    let leader = Leader::Polyline(PolylineCurve(vec![v[0].point(), v[1].point()]));
+    let curve = IntersectionCurve::new_unchecked(
+        Box::new(Surface::Plane(plane0)),
+        Box::new(Surface::Plane(plane1)),
+        leader,
+        TOLERANCE,
+    );
+    let edge = Edge::new(&v[0], &v[1], Curve::IntersectionCurve(curve));
+    let wire: Wire = vec![
+        edge.clone(),
+        line(&v[1], &v[2]),
+        line(&v[2], &v[3]),
+        line(&v[3], &v[0]),
+    ]
+    .into();
+    let face = Face::new(vec![wire.clone()], Surface::Plane(plane0));
+
+    let err = Err(Error::UnsupportedIntersectionCurve);
+    assert_eq!(try_tsweep(&edge, Vector3::unit_z()).map(|_| ()), err);
+    let swept = try_rsweep(&face, Point3::new(0.0, -1.0, 0.0), Vector3::unit_x(), Rad(1.0));
+    assert_eq!(swept.map(|_| ()), err);
+    assert_eq!(try_homotopy(&edge, &line(&v[3], &v[2])).map(|_| ()), err);
+    assert_eq!(try_wire_homotopy(&wire, &wire).map(|_| ()), err);
+    assert_eq!(try_patch(&wire).map(|_| ()), err);
+    let options = ExtrusionOptions::default();
+    let swept = try_tsweep_with_options(&face, Vector3::unit_z(), options);
+    assert_eq!(swept.map(|_| ()), err);
+}
+
+#[test]
+fn fill_hole_tangent_at_corners() {
+    let v = vertex(Point3::new(0.5, 0.0, 0.0));
+    let circle = rsweep(&v, Point3::new(0.75, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let mut shell = rsweep(&circle, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    shell.pop();
+    let boundary = shell.extract_boundaries();
+    let filling = try_fill_hole(&shell, &boundary[0].inverse()).unwrap();
+    assert!(filling.is_geometric_consistent());
+    filling.face_iter().for_each(|face| {
+        // the edge from the corner of the hole to the center
+        let curve = face.boundaries()[0][1].oriented_curve();
+        let (t0, _) = curve.parameter_range();
+        let point = curve.subs(t0);
+        let radial = Vector3::new(point.x, point.y, 0.0).normalize() * 0.75;
+        let normal = (point.to_vec() - radial).normalize();
+        assert!(curve.der(t0).normalize().dot(normal).so_small());
+    });
+}
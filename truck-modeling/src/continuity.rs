@@ -0,0 +1,144 @@
+use crate::*;
+
+const SAMPLING_DIVISION: usize = 16;
+const SEARCH_PARAMETER_TRIALS: usize = 100;
+
+/// The maximum and the mean of the deviations at the sampling points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Deviation {
+    /// the maximum deviation
+    pub max: f64,
+    /// the mean deviation
+    pub mean: f64,
+}
+
+/// The continuity of two faces along the shared edge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContinuityReport {
+    /// the distance between the points on two surfaces, G0 continuity
+    pub position: Deviation,
+    /// the angle between the normals of two surfaces in radians, G1 continuity
+    pub tangent: Deviation,
+    /// the difference of the normal curvatures in the direction across the edge, G2 continuity
+    pub curvature: Deviation,
+}
+
+impl Deviation {
+    fn new(values: &[f64]) -> Self {
+        Self {
+            max: values.iter().fold(0.0, |max, x| f64::max(max, *x)),
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+        }
+    }
+}
+
+/// Measures the positional, tangent and curvature continuity of `face0` and `face1` along
+/// `edge`.
+///
+/// The deviations are evaluated at the sampling points on the edge, by the oriented surfaces
+/// of the faces. Returns `None` if the sampling points cannot be projected to the surfaces, or
+/// if the surfaces or the edge degenerate at a sampling point, e.g. at the apex of a cone.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // the faces of a cylinder are smoothly connected.
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let line = builder::tsweep(&v, Vector3::unit_z());
+/// let cylinder = builder::rsweep(&line, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+/// let edge = cylinder[0]
+///     .edge_iter()
+///     .find(|edge| cylinder[1].edge_iter().any(|e| e.id() == edge.id()))
+///     .unwrap();
+/// let report = continuity::continuity_report(&cylinder[0], &cylinder[1], &edge).unwrap();
+/// assert!(report.position.max < TOLERANCE);
+/// assert!(report.tangent.max < TOLERANCE);
+/// assert!(report.curvature.max < 1.0e-3);
+///
+/// // the faces of a cube cross at right angles.
+/// let e = builder::tsweep(&v, Vector3::unit_y());
+/// let f = builder::tsweep(&e, Vector3::unit_z());
+/// let cube = builder::tsweep(&f, Vector3::unit_x());
+/// let shell = &cube.boundaries()[0];
+/// let edge = shell[0]
+///     .edge_iter()
+///     .find(|edge| shell[1].edge_iter().any(|e| e.id() == edge.id()))
+///     .unwrap();
+/// let report = continuity::continuity_report(&shell[0], &shell[1], &edge).unwrap();
+/// assert!(report.position.max < TOLERANCE);
+/// assert!(report.tangent.max.near(&(PI / 2.0)));
+/// assert!(report.tangent.mean.near(&(PI / 2.0)));
+///
+/// // the faces of a cone degenerate at the apex.
+/// let apex = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+/// let line = builder::line(&v, &apex);
+/// let cone = builder::cone(&vec![line].into(), Vector3::unit_z(), Rad(2.0 * PI));
+/// let edge = cone[0]
+///     .edge_iter()
+///     .find(|edge| cone[1].edge_iter().any(|e| e.id() == edge.id()))
+///     .unwrap();
+/// assert!(continuity::continuity_report(&cone[0], &cone[1], &edge).is_none());
+/// ```
+pub fn continuity_report(face0: &Face, face1: &Face, edge: &Edge) -> Option<ContinuityReport> {
+    let curve = edge.curve();
+    let (t0, t1) = curve.parameter_range();
+    let surface0 = face0.oriented_surface();
+    let surface1 = face1.oriented_surface();
+    let mut positions = Vec::with_capacity(SAMPLING_DIVISION + 1);
+    let mut tangents = Vec::with_capacity(SAMPLING_DIVISION + 1);
+    let mut curvatures = Vec::with_capacity(SAMPLING_DIVISION + 1);
+    for i in 0..=SAMPLING_DIVISION {
+        let t = t0 + (t1 - t0) * (i as f64) / (SAMPLING_DIVISION as f64);
+        let point = curve.subs(t);
+        let (u0, v0) = surface0.search_nearest_parameter(point, None, SEARCH_PARAMETER_TRIALS)?;
+        let (u1, v1) = surface1.search_nearest_parameter(point, None, SEARCH_PARAMETER_TRIALS)?;
+        positions.push(surface0.subs(u0, v0).distance(surface1.subs(u1, v1)));
+        let normal0 = nondegenerate_normal(&surface0, (u0, v0))?;
+        let normal1 = nondegenerate_normal(&surface1, (u1, v1))?;
+        tangents.push(f64::atan2(normal0.cross(normal1).magnitude(), normal0.dot(normal1)));
+        // the direction across the edge in the tangent plane
+        let dir = normal0.cross(curve.der(t));
+        if dir.so_small() {
+            return None;
+        }
+        let dir = dir.normalize();
+        let curvature0 = normal_curvature(&surface0, (u0, v0), dir);
+        let curvature1 = normal_curvature(&surface1, (u1, v1), dir);
+        curvatures.push(f64::abs(curvature0 - curvature1));
+    }
+    Some(ContinuityReport {
+        position: Deviation::new(&positions),
+        tangent: Deviation::new(&tangents),
+        curvature: Deviation::new(&curvatures),
+    })
+}
+
+/// Returns the normal of `surface` at `(u, v)`, or `None` if the surface degenerates there.
+fn nondegenerate_normal(surface: &Surface, (u, v): (f64, f64)) -> Option<Vector3> {
+    let normal = surface.uder(u, v).cross(surface.vder(u, v));
+    match normal.so_small() {
+        true => None,
+        false => Some(normal.normalize()),
+    }
+}
+
+/// Returns the normal curvature of `surface` at `(u, v)` in the direction `dir`, projected to
+/// the tangent plane.
+fn normal_curvature(surface: &Surface, (u, v): (f64, f64), dir: Vector3) -> f64 {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    let (e, f, g) = surface.first_fundamental_form(u, v);
+    let (l, m, n) = surface.second_fundamental_form(u, v);
+    let det = e * g - f * f;
+    if det.so_small() {
+        return 0.0;
+    }
+    let (a, b) = (uder.dot(dir), vder.dot(dir));
+    let (du, dv) = ((g * a - f * b) / det, (e * b - f * a) / det);
+    let first = e * du * du + 2.0 * f * du * dv + g * dv * dv;
+    let second = l * du * du + 2.0 * m * du * dv + n * dv * dv;
+    match first.so_small() {
+        true => 0.0,
+        false => second / first,
+    }
+}
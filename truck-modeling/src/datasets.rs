@@ -0,0 +1,137 @@
+use crate::*;
+use std::f64::consts::PI;
+
+/// Creates a spur gear with `teeth` trapezoidal teeth and a bore.
+///
+/// The pitch radius is 1, the face width is 0.2, and the gear is placed on the xy-plane with its
+/// axis along the z-axis. The number of faces is `4 * teeth + 4`.
+/// # Panics
+/// `teeth` must be at least three.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let gear = datasets::gear(12);
+/// assert_eq!(gear.boundaries()[0].len(), 52);
+/// assert!(gear.is_geometric_consistent());
+/// ```
+pub fn gear(teeth: usize) -> Solid {
+    assert!(teeth >= 3, "a gear must have at least three teeth.");
+    let module = 2.0 / teeth as f64;
+    let (root, tip) = (1.0 - 1.25 * module, 1.0 + module);
+    let pitch = 2.0 * PI / teeth as f64;
+    let points = (0..teeth).flat_map(|i| {
+        let angle = i as f64 * pitch;
+        [(root, 0.0), (tip, 0.25), (tip, 0.5), (root, 0.75)].map(|(r, t)| {
+            let theta = angle + t * pitch;
+            Point3::new(r * f64::cos(theta), r * f64::sin(theta), 0.0)
+        })
+    });
+    let mut face = polygon_face(points);
+    let v = builder::vertex(Point3::new(root * 0.3, 0.0, 0.0));
+    let bore = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    face.add_boundary(bore.inverse());
+    builder::tsweep(&face, Vector3::new(0.0, 0.0, 0.2))
+}
+
+/// Creates a turbine blade lofted through `sections` + 1 twisted airfoil sections.
+///
+/// The blade stands along the z-axis with height 3. The chord shrinks and the section twists
+/// toward the tip. The number of faces is `4 * sections + 2`.
+/// # Panics
+/// `sections` must be positive.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let blade = datasets::turbine_blade(8);
+/// assert_eq!(blade.boundaries()[0].len(), 34);
+/// assert!(blade.is_geometric_consistent());
+/// ```
+pub fn turbine_blade(sections: usize) -> Solid {
+    assert!(sections > 0, "a blade must have at least one section.");
+    let wires = (0..=sections)
+        .map(|i| {
+            let t = i as f64 / sections as f64;
+            airfoil_section(1.0 - 0.4 * t, 3.0 * t, Rad(PI / 3.0 * t))
+        })
+        .collect::<Vec<_>>();
+    let bottom = builder::try_attach_plane(&[wires[0].inverse()]).unwrap();
+    let top = builder::try_attach_plane(&[wires[sections].clone()]).unwrap();
+    let mut shell: Shell = vec![bottom].into();
+    wires.windows(2).for_each(|wires| {
+        let mut side = builder::try_wire_homotopy(&wires[0], &wires[1]).unwrap();
+        shell.append(&mut side);
+    });
+    shell.push(top);
+    Solid::new(vec![shell])
+}
+
+/// Creates a bracket plate with the lattice of `cells` x `cells` square windows.
+///
+/// The plate is the unit square on the xy-plane with thickness 0.1. The number of faces is
+/// `4 * cells * cells + 6`.
+/// # Panics
+/// `cells` must be positive.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let bracket = datasets::lattice_bracket(4);
+/// assert_eq!(bracket.boundaries()[0].len(), 70);
+/// assert!(bracket.is_geometric_consistent());
+/// ```
+pub fn lattice_bracket(cells: usize) -> Solid {
+    assert!(cells > 0, "a lattice must have at least one cell.");
+    let square = |center: Point3, half: f64| {
+        [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .map(|(x, y)| center + half * Vector3::new(x, y, 0.0))
+    };
+    let mut face = polygon_face(square(Point3::new(0.5, 0.5, 0.0), 0.5));
+    let (size, half) = (1.0 / cells as f64, 0.35 / cells as f64);
+    (0..cells)
+        .flat_map(|i| (0..cells).map(move |j| (i, j)))
+        .for_each(|(i, j)| {
+            let center = Point3::new((i as f64 + 0.5) * size, (j as f64 + 0.5) * size, 0.0);
+            face.add_boundary(polygon_wire(square(center, half)).inverse());
+        });
+    builder::tsweep(&face, Vector3::new(0.0, 0.0, 0.1))
+}
+
+/// the closed polygonal wire through `points`
+fn polygon_wire(points: impl IntoIterator<Item = Point3>) -> Wire {
+    let vertices = points.into_iter().map(builder::vertex).collect::<Vec<_>>();
+    let len = vertices.len();
+    (0..len)
+        .map(|i| builder::line(&vertices[i], &vertices[(i + 1) % len]))
+        .collect()
+}
+
+/// the planar face bounded by the counter-clockwise polygon, whose normal is the z-axis
+fn polygon_face(points: impl IntoIterator<Item = Point3>) -> Face {
+    let face = builder::try_attach_plane(&[polygon_wire(points)]).unwrap();
+    match face.oriented_surface().normal(0.0, 0.0).z < 0.0 {
+        true => face.inverse(),
+        false => face,
+    }
+}
+
+/// the cambered airfoil at `height` with `chord`, rotated by `twist`
+fn airfoil_section(chord: f64, height: f64, twist: Rad<f64>) -> Wire {
+    const EDGES: usize = 4;
+    let rot = Matrix3::from_angle_z(twist);
+    let point = |theta: f64| {
+        let x = 0.5 * chord * f64::cos(theta);
+        let camber = 0.1 * chord * (1.0 - 4.0 * x * x / (chord * chord));
+        let y = 0.06 * chord * f64::sin(theta) + camber;
+        Point3::from_vec(rot * Vector3::new(x, y, 0.0)) + height * Vector3::unit_z()
+    };
+    let delta = 2.0 * PI / EDGES as f64;
+    let vertices = (0..EDGES)
+        .map(|i| builder::vertex(point(i as f64 * delta)))
+        .collect::<Vec<_>>();
+    (0..EDGES)
+        .map(|i| {
+            let theta = i as f64 * delta;
+            let inter_points = vec![point(theta + delta / 3.0), point(theta + delta * 2.0 / 3.0)];
+            builder::bezier(&vertices[i], &vertices[(i + 1) % EDGES], inter_points)
+        })
+        .collect()
+}
@@ -0,0 +1,410 @@
+use crate::*;
+use std::f64::consts::PI;
+use std::io::{BufRead, BufReader, BufWriter, Read, Result, Write};
+
+/// the tolerance of the polylines approximating the intersection curves in writing
+const POLYLINE_TOLERANCE: f64 = 1.0e-3;
+
+fn syntax_error(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// a pair of the group code and the value
+type Group = (i32, String);
+
+/// an entity in the `ENTITIES` section
+#[derive(Clone, Copy, Debug)]
+struct Entity<'a> {
+    kind: &'a str,
+    groups: &'a [Group],
+}
+
+impl<'a> Entity<'a> {
+    fn values(&self, code: i32) -> impl Iterator<Item = Result<f64>> + 'a {
+        self.groups
+            .iter()
+            .filter(move |(c, _)| *c == code)
+            .map(|(_, value)| parse_f64(value))
+    }
+
+    fn value(&self, code: i32) -> Result<f64> {
+        match self.values(code).next() {
+            Some(got) => got,
+            None => Err(syntax_error("missing group code")),
+        }
+    }
+
+    #[inline(always)]
+    fn value_or(&self, code: i32, default: f64) -> Result<f64> {
+        self.values(code).next().unwrap_or(Ok(default))
+    }
+
+    #[inline(always)]
+    fn flags(&self) -> Result<i32> { Ok(self.value_or(70, 0.0)? as i32) }
+
+    #[inline(always)]
+    fn point(&self, code: i32) -> Result<Point3> {
+        Ok(Point3::new(
+            self.value(code)?,
+            self.value(code + 10)?,
+            self.value_or(code + 20, 0.0)?,
+        ))
+    }
+
+    /// the points by the repeated codes `code`, `code + 10` and `code + 20`, and the bulges.
+    fn vertices(&self, code: i32, default_z: f64) -> Result<Vec<(Point3, f64)>> {
+        let mut vertices = Vec::<(Point3, f64)>::new();
+        for (c, value) in self.groups {
+            let last = vertices.last_mut();
+            match (*c - code, last) {
+                (0, _) => vertices.push((Point3::new(parse_f64(value)?, 0.0, default_z), 0.0)),
+                (10, Some(last)) => last.0.y = parse_f64(value)?,
+                (20, Some(last)) => last.0.z = parse_f64(value)?,
+                (_, Some(last)) if *c == 42 => last.1 = parse_f64(value)?,
+                _ => {}
+            }
+        }
+        Ok(vertices)
+    }
+}
+
+#[inline(always)]
+fn parse_f64(value: &str) -> Result<f64> {
+    value.parse().map_err(|_| syntax_error("invalid number"))
+}
+
+fn read_groups<R: Read>(reader: R) -> Result<Vec<Group>> {
+    let mut lines = BufReader::new(reader).lines();
+    let mut groups = Vec::new();
+    while let Some(code) = lines.next() {
+        let code = code?;
+        if code.trim().is_empty() {
+            continue;
+        }
+        let code = match code.trim().parse::<i32>() {
+            Ok(code) => code,
+            Err(_) => return Err(syntax_error("invalid group code")),
+        };
+        let value = match lines.next() {
+            Some(value) => value?.trim().to_string(),
+            None => return Err(syntax_error("missing group value")),
+        };
+        if code == 0 && value == "EOF" {
+            break;
+        }
+        groups.push((code, value));
+    }
+    Ok(groups)
+}
+
+fn read_entities(groups: &[Group]) -> Vec<Entity<'_>> {
+    let is_section = |i: usize| {
+        groups[i] == (0, "SECTION".to_string()) && groups[i + 1] == (2, "ENTITIES".to_string())
+    };
+    let start = match (0..groups.len().saturating_sub(1)).find(|i| is_section(*i)) {
+        Some(idx) => idx + 2,
+        None => return Vec::new(),
+    };
+    let end = (start..groups.len())
+        .find(|i| groups[*i] == (0, "ENDSEC".to_string()))
+        .unwrap_or(groups.len());
+    let mut entities = Vec::new();
+    let mut idx = start;
+    while idx < end {
+        let next = (idx + 1..end).find(|i| groups[*i].0 == 0).unwrap_or(end);
+        if groups[idx].0 == 0 {
+            entities.push(Entity {
+                kind: &groups[idx].1,
+                groups: &groups[idx + 1..next],
+            });
+        }
+        idx = next;
+    }
+    entities
+}
+
+/// the edge from `v0` to `v1` whose bulge is `bulge`, the tangent of the quarter of the angle
+fn bulge_edge(v0: &Vertex, v1: &Vertex, bulge: f64) -> Edge {
+    if bulge.so_small() {
+        return builder::line(v0, v1);
+    }
+    let (p, q) = (v0.point(), v1.point());
+    let chord = q - p;
+    let right = Vector3::new(chord.y, -chord.x, 0.0) / 2.0;
+    builder::circle_arc(v0, v1, p.midpoint(q) + bulge * right)
+}
+
+fn polyline_wire(mut vertices: Vec<(Point3, f64)>, closed: bool) -> Option<Wire> {
+    vertices.dedup_by(|(p, _), (q, _)| p.near(q));
+    if closed && vertices.len() > 1 && vertices[0].0.near(&vertices[vertices.len() - 1].0) {
+        vertices.pop();
+    }
+    if vertices.len() < 2 {
+        return None;
+    }
+    let v: Vec<Vertex> = vertices
+        .iter()
+        .map(|(pt, _)| builder::vertex(*pt))
+        .collect();
+    let n = match closed {
+        true => v.len(),
+        false => v.len() - 1,
+    };
+    let wire = (0..n)
+        .map(|i| bulge_edge(&v[i], &v[(i + 1) % v.len()], vertices[i].1))
+        .collect();
+    Some(wire)
+}
+
+/// Returns the error if the circle or the arc is degenerate.
+fn check_radius(radius: f64) -> Result<()> {
+    match radius >= TOLERANCE {
+        true => Ok(()),
+        false => Err(syntax_error("the radius is too small")),
+    }
+}
+
+fn circle_wire(center: Point3, radius: f64) -> Result<Wire> {
+    check_radius(radius)?;
+    let (x, y) = (radius * Vector3::unit_x(), radius * Vector3::unit_y());
+    let v0 = builder::vertex(center + x);
+    let v1 = builder::vertex(center - x);
+    let wire = vec![
+        builder::circle_arc(&v0, &v1, center + y),
+        builder::circle_arc(&v1, &v0, center - y),
+    ];
+    Ok(wire.into())
+}
+
+fn arc_wire(center: Point3, radius: f64, start: f64, end: f64) -> Result<Wire> {
+    check_radius(radius)?;
+    let end = match end <= start {
+        true => end + 360.0,
+        false => end,
+    };
+    if end - start >= 360.0 {
+        return circle_wire(center, radius);
+    }
+    let point = |angle: f64| {
+        let angle = angle * PI / 180.0;
+        center + radius * Vector3::new(f64::cos(angle), f64::sin(angle), 0.0)
+    };
+    let v0 = builder::vertex(point(start));
+    let v1 = builder::vertex(point(end));
+    Ok(vec![builder::circle_arc(&v0, &v1, point((start + end) / 2.0))].into())
+}
+
+fn spline_wire(entity: &Entity<'_>) -> Result<Wire> {
+    let degree = entity.value(71)?;
+    let knots = entity.values(40).collect::<Result<Vec<f64>>>()?;
+    let weights = entity.values(41).collect::<Result<Vec<f64>>>()?;
+    let points: Vec<Point3> = entity.vertices(10, 0.0)?.into_iter().map(|x| x.0).collect();
+    // The degree is a positive integer less than the number of the control points.
+    if degree.fract() != 0.0 || degree < 1.0 || degree >= points.len() as f64 {
+        return Err(syntax_error("invalid degree"));
+    }
+    let degree = degree as usize;
+    if knots.len() != points.len() + degree + 1 {
+        return Err(syntax_error("the number of knots is inconsistent"));
+    }
+    let knot_vec = KnotVec::from(knots);
+    let curve: Curve = match weights.iter().all(|w| *w == 1.0) {
+        true => match BSplineCurve::try_new(knot_vec, points) {
+            Ok(curve) => curve.into(),
+            Err(_) => return Err(syntax_error("invalid B-spline curve")),
+        },
+        false => {
+            if weights.len() != points.len() {
+                return Err(syntax_error("the number of weights is inconsistent"));
+            }
+            let points = points
+                .into_iter()
+                .zip(weights)
+                .map(|(pt, w)| Vector4::from_point_weight(pt, w))
+                .collect();
+            match BSplineCurve::try_new(knot_vec, points) {
+                Ok(curve) => NurbsCurve::new(curve).into(),
+                Err(_) => return Err(syntax_error("invalid B-spline curve")),
+            }
+        }
+    };
+    let (t0, t1) = curve.parameter_range();
+    let (p, q) = (curve.front(), curve.back());
+    let v0 = builder::vertex(p);
+    let wire = match p.near(&q) {
+        true => {
+            let mut curve0 = curve;
+            let t = (t0 + t1) / 2.0;
+            let curve1 = curve0.cut(t);
+            let v1 = builder::vertex(curve1.front());
+            vec![Edge::new(&v0, &v1, curve0), Edge::new(&v1, &v0, curve1)].into()
+        }
+        false => vec![Edge::new(&v0, &builder::vertex(q), curve)].into(),
+    };
+    Ok(wire)
+}
+
+/// Reads the lines, arcs, circles, polylines and splines in the DXF data.
+///
+/// Each entity is read as one wire: a line, an arc or a spline as a wire with one edge, and a
+/// circle, a closed spline or a polyline as a wire with some edges. The wires do not share the
+/// vertices, so the profiles consisting of some entities are chained by
+/// [`Wire::from_edge_soup`](truck_topology::Wire::from_edge_soup). The arcs and the circles are
+/// exact rational curves. The entities are assumed to be in the world coordinate, i.e. the
+/// extrusion directions are ignored, and the other entities are skipped.
+/// # Failures
+/// Returns the error of [`InvalidData`](std::io::ErrorKind::InvalidData) if an entity is
+/// broken, e.g. a circle with the zero radius or a spline with a negative degree.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let data = "0\nSECTION\n2\nENTITIES\n\
+///     0\nLINE\n8\n0\n10\n0.0\n20\n0.0\n30\n0.0\n11\n2.0\n21\n0.0\n31\n0.0\n\
+///     0\nARC\n8\n0\n10\n1.0\n20\n0.0\n30\n0.0\n40\n1.0\n50\n0.0\n51\n180.0\n\
+///     0\nENDSEC\n0\nEOF\n";
+/// let wires = dxf::read(data.as_bytes()).unwrap();
+/// assert_eq!(wires.len(), 2);
+///
+/// // a closed profile, a half disk
+/// let wire = Wire::from_edge_soup(wires.into_iter().flatten(), TOLERANCE).pop().unwrap();
+/// assert!(wire.is_closed());
+/// assert_near!(planarity::area(&wire).unwrap(), std::f64::consts::PI / 2.0);
+/// ```
+pub fn read<R: Read>(reader: R) -> Result<Vec<Wire>> {
+    let groups = read_groups(reader)?;
+    let entities = read_entities(&groups);
+    let mut wires = Vec::new();
+    let mut iter = entities.iter().peekable();
+    while let Some(entity) = iter.next() {
+        let wire = match entity.kind {
+            "LINE" => {
+                let (p, q) = (entity.point(10)?, entity.point(11)?);
+                polyline_wire(vec![(p, 0.0), (q, 0.0)], false)
+            }
+            "CIRCLE" => Some(circle_wire(entity.point(10)?, entity.value(40)?)?),
+            "ARC" => Some(arc_wire(
+                entity.point(10)?,
+                entity.value(40)?,
+                entity.value(50)?,
+                entity.value(51)?,
+            )?),
+            "LWPOLYLINE" => {
+                let vertices = entity.vertices(10, entity.value_or(38, 0.0)?)?;
+                polyline_wire(vertices, entity.flags()? & 1 == 1)
+            }
+            "POLYLINE" => {
+                let mut vertices = Vec::new();
+                while let Some(vertex) = iter.next_if(|vertex| vertex.kind == "VERTEX") {
+                    vertices.extend(vertex.vertices(10, 0.0)?);
+                }
+                // The vertices end at the next entity if `SEQEND` is omitted.
+                iter.next_if(|seqend| seqend.kind == "SEQEND");
+                // polyface meshes and polygon meshes are not profiles.
+                match entity.flags()? & (16 | 64) {
+                    0 => polyline_wire(vertices, entity.flags()? & 1 == 1),
+                    _ => None,
+                }
+            }
+            "SPLINE" => Some(spline_wire(entity)?),
+            _ => None,
+        };
+        wires.extend(wire);
+    }
+    Ok(wires)
+}
+
+fn write_group<W: Write>(writer: &mut W, code: i32, value: impl std::fmt::Display) -> Result<()> {
+    writeln!(writer, "{code}\n{value}")
+}
+
+fn write_point<W: Write>(writer: &mut W, code: i32, point: Point3) -> Result<()> {
+    write_group(writer, code, point.x)?;
+    write_group(writer, code + 10, point.y)?;
+    write_group(writer, code + 20, point.z)
+}
+
+fn write_line<W: Write>(writer: &mut W, p: Point3, q: Point3) -> Result<()> {
+    write_group(writer, 0, "LINE")?;
+    write_group(writer, 8, 0)?;
+    write_point(writer, 10, p)?;
+    write_point(writer, 11, q)
+}
+
+fn write_spline<W: Write>(
+    writer: &mut W,
+    knot_vec: &KnotVec,
+    control_points: &[(Point3, f64)],
+) -> Result<()> {
+    let rational = control_points.iter().any(|(_, w)| *w != 1.0);
+    write_group(writer, 0, "SPLINE")?;
+    write_group(writer, 8, 0)?;
+    write_group(writer, 70, if rational { 8 | 4 } else { 8 })?;
+    write_group(writer, 71, knot_vec.len() - control_points.len() - 1)?;
+    write_group(writer, 72, knot_vec.len())?;
+    write_group(writer, 73, control_points.len())?;
+    knot_vec
+        .iter()
+        .try_for_each(|t| write_group(writer, 40, t))?;
+    control_points.iter().try_for_each(|(pt, w)| {
+        write_point(writer, 10, *pt)?;
+        match rational {
+            true => write_group(writer, 41, w),
+            false => Ok(()),
+        }
+    })
+}
+
+/// Writes the wires to the DXF data.
+///
+/// The lines are written as the entities `LINE`, and the B-spline curves and the NURBS curves
+/// are written as the entities `SPLINE`, so the arcs and the circles are exact. The
+/// intersection curves are approximated by lines.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// let mut data = Vec::new();
+/// dxf::write(&[circle], &mut data).unwrap();
+///
+/// let wires = dxf::read(data.as_slice()).unwrap();
+/// let wire = Wire::from_edge_soup(wires.into_iter().flatten(), TOLERANCE).pop().unwrap();
+/// assert!(wire.is_closed());
+/// assert_near!(planarity::area(&wire).unwrap(), std::f64::consts::PI);
+/// ```
+pub fn write<W: Write>(wires: &[Wire], writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    write_group(&mut writer, 0, "SECTION")?;
+    write_group(&mut writer, 2, "ENTITIES")?;
+    for edge in wires.iter().flatten() {
+        match edge.oriented_curve() {
+            Curve::Line(line) => write_line(&mut writer, line.0, line.1)?,
+            Curve::BSplineCurve(curve) => {
+                let control_points: Vec<_> =
+                    curve.control_points().iter().map(|pt| (*pt, 1.0)).collect();
+                write_spline(&mut writer, curve.knot_vec(), &control_points)?
+            }
+            Curve::NurbsCurve(curve) => {
+                let control_points: Vec<_> = curve
+                    .control_points()
+                    .iter()
+                    .map(|pt| (pt.to_point(), pt.w))
+                    .collect();
+                write_spline(&mut writer, curve.knot_vec(), &control_points)?
+            }
+            Curve::Polyline(polyline) => polyline
+                .windows(2)
+                .try_for_each(|p| write_line(&mut writer, p[0], p[1]))?,
+            curve => {
+                let range = curve.parameter_range();
+                let (_, points) = curve.parameter_division(range, POLYLINE_TOLERANCE);
+                points
+                    .windows(2)
+                    .try_for_each(|p| write_line(&mut writer, p[0], p[1]))?
+            }
+        }
+    }
+    write_group(&mut writer, 0, "ENDSEC")?;
+    write_group(&mut writer, 0, "EOF")?;
+    writer.flush()
+}
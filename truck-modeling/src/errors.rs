@@ -14,6 +14,42 @@ pub enum Error {
     /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to create a patch bounded by a wire that does not consist of four edges.
+    /// cf. [`builder::try_patch`](../builder/fn.try_patch.html)
+    #[error("The wire must consist of four edges to create a patch.")]
+    NotFourSidedWire,
+    /// tried to create a shape from a degenerate profile, e.g. coincident points or a zero radius.
+    /// cf. [`builder::try_circle_arc`](../builder/fn.try_circle_arc.html)
+    #[error("The profile degenerates to a point or a line.")]
+    DegenerateProfile,
+    /// tried to sweep a shape along the zero vector.
+    /// cf. [`builder::try_tsweep`](../builder/fn.try_tsweep.html)
+    #[error("The sweep vector must not be zero.")]
+    ZeroLengthSweepVector,
+    /// tried to revolve a shape around the zero axis.
+    /// cf. [`builder::try_rsweep`](../builder/fn.try_rsweep.html)
+    #[error("The axis must not be zero.")]
+    ZeroLengthAxis,
+    /// tried to draft a face which is not planar or bounded by the curves other than lines and
+    /// circle arcs.
+    /// cf. [`builder::try_tsweep_with_options`](../builder/fn.try_tsweep_with_options.html)
+    #[error("The drafted profile must be a planar face bounded by lines and circle arcs.")]
+    UnsupportedDraftProfile,
+    /// tried to sweep or interpolate the edges of intersection curves, which cannot be converted
+    /// to B-spline curves.
+    /// cf. [`builder::try_tsweep`](../builder/fn.try_tsweep.html)
+    #[error("The edges of intersection curves cannot be swept or interpolated.")]
+    UnsupportedIntersectionCurve,
+    /// tried to blend the profiles along the rails which do not connect the vertices of both
+    /// profiles at the same positions.
+    /// cf. [`builder::try_rail_blend`](../builder/fn.try_rail_blend.html)
+    #[error("The rails must connect the corresponding vertices of the profiles.")]
+    RailsNotOnProfiles,
+    /// tried to read the outlines of text from the invalid font data.
+    /// cf. [`text::text_wires`](../text/fn.text_wires.html)
+    #[cfg(feature = "text")]
+    #[error("The font data cannot be parsed.")]
+    InvalidFontData,
 }
 
 #[test]
@@ -31,6 +67,7 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::DegenerateProfile).unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
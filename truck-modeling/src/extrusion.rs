@@ -0,0 +1,232 @@
+use crate::builder::ExtrusionOptions;
+use crate::errors::Error;
+use crate::*;
+use std::f64::consts::PI;
+
+/// the maximum twist angle of a section of the twisted extrusions
+const MAX_SECTION_TWIST: f64 = PI / 8.0;
+/// the number of the sampling points on each edge checking circles and bounding profiles
+const SAMPLING_DIVISION: usize = 8;
+
+/// Returns the center and the radius of `curve` if it is a circle arc.
+fn circle_of(curve: &NurbsCurve<Vector4>) -> Option<(Point3, f64)> {
+    let (t0, t1) = curve.parameter_range();
+    let (p0, p1, p2) = (curve.subs(t0), curve.subs((t0 + t1) / 2.0), curve.subs(t1));
+    let (vec0, vec1) = (p1 - p0, p2 - p0);
+    let (a2, ab, b2) = (vec0.dot(vec0), vec0.dot(vec1), vec1.dot(vec1));
+    let det = a2 * b2 - ab * ab;
+    if det.so_small() {
+        return None;
+    }
+    let (u, v) = (a2 * b2 - ab * b2, a2 * b2 - ab * a2);
+    let center = p0 + u / (2.0 * det) * vec0 + v / (2.0 * det) * vec1;
+    let radius = p0.distance(center);
+    let normal = vec0.cross(vec1).normalize();
+    let on_circle = (0..=SAMPLING_DIVISION).all(|i| {
+        let pt = curve.subs(t0 + (t1 - t0) * i as f64 / SAMPLING_DIVISION as f64);
+        pt.distance(center).near(&radius) && (pt - center).dot(normal).so_small()
+    });
+    match on_circle {
+        true => Some((center, radius)),
+        false => None,
+    }
+}
+
+/// Returns the ruled surface between `curve0` and `curve1`.
+///
+/// The intersection curves have to be excluded by `builder::check_curves` before sweeping.
+pub(super) fn ruled_surface(curve0: &Curve, curve1: &Curve) -> Surface {
+    match (curve0, curve1) {
+        (Curve::Line(line0), Curve::Line(line1)) => {
+            let normal = (line0.1 - line0.0).cross(line1.0 - line0.0);
+            match (line1.1 - line0.0).dot(normal).so_small() {
+                true => Plane::new(line0.0, line0.1, line1.0).into(),
+                false => BSplineSurface::homotopy(line0.to_bspline(), line1.to_bspline()).into(),
+            }
+        }
+        (Curve::BSplineCurve(curve0), Curve::BSplineCurve(curve1)) => {
+            BSplineSurface::homotopy(curve0.clone(), curve1.clone()).into()
+        }
+        (Curve::NurbsCurve(curve0), Curve::NurbsCurve(curve1)) => {
+            NurbsSurface::new(BSplineSurface::homotopy(
+                curve0.non_rationalized().clone(),
+                curve1.non_rationalized().clone(),
+            ))
+            .into()
+        }
+        (Curve::Polyline(curve0), Curve::Polyline(curve1)) => {
+            let curve0 = geometry::polyline_to_bspline(curve0);
+            let curve1 = geometry::polyline_to_bspline(curve1);
+            BSplineSurface::homotopy(curve0, curve1).into()
+        }
+        (Curve::IntersectionCurve(_), _) | (_, Curve::IntersectionCurve(_)) => {
+            unreachable!("the intersection curves are excluded before sweeping")
+        }
+        _ => NurbsSurface::new(BSplineSurface::homotopy(
+            curve0.clone().lift_up(),
+            curve1.clone().lift_up(),
+        ))
+        .into(),
+    }
+}
+
+/// Returns the center of the bounding box of the outer boundary of `face`.
+fn profile_center(face: &Face) -> Point3 {
+    let bdb: BoundingBox<Point3> = face.boundaries()[0]
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.parameter_range();
+            let division = SAMPLING_DIVISION as f64;
+            (0..SAMPLING_DIVISION).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / division))
+        })
+        .collect();
+    bdb.center()
+}
+
+/// Returns the unit outward normals of the boundary of the face with the normal `normal` at
+/// the front and the back of `edge`, whose orientation is the one in the boundary.
+fn outward_normals(edge: &Edge, normal: Vector3) -> (Vector3, Vector3) {
+    let curve = edge.oriented_curve();
+    let (t0, t1) = curve.parameter_range();
+    let outward = |t: f64| curve.der(t).cross(normal).normalize();
+    (outward(t0), outward(t1))
+}
+
+/// The vertices of the sections of a drafted extrusion, paired with the ones of the next
+/// sections
+#[derive(Clone, Debug)]
+struct DraftTable(Vec<(Point3, Point3)>);
+
+impl DraftTable {
+    /// Creates the table of `division` sections moved by `step`, whose boundaries are offset
+    /// outward by `offset` in each section.
+    fn new(
+        face: &Face,
+        normal: Vector3,
+        offset: f64,
+        step: Matrix4,
+        division: usize,
+    ) -> Result<Self> {
+        let total = offset * division as f64;
+        let mut table = Vec::new();
+        for wire in face.boundaries() {
+            let normals = wire.edge_iter().map(|e| outward_normals(e, normal));
+            let normals: Vec<_> = normals.collect();
+            let len = normals.len();
+            let miters = (0..len)
+                .map(|i| {
+                    let (n0, n1) = (normals[(i + len - 1) % len].1, normals[i].0);
+                    let denom = 1.0 + n0.dot(n1);
+                    match denom.so_small() {
+                        true => Err(Error::DegenerateProfile),
+                        false => Ok((n0 + n1) / denom),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            for (i, edge) in wire.edge_iter().enumerate() {
+                let (p0, p1) = (edge.front().point(), edge.back().point());
+                let collapsed = match edge.curve() {
+                    Curve::Line(_) => {
+                        let q0 = p0 + miters[i] * total;
+                        let q1 = p1 + miters[(i + 1) % len] * total;
+                        (q1 - q0).dot(p1 - p0) < TOLERANCE
+                    }
+                    Curve::NurbsCurve(curve) => {
+                        let circle = circle_of(&curve);
+                        let (center, radius) = circle.ok_or(Error::UnsupportedDraftProfile)?;
+                        let sign = normals[i].0.dot(p0 - center).signum();
+                        radius + sign * total < TOLERANCE
+                    }
+                    _ => return Err(Error::UnsupportedDraftProfile),
+                };
+                if collapsed {
+                    return Err(Error::DegenerateProfile);
+                }
+                let mut matrix = Matrix4::identity();
+                (0..division).for_each(|k| {
+                    let next = step * matrix;
+                    let pt = matrix.transform_point(p0 + miters[i] * (offset * k as f64));
+                    let moved = next.transform_point(p0 + miters[i] * (offset * (k + 1) as f64));
+                    table.push((pt, moved));
+                    matrix = next;
+                });
+            }
+        }
+        Ok(Self(table))
+    }
+
+    /// Returns the vertex of the next section corresponding to `point`.
+    fn map(&self, point: Point3) -> Point3 {
+        let entry = self.0.iter().find(|(p, _)| p.near(&point));
+        entry.map(|(_, q)| *q).unwrap_or(point)
+    }
+
+    /// Returns the curve of the next section corresponding to `curve`.
+    fn map_curve(&self, curve: &Curve, step: Matrix4) -> Curve {
+        match curve {
+            Curve::Line(line) => Line(self.map(line.0), self.map(line.1)).into(),
+            Curve::NurbsCurve(arc) => {
+                let (center, radius) = circle_of(arc).unwrap();
+                let (t0, t1) = arc.parameter_range();
+                let (p0, p1) = (self.map(arc.subs(t0)), self.map(arc.subs(t1)));
+                let center = step.transform_point(center);
+                let transit = step.transform_point(arc.subs((t0 + t1) / 2.0));
+                let transit = center + (transit - center) * (p0.distance(center) / radius);
+                geom_impls::circle_arc_by_three_points(p0, p1, transit).into()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub(super) fn try_tsweep_with_options(
+    face: &Face,
+    vector: Vector3,
+    options: ExtrusionOptions,
+) -> Result<Solid> {
+    if vector.so_small() {
+        return Err(Error::ZeroLengthSweepVector);
+    }
+    builder::check_curves(face)?;
+    let length = vector.magnitude();
+    let twist = options.twist_per_length.map_or(0.0, |a| a.0 * length);
+    let division = usize::max((twist.abs() / MAX_SECTION_TWIST).ceil() as usize, 1);
+    let center = profile_center(face).to_vec();
+    let step = Matrix4::from_translation(vector / division as f64 + center)
+        * Matrix4::from_axis_angle(vector / length, Rad(twist / division as f64))
+        * Matrix4::from_translation(-center);
+    let connect_points = |pt0: &Point3, pt1: &Point3| Curve::Line(Line(*pt0, *pt1));
+    let surface_mapping = |surface: &Surface| surface.transformed(step);
+    let solid = match options.draft {
+        Some(draft) if !draft.0.so_small() => {
+            if !matches!(face.surface(), Surface::Plane(_)) || draft.0.abs() >= PI / 2.0 {
+                return Err(Error::UnsupportedDraftProfile);
+            }
+            let normal = face.oriented_surface().normal(0.0, 0.0);
+            let height = vector.dot(normal).abs();
+            if height.so_small() {
+                return Err(Error::DegenerateProfile);
+            }
+            let offset = height * draft.0.tan() / division as f64;
+            let table = DraftTable::new(face, normal, offset, step, division)?;
+            face.multi_sweep(
+                &|pt: &Point3| table.map(*pt),
+                &|curve: &Curve| table.map_curve(curve, step),
+                &surface_mapping,
+                &connect_points,
+                &ruled_surface,
+                division,
+            )
+        }
+        _ => face.multi_sweep(
+            &|pt: &Point3| step.transform_point(*pt),
+            &|curve: &Curve| curve.transformed(step),
+            &surface_mapping,
+            &connect_points,
+            &ruled_surface,
+            division,
+        ),
+    };
+    Ok(solid)
+}
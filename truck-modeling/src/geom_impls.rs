@@ -55,6 +55,36 @@ pub(super) fn circle_arc(
     unit_curve
 }
 
+/// Returns the exact NURBS curve of `arc` divided into the arcs of at most a quarter circle.
+pub(super) fn circle_arc_to_nurbs(arc: &CircleArc) -> NurbsCurve<Vector4> {
+    let (t0, t1) = arc.entity().parameter_range();
+    let n = f64::max(f64::ceil((t1 - t0) / (PI / 2.0) - TOLERANCE), 1.0) as usize;
+    let delta = (t1 - t0) / n as f64;
+    let knots = (0..=n)
+        .flat_map(|i| match i {
+            0 => vec![0.0; 3],
+            _ if i == n => vec![1.0; 3],
+            _ => vec![i as f64 / n as f64; 2],
+        })
+        .collect::<Vec<_>>();
+    let ctrl_pts = (0..=2 * n)
+        .map(|i| {
+            let t = t0 + delta * i as f64 / 2.0;
+            let (cos, sin) = (f64::cos(t), f64::sin(t));
+            match i % 2 {
+                0 => Vector4::new(cos, sin, 0.0, 1.0),
+                _ => Vector4::new(cos, sin, 0.0, f64::cos(delta / 2.0)),
+            }
+        })
+        .collect();
+    let mut curve = NurbsCurve::new(BSplineCurve::new(KnotVec::from(knots), ctrl_pts));
+    curve.transform_by(*arc.transform());
+    if !arc.orientation() {
+        curve.invert();
+    }
+    curve
+}
+
 fn closed_polyline_orientation<'a>(pts: impl IntoIterator<Item = &'a Vec<Point3>>) -> bool {
     pts.into_iter()
         .flat_map(|vec| vec.windows(2))
@@ -63,7 +93,7 @@ fn closed_polyline_orientation<'a>(pts: impl IntoIterator<Item = &'a Vec<Point3>
         >= 0.0
 }
 
-fn take_one_axis_by_normal(n: Vector3) -> Vector3 {
+pub(super) fn take_one_axis_by_normal(n: Vector3) -> Vector3 {
     let a = n.map(f64::abs);
     if a.x > a.z || a.y > a.z {
         Vector3::new(-n.y, n.x, 0.0).normalize()
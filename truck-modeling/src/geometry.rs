@@ -54,6 +54,8 @@ pub enum Curve {
     NurbsCurve(NurbsCurve<Vector4>),
     /// intersection curve
     IntersectionCurve(IntersectionCurve<Leader, Surface>),
+    /// polyline curve, e.g. the edges derived from meshes or scanned data
+    Polyline(PolylineCurve<Point3>),
 }
 
 macro_rules! derive_curve_method {
@@ -63,6 +65,7 @@ macro_rules! derive_curve_method {
             Curve::BSplineCurve(got) => $method(got, $($ver), *),
             Curve::NurbsCurve(got) => $method(got, $($ver), *),
             Curve::IntersectionCurve(got) => $method(got, $($ver), *),
+            Curve::Polyline(got) => $method(got, $($ver), *),
         }
     };
 }
@@ -74,6 +77,7 @@ macro_rules! derive_curve_self_method {
             Curve::BSplineCurve(got) => Curve::BSplineCurve($method(got, $($ver), *)),
             Curve::NurbsCurve(got) => Curve::NurbsCurve($method(got, $($ver), *)),
             Curve::IntersectionCurve(got) => Curve::IntersectionCurve($method(got, $($ver), *)),
+            Curve::Polyline(got) => Curve::Polyline($method(got, $($ver), *)),
         }
     };
 }
@@ -108,11 +112,22 @@ impl From<IntersectionCurve<PolylineCurve<Point3>, Surface>> for Curve {
     }
 }
 
+/// Returns the B-spline curve of degree one with the same parametrization as `polyline`.
+pub(crate) fn polyline_to_bspline(polyline: &PolylineCurve<Point3>) -> BSplineCurve<Point3> {
+    let n = polyline.len();
+    let knots = std::iter::once(0.0)
+        .chain((0..n).map(|i| i as f64))
+        .chain(std::iter::once(n as f64 - 1.0))
+        .collect::<Vec<_>>();
+    BSplineCurve::new(KnotVec::from(knots), polyline.0.clone())
+}
+
 impl Curve {
     /// Into non-ratinalized 4-dimensinal B-spline curve
     pub fn lift_up(self) -> BSplineCurve<Vector4> {
         match self {
             Curve::Line(curve) => Curve::BSplineCurve(curve.to_bspline()).lift_up(),
+            Curve::Polyline(curve) => Curve::BSplineCurve(polyline_to_bspline(&curve)).lift_up(),
             Curve::BSplineCurve(curve) => BSplineCurve::new(
                 curve.knot_vec().clone(),
                 curve
@@ -127,6 +142,56 @@ impl Curve {
             }
         }
     }
+    /// Divides the curve at the knots into the lines, the circle arcs and the free curves
+    /// recognized within `tol` by [`recognize_spans`], e.g. in order to clean up the imported
+    /// curves. The arcs are converted into the exact NURBS curves, and the free curves are cut
+    /// out of the curve. The intersection curves are not divided.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// // the polyline turning at (2, 0, 0)
+    /// let ctrl_pts = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 1.0, 0.0),
+    /// ];
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 1.0, 2.0, 3.0, 3.0]);
+    /// let curve = Curve::BSplineCurve(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let spans = curve.analytic_spans(1.0e-6);
+    /// assert_eq!(spans.len(), 2);
+    /// assert!(matches!(&spans[0], Curve::Line(line) if line.1.near(&Point3::new(2.0, 0.0, 0.0))));
+    /// assert!(matches!(&spans[1], Curve::Line(line) if line.1.near(&Point3::new(2.0, 1.0, 0.0))));
+    /// ```
+    pub fn analytic_spans(&self, tol: f64) -> Vec<Curve> {
+        let breaks = match self {
+            Curve::Line(_) | Curve::IntersectionCurve(_) => return vec![self.clone()],
+            Curve::BSplineCurve(curve) => curve.knot_vec().to_single_multi().0,
+            Curve::NurbsCurve(curve) => curve.knot_vec().to_single_multi().0,
+            Curve::Polyline(curve) => (0..curve.len()).map(|i| i as f64).collect(),
+        };
+        let (t0, t1) = self.parameter_range();
+        let cut_out = |(s0, s1): (f64, f64)| {
+            let mut curve = self.clone();
+            if s0 > t0 {
+                curve = curve.cut(s0);
+            }
+            if s1 < t1 {
+                curve.cut(s1);
+            }
+            curve
+        };
+        recognize_spans(self, &breaks, tol)
+            .into_iter()
+            .map(|(range, span)| match span {
+                Some(AnalyticSpan::Line(line)) => Curve::Line(line),
+                Some(AnalyticSpan::CircleArc(arc)) => {
+                    Curve::NurbsCurve(geom_impls::circle_arc_to_nurbs(&arc))
+                }
+                None => cut_out(range),
+            })
+            .collect()
+    }
     /// Make the leaders of `IntersectionCurve`s B-spline curves.
     pub fn to_bspline_leader(&mut self, p_tol: f64, d_tol: f64, trials: usize) -> bool {
         if let Curve::IntersectionCurve(ref mut curve) = self {
@@ -200,6 +265,18 @@ impl ParametricSurface3D for Surface {
     fn normal(&self, u: f64, v: f64) -> Vector3 {
         derive_surface_method!(self, ParametricSurface3D::normal, u, v)
     }
+    #[inline(always)]
+    fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        derive_surface_method!(self, ParametricSurface3D::first_fundamental_form, u, v)
+    }
+    #[inline(always)]
+    fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        derive_surface_method!(self, ParametricSurface3D::second_fundamental_form, u, v)
+    }
+    #[inline(always)]
+    fn shape_operator(&self, u: f64, v: f64) -> Option<Matrix2> {
+        derive_surface_method!(self, ParametricSurface3D::shape_operator, u, v)
+    }
 }
 
 impl Transformed<Matrix4> for Surface {
@@ -219,22 +296,28 @@ impl IncludeCurve<Curve> for Surface {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
+                Curve::Polyline(curve) => surface.include(&polyline_to_bspline(curve)),
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
             Surface::NurbsSurface(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
+                Curve::Polyline(curve) => surface.include(&polyline_to_bspline(curve)),
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
             Surface::Plane(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
+                Curve::Polyline(curve) => surface.include(&polyline_to_bspline(curve)),
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
             Surface::RevolutedCurve(surface) => match surface.entity_curve() {
                 Curve::Line(curve) => self.include(&Curve::BSplineCurve(curve.to_bspline())),
+                Curve::Polyline(curve) => {
+                    self.include(&Curve::BSplineCurve(polyline_to_bspline(curve)))
+                }
                 Curve::BSplineCurve(entity_curve) => {
                     let surface = RevolutedCurve::by_revolution(
                         entity_curve,
@@ -245,6 +328,7 @@ impl IncludeCurve<Curve> for Surface {
                         Curve::Line(curve) => surface.include(&curve.to_bspline()),
                         Curve::BSplineCurve(curve) => surface.include(curve),
                         Curve::NurbsCurve(curve) => surface.include(curve),
+                        Curve::Polyline(curve) => surface.include(&polyline_to_bspline(curve)),
                         Curve::IntersectionCurve(_) => unimplemented!(),
                     }
                 }
@@ -258,6 +342,7 @@ impl IncludeCurve<Curve> for Surface {
                         Curve::Line(curve) => surface.include(&curve.to_bspline()),
                         Curve::BSplineCurve(curve) => surface.include(curve),
                         Curve::NurbsCurve(curve) => surface.include(curve),
+                        Curve::Polyline(curve) => surface.include(&polyline_to_bspline(curve)),
                         Curve::IntersectionCurve(_) => unimplemented!(),
                     }
                 }
@@ -55,10 +55,11 @@ pub mod topology {
 }
 pub use topology::*;
 
-/// topological utility: [`Mapped`], [`Sweep`], and [`ClosedSweep`].
+/// topological utility: [`Mapped`], [`Sweep`], [`SectionSweep`], and [`ClosedSweep`].
 ///
 /// [`Mapped`]: ./topo_traits/trait.Mapped.html
 /// [`Sweep`]: ./topo_traits/trait.Sweep.html
+/// [`SectionSweep`]: ./topo_traits/trait.SectionSweep.html
 /// [`ClosedSweep`]: ./topo_traits/trait.ClosedSweep.html
 pub mod topo_traits {
     /// Mapping, duplicates and moves a topological element.
@@ -127,6 +128,29 @@ pub mod topo_traits {
         ) -> Self::Swept;
     }
 
+    /// Sweeping through the sections given by the index, builds a tapered pipe, and so on.
+    pub trait SectionSweep<P, C, S> {
+        /// The struct of sweeped topology.
+        type Swept;
+        /// Maps the topology to the sections `1..=division` by the mappings with the indices of
+        /// the sections, and connects the vertices and the edges of the adjacent sections.
+        fn section_sweep<
+            FP: Fn(usize, &P) -> P,
+            FC: Fn(usize, &C) -> C,
+            FS: Fn(usize, &S) -> S,
+            CP: Fn(&P, &P) -> C,
+            CE: Fn(&C, &C) -> S,
+        >(
+            &self,
+            point_mapping: &FP,
+            curve_mapping: &FC,
+            surface_mapping: &FS,
+            connect_points: &CP,
+            connect_curves: &CE,
+            division: usize,
+        ) -> Self::Swept;
+    }
+
     /// closed sweep, builds a closed torus, and so on.
     pub trait ClosedSweep<P, C, S>: MultiSweep<P, C, S> {
         /// Transform topologies and connect vertices and edges in boundaries.
@@ -155,10 +179,25 @@ pub type Result<T> = std::result::Result<T, errors::Error>;
 /// the building model utility API
 pub mod builder;
 mod closed_sweep;
+/// continuity evaluation between adjacent faces
+pub mod continuity;
+/// procedurally generated models for stress tests and benchmarks: gears, turbine blades, and
+/// lattice brackets, whose numbers of faces increase with the parameters
+pub mod datasets;
+/// DXF input and output of wires
+pub mod dxf;
 /// declare errors
 pub mod errors;
+mod extrusion;
 mod geom_impls;
 mod mapped;
 mod multi_sweep;
+/// planarity, area and winding direction of wires
+pub mod planarity;
+mod rail_blend;
+mod section_sweep;
 mod sweep;
+/// planar wires and faces of the outlines of text
+#[cfg(feature = "text")]
+pub mod text;
 mod topo_impls;
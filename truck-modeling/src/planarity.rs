@@ -0,0 +1,190 @@
+use crate::*;
+
+const SAMPLING_DIVISION: usize = 16;
+const INTEGRATION_DIVISION: usize = 16;
+
+/// the nodes and the weights of the three-point Gauss-Legendre quadrature on `[0, 1]`
+const GAUSS_LEGENDRE: [(f64, f64); 3] = [
+    (0.1127016653792583, 5.0 / 18.0),
+    (0.5, 8.0 / 18.0),
+    (0.8872983346207417, 5.0 / 18.0),
+];
+
+/// The winding direction of a closed wire seen from the side of a normal vector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Winding {
+    /// counterclockwise, the wire turns positively around the normal
+    CounterClockwise,
+    /// clockwise, the wire turns negatively around the normal
+    Clockwise,
+}
+
+/// Returns whether all the edges of `wire` are in one plane.
+///
+/// The wire has to determine the plane uniquely, so the empty wires and the wires consisting of
+/// collinear edges are not planar.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// assert!(planarity::is_planar(&circle));
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+/// let skew: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v3),
+/// ]
+/// .into();
+/// assert!(!planarity::is_planar(&skew));
+/// assert!(planarity::is_planar(&Wire::from(vec![skew[0].clone(), skew[1].clone()])));
+/// assert!(!planarity::is_planar(&Wire::from(vec![skew[0].clone()])));
+/// ```
+#[inline(always)]
+pub fn is_planar(wire: &Wire) -> bool { plane_of(wire).is_some() }
+
+/// Returns the plane including all the edges of `wire`.
+///
+/// The origin of the plane is the center of the sampling points on the edges, and the wire
+/// winds counterclockwise around the normal of the plane. Returns `None` if the wire is not
+/// planar in the sense of [`is_planar`]. The wire does not have to be closed.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// let plane = planarity::plane_of(&circle).unwrap();
+/// assert_near!(plane.origin(), Point3::origin());
+/// assert_near!(plane.normal(), Vector3::unit_z());
+///
+/// // the plane is reversed by the inversed wire.
+/// let plane = planarity::plane_of(&circle.inverse()).unwrap();
+/// assert_near!(plane.normal(), -Vector3::unit_z());
+/// ```
+pub fn plane_of(wire: &Wire) -> Option<Plane> {
+    if wire.is_empty() {
+        return None;
+    }
+    let pts = sampling_points(wire);
+    let center = pts
+        .iter()
+        .fold(Point3::origin(), |sum, pt| sum + pt.to_vec())
+        / pts.len() as f64;
+    // the normal by Newell's method, for the polygon closed by the last segment
+    let normal = pts
+        .iter()
+        .zip(pts.iter().cycle().skip(1))
+        .fold(Vector3::zero(), |sum, (p, q)| {
+            sum + (*p - center).cross(*q - center)
+        });
+    let n = match normal.so_small() {
+        true => return None,
+        false => normal.normalize(),
+    };
+    if !pts.iter().all(|pt| (*pt - center).dot(n).so_small()) {
+        return None;
+    }
+    let a = geom_impls::take_one_axis_by_normal(n);
+    Some(Plane::new(center, center + a, center + n.cross(a)))
+}
+
+/// Returns the area enclosed by the planar and closed `wire`.
+///
+/// The area is evaluated by the line integral along the edges, so the regions enclosed by the
+/// wire winding clockwise are subtracted. Returns `None` if the wire is not closed or not
+/// planar.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let v = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// assert_near!(planarity::area(&circle).unwrap(), 4.0 * PI);
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(3.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 0.0, 2.0));
+/// let triangle: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v0),
+/// ]
+/// .into();
+/// assert_near!(planarity::area(&triangle).unwrap(), 3.0);
+/// assert!(planarity::area(&Wire::from(vec![triangle[0].clone()])).is_none());
+/// ```
+pub fn area(wire: &Wire) -> Option<f64> {
+    match wire.is_closed() && is_planar(wire) {
+        true => Some(area_vector(wire).magnitude()),
+        false => None,
+    }
+}
+
+/// Returns the winding direction of the planar and closed `wire` seen from the side of
+/// `normal`.
+///
+/// Returns `None` if the wire is not closed or not planar, or if `normal` is parallel to the
+/// plane of the wire.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use planarity::Winding;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// let normal = Vector3::unit_z();
+/// assert_eq!(planarity::winding(&circle, normal), Some(Winding::CounterClockwise));
+/// assert_eq!(planarity::winding(&circle.inverse(), normal), Some(Winding::Clockwise));
+/// assert_eq!(planarity::winding(&circle, Vector3::unit_x()), None);
+/// ```
+pub fn winding(wire: &Wire, normal: Vector3) -> Option<Winding> {
+    if !wire.is_closed() || !is_planar(wire) {
+        return None;
+    }
+    let dot = area_vector(wire).dot(normal);
+    if dot.so_small() {
+        None
+    } else if dot > 0.0 {
+        Some(Winding::CounterClockwise)
+    } else {
+        Some(Winding::Clockwise)
+    }
+}
+
+/// the points on the edges, including both ends of the wire
+fn sampling_points(wire: &Wire) -> Vec<Point3> {
+    wire.edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.parameter_range();
+            (0..SAMPLING_DIVISION).map(move |i| {
+                let t = t0 + (t1 - t0) * (i as f64) / (SAMPLING_DIVISION as f64);
+                curve.subs(t)
+            })
+        })
+        .chain(wire.back_vertex().map(|v| v.point()))
+        .collect()
+}
+
+/// the vector `1/2 \oint (p - p_0) \times dp` of the closed wire, whose magnitude is the area
+fn area_vector(wire: &Wire) -> Vector3 {
+    let origin = match wire.front_vertex() {
+        Some(v) => v.point(),
+        None => return Vector3::zero(),
+    };
+    let sum = wire.edge_iter().fold(Vector3::zero(), |sum, edge| {
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.parameter_range();
+        let h = (t1 - t0) / INTEGRATION_DIVISION as f64;
+        (0..INTEGRATION_DIVISION)
+            .flat_map(|i| GAUSS_LEGENDRE.iter().map(move |&(x, w)| (i as f64 + x, w)))
+            .fold(sum, |sum, (s, w)| {
+                let t = t0 + h * s;
+                sum + (curve.subs(t) - origin).cross(curve.der(t)) * (w * h)
+            })
+    });
+    sum / 2.0
+}
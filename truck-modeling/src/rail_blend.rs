@@ -0,0 +1,170 @@
+use crate::errors::Error;
+use crate::*;
+
+/// the tolerance of the cubic approximations of the rational profiles
+const APPROXIMATION_TOLERANCE: f64 = 1.0e-4;
+/// the maximum depth of the subdivisions of the cubic approximations
+const APPROXIMATION_TRIALS: usize = 16;
+
+/// Returns the B-spline curve of `curve`. The circle arcs and the other curves which are not
+/// B-spline curves are approximated by cubic B-spline curves.
+fn bspline_of(curve: &Curve) -> Result<BSplineCurve<Point3>> {
+    match curve {
+        Curve::Line(line) => Ok(line.to_bspline()),
+        Curve::BSplineCurve(curve) => Ok(curve.clone()),
+        Curve::Polyline(curve) => Ok(geometry::polyline_to_bspline(curve)),
+        _ => BSplineCurve::cubic_approximation(
+            curve,
+            curve.parameter_range(),
+            APPROXIMATION_TOLERANCE,
+            APPROXIMATION_TOLERANCE,
+            APPROXIMATION_TRIALS,
+        )
+        .ok_or(Error::DegenerateProfile),
+    }
+}
+
+/// Returns the C^1 cubic B-spline curve passing `points[k]` at the parameter `k / n`, where
+/// `n + 1` is the number of the points. The tangents are the ones of the Catmull-Rom spline.
+fn interpolating_curve(points: &[Point3]) -> BSplineCurve<Point3> {
+    let n = points.len() - 1;
+    let tangent = |k: usize| {
+        let (k0, k1) = (k.saturating_sub(1), usize::min(k + 1, n));
+        (points[k1] - points[k0]) / (k1 - k0) as f64
+    };
+    let mut knots = vec![0.0; 4];
+    let mut control_points = vec![points[0]];
+    (0..n).for_each(|k| {
+        control_points.push(points[k] + tangent(k) / 3.0);
+        control_points.push(points[k + 1] - tangent(k + 1) / 3.0);
+        control_points.push(points[k + 1]);
+        knots.extend([(k + 1) as f64 / n as f64; 3]);
+    });
+    knots.push(1.0);
+    BSplineCurve::new(KnotVec::from(knots), control_points)
+}
+
+/// Returns the frame of the rails at the ratio `t` of their parameter ranges. The origin is on
+/// the first rail, the first axis is the chord to the second rail, and the second axis is the
+/// sum of the derivations of the rails orthogonalized to the chord. All axes have the length of
+/// the chord.
+fn rail_frame(rails: &[Curve; 2], t: f64) -> Result<Matrix4> {
+    let parameter = |rail: &Curve| {
+        let (t0, t1) = rail.parameter_range();
+        t0 + (t1 - t0) * t
+    };
+    let (t0, t1) = (parameter(&rails[0]), parameter(&rails[1]));
+    let origin = rails[0].subs(t0);
+    let chord = rails[1].subs(t1) - origin;
+    let length = chord.magnitude();
+    let tangent = rails[0].der(t0) + rails[1].der(t1);
+    let tangent = tangent - chord * (tangent.dot(chord) / (length * length));
+    if length.so_small() || tangent.so_small() {
+        return Err(Error::DegenerateProfile);
+    }
+    let axis1 = tangent.normalize() * length;
+    let axis2 = chord.cross(axis1) / length;
+    let (axis0, axis1, axis2) = (chord.extend(0.0), axis1.extend(0.0), axis2.extend(0.0));
+    let origin = origin.to_homogeneous();
+    Ok(Matrix4::from_cols(axis0, axis1, axis2, origin))
+}
+
+/// Returns the points of the vertices of `wire`: the front vertices of the edges, and the back
+/// vertex of the last edge.
+fn vertex_points(wire: &Wire) -> Vec<Point3> {
+    let fronts = wire.edge_iter().map(|edge| edge.front().point());
+    let back = wire.back_vertex().map(Vertex::point);
+    fronts.chain(back).collect()
+}
+
+pub(super) fn try_rail_blend(
+    profile0: &Wire,
+    profile1: &Wire,
+    rails: [&Edge; 2],
+    division: usize,
+) -> Result<Shell> {
+    if profile0.len() != profile1.len() || profile0.is_closed() != profile1.is_closed() {
+        return Err(Error::NotSameNumberOfEdges);
+    }
+    let division = usize::max(division, 1);
+    let rails = rails.map(Edge::oriented_curve);
+    let (points0, points1) = (vertex_points(profile0), vertex_points(profile1));
+    let rail_index = |rail: &Curve| {
+        let index = |points: &[Point3], pt: Point3| points.iter().position(|p| p.near(&pt));
+        match (index(&points0, rail.front()), index(&points1, rail.back())) {
+            (Some(i), Some(j)) if i == j => Ok(i),
+            _ => Err(Error::RailsNotOnProfiles),
+        }
+    };
+    let (index0, index1) = (rail_index(&rails[0])?, rail_index(&rails[1])?);
+    if points0[index0].near(&points0[index1]) {
+        return Err(Error::RailsNotOnProfiles);
+    }
+    let frames = (0..=division)
+        .map(|k| rail_frame(&rails, k as f64 / division as f64))
+        .collect::<Result<Vec<_>>>()?;
+    let inverse0 = frames[0].invert().unwrap();
+    let inverse1 = frames[division].invert().unwrap();
+
+    // the sections of the edges in the frames of the rails
+    let sections = profile0
+        .edge_iter()
+        .zip(profile1.edge_iter())
+        .map(|(edge0, edge1)| {
+            let mut curve0 = bspline_of(&edge0.oriented_curve())?.transformed(inverse0);
+            let mut curve1 = bspline_of(&edge1.oriented_curve())?.transformed(inverse1);
+            curve0.knot_normalize();
+            curve1.knot_normalize();
+            curve0.syncro_degree(&mut curve1);
+            curve0.syncro_knots(&mut curve1);
+            let sections = frames.iter().enumerate().map(|(k, frame)| {
+                let s = k as f64 / division as f64;
+                let pairs = curve0.control_points().iter().zip(curve1.control_points());
+                let blended = pairs.map(|(p, q)| *p + (*q - *p) * s);
+                let curve = BSplineCurve::new(curve0.knot_vec().clone(), blended.collect());
+                curve.transformed(*frame)
+            });
+            Ok(sections.collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let len = sections.len();
+    let count = match profile0.is_closed() {
+        true => len,
+        false => len + 1,
+    };
+    let trajectories: Vec<BSplineCurve<Point3>> = (0..count)
+        .map(|i| {
+            let points: Vec<Point3> = match i < len {
+                true => sections[i].iter().map(BSplineCurve::front).collect(),
+                false => sections[len - 1].iter().map(BSplineCurve::back).collect(),
+            };
+            interpolating_curve(&points)
+        })
+        .collect();
+    let vertices = |end: fn(&BSplineCurve<Point3>) -> Point3| -> Vec<Vertex> {
+        let points = trajectories.iter().map(end);
+        points.map(Vertex::new).collect()
+    };
+    let (bottoms, tops) = (vertices(BSplineCurve::front), vertices(BSplineCurve::back));
+    let sides: Vec<Edge> = (0..count)
+        .map(|i| Edge::new(&bottoms[i], &tops[i], trajectories[i].clone().into()))
+        .collect();
+    let shell = sections
+        .into_iter()
+        .enumerate()
+        .map(|(i, curves)| {
+            let (i0, i1) = (i, (i + 1) % count);
+            let bottom = Edge::new(&bottoms[i0], &bottoms[i1], curves[0].clone().into());
+            let top = Edge::new(&tops[i0], &tops[i1], curves[division].clone().into());
+            let ends = curves.iter().map(|curve| vec![curve.front(), curve.back()]);
+            let points: Vec<Vec<Point3>> = ends.collect();
+            let rails = vec![trajectories[i0].clone(), trajectories[i1].clone()];
+            let surface = BSplineSurface::gordon(curves, rails, &points);
+            let (side0, side1) = (sides[i0].inverse(), sides[i1].clone());
+            let wire = Wire::from(vec![bottom, side1, top.inverse(), side0]);
+            Face::debug_new(vec![wire], surface.into())
+        })
+        .collect();
+    Ok(shell)
+}
@@ -0,0 +1,130 @@
+use crate::topo_impls::*;
+use crate::topo_traits::*;
+use truck_topology::*;
+
+impl<P: Clone, C: Clone, S: Clone> SectionSweep<P, C, S> for Vertex<P> {
+    type Swept = Wire<P, C>;
+    fn section_sweep<
+        FP: Fn(usize, &P) -> P,
+        FC: Fn(usize, &C) -> C,
+        FS: Fn(usize, &S) -> S,
+        CP: Fn(&P, &P) -> C,
+        CE: Fn(&C, &C) -> S,
+    >(
+        &self,
+        point_mapping: &FP,
+        _: &FC,
+        _: &FS,
+        connect_points: &CP,
+        _: &CE,
+        division: usize,
+    ) -> Self::Swept {
+        let mut vertex = self.clone();
+        (1..=division)
+            .map(move |k| {
+                let new_vertex = self.mapped(&|pt: &P| point_mapping(k, pt));
+                let edge = connect_vertices(&vertex, &new_vertex, connect_points);
+                vertex = new_vertex;
+                edge
+            })
+            .collect()
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> SectionSweep<P, C, S> for Edge<P, C> {
+    type Swept = Shell<P, C, S>;
+    fn section_sweep<
+        FP: Fn(usize, &P) -> P,
+        FC: Fn(usize, &C) -> C,
+        FS: Fn(usize, &S) -> S,
+        CP: Fn(&P, &P) -> C,
+        CE: Fn(&C, &C) -> S,
+    >(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        _: &FS,
+        connect_points: &CP,
+        connect_curves: &CE,
+        division: usize,
+    ) -> Self::Swept {
+        let mut edge = self.clone();
+        (1..=division)
+            .map(move |k| {
+                let curve_mapping = |curve: &C| curve_mapping(k, curve);
+                let new_edge = self.mapped(&|pt: &P| point_mapping(k, pt), &curve_mapping);
+                let face = connect_edges(&edge, &new_edge, connect_points, connect_curves);
+                edge = new_edge;
+                face
+            })
+            .collect()
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> SectionSweep<P, C, S> for Wire<P, C> {
+    type Swept = Shell<P, C, S>;
+    fn section_sweep<
+        FP: Fn(usize, &P) -> P,
+        FC: Fn(usize, &C) -> C,
+        FS: Fn(usize, &S) -> S,
+        CP: Fn(&P, &P) -> C,
+        CE: Fn(&C, &C) -> S,
+    >(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        _: &FS,
+        connect_points: &CP,
+        connect_curves: &CE,
+        division: usize,
+    ) -> Self::Swept {
+        let mut wire = self.clone();
+        (1..=division)
+            .flat_map(move |k| {
+                let curve_mapping = |curve: &C| curve_mapping(k, curve);
+                let new_wire = self.mapped(&|pt: &P| point_mapping(k, pt), &curve_mapping);
+                let shell: Vec<_> =
+                    connect_wires(&wire, &new_wire, connect_points, connect_curves).collect();
+                wire = new_wire;
+                shell
+            })
+            .collect()
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> SectionSweep<P, C, S> for Face<P, C, S> {
+    type Swept = Solid<P, C, S>;
+    fn section_sweep<
+        FP: Fn(usize, &P) -> P,
+        FC: Fn(usize, &C) -> C,
+        FS: Fn(usize, &S) -> S,
+        CP: Fn(&P, &P) -> C,
+        CE: Fn(&C, &C) -> S,
+    >(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+        connect_points: &CP,
+        connect_curves: &CE,
+        division: usize,
+    ) -> Self::Swept {
+        let mut shell = Shell::from(vec![self.inverse()]);
+        let mut face_cursor = self.clone();
+        shell.extend((1..=division).flat_map(|k| {
+            let seiling = self.mapped(
+                &|pt: &P| point_mapping(k, pt),
+                &|curve: &C| curve_mapping(k, curve),
+                &|surface: &S| surface_mapping(k, surface),
+            );
+            let biter0 = face_cursor.boundary_iters().into_iter().flatten();
+            let biter1 = seiling.boundary_iters().into_iter().flatten();
+            let vec: Vec<_> =
+                connect_raw_wires(biter0, biter1, connect_points, connect_curves).collect();
+            face_cursor = seiling;
+            vec
+        }));
+        shell.push(face_cursor);
+        Solid::debug_new(vec![shell])
+    }
+}
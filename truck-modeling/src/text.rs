@@ -0,0 +1,245 @@
+use crate::*;
+use errors::Error;
+use ttf_parser::OutlineBuilder;
+
+const SAMPLING_DIVISION: usize = 8;
+
+/// Returns the closed wires of the outlines of `text` in the font.
+///
+/// `font` is the data of a TrueType or an OpenType font, and `size` is the height of the em
+/// square. The wires are on the xy-plane, and the baseline of the first line is the x-axis from
+/// the origin. The lines are separated by `'\n'`. The segments of the outlines are lines and the
+/// Bézier curves of degree two or three.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// // the test font of the glyphs 'I', 'O' and 'o'
+/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fonts/test-glyphs.ttf");
+/// let font = std::fs::read(path).unwrap();
+/// let wires = text::text_wires(&font, "IOo", 10.0).unwrap();
+/// assert_eq!(wires.len(), 4);
+/// assert!(wires.iter().all(|wire| wire.is_closed()));
+/// ```
+/// # Failures
+/// Returns `Error::InvalidFontData` if `font` cannot be parsed.
+pub fn text_wires(font: &[u8], text: &str, size: f64) -> Result<Vec<Wire>> {
+    Ok(glyph_outlines(font, text, size)?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// Returns the planar faces of the outlines of `text` in the font.
+///
+/// The contours of each glyph are classified by their nesting, the outer contours are the outer
+/// boundaries and the contours directly inside them are the holes. The normals of the faces are
+/// `Vector3::unit_z()`, so embossed text is created by sweeping the faces along the z-axis.
+/// The arguments are the same as [`text_wires`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fonts/test-glyphs.ttf");
+/// let font = std::fs::read(path).unwrap();
+/// let solids: Vec<Solid> = text::text_faces(&font, "IOo", 10.0)
+///     .unwrap()
+///     .iter()
+///     .map(|face| builder::tsweep(face, Vector3::new(0.0, 0.0, 1.0)))
+///     .collect();
+/// // the hole of 'O' is the inner boundary
+/// assert_eq!(solids.len(), 3);
+/// assert_eq!(solids[1].boundaries()[0].len(), 10);
+/// ```
+/// # Failures
+/// Returns `Error::InvalidFontData` if `font` cannot be parsed.
+pub fn text_faces(font: &[u8], text: &str, size: f64) -> Result<Vec<Face>> {
+    Ok(glyph_outlines(font, text, size)?
+        .into_iter()
+        .flat_map(glyph_faces)
+        .collect())
+}
+
+/// the contours of each glyph
+fn glyph_outlines(font: &[u8], text: &str, size: f64) -> Result<Vec<Vec<Wire>>> {
+    let face = match ttf_parser::Face::parse(font, 0) {
+        Ok(got) => got,
+        Err(_) => return Err(Error::InvalidFontData),
+    };
+    let scale = size / face.units_per_em() as f64;
+    let (ascender, descender) = (face.ascender() as f64, face.descender() as f64);
+    let line_height = (ascender - descender + face.line_gap() as f64) * scale;
+    let mut origin = Point3::origin();
+    let mut res = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        origin.x = 0.0;
+        origin.y = -line_height * i as f64;
+        for c in line.chars() {
+            let id = face.glyph_index(c).unwrap_or(ttf_parser::GlyphId(0));
+            let mut builder = ContourBuilder::new(origin, scale);
+            face.outline_glyph(id, &mut builder);
+            res.push(builder.contours);
+            origin.x += face.glyph_hor_advance(id).unwrap_or(0) as f64 * scale;
+        }
+    }
+    Ok(res)
+}
+
+/// collects the contours of a glyph by the callbacks of `ttf_parser`
+struct ContourBuilder {
+    origin: Point3,
+    scale: f64,
+    contours: Vec<Wire>,
+    current: Wire,
+    first: Option<Vertex>,
+    last: Option<Vertex>,
+}
+
+impl ContourBuilder {
+    fn new(origin: Point3, scale: f64) -> Self {
+        Self {
+            origin,
+            scale,
+            contours: Vec::new(),
+            current: Wire::new(),
+            first: None,
+            last: None,
+        }
+    }
+
+    #[inline(always)]
+    fn point(&self, x: f32, y: f32) -> Point3 {
+        self.origin + self.scale * Vector3::new(x as f64, y as f64, 0.0)
+    }
+
+    /// the vertex at `point`, the first vertex of the contour if they coincide
+    fn vertex(&self, point: Point3) -> Vertex {
+        match &self.first {
+            Some(first) if first.point().near(&point) => first.clone(),
+            _ => builder::vertex(point),
+        }
+    }
+
+    fn push(&mut self, end: Vertex, inter_points: Vec<Point3>) {
+        let last = match &self.last {
+            Some(last) if *last != end => last.clone(),
+            _ => return,
+        };
+        let edge = match inter_points.is_empty() {
+            true => builder::line(&last, &end),
+            false => builder::bezier(&last, &end, inter_points),
+        };
+        self.current.push_back(edge);
+        self.last = Some(end);
+    }
+}
+
+impl OutlineBuilder for ContourBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        let vertex = builder::vertex(self.point(x, y));
+        self.first = Some(vertex.clone());
+        self.last = Some(vertex);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let end = self.vertex(self.point(x, y));
+        self.push(end, Vec::new());
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let end = self.vertex(self.point(x, y));
+        self.push(end, vec![self.point(x1, y1)]);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let end = self.vertex(self.point(x, y));
+        self.push(end, vec![self.point(x1, y1), self.point(x2, y2)]);
+    }
+
+    fn close(&mut self) {
+        if let Some(first) = self.first.clone() {
+            self.push(first, Vec::new());
+        }
+        let wire = std::mem::take(&mut self.current);
+        if wire.is_closed() && !wire.is_empty() {
+            self.contours.push(wire);
+        }
+        self.first = None;
+        self.last = None;
+    }
+}
+
+/// the faces bounded by the contours of a glyph
+fn glyph_faces(contours: Vec<Wire>) -> Vec<Face> {
+    let polygons: Vec<Vec<Point2>> = contours.iter().map(sampling_polygon).collect();
+    let areas: Vec<f64> = polygons
+        .iter()
+        .map(|polygon| signed_area(polygon))
+        .collect();
+    let contains = |i: usize, j: usize| i != j && inside(&polygons[i], polygons[j][0]);
+    let depths: Vec<usize> = (0..contours.len())
+        .map(|j| (0..contours.len()).filter(|i| contains(*i, j)).count())
+        .collect();
+    let parents: Vec<Option<usize>> = (0..contours.len())
+        .map(|j| {
+            (0..contours.len())
+                .filter(|i| contains(*i, j) && depths[*i] + 1 == depths[j])
+                .min_by(|i, k| areas[*i].abs().partial_cmp(&areas[*k].abs()).unwrap())
+        })
+        .collect();
+    let oriented = |i: usize, ccw: bool| match (areas[i] > 0.0) == ccw {
+        true => contours[i].clone(),
+        false => contours[i].inverse(),
+    };
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    (0..contours.len())
+        .filter(|i| depths[*i] % 2 == 0)
+        .map(|i| {
+            let holes = (0..contours.len())
+                .filter(|j| depths[*j] % 2 == 1 && parents[*j] == Some(i))
+                .map(|j| oriented(j, false));
+            let boundaries = std::iter::once(oriented(i, true)).chain(holes).collect();
+            Face::debug_new(boundaries, plane.into())
+        })
+        .collect()
+}
+
+/// the polygon of the sampling points on the closed wire in the xy-plane
+fn sampling_polygon(wire: &Wire) -> Vec<Point2> {
+    wire.edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.parameter_range();
+            (0..SAMPLING_DIVISION).map(move |i| {
+                let t = t0 + (t1 - t0) * (i as f64) / (SAMPLING_DIVISION as f64);
+                let pt = curve.subs(t);
+                Point2::new(pt.x, pt.y)
+            })
+        })
+        .collect()
+}
+
+/// twice of the signed area of the polygon, positive if counterclockwise
+fn signed_area(polygon: &[Point2]) -> f64 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(p, q)| p.x * q.y - p.y * q.x)
+        .sum()
+}
+
+/// the even-odd rule
+fn inside(polygon: &[Point2], pt: Point2) -> bool {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .filter(|(p, q)| {
+            (p.y > pt.y) != (q.y > pt.y) && pt.x < p.x + (pt.y - p.y) / (q.y - p.y) * (q.x - p.x)
+        })
+        .count()
+        % 2
+        == 1
+}
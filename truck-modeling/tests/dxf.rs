@@ -0,0 +1,91 @@
+use truck_modeling::*;
+
+/// the DXF data of the entities section
+fn dxf_data(entities: &[&str]) -> String {
+    let entities: String = entities.concat();
+    format!("0\nSECTION\n2\nENTITIES\n{entities}0\nENDSEC\n0\nEOF\n")
+}
+
+fn vertex(x: f64, y: f64) -> String { format!("0\nVERTEX\n8\n0\n10\n{x}\n20\n{y}\n30\n0.0\n") }
+
+fn polyline(closed: bool) -> String { format!("0\nPOLYLINE\n8\n0\n66\n1\n70\n{}\n", closed as i32) }
+
+const SEQEND: &str = "0\nSEQEND\n8\n0\n";
+const LINE: &str = "0\nLINE\n8\n0\n10\n0.0\n20\n2.0\n30\n0.0\n11\n1.0\n21\n2.0\n31\n0.0\n";
+
+fn square() -> Vec<String> {
+    vec![
+        vertex(0.0, 0.0),
+        vertex(1.0, 0.0),
+        vertex(1.0, 1.0),
+        vertex(0.0, 1.0),
+    ]
+}
+
+#[test]
+fn polyline_with_seqend() {
+    let (polyline, square) = (polyline(true), square());
+    let mut entities: Vec<&str> = vec![&polyline];
+    entities.extend(square.iter().map(String::as_str));
+    entities.extend([SEQEND, LINE]);
+    let wires = dxf::read(dxf_data(&entities).as_bytes()).unwrap();
+    assert_eq!(wires.len(), 2);
+    assert!(wires[0].is_closed());
+    assert_eq!(wires[0].len(), 4);
+    assert_near!(planarity::area(&wires[0]).unwrap(), 1.0);
+    assert_eq!(wires[1].len(), 1);
+}
+
+#[test]
+fn polyline_without_seqend() {
+    let (polyline, square) = (polyline(false), square());
+    let mut entities: Vec<&str> = vec![&polyline];
+    entities.extend(square.iter().map(String::as_str));
+    entities.push(LINE);
+    let wires = dxf::read(dxf_data(&entities).as_bytes()).unwrap();
+    // The line is not swallowed by the polyline.
+    assert_eq!(wires.len(), 2);
+    assert!(!wires[0].is_closed());
+    assert_eq!(wires[0].len(), 3);
+    let line = &wires[1];
+    assert_eq!(line.len(), 1);
+    assert_near!(
+        line.front_vertex().unwrap().point(),
+        Point3::new(0.0, 2.0, 0.0)
+    );
+    assert_near!(
+        line.back_vertex().unwrap().point(),
+        Point3::new(1.0, 2.0, 0.0)
+    );
+}
+
+#[test]
+fn polylines_without_seqend() {
+    let (polyline, square) = (polyline(true), square());
+    let mut entities: Vec<&str> = Vec::new();
+    (0..2).for_each(|_| {
+        entities.push(&polyline);
+        entities.extend(square.iter().map(String::as_str));
+    });
+    let wires = dxf::read(dxf_data(&entities).as_bytes()).unwrap();
+    assert_eq!(wires.len(), 2);
+    assert!(wires.iter().all(|wire| wire.is_closed() && wire.len() == 4));
+}
+
+#[test]
+fn degenerate_entities() {
+    let circle = "0\nCIRCLE\n8\n0\n10\n0.0\n20\n0.0\n30\n0.0\n40\n0.0\n";
+    let arc = "0\nARC\n8\n0\n10\n0.0\n20\n0.0\n30\n0.0\n40\n0.0\n50\n0.0\n51\n90.0\n";
+    let spline = |degree: i32| {
+        format!(
+            "0\nSPLINE\n8\n0\n71\n{degree}\n40\n0.0\n40\n0.0\n40\n1.0\n40\n1.0\n\
+            10\n0.0\n20\n0.0\n30\n0.0\n10\n1.0\n20\n0.0\n30\n0.0\n"
+        )
+    };
+    assert!(dxf::read(dxf_data(&[circle]).as_bytes()).is_err());
+    assert!(dxf::read(dxf_data(&[arc]).as_bytes()).is_err());
+    assert!(dxf::read(dxf_data(&[&spline(-1)]).as_bytes()).is_err());
+    assert!(dxf::read(dxf_data(&[&spline(i32::MAX)]).as_bytes()).is_err());
+    let wires = dxf::read(dxf_data(&[&spline(1)]).as_bytes()).unwrap();
+    assert_eq!(wires.len(), 1);
+}
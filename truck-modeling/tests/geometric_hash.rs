@@ -0,0 +1,37 @@
+use truck_modeling::*;
+
+const TOL: f64 = 1.0e-6;
+
+fn block(x: f64) -> Solid { builder::cuboid(Point3::origin(), Point3::new(x, 1.0, 2.0)) }
+
+#[test]
+fn invariant_under_ordering() {
+    let solid = block(3.0);
+    let mut shell = solid.boundaries()[0].clone();
+    shell.reverse();
+    shell.rotate_left(2);
+    let reordered = Solid::new(vec![shell]);
+    let copy = builder::clone(&solid);
+    let hash = solid.geometric_hash(TOL, false);
+    assert_eq!(reordered.geometric_hash(TOL, false), hash);
+    assert_eq!(copy.geometric_hash(TOL, false), hash);
+    assert_ne!(block(3.5).geometric_hash(TOL, false), hash);
+}
+
+#[test]
+fn invariant_under_rigid_transformations() {
+    let solid = block(3.0);
+    let moved = builder::rotated(
+        &builder::translated(&solid, Vector3::new(1.0, -2.0, 5.0)),
+        Point3::new(0.5, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Rad(0.7),
+    );
+    let hash = solid.geometric_hash(TOL, false);
+    assert_ne!(moved.geometric_hash(TOL, false), hash);
+    let hash = solid.geometric_hash(TOL, true);
+    assert_eq!(moved.geometric_hash(TOL, true), hash);
+
+    let sphere = builder::sphere(Point3::origin(), 1.5);
+    assert_ne!(sphere.geometric_hash(TOL, true), hash);
+}
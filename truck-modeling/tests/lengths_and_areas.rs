@@ -0,0 +1,56 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn circle(center: Point3, radius: f64) -> Wire {
+    let v = builder::vertex(center + radius * Vector3::unit_x());
+    builder::rsweep(&v, center, Vector3::unit_z(), Rad(7.0))
+}
+
+#[test]
+fn edge_and_wire_lengths() {
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(3.0, 4.0, 0.0));
+    let line = builder::line(&v0, &v1);
+    assert_near!(line.length(), 5.0);
+    assert_near!(line.inverse().length(), 5.0);
+
+    let v2 = builder::vertex(Point3::new(-3.0, 4.0, 0.0));
+    let arc = builder::circle_arc(&v1, &v2, Point3::new(0.0, 5.0, 0.0));
+    let angle = 2.0 * f64::atan2(3.0, 4.0);
+    assert_near!(arc.length(), 5.0 * angle);
+
+    let wire = circle(Point3::new(1.0, 2.0, 3.0), 2.0);
+    assert_near!(wire.length(), 4.0 * PI);
+    assert_near!(Wire::new().length(), 0.0);
+}
+
+#[test]
+fn polygonal_face_areas() {
+    let cuboid = builder::cuboid(Point3::origin(), Point3::new(1.0, 2.0, 3.0));
+    let mut areas: Vec<f64> = cuboid.face_iter().filter_map(Face::area).collect();
+    assert_eq!(areas.len(), 6);
+    areas.sort_by(f64::total_cmp);
+    let answers = [2.0, 2.0, 3.0, 3.0, 6.0, 6.0];
+    for (area, answer) in areas.into_iter().zip(answers) {
+        assert_near!(area, answer);
+    }
+
+    // the annulus with the hole
+    let outer = circle(Point3::origin(), 2.0);
+    let inner = circle(Point3::origin(), 1.0);
+    let annulus = builder::try_attach_plane(&[outer, inner.inverse()]).unwrap();
+    assert_near!(annulus.area().unwrap(), 3.0 * PI);
+    assert_near!(annulus.inverse().area().unwrap(), 3.0 * PI);
+}
+
+#[test]
+fn curved_face_areas() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::new(0.0, 0.0, 2.0), 0.5);
+    let area: f64 = cylinder.face_iter().map(|face| face.area().unwrap()).sum();
+    assert_near!(area, 2.5 * PI);
+
+    // the faces of the sphere degenerate to the poles.
+    let sphere = builder::sphere(Point3::new(1.0, 2.0, 3.0), 2.0);
+    let area: f64 = sphere.face_iter().map(|face| face.area().unwrap()).sum();
+    assert!(f64::abs(area - 16.0 * PI) < 1.0e-4, "{area}");
+}
@@ -0,0 +1,36 @@
+use truck_modeling::*;
+
+fn polyline_edge() -> Edge {
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.5, 0.0),
+        Point3::new(1.5, -0.5, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+    ];
+    let v0 = builder::vertex(points[0]);
+    let v1 = builder::vertex(points[3]);
+    Edge::new(&v0, &v1, Curve::Polyline(PolylineCurve(points)))
+}
+
+#[test]
+fn polyline_lift_up() {
+    let curve = polyline_edge().curve();
+    let lifted = curve.clone().lift_up();
+    (0..=30).for_each(|i| {
+        let t = i as f64 / 10.0;
+        let pt = lifted.subs(t);
+        assert_near!(Point3::from_homogeneous(pt), curve.subs(t));
+    });
+}
+
+#[test]
+fn polyline_tsweep() {
+    let edge = polyline_edge();
+    let face = builder::tsweep(&edge, Vector3::unit_z());
+    let surface = face.surface();
+    assert!(surface.include(&edge.curve()));
+    let moved = builder::translated(&edge, Vector3::unit_z());
+    assert!(surface.include(&moved.curve()));
+    let bdd = face.boundaries()[0].clone();
+    assert_eq!(bdd.len(), 4);
+}
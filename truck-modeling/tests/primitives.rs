@@ -0,0 +1,214 @@
+use truck_modeling::*;
+
+/// Checks that the normals at the middle points of the edges point away from `inside(point)`.
+fn assert_outward(solid: &Solid, inside: impl Fn(Point3) -> Point3) {
+    assert!(solid.is_geometric_consistent());
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    shell.face_iter().for_each(|face| {
+        let surface = face.oriented_surface();
+        face.boundaries().iter().flatten().for_each(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.parameter_range();
+            let pt = curve.subs((t0 + t1) / 2.0);
+            let (u, v) = surface.search_nearest_parameter(pt, None, 100).unwrap();
+            let normal = surface.normal(u, v);
+            assert!(normal.dot(pt - inside(pt)) > 0.0, "{pt:?}");
+        });
+    });
+}
+
+#[test]
+fn cuboid() {
+    let cuboid = builder::cuboid(Point3::new(1.0, 2.0, 3.0), Point3::new(0.0, -1.0, 0.5));
+    assert_outward(&cuboid, |_| Point3::new(0.5, 0.5, 1.75));
+}
+
+#[test]
+fn sphere() {
+    let center = Point3::new(1.0, -1.0, 0.5);
+    let sphere = builder::sphere(center, 2.0);
+    assert_outward(&sphere, |_| center);
+}
+
+#[test]
+fn cylinder() {
+    let axis = Vector3::new(1.0, 2.0, 2.0);
+    let cylinder = builder::cylinder(Point3::new(1.0, 0.0, 0.0), axis, 0.5);
+    assert_outward(&cylinder, |_| Point3::new(1.0, 0.0, 0.0) + axis / 2.0);
+}
+
+#[test]
+fn circular_cone() {
+    let cone = builder::circular_cone(Point3::origin(), Vector3::new(0.0, 0.0, 2.0), 1.0);
+    assert_outward(&cone, |_| Point3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn torus() {
+    let torus = builder::torus(Point3::origin(), Vector3::unit_z(), 2.0, 0.5);
+    assert_outward(&torus, |pt| {
+        Point3::from_vec(2.0 * Vector3::new(pt.x, pt.y, 0.0).normalize())
+    });
+}
+
+#[test]
+fn prism() {
+    let mut points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(3.0, 0.0, 0.0),
+        Point3::new(3.0, 3.0, 0.0),
+        Point3::new(0.0, 3.0, 0.0),
+    ];
+    let inside = |_: Point3| Point3::new(1.5, 1.5, 0.5);
+    assert_outward(&builder::prism(&points, Vector3::unit_z()), inside);
+    points.reverse();
+    assert_outward(&builder::prism(&points, Vector3::unit_z()), inside);
+}
+
+#[test]
+fn drafted_and_twisted_extrusions() {
+    use builder::ExtrusionOptions;
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let options = ExtrusionOptions {
+        draft: Some(Deg(10.0).into()),
+        ..Default::default()
+    };
+    let frustum = builder::tsweep_with_options(&disk, Vector3::new(0.0, 0.0, 2.0), options);
+    assert_outward(&frustum, |_| Point3::new(0.0, 0.0, 1.0));
+    let top_radius = 1.0 + 2.0 * f64::tan(10.0_f64.to_radians());
+    frustum.boundaries()[0].vertex_iter().for_each(|v| {
+        let pt = v.point();
+        let radius = f64::hypot(pt.x, pt.y);
+        assert!(radius.near(&1.0) || (pt.z.near(&2.0) && radius.near(&top_radius)));
+    });
+
+    let points = [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)];
+    let points = points.map(|(x, y)| Point3::new(x, y, 0.0));
+    let options = ExtrusionOptions {
+        draft: Some(Deg(-5.0).into()),
+        twist_per_length: Some(Deg(30.0).into()),
+    };
+    let prism = builder::prism(&points, Vector3::unit_z());
+    let bottom = prism.boundaries()[0].face_iter().next().unwrap().inverse();
+    let twisted = builder::tsweep_with_options(&bottom, Vector3::new(0.0, 0.0, 3.0), options);
+    assert_outward(&twisted, |_| Point3::new(1.0, 0.5, 1.5));
+    // four sections twisted by 22.5 degrees
+    assert_eq!(twisted.boundaries()[0].len(), 2 + 4 * 4);
+}
+
+#[test]
+fn law_sweep_along_arc() {
+    use std::f64::consts::PI;
+    let v = builder::vertex(Point3::new(0.5, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    // the quarter of the circle with the center (2, 0, 0), from the origin to (2, 0, 2)
+    let transit = Point3::new(2.0 - f64::sqrt(2.0), 0.0, f64::sqrt(2.0));
+    let path = builder::circle_arc(
+        &builder::vertex(Point3::origin()),
+        &builder::vertex(Point3::new(2.0, 0.0, 2.0)),
+        transit,
+    );
+    let law = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![Point1::new(1.0), Point1::new(2.0), Point1::new(0.5)],
+    );
+    let handle = builder::law_sweep(&disk, &path, &law, 8);
+    // the point on the path at the angle of `pt`, apart from the both ends
+    assert_outward(&handle, |pt| {
+        let angle = f64::atan2(pt.z, pt.x - 2.0).clamp(PI / 2.0 + 0.1, PI - 0.1);
+        Point3::new(2.0 + 2.0 * angle.cos(), 0.0, 2.0 * angle.sin())
+    });
+    let shell = &handle.boundaries()[0];
+    // the bottom, the eight sections of the two side faces, and the top
+    assert_eq!(shell.len(), 2 + 8 * 2);
+    let top = shell.face_iter().last().unwrap();
+    top.boundaries()[0].vertex_iter().for_each(|v| {
+        let pt = v.point();
+        assert_near!(pt.x, 2.0);
+        assert_near!(pt.distance(Point3::new(2.0, 0.0, 2.0)), 0.25);
+    });
+}
+
+#[test]
+fn rail_blend_of_circles() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let profile0 = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let v = builder::vertex(Point3::new(1.5, 0.0, 3.0));
+    let profile1 = builder::rsweep(&v, Point3::new(1.0, 0.0, 3.0), Vector3::unit_z(), Rad(7.0));
+    // the rails bent toward the offset end
+    let bezier = |p: Point3, q: Point3, r: Point3| {
+        builder::bezier(&builder::vertex(p), &builder::vertex(r), vec![q])
+    };
+    let rail0 = bezier(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 1.5),
+        Point3::new(1.5, 0.0, 3.0),
+    );
+    let rail1 = bezier(
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(-1.0, 0.0, 1.5),
+        Point3::new(0.5, 0.0, 3.0),
+    );
+    let pipe = builder::rail_blend(&profile0, &profile1, [&rail0, &rail1], 6);
+    assert_eq!(pipe.len(), 2);
+    assert_eq!(pipe.shell_condition(), ShellCondition::Oriented);
+    assert!(pipe.is_geometric_consistent());
+    let boundaries = pipe.extract_boundaries();
+    assert_eq!(boundaries.len(), 2);
+    // the vertices are the ends of the rails
+    let ends = [rail0.front(), rail0.back(), rail1.front(), rail1.back()].map(Vertex::point);
+    let is_end = |pt: Point3| ends.iter().any(|end| end.near(&pt));
+    pipe.vertex_iter().for_each(|v| assert!(is_end(v.point())));
+    // the ends are the circles of the profiles
+    let radii = boundaries.iter().map(|wire| {
+        let curve = wire[0].oriented_curve();
+        let (t0, t1) = curve.parameter_range();
+        let pt = curve.subs((t0 + t1) / 2.0);
+        match pt.z < 1.5 {
+            true => f64::hypot(pt.x, pt.y),
+            false => f64::hypot(pt.x - 1.0, pt.y),
+        }
+    });
+    radii.for_each(|radius| {
+        assert!(f64::abs(radius - 1.0) < 1.0e-3 || f64::abs(radius - 0.5) < 1.0e-3)
+    });
+}
+
+#[test]
+fn capped_rsweeps() {
+    use std::f64::consts::PI;
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let square = builder::tsweep(&e, Vector3::unit_z());
+    let profile = square.boundaries()[0].clone();
+    // the point on the center circle of the ring at the angle of `pt`, apart from the both ends
+    let inside = |pt: Point3| {
+        let angle = f64::atan2(pt.y, pt.x).clamp(0.1, PI / 2.0 - 0.1);
+        Point3::new(1.5 * angle.cos(), 1.5 * angle.sin(), 0.5)
+    };
+    let quarter = |profile: &Wire| {
+        builder::capped_rsweep(profile, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0))
+    };
+    assert_outward(&quarter(&profile), inside);
+    assert_outward(&quarter(&profile.inverse()), inside);
+    // the open profile is closed by the line
+    let mut open = profile.clone();
+    open.pop_back();
+    assert_outward(&quarter(&open), inside);
+
+    let north = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let south = builder::vertex(Point3::new(0.0, 0.0, -1.0));
+    let arc = builder::circle_arc(&north, &south, Point3::new(1.0, 0.0, 0.0));
+    let arc: Wire = vec![arc].into();
+    let revolve = |profile: &Wire, angle: f64| {
+        builder::capped_rsweep(profile, Point3::origin(), Vector3::unit_z(), Rad(angle))
+    };
+    assert_outward(&revolve(&arc, PI), |_| Point3::new(0.0, 0.3, 0.0));
+    assert_outward(&revolve(&arc.inverse(), PI), |_| Point3::new(0.0, 0.3, 0.0));
+    let ball = revolve(&arc, 2.0 * PI);
+    assert_outward(&ball, |_| Point3::origin());
+}
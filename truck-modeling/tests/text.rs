@@ -0,0 +1,104 @@
+#![cfg(feature = "text")]
+use truck_modeling::{errors::Error, *};
+
+// The glyphs of the test font, whose em square is 1000 units:
+// - 'I': the rectangle `[100, 200] x [0, 700]`, the advance is 300.
+// - 'O': the rectangle `[100, 500] x [0, 700]` with the hole `[200, 400] x [100, 600]`.
+// - 'o': the closed curve of four quadratic Bézier curves in `[100, 500] x [0, 500]`.
+// The ascender is 800, the descender is -200 and the line gap is 100.
+const FONT: &[u8] = include_bytes!("fonts/test-glyphs.ttf");
+
+fn bounding_box(wire: &Wire) -> BoundingBox<Point3> {
+    wire.vertex_iter().map(|vertex| vertex.point()).collect()
+}
+
+/// twice of the signed area of the polygon of the vertices, positive if counterclockwise
+fn signed_area(wire: &Wire) -> f64 {
+    let points: Vec<Point3> = wire.vertex_iter().map(|vertex| vertex.point()).collect();
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(p, q)| p.x * q.y - p.y * q.x)
+        .sum()
+}
+
+#[test]
+fn glyph_of_lines() {
+    let wires = text::text_wires(FONT, "I", 10.0).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    assert_eq!(wires[0].len(), 4);
+    assert!(wires[0]
+        .edge_iter()
+        .all(|edge| matches!(edge.curve(), Curve::Line(_))));
+    let bdd = bounding_box(&wires[0]);
+    assert_near!(bdd.min(), Point3::new(1.0, 0.0, 0.0));
+    assert_near!(bdd.max(), Point3::new(2.0, 7.0, 0.0));
+}
+
+#[test]
+fn glyph_of_quadratic_curves() {
+    let wires = text::text_wires(FONT, "o", 10.0).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    assert_eq!(wires[0].len(), 4);
+    let middles: Vec<Point3> = wires[0]
+        .edge_iter()
+        .map(|edge| match edge.curve() {
+            Curve::BSplineCurve(curve) => {
+                assert_eq!(curve.degree(), 2);
+                let (t0, t1) = curve.parameter_range();
+                curve.subs((t0 + t1) / 2.0)
+            }
+            curve => panic!("the quadratic curve is expected: {curve:?}"),
+        })
+        .collect();
+    // the middle point of the curve from (300, 0) to (100, 250) via (100, 0)
+    let middle = Point3::new(1.5, 0.625, 0.0);
+    assert!(middles.iter().any(|pt| pt.near(&middle)));
+}
+
+#[test]
+fn glyphs_in_lines() {
+    let wires = text::text_wires(FONT, "IO\nI", 10.0).unwrap();
+    assert_eq!(wires.len(), 4);
+    // 'O' is put after the advance of 'I'.
+    let bdd = bounding_box(&wires[1]);
+    assert_near!(bdd.min(), Point3::new(4.0, 0.0, 0.0));
+    assert_near!(bdd.max(), Point3::new(8.0, 7.0, 0.0));
+    // The second line is below the first one by the ascender, the descender and the line gap.
+    let bdd = bounding_box(&wires[3]);
+    assert_near!(bdd.min(), Point3::new(1.0, -11.0, 0.0));
+    assert_near!(bdd.max(), Point3::new(2.0, -4.0, 0.0));
+}
+
+#[test]
+fn glyph_faces_with_hole() {
+    let faces = text::text_faces(FONT, "O", 10.0).unwrap();
+    assert_eq!(faces.len(), 1);
+    let boundaries = faces[0].boundaries();
+    assert_eq!(boundaries.len(), 2);
+    assert!(signed_area(&boundaries[0]) > 0.0);
+    assert!(signed_area(&boundaries[1]) < 0.0);
+    let bdd = bounding_box(&boundaries[1]);
+    assert_near!(bdd.min(), Point3::new(2.0, 1.0, 0.0));
+    assert_near!(bdd.max(), Point3::new(4.0, 6.0, 0.0));
+
+    let solid = builder::tsweep(&faces[0], Vector3::unit_z());
+    assert_eq!(solid.boundaries()[0].len(), 10);
+    assert!(solid.is_geometric_consistent());
+
+    let faces = text::text_faces(FONT, "oI", 10.0).unwrap();
+    assert_eq!(faces.len(), 2);
+    assert!(faces.iter().all(|face| face.boundaries().len() == 1));
+    assert!(signed_area(&faces[1].boundaries()[0]) > 0.0);
+}
+
+#[test]
+fn missing_glyph_and_invalid_font() {
+    assert!(text::text_wires(FONT, "x", 10.0).unwrap().is_empty());
+    assert_eq!(
+        text::text_wires(&[0, 1, 2, 3], "I", 10.0).unwrap_err(),
+        Error::InvalidFontData,
+    );
+}
@@ -21,6 +21,75 @@ impl BufferHandler {
             stride,
         }
     }
+
+    /// Creates a buffer handler from the bytes of the elements of size `stride`.
+    ///
+    /// The bytes are uploaded as they are, e.g. from a memory-mapped file, without the cast or
+    /// the intermediate copy. That the length of `bytes` is a multiple of `stride` and that the
+    /// bytes are valid as the elements are not checked.
+    #[inline(always)]
+    pub fn from_bytes_unchecked(
+        bytes: &[u8],
+        stride: u64,
+        device: &Device,
+        usage: BufferUsages,
+    ) -> Self {
+        let buffer = device.create_buffer_init(&BufferInitDescriptor {
+            contents: bytes,
+            usage,
+            label: None,
+        });
+        BufferHandler {
+            buffer,
+            size: bytes.len() as u64,
+            stride,
+        }
+    }
+
+    /// Creates a buffer handler of `len` elements of `T`, written directly into the mapped memory.
+    ///
+    /// `write` is called with the mapped slice, so the large tessellations can be uploaded
+    /// without building the vectors of the vertices.
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// use wgpu::*;
+    /// let handler = pollster::block_on(DeviceHandler::default_device());
+    /// let buffer = BufferHandler::from_mapped::<f32, _>(
+    ///     100,
+    ///     handler.device(),
+    ///     BufferUsages::STORAGE | BufferUsages::COPY_SRC,
+    ///     |values| values.iter_mut().enumerate().for_each(|(i, x)| *x = i as f32),
+    /// );
+    /// let res = pollster::block_on(buffer.read_buffer(&handler));
+    /// assert_eq!(bytemuck::cast_slice::<u8, f32>(&res)[42], 42.0);
+    /// ```
+    pub fn from_mapped<T: Sized + Pod + Zeroable, F: FnOnce(&mut [T])>(
+        len: usize,
+        device: &Device,
+        usage: BufferUsages,
+        write: F,
+    ) -> Self {
+        let stride = std::mem::size_of::<T>() as u64;
+        let size = len as u64 * stride;
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: None,
+            size: aligned_size(size),
+            usage,
+            mapped_at_creation: true,
+        });
+        {
+            let mut range = buffer.slice(..).get_mapped_range_mut();
+            write(bytemuck::cast_slice_mut(&mut range[..size as usize]));
+        }
+        buffer.unmap();
+        BufferHandler {
+            buffer,
+            size,
+            stride,
+        }
+    }
+
     /// Returns the reference of the buffer.
     #[inline(always)]
     pub const fn buffer(&self) -> &Buffer { &self.buffer }
@@ -29,16 +98,56 @@ impl BufferHandler {
     #[inline(always)]
     pub const fn size(&self) -> u64 { self.size }
 
+    /// Returns the size of an element of the buffer.
+    #[inline(always)]
+    pub const fn stride(&self) -> u64 { self.stride }
+
     /// Creates a binding resource from buffer slice.
+    ///
+    /// The binding is restricted to `self.size()`, so that the tail of a larger buffer reused
+    /// from [`BufferPool`] is not seen by the shaders, e.g. by `arrayLength`.
     #[inline(always)]
     pub const fn binding_resource(&self) -> BindingResource<'_> {
         BindingResource::Buffer(BufferBinding {
             buffer: &self.buffer,
             offset: 0,
-            size: None,
+            size: std::num::NonZeroU64::new(self.size),
         })
     }
 
+    /// Overwrites the values of buffer from the start by `vec` via `queue`.
+    ///
+    /// The buffer must have the usage `BufferUsages::COPY_DST`. The write is done before the
+    /// commands submitted later, so the bind groups holding the buffer do not need to be
+    /// recreated.
+    /// # Panics
+    /// Panic occurs if `vec` is larger than the buffer, or the size of `vec` is not a multiple of
+    /// [`COPY_BUFFER_ALIGNMENT`].
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// use wgpu::*;
+    /// let handler = pollster::block_on(DeviceHandler::default_device());
+    /// let usage = BufferUsages::UNIFORM | BufferUsages::COPY_DST | BufferUsages::COPY_SRC;
+    /// let buffer = BufferHandler::from_slice(&[0.0_f32; 4], handler.device(), usage);
+    /// buffer.write(handler.queue(), &[1.0_f32, 2.0, 3.0, 4.0]);
+    /// let res = pollster::block_on(buffer.read_buffer(&handler));
+    /// assert_eq!(bytemuck::cast_slice::<u8, f32>(&res), &[1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    #[inline(always)]
+    pub fn write<T: Sized + Pod + Zeroable, A: AsRef<[T]>>(&self, queue: &Queue, vec: &A) {
+        let bytes: &[u8] = bytemuck::cast_slice(vec.as_ref());
+        assert!(
+            bytes.len() as u64 <= self.size,
+            "The data must be no larger than the buffer."
+        );
+        assert!(
+            bytes.len() as u64 % COPY_BUFFER_ALIGNMENT == 0,
+            "The size of the data must be aligned."
+        );
+        queue.write_buffer(&self.buffer, 0, bytes);
+    }
+
     /// Copy the values of buffer to `dest`.
     /// # Panic
     /// Panic occurs if the size of `dest` is smaller than the one of `self`.
@@ -50,4 +159,136 @@ impl BufferHandler {
         );
         encoder.copy_buffer_to_buffer(&self.buffer, 0, &dest.buffer, 0, self.size);
     }
+
+    /// Reads the values of buffer.
+    ///
+    /// The buffer must have the usage `BufferUsages::COPY_SRC`.
+    pub async fn read_buffer(&self, handler: &DeviceHandler) -> Vec<u8> {
+        let (device, queue) = (handler.device(), handler.queue());
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: None,
+            mapped_at_creation: false,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            size: self.size,
+        });
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.copy_buffer_to_buffer(&self.buffer, 0, &buffer, 0, self.size);
+        queue.submit(Some(encoder.finish()));
+        read_mapped_buffer(device, &buffer).await
+    }
+}
+
+/// the size rounded up to the multiple of [`COPY_BUFFER_ALIGNMENT`]
+#[inline(always)]
+fn aligned_size(size: u64) -> u64 {
+    let align = COPY_BUFFER_ALIGNMENT;
+    u64::max((size + align - 1) / align * align, align)
+}
+
+impl BufferPool {
+    /// Creates an empty pool.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Returns the number of the buffers kept in the pool.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.buffers.len() }
+
+    /// Returns whether the pool keeps no buffers.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.buffers.is_empty() }
+
+    /// Creates a buffer handler from a slice, reusing a released buffer if possible.
+    ///
+    /// The smallest released buffer with the usage `usage | BufferUsages::COPY_DST` and enough
+    /// capacity is overwritten by `queue`. If there is no such buffer, a new one is created.
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// use wgpu::*;
+    /// let handler = pollster::block_on(DeviceHandler::default_device());
+    /// let (device, queue) = (handler.device(), handler.queue());
+    /// let usage = BufferUsages::UNIFORM;
+    /// let mut pool = BufferPool::new();
+    ///
+    /// let buffer = pool.get_from_slice(&[1.0_f32, 2.0, 3.0, 4.0], device, queue, usage);
+    /// pool.release(buffer);
+    /// assert_eq!(pool.len(), 1);
+    ///
+    /// // the released buffer is reused.
+    /// let buffer = pool.get_from_slice(&[5.0_f32, 6.0], device, queue, usage);
+    /// assert!(pool.is_empty());
+    /// assert_eq!(buffer.size(), 8);
+    ///
+    /// // only the written part is bound.
+    /// let binding_size = match buffer.binding_resource() {
+    ///     BindingResource::Buffer(binding) => binding.size,
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(binding_size.unwrap().get(), 8);
+    /// ```
+    pub fn get_from_slice<T: Sized + Pod + Zeroable, A: AsRef<[T]>>(
+        &mut self,
+        vec: &A,
+        device: &Device,
+        queue: &Queue,
+        usage: BufferUsages,
+    ) -> BufferHandler {
+        let usage = usage | BufferUsages::COPY_DST;
+        let bytes: &[u8] = bytemuck::cast_slice(vec.as_ref());
+        let stride = std::mem::size_of::<T>() as u64;
+        let size = bytes.len() as u64;
+        // `Queue::write_buffer` requires the aligned size.
+        if size % COPY_BUFFER_ALIGNMENT != 0 {
+            return BufferHandler::from_slice(vec, device, usage);
+        }
+        let idx = self
+            .buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, buffer)| buffer.usage() == usage && buffer.size() >= size)
+            .min_by_key(|(_, buffer)| buffer.size())
+            .map(|(idx, _)| idx);
+        match idx {
+            Some(idx) => {
+                let buffer = self.buffers.swap_remove(idx);
+                if size > 0 {
+                    queue.write_buffer(&buffer, 0, bytes);
+                }
+                BufferHandler {
+                    buffer,
+                    size,
+                    stride,
+                }
+            }
+            None => BufferHandler::from_slice(vec, device, usage),
+        }
+    }
+
+    /// Returns the buffer to the pool for reuse.
+    ///
+    /// The buffer must not be used by the commands submitted later.
+    #[inline(always)]
+    pub fn release(&mut self, buffer: BufferHandler) { self.buffers.push(buffer.buffer) }
+
+    /// Destroys all buffers kept in the pool.
+    #[inline(always)]
+    pub fn clear(&mut self) { self.buffers.clear() }
+}
+
+/// Maps `buffer` and reads the values.
+pub(crate) async fn read_mapped_buffer(device: &Device, buffer: &Buffer) -> Vec<u8> {
+    let buffer_slice = buffer.slice(..);
+    let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+    buffer_slice.map_async(MapMode::Read, move |v| sender.send(v).unwrap());
+    device.poll(Maintain::Wait);
+    match receiver.receive().await {
+        Some(Ok(_)) => buffer_slice.get_mapped_range().iter().copied().collect(),
+        Some(Err(e)) => panic!("{}", e),
+        None => panic!("Asynchronous processing fails"),
+    }
 }
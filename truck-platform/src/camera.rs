@@ -263,11 +263,15 @@ impl Camera {
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
-    /// ```glsl
-    /// layout(set = 0, binding = 0) uniform Camera {
-    ///     mat4 camera_matrix;     // the camera matrix
-    ///     mat4 camera_projection; // the projection into the normalized view volume
-    /// };
+    /// ```wgsl
+    /// struct Camera {
+    ///     camera_matrix: mat4x4<f32>,     // the camera matrix
+    ///     camera_projection: mat4x4<f32>, // the projection into the normalized view volume
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(0)
+    /// var<uniform> camera: Camera;
     /// ```
     pub fn buffer(&self, as_rat: f64, device: &Device) -> BufferHandler {
         BufferHandler::from_slice(&[self.camera_info(as_rat)], device, BufferUsages::UNIFORM)
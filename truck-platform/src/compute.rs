@@ -0,0 +1,80 @@
+use crate::*;
+
+impl ComputeKernel {
+    /// Creates the kernel from the shader module.
+    ///
+    /// The kernel reads and writes `num_buffers` storage buffers, which are bound in `group = 0`
+    /// from `binding = 0` in order.
+    /// # Shader Examples
+    /// ```wgsl
+    /// @group(0)
+    /// @binding(0)
+    /// var<storage, read_write> values: array<f32>;
+    ///
+    /// @compute
+    /// @workgroup_size(64)
+    /// fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    ///     values[id.x] = 2.0 * values[id.x];
+    /// }
+    /// ```
+    pub fn new(
+        device: &Device,
+        module: &ShaderModule,
+        entry_point: &str,
+        num_buffers: usize,
+    ) -> Self {
+        let entries = (0..num_buffers)
+            .map(|_| PreBindGroupLayoutEntry {
+                visibility: ShaderStages::COMPUTE,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Storage { read_only: false },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            })
+            .collect::<Vec<_>>();
+        let bind_group_layout = bind_group_util::create_bind_group_layout(device, &entries);
+        let layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_compute_pipeline(&ComputePipelineDescriptor {
+            label: None,
+            layout: Some(&layout),
+            module,
+            entry_point,
+        });
+        Self {
+            pipeline,
+            bind_group_layout,
+        }
+    }
+
+    /// Dispatches the kernel with the storage buffers and the numbers of the workgroups.
+    ///
+    /// The buffers must have the usage `BufferUsages::STORAGE`. The results are read by
+    /// [`BufferHandler::read_buffer`].
+    pub fn dispatch(
+        &self,
+        handler: &DeviceHandler,
+        buffers: &[&BufferHandler],
+        workgroups: (u32, u32, u32),
+    ) {
+        let (device, queue) = (handler.device(), handler.queue());
+        let bind_group = bind_group_util::create_bind_group(
+            device,
+            &self.bind_group_layout,
+            buffers.iter().map(|buffer| buffer.binding_resource()),
+        );
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        {
+            let mut cpass = encoder.begin_compute_pass(&ComputePassDescriptor { label: None });
+            cpass.set_pipeline(&self.pipeline);
+            cpass.set_bind_group(0, &bind_group, &[]);
+            cpass.dispatch_workgroups(workgroups.0, workgroups.1, workgroups.2);
+        }
+        queue.submit(Some(encoder.finish()));
+    }
+}
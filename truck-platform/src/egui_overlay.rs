@@ -0,0 +1,99 @@
+use crate::*;
+use egui_wgpu::renderer::{Renderer, ScreenDescriptor};
+
+/// Renderer of [`egui`] UI on top of [`Scene`].
+///
+/// The overlay shares the device and the queue with the scene, and renders the UI to the same
+/// texture after the scene is rendered. The input events of the window are handled by the
+/// integration of egui and winit, e.g. `egui-winit`, on the application side.
+pub struct EguiOverlay {
+    renderer: Renderer,
+}
+
+impl std::fmt::Debug for EguiOverlay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EguiOverlay").finish_non_exhaustive()
+    }
+}
+
+impl EguiOverlay {
+    /// Creates the overlay compatible with the render texture of `scene`.
+    #[inline(always)]
+    pub fn new(scene: &Scene) -> Self {
+        let format = scene.descriptor().render_texture.format;
+        Self {
+            renderer: Renderer::new(scene.device(), format, None, 1),
+        }
+    }
+
+    /// Renders the output of the UI frame to `view`.
+    ///
+    /// The contents of `view` are preserved, so this method is called after [`Scene::render`].
+    pub fn render(
+        &mut self,
+        scene: &Scene,
+        view: &TextureView,
+        context: &egui::Context,
+        output: egui::FullOutput,
+    ) {
+        let (device, queue) = (scene.device(), scene.queue());
+        let (width, height) = scene.descriptor().render_texture.canvas_size;
+        let screen_descriptor = ScreenDescriptor {
+            size_in_pixels: [width, height],
+            pixels_per_point: context.pixels_per_point(),
+        };
+        let paint_jobs = context.tessellate(output.shapes);
+        output
+            .textures_delta
+            .set
+            .iter()
+            .for_each(|(id, delta)| self.renderer.update_texture(device, queue, *id, delta));
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        let mut commands = self.renderer.update_buffers(
+            device,
+            queue,
+            &mut encoder,
+            &paint_jobs,
+            &screen_descriptor,
+        );
+        {
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                ..Default::default()
+            });
+            self.renderer.render(&mut rpass, &paint_jobs, &screen_descriptor);
+        }
+        commands.push(encoder.finish());
+        queue.submit(commands);
+        output
+            .textures_delta
+            .free
+            .iter()
+            .for_each(|id| self.renderer.free_texture(id));
+    }
+}
+
+impl WindowScene {
+    /// Renders the scene and the UI over it to the initializing window.
+    pub fn render_frame_with_egui(
+        &mut self,
+        overlay: &mut EguiOverlay,
+        context: &egui::Context,
+        output: egui::FullOutput,
+    ) {
+        let surface_texture = self.current_surface_texture();
+        let view = surface_texture
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        self.render(&view);
+        overlay.render(self, &view, context, output);
+        surface_texture.present();
+    }
+}
@@ -0,0 +1,355 @@
+use crate::*;
+use std::num::TryFromIntError;
+
+impl GBuffer {
+    /// the texture format of the normal vectors
+    pub const NORMAL_FORMAT: TextureFormat = TextureFormat::Rgba32Float;
+    /// the texture format of the linear depths
+    pub const DEPTH_FORMAT: TextureFormat = TextureFormat::R32Float;
+    /// the texture format of the render ids
+    pub const OBJECT_ID_FORMAT: TextureFormat = TextureFormat::R32Uint;
+
+    /// Returns the color targets of the pipelines writing the G-buffer.
+    #[inline(always)]
+    pub fn color_targets() -> [Option<ColorTargetState>; 3] {
+        let formats = [
+            Self::NORMAL_FORMAT,
+            Self::DEPTH_FORMAT,
+            Self::OBJECT_ID_FORMAT,
+        ];
+        formats.map(|format| {
+            Some(ColorTargetState {
+                format,
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            })
+        })
+    }
+
+    /// Returns the depth stencil state of the pipelines writing the G-buffer.
+    #[inline(always)]
+    pub fn depth_stencil_state() -> DepthStencilState {
+        DepthStencilState {
+            format: TextureFormat::Depth32Float,
+            depth_write_enabled: true,
+            depth_compare: CompareFunction::Less,
+            stencil: Default::default(),
+            bias: Default::default(),
+        }
+    }
+
+    fn new(device: &Device, size: (u32, u32)) -> Self {
+        let texture = |format: TextureFormat, usage: TextureUsages| {
+            device.create_texture(&TextureDescriptor {
+                size: Extent3d {
+                    width: size.0,
+                    height: size.1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format,
+                usage,
+                view_formats: &[],
+                label: None,
+            })
+        };
+        let attachment = TextureUsages::RENDER_ATTACHMENT;
+        let usage = attachment | TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_SRC;
+        Self {
+            normal: texture(Self::NORMAL_FORMAT, usage),
+            depth: texture(Self::DEPTH_FORMAT, usage),
+            object_id: texture(Self::OBJECT_ID_FORMAT, usage),
+            depth_stencil: texture(TextureFormat::Depth32Float, attachment),
+            size,
+        }
+    }
+
+    /// Returns the texture of the normal vectors.
+    #[inline(always)]
+    pub const fn normal(&self) -> &Texture { &self.normal }
+
+    /// Returns the texture of the linear depths.
+    #[inline(always)]
+    pub const fn depth(&self) -> &Texture { &self.depth }
+
+    /// Returns the texture of the render ids.
+    #[inline(always)]
+    pub const fn object_id(&self) -> &Texture { &self.object_id }
+
+    /// Returns the size of the textures.
+    #[inline(always)]
+    pub const fn size(&self) -> (u32, u32) { self.size }
+
+    /// Reads the normal vectors, row by row from the top-left pixel.
+    pub async fn read_normals(&self, device_handler: &DeviceHandler) -> Vec<[f32; 4]> {
+        let (width, height) = self.size;
+        let bytes = read_texture(device_handler, &self.normal, 16, (0, 0), (width, height)).await;
+        let floats: Vec<f32> = words(&bytes).map(f32::from_bits).collect();
+        floats.chunks(4).map(|v| [v[0], v[1], v[2], v[3]]).collect()
+    }
+
+    /// Reads the linear depths, row by row from the top-left pixel.
+    pub async fn read_depths(&self, device_handler: &DeviceHandler) -> Vec<f32> {
+        let (width, height) = self.size;
+        let bytes = read_texture(device_handler, &self.depth, 4, (0, 0), (width, height)).await;
+        words(&bytes).map(f32::from_bits).collect()
+    }
+
+    /// Reads the render ids, row by row from the top-left pixel.
+    pub async fn read_object_ids(&self, device_handler: &DeviceHandler) -> Vec<Option<RenderID>> {
+        let (width, height) = self.size;
+        let bytes = read_texture(device_handler, &self.object_id, 4, (0, 0), (width, height)).await;
+        words(&bytes).map(RenderID::from_object_id).collect()
+    }
+}
+
+/// the 32-bit words in the native endian
+#[inline(always)]
+pub(crate) fn words(bytes: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    bytes
+        .chunks_exact(4)
+        .map(|word| u32::from_ne_bytes([word[0], word[1], word[2], word[3]]))
+}
+
+impl RenderID {
+    /// the value written in the buffer of the render ids
+    ///
+    /// Returns `Err` if the render id plus one does not fit in `u32`.
+    #[inline(always)]
+    pub(crate) fn object_id(self) -> Result<u32, TryFromIntError> {
+        u32::try_from(self.0 as u128 + 1)
+    }
+
+    #[inline(always)]
+    pub(crate) const fn from_object_id(id: u32) -> Option<Self> {
+        match id {
+            0 => None,
+            _ => Some(RenderID(id as usize - 1)),
+        }
+    }
+}
+
+/// Reads the pixels of the rectangle `origin`..`origin + size` of `texture`.
+pub(crate) async fn read_texture(
+    device_handler: &DeviceHandler,
+    texture: &Texture,
+    bytes_per_pixel: u32,
+    origin: (u32, u32),
+    size: (u32, u32),
+) -> Vec<u8> {
+    let (device, queue) = (device_handler.device(), device_handler.queue());
+    let row_size = size.0 * bytes_per_pixel;
+    let bytes_per_row = aligned_row_size(row_size);
+    let buffer = device.create_buffer(&BufferDescriptor {
+        label: None,
+        mapped_at_creation: false,
+        usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+        size: (bytes_per_row * size.1) as u64,
+    });
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    encoder.copy_texture_to_buffer(
+        ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: Origin3d {
+                x: origin.0,
+                y: origin.1,
+                z: 0,
+            },
+            aspect: TextureAspect::All,
+        },
+        ImageCopyBuffer {
+            buffer: &buffer,
+            layout: ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(size.1),
+            },
+        },
+        Extent3d {
+            width: size.0,
+            height: size.1,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit(Some(encoder.finish()));
+    let bytes = buffer_handler::read_mapped_buffer(device, &buffer).await;
+    bytes
+        .chunks(bytes_per_row as usize)
+        .flat_map(|row| &row[..row_size as usize])
+        .copied()
+        .collect()
+}
+
+/// the size of the rows aligned to `COPY_BYTES_PER_ROW_ALIGNMENT`
+#[inline(always)]
+fn aligned_row_size(row_size: u32) -> u32 {
+    let align = COPY_BYTES_PER_ROW_ALIGNMENT;
+    (row_size + align - 1) / align * align
+}
+
+impl Scene {
+    /// the size of the uniform of the render id: `u32` padded to 16 bytes
+    pub(crate) const OBJECT_ID_SIZE: u64 = 16;
+
+    /// Writes the render id of `id` to the slot of the object in the ring of `set = 2`.
+    ///
+    /// The object whose render id does not fit in the texture of the ids gets no slot, and is
+    /// not rendered to the G-buffers.
+    #[inline(always)]
+    pub(crate) fn insert_object_id(&mut self, id: RenderID) {
+        if let Ok(object_id) = id.object_id() {
+            self.object_ids.get_mut().unwrap().insert(id, &[object_id, 0, 0, 0]);
+        }
+    }
+
+    /// Creates the G-buffer pipeline of `object` with the bind group layouts in `set = 0, 1, 2`.
+    pub(crate) fn gbuffer_pipeline_of<R: Rendered + ?Sized>(
+        device_handler: &DeviceHandler,
+        layouts: [&BindGroupLayout; 3],
+        object: &R,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        let device = device_handler.device();
+        let layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &layouts,
+            push_constant_ranges: &[],
+            label: None,
+        });
+        object.gbuffer_pipeline(device_handler, &layout, scene_desc)
+    }
+
+    /// Creates the G-buffer with the size of the canvas.
+    #[inline(always)]
+    pub fn create_gbuffer(&self) -> GBuffer {
+        GBuffer::new(self.device(), self.scene_desc.render_texture.canvas_size)
+    }
+
+    /// Renders the normals, the linear depths and the render ids of the objects to `gbuffer`.
+    ///
+    /// Only the visible objects whose [`Rendered::gbuffer_pipeline`] are given are rendered.
+    /// The size of `gbuffer` is assumed to be the same as the canvas.
+    /// # Examples
+    /// ```no_run
+    /// use truck_platform::*;
+    /// let scene = pollster::block_on(Scene::from_default_device(&Default::default()));
+    /// // add objects to the scene
+    /// let gbuffer = scene.create_gbuffer();
+    /// scene.render_gbuffer(&gbuffer);
+    /// let task = gbuffer.read_object_ids(scene.device_handler());
+    /// let ids = pollster::block_on(task);
+    /// ```
+    #[inline(always)]
+    pub fn render_gbuffer(&self, gbuffer: &GBuffer) { self.render_gbuffer_in(gbuffer, None) }
+
+    /// Renders the G-buffer in the rectangle `(x, y, width, height)`, or in the whole if `None`.
+    fn render_gbuffer_in(&self, gbuffer: &GBuffer, scissor: Option<(u32, u32, u32, u32)>) {
+        let bind_group = self.scene_bind_group();
+        // The render ids of the objects added since the last frame are uploaded at once.
+        let mut object_ids = self.object_ids.lock().unwrap();
+        object_ids.flush(&self.device_handler, &self.object_id_layout);
+        let views = [&gbuffer.normal, &gbuffer.depth, &gbuffer.object_id]
+            .map(|texture| texture.create_view(&Default::default()));
+        let depth_view = gbuffer.depth_stencil.create_view(&Default::default());
+        let clear_colors = [
+            Color::TRANSPARENT,
+            Color {
+                r: f32::MAX as f64,
+                ..Color::TRANSPARENT
+            },
+            Color::TRANSPARENT,
+        ];
+        let color_attachments: Vec<_> = views
+            .iter()
+            .zip(clear_colors)
+            .map(|(view, color)| {
+                Some(RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(color),
+                        store: true,
+                    },
+                })
+            })
+            .collect();
+        let mut encoder = self
+            .device()
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        self.record_pass(&mut encoder, "gbuffer", |encoder, statistics| {
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &color_attachments,
+                depth_stencil_attachment: Some(Self::depth_stencil_attachment_descriptor(
+                    &depth_view,
+                )),
+                ..Default::default()
+            });
+            if let Some((x, y, width, height)) = scissor {
+                rpass.set_scissor_rect(x, y, width, height);
+            }
+            rpass.set_bind_group(0, &bind_group, &[]);
+            for (id, object) in &self.objects {
+                let drawn = object.is_drawn(self.visible_layers);
+                // `flush` has created the bind groups of all the objects.
+                let object_id = object_ids.bind_group(*id).zip(object_ids.offset(*id));
+                let (pipeline, (object_id_group, offset)) =
+                    match (drawn, &object.gbuffer_pipeline, object_id) {
+                        (true, Some(pipeline), Some(object_id)) => (pipeline, object_id),
+                        _ => continue,
+                    };
+                rpass.set_pipeline(pipeline);
+                rpass.set_bind_group(1, &object.bind_group, &object.dynamic_offsets);
+                rpass.set_bind_group(2, object_id_group, &[offset]);
+                let vertex_count = object.draw(&mut rpass);
+                statistics.count_draw(object, vertex_count);
+            }
+        });
+        self.queue().submit(vec![encoder.finish()]);
+    }
+
+    /// Returns the render id of the object drawn at the pixel `(x, y)`, from the top-left of
+    /// the canvas.
+    ///
+    /// Renders only the pixel to the offscreen buffer of the render ids, and reads it back.
+    /// Returns `None` if no object is drawn at the pixel or the pixel is out of the canvas.
+    /// The objects without [`Rendered::gbuffer_pipeline`] cannot be picked.
+    /// # Examples
+    /// ```no_run
+    /// use truck_platform::*;
+    /// let scene = pollster::block_on(Scene::from_default_device(&Default::default()));
+    /// // add objects to the scene
+    /// match pollster::block_on(scene.pick(256, 256)) {
+    ///     Some(id) => println!("{id:?} is picked."),
+    ///     None => println!("nothing is picked."),
+    /// }
+    /// ```
+    pub async fn pick(&self, x: u32, y: u32) -> Option<RenderID> {
+        let size = self.scene_desc.render_texture.canvas_size;
+        if x >= size.0 || y >= size.1 {
+            return None;
+        }
+        // The buffer is reused unless the canvas is resized.
+        let cached = self.picking_buffer.lock().unwrap().take();
+        let gbuffer = match cached {
+            Some(gbuffer) if gbuffer.size == size => gbuffer,
+            _ => self.create_gbuffer(),
+        };
+        self.render_gbuffer_in(&gbuffer, Some((x, y, 1, 1)));
+        let task = read_texture(&self.device_handler, &gbuffer.object_id, 4, (x, y), (1, 1));
+        let bytes = task.await;
+        *self.picking_buffer.lock().unwrap() = Some(gbuffer);
+        words(&bytes).next().and_then(RenderID::from_object_id)
+    }
+}
+
+#[test]
+fn object_id_overflow() {
+    assert_eq!(RenderID(0).object_id(), Ok(1));
+    let last = RenderID(u32::MAX as usize - 1);
+    assert_eq!(last.object_id(), Ok(u32::MAX));
+    assert_eq!(RenderID::from_object_id(u32::MAX), Some(last));
+    assert!(RenderID(u32::MAX as usize).object_id().is_err());
+    assert!(RenderID(usize::MAX).object_id().is_err());
+}
@@ -0,0 +1,81 @@
+//! Hot reload of the shaders for the development.
+//!
+//! [`ShaderWatcher`] polls the modification times of the shader files, and
+//! [`create_wgsl_module`](crate::shader_util::create_wgsl_module) compiles the modified sources
+//! after validating them, so that a broken shader does not abort the application. The pipelines
+//! of the objects using the new modules are rebuilt by
+//! [`Scene::update_pipelines`](crate::Scene::update_pipelines) without re-adding the objects to
+//! the scene.
+//! # Examples
+//! ```no_run
+//! use truck_platform::{hot_reload::*, shader_util::*, *};
+//! let device_handler = pollster::block_on(DeviceHandler::default_device());
+//! let mut watcher = ShaderWatcher::new();
+//! watcher.watch("shaders/polygon.wgsl");
+//! // in the main loop
+//! if !watcher.poll().is_empty() {
+//!     let source = std::fs::read_to_string("shaders/polygon.wgsl").unwrap();
+//!     match create_wgsl_module(device_handler.device(), &source) {
+//!         // replace the module of the objects and call `Scene::update_pipelines`.
+//!         Ok(_module) => {}
+//!         // the scene keeps the previous pipelines.
+//!         Err(error) => eprintln!("{error}"),
+//!     }
+//! }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Watcher of the shader files by their modification times
+#[derive(Clone, Debug, Default)]
+pub struct ShaderWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+#[inline(always)]
+fn modified(path: &Path) -> Option<SystemTime> {
+    let meta = std::fs::metadata(path).ok()?;
+    meta.modified().ok()
+}
+
+impl ShaderWatcher {
+    /// Creates the watcher watching no files.
+    #[inline(always)]
+    pub const fn new() -> Self { Self { files: Vec::new() } }
+
+    /// Adds `path` to the watched files.
+    ///
+    /// The current state of the file is regarded as unchanged. Does nothing if `path` has
+    /// already been watched.
+    pub fn watch(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        if self.files.iter().all(|(p, _)| *p != path) {
+            let time = modified(&path);
+            self.files.push((path, time));
+        }
+    }
+
+    /// Returns the iterator on the watched files.
+    #[inline(always)]
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Returns the files modified, created or removed since the last poll.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        self.files
+            .iter_mut()
+            .filter_map(|(path, time)| {
+                let new_time = modified(path);
+                match new_time != *time {
+                    true => {
+                        *time = new_time;
+                        Some(path.clone())
+                    }
+                    false => None,
+                }
+            })
+            .collect()
+    }
+}
@@ -46,6 +46,7 @@ use bytemuck::{Pod, Zeroable};
 use derive_more::*;
 use std::sync::Arc;
 use truck_base::cgmath64::*;
+pub use naga;
 pub use wgpu;
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 use wgpu::*;
@@ -86,6 +87,86 @@ pub struct BufferHandler {
     stride: u64,
 }
 
+/// Pool of the buffers reused across frames
+///
+/// Released buffers are kept by their usages, and are overwritten by the queue instead of
+/// allocated again. It reduces the churn of the staging and the uniform buffers recreated every
+/// frame, and of the vertex buffers of the large scenes set up repeatedly.
+#[derive(Debug, Default)]
+pub struct BufferPool {
+    buffers: Vec<Buffer>,
+}
+
+/// Uniform buffer holding the fixed-size data of many objects in the slots bound by dynamic
+/// offsets
+///
+/// The objects share the buffers and the bind groups of the chunks of the slots, and the draw
+/// calls select their slots by the dynamic offsets given by [`UniformRing::offset`]. The data are
+/// written on the CPU and uploaded by [`UniformRing::flush`] at most once per frame, so the large
+/// scenes do not create a buffer and a bind group for each object. A new chunk is added when the
+/// slots run out, and the existing chunks are never reallocated, so the bind groups given by
+/// [`UniformRing::bind_group`] keep valid while the ring lives.
+#[derive(Debug)]
+pub struct UniformRing {
+    chunks: Vec<(Buffer, Arc<BindGroup>)>,
+    data: Vec<u8>,
+    slots: rustc_hash::FxHashMap<RenderID, u32>,
+    free: Vec<u32>,
+    element_size: u64,
+    stride: u64,
+    dirty: Option<std::ops::Range<usize>>,
+}
+
+/// The uniform ring shared by the objects on a device for their per-object data, e.g. the
+/// matrices and the materials of the instances
+///
+/// The slots are [`ObjectUniforms::ELEMENT_SIZE`] bytes, and the bind groups of the chunks
+/// consist of only the slot in `binding = 0`, whose layout is [`ObjectUniforms::layout`]. So the
+/// objects binding only their slots in `set = 1` share the bind groups of the chunks, and select
+/// their data by [`Rendered::dynamic_offsets`]. The ring is given by
+/// [`DeviceHandler::object_uniforms`].
+#[derive(Debug)]
+pub struct ObjectUniforms {
+    ring: std::sync::Mutex<UniformRing>,
+    layout: Arc<BindGroupLayout>,
+}
+
+/// Key of the cached render pipelines
+///
+/// Consists of the shader modules and their entry points, the entries of the bind group layout
+/// in `set = 1`, the other states of the pipeline chosen by the rendered object, and the
+/// configurations of the scene, i.e. the sample count, the texture format and the depth test.
+#[derive(Clone, Debug)]
+pub struct PipelineKey {
+    shaders: Vec<(Arc<ShaderModule>, String)>,
+    layout: Vec<BindGroupLayoutEntry>,
+    variant: u64,
+    sample_count: u32,
+    format: TextureFormat,
+    depth_test: bool,
+}
+
+/// Cache of the render pipelines shared by the objects on a device
+///
+/// Each device handler has its cache, and the implementations of [`Rendered::pipeline`] reuse
+/// the pipelines of the same key instead of creating them on every addition to the scene.
+/// The pipelines for the old configurations are dropped when the descriptor of a scene is changed,
+/// e.g. by resizing the window.
+#[derive(Debug, Default)]
+pub struct PipelineCache {
+    pipelines: std::sync::Mutex<rustc_hash::FxHashMap<PipelineKey, Arc<RenderPipeline>>>,
+}
+
+/// Compute pipeline with the layout of the storage buffers
+///
+/// The kernel can be dispatched by the device handler of [`DeviceHandler::compute_device`]
+/// without any scenes or windows.
+#[derive(Debug)]
+pub struct ComputeKernel {
+    pipeline: ComputePipeline,
+    bind_group_layout: BindGroupLayout,
+}
+
 /// Utility for [`BindGroupLayoutEntry`]
 ///
 /// The member variables of this struct are the ones of [`BindGroupLayoutEntry`]
@@ -138,7 +219,15 @@ pub struct RenderObject {
     pipeline: Arc<RenderPipeline>,
     bind_group_layout: Arc<BindGroupLayout>,
     bind_group: Arc<BindGroup>,
+    dynamic_offsets: Vec<u32>,
+    gbuffer_pipeline: Option<Arc<RenderPipeline>>,
+    topology: PrimitiveTopology,
+    // the visibility and the layers of the instance, synchronized by `Scene::update_bind_group`
     visible: bool,
+    layers: u32,
+    // the visibility and the layers set by the scene, kept through the synchronization
+    scene_visible: bool,
+    scene_layers: Option<u32>,
 }
 
 /// the projection type of camera
@@ -209,6 +298,8 @@ pub struct DeviceHandler {
     adapter: Arc<Adapter>,
     device: Arc<Device>,
     queue: Arc<Queue>,
+    pipeline_cache: Arc<PipelineCache>,
+    object_uniforms: Arc<ObjectUniforms>,
 }
 
 #[derive(Debug)]
@@ -232,6 +323,14 @@ struct WindowHandler {
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub struct RenderID(usize);
 
+/// The identifier of a group of the render objects in a scene
+///
+/// The groups are created by [`Scene::create_group`], and the visibility and the layers of the
+/// members are updated at once by [`Scene::set_group_visibility`] and
+/// [`Scene::set_group_layers`]. An object can belong to several groups.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub struct GroupID(usize);
+
 /// Configuration for studio to shoot the scene.
 #[derive(Debug, Clone)]
 pub struct StudioConfig {
@@ -282,6 +381,74 @@ pub struct WindowSceneDescriptor {
     pub backend_buffer: BackendBufferConfig,
 }
 
+/// The additional render targets of the scene written by [`Scene::render_gbuffer`].
+///
+/// The G-buffer consists of the following textures, whose sizes are the same as the canvas.
+/// - `normal`: the normal vectors in the world coordinate, `Rgba32Float`. The fourth component
+///   is `1.0` on the objects and `0.0` on the background.
+/// - `depth`: the linear depths, i.e. the distances from the camera along the view direction,
+///   `R32Float`. The depth of the background is `f32::MAX`.
+/// - `object_id`: the render ids of the objects plus one, `R32Uint`. The id of the background
+///   is `0`. The objects whose render ids plus one exceed `u32::MAX` are not rendered.
+///
+/// The objects are rendered by the pipelines given by [`Rendered::gbuffer_pipeline`]. The render
+/// ids of all the objects are held by one [`UniformRing`], and bound in `set = 2` with the
+/// dynamic offsets.
+/// # Shader Examples
+/// ```wgsl
+/// struct ObjectId {
+///     id: u32, // the render id plus one
+/// }
+///
+/// @group(2)
+/// @binding(0)
+/// var<uniform> object_id: ObjectId;
+///
+/// struct GBufferOutput {
+///     @location(0) normal: vec4<f32>,
+///     @location(1) depth: f32,
+///     @location(2) object_id: u32,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct GBuffer {
+    normal: Texture,
+    depth: Texture,
+    object_id: Texture,
+    depth_stencil: Texture,
+    size: (u32, u32),
+}
+
+/// The statistics of the passes rendered by [`Scene`]
+///
+/// The counts are accumulated by [`Scene::render`] and [`Scene::render_gbuffer`], and taken by
+/// [`Scene::take_statistics`]. The GPU durations are measured by the timestamp queries only if
+/// the device has `Features::TIMESTAMP_QUERY`, cf. [`Scene::gpu_timing_supported`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameStatistics {
+    /// the number of the render passes
+    pub passes: usize,
+    /// the number of the draw calls
+    pub draw_calls: usize,
+    /// the number of the drawn triangles
+    pub triangles: usize,
+    /// the number of the drawn line segments
+    pub lines: usize,
+    /// the number of the drawn points
+    pub points: usize,
+    /// the GPU durations of the passes in the order of the submissions
+    pub pass_timings: Vec<PassTiming>,
+}
+
+/// The GPU duration of a render pass
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PassTiming {
+    /// the name of the pass: `"render"` or `"gbuffer"`
+    pub name: &'static str,
+    /// the duration between the timestamps before and after the pass
+    pub duration: std::time::Duration,
+}
+
 /// Wraps `wgpu` and provides an intuitive graphics API.
 ///
 /// `Scene` is the most important in `truck-platform`.
@@ -292,10 +459,16 @@ pub struct Scene {
     device_handler: DeviceHandler,
     objects: SliceHashMap<RenderID, RenderObject>,
     bind_group_layout: BindGroupLayout,
+    object_id_layout: BindGroupLayout,
+    object_ids: std::sync::Mutex<UniformRing>,
     foward_depth: Option<Texture>,
     sampling_buffer: Option<Texture>,
     scene_desc: SceneDescriptor,
     clock: instant::Instant,
+    picking_buffer: std::sync::Mutex<Option<GBuffer>>,
+    visible_layers: u32,
+    groups: rustc_hash::FxHashMap<GroupID, Vec<RenderID>>,
+    statistics: std::sync::Mutex<statistics::StatisticsRecorder>,
 }
 
 /// Utility for wrapper
@@ -322,11 +495,22 @@ pub trait Rendered {
     /// Creates the bind group layout.
     fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout>;
     /// Creates the bind group in `set = 1`.
+    ///
+    /// The bind group may be shared by many objects, e.g. the one of a chunk of [`UniformRing`],
+    /// and then each object selects its data by [`Rendered::dynamic_offsets`].
     fn bind_group(
         &self,
         device_handler: &DeviceHandler,
         layout: &BindGroupLayout,
     ) -> Arc<BindGroup>;
+    /// Returns the dynamic offsets of the bind group in `set = 1`, in the order of the bindings
+    /// with `has_dynamic_offset`.
+    ///
+    /// This method is called after [`Rendered::bind_group`], both when the object is added to
+    /// the scene and by [`Scene::update_bind_group`]. The default implementation returns the
+    /// empty vector, i.e. the layout has no dynamic offsets.
+    #[inline(always)]
+    fn dynamic_offsets(&self) -> Vec<u32> { Vec::new() }
     /// Creates the render pipeline.
     fn pipeline(
         &self,
@@ -334,6 +518,41 @@ pub trait Rendered {
         layout: &PipelineLayout,
         scene_descriptor: &SceneDescriptor,
     ) -> Arc<RenderPipeline>;
+    /// Creates the render pipeline writing the G-buffer.
+    ///
+    /// The pipeline layout consists of the bind group layouts of the scene in `set = 0`, the
+    /// instance in `set = 1` and the render id in `set = 2`. The color targets, the depth state and
+    /// the multisample state are given by [`GBuffer::color_targets`],
+    /// [`GBuffer::depth_stencil_state`] and the default `MultisampleState`, respectively.
+    ///
+    /// The default implementation returns `None`, and then the object is not rendered in the
+    /// G-buffer.
+    #[inline(always)]
+    fn gbuffer_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Returns whether the object is drawn.
+    ///
+    /// The default implementation returns `true`.
+    #[inline(always)]
+    fn visible(&self) -> bool { true }
+    /// Returns the bit mask of the layers which the object belongs to.
+    ///
+    /// The object is drawn only if one of its layers is visible in the scene. The default
+    /// implementation returns `1`, i.e. the object belongs to the layer `0`.
+    #[inline(always)]
+    fn layers(&self) -> u32 { 1 }
+    /// Returns the primitive topology of the pipeline, by which the primitives are counted in
+    /// [`FrameStatistics`].
+    ///
+    /// The default implementation returns `PrimitiveTopology::TriangleList`.
+    #[inline(always)]
+    fn primitive_topology(&self) -> PrimitiveTopology { PrimitiveTopology::TriangleList }
     #[doc(hidden)]
     fn render_object(&self, scene: &Scene) -> RenderObject {
         let (vertex_buffer, index_buffer) = self.vertex_buffer(scene.device_handler());
@@ -347,25 +566,57 @@ pub trait Rendered {
                 label: None,
             });
         let pipeline = self.pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
+        let layouts = [
+            &scene.bind_group_layout,
+            &bind_group_layout,
+            &scene.object_id_layout,
+        ];
+        let gbuffer_pipeline =
+            Scene::gbuffer_pipeline_of(scene.device_handler(), layouts, self, &scene.scene_desc);
         RenderObject {
             vertex_buffer,
             index_buffer,
             bind_group_layout,
             bind_group,
+            dynamic_offsets: self.dynamic_offsets(),
             pipeline,
-            visible: true,
+            gbuffer_pipeline,
+            topology: self.primitive_topology(),
+            visible: self.visible(),
+            layers: self.layers(),
+            scene_visible: true,
+            scene_layers: None,
         }
     }
 }
 
+/// A GUI framework module providing MFC-like API.
+#[cfg(feature = "app")]
+pub mod app;
 mod buffer_handler;
 mod camera;
+mod compute;
+#[cfg(feature = "egui-overlay")]
+mod egui_overlay;
+#[cfg(feature = "egui-overlay")]
+pub use egui_overlay::EguiOverlay;
+#[cfg(feature = "egui-overlay")]
+pub use egui;
+#[cfg(feature = "egui-overlay")]
+pub use egui_wgpu;
+mod gbuffer;
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+pub mod hot_reload;
 mod light;
+mod pipeline_cache;
 #[doc(hidden)]
 pub mod rendered_macros;
 mod scene;
+pub mod shader_util;
 mod slice_hashmap;
 use slice_hashmap::SliceHashMap;
+mod statistics;
+mod uniform_ring;
 
 #[doc(hidden)]
 pub mod bind_group_util {
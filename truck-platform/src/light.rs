@@ -16,12 +16,15 @@ impl Light {
     /// and not used by [`Scene`](./struct.Scene.html).
     ///
     /// # Shader Example
-    /// ```glsl
-    /// layout(// binding info //) uniform Light {
-    ///     vec4 position;      // the position of light, position.w == 1.0
-    ///     vec4 color;         // the color of light, color.w == 1.0
-    ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
-    /// };
+    /// ```wgsl
+    /// struct Light {
+    ///     position: vec4<f32>,   // the position of light, position.w == 1.0
+    ///     color: vec4<f32>,      // the color of light, color.w == 1.0
+    ///     light_type: vec4<u32>, // Point => vec4<u32>(0u), Uniform => vec4<u32>(1u, 0u, 0u, 0u)
+    /// }
+    ///
+    /// // the group and the binding are given by the developer
+    /// var<uniform> light: Light;
     /// ```
     #[inline(always)]
     pub fn buffer(&self, device: &Device) -> BufferHandler {
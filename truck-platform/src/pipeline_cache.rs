@@ -0,0 +1,128 @@
+use crate::*;
+use std::hash::{Hash, Hasher};
+
+impl PipelineKey {
+    /// Creates the key of the pipeline.
+    ///
+    /// - `shaders`: the shader modules of the pipeline with their entry points, e.g. the vertex
+    ///   and the fragment ones,
+    /// - `layout`: the entries of the bind group layout in `set = 1`,
+    /// - `variant`: the other states of the pipeline defined by the object, e.g. the culling and
+    ///   the blending, encoded as an integer,
+    /// - `scene_desc`: the descriptor of the scene.
+    ///
+    /// The shader modules are identified by the addresses, i.e. the clones of the same `Arc`,
+    /// and the entry points by the names, so the pipelines of the different entry points in one
+    /// module are distinguished.
+    pub fn new<'a, T: IntoIterator<Item = &'a PreBindGroupLayoutEntry>>(
+        shaders: &[(&Arc<ShaderModule>, &str)],
+        layout: T,
+        variant: u64,
+        scene_desc: &SceneDescriptor,
+    ) -> Self {
+        let layout = layout
+            .into_iter()
+            .enumerate()
+            .map(|(i, e)| BindGroupLayoutEntry {
+                binding: i as u32,
+                visibility: e.visibility,
+                ty: e.ty,
+                count: e.count,
+            })
+            .collect();
+        Self {
+            shaders: shaders
+                .iter()
+                .map(|(shader, entry_point)| (Arc::clone(shader), entry_point.to_string()))
+                .collect(),
+            layout,
+            variant,
+            sample_count: scene_desc.backend_buffer.sample_count,
+            format: scene_desc.render_texture.format,
+            depth_test: scene_desc.backend_buffer.depth_test,
+        }
+    }
+
+    #[inline(always)]
+    fn is_compatible(&self, scene_desc: &SceneDescriptor) -> bool {
+        self.sample_count == scene_desc.backend_buffer.sample_count
+            && self.format == scene_desc.render_texture.format
+            && self.depth_test == scene_desc.backend_buffer.depth_test
+    }
+}
+
+impl PartialEq for PipelineKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.shaders.len() == other.shaders.len()
+            && self
+                .shaders
+                .iter()
+                .zip(&other.shaders)
+                .all(|(x, y)| Arc::ptr_eq(&x.0, &y.0) && x.1 == y.1)
+            && self.layout == other.layout
+            && self.variant == other.variant
+            && self.sample_count == other.sample_count
+            && self.format == other.format
+            && self.depth_test == other.depth_test
+    }
+}
+
+impl Eq for PipelineKey {}
+
+impl Hash for PipelineKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shaders.iter().for_each(|(shader, entry_point)| {
+            Arc::as_ptr(shader).hash(state);
+            entry_point.hash(state);
+        });
+        self.layout.hash(state);
+        self.variant.hash(state);
+        self.sample_count.hash(state);
+        self.format.hash(state);
+        self.depth_test.hash(state);
+    }
+}
+
+impl PipelineCache {
+    /// Returns the cached pipeline of `key`, or creates and caches it by `create`.
+    ///
+    /// The pipeline layout given to `create` must be compatible with the layout of `key`.
+    pub fn get_or_create<F: FnOnce() -> RenderPipeline>(
+        &self,
+        key: PipelineKey,
+        create: F,
+    ) -> Arc<RenderPipeline> {
+        let mut pipelines = self.pipelines.lock().unwrap();
+        let pipeline = pipelines.entry(key).or_insert_with(|| Arc::new(create()));
+        Arc::clone(pipeline)
+    }
+
+    /// Returns the number of the cached pipelines.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.pipelines.lock().unwrap().len() }
+
+    /// Returns whether there are no cached pipelines.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Drops all the cached pipelines.
+    #[inline(always)]
+    pub fn clear(&self) { self.pipelines.lock().unwrap().clear() }
+
+    /// Drops the pipelines whose shader modules are referred to only by the cache, e.g. the
+    /// modules replaced by the hot reload.
+    pub fn remove_unused(&self) {
+        let mut pipelines = self.pipelines.lock().unwrap();
+        pipelines.retain(|key, _| {
+            key.shaders
+                .iter()
+                .all(|(shader, _)| Arc::strong_count(shader) > 1)
+        });
+    }
+
+    /// Drops the pipelines incompatible with `scene_desc`.
+    pub(crate) fn retain_descriptor(&self, scene_desc: &SceneDescriptor) {
+        let mut pipelines = self.pipelines.lock().unwrap();
+        pipelines.retain(|key, _| key.is_compatible(scene_desc));
+    }
+}
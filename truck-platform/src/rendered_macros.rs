@@ -50,7 +50,8 @@ macro_rules! derive_bind_group_layout {
     };
 }
 
-/// Derives [`Rendred::bind_group()`](./trait.Rendered.html#tymethod.bind_group)
+/// Derives [`Rendred::bind_group()`](./trait.Rendered.html#tymethod.bind_group) and
+/// [`Rendred::dynamic_offsets()`](./trait.Rendered.html#method.dynamic_offsets)
 /// # Arguments
 /// `id_member`: the member variant of the super `Rendered` struct.
 #[macro_export]
@@ -65,6 +66,8 @@ macro_rules! derive_bind_group {
         {
             self.$($id_member)*.bind_group(device_handler, layout)
         }
+        #[inline(always)]
+        fn dynamic_offsets(&self) -> Vec<u32> { self.$($id_member)*.dynamic_offsets() }
     };
 }
 
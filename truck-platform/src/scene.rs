@@ -3,6 +3,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use winit::window::Window;
 
 static MAXID: AtomicUsize = AtomicUsize::new(0);
+static MAX_GROUP_ID: AtomicUsize = AtomicUsize::new(0);
 
 impl RenderID {
     /// Generate the unique `RenderID`.
@@ -10,6 +11,43 @@ impl RenderID {
     pub fn gen() -> Self { RenderID(MAXID.fetch_add(1, Ordering::SeqCst)) }
 }
 
+impl GroupID {
+    #[inline(always)]
+    fn gen() -> Self { GroupID(MAX_GROUP_ID.fetch_add(1, Ordering::SeqCst)) }
+}
+
+impl RenderObject {
+    /// Returns whether the object is drawn in the scene showing `visible_layers`.
+    ///
+    /// The object is drawn only if both the instance and the scene make it visible, and the
+    /// layers set by the scene precede the ones of the instance.
+    #[inline(always)]
+    pub(crate) fn is_drawn(&self, visible_layers: u32) -> bool {
+        let layers = self.scene_layers.unwrap_or(self.layers);
+        self.visible && self.scene_visible && layers & visible_layers != 0
+    }
+
+    /// Draws the vertices by the pipeline and the bind groups already set.
+    ///
+    /// Returns the number of the drawn vertices, or the indices for the indexed draw.
+    pub(crate) fn draw<'a>(&'a self, rpass: &mut RenderPass<'a>) -> u32 {
+        rpass.set_vertex_buffer(0, self.vertex_buffer.buffer.slice(..));
+        match self.index_buffer {
+            Some(ref index_buffer) => {
+                rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
+                rpass.draw_indexed(0..index_size, 0, 0..1);
+                index_size
+            }
+            None => {
+                let vertex_size = (self.vertex_buffer.size / self.vertex_buffer.stride) as u32;
+                rpass.draw(0..vertex_size, 0..1);
+                vertex_size
+            }
+        }
+    }
+}
+
 async fn init_default_device(
     window: Option<Arc<Window>>,
 ) -> (DeviceHandler, Option<WindowHandler>) {
@@ -33,56 +71,66 @@ async fn init_default_device(
         })
     };
 
-    let adapter = instance
+    #[cfg(not(feature = "webgl"))]
+    let power_preference = PowerPreference::HighPerformance;
+    #[cfg(feature = "webgl")]
+    let power_preference = PowerPreference::LowPower;
+    let adapter = request_adapter(&instance, power_preference, surface.as_ref()).await;
+
+    #[cfg(not(feature = "webgl"))]
+    let limits = Limits::downlevel_defaults().using_resolution(adapter.limits());
+    #[cfg(feature = "webgl")]
+    let limits = Limits::downlevel_webgl2_defaults();
+    let device_handler = request_device(adapter, limits).await;
+    let window_handler = window.map(|window| WindowHandler {
+        window,
+        surface: Arc::new(surface.unwrap()),
+    });
+    (device_handler, window_handler)
+}
+
+async fn request_adapter(
+    instance: &Instance,
+    power_preference: PowerPreference,
+    compatible_surface: Option<&Surface>,
+) -> Adapter {
+    instance
         .request_adapter(&RequestAdapterOptions {
-            #[cfg(not(feature = "webgl"))]
-            power_preference: PowerPreference::HighPerformance,
-            #[cfg(feature = "webgl")]
-            power_preference: PowerPreference::LowPower,
-            compatible_surface: surface.as_ref(),
+            power_preference,
+            compatible_surface,
             force_fallback_adapter: false,
         })
         .await
-        .expect("Failed to find an appropriate adapter");
+        .expect("Failed to find an appropriate adapter")
+}
 
+async fn request_device(adapter: Adapter, limits: Limits) -> DeviceHandler {
     let (device, queue) = adapter
         .request_device(
             &DeviceDescriptor {
-                features: Default::default(),
-                #[cfg(not(feature = "webgl"))]
-                limits: Limits::downlevel_defaults().using_resolution(adapter.limits()),
-                #[cfg(feature = "webgl")]
-                limits: Limits::downlevel_webgl2_defaults(),
+                // for `Scene::take_statistics`
+                features: adapter.features() & Features::TIMESTAMP_QUERY,
+                limits,
                 label: None,
             },
             None,
         )
         .await
         .expect("Failed to create device");
-    let device_handler = DeviceHandler {
-        adapter: Arc::new(adapter),
-        device: Arc::new(device),
-        queue: Arc::new(queue),
-    };
-    let window_handler = window.map(|window| WindowHandler {
-        window,
-        surface: Arc::new(surface.unwrap()),
-    });
-    (device_handler, window_handler)
+    DeviceHandler::new(Arc::new(adapter), Arc::new(device), Arc::new(queue))
 }
 
 impl DeviceHandler {
     /// constructor
     #[inline(always)]
-    pub const fn new(
-        adapter: Arc<Adapter>,
-        device: Arc<Device>,
-        queue: Arc<Queue>,
-    ) -> DeviceHandler {
+    pub fn new(adapter: Arc<Adapter>, device: Arc<Device>, queue: Arc<Queue>) -> DeviceHandler {
+        let object_uniforms = Arc::new(ObjectUniforms::new(&device));
         DeviceHandler {
             adapter,
             device,
             queue,
+            pipeline_cache: Default::default(),
+            object_uniforms,
         }
     }
     /// Returns the reference of the adapter.
@@ -94,9 +142,29 @@ impl DeviceHandler {
     /// Returns the reference of the queue.
     #[inline(always)]
     pub const fn queue(&self) -> &Arc<Queue> { &self.queue }
+    /// Returns the reference of the cache of the render pipelines.
+    #[inline(always)]
+    pub const fn pipeline_cache(&self) -> &Arc<PipelineCache> { &self.pipeline_cache }
+    /// Returns the reference of the uniform ring of the per-object data.
+    #[inline(always)]
+    pub const fn object_uniforms(&self) -> &Arc<ObjectUniforms> { &self.object_uniforms }
 
     /// Creates default device handler.
     pub async fn default_device() -> Self { init_default_device(None).await.0 }
+
+    /// Creates device handler for the compute shaders.
+    ///
+    /// The device is requested without any surfaces, with the best limits of the adapter.
+    /// WebGL is not supported since it has no compute shaders.
+    pub async fn compute_device() -> Self {
+        let instance = Instance::new(InstanceDescriptor {
+            backends: Backends::PRIMARY,
+            ..Default::default()
+        });
+        let adapter = request_adapter(&instance, PowerPreference::HighPerformance, None).await;
+        let limits = adapter.limits();
+        request_device(adapter, limits).await
+    }
 }
 
 impl Default for StudioConfig {
@@ -152,11 +220,15 @@ impl SceneDescriptor {
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
-    /// ```glsl
-    /// layout(set = 0, binding = 0) uniform Camera {
-    ///     mat4 camera_matrix;     // the camera matrix
-    ///     mat4 camera_projection; // the projection into the normalized view volume
-    /// };
+    /// ```wgsl
+    /// struct Camera {
+    ///     camera_matrix: mat4x4<f32>,     // the camera matrix
+    ///     camera_projection: mat4x4<f32>, // the projection into the normalized view volume
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(0)
+    /// var<uniform> camera: Camera;
     /// ```
     #[inline(always)]
     pub fn camera_buffer(&self, device: &Device) -> BufferHandler {
@@ -165,21 +237,25 @@ impl SceneDescriptor {
         self.studio.camera.buffer(as_rat, device)
     }
 
-    /// Creates a `STORAGE` buffer of all lights.
+    /// Creates a `UNIFORM` buffer of all lights.
     ///
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
-    /// ```glsl
+    /// ```wgsl
     /// struct Light {
-    ///     vec4 position;      // the position of light, position.w == 1.0
-    ///     vec4 color;         // the color of light, color.w == 1.0
-    ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
-    /// };
-    ///
-    /// layout(set = 0, binding = 1) buffer Lights {
-    ///     Light lights[];
-    /// };
+    ///     position: vec4<f32>,   // the position of light, position.w == 1.0
+    ///     color: vec4<f32>,      // the color of light, color.w == 1.0
+    ///     light_type: vec4<u32>, // Point => vec4<u32>(0u), Uniform => vec4<u32>(1u, 0u, 0u, 0u)
+    /// }
+    ///
+    /// struct Lights {
+    ///     lights: array<Light, 255>,
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(1)
+    /// var<uniform> lights: Lights;
     /// ```
     #[inline(always)]
     pub fn lights_buffer(&self, device: &Device) -> BufferHandler {
@@ -273,6 +349,8 @@ impl<'a> Drop for SceneDescriptorMut<'a> {
         let (forward_depth, sampling_buffer) = self.backend_buffers(self.0.device());
         self.0.foward_depth = forward_depth;
         self.0.sampling_buffer = sampling_buffer;
+        let cache = self.0.device_handler.pipeline_cache();
+        cache.retain_descriptor(&self.0.scene_desc);
     }
 }
 
@@ -336,12 +414,21 @@ impl Scene {
         let device = device_handler.device();
         let (foward_depth, sampling_buffer) = scene_desc.backend_buffers(device);
         let bind_group_layout = Self::init_scene_bind_group_layout(device);
+        let object_ids = UniformRing::new(device, Self::OBJECT_ID_SIZE);
+        let object_id_layout =
+            bind_group_util::create_bind_group_layout(device, &[object_ids.bgl_entry()]);
         Scene {
             objects: Default::default(),
             bind_group_layout,
+            object_id_layout,
+            object_ids: std::sync::Mutex::new(object_ids),
             foward_depth,
             sampling_buffer,
             clock: instant::Instant::now(),
+            picking_buffer: Default::default(),
+            visible_layers: u32::MAX,
+            groups: Default::default(),
+            statistics: std::sync::Mutex::new(statistics::StatisticsRecorder::new(device)),
             scene_desc: scene_desc.clone(),
             device_handler,
         }
@@ -423,30 +510,38 @@ impl Scene {
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
-    /// ```glsl
-    /// layout(set = 0, binding = 0) uniform Camera {
-    ///     mat4 camera_matrix;     // the camera matrix
-    ///     mat4 camera_projection; // the projection into the normalized view volume
-    /// };
+    /// ```wgsl
+    /// struct Camera {
+    ///     camera_matrix: mat4x4<f32>,     // the camera matrix
+    ///     camera_projection: mat4x4<f32>, // the projection into the normalized view volume
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(0)
+    /// var<uniform> camera: Camera;
     /// ```
     #[inline(always)]
     pub fn camera_buffer(&self) -> BufferHandler { self.scene_desc.camera_buffer(self.device()) }
 
-    /// Creates a `STORAGE` buffer of all lights.
+    /// Creates a `UNIFORM` buffer of all lights.
     ///
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
-    /// ```glsl
+    /// ```wgsl
     /// struct Light {
-    ///     vec4 position;      // the position of light, position.w == 1.0
-    ///     vec4 color;         // the color of light, color.w == 1.0
-    ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
-    /// };
-    ///
-    /// layout(set = 0, binding = 1) buffer Lights {
-    ///     Light lights[]; // the number of lights must be gotten from another place
-    /// };
+    ///     position: vec4<f32>,   // the position of light, position.w == 1.0
+    ///     color: vec4<f32>,      // the color of light, color.w == 1.0
+    ///     light_type: vec4<u32>, // Point => vec4<u32>(0u), Uniform => vec4<u32>(1u, 0u, 0u, 0u)
+    /// }
+    ///
+    /// struct Lights {
+    ///     lights: array<Light, 255>, // the number of lights must be gotten from another place
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(1)
+    /// var<uniform> lights: Lights;
     /// ```
     #[inline(always)]
     pub fn lights_buffer(&self) -> BufferHandler { self.scene_desc.lights_buffer(self.device()) }
@@ -456,12 +551,17 @@ impl Scene {
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
-    /// ```glsl
-    /// layout(set = 0, binding = 2) uniform Scene {
-    ///     vec4 bk_color;  // color of back ground
-    ///     float time;     // elapsed time since the scene was created.
-    ///     uint nlights;   // the number of lights
-    /// };
+    /// ```wgsl
+    /// struct SceneInfo {
+    ///     bk_color: vec4<f32>,   // color of back ground
+    ///     resolution: vec2<u32>, // the resolution of the canvas
+    ///     time: f32,             // elapsed time since the scene was created.
+    ///     nlights: u32,          // the number of lights
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(2)
+    /// var<uniform> info: SceneInfo;
     /// ```
     #[inline(always)]
     pub fn scene_status_buffer(&self) -> BufferHandler {
@@ -479,26 +579,40 @@ impl Scene {
     /// Creates bind group.
     /// # Shader Examples
     /// Suppose binded as `set = 0`.
-    /// ```glsl
-    /// layout(set = 0, binding = 0) uniform Camera {
-    ///     mat4 camera_matrix;     // the camera matrix
-    ///     mat4 camera_projection; // the projection into the normalized view volume
-    /// };
+    /// ```wgsl
+    /// struct Camera {
+    ///     camera_matrix: mat4x4<f32>,     // the camera matrix
+    ///     camera_projection: mat4x4<f32>, // the projection into the normalized view volume
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(0)
+    /// var<uniform> camera: Camera;
     ///
     /// struct Light {
-    ///     vec4 position;      // the position of light, position.w == 1.0
-    ///     vec4 color;         // the color of light, color.w == 1.0
-    ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
-    /// };
-    ///
-    /// layout(set = 0, binding = 1) buffer Lights {
-    ///     Light lights[];
-    /// };
-    ///
-    /// layout(set = 0, binding = 2) uniform Scene {
-    ///     float time;     // elapsed time since the scene was created.
-    ///     uint nlights;   // the number of lights
-    /// };
+    ///     position: vec4<f32>,   // the position of light, position.w == 1.0
+    ///     color: vec4<f32>,      // the color of light, color.w == 1.0
+    ///     light_type: vec4<u32>, // Point => vec4<u32>(0u), Uniform => vec4<u32>(1u, 0u, 0u, 0u)
+    /// }
+    ///
+    /// struct Lights {
+    ///     lights: array<Light, 255>,
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(1)
+    /// var<uniform> lights: Lights;
+    ///
+    /// struct SceneInfo {
+    ///     bk_color: vec4<f32>,   // color of back ground
+    ///     resolution: vec2<u32>, // the resolution of the canvas
+    ///     time: f32,             // elapsed time since the scene was created.
+    ///     nlights: u32,          // the number of lights
+    /// }
+    ///
+    /// @group(0)
+    /// @binding(2)
+    /// var<uniform> info: SceneInfo;
     /// ```
     #[inline(always)]
     pub fn scene_bind_group(&self) -> BindGroup {
@@ -520,20 +634,37 @@ impl Scene {
     #[inline(always)]
     pub fn add_object<R: Rendered>(&mut self, object: &R) -> bool {
         let render_object = object.render_object(self);
+        self.insert_object_id(object.render_id());
         self.objects
             .insert(object.render_id(), render_object)
             .is_none()
     }
-    /// Sets the visibility of a render object.
+    /// Sets the visibility of a render object in the scene.
     ///
+    /// The object is drawn only if both the scene and the object make it visible, so the
+    /// visibility set by the scene is kept through [`Scene::update_bind_group`].
     /// If there does not exist the render object in the scene, does nothing and returns `false`.
     #[inline(always)]
     pub fn set_visibility<R: Rendered>(&mut self, object: &R, visible: bool) -> bool {
         self.objects
             .get_mut(&object.render_id())
-            .map(|obj| obj.visible = visible)
+            .map(|obj| obj.scene_visible = visible)
             .is_some()
     }
+    /// Sets the visibility of the layers in the bit mask `layers`.
+    ///
+    /// The objects are drawn only if they belong to one of the visible layers. All layers are
+    /// visible by default.
+    #[inline(always)]
+    pub fn set_layer_visibility(&mut self, layers: u32, visible: bool) {
+        match visible {
+            true => self.visible_layers |= layers,
+            false => self.visible_layers &= !layers,
+        }
+    }
+    /// Returns the bit mask of the visible layers.
+    #[inline(always)]
+    pub const fn visible_layers(&self) -> u32 { self.visible_layers }
     /// Adds render objects to the scene.
     ///
     /// If there already exists a render object with the same ID,
@@ -551,7 +682,9 @@ impl Scene {
     /// If there does not exist the render object in the scene, does nothing and returns `false`.
     #[inline(always)]
     pub fn remove_object<R: Rendered>(&mut self, object: &R) -> bool {
-        self.objects.remove(&object.render_id()).is_some()
+        let id = object.render_id();
+        self.object_ids.get_mut().unwrap().remove(id);
+        self.objects.remove(&id).is_some()
     }
     /// Removes render objects from the scene.
     ///
@@ -566,8 +699,88 @@ impl Scene {
     }
 
     /// Removes all render objects from the scene.
+    ///
+    /// The groups are kept, and the objects added again belong to the groups.
     #[inline(always)]
-    pub fn clear_objects(&mut self) { self.objects.clear() }
+    pub fn clear_objects(&mut self) {
+        self.objects.clear();
+        self.object_ids.get_mut().unwrap().clear();
+    }
+
+    /// Creates a group of `objects`.
+    ///
+    /// The objects do not need to be in the scene. The members added to the scene later are
+    /// also updated by the group.
+    pub fn create_group<'a, R, I>(&mut self, objects: I) -> GroupID
+    where
+        R: 'a + Rendered,
+        I: IntoIterator<Item = &'a R>, {
+        let id = GroupID::gen();
+        let members = objects.into_iter().map(Rendered::render_id).collect();
+        self.groups.insert(id, members);
+        id
+    }
+    /// Adds `objects` to the group.
+    ///
+    /// If there does not exist the group in the scene, does nothing and returns `false`.
+    pub fn add_to_group<'a, R, I>(&mut self, group: GroupID, objects: I) -> bool
+    where
+        R: 'a + Rendered,
+        I: IntoIterator<Item = &'a R>, {
+        match self.groups.get_mut(&group) {
+            Some(members) => {
+                objects.into_iter().for_each(|object| {
+                    let id = object.render_id();
+                    if !members.contains(&id) {
+                        members.push(id);
+                    }
+                });
+                true
+            }
+            None => false,
+        }
+    }
+    /// Removes the group. The members are not removed from the scene.
+    ///
+    /// Returns the render ids of the members, or `None` if there does not exist the group.
+    #[inline(always)]
+    pub fn remove_group(&mut self, group: GroupID) -> Option<Vec<RenderID>> {
+        self.groups.remove(&group)
+    }
+    /// Returns the render ids of the members of the group.
+    #[inline(always)]
+    pub fn group_members(&self, group: GroupID) -> Option<&[RenderID]> {
+        self.groups.get(&group).map(Vec::as_slice)
+    }
+    /// Sets the visibility of all the members of the group in the scene, the same as
+    /// [`Scene::set_visibility`].
+    ///
+    /// Only the flags of the render objects are changed, and no buffers are updated.
+    /// If there does not exist the group, does nothing and returns `false`.
+    pub fn set_group_visibility(&mut self, group: GroupID, visible: bool) -> bool {
+        self.update_group(group, |object| object.scene_visible = visible)
+    }
+    /// Sets the bit mask of the layers of all the members of the group in the scene.
+    ///
+    /// The layers set by the scene precede the ones of the instances, and are kept through
+    /// [`Scene::update_bind_group`].
+    /// If there does not exist the group, does nothing and returns `false`.
+    pub fn set_group_layers(&mut self, group: GroupID, layers: u32) -> bool {
+        self.update_group(group, |object| object.scene_layers = Some(layers))
+    }
+    fn update_group(&mut self, group: GroupID, mut update: impl FnMut(&mut RenderObject)) -> bool {
+        let (groups, objects) = (&self.groups, &mut self.objects);
+        match groups.get(&group) {
+            Some(members) => {
+                members
+                    .iter()
+                    .filter_map(|id| objects.get_mut(id))
+                    .for_each(&mut update);
+                true
+            }
+            None => false,
+        }
+    }
 
     /// Returns the number of the render objects in the scene.
     #[inline(always)]
@@ -607,6 +820,9 @@ impl Scene {
     /// Synchronizes the information of bind group of `object` in the CPU memory
     /// and that in the GPU memory.
     ///
+    /// The visibility and the layers of `object` are also synchronized, and the ones set by the
+    /// scene are kept.
+    ///
     /// If there does not exist the render object in the scene, does nothing and returns false.
     #[inline(always)]
     pub fn update_bind_group<R: Rendered>(&mut self, object: &R) -> bool {
@@ -615,6 +831,9 @@ impl Scene {
             Some(render_object) => {
                 let bind_group = object.bind_group(handler, &render_object.bind_group_layout);
                 render_object.bind_group = bind_group;
+                render_object.dynamic_offsets = object.dynamic_offsets();
+                render_object.visible = object.visible();
+                render_object.layers = object.layers();
                 true
             }
             _ => false,
@@ -652,6 +871,14 @@ impl Scene {
                 });
                 render_object.pipeline =
                     object.pipeline(handler, &pipeline_layout, &self.scene_desc);
+                let layouts = [
+                    &self.bind_group_layout,
+                    &render_object.bind_group_layout,
+                    &self.object_id_layout,
+                ];
+                render_object.gbuffer_pipeline =
+                    Self::gbuffer_pipeline_of(handler, layouts, object, &self.scene_desc);
+                render_object.topology = object.primitive_topology();
                 true
             }
             _ => false,
@@ -670,7 +897,7 @@ impl Scene {
         objects.into_iter().fold(true, closure)
     }
     #[inline(always)]
-    fn depth_stencil_attachment_descriptor(
+    pub(crate) fn depth_stencil_attachment_descriptor(
         depth_view: &TextureView,
     ) -> RenderPassDepthStencilAttachment<'_> {
         RenderPassDepthStencilAttachment {
@@ -697,7 +924,7 @@ impl Scene {
         let mut encoder = self
             .device()
             .create_command_encoder(&CommandEncoderDescriptor { label: None });
-        {
+        self.record_pass(&mut encoder, "render", |encoder, statistics| {
             let (attachment, resolve_target) = match sampled_view.as_ref() {
                 Some(sampled_view) => (sampled_view, Some(view)),
                 None => (view, None),
@@ -718,26 +945,15 @@ impl Scene {
             });
             rpass.set_bind_group(0, &bind_group, &[]);
             for (_, object) in &self.objects {
-                if !object.visible {
+                if !object.is_drawn(self.visible_layers) {
                     continue;
                 }
                 rpass.set_pipeline(&object.pipeline);
-                rpass.set_bind_group(1, &object.bind_group, &[]);
-                rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
-                match object.index_buffer {
-                    Some(ref index_buffer) => {
-                        rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
-                        let index_size =
-                            index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
-                        rpass.draw_indexed(0..index_size, 0, 0..1);
-                    }
-                    None => rpass.draw(
-                        0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
-                        0..1,
-                    ),
-                }
+                rpass.set_bind_group(1, &object.bind_group, &object.dynamic_offsets);
+                let vertex_count = object.draw(&mut rpass);
+                statistics.count_draw(object, vertex_count);
             }
-        }
+        });
         self.queue().submit(vec![encoder.finish()]);
     }
 
@@ -779,15 +995,7 @@ impl Scene {
             },
         );
         queue.submit(Some(encoder.finish()));
-        let buffer_slice = buffer.slice(..);
-        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
-        buffer_slice.map_async(MapMode::Read, move |v| sender.send(v).unwrap());
-        device.poll(Maintain::Wait);
-        match receiver.receive().await {
-            Some(Ok(_)) => buffer_slice.get_mapped_range().iter().copied().collect(),
-            Some(Err(e)) => panic!("{}", e),
-            None => panic!("Asynchronous processing fails"),
-        }
+        buffer_handler::read_mapped_buffer(device, &buffer).await
     }
 }
 
@@ -837,9 +1045,18 @@ impl WindowScene {
     }
     /// Render scene to initializing window.
     pub fn render_frame(&mut self) {
+        let surface_texture = self.current_surface_texture();
+        let view = surface_texture
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        self.render(&view);
+        surface_texture.present();
+    }
+    /// Aligns the size of the buffers and acquires the next texture of the surface.
+    pub(crate) fn current_surface_texture(&mut self) -> SurfaceTexture {
         self.size_alignment();
         let surface = self.surface();
-        let surface_texture = match surface.get_current_texture() {
+        match surface.get_current_texture() {
             Ok(got) => got,
             Err(_) => {
                 let config = self
@@ -852,11 +1069,6 @@ impl WindowScene {
                     .get_current_texture()
                     .expect("Failed to acquire next surface texture!")
             }
-        };
-        let view = surface_texture
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
-        self.render(&view);
-        surface_texture.present();
+        }
     }
 }
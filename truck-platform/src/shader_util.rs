@@ -0,0 +1,76 @@
+//! Runtime compilation of the shaders by `naga`.
+//!
+//! All the shaders of truck are written in WGSL, which is accepted by all backends including
+//! WebGPU. The GLSL sources are also available with the feature `glsl`, by translating them to
+//! WGSL at runtime.
+
+use crate::*;
+use naga::valid::{Capabilities, ModuleInfo, ValidationFlags, Validator};
+use std::io::{Error, ErrorKind, Result};
+
+#[inline(always)]
+fn invalid_data(message: String) -> Error { Error::new(ErrorKind::InvalidData, message) }
+
+#[inline(always)]
+fn validate(module: &naga::Module) -> Result<ModuleInfo> {
+    Validator::new(ValidationFlags::all(), Capabilities::empty())
+        .validate(module)
+        .map_err(|error| invalid_data(format!("Validation Error: {error}")))
+}
+
+/// Creates the shader module from the WGSL source after the validation.
+///
+/// Returns the error of the kind [`ErrorKind::InvalidData`] with the message of the parser or the
+/// validator, instead of the panic in `wgpu`, if the source is invalid.
+pub fn create_wgsl_module(device: &Device, source: &str) -> Result<ShaderModule> {
+    let module = naga::front::wgsl::parse_str(source)
+        .map_err(|error| invalid_data(format!("WGSL Parse Error: {error}")))?;
+    validate(&module)?;
+    Ok(device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(source.into()),
+        label: None,
+    }))
+}
+
+/// Translates the GLSL source of `stage` into WGSL.
+///
+/// The entry point of the GLSL shader is `main`.
+/// # Examples
+/// ```
+/// use truck_platform::{naga::ShaderStage, shader_util};
+/// let glsl = "#version 450
+/// layout(location = 0) out vec4 color;
+/// void main() { color = vec4(1.0, 0.0, 0.0, 1.0); }
+/// ";
+/// let wgsl = shader_util::glsl_to_wgsl(glsl, ShaderStage::Fragment).unwrap();
+/// assert!(wgsl.contains("@fragment"));
+/// ```
+#[cfg(feature = "glsl")]
+pub fn glsl_to_wgsl(source: &str, stage: naga::ShaderStage) -> Result<String> {
+    use naga::{back::wgsl, front::glsl};
+    let module = glsl::Frontend::default()
+        .parse(&glsl::Options::from(stage), source)
+        .map_err(|errors| {
+            let messages: Vec<_> = errors.iter().map(ToString::to_string).collect();
+            invalid_data(format!("GLSL Parse Error: {}", messages.join("\n")))
+        })?;
+    let info = validate(&module)?;
+    wgsl::write_string(&module, &info, wgsl::WriterFlags::empty())
+        .map_err(|error| invalid_data(format!("WGSL Write Error: {error}")))
+}
+
+/// Creates the shader module from the GLSL source of `stage` via the translation into WGSL.
+///
+/// The entry point of the shader module is `main`.
+#[cfg(feature = "glsl")]
+pub fn create_glsl_module(
+    device: &Device,
+    source: &str,
+    stage: naga::ShaderStage,
+) -> Result<ShaderModule> {
+    let wgsl = glsl_to_wgsl(source, stage)?;
+    Ok(device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(wgsl.into()),
+        label: None,
+    }))
+}
@@ -0,0 +1,200 @@
+use crate::*;
+use std::time::Duration;
+
+/// the maximum number of the timed passes between the readings of the statistics
+const MAX_TIMED_PASSES: u32 = 64;
+
+impl FrameStatistics {
+    /// Counts the draw call of `vertex_count` vertices assembled by `topology`.
+    #[inline(always)]
+    fn count_draw(&mut self, topology: PrimitiveTopology, vertex_count: u32) {
+        let count = vertex_count as usize;
+        self.draw_calls += 1;
+        match topology {
+            PrimitiveTopology::PointList => self.points += count,
+            PrimitiveTopology::LineList => self.lines += count / 2,
+            PrimitiveTopology::LineStrip => self.lines += count.saturating_sub(1),
+            PrimitiveTopology::TriangleList => self.triangles += count / 3,
+            PrimitiveTopology::TriangleStrip => self.triangles += count.saturating_sub(2),
+        }
+    }
+
+    /// Returns the total GPU duration of the passes.
+    #[inline(always)]
+    pub fn gpu_time(&self) -> Duration { self.pass_timings.iter().map(|t| t.duration).sum() }
+}
+
+/// The timestamp queries written before and after the passes
+#[derive(Debug)]
+struct GpuTimer {
+    query_set: QuerySet,
+    resolve_buffer: Buffer,
+}
+
+/// The statistics accumulated in a scene, and the names of the timed passes
+#[derive(Debug)]
+pub(crate) struct StatisticsRecorder {
+    statistics: FrameStatistics,
+    timer: Option<GpuTimer>,
+    timed_passes: Vec<&'static str>,
+}
+
+impl StatisticsRecorder {
+    /// Creates the recorder, with the timestamp queries if the device supports them.
+    pub(crate) fn new(device: &Device) -> Self {
+        let supported = device.features().contains(Features::TIMESTAMP_QUERY);
+        let timer = supported.then(|| {
+            let count = 2 * MAX_TIMED_PASSES;
+            GpuTimer {
+                query_set: device.create_query_set(&QuerySetDescriptor {
+                    label: None,
+                    ty: QueryType::Timestamp,
+                    count,
+                }),
+                resolve_buffer: device.create_buffer(&BufferDescriptor {
+                    label: None,
+                    size: count as u64 * std::mem::size_of::<u64>() as u64,
+                    usage: BufferUsages::QUERY_RESOLVE | BufferUsages::COPY_SRC,
+                    mapped_at_creation: false,
+                }),
+            }
+        });
+        Self {
+            statistics: Default::default(),
+            timer,
+            timed_passes: Vec::new(),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn timing_supported(&self) -> bool { self.timer.is_some() }
+
+    /// Writes the timestamp before the pass `name`, and returns the index of the pass for
+    /// [`StatisticsRecorder::end_pass`]. The pass is not timed if the queries run out.
+    pub(crate) fn begin_pass(
+        &mut self,
+        encoder: &mut CommandEncoder,
+        name: &'static str,
+    ) -> Option<u32> {
+        self.statistics.passes += 1;
+        let idx = self.timed_passes.len() as u32;
+        match &self.timer {
+            Some(timer) if idx < MAX_TIMED_PASSES => {
+                encoder.write_timestamp(&timer.query_set, 2 * idx);
+                self.timed_passes.push(name);
+                Some(idx)
+            }
+            _ => None,
+        }
+    }
+
+    /// Writes the timestamp after the pass started by [`StatisticsRecorder::begin_pass`].
+    pub(crate) fn end_pass(&mut self, encoder: &mut CommandEncoder, pass: Option<u32>) {
+        if let (Some(timer), Some(idx)) = (&self.timer, pass) {
+            encoder.write_timestamp(&timer.query_set, 2 * idx + 1);
+        }
+    }
+
+    /// Counts the draw call of the render object.
+    #[inline(always)]
+    pub(crate) fn count_draw(&mut self, object: &RenderObject, vertex_count: u32) {
+        self.statistics.count_draw(object.topology, vertex_count)
+    }
+
+    /// Takes the statistics and the names of the timed passes, and copies their timestamps to
+    /// a mappable buffer.
+    pub(crate) fn take(
+        &mut self,
+        device_handler: &DeviceHandler,
+    ) -> (FrameStatistics, Vec<&'static str>, Option<Buffer>) {
+        let statistics = std::mem::take(&mut self.statistics);
+        let timed_passes = std::mem::take(&mut self.timed_passes);
+        let buffer = match (&self.timer, timed_passes.len() as u32) {
+            (Some(timer), len) if len > 0 => {
+                let (device, queue) = (device_handler.device(), device_handler.queue());
+                let size = 2 * len as u64 * std::mem::size_of::<u64>() as u64;
+                let buffer = device.create_buffer(&BufferDescriptor {
+                    label: None,
+                    size,
+                    usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+                    mapped_at_creation: false,
+                });
+                let descriptor = CommandEncoderDescriptor { label: None };
+                let mut encoder = device.create_command_encoder(&descriptor);
+                encoder.resolve_query_set(&timer.query_set, 0..2 * len, &timer.resolve_buffer, 0);
+                encoder.copy_buffer_to_buffer(&timer.resolve_buffer, 0, &buffer, 0, size);
+                queue.submit(Some(encoder.finish()));
+                Some(buffer)
+            }
+            _ => None,
+        };
+        (statistics, timed_passes, buffer)
+    }
+}
+
+impl Scene {
+    /// Returns whether the GPU durations of the passes are measured, i.e. the device has
+    /// `Features::TIMESTAMP_QUERY`.
+    ///
+    /// The default devices of [`DeviceHandler`] request the feature if the adapter supports it.
+    #[inline(always)]
+    pub fn gpu_timing_supported(&self) -> bool {
+        self.statistics.lock().unwrap().timing_supported()
+    }
+
+    /// Counts the draws and measures the GPU time of the pass recorded by `record`.
+    pub(crate) fn record_pass<F>(&self, encoder: &mut CommandEncoder, name: &'static str, record: F)
+    where F: FnOnce(&mut CommandEncoder, &mut StatisticsRecorder) {
+        let mut statistics = self.statistics.lock().unwrap();
+        let pass = statistics.begin_pass(encoder, name);
+        record(encoder, &mut statistics);
+        statistics.end_pass(encoder, pass);
+    }
+
+    /// Returns the statistics of the passes rendered since the last call, and resets them.
+    ///
+    /// The timestamps of the passes are read back from the GPU, so the previous passes are
+    /// completed.
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// let scene = pollster::block_on(Scene::from_default_device(&Default::default()));
+    /// let texture = scene.compatible_texture();
+    /// scene.render(&texture.create_view(&Default::default()));
+    ///
+    /// let statistics = pollster::block_on(scene.take_statistics());
+    /// assert_eq!(statistics.passes, 1);
+    /// assert_eq!(statistics.draw_calls, 0);
+    /// if scene.gpu_timing_supported() {
+    ///     assert_eq!(statistics.pass_timings[0].name, "render");
+    /// }
+    ///
+    /// // the statistics are reset.
+    /// let statistics = pollster::block_on(scene.take_statistics());
+    /// assert_eq!(statistics, FrameStatistics::default());
+    /// ```
+    pub async fn take_statistics(&self) -> FrameStatistics {
+        let taken = self.statistics.lock().unwrap().take(&self.device_handler);
+        let (mut statistics, timed_passes, buffer) = taken;
+        if let Some(buffer) = buffer {
+            let bytes = buffer_handler::read_mapped_buffer(self.device(), &buffer).await;
+            let timestamps: Vec<u64> = bytes
+                .chunks_exact(8)
+                .map(|t| u64::from_ne_bytes(t.try_into().unwrap()))
+                .collect();
+            let period = self.queue().get_timestamp_period() as f64;
+            statistics.pass_timings = timed_passes
+                .into_iter()
+                .zip(timestamps.chunks_exact(2))
+                .map(|(name, t)| {
+                    let nanos = t[1].saturating_sub(t[0]) as f64 * period;
+                    PassTiming {
+                        name,
+                        duration: Duration::from_nanos(nanos as u64),
+                    }
+                })
+                .collect();
+        }
+        statistics
+    }
+}
@@ -0,0 +1,298 @@
+use crate::*;
+
+impl UniformRing {
+    /// the number of the slots of each chunk
+    const CHUNK_CAPACITY: u64 = 256;
+
+    /// Creates an empty ring of the slots of `element_size` bytes.
+    ///
+    /// The slots are aligned to `min_uniform_buffer_offset_alignment` of the device.
+    /// # Panics
+    /// Panic occurs if `element_size` is zero.
+    pub fn new(device: &Device, element_size: u64) -> Self {
+        assert!(element_size > 0, "The element size must be positive.");
+        let align = device.limits().min_uniform_buffer_offset_alignment as u64;
+        Self {
+            chunks: Vec::new(),
+            data: Vec::new(),
+            slots: Default::default(),
+            free: Vec::new(),
+            element_size,
+            stride: (element_size + align - 1) / align * align,
+            dirty: None,
+        }
+    }
+
+    /// Returns the layout entry of the buffer, with the dynamic offset.
+    #[inline(always)]
+    pub fn bgl_entry(&self) -> PreBindGroupLayoutEntry {
+        PreBindGroupLayoutEntry {
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: BufferSize::new(self.element_size),
+            },
+            count: None,
+        }
+    }
+
+    /// Returns the size of the data of an object.
+    #[inline(always)]
+    pub const fn element_size(&self) -> u64 { self.element_size }
+
+    /// Returns the distance between the slots in the buffer.
+    #[inline(always)]
+    pub const fn stride(&self) -> u64 { self.stride }
+
+    /// Returns the number of the objects in the ring.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.slots.len() }
+
+    /// Returns whether the ring has no objects.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.slots.is_empty() }
+
+    /// Writes the data of the object with `id`, allocating a slot if it is new.
+    ///
+    /// The data are uploaded by the next [`UniformRing::flush`]. Returns `true` if the object
+    /// is new.
+    /// # Panics
+    /// Panic occurs if `data` is larger than the element size.
+    pub fn insert<T: Sized + Pod + Zeroable>(&mut self, id: RenderID, data: &T) -> bool {
+        let bytes = bytemuck::bytes_of(data);
+        assert!(
+            bytes.len() as u64 <= self.element_size,
+            "The data must be no larger than the element size."
+        );
+        let (slots, free, data) = (&mut self.slots, &mut self.free, &mut self.data);
+        let stride = self.stride as usize;
+        let mut is_new = false;
+        let slot = *slots.entry(id).or_insert_with(|| {
+            is_new = true;
+            free.pop().unwrap_or((data.len() / stride) as u32)
+        });
+        let start = slot as usize * stride;
+        if data.len() < start + stride {
+            data.resize(start + stride, 0);
+        }
+        data[start..start + bytes.len()].copy_from_slice(bytes);
+        data[start + bytes.len()..start + stride].fill(0);
+        self.dirty = Some(match self.dirty.take() {
+            Some(range) => usize::min(range.start, start)..usize::max(range.end, start + stride),
+            None => start..start + stride,
+        });
+        is_new
+    }
+
+    /// Releases the slot of the object with `id` for the later objects.
+    ///
+    /// Returns `false` if the object is not in the ring.
+    #[inline(always)]
+    pub fn remove(&mut self, id: RenderID) -> bool {
+        let slot = self.slots.remove(&id);
+        slot.map(|slot| self.free.push(slot)).is_some()
+    }
+
+    /// Removes all the objects.
+    ///
+    /// The chunks are kept for the later objects.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.free.clear();
+        self.data.clear();
+        self.dirty = None;
+    }
+
+    /// Returns the dynamic offset of the object with `id` in its chunk.
+    #[inline(always)]
+    pub fn offset(&self, id: RenderID) -> Option<u32> {
+        let slot = self.slots.get(&id);
+        slot.map(|slot| (*slot as u64 % Self::CHUNK_CAPACITY * self.stride) as u32)
+    }
+
+    /// Uploads the data written since the last flush by one write to the queue for each chunk.
+    ///
+    /// If the slots run out, the new chunks are added, and their bind groups are created with
+    /// `layout`, which must be compatible with [`UniformRing::bgl_entry`] in `binding = 0`.
+    pub fn flush(&mut self, device_handler: &DeviceHandler, layout: &BindGroupLayout) {
+        let (device, queue) = (device_handler.device(), device_handler.queue());
+        let chunk_size = Self::CHUNK_CAPACITY * self.stride;
+        let required = (self.data.len() as u64 + chunk_size - 1) / chunk_size;
+        while (self.chunks.len() as u64) < required {
+            let buffer = device.create_buffer(&BufferDescriptor {
+                label: None,
+                size: chunk_size,
+                usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let resource = BindingResource::Buffer(BufferBinding {
+                buffer: &buffer,
+                offset: 0,
+                size: BufferSize::new(self.element_size),
+            });
+            let bind_group = bind_group_util::create_bind_group(device, layout, [resource]);
+            self.chunks.push((buffer, Arc::new(bind_group)));
+        }
+        if let Some(range) = self.dirty.take() {
+            let chunk_size = chunk_size as usize;
+            let mut start = range.start;
+            while start < range.end {
+                let (chunk, local) = (start / chunk_size, start % chunk_size);
+                let end = usize::min(range.end, (chunk + 1) * chunk_size);
+                queue.write_buffer(&self.chunks[chunk].0, local as u64, &self.data[start..end]);
+                start = end;
+            }
+        }
+    }
+
+    /// Returns the bind group of the chunk of the object with `id`, created by the first
+    /// [`UniformRing::flush`] after the insertion.
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// let handler = pollster::block_on(DeviceHandler::default_device());
+    /// let mut ring = UniformRing::new(handler.device(), 16);
+    /// let entries = [ring.bgl_entry()];
+    /// let layout = bind_group_util::create_bind_group_layout(handler.device(), &entries);
+    ///
+    /// let ids: Vec<RenderID> = (0..1000).map(|_| RenderID::gen()).collect();
+    /// ids.iter().enumerate().for_each(|(i, id)| {
+    ///     assert!(ring.insert(*id, &[i as u32, 0, 0, 0]));
+    /// });
+    /// assert!(ring.bind_group(ids[0]).is_none());
+    ///
+    /// // one write for each chunk of the objects
+    /// ring.flush(&handler, &layout);
+    /// let bind_group = ring.bind_group(ids[0]).unwrap().clone();
+    /// assert_eq!(ring.offset(ids[1]), Some(ring.stride() as u32));
+    ///
+    /// // the released slot is reused, and the bind group is kept.
+    /// assert!(ring.remove(ids[1]));
+    /// let id = RenderID::gen();
+    /// ring.insert(id, &[1000_u32, 0, 0, 0]);
+    /// ring.flush(&handler, &layout);
+    /// assert_eq!(ring.offset(id), Some(ring.stride() as u32));
+    /// assert!(std::sync::Arc::ptr_eq(ring.bind_group(id).unwrap(), &bind_group));
+    /// ```
+    #[inline(always)]
+    pub fn bind_group(&self, id: RenderID) -> Option<&Arc<BindGroup>> {
+        let (_, bind_group) = self.chunk(id)?;
+        Some(bind_group)
+    }
+
+    /// Returns the binding of the slot of the object with `id` for the bind groups with the
+    /// other resources. The slot is selected by the dynamic offset [`UniformRing::offset`].
+    ///
+    /// The binding is available after the first [`UniformRing::flush`] after the insertion.
+    #[inline(always)]
+    pub fn binding_resource(&self, id: RenderID) -> Option<BindingResource<'_>> {
+        let (buffer, _) = self.chunk(id)?;
+        Some(BindingResource::Buffer(BufferBinding {
+            buffer,
+            offset: 0,
+            size: BufferSize::new(self.element_size),
+        }))
+    }
+
+    #[inline(always)]
+    fn chunk(&self, id: RenderID) -> Option<&(Buffer, Arc<BindGroup>)> {
+        let slot = *self.slots.get(&id)? as u64;
+        self.chunks.get((slot / Self::CHUNK_CAPACITY) as usize)
+    }
+}
+
+impl ObjectUniforms {
+    /// the size of the slots in bytes
+    pub const ELEMENT_SIZE: u64 = 256;
+
+    pub(crate) fn new(device: &Device) -> Self {
+        let ring = UniformRing::new(device, Self::ELEMENT_SIZE);
+        let layout = bind_group_util::create_bind_group_layout(device, &[ring.bgl_entry()]);
+        Self {
+            ring: std::sync::Mutex::new(ring),
+            layout: Arc::new(layout),
+        }
+    }
+
+    /// Returns the layout of the bind groups of the chunks.
+    #[inline(always)]
+    pub const fn layout(&self) -> &Arc<BindGroupLayout> { &self.layout }
+
+    /// Returns the layout entry of the slots, with the dynamic offset.
+    #[inline(always)]
+    pub fn bgl_entry(&self) -> PreBindGroupLayoutEntry { self.ring.lock().unwrap().bgl_entry() }
+
+    /// Writes `data` to the slot of the object with `id`, allocating a slot if it is new, and
+    /// uploads it.
+    ///
+    /// Returns the bind group of the chunk of the slot and the dynamic offset.
+    /// # Panics
+    /// Panic occurs if `data` is larger than [`ObjectUniforms::ELEMENT_SIZE`].
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// let handler = pollster::block_on(DeviceHandler::default_device());
+    /// let uniforms = handler.object_uniforms();
+    ///
+    /// let ids: Vec<RenderID> = (0..1000).map(|_| RenderID::gen()).collect();
+    /// let slots: Vec<_> = ids
+    ///     .iter()
+    ///     .map(|id| uniforms.write(&handler, *id, &[1.0_f32; 16]))
+    ///     .collect();
+    ///
+    /// // The objects in the same chunk share the bind group.
+    /// assert!(std::sync::Arc::ptr_eq(&slots[0].0, &slots[1].0));
+    /// assert_ne!(slots[0].1, slots[1].1);
+    ///
+    /// // The slot is kept by the rewriting.
+    /// let slot = uniforms.write(&handler, ids[1], &[2.0_f32; 16]);
+    /// assert_eq!(slot.1, slots[1].1);
+    /// assert!(uniforms.remove(ids[1]));
+    /// assert!(uniforms.slot(ids[1]).is_none());
+    /// ```
+    pub fn write<T: Sized + Pod + Zeroable>(
+        &self,
+        device_handler: &DeviceHandler,
+        id: RenderID,
+        data: &T,
+    ) -> (Arc<BindGroup>, u32) {
+        let mut ring = self.ring.lock().unwrap();
+        ring.insert(id, data);
+        ring.flush(device_handler, &self.layout);
+        // `flush` has created the chunk of the slot.
+        let bind_group = Arc::clone(ring.bind_group(id).unwrap());
+        (bind_group, ring.offset(id).unwrap())
+    }
+
+    /// Returns the bind group of the chunk and the dynamic offset of the object with `id`.
+    #[inline(always)]
+    pub fn slot(&self, id: RenderID) -> Option<(Arc<BindGroup>, u32)> {
+        let ring = self.ring.lock().unwrap();
+        Some((Arc::clone(ring.bind_group(id)?), ring.offset(id)?))
+    }
+
+    /// Creates the bind group whose `binding = 0` is the slot of the object with `id`, and the
+    /// following bindings are `resources`.
+    ///
+    /// Returns `None` if the slot has not been written.
+    pub fn create_bind_group<'a>(
+        &self,
+        device: &Device,
+        id: RenderID,
+        layout: &BindGroupLayout,
+        resources: impl IntoIterator<Item = BindingResource<'a>>,
+    ) -> Option<BindGroup> {
+        let ring = self.ring.lock().unwrap();
+        let mut entries = vec![ring.binding_resource(id)?];
+        resources.into_iter().for_each(|resource| entries.push(resource));
+        Some(bind_group_util::create_bind_group(device, layout, entries))
+    }
+
+    /// Releases the slot of the object with `id` for the later objects.
+    ///
+    /// Returns `false` if the object has no slot.
+    #[inline(always)]
+    pub fn remove(&self, id: RenderID) -> bool { self.ring.lock().unwrap().remove(id) }
+}
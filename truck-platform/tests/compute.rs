@@ -0,0 +1,29 @@
+mod common;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_compute_test(backend: Backends, _: &str) {
+    let handler = common::init_device(backend);
+    let device = handler.device();
+    let module = device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(include_str!("shaders/double.wgsl").into()),
+        label: None,
+    });
+    let kernel = ComputeKernel::new(device, &module, "main", 1);
+    let values = (0..100).map(|i| i as f32).collect::<Vec<_>>();
+    let buffer = BufferHandler::from_slice(
+        &values,
+        device,
+        BufferUsages::STORAGE | BufferUsages::COPY_SRC,
+    );
+    kernel.dispatch(&handler, &[&buffer], (2, 1, 1));
+    let res = pollster::block_on(buffer.read_buffer(&handler));
+    let res: &[f32] = bytemuck::cast_slice(&res);
+    assert_eq!(res.len(), values.len());
+    res.iter()
+        .zip(&values)
+        .for_each(|(x, y)| assert_eq!(*x, 2.0 * *y));
+}
+
+#[test]
+fn compute_test() { common::os_alt_exec_test(exec_compute_test); }
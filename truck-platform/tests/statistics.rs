@@ -0,0 +1,30 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_statistics_test(backend: Backends, _: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(handler, &Default::default());
+    let plane = new_plane!("shaders/trapezoid.wgsl", "vs_main", "fs_main");
+    common::render_one(&mut scene, &plane);
+    let statistics = pollster::block_on(scene.take_statistics());
+    assert_eq!(statistics.passes, 1);
+    assert_eq!(statistics.draw_calls, 1);
+    assert_eq!(statistics.triangles, 2);
+    assert_eq!(statistics.lines, 0);
+    // `common::init_device` does not request the timestamp queries.
+    assert!(!scene.gpu_timing_supported());
+    assert!(statistics.pass_timings.is_empty());
+
+    scene.add_object(&plane);
+    scene.set_visibility(&plane, false);
+    let texture = scene.compatible_texture();
+    scene.render(&texture.create_view(&Default::default()));
+    let statistics = pollster::block_on(scene.take_statistics());
+    assert_eq!(statistics.passes, 1);
+    assert_eq!(statistics.draw_calls, 0);
+}
+
+#[test]
+fn statistics_test() { common::os_alt_exec_test(exec_statistics_test); }
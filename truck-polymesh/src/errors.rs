@@ -80,6 +80,32 @@ pub enum Error<V: std::fmt::Debug = crate::StandardVertex> {
     /// ```
     #[error("This division vector is unsorted.")]
     UnsortedDivision,
+    /// The polygon mesh cannot be converted into `StructuredMesh`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use errors::Error;
+    ///
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// ];
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    ///
+    /// match StructuredMesh::try_from(&mesh) {
+    ///     Err(Error::NotStructuredMesh) => {}
+    ///     _ => panic!("wrong result!"),
+    /// }
+    /// ```
+    #[error("This polygon mesh is not a structured grid of quadrangles.")]
+    NotStructuredMesh,
     /// Errors caused by obj files I/O.
     #[error(transparent)]
     FromIO(#[from] std::io::Error),
@@ -0,0 +1,224 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// The half-edge structure of the connectivity of a polygon mesh
+///
+/// Each face of the mesh is a loop of the half-edges, and each half-edge starts from a vertex of
+/// the face. The twin of a half-edge is the half-edge of the adjacent face in the opposite
+/// direction. The half-edges on the boundaries and on the non-manifold edges, shared by more than
+/// two faces or by two faces with the inconsistent orientations, have no twins.
+///
+/// The faces are indexed in the order of [`PolygonMesh::face_iter`], and the vertices are
+/// indexed by the positions of the mesh. The structure is converted back to the mesh by
+/// [`From`], keeping the attributes and the faces.
+/// # Examples
+/// ```
+/// use truck_polymesh::{half_edge::HalfEdgeMesh, *};
+///
+/// // the square divided into the four triangles around the center
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.5, 0.5, 0.0),
+/// ];
+/// let faces = Faces::from_iter([[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+///
+/// let mut half_edges = HalfEdgeMesh::from(&mesh);
+/// assert_eq!(half_edges.half_edge_count(), 12);
+/// assert_eq!(half_edges.adjacent_vertices(4).len(), 4);
+/// assert_eq!(half_edges.adjacent_vertices(0).len(), 3);
+///
+/// // moves the center to the average of the neighbors.
+/// let neighbors = half_edges.adjacent_vertices(4);
+/// let sum = neighbors.iter().fold(Vector3::zero(), |sum, i| {
+///     sum + half_edges.positions()[*i].to_vec()
+/// });
+/// half_edges.positions_mut()[4] = Point3::from_vec(sum / 4.0);
+/// let mesh = PolygonMesh::from(half_edges);
+/// assert_eq!(mesh.positions()[4], Point3::new(0.5, 0.5, 0.0));
+/// assert_eq!(mesh.faces().len(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HalfEdgeMesh {
+    attributes: StandardAttributes,
+    // the vertices of the faces at the origins of the half-edges
+    corners: Vec<StandardVertex>,
+    next: Vec<usize>,
+    twins: Vec<Option<usize>>,
+    faces: Vec<usize>,
+    // the first half-edge of each face
+    face_edges: Vec<usize>,
+    // an outgoing half-edge of each vertex, one without twin if it exists
+    vertex_edges: Vec<Option<usize>>,
+}
+
+impl HalfEdgeMesh {
+    /// Returns the attributes of the mesh.
+    #[inline(always)]
+    pub const fn attributes(&self) -> &StandardAttributes { &self.attributes }
+
+    /// Returns the positions of the vertices.
+    #[inline(always)]
+    pub const fn positions(&self) -> &Vec<Point3> { &self.attributes.positions }
+
+    /// Returns the mutable slice of the positions of the vertices, e.g. for the smoothing.
+    #[inline(always)]
+    pub fn positions_mut(&mut self) -> &mut [Point3] { &mut self.attributes.positions }
+
+    /// Returns the number of the vertices, i.e. the positions.
+    #[inline(always)]
+    pub fn vertex_count(&self) -> usize { self.attributes.positions.len() }
+
+    /// Returns the number of the half-edges.
+    #[inline(always)]
+    pub fn half_edge_count(&self) -> usize { self.corners.len() }
+
+    /// Returns the number of the faces.
+    #[inline(always)]
+    pub fn face_count(&self) -> usize { self.face_edges.len() }
+
+    /// Returns the vertex of the face at the origin of the half-edge `edge`.
+    #[inline(always)]
+    pub fn corner(&self, edge: usize) -> StandardVertex { self.corners[edge] }
+
+    /// Returns the vertex at the origin of the half-edge `edge`.
+    #[inline(always)]
+    pub fn origin(&self, edge: usize) -> usize { self.corners[edge].pos }
+
+    /// Returns the vertex at the end of the half-edge `edge`.
+    #[inline(always)]
+    pub fn target(&self, edge: usize) -> usize { self.corners[self.next[edge]].pos }
+
+    /// Returns the next half-edge of `edge` in its face.
+    #[inline(always)]
+    pub fn next(&self, edge: usize) -> usize { self.next[edge] }
+
+    /// Returns the previous half-edge of `edge` in its face.
+    pub fn prev(&self, edge: usize) -> usize {
+        let mut prev = edge;
+        while self.next[prev] != edge {
+            prev = self.next[prev];
+        }
+        prev
+    }
+
+    /// Returns the twin of the half-edge `edge`, or `None` if `edge` is on the boundary or
+    /// non-manifold.
+    #[inline(always)]
+    pub fn twin(&self, edge: usize) -> Option<usize> { self.twins[edge] }
+
+    /// Returns the face of the half-edge `edge`.
+    #[inline(always)]
+    pub fn face(&self, edge: usize) -> usize { self.faces[edge] }
+
+    /// Returns `true` if the half-edge `edge` has no twin.
+    #[inline(always)]
+    pub fn is_boundary(&self, edge: usize) -> bool { self.twins[edge].is_none() }
+
+    /// Returns an outgoing half-edge of the vertex `vertex`, which has no twin if the vertex is
+    /// on the boundary. Returns `None` if the vertex is not used by the faces.
+    #[inline(always)]
+    pub fn outgoing(&self, vertex: usize) -> Option<usize> { self.vertex_edges[vertex] }
+
+    /// Returns the iterator over the half-edges of the face `face` in its order.
+    pub fn face_loop(&self, face: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.face_edges[face];
+        let next = move |edge: &usize| Some(self.next[*edge]).filter(|edge| *edge != first);
+        std::iter::successors(Some(first), next)
+    }
+
+    /// Returns the iterator over the outgoing half-edges of the vertex `vertex`, rotating in the
+    /// orientation of the faces from [`HalfEdgeMesh::outgoing`].
+    ///
+    /// The half-edges of the faces around the vertex are iterated until the boundary, so only
+    /// one fan of the faces is iterated around the non-manifold vertices.
+    pub fn vertex_ring(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.vertex_edges[vertex];
+        let next = move |edge: &usize| {
+            let edge = self.twins[self.prev(*edge)]?;
+            Some(edge).filter(|edge| Some(*edge) != first)
+        };
+        std::iter::successors(first, next)
+    }
+
+    /// Returns the vertices adjacent to the vertex `vertex` by the edges in the order of
+    /// [`HalfEdgeMesh::vertex_ring`]. The last one of a boundary vertex is the origin of the
+    /// incoming boundary half-edge.
+    pub fn adjacent_vertices(&self, vertex: usize) -> Vec<usize> {
+        let ring: Vec<usize> = self.vertex_ring(vertex).collect();
+        let mut vertices: Vec<usize> = ring.iter().map(|edge| self.target(*edge)).collect();
+        if let Some(last) = ring.last() {
+            let prev = self.prev(*last);
+            if self.twins[prev].is_none() {
+                vertices.push(self.origin(prev));
+            }
+        }
+        vertices
+    }
+}
+
+impl From<&PolygonMesh> for HalfEdgeMesh {
+    fn from(mesh: &PolygonMesh) -> Self {
+        let (mut corners, mut next, mut faces) = (Vec::new(), Vec::new(), Vec::new());
+        let mut face_edges = Vec::new();
+        mesh.face_iter().enumerate().for_each(|(i, face)| {
+            let first = corners.len();
+            face_edges.push(first);
+            face.iter().enumerate().for_each(|(j, v)| {
+                corners.push(*v);
+                next.push(first + (j + 1) % face.len());
+                faces.push(i);
+            });
+        });
+        let mut directed = HashMap::<[usize; 2], Vec<usize>>::default();
+        (0..corners.len()).for_each(|edge| {
+            let key = [corners[edge].pos, corners[next[edge]].pos];
+            directed.entry(key).or_default().push(edge);
+        });
+        let twins = (0..corners.len())
+            .map(|edge| {
+                let [i, j] = [corners[edge].pos, corners[next[edge]].pos];
+                match (directed.get(&[i, j]), directed.get(&[j, i])) {
+                    (Some(a), Some(b)) if a.len() == 1 && b.len() == 1 && i != j => Some(b[0]),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut vertex_edges = vec![None; mesh.positions().len()];
+        (0..corners.len()).for_each(|edge| {
+            let vertex_edge = &mut vertex_edges[corners[edge].pos];
+            if vertex_edge.is_none() || twins[edge].is_none() {
+                *vertex_edge = Some(edge);
+            }
+        });
+        Self {
+            attributes: mesh.attributes().clone(),
+            corners,
+            next,
+            twins,
+            faces,
+            face_edges,
+            vertex_edges,
+        }
+    }
+}
+
+impl From<HalfEdgeMesh> for PolygonMesh {
+    fn from(mesh: HalfEdgeMesh) -> Self {
+        let faces = (0..mesh.face_count()).map(|face| {
+            let face = mesh.face_loop(face).map(|edge| mesh.corners[edge]);
+            face.collect::<Vec<_>>()
+        });
+        let faces = Faces::from_iter(faces);
+        PolygonMesh::new(mesh.attributes, faces)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::*;
+use std::io::{BufReader, Read};
+type Result<T> = std::result::Result<T, errors::Error>;
+
+const MIN_HEADER_SIZE: usize = 227;
+
+fn syntax_error(message: &str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string()).into()
+}
+
+macro_rules! le_value {
+    ($bytes: expr, $ty: ty, $offset: expr) => {{
+        const SIZE: usize = std::mem::size_of::<$ty>();
+        let mut buf = [0u8; SIZE];
+        buf.copy_from_slice(&$bytes[$offset..$offset + SIZE]);
+        <$ty>::from_le_bytes(buf)
+    }};
+}
+
+/// The public header block of LAS file.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LasHeader {
+    /// the version of the format: (major, minor)
+    pub version: (u8, u8),
+    /// the point data record format
+    pub point_format: u8,
+    /// the length of each point data record
+    pub point_record_length: usize,
+    /// the number of the point records
+    pub number_of_points: usize,
+    /// the scale factors of coordinates
+    pub scale: Vector3,
+    /// the offsets of coordinates
+    pub offset: Vector3,
+}
+
+impl LasHeader {
+    /// the byte offset of RGB in each point data record
+    fn color_offset(&self) -> Option<usize> {
+        match self.point_format {
+            2 => Some(20),
+            3 | 5 => Some(28),
+            7 | 8 | 10 => Some(30),
+            _ => None,
+        }
+    }
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<(LasHeader, usize)> {
+    let mut bytes = vec![0u8; MIN_HEADER_SIZE];
+    reader.read_exact(&mut bytes)?;
+    if &bytes[0..4] != b"LASF" {
+        return Err(syntax_error("this is not LAS file"));
+    }
+    let version = (bytes[24], bytes[25]);
+    let header_size = le_value!(bytes, u16, 94) as usize;
+    if header_size < MIN_HEADER_SIZE {
+        return Err(syntax_error("too short LAS header"));
+    }
+    let mut rest = vec![0u8; header_size - MIN_HEADER_SIZE];
+    reader.read_exact(&mut rest)?;
+    bytes.extend(rest);
+    let offset_to_points = le_value!(bytes, u32, 96) as usize;
+    // The upper two bits are used for the compression flag by LAZ.
+    let point_format = bytes[104] & 0x3f;
+    let point_record_length = le_value!(bytes, u16, 105) as usize;
+    let mut number_of_points = le_value!(bytes, u32, 107) as usize;
+    if number_of_points == 0 && version.1 >= 4 && bytes.len() >= 255 {
+        number_of_points = le_value!(bytes, u64, 247) as usize;
+    }
+    let scale = Vector3::new(
+        le_value!(bytes, f64, 131),
+        le_value!(bytes, f64, 139),
+        le_value!(bytes, f64, 147),
+    );
+    let offset = Vector3::new(
+        le_value!(bytes, f64, 155),
+        le_value!(bytes, f64, 163),
+        le_value!(bytes, f64, 171),
+    );
+    let header = LasHeader {
+        version,
+        point_format,
+        point_record_length,
+        number_of_points,
+        scale,
+        offset,
+    };
+    if point_record_length < 12 || offset_to_points < header_size {
+        return Err(syntax_error("invalid LAS header"));
+    }
+    if matches!(header.color_offset(), Some(c) if point_record_length < c + 6) {
+        return Err(syntax_error("too short LAS point record"));
+    }
+    Ok((header, offset_to_points - header_size))
+}
+
+/// Reads the header of LAS file.
+#[inline(always)]
+pub fn read_header_only<R: Read>(mut reader: R) -> Result<LasHeader> {
+    read_header(&mut reader).map(|(header, _)| header)
+}
+
+/// Reads point cloud from LAS file.
+///
+/// The coordinates are scaled and offset by the header, and the colors are normalized
+/// into `[0, 1]` if the point data record format has RGB. Compressed LAZ files are not supported.
+pub fn read<R: Read>(reader: R) -> Result<PointCloud> {
+    let mut reader = BufReader::new(reader);
+    let (header, skip) = read_header(&mut reader)?;
+    std::io::copy(&mut (&mut reader).take(skip as u64), &mut std::io::sink())?;
+    let color_offset = header.color_offset();
+    let reserved = usize::min(header.number_of_points, point_cloud::MAX_RESERVED_POINTS);
+    let mut positions = Vec::with_capacity(reserved);
+    let mut colors = Vec::new();
+    let mut record = vec![0u8; header.point_record_length];
+    for _ in 0..header.number_of_points {
+        reader.read_exact(&mut record)?;
+        let (x, y, z) = (
+            le_value!(record, i32, 0),
+            le_value!(record, i32, 4),
+            le_value!(record, i32, 8),
+        );
+        let coord = Vector3::new(f64::from(x), f64::from(y), f64::from(z));
+        let coord = header.offset + Vector3::new(
+            coord.x * header.scale.x,
+            coord.y * header.scale.y,
+            coord.z * header.scale.z,
+        );
+        positions.push(Point3::from_vec(coord));
+        if let Some(c) = color_offset {
+            colors.push(Vector3::new(
+                f64::from(le_value!(record, u16, c)) / 65535.0,
+                f64::from(le_value!(record, u16, c + 2)) / 65535.0,
+                f64::from(le_value!(record, u16, c + 4)) / 65535.0,
+            ));
+        }
+    }
+    let colors = color_offset.map(|_| colors);
+    PointCloud::try_new(positions, None, colors)
+}
@@ -32,6 +32,46 @@ pub trait Attributes<V> {
     fn get(&self, vertex: V) -> Option<Self::Output>;
 }
 
+/// Discretization of curves into polylines with the control of the deviation.
+///
+/// The criterion is the chordal deviation computed by [`algo::curve::chordal_division`], which
+/// does not depend on the parametrization of the curve. The tessellation of shapes does not use
+/// this trait, and divides the edges by [`ParameterDivision1D`].
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+///
+/// // the unit circle
+/// #[derive(Clone, Debug)]
+/// struct Circle;
+/// impl ParametricCurve for Circle {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(f64::cos(t), f64::sin(t)) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(-f64::sin(t), f64::cos(t)) }
+///     fn der2(&self, t: f64) -> Vector2 { Vector2::new(-f64::cos(t), -f64::sin(t)) }
+/// }
+/// impl BoundedCurve for Circle {
+///     fn parameter_range(&self) -> (f64, f64) { (0.0, 2.0 * std::f64::consts::PI) }
+/// }
+///
+/// let polyline = Circle.to_polyline(0.01);
+/// assert!(polyline[0].near(&polyline[polyline.len() - 1]));
+/// // the sagitta of the chord with the central angle `a` is `1 - cos(a / 2)`.
+/// polyline.windows(2).for_each(|a| {
+///     let angle = a[0].to_vec().angle(a[1].to_vec());
+///     assert!(1.0 - f64::cos(angle.0 / 2.0) < 0.01);
+/// });
+/// ```
+pub trait ToPolyline: BoundedCurve {
+    /// Returns the polyline whose chordal deviation from the curve is less than `tol`.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    fn to_polyline(&self, tol: f64) -> PolylineCurve<Self::Point>;
+}
+
 /// standard attributions
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct StandardAttributes {
@@ -99,19 +139,43 @@ pub struct StructuredMesh {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PolylineCurve<P>(pub Vec<P>);
 
+/// point cloud
+///
+/// Positions are always held. Normals and colors are optional,
+/// and if they exist, their lengths are the same as the positions'.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointCloud {
+    positions: Vec<Point3>,
+    normals: Option<Vec<Vector3>>,
+    colors: Option<Vec<Vector3>>,
+}
+
 mod attributes;
 /// Defines errors
 pub mod errors;
 mod expand;
 /// Defines triangle
 pub mod faces;
+/// Defines the half-edge structure of the connectivity of the polygon meshes
+pub mod half_edge;
+/// LAS (LiDAR point cloud) input
+pub mod las;
 mod meshing_shape;
 /// wavefront obj I/O
 pub mod obj;
+/// planar polygon operations: boolean operations and offsetting
+pub mod planar;
+/// PLY (Stanford polygon file) point cloud input
+pub mod ply;
+mod point_cloud;
 /// Defines [`PolygonMeshEditor`](./polygon_mesh/struct.PolygonMeshEditor.html).
 pub mod polygon_mesh;
 /// Defines generalized polyline curve.
 pub mod polyline_curve;
 /// STL I/O
 pub mod stl;
+/// progress reporting of streaming input and output
+pub mod stream;
 mod structured_mesh;
+/// 3MF (3D Manufacturing Format) output
+pub mod threemf;
@@ -8,6 +8,18 @@ impl<P> PolylineCurve<P> {
     }
 }
 
+impl<C> ToPolyline for C
+where
+    C: BoundedCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    fn to_polyline(&self, tol: f64) -> PolylineCurve<C::Point> {
+        let range = self.parameter_range();
+        PolylineCurve(algo::curve::chordal_division(self, range, tol).1)
+    }
+}
+
 impl StructuredMesh {
     /// meshing the surface
     /// # Arguments
@@ -1,5 +1,6 @@
 use crate::*;
-use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use rustc_hash::FxHashMap as HashMap;
+use std::io::{BufRead, BufReader, BufWriter, Lines, Read, Write};
 type Vertex = StandardVertex;
 type Result<T> = std::result::Result<T, errors::Error>;
 
@@ -49,16 +50,19 @@ type Result<T> = std::result::Result<T, errors::Error>;
 /// );
 /// obj::write(&mesh, std::fs::File::create("meshdata.obj").unwrap());
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
-    sub_write(mesh, &mut BufWriter::new(writer))
+    sub_write(mesh, &mut BufWriter::new(writer), &mut [0; 3])
 }
 
 /// Writes obj data to output stream
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 pub fn write_vec<W: Write>(mesh: &[PolygonMesh], writer: W) -> Result<()> {
     let mut writer = BufWriter::new(writer);
+    let mut offset = [0; 3];
     for (i, mesh) in mesh.iter().enumerate() {
         writer.write_fmt(format_args!("g {i}\n"))?;
-        sub_write(mesh, &mut writer)?;
+        sub_write(mesh, &mut writer, &mut offset)?;
     }
     Ok(())
 }
@@ -89,25 +93,27 @@ fn write3vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
 }
 
 impl Vertex {
-    fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        match (self.uv, self.nor) {
-            (None, None) => writer.write_fmt(format_args!("{}", self.pos + 1)),
-            (Some(uv), None) => writer.write_fmt(format_args!("{}/{}", self.pos + 1, uv + 1)),
-            (None, Some(nor)) => writer.write_fmt(format_args!("{}//{}", self.pos + 1, nor + 1)),
-            (Some(uv), Some(nor)) => {
-                writer.write_fmt(format_args!("{}/{}/{}", self.pos + 1, uv + 1, nor + 1))
-            }
+    /// writes the indices, shifted by the numbers of the attributes previously written
+    fn write<W: Write>(&self, writer: &mut W, offset: [usize; 3]) -> std::io::Result<()> {
+        let pos = self.pos + offset[0] + 1;
+        let uv = self.uv.map(|uv| uv + offset[1] + 1);
+        let nor = self.nor.map(|nor| nor + offset[2] + 1);
+        match (uv, nor) {
+            (None, None) => writer.write_fmt(format_args!("{pos}")),
+            (Some(uv), None) => writer.write_fmt(format_args!("{pos}/{uv}")),
+            (None, Some(nor)) => writer.write_fmt(format_args!("{pos}//{nor}")),
+            (Some(uv), Some(nor)) => writer.write_fmt(format_args!("{pos}/{uv}/{nor}")),
         }
     }
 }
 
 impl Faces {
-    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+    fn write<W: Write>(&self, writer: &mut W, offset: [usize; 3]) -> Result<()> {
         for face in self.face_iter() {
             writer.write_all(b"f")?;
             for v in face {
                 writer.write_all(b" ")?;
-                v.write(writer)?;
+                v.write(writer, offset)?;
             }
             writer.write_all(b"\n")?;
         }
@@ -115,72 +121,582 @@ impl Faces {
     }
 }
 
-fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<()> {
+fn sub_write<W: Write>(
+    mesh: &PolygonMesh,
+    writer: &mut BufWriter<W>,
+    offset: &mut [usize; 3],
+) -> Result<()> {
     write3vec(writer, mesh.positions(), "v")?;
     write2vec(writer, mesh.uv_coords(), "vt")?;
     write3vec(writer, mesh.normals(), "vn")?;
-    mesh.faces.write(writer)
+    mesh.faces.write(writer, *offset)?;
+    offset[0] += mesh.positions().len();
+    offset[1] += mesh.uv_coords().len();
+    offset[2] += mesh.normals().len();
+    Ok(())
 }
 
-/// Reads mesh data from wavefront obj file.
-pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
-    let mut positions = Vec::new();
-    let mut uv_coords = Vec::new();
-    let mut normals = Vec::new();
-    let mut faces = Faces::default();
-    let reader = BufReader::new(reader);
-    for line in reader.lines().map(|s| s.unwrap()) {
+/// a named sub-mesh in wavefront obj file, by `g` or `o`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    /// the name of the group, empty if the faces are before any `g` or `o`
+    pub name: String,
+    /// the name of the material by `usemtl`
+    pub material: Option<String>,
+    /// the mesh consisting only of the attributes referred by the faces of the group
+    pub mesh: PolygonMesh,
+}
+
+/// the groups and the material libraries in wavefront obj file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Model {
+    /// the file names of the material libraries by `mtllib`
+    pub material_libraries: Vec<String>,
+    /// the groups in the order of appearance
+    pub groups: Vec<Group>,
+}
+
+/// a material in MTL file
+#[derive(Clone, Debug, PartialEq)]
+pub struct Material {
+    /// the name of the material by `newmtl`
+    pub name: String,
+    /// the ambient color `Ka`. Default is `Vector3::new(1.0, 1.0, 1.0)`.
+    pub ambient: Vector3,
+    /// the diffuse color `Kd`. Default is `Vector3::new(1.0, 1.0, 1.0)`.
+    pub diffuse: Vector3,
+    /// the specular color `Ks`. Default is `Vector3::new(0.0, 0.0, 0.0)`.
+    pub specular: Vector3,
+    /// the specular exponent `Ns`, [0, 1000]. Default is 0.0.
+    pub shininess: f64,
+    /// the opacity `d`, or `1 - Tr`. Default is 1.0.
+    pub dissolve: f64,
+    /// the file name of the diffuse texture `map_Kd`
+    pub diffuse_map: Option<String>,
+}
+
+impl Material {
+    /// Creates the material with the default values.
+    #[inline(always)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ambient: Vector3::new(1.0, 1.0, 1.0),
+            diffuse: Vector3::new(1.0, 1.0, 1.0),
+            specular: Vector3::new(0.0, 0.0, 0.0),
+            shininess: 0.0,
+            dissolve: 1.0,
+            diffuse_map: None,
+        }
+    }
+}
+
+/// Writes the groups with the material libraries to output stream.
+///
+/// The indices of each group are shifted by the numbers of the attributes of the previous groups.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let model = obj::Model {
+///     material_libraries: vec!["triangles.mtl".to_string()],
+///     groups: vec![
+///         obj::Group {
+///             name: "red".to_string(),
+///             material: Some("red".to_string()),
+///             mesh: mesh.clone(),
+///         },
+///         obj::Group {
+///             name: "blue".to_string(),
+///             material: Some("blue".to_string()),
+///             mesh,
+///         },
+///     ],
+/// };
+/// let mut buffer = Vec::new();
+/// obj::write_model(&model, &mut buffer).unwrap();
+/// assert_eq!(obj::read_model(buffer.as_slice()).unwrap(), model);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn write_model<W: Write>(model: &Model, writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    for library in &model.material_libraries {
+        writer.write_fmt(format_args!("mtllib {library}\n"))?;
+    }
+    let mut offset = [0; 3];
+    for group in &model.groups {
+        writer.write_fmt(format_args!("g {}\n", group.name))?;
+        if let Some(material) = &group.material {
+            writer.write_fmt(format_args!("usemtl {material}\n"))?;
+        }
+        sub_write(&group.mesh, &mut writer, &mut offset)?;
+    }
+    Ok(())
+}
+
+/// Writes the materials to MTL file.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mut material = obj::Material::new("red");
+/// material.diffuse = Vector3::new(1.0, 0.0, 0.0);
+/// material.diffuse_map = Some("red.png".to_string());
+/// let mut buffer = Vec::new();
+/// obj::write_mtl(&[material.clone()], &mut buffer).unwrap();
+/// assert_eq!(obj::read_mtl(buffer.as_slice()).unwrap(), vec![material]);
+/// ```
+pub fn write_mtl<W: Write>(materials: &[Material], writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    for material in materials {
+        writer.write_fmt(format_args!("newmtl {}\n", material.name))?;
+        write3vec(&mut writer, &[material.ambient], "Ka")?;
+        write3vec(&mut writer, &[material.diffuse], "Kd")?;
+        write3vec(&mut writer, &[material.specular], "Ks")?;
+        writer.write_fmt(format_args!("Ns {:.10e}\n", material.shininess))?;
+        writer.write_fmt(format_args!("d {:.10e}\n", material.dissolve))?;
+        if let Some(map) = &material.diffuse_map {
+            writer.write_fmt(format_args!("map_Kd {map}\n"))?;
+        }
+    }
+    Ok(())
+}
+
+/// The streaming writer of wavefront obj file, writing the elements one by one.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use obj::{ObjElement, ObjReader, ObjWriter};
+/// let obj = b"g triangle\nv 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nf 1 2 3\n";
+/// let mut buffer = Vec::new();
+/// let mut writer = ObjWriter::new(&mut buffer);
+/// for element in ObjReader::new(&obj[..]) {
+///     writer.write_element(&element.unwrap()).unwrap();
+/// }
+/// writer.flush().unwrap();
+/// drop(writer);
+/// assert_eq!(obj::read(buffer.as_slice()).unwrap(), obj::read(&obj[..]).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct ObjWriter<W: Write> {
+    writer: BufWriter<W>,
+}
+
+impl<W: Write> ObjWriter<W> {
+    /// Creates the streaming writer.
+    #[inline(always)]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+        }
+    }
+
+    /// Writes an element.
+    pub fn write_element(&mut self, element: &ObjElement) -> Result<()> {
+        let writer = &mut self.writer;
+        match element {
+            ObjElement::Position(p) => write3vec(writer, &[*p], "v"),
+            ObjElement::UVCoord(uv) => write2vec(writer, &[*uv], "vt"),
+            ObjElement::Normal(n) => write3vec(writer, &[*n], "vn"),
+            ObjElement::Face(face) => {
+                writer.write_all(b"f")?;
+                for v in face {
+                    writer.write_all(b" ")?;
+                    v.write(writer, [0; 3])?;
+                }
+                writer.write_all(b"\n")?;
+                Ok(())
+            }
+            ObjElement::Group(name) => Ok(writer.write_fmt(format_args!("g {name}\n"))?),
+            ObjElement::Material(name) => Ok(writer.write_fmt(format_args!("usemtl {name}\n"))?),
+            ObjElement::MaterialLibraries(libraries) => {
+                let libraries = libraries.join(" ");
+                Ok(writer.write_fmt(format_args!("mtllib {libraries}\n"))?)
+            }
+        }
+    }
+
+    /// Flushes the buffered elements.
+    #[inline(always)]
+    pub fn flush(&mut self) -> Result<()> { Ok(self.writer.flush()?) }
+}
+
+fn syntax_error() -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, "syntax error").into()
+}
+
+#[inline(always)]
+fn parse_f64<'a>(args: &mut impl Iterator<Item = &'a str>) -> Result<f64> {
+    match args.next() {
+        Some(arg) => Ok(arg.parse::<f64>()?),
+        None => Err(syntax_error()),
+    }
+}
+
+/// the index in obj file: 1-origin if positive, relative to the end of the attributes if negative
+#[inline(always)]
+fn parse_index(arg: &str, len: usize) -> Option<usize> {
+    match arg.parse::<isize>().ok()? {
+        idx if idx > 0 => Some(idx as usize - 1),
+        idx if idx < 0 => len.checked_sub(idx.unsigned_abs()),
+        _ => None,
+    }
+}
+
+/// the rest of the line after the keyword
+#[inline(always)]
+fn rest_of_line<'a>(line: &'a str, keyword: &str) -> &'a str {
+    line.trim_start()[keyword.len()..].trim()
+}
+
+/// the faces of a group referring to the attributes of the whole file
+#[derive(Debug, Default)]
+struct RawGroup {
+    name: String,
+    material: Option<String>,
+    faces: Faces,
+}
+
+#[derive(Debug, Default)]
+struct RawObj {
+    attributes: StandardAttributes,
+    material_libraries: Vec<String>,
+    groups: Vec<RawGroup>,
+}
+
+impl RawObj {
+    /// the group to which the following faces are added
+    fn current_group(&mut self) -> &mut RawGroup {
+        if self.groups.is_empty() {
+            self.groups.push(RawGroup::default());
+        }
+        self.groups.last_mut().unwrap()
+    }
+
+    /// Starts a new group unless the current group has no faces.
+    fn start_group(&mut self, name: String, material: Option<String>) {
+        let group = RawGroup {
+            name,
+            material,
+            faces: Faces::default(),
+        };
+        match self.groups.last_mut() {
+            Some(last) if last.faces.is_empty() => *last = group,
+            _ => self.groups.push(group),
+        }
+    }
+}
+
+/// an element of wavefront obj file, read by [`ObjReader`] and written by [`ObjWriter`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjElement {
+    /// the position `v`
+    Position(Point3),
+    /// the texture coordinate `vt`
+    UVCoord(Vector2),
+    /// the normal `vn`
+    Normal(Vector3),
+    /// the face `f` by the 0-origin indices
+    Face(Vec<StandardVertex>),
+    /// the name of a group `g` or an object `o`
+    Group(String),
+    /// the name of the material `usemtl`
+    Material(String),
+    /// the file names of the material libraries `mtllib`
+    MaterialLibraries(Vec<String>),
+}
+
+/// The streaming reader of wavefront obj file, iterating over the elements.
+///
+/// The lines are read one by one, so the whole file is not held in memory. The negative indices
+/// of the faces are resolved by the numbers of the attributes read so far. The unsupported
+/// statements are skipped.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use obj::{ObjElement, ObjReader};
+/// let obj = b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\ns off\nf -3 -2 -1\n";
+/// let elements = ObjReader::new(&obj[..]).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(elements.len(), 4);
+/// assert_eq!(elements[1], ObjElement::Position(Point3::new(1.0, 0.0, 0.0)));
+/// assert_eq!(elements[3], ObjElement::Face(vec![0.into(), 1.into(), 2.into()]));
+/// ```
+#[derive(Debug)]
+pub struct ObjReader<R> {
+    lines: Lines<BufReader<R>>,
+    lens: [usize; 3],
+}
+
+impl<R: Read> ObjReader<R> {
+    /// Creates the streaming reader.
+    #[inline(always)]
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: BufReader::new(reader).lines(),
+            lens: [0; 3],
+        }
+    }
+
+    /// Returns the numbers of the positions, the texture coordinates and the normals read so far.
+    #[inline(always)]
+    pub const fn attribute_lens(&self) -> [usize; 3] { self.lens }
+
+    fn parse_line(&mut self, line: &str) -> Result<Option<ObjElement>> {
         let mut args = line.split_whitespace();
-        if let Some(first_str) = args.next() {
-            if first_str == "v" {
-                let x = args.next().unwrap().parse::<f64>()?;
-                let y = args.next().unwrap().parse::<f64>()?;
-                let z = args.next().unwrap().parse::<f64>()?;
-                positions.push(Point3::new(x, y, z));
-            } else if first_str == "vt" {
-                let u = args.next().unwrap().parse::<f64>()?;
-                let v = args.next().unwrap().parse::<f64>()?;
-                uv_coords.push(Vector2::new(u, v));
-            } else if first_str == "vn" {
-                let x = args.next().unwrap().parse::<f64>()?;
-                let y = args.next().unwrap().parse::<f64>()?;
-                let z = args.next().unwrap().parse::<f64>()?;
-                normals.push(Vector3::new(x, y, z));
-            } else if first_str == "f" {
+        let element = match args.next() {
+            Some("v") => {
+                let x = parse_f64(&mut args)?;
+                let y = parse_f64(&mut args)?;
+                let z = parse_f64(&mut args)?;
+                self.lens[0] += 1;
+                ObjElement::Position(Point3::new(x, y, z))
+            }
+            Some("vt") => {
+                let u = parse_f64(&mut args)?;
+                let v = parse_f64(&mut args).unwrap_or(0.0);
+                self.lens[1] += 1;
+                ObjElement::UVCoord(Vector2::new(u, v))
+            }
+            Some("vn") => {
+                let x = parse_f64(&mut args)?;
+                let y = parse_f64(&mut args)?;
+                let z = parse_f64(&mut args)?;
+                self.lens[2] += 1;
+                ObjElement::Normal(Vector3::new(x, y, z))
+            }
+            Some("f") => {
+                let lens = self.lens;
                 let mut face = Vec::new();
                 for vert_str in args {
-                    if &vert_str[0..1] == "#" {
+                    if vert_str.starts_with('#') {
                         break;
                     }
                     let mut iter = vert_str.split('/');
-                    let pos = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let uv = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let nor = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
+                    let mut index = |len: usize| iter.next().and_then(|x| parse_index(x, len));
+                    let (pos, uv, nor) = (index(lens[0]), index(lens[1]), index(lens[2]));
                     let vert = match (pos, uv, nor) {
                         (None, _, _) => continue,
                         (Some(pos), uv, nor) => Vertex { pos, uv, nor },
                     };
                     face.push(vert);
                 }
-                faces.push(face);
+                ObjElement::Face(face)
+            }
+            Some(keyword @ ("g" | "o")) => ObjElement::Group(rest_of_line(line, keyword).into()),
+            Some("usemtl") => ObjElement::Material(rest_of_line(line, "usemtl").into()),
+            Some("mtllib") => {
+                let libraries = rest_of_line(line, "mtllib").split_whitespace();
+                ObjElement::MaterialLibraries(libraries.map(String::from).collect())
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(element))
+    }
+}
+
+impl<R: Read> Iterator for ObjReader<R> {
+    type Item = Result<ObjElement>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(error) => return Some(Err(error.into())),
+            };
+            match self.parse_line(&line) {
+                Ok(Some(element)) => return Some(Ok(element)),
+                Ok(None) => continue,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+fn read_raw<R: Read>(reader: R) -> Result<RawObj> {
+    let mut obj = RawObj::default();
+    for element in ObjReader::new(reader) {
+        let attrs = &mut obj.attributes;
+        match element? {
+            ObjElement::Position(p) => attrs.positions.push(p),
+            ObjElement::UVCoord(uv) => attrs.uv_coords.push(uv),
+            ObjElement::Normal(n) => attrs.normals.push(n),
+            ObjElement::Face(face) => obj.current_group().faces.push(face),
+            ObjElement::Group(name) => {
+                let material = obj.current_group().material.clone();
+                obj.start_group(name, material);
+            }
+            ObjElement::Material(material) => {
+                let name = obj.current_group().name.clone();
+                obj.start_group(name, Some(material));
+            }
+            ObjElement::MaterialLibraries(libraries) => obj.material_libraries.extend(libraries),
+        }
+    }
+    obj.groups.retain(|group| !group.faces.is_empty());
+    Ok(obj)
+}
+
+/// Reads mesh data from wavefront obj file.
+///
+/// The faces of all groups are merged into one mesh. Negative indices refer to the attributes
+/// relatively from the end of the ones read so far.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    let obj = read_raw(reader)?;
+    let faces = obj
+        .groups
+        .into_iter()
+        .fold(Faces::default(), |mut faces, group| {
+            faces.naive_concat(group.faces);
+            faces
+        });
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        positions = obj.attributes.positions.len(),
+        faces = faces.len(),
+        "read obj"
+    );
+    PolygonMesh::try_new(obj.attributes, faces)
+}
+
+/// the mesh of the attributes referred by `faces`, reindexed
+fn compact_mesh(attributes: &StandardAttributes, faces: &Faces) -> Result<PolygonMesh> {
+    faces.is_compatible(attributes)?;
+    let mut res = StandardAttributes::default();
+    let mut maps = <[HashMap<usize, usize>; 3]>::default();
+    let signup = |map: &mut HashMap<usize, usize>, idx: usize| {
+        let len = map.len();
+        *map.entry(idx).or_insert(len)
+    };
+    let mut new_faces = Faces::default();
+    for face in faces.face_iter() {
+        let face: Vec<Vertex> = face
+            .iter()
+            .map(|v| Vertex {
+                pos: signup(&mut maps[0], v.pos),
+                uv: v.uv.map(|uv| signup(&mut maps[1], uv)),
+                nor: v.nor.map(|nor| signup(&mut maps[2], nor)),
+            })
+            .collect();
+        new_faces.push(face);
+    }
+    res.positions = reordered(&attributes.positions, &maps[0]);
+    res.uv_coords = reordered(&attributes.uv_coords, &maps[1]);
+    res.normals = reordered(&attributes.normals, &maps[2]);
+    Ok(PolygonMesh::debug_new(res, new_faces))
+}
+
+/// the elements sorted by the new indices in `map`
+fn reordered<T: Copy>(vec: &[T], map: &HashMap<usize, usize>) -> Vec<T> {
+    let mut pairs: Vec<(usize, usize)> = map.iter().map(|(i, j)| (*j, *i)).collect();
+    pairs.sort();
+    pairs.into_iter().map(|(_, i)| vec[i]).collect()
+}
+
+/// Reads the groups and the material libraries from wavefront obj file.
+///
+/// A group is started by `g` or `o`, and is divided by `usemtl` if its material is changed.
+/// Each group is a mesh only of the attributes referred by its faces.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let obj = b"mtllib cubes.mtl
+/// v 0.0 0.0 0.0
+/// v 1.0 0.0 0.0
+/// v 0.0 1.0 0.0
+/// o first
+/// usemtl red
+/// f 1 2 3
+/// v 0.0 0.0 1.0
+/// o second
+/// usemtl blue
+/// f -4 -3 -1
+/// ";
+/// let model = obj::read_model(&obj[..]).unwrap();
+/// assert_eq!(model.material_libraries, vec!["cubes.mtl".to_string()]);
+/// assert_eq!(model.groups.len(), 2);
+/// let second = &model.groups[1];
+/// assert_eq!(second.name, "second");
+/// assert_eq!(second.material.as_deref(), Some("blue"));
+/// assert_eq!(
+///     second.mesh.positions(),
+///     &vec![
+///         Point3::new(0.0, 0.0, 0.0),
+///         Point3::new(1.0, 0.0, 0.0),
+///         Point3::new(0.0, 0.0, 1.0),
+///     ],
+/// );
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn read_model<R: Read>(reader: R) -> Result<Model> {
+    let obj = read_raw(reader)?;
+    let groups = obj
+        .groups
+        .into_iter()
+        .map(|group| {
+            Ok(Group {
+                mesh: compact_mesh(&obj.attributes, &group.faces)?,
+                name: group.name,
+                material: group.material,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Model {
+        material_libraries: obj.material_libraries,
+        groups,
+    })
+}
+
+#[inline(always)]
+fn parse_vector3<'a>(args: &mut impl Iterator<Item = &'a str>) -> Result<Vector3> {
+    let x = parse_f64(args)?;
+    // `Kd r` is the abbreviation of `Kd r r r`.
+    let y = parse_f64(args).unwrap_or(x);
+    let z = parse_f64(args).unwrap_or(y);
+    Ok(Vector3::new(x, y, z))
+}
+
+/// Reads the materials from MTL file.
+///
+/// The colors `Ka`, `Kd`, `Ks`, the specular exponent `Ns`, the opacity `d` or `Tr` and the
+/// diffuse texture `map_Kd` are read, and the other statements are ignored.
+pub fn read_mtl<R: Read>(reader: R) -> Result<Vec<Material>> {
+    let mut materials = Vec::<Material>::new();
+    let reader = BufReader::new(reader);
+    for line in reader.lines() {
+        let line = line?;
+        let mut args = line.split_whitespace();
+        let keyword = args.next();
+        if keyword == Some("newmtl") {
+            materials.push(Material::new(rest_of_line(&line, "newmtl")));
+            continue;
+        }
+        let material = match materials.last_mut() {
+            Some(material) => material,
+            None => continue,
+        };
+        match keyword {
+            Some("Ka") => material.ambient = parse_vector3(&mut args)?,
+            Some("Kd") => material.diffuse = parse_vector3(&mut args)?,
+            Some("Ks") => material.specular = parse_vector3(&mut args)?,
+            Some("Ns") => material.shininess = parse_f64(&mut args)?,
+            Some("d") => material.dissolve = parse_f64(&mut args)?,
+            Some("Tr") => material.dissolve = 1.0 - parse_f64(&mut args)?,
+            // the options of the texture, e.g. `-s 1 1 1`, are not supported.
+            Some("map_Kd") => {
+                material.diffuse_map = Some(rest_of_line(&line, "map_Kd").to_string())
             }
+            _ => {}
         }
     }
-    PolygonMesh::try_new(
-        StandardAttributes {
-            positions,
-            uv_coords,
-            normals,
-        },
-        faces,
-    )
+    Ok(materials)
 }
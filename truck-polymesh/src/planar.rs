@@ -0,0 +1,492 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::f64::consts::PI;
+
+/// the number of segments of the round joins for the whole circle
+const ROUND_DIVISION: usize = 32;
+/// the maximum ratio of the length of miter joins to the offset distance
+const MITER_LIMIT: f64 = 2.0;
+/// the distance of the sample points from the edges for deciding which side is filled
+const SIDE_OFFSET: f64 = TOLERANCE * 0.1;
+
+/// The type of joins of offset contours at the corners.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JoinType {
+    /// sharp corners. The corners sharper than the miter limit `2.0` are beveled.
+    Miter,
+    /// corners rounded by circular arcs
+    Round,
+}
+
+/// Returns the signed area of the contour, positive if the contour is counter-clockwise.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let square = vec![
+///     Point2::new(0.0, 0.0),
+///     Point2::new(2.0, 0.0),
+///     Point2::new(2.0, 2.0),
+///     Point2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(planar::signed_area(&square), 4.0);
+/// ```
+pub fn signed_area(contour: &[Point2]) -> f64 {
+    contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .fold(0.0, |sum, (p, q)| sum + (p.x * q.y - p.y * q.x))
+        / 2.0
+}
+
+/// Returns the winding number of the contours around `point`.
+pub fn winding_number(contours: &[Vec<Point2>], point: Point2) -> i32 {
+    contours
+        .iter()
+        .flat_map(|contour| contour.iter().zip(contour.iter().cycle().skip(1)))
+        .fold(0, |sum, (p, q)| sum + crossing(*p, *q, point))
+}
+
+/// Returns whether `point` is in the region, i.e. the winding number is positive.
+///
+/// In this module, a region is represented by contours whose inside is on the left side,
+/// that is, the outer contours are counter-clockwise and the holes are clockwise.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let square = vec![
+///     Point2::new(0.0, 0.0),
+///     Point2::new(2.0, 0.0),
+///     Point2::new(2.0, 2.0),
+///     Point2::new(0.0, 2.0),
+/// ];
+/// let hole = vec![
+///     Point2::new(0.5, 0.5),
+///     Point2::new(0.5, 1.5),
+///     Point2::new(1.5, 1.5),
+///     Point2::new(1.5, 0.5),
+/// ];
+/// let region = vec![square, hole];
+/// assert!(planar::contains(&region, Point2::new(0.25, 1.0)));
+/// assert!(!planar::contains(&region, Point2::new(1.0, 1.0)));
+/// assert!(!planar::contains(&region, Point2::new(3.0, 1.0)));
+/// ```
+#[inline(always)]
+pub fn contains(contours: &[Vec<Point2>], point: Point2) -> bool {
+    winding_number(contours, point) > 0
+}
+
+/// Returns the union of two regions.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let square = |x: f64, y: f64| {
+///     vec![vec![
+///         Point2::new(x, y),
+///         Point2::new(x + 2.0, y),
+///         Point2::new(x + 2.0, y + 2.0),
+///         Point2::new(x, y + 2.0),
+///     ]]
+/// };
+/// let union = planar::union(&square(0.0, 0.0), &square(1.0, 1.0));
+/// assert_eq!(union.len(), 1);
+/// assert_eq!(union[0].len(), 8);
+/// assert!(planar::signed_area(&union[0]).near(&7.0));
+/// ```
+#[inline(always)]
+pub fn union(subject: &[Vec<Point2>], clip: &[Vec<Point2>]) -> Vec<Vec<Point2>> {
+    boolean(subject, clip, |a, b| a > 0 || b > 0)
+}
+
+/// Returns the intersection of two regions.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let square = |x: f64, y: f64| {
+///     vec![vec![
+///         Point2::new(x, y),
+///         Point2::new(x + 2.0, y),
+///         Point2::new(x + 2.0, y + 2.0),
+///         Point2::new(x, y + 2.0),
+///     ]]
+/// };
+/// let intersection = planar::intersection(&square(0.0, 0.0), &square(1.0, 1.0));
+/// assert_eq!(intersection.len(), 1);
+/// assert_eq!(intersection[0].len(), 4);
+/// assert!(planar::signed_area(&intersection[0]).near(&1.0));
+/// ```
+#[inline(always)]
+pub fn intersection(subject: &[Vec<Point2>], clip: &[Vec<Point2>]) -> Vec<Vec<Point2>> {
+    boolean(subject, clip, |a, b| a > 0 && b > 0)
+}
+
+/// Returns the difference `subject - clip` of two regions.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let square = |x: f64, y: f64, size: f64| {
+///     vec![vec![
+///         Point2::new(x, y),
+///         Point2::new(x + size, y),
+///         Point2::new(x + size, y + size),
+///         Point2::new(x, y + size),
+///     ]]
+/// };
+/// // punch a hole
+/// let difference = planar::difference(&square(0.0, 0.0, 3.0), &square(1.0, 1.0, 1.0));
+/// assert_eq!(difference.len(), 2);
+/// let area = difference.iter().map(|c| planar::signed_area(c)).sum::<f64>();
+/// assert!(area.near(&8.0));
+/// ```
+#[inline(always)]
+pub fn difference(subject: &[Vec<Point2>], clip: &[Vec<Point2>]) -> Vec<Vec<Point2>> {
+    boolean(subject, clip, |a, b| a > 0 && b <= 0)
+}
+
+/// Returns the symmetric difference of two regions.
+#[inline(always)]
+pub fn xor(subject: &[Vec<Point2>], clip: &[Vec<Point2>]) -> Vec<Vec<Point2>> {
+    boolean(subject, clip, |a, b| (a > 0) != (b > 0))
+}
+
+/// Offsets the region by `delta`: outward if `delta` is positive, and inward if negative.
+///
+/// The offset contours of all edges are joined by `join` at the corners, and then their
+/// self-intersections are resolved. Hence, collapsed parts vanish and colliding parts are merged.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let square = vec![vec![
+///     Point2::new(0.0, 0.0),
+///     Point2::new(1.0, 0.0),
+///     Point2::new(1.0, 1.0),
+///     Point2::new(0.0, 1.0),
+/// ]];
+/// let outer = planar::offset(&square, 0.5, planar::JoinType::Miter);
+/// assert!(planar::signed_area(&outer[0]).near(&4.0));
+///
+/// let round = planar::offset(&square, 0.5, planar::JoinType::Round);
+/// let area = 1.0 + 4.0 * 0.5 + std::f64::consts::PI * 0.25;
+/// assert!(f64::abs(planar::signed_area(&round[0]) - area) < 0.01);
+///
+/// let inner = planar::offset(&square, -0.25, planar::JoinType::Miter);
+/// assert!(planar::signed_area(&inner[0]).near(&0.25));
+///
+/// // collapsed
+/// assert!(planar::offset(&square, -0.6, planar::JoinType::Miter).is_empty());
+/// ```
+pub fn offset(contours: &[Vec<Point2>], delta: f64, join: JoinType) -> Vec<Vec<Point2>> {
+    let raw = contours
+        .iter()
+        .map(|contour| dedup_contour(contour))
+        .filter(|contour| contour.len() > 2)
+        .map(|contour| match delta.abs() < TOLERANCE {
+            true => contour,
+            false => raw_offset(&contour, delta, join),
+        })
+        .collect::<Vec<_>>();
+    boolean(&raw, &[], |a, _| a > 0)
+}
+
+/// the contribution of the directed edge `p -> q` to the winding number around `point`
+#[inline(always)]
+fn crossing(p: Point2, q: Point2, point: Point2) -> i32 {
+    let side = (q - p).perp_dot(point - p);
+    match (p.y <= point.y, q.y <= point.y) {
+        (true, false) if side > 0.0 => 1,
+        (false, true) if side < 0.0 => -1,
+        _ => 0,
+    }
+}
+
+fn dedup_contour(contour: &[Point2]) -> Vec<Point2> {
+    let mut res = Vec::<Point2>::with_capacity(contour.len());
+    contour.iter().for_each(|p| match res.last() {
+        Some(last) if last.distance(*p) <= TOLERANCE => {}
+        _ => res.push(*p),
+    });
+    while res.len() > 1 && res[0].distance(res[res.len() - 1]) <= TOLERANCE {
+        res.pop();
+    }
+    res
+}
+
+fn raw_offset(contour: &[Point2], delta: f64, join: JoinType) -> Vec<Point2> {
+    let len = contour.len();
+    let (d, sign) = (delta.abs(), delta.signum());
+    // the unit normals on the offset side
+    let normals = (0..len)
+        .map(|i| {
+            let dir = (contour[(i + 1) % len] - contour[i]).normalize();
+            Vector2::new(dir.y, -dir.x) * sign
+        })
+        .collect::<Vec<_>>();
+    let mut res = Vec::new();
+    (0..len).for_each(|i| {
+        let p = contour[i];
+        let (n0, n1) = (normals[(i + len - 1) % len], normals[i]);
+        let (cross, dot) = (n0.perp_dot(n1), n0.dot(n1));
+        // the offset curves of the adjacent edges do not meet: convex corner for the offset side
+        let gap = match cross.abs() < TOLERANCE {
+            true => dot < 0.0,
+            false => cross * sign > 0.0,
+        };
+        if !gap {
+            if dot < 1.0 - TOLERANCE {
+                res.push(p + n0 * d);
+                res.push(p);
+            }
+            res.push(p + n1 * d);
+            return;
+        }
+        match join {
+            JoinType::Miter if 1.0 + dot >= 2.0 / (MITER_LIMIT * MITER_LIMIT) => {
+                res.push(p + (n0 + n1) * (d / (1.0 + dot)))
+            }
+            JoinType::Miter => {
+                res.push(p + n0 * d);
+                res.push(p + n1 * d);
+            }
+            JoinType::Round => {
+                let angle = f64::atan2(cross, dot);
+                let division =
+                    f64::ceil(angle.abs() / (2.0 * PI / ROUND_DIVISION as f64)) as usize;
+                let start = f64::atan2(n0.y, n0.x);
+                (0..=division).for_each(|k| {
+                    let t = start + angle * k as f64 / division as f64;
+                    res.push(p + Vector2::new(t.cos(), t.sin()) * d);
+                });
+            }
+        }
+    });
+    res
+}
+
+/// the vertices identified those within the tolerance
+#[derive(Clone, Debug, Default)]
+struct VertexPool {
+    points: Vec<Point2>,
+    grid: HashMap<[i64; 2], Vec<usize>>,
+}
+
+impl VertexPool {
+    #[inline(always)]
+    fn cell(point: Point2) -> [i64; 2] {
+        [
+            f64::floor(point.x / TOLERANCE) as i64,
+            f64::floor(point.y / TOLERANCE) as i64,
+        ]
+    }
+    fn insert(&mut self, point: Point2) -> usize {
+        let [x, y] = Self::cell(point);
+        let found = (x - 1..=x + 1)
+            .flat_map(|x| (y - 1..=y + 1).map(move |y| [x, y]))
+            .filter_map(|cell| self.grid.get(&cell))
+            .flatten()
+            .find(|i| self.points[**i].distance(point) <= TOLERANCE)
+            .copied();
+        match found {
+            Some(i) => i,
+            None => {
+                self.points.push(point);
+                self.grid.entry([x, y]).or_default().push(self.points.len() - 1);
+                self.points.len() - 1
+            }
+        }
+    }
+}
+
+/// the parameters of the points on the segment `a -> b` where the segment `c -> d` meets
+fn split_parameters([a, b]: [Point2; 2], [c, d]: [Point2; 2]) -> Vec<f64> {
+    let (ab, cd) = (b - a, d - c);
+    let mut res = Vec::new();
+    let on_segment = |p: Point2| {
+        let t = (p - a).dot(ab) / ab.magnitude2();
+        match (0.0..=1.0).contains(&t) && (a + ab * t).distance(p) <= TOLERANCE {
+            true => Some(t),
+            false => None,
+        }
+    };
+    res.extend(on_segment(c));
+    res.extend(on_segment(d));
+    let denom = ab.perp_dot(cd);
+    if denom.abs() > f64::EPSILON * ab.magnitude() * cd.magnitude() {
+        let t = (c - a).perp_dot(cd) / denom;
+        let s = (c - a).perp_dot(ab) / denom;
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&s) {
+            res.push(t);
+        }
+    }
+    res
+}
+
+/// the boolean operation of regions, where `filled` decides whether the point is filled
+/// from the winding numbers of the subject and the clip.
+fn boolean<F: Fn(i32, i32) -> bool>(
+    subject: &[Vec<Point2>],
+    clip: &[Vec<Point2>],
+    filled: F,
+) -> Vec<Vec<Point2>> {
+    // the input segments labeled by `false` for the subject and `true` for the clip
+    let segments = [(subject, false), (clip, true)]
+        .into_iter()
+        .flat_map(|(contours, label)| {
+            contours
+                .iter()
+                .map(|contour| dedup_contour(contour))
+                .filter(|contour| contour.len() > 1)
+                .flat_map(move |contour| {
+                    let len = contour.len();
+                    (0..len)
+                        .map(|i| ([contour[i], contour[(i + 1) % len]], label))
+                        .collect::<Vec<_>>()
+                })
+        })
+        .collect::<Vec<_>>();
+
+    // split the segments at the intersection points
+    let bdds = segments
+        .iter()
+        .map(|(seg, _)| seg.iter().collect::<BoundingBox<Point2>>())
+        .collect::<Vec<_>>();
+    let close = |i: usize, j: usize| {
+        let (a, b) = (&bdds[i], &bdds[j]);
+        a.min().x <= b.max().x + TOLERANCE
+            && b.min().x <= a.max().x + TOLERANCE
+            && a.min().y <= b.max().y + TOLERANCE
+            && b.min().y <= a.max().y + TOLERANCE
+    };
+    // the pairs of the close segments by sweeping the bounding boxes along the x-axis
+    let mut order = (0..segments.len()).collect::<Vec<_>>();
+    order.sort_by(|i, j| {
+        let (x, y) = (bdds[*i].min().x, bdds[*j].min().x);
+        x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut neighbors = vec![Vec::new(); segments.len()];
+    order.iter().enumerate().for_each(|(k, i)| {
+        let xmax = bdds[*i].max().x + TOLERANCE;
+        order[k + 1..]
+            .iter()
+            .take_while(|j| bdds[**j].min().x <= xmax)
+            .filter(|j| close(*i, **j))
+            .for_each(|j| {
+                neighbors[*i].push(*j);
+                neighbors[*j].push(*i);
+            });
+    });
+    let mut pool = VertexPool::default();
+    let mut edges = Vec::<(usize, usize, bool)>::new();
+    segments.iter().enumerate().for_each(|(i, (seg, label))| {
+        let mut params = vec![0.0, 1.0];
+        neighbors[i]
+            .iter()
+            .for_each(|j| params.extend(split_parameters(*seg, segments[*j].0)));
+        params.sort_by(|s, t| s.partial_cmp(t).unwrap_or(std::cmp::Ordering::Equal));
+        let ids = params
+            .into_iter()
+            .map(|t| pool.insert(seg[0] + (seg[1] - seg[0]) * t))
+            .collect::<Vec<_>>();
+        ids.windows(2)
+            .filter(|w| w[0] != w[1])
+            .for_each(|w| edges.push((w[0], w[1], *label)));
+    });
+    let points = &pool.points;
+
+    // the winding numbers by the split edges
+    let winding = |point: Point2| {
+        edges.iter().fold((0, 0), |(a, b), (i, j, label)| {
+            let c = crossing(points[*i], points[*j], point);
+            match label {
+                false => (a + c, b),
+                true => (a, b + c),
+            }
+        })
+    };
+
+    // the boundary edges of the result, the filled side is on the left
+    let mut unique = edges
+        .iter()
+        .map(|(i, j, _)| (usize::min(*i, *j), usize::max(*i, *j)))
+        .collect::<Vec<_>>();
+    unique.sort_unstable();
+    unique.dedup();
+    let mut outgoing = HashMap::<usize, Vec<usize>>::default();
+    unique.into_iter().for_each(|(i, j)| {
+        let (p, q) = (points[i], points[j]);
+        let mid = p + (q - p) * 0.5;
+        let dir = (q - p).normalize();
+        let normal = Vector2::new(-dir.y, dir.x) * SIDE_OFFSET;
+        let (a, b) = winding(mid + normal);
+        let left = filled(a, b);
+        let (a, b) = winding(mid - normal);
+        let right = filled(a, b);
+        match (left, right) {
+            (true, false) => outgoing.entry(i).or_default().push(j),
+            (false, true) => outgoing.entry(j).or_default().push(i),
+            _ => {}
+        }
+    });
+
+    // link the boundary edges into contours, turning leftmost at each vertex
+    let mut contours = Vec::new();
+    while let Some(start) = outgoing.keys().next().copied() {
+        let mut contour = vec![start];
+        let mut prev = start;
+        let mut current = match outgoing.get_mut(&start).and_then(|vec| vec.pop()) {
+            Some(next) => next,
+            None => {
+                outgoing.remove(&start);
+                continue;
+            }
+        };
+        let closed = loop {
+            if current == start {
+                break true;
+            }
+            contour.push(current);
+            let incoming = points[current] - points[prev];
+            let next = outgoing.get_mut(&current).and_then(|candidates| {
+                let angle = |k: &usize| {
+                    let out = points[*k] - points[current];
+                    f64::atan2(incoming.perp_dot(out), incoming.dot(out))
+                };
+                let (idx, _) = candidates.iter().enumerate().max_by(|(_, k), (_, l)| {
+                    angle(k).partial_cmp(&angle(l)).unwrap_or(std::cmp::Ordering::Equal)
+                })?;
+                Some(candidates.swap_remove(idx))
+            });
+            match next {
+                Some(next) => {
+                    prev = current;
+                    current = next;
+                }
+                None => break false,
+            }
+        };
+        outgoing.retain(|_, vec| !vec.is_empty());
+        if closed {
+            let contour = simplify(contour.into_iter().map(|i| points[i]).collect());
+            if contour.len() > 2 && signed_area(&contour).abs() > TOLERANCE * TOLERANCE {
+                contours.push(contour);
+            }
+        }
+    }
+    contours
+}
+
+/// removes the vertices on the straight lines
+fn simplify(mut contour: Vec<Point2>) -> Vec<Point2> {
+    let mut i = 0;
+    while i < contour.len() && contour.len() > 2 {
+        let len = contour.len();
+        let (p, q, r) = (contour[(i + len - 1) % len], contour[i], contour[(i + 1) % len]);
+        let (a, b) = (q - p, r - q);
+        let distance = a.perp_dot(b).abs() / (r - p).magnitude().max(TOLERANCE);
+        match distance <= TOLERANCE * 0.5 && a.dot(b) >= 0.0 {
+            true => {
+                contour.remove(i);
+            }
+            false => i += 1,
+        }
+    }
+    contour
+}
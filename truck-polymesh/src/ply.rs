@@ -0,0 +1,537 @@
+use crate::*;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+type Result<T> = std::result::Result<T, errors::Error>;
+
+fn syntax_error(message: &str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string()).into()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScalarType {
+    Char,
+    UChar,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Float,
+    Double,
+}
+
+impl ScalarType {
+    fn parse(s: &str) -> Result<Self> {
+        match s {
+            "char" | "int8" => Ok(Self::Char),
+            "uchar" | "uint8" => Ok(Self::UChar),
+            "short" | "int16" => Ok(Self::Short),
+            "ushort" | "uint16" => Ok(Self::UShort),
+            "int" | "int32" => Ok(Self::Int),
+            "uint" | "uint32" => Ok(Self::UInt),
+            "float" | "float32" => Ok(Self::Float),
+            "double" | "float64" => Ok(Self::Double),
+            _ => Err(syntax_error("unknown scalar type")),
+        }
+    }
+
+    /// the scale which maps the color value into `[0, 1]`.
+    #[inline(always)]
+    fn color_scale(self) -> f64 {
+        match self {
+            Self::Char | Self::UChar => 255.0,
+            Self::Short | Self::UShort => 65535.0,
+            _ => 1.0,
+        }
+    }
+
+    fn read_binary<R: Read>(self, reader: &mut R, format: Format) -> Result<f64> {
+        macro_rules! read_value {
+            ($ty: ty, $n: expr) => {{
+                let mut buf = [0u8; $n];
+                reader.read_exact(&mut buf)?;
+                match format {
+                    Format::BinaryBigEndian => f64::from(<$ty>::from_be_bytes(buf)),
+                    _ => f64::from(<$ty>::from_le_bytes(buf)),
+                }
+            }};
+        }
+        Ok(match self {
+            Self::Char => read_value!(i8, 1),
+            Self::UChar => read_value!(u8, 1),
+            Self::Short => read_value!(i16, 2),
+            Self::UShort => read_value!(u16, 2),
+            Self::Int => read_value!(i32, 4),
+            Self::UInt => read_value!(u32, 4),
+            Self::Float => read_value!(f32, 4),
+            Self::Double => read_value!(f64, 8),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Property {
+    Scalar(ScalarType, String),
+    List(ScalarType, ScalarType, String),
+}
+
+impl Property {
+    #[inline(always)]
+    fn name(&self) -> &str {
+        match self {
+            Property::Scalar(_, name) => name,
+            Property::List(_, _, name) => name,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+#[derive(Clone, Debug)]
+struct Header {
+    format: Format,
+    elements: Vec<Element>,
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<Header> {
+    let mut line = String::new();
+    let mut next_line = |line: &mut String| -> Result<()> {
+        line.clear();
+        match reader.read_line(line)? {
+            0 => Err(syntax_error("unexpected end of PLY header")),
+            _ => Ok(()),
+        }
+    };
+    next_line(&mut line)?;
+    if line.trim() != "ply" {
+        return Err(syntax_error("this is not PLY file"));
+    }
+    let mut format = None;
+    let mut elements = Vec::<Element>::new();
+    loop {
+        next_line(&mut line)?;
+        let mut args = line.split_whitespace();
+        match args.next() {
+            Some("format") => {
+                format = Some(match args.next() {
+                    Some("ascii") => Format::Ascii,
+                    Some("binary_little_endian") => Format::BinaryLittleEndian,
+                    Some("binary_big_endian") => Format::BinaryBigEndian,
+                    _ => return Err(syntax_error("unknown PLY format")),
+                });
+            }
+            Some("element") => {
+                let name = args.next().ok_or_else(|| syntax_error("no element name"))?;
+                let count = args
+                    .next()
+                    .ok_or_else(|| syntax_error("no element count"))?
+                    .parse::<usize>()?;
+                elements.push(Element {
+                    name: name.to_string(),
+                    count,
+                    properties: Vec::new(),
+                });
+            }
+            Some("property") => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| syntax_error("property before element"))?;
+                let mut next = || args.next().ok_or_else(|| syntax_error("invalid property"));
+                let property = match next()? {
+                    "list" => {
+                        let count_type = ScalarType::parse(next()?)?;
+                        let value_type = ScalarType::parse(next()?)?;
+                        Property::List(count_type, value_type, next()?.to_string())
+                    }
+                    ty => Property::Scalar(ScalarType::parse(ty)?, next()?.to_string()),
+                };
+                element.properties.push(property);
+            }
+            Some("end_header") => break,
+            _ => {}
+        }
+    }
+    Ok(Header {
+        format: format.ok_or_else(|| syntax_error("no PLY format"))?,
+        elements,
+    })
+}
+
+/// reads the values of one row of the element. The values of list properties are ignored.
+///
+/// The row of ASCII format is a line, which is read into `line`.
+fn read_row<R: BufRead>(
+    element: &Element,
+    format: Format,
+    reader: &mut R,
+    line: &mut String,
+    row: &mut Vec<f64>,
+) -> Result<()> {
+    row.clear();
+    line.clear();
+    while format == Format::Ascii && line.trim().is_empty() {
+        line.clear();
+        if reader.read_line(line)? == 0 {
+            return Err(syntax_error("unexpected end of PLY data"));
+        }
+    }
+    let mut tokens = line.split_whitespace();
+    let mut next_value = |ty: ScalarType, reader: &mut R| -> Result<f64> {
+        match format {
+            Format::Ascii => Ok(tokens
+                .next()
+                .ok_or_else(|| syntax_error("unexpected end of PLY data"))?
+                .parse::<f64>()?),
+            _ => ty.read_binary(reader, format),
+        }
+    };
+    for property in &element.properties {
+        match property {
+            Property::Scalar(ty, _) => row.push(next_value(*ty, reader)?),
+            Property::List(count_ty, value_ty, _) => {
+                let count = next_value(*count_ty, reader)? as usize;
+                for _ in 0..count {
+                    next_value(*value_ty, reader)?;
+                }
+                row.push(f64::NAN);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// the indices of the used properties in the element `vertex`
+#[derive(Clone, Copy, Debug)]
+struct VertexLayout {
+    position: [usize; 3],
+    normal: Option<[usize; 3]>,
+    color: Option<([usize; 3], [f64; 3])>,
+}
+
+impl VertexLayout {
+    fn new(element: &Element) -> Result<Self> {
+        let index = |name: &str| element.properties.iter().position(|p| p.name() == name);
+        let position = match (index("x"), index("y"), index("z")) {
+            (Some(x), Some(y), Some(z)) => [x, y, z],
+            _ => return Err(syntax_error("vertex has no position")),
+        };
+        let normal = match (index("nx"), index("ny"), index("nz")) {
+            (Some(x), Some(y), Some(z)) => Some([x, y, z]),
+            _ => None,
+        };
+        let color = match (index("red"), index("green"), index("blue")) {
+            (Some(r), Some(g), Some(b)) => Some([r, g, b]),
+            _ => None,
+        };
+        let color = color.map(|idcs| {
+            let scales = idcs.map(|i| match &element.properties[i] {
+                Property::Scalar(ty, _) => ty.color_scale(),
+                Property::List(_, ty, _) => ty.color_scale(),
+            });
+            (idcs, scales)
+        });
+        Ok(Self {
+            position,
+            normal,
+            color,
+        })
+    }
+
+    fn vertex(&self, row: &[f64]) -> PlyVertex {
+        let vector = |[x, y, z]: [usize; 3]| Vector3::new(row[x], row[y], row[z]);
+        PlyVertex {
+            position: Point3::from_vec(vector(self.position)),
+            normal: self.normal.map(vector),
+            color: self
+                .color
+                .map(|(idcs, scales)| vector(idcs).div_element_wise(Vector3::from(scales))),
+        }
+    }
+}
+
+/// a vertex of PLY file
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlyVertex {
+    /// the position by `x`, `y` and `z`
+    pub position: Point3,
+    /// the normal by `nx`, `ny` and `nz`
+    pub normal: Option<Vector3>,
+    /// the color by `red`, `green`, and `blue`, mapped into `[0, 1]`
+    pub color: Option<Vector3>,
+}
+
+/// The streaming reader of PLY file, iterating over the vertices.
+///
+/// Only the header is read at the creation, and the vertices are read one by one. The rows of
+/// the other elements are skipped.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use ply::PlyReader;
+/// let ply = b"ply
+/// format ascii 1.0
+/// element vertex 2
+/// property float x
+/// property float y
+/// property float z
+/// end_header
+/// 0.0 1.0 2.0
+/// 3.0 4.0 5.0
+/// ";
+/// let reader = PlyReader::new(&ply[..]).unwrap();
+/// assert_eq!(reader.vertex_count(), 2);
+/// let sum = reader.fold(Vector3::zero(), |sum, vertex| {
+///     sum + vertex.unwrap().position.to_vec()
+/// });
+/// assert_eq!(sum, Vector3::new(3.0, 5.0, 7.0));
+/// ```
+#[derive(Debug)]
+pub struct PlyReader<R> {
+    reader: BufReader<R>,
+    header: Header,
+    layouts: Vec<Option<VertexLayout>>,
+    element: usize,
+    row_idx: usize,
+    line: String,
+    row: Vec<f64>,
+}
+
+impl<R: Read> PlyReader<R> {
+    /// Creates the streaming reader by reading the header.
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = BufReader::new(reader);
+        let header = read_header(&mut reader)?;
+        let layouts = header
+            .elements
+            .iter()
+            .map(|element| match element.name == "vertex" {
+                true => VertexLayout::new(element).map(Some),
+                false => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            reader,
+            header,
+            layouts,
+            element: 0,
+            row_idx: 0,
+            line: String::new(),
+            row: Vec::new(),
+        })
+    }
+
+    /// Returns the number of the vertices declared in the header.
+    pub fn vertex_count(&self) -> usize {
+        let vertices = self.header.elements.iter().zip(&self.layouts);
+        vertices
+            .filter(|(_, layout)| layout.is_some())
+            .map(|(e, _)| e.count)
+            .sum()
+    }
+
+    /// Returns whether the vertices have the normals.
+    pub fn has_normals(&self) -> bool {
+        let mut layouts = self.layouts.iter().flatten();
+        layouts.any(|layout| layout.normal.is_some())
+    }
+
+    /// Returns whether the vertices have the colors.
+    pub fn has_colors(&self) -> bool {
+        let mut layouts = self.layouts.iter().flatten();
+        layouts.any(|layout| layout.color.is_some())
+    }
+}
+
+impl<R: Read> Iterator for PlyReader<R> {
+    type Item = Result<PlyVertex>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let element = self.header.elements.get(self.element)?;
+            if self.row_idx == element.count {
+                self.element += 1;
+                self.row_idx = 0;
+                continue;
+            }
+            self.row_idx += 1;
+            let (format, reader) = (self.header.format, &mut self.reader);
+            if let Err(error) = read_row(element, format, reader, &mut self.line, &mut self.row) {
+                self.element = self.header.elements.len();
+                return Some(Err(error));
+            }
+            if let Some(layout) = &self.layouts[self.element] {
+                return Some(Ok(layout.vertex(&self.row)));
+            }
+        }
+    }
+}
+
+/// Reads point cloud from PLY file.
+///
+/// Only the element `vertex` is read, and the properties `x`, `y`, `z`, `nx`, `ny`, `nz`,
+/// `red`, `green`, and `blue` are used. Both ASCII and binary formats are supported.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let ply = b"ply
+/// format ascii 1.0
+/// element vertex 2
+/// property float x
+/// property float y
+/// property float z
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// end_header
+/// 0.0 1.0 2.0 255 0 0
+/// 3.0 4.0 5.0 0 255 0
+/// ";
+/// let cloud = ply::read(&ply[..]).unwrap();
+/// assert_eq!(cloud.positions(), &vec![Point3::new(0.0, 1.0, 2.0), Point3::new(3.0, 4.0, 5.0)]);
+/// assert_eq!(cloud.colors().unwrap()[1], Vector3::new(0.0, 1.0, 0.0));
+/// assert!(cloud.normals().is_none());
+/// ```
+pub fn read<R: Read>(reader: R) -> Result<PointCloud> {
+    let reader = PlyReader::new(reader)?;
+    let count = usize::min(reader.vertex_count(), point_cloud::MAX_RESERVED_POINTS);
+    let mut positions = Vec::with_capacity(count);
+    let mut normals = Vec::new();
+    let mut colors = Vec::new();
+    for vertex in reader {
+        let vertex = vertex?;
+        positions.push(vertex.position);
+        normals.extend(vertex.normal);
+        colors.extend(vertex.color);
+    }
+    let normals = (!normals.is_empty()).then_some(normals);
+    let colors = (!colors.is_empty()).then_some(colors);
+    PointCloud::try_new(positions, normals, colors)
+}
+
+/// The streaming writer of PLY file by the ASCII format.
+///
+/// The header is written at the creation, so the number of the vertices and whether they have
+/// the normals and the colors are fixed in advance. Colors are written as `uchar`.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use ply::{PlyVertex, PlyWriter};
+/// let mut buffer = Vec::new();
+/// let mut writer = PlyWriter::new(&mut buffer, 100, false, false).unwrap();
+/// for i in 0..100 {
+///     let vertex = PlyVertex {
+///         position: Point3::new(i as f64, 0.0, 0.0),
+///         normal: None,
+///         color: None,
+///     };
+///     writer.write_vertex(&vertex).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// assert_eq!(ply::read(buffer.as_slice()).unwrap().len(), 100);
+/// ```
+#[derive(Debug)]
+pub struct PlyWriter<W: Write> {
+    writer: BufWriter<W>,
+    rest: usize,
+    normals: bool,
+    colors: bool,
+}
+
+impl<W: Write> PlyWriter<W> {
+    /// Creates the streaming writer of `count` vertices by writing the header.
+    pub fn new(writer: W, count: usize, normals: bool, colors: bool) -> Result<Self> {
+        let mut writer = BufWriter::new(writer);
+        writer.write_all(b"ply\nformat ascii 1.0\n")?;
+        writer.write_fmt(format_args!("element vertex {count}\n"))?;
+        writer.write_all(b"property double x\nproperty double y\nproperty double z\n")?;
+        if normals {
+            writer.write_all(b"property double nx\nproperty double ny\nproperty double nz\n")?;
+        }
+        if colors {
+            writer.write_all(b"property uchar red\nproperty uchar green\nproperty uchar blue\n")?;
+        }
+        writer.write_all(b"end_header\n")?;
+        Ok(Self {
+            writer,
+            rest: count,
+            normals,
+            colors,
+        })
+    }
+
+    /// Writes a vertex.
+    ///
+    /// # Failures
+    /// Returns an error if the vertices are more than the count, or the normal or the color is
+    /// inconsistent with the header.
+    pub fn write_vertex(&mut self, vertex: &PlyVertex) -> Result<()> {
+        if self.rest == 0 {
+            return Err(syntax_error("too many vertices"));
+        }
+        let writer = &mut self.writer;
+        let p = vertex.position;
+        writer.write_fmt(format_args!("{:?} {:?} {:?}", p.x, p.y, p.z))?;
+        match (self.normals, vertex.normal) {
+            (true, Some(n)) => writer.write_fmt(format_args!(" {:?} {:?} {:?}", n.x, n.y, n.z))?,
+            (false, None) => {}
+            _ => return Err(syntax_error("inconsistent normal")),
+        }
+        match (self.colors, vertex.color) {
+            (true, Some(c)) => {
+                let c = c.map(|x| (f64::clamp(x, 0.0, 1.0) * 255.0).round() as u8);
+                writer.write_fmt(format_args!(" {} {} {}", c.x, c.y, c.z))?;
+            }
+            (false, None) => {}
+            _ => return Err(syntax_error("inconsistent color")),
+        }
+        writer.write_all(b"\n")?;
+        self.rest -= 1;
+        Ok(())
+    }
+
+    /// Flushes the vertices, and checks that all the vertices are written.
+    pub fn finish(mut self) -> Result<()> {
+        self.writer.flush()?;
+        match self.rest {
+            0 => Ok(()),
+            _ => Err(syntax_error("too few vertices")),
+        }
+    }
+}
+
+/// Writes point cloud to PLY file by the ASCII format.
+///
+/// Colors are written as `uchar`.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let cloud = PointCloud::new(
+///     vec![Point3::new(0.0, 1.0, 2.0), Point3::new(3.0, 4.0, 5.0)],
+///     Some(vec![Vector3::unit_x(), Vector3::unit_z()]),
+///     None,
+/// );
+/// let mut buffer = Vec::new();
+/// ply::write(&cloud, &mut buffer).unwrap();
+/// assert_eq!(ply::read(buffer.as_slice()).unwrap(), cloud);
+/// ```
+pub fn write<W: Write>(cloud: &PointCloud, writer: W) -> Result<()> {
+    let (normals, colors) = (cloud.normals(), cloud.colors());
+    let mut writer = PlyWriter::new(writer, cloud.len(), normals.is_some(), colors.is_some())?;
+    for (i, p) in cloud.positions().iter().enumerate() {
+        writer.write_vertex(&PlyVertex {
+            position: *p,
+            normal: normals.map(|normals| normals[i]),
+            color: colors.map(|colors| colors[i]),
+        })?;
+    }
+    writer.finish()
+}
@@ -0,0 +1,186 @@
+use crate::*;
+use errors::Error;
+type Result<T> = std::result::Result<T, Error>;
+
+/// the maximum number of the points reserved in advance by the readers, since the counts in the
+/// headers of the files are not trusted
+pub(crate) const MAX_RESERVED_POINTS: usize = 1 << 20;
+
+impl PointCloud {
+    /// Creates a point cloud without normals and colors.
+    #[inline(always)]
+    pub const fn from_positions(positions: Vec<Point3>) -> PointCloud {
+        PointCloud {
+            positions,
+            normals: None,
+            colors: None,
+        }
+    }
+
+    /// Creates new point cloud.
+    /// # Panics
+    /// Panic occurs if the lengths of `normals` or `colors` are different from the one of `positions`.
+    #[inline(always)]
+    pub fn new(
+        positions: Vec<Point3>,
+        normals: Option<Vec<Vector3>>,
+        colors: Option<Vec<Vector3>>,
+    ) -> PointCloud {
+        PointCloud::try_new(positions, normals, colors).unwrap_or_else(|e| panic!("{e:?}"))
+    }
+
+    /// Creates new point cloud.
+    /// # Failures
+    /// Returns [`Error::DifferentLengthArrays`] if the lengths of `normals` or `colors`
+    /// are different from the one of `positions`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use errors::Error;
+    /// let positions = vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+    /// let normals = vec![Vector3::unit_z()];
+    /// match PointCloud::try_new(positions, Some(normals), None) {
+    ///     Err(Error::DifferentLengthArrays) => {}
+    ///     _ => panic!("wrong result!"),
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn try_new(
+        positions: Vec<Point3>,
+        normals: Option<Vec<Vector3>>,
+        colors: Option<Vec<Vector3>>,
+    ) -> Result<PointCloud> {
+        let len = positions.len();
+        let compatible = |vec: &Option<Vec<Vector3>>| vec.as_ref().map_or(true, |v| v.len() == len);
+        match compatible(&normals) && compatible(&colors) {
+            true => Ok(PointCloud::new_unchecked(positions, normals, colors)),
+            false => Err(Error::DifferentLengthArrays),
+        }
+    }
+
+    /// Creates new point cloud without checking the lengths of arrays.
+    #[inline(always)]
+    pub const fn new_unchecked(
+        positions: Vec<Point3>,
+        normals: Option<Vec<Vector3>>,
+        colors: Option<Vec<Vector3>>,
+    ) -> PointCloud {
+        PointCloud {
+            positions,
+            normals,
+            colors,
+        }
+    }
+
+    /// Returns the number of points.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.positions.len() }
+
+    /// Returns whether the point cloud has no points or not.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.positions.is_empty() }
+
+    /// Returns the vector of all positions.
+    #[inline(always)]
+    pub fn positions(&self) -> &Vec<Point3> { &self.positions }
+
+    /// Returns the mutable slice of all positions.
+    #[inline(always)]
+    pub fn positions_mut(&mut self) -> &mut [Point3] { &mut self.positions }
+
+    /// Returns the vector of all normals.
+    #[inline(always)]
+    pub fn normals(&self) -> Option<&Vec<Vector3>> { self.normals.as_ref() }
+
+    /// Returns the mutable slice of all normals.
+    #[inline(always)]
+    pub fn normals_mut(&mut self) -> Option<&mut [Vector3]> {
+        self.normals.as_mut().map(|vec| vec.as_mut())
+    }
+
+    /// Sets normals.
+    /// # Failures
+    /// Returns [`Error::DifferentLengthArrays`] if the length of `normals` is different from
+    /// the number of points.
+    #[inline(always)]
+    pub fn set_normals(&mut self, normals: Vec<Vector3>) -> Result<()> {
+        match normals.len() == self.len() {
+            true => {
+                self.normals = Some(normals);
+                Ok(())
+            }
+            false => Err(Error::DifferentLengthArrays),
+        }
+    }
+
+    /// Returns the vector of all colors. Each color is RGB in `[0, 1]^3`.
+    #[inline(always)]
+    pub fn colors(&self) -> Option<&Vec<Vector3>> { self.colors.as_ref() }
+
+    /// Returns the mutable slice of all colors.
+    #[inline(always)]
+    pub fn colors_mut(&mut self) -> Option<&mut [Vector3]> {
+        self.colors.as_mut().map(|vec| vec.as_mut())
+    }
+
+    /// Sets colors.
+    /// # Failures
+    /// Returns [`Error::DifferentLengthArrays`] if the length of `colors` is different from
+    /// the number of points.
+    #[inline(always)]
+    pub fn set_colors(&mut self, colors: Vec<Vector3>) -> Result<()> {
+        match colors.len() == self.len() {
+            true => {
+                self.colors = Some(colors);
+                Ok(())
+            }
+            false => Err(Error::DifferentLengthArrays),
+        }
+    }
+
+    /// Returns the bounding box of the point cloud.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> BoundingBox<Point3> { self.positions.iter().collect() }
+
+    /// Transforms the points and normals by the affine transformation `mat`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mut cloud = PointCloud::new(
+    ///     vec![Point3::new(1.0, 0.0, 0.0)],
+    ///     Some(vec![Vector3::unit_x()]),
+    ///     None,
+    /// );
+    /// let mat = Matrix4::from_translation(Vector3::new(0.0, 0.0, 1.0))
+    ///     * Matrix4::from_angle_z(Rad(std::f64::consts::PI / 2.0));
+    /// cloud.transform_by(mat);
+    /// assert!(cloud.positions()[0].near(&Point3::new(0.0, 1.0, 1.0)));
+    /// assert!(cloud.normals().unwrap()[0].near(&Vector3::unit_y()));
+    /// ```
+    pub fn transform_by(&mut self, mat: Matrix4) {
+        self.positions
+            .iter_mut()
+            .for_each(|p| *p = mat.transform_point(*p));
+        if let Some(normals) = &mut self.normals {
+            let inv = mat.invert().map(|inv| inv.transpose()).unwrap_or(mat);
+            normals.iter_mut().for_each(|n| {
+                let m = inv.transform_vector(*n);
+                if !m.so_small() {
+                    *n = m.normalize();
+                }
+            });
+        }
+    }
+}
+
+impl FromIterator<Point3> for PointCloud {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = Point3>>(iter: I) -> Self {
+        PointCloud::from_positions(iter.into_iter().collect())
+    }
+}
+
+impl From<Vec<Point3>> for PointCloud {
+    #[inline(always)]
+    fn from(positions: Vec<Point3>) -> Self { PointCloud::from_positions(positions) }
+}
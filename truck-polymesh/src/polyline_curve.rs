@@ -55,6 +55,8 @@ impl<P: ControlPoint<f64>> ParametricCurve for PolylineCurve<P> {
     }
     #[inline(always)]
     fn der2(&self, _: f64) -> P::Diff { P::Diff::zero() }
+    #[inline(always)]
+    fn try_range(&self) -> Option<(f64, f64)> { Some(self.parameter_range()) }
 }
 
 impl<P: ControlPoint<f64>> BoundedCurve for PolylineCurve<P> {
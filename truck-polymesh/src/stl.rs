@@ -170,6 +170,7 @@ fn binary_one_read<R: Read>(reader: &mut R) -> Result<Option<StlFace>> {
 /// Write STL file in `stl_type` format.
 ///
 /// If `stl_type == StlType::Automatic`, write the binary format.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 #[inline(always)]
 pub fn write<I: IntoStlIterator, W: Write>(
     iter: I,
@@ -351,6 +352,7 @@ impl FromIterator<StlFace> for PolygonMesh {
 }
 
 /// Read STL file and parse to [`PolygonMesh`].
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 #[inline(always)]
 pub fn read<R: Read>(reader: R, stl_type: StlType) -> Result<PolygonMesh> {
     StlReader::new(reader, stl_type)?.collect()
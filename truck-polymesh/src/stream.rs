@@ -0,0 +1,125 @@
+use std::io::{BufRead, Read, Result, Write};
+
+/// The reader reporting the progress by the number of the bytes read so far.
+///
+/// Wraps the input of the streaming readers [`StlReader`](crate::stl::StlReader),
+/// [`ObjReader`](crate::obj::ObjReader) and [`PlyReader`](crate::ply::PlyReader) to report the
+/// progress of reading the large files, together with the size of the file.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stream::ProgressReader;
+/// let obj = b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nf 1 2 3\n";
+/// let mut progress = 0;
+/// let reader = ProgressReader::new(&obj[..], |bytes| progress = bytes);
+/// let count = obj::ObjReader::new(reader).count();
+/// assert_eq!(count, 4);
+/// assert_eq!(progress, obj.len() as u64);
+/// ```
+#[derive(Debug)]
+pub struct ProgressReader<R, F> {
+    reader: R,
+    bytes: u64,
+    callback: F,
+}
+
+impl<R, F: FnMut(u64)> ProgressReader<R, F> {
+    /// Creates the reader calling `callback` with the total number of the bytes read each time.
+    #[inline(always)]
+    pub const fn new(reader: R, callback: F) -> Self {
+        Self {
+            reader,
+            bytes: 0,
+            callback,
+        }
+    }
+
+    /// Returns the number of the bytes read so far.
+    #[inline(always)]
+    pub const fn bytes(&self) -> u64 { self.bytes }
+
+    /// Returns the inner reader.
+    #[inline(always)]
+    pub fn into_inner(self) -> R { self.reader }
+
+    #[inline(always)]
+    fn advance(&mut self, bytes: usize) {
+        if bytes > 0 {
+            self.bytes += bytes as u64;
+            (self.callback)(self.bytes);
+        }
+    }
+}
+
+impl<R: Read, F: FnMut(u64)> Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let bytes = self.reader.read(buf)?;
+        self.advance(bytes);
+        Ok(bytes)
+    }
+}
+
+impl<R: BufRead, F: FnMut(u64)> BufRead for ProgressReader<R, F> {
+    #[inline(always)]
+    fn fill_buf(&mut self) -> Result<&[u8]> { self.reader.fill_buf() }
+    fn consume(&mut self, amt: usize) {
+        self.reader.consume(amt);
+        self.advance(amt);
+    }
+}
+
+/// The writer reporting the progress by the number of the bytes written so far.
+///
+/// Wraps the output of the streaming writers, e.g. [`ObjWriter`](crate::obj::ObjWriter).
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stream::ProgressWriter;
+/// let mut buffer = Vec::new();
+/// let mut progress = 0;
+/// let mut writer = obj::ObjWriter::new(ProgressWriter::new(&mut buffer, |x| progress = x));
+/// let element = obj::ObjElement::Position(Point3::new(0.0, 0.0, 0.0));
+/// writer.write_element(&element).unwrap();
+/// writer.flush().unwrap();
+/// drop(writer);
+/// assert_eq!(progress, buffer.len() as u64);
+/// ```
+#[derive(Debug)]
+pub struct ProgressWriter<W, F> {
+    writer: W,
+    bytes: u64,
+    callback: F,
+}
+
+impl<W, F: FnMut(u64)> ProgressWriter<W, F> {
+    /// Creates the writer calling `callback` with the total number of the bytes written each time.
+    #[inline(always)]
+    pub const fn new(writer: W, callback: F) -> Self {
+        Self {
+            writer,
+            bytes: 0,
+            callback,
+        }
+    }
+
+    /// Returns the number of the bytes written so far.
+    #[inline(always)]
+    pub const fn bytes(&self) -> u64 { self.bytes }
+
+    /// Returns the inner writer.
+    #[inline(always)]
+    pub fn into_inner(self) -> W { self.writer }
+}
+
+impl<W: Write, F: FnMut(u64)> Write for ProgressWriter<W, F> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let bytes = self.writer.write(buf)?;
+        if bytes > 0 {
+            self.bytes += bytes as u64;
+            (self.callback)(self.bytes);
+        }
+        Ok(bytes)
+    }
+    #[inline(always)]
+    fn flush(&mut self) -> Result<()> { self.writer.flush() }
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use errors::Error;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 type Result<T> = std::result::Result<T, Error>;
 
 impl StandardVertex {
@@ -229,6 +230,238 @@ impl StructuredMesh {
             faces,
         }
     }
+
+    /// Creates new polygon mesh with quadrangles.
+    #[inline(always)]
+    pub fn to_polygon(&self) -> PolygonMesh { self.clone().destruct() }
+
+    /// Returns the structured mesh whose quadrangles are divided into four by the midpoints.
+    ///
+    /// The new positions, uv coordinates and normals are interpolated bilinearly.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)],
+    /// ];
+    /// let mesh = StructuredMesh::from_positions_and_uvs(positions, (vec![0.0, 1.0], vec![0.0, 1.0]));
+    /// let subdivided = mesh.subdivide();
+    /// assert_eq!(subdivided.positions().len(), 3);
+    /// assert_eq!(subdivided.positions()[0].len(), 3);
+    /// assert_eq!(subdivided.positions()[1][1], Point3::new(0.5, 0.5, 0.25));
+    /// assert_eq!(subdivided.uv_division().unwrap().0, &vec![0.0, 0.5, 1.0]);
+    /// ```
+    pub fn subdivide(&self) -> StructuredMesh {
+        StructuredMesh {
+            positions: refine_matrix(&self.positions, |p, q| p.midpoint(q)),
+            uv_division: self.uv_division.as_ref().map(|(udiv, vdiv)| {
+                let mid = |s: f64, t: f64| (s + t) / 2.0;
+                (refine_vector(udiv, mid), refine_vector(vdiv, mid))
+            }),
+            normals: self.normals.as_ref().map(|normals| {
+                refine_matrix(normals, |n0, n1| match (n0 + n1).so_small() {
+                    true => n0,
+                    false => (n0 + n1).normalize(),
+                })
+            }),
+        }
+    }
+
+    /// Returns the structured mesh consisting of every other row and column.
+    /// The first and the last rows and columns are always kept, so the boundary is not changed.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = (0..5)
+    ///     .map(|i| (0..4).map(|j| Point3::new(i as f64, j as f64, 0.0)).collect())
+    ///     .collect();
+    /// let mesh = StructuredMesh::from_positions(positions);
+    /// let coarsened = mesh.coarsen();
+    /// assert_eq!(coarsened.positions().len(), 3);
+    /// assert_eq!(
+    ///     coarsened.positions()[1],
+    ///     vec![
+    ///         Point3::new(2.0, 0.0, 0.0),
+    ///         Point3::new(2.0, 2.0, 0.0),
+    ///         Point3::new(2.0, 3.0, 0.0),
+    ///     ],
+    /// );
+    /// ```
+    pub fn coarsen(&self) -> StructuredMesh {
+        let rows = coarsen_indices(self.positions.len());
+        let columns = coarsen_indices(self.positions[0].len());
+        StructuredMesh {
+            positions: coarsen_matrix(&self.positions, &rows, &columns),
+            uv_division: self.uv_division.as_ref().map(|(udiv, vdiv)| {
+                (
+                    rows.iter().map(|i| udiv[*i]).collect(),
+                    columns.iter().map(|j| vdiv[*j]).collect(),
+                )
+            }),
+            normals: self
+                .normals
+                .as_ref()
+                .map(|normals| coarsen_matrix(normals, &rows, &columns)),
+        }
+    }
+
+    /// Returns the iso-line consisting of the `i`-th row of the positions, i.e. the `i`-th
+    /// u-parameter is fixed.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)],
+    /// ];
+    /// let mesh = StructuredMesh::from_positions(positions);
+    /// let line = mesh.u_iso_line(1);
+    /// assert_eq!(line.0, vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)]);
+    /// let line = mesh.v_iso_line(1);
+    /// assert_eq!(line.0, vec![Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 1.0, 1.0)]);
+    /// ```
+    #[inline(always)]
+    pub fn u_iso_line(&self, i: usize) -> PolylineCurve<Point3> {
+        PolylineCurve(self.positions[i].clone())
+    }
+
+    /// Returns the iso-line consisting of the `j`-th column of the positions, i.e. the `j`-th
+    /// v-parameter is fixed.
+    #[inline(always)]
+    pub fn v_iso_line(&self, j: usize) -> PolylineCurve<Point3> {
+        PolylineCurve(self.positions.iter().map(|row| row[j]).collect())
+    }
+}
+
+impl From<StructuredMesh> for PolygonMesh {
+    #[inline(always)]
+    fn from(mesh: StructuredMesh) -> PolygonMesh { mesh.destruct() }
+}
+
+impl TryFrom<&PolygonMesh> for StructuredMesh {
+    type Error = Error;
+    /// Recognizes the grid of quadrangles.
+    ///
+    /// The mesh has to consist only of quadrangles, forming a topological rectangle. The uv
+    /// divisions are restored if the uv coordinates are compatible with a grid, and the normals
+    /// are restored if all vertices have normals.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = (0..4)
+    ///     .map(|i| (0..3).map(|j| Point3::new(i as f64, j as f64, (i * j) as f64)).collect())
+    ///     .collect();
+    /// let udiv = vec![0.0, 1.0, 2.0, 3.0];
+    /// let vdiv = vec![0.0, 0.5, 1.0];
+    /// let mesh = StructuredMesh::from_positions_and_uvs(positions, (udiv, vdiv));
+    ///
+    /// let polygon = mesh.to_polygon();
+    /// let restored = StructuredMesh::try_from(&polygon).unwrap();
+    /// assert_eq!(restored.positions(), mesh.positions());
+    /// assert_eq!(restored.uv_division(), mesh.uv_division());
+    /// assert!(restored.normals().is_none());
+    /// ```
+    fn try_from(mesh: &PolygonMesh) -> Result<StructuredMesh> {
+        let quads = mesh.quad_faces();
+        if quads.is_empty() || !mesh.tri_faces().is_empty() || !mesh.other_faces().is_empty() {
+            return Err(Error::NotStructuredMesh);
+        }
+        // the map from the directed edges to the quadrangles and the local indices
+        let edge_map: HashMap<(usize, usize), (usize, usize)> = quads
+            .iter()
+            .enumerate()
+            .flat_map(|(i, quad)| {
+                (0..4).map(move |k| ((quad[k].pos, quad[(k + 1) % 4].pos), (i, k)))
+            })
+            .collect();
+        let is_boundary = |quad: &[StandardVertex; 4], k: usize| {
+            !edge_map.contains_key(&(quad[(k + 1) % 4].pos, quad[k].pos))
+        };
+        // A cell is a pair of a quadrangle and a rotation `r`, where the `k`-th side of the cell
+        // is the `(r + k)`-th edge of the quadrangle. The 1st side is adjacent to the next cell
+        // in the u-direction, and the 2nd side is adjacent to that in the v-direction.
+        let neighbor = |(q, r): (usize, usize), side: usize| {
+            let quad = &quads[q];
+            let (a, b) = (quad[(r + side) % 4].pos, quad[(r + side + 1) % 4].pos);
+            let (next, k) = *edge_map.get(&(b, a))?;
+            Some((next, (k + 4 - (side + 2) % 4) % 4))
+        };
+        let corner = (0..quads.len())
+            .flat_map(|q| (0..4).map(move |r| (q, r)))
+            .filter(|(q, r)| is_boundary(&quads[*q], (r + 3) % 4) && is_boundary(&quads[*q], *r))
+            .min_by_key(|(q, r)| quads[*q][*r].pos)
+            .ok_or(Error::NotStructuredMesh)?;
+        let columns = std::iter::successors(Some(corner), |cell| neighbor(*cell, 2))
+            .take(quads.len() + 1)
+            .map(|cell| {
+                std::iter::successors(Some(cell), |cell| neighbor(*cell, 1))
+                    .take(quads.len() + 1)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let (m, n) = (columns[0].len(), columns.len());
+        let regular = columns.iter().all(|column| column.len() == m);
+        let used = columns.iter().flatten().map(|(q, _)| *q).collect::<HashSet<_>>();
+        if !regular || m * n != quads.len() || used.len() != quads.len() {
+            return Err(Error::NotStructuredMesh);
+        }
+        // the vertices of the grid
+        let mut grid = vec![vec![None; n + 1]; m + 1];
+        for (j, column) in columns.iter().enumerate() {
+            for (i, (q, r)) in column.iter().enumerate() {
+                let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)];
+                for (k, (x, y)) in corners.into_iter().enumerate() {
+                    let vertex = quads[*q][(r + k) % 4];
+                    let old = grid[x][y].replace(vertex);
+                    if matches!(old, Some(v) if v.pos != vertex.pos) {
+                        return Err(Error::NotStructuredMesh);
+                    }
+                }
+            }
+        }
+        let grid = grid
+            .into_iter()
+            .map(|row| row.into_iter().collect::<Option<Vec<_>>>())
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::NotStructuredMesh)?;
+        let positions = grid
+            .iter()
+            .map(|row| row.iter().map(|v| mesh.positions()[v.pos]).collect())
+            .collect();
+        let uv_division = grid
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|v| Some(mesh.uv_coords()[v.uv?]))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+            .and_then(|uvs| {
+                let udiv = uvs.iter().map(|row| row[0].x).collect::<Vec<_>>();
+                let vdiv = uvs[0].iter().map(|uv| uv.y).collect::<Vec<_>>();
+                let compatible = uvs.iter().zip(&udiv).all(|(row, u)| {
+                    row.iter().zip(&vdiv).all(|(uv, v)| uv.x.near(u) && uv.y.near(v))
+                });
+                match compatible && check_vectors_regularity(&udiv, &vdiv).is_ok() {
+                    true => Some((udiv, vdiv)),
+                    false => None,
+                }
+            });
+        let normals = grid
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|v| Some(mesh.normals()[v.nor?]))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>();
+        Ok(StructuredMesh {
+            positions,
+            uv_division,
+            normals,
+        })
+    }
 }
 
 impl<'de> Deserialize<'de> for StructuredMesh {
@@ -309,3 +542,38 @@ fn check_matrix_vectors_compatibility<T>(
     }
     Ok(())
 }
+
+#[inline(always)]
+fn refine_vector<T: Copy>(vec: &[T], mid: impl Fn(T, T) -> T) -> Vec<T> {
+    vec.windows(2)
+        .flat_map(|w| [w[0], mid(w[0], w[1])])
+        .chain(vec.last().copied())
+        .collect()
+}
+
+fn refine_matrix<T: Copy>(matrix: &[Vec<T>], mid: impl Fn(T, T) -> T + Copy) -> Vec<Vec<T>> {
+    let rows = matrix
+        .iter()
+        .map(|row| refine_vector(row, mid))
+        .collect::<Vec<_>>();
+    rows.windows(2)
+        .flat_map(|w| {
+            let middle = w[0].iter().zip(&w[1]).map(|(a, b)| mid(*a, *b)).collect();
+            [w[0].clone(), middle]
+        })
+        .chain(rows.last().cloned())
+        .collect()
+}
+
+#[inline(always)]
+fn coarsen_matrix<T: Copy>(matrix: &[Vec<T>], rows: &[usize], columns: &[usize]) -> Vec<Vec<T>> {
+    rows.iter()
+        .map(|i| columns.iter().map(|j| matrix[*i][*j]).collect())
+        .collect()
+}
+
+/// the indices of every other element with the last one
+#[inline(always)]
+fn coarsen_indices(len: usize) -> Vec<usize> {
+    (0..len).filter(|i| i % 2 == 0 || i + 1 == len).collect()
+}
@@ -0,0 +1,314 @@
+use crate::*;
+use std::io::{BufWriter, Write};
+type Result<T> = std::result::Result<T, errors::Error>;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+ <Default Extension="rels"
+  ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+ <Default Extension="model"
+  ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+ <Relationship Target="/3D/3dmodel.model" Id="rel0"
+  Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+const MODEL_NAMESPACE: &str = "http://schemas.microsoft.com/3dmanufacturing/core/2015/02";
+
+/// the unit of the coordinates in the 3MF model
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// micrometer
+    Micron,
+    /// millimeter, the default unit of 3MF
+    #[default]
+    Millimeter,
+    /// centimeter
+    Centimeter,
+    /// inch
+    Inch,
+    /// foot
+    Foot,
+    /// meter
+    Meter,
+}
+
+impl Unit {
+    fn as_str(self) -> &'static str {
+        match self {
+            Unit::Micron => "micron",
+            Unit::Millimeter => "millimeter",
+            Unit::Centimeter => "centimeter",
+            Unit::Inch => "inch",
+            Unit::Foot => "foot",
+            Unit::Meter => "meter",
+        }
+    }
+}
+
+/// an object, a mesh with its name and color, in the 3MF model
+#[derive(Clone, Debug)]
+pub struct Object<'a> {
+    /// the name of the object
+    pub name: String,
+    /// the mesh of the object, assumed to be closed and oriented outward
+    pub mesh: &'a PolygonMesh,
+    /// the color of the object by RGBA, each component in `[0, 1]`
+    pub color: Option<Vector4>,
+}
+
+/// the 3MF model: the unit, the metadata and the objects
+#[derive(Clone, Debug, Default)]
+pub struct Model<'a> {
+    /// the unit of the coordinates
+    pub unit: Unit,
+    /// the pairs of the names and the values, e.g. `("Title", "cube")`
+    pub metadata: Vec<(String, String)>,
+    /// the objects, each of which is placed on the build plate as it is
+    pub objects: Vec<Object<'a>>,
+}
+
+impl<'a> Model<'a> {
+    /// Creates an empty model in `unit`.
+    #[inline(always)]
+    pub fn new(unit: Unit) -> Self {
+        Self {
+            unit,
+            ..Default::default()
+        }
+    }
+
+    /// Adds the metadata.
+    #[inline(always)]
+    pub fn push_metadata(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.metadata.push((name.into(), value.into()));
+    }
+
+    /// Adds the object.
+    #[inline(always)]
+    pub fn push_object(
+        &mut self,
+        name: impl Into<String>,
+        mesh: &'a PolygonMesh,
+        color: Option<Vector4>,
+    ) {
+        self.objects.push(Object {
+            name: name.into(),
+            mesh,
+            color,
+        });
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&apos;"),
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+/// `#RRGGBBAA`
+fn color_code(color: Vector4) -> String {
+    let c = color.map(|x| (f64::clamp(x, 0.0, 1.0) * 255.0).round() as u8);
+    format!("#{:02X}{:02X}{:02X}{:02X}", c.x, c.y, c.z, c.w)
+}
+
+fn write_model<W: Write>(model: &Model<'_>, writer: &mut W) -> Result<()> {
+    writer.write_all(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
+    writer.write_fmt(format_args!(
+        "<model unit=\"{}\" xml:lang=\"en-US\" xmlns=\"{MODEL_NAMESPACE}\">\n",
+        model.unit.as_str(),
+    ))?;
+    for (name, value) in &model.metadata {
+        writer.write_fmt(format_args!(
+            " <metadata name=\"{}\">{}</metadata>\n",
+            escape(name),
+            escape(value),
+        ))?;
+    }
+    writer.write_all(b" <resources>\n")?;
+    // The colors are the base materials with the id 1, and the objects have the ids from 2.
+    if model.objects.iter().any(|object| object.color.is_some()) {
+        writer.write_all(b"  <basematerials id=\"1\">\n")?;
+        for object in model.objects.iter() {
+            if let Some(color) = object.color {
+                writer.write_fmt(format_args!(
+                    "   <base name=\"{}\" displaycolor=\"{}\"/>\n",
+                    escape(&object.name),
+                    color_code(color),
+                ))?;
+            }
+        }
+        writer.write_all(b"  </basematerials>\n")?;
+    }
+    let mut pindex = 0;
+    for (i, object) in model.objects.iter().enumerate() {
+        writer.write_fmt(format_args!(
+            "  <object id=\"{}\" type=\"model\" name=\"{}\"",
+            i + 2,
+            escape(&object.name),
+        ))?;
+        if object.color.is_some() {
+            writer.write_fmt(format_args!(" pid=\"1\" pindex=\"{pindex}\""))?;
+            pindex += 1;
+        }
+        writer.write_all(b">\n   <mesh>\n    <vertices>\n")?;
+        for p in object.mesh.positions() {
+            writer.write_fmt(format_args!(
+                "     <vertex x=\"{:?}\" y=\"{:?}\" z=\"{:?}\"/>\n",
+                p.x, p.y, p.z,
+            ))?;
+        }
+        writer.write_all(b"    </vertices>\n    <triangles>\n")?;
+        // 3MF does not allow the degenerate triangles by the indices.
+        let triangles = object.mesh.faces().triangle_iter().filter(|tri| {
+            tri[0].pos != tri[1].pos && tri[1].pos != tri[2].pos && tri[2].pos != tri[0].pos
+        });
+        for tri in triangles {
+            writer.write_fmt(format_args!(
+                "     <triangle v1=\"{}\" v2=\"{}\" v3=\"{}\"/>\n",
+                tri[0].pos, tri[1].pos, tri[2].pos,
+            ))?;
+        }
+        writer.write_all(b"    </triangles>\n   </mesh>\n  </object>\n")?;
+    }
+    writer.write_all(b" </resources>\n <build>\n")?;
+    for i in 0..model.objects.len() {
+        writer.write_fmt(format_args!("  <item objectid=\"{}\"/>\n", i + 2))?;
+    }
+    writer.write_all(b" </build>\n</model>\n")?;
+    Ok(())
+}
+
+/// CRC-32 of the zip archive
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0_u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xEDB88320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+/// Converts the size or the offset into the field of the zip archive.
+///
+/// The archive is not written in ZIP64, so its size is limited to 4 GiB.
+fn zip_field(size: usize) -> Result<u32> {
+    u32::try_from(size).map_err(|_| {
+        let message = "the 3MF package exceeds 4 GiB, the limit of the zip archive";
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, message).into()
+    })
+}
+
+/// Writes the zip archive of the uncompressed files.
+/// # Failures
+/// Returns the I/O error of `InvalidInput` if the archive exceeds 4 GiB.
+fn write_zip<W: Write>(files: &[(&str, &[u8])], writer: &mut W) -> Result<()> {
+    // 1980-01-01 00:00:00, the origin of the MS-DOS date
+    const TIME: u16 = 0;
+    const DATE: u16 = 0x21;
+    let header = |name: &str, data: &[u8]| -> Result<Vec<u8>> {
+        let size = zip_field(data.len())?;
+        let mut header = Vec::new();
+        header.extend(20_u16.to_le_bytes()); // the version needed to extract
+        header.extend(0_u16.to_le_bytes()); // the flags
+        header.extend(0_u16.to_le_bytes()); // the method, stored
+        header.extend(TIME.to_le_bytes());
+        header.extend(DATE.to_le_bytes());
+        header.extend(crc32(data).to_le_bytes());
+        header.extend(size.to_le_bytes()); // the compressed size
+        header.extend(size.to_le_bytes()); // the uncompressed size
+        header.extend((name.len() as u16).to_le_bytes());
+        header.extend(0_u16.to_le_bytes()); // the length of the extra field
+        Ok(header)
+    };
+    let mut offset = 0_usize;
+    let mut central = Vec::new();
+    for (name, data) in files {
+        let header = header(name, data)?;
+        writer.write_all(&0x04034b50_u32.to_le_bytes())?;
+        writer.write_all(&header)?;
+        writer.write_all(name.as_bytes())?;
+        writer.write_all(data)?;
+
+        central.extend(0x02014b50_u32.to_le_bytes());
+        central.extend(20_u16.to_le_bytes()); // the version made by
+        central.extend(&header);
+        central.extend(0_u16.to_le_bytes()); // the length of the comment
+        central.extend(0_u16.to_le_bytes()); // the disk number
+        central.extend(0_u16.to_le_bytes()); // the internal attributes
+        central.extend(0_u32.to_le_bytes()); // the external attributes
+        central.extend(zip_field(offset)?.to_le_bytes());
+        central.extend(name.as_bytes());
+        offset += 30 + name.len() + data.len();
+    }
+    writer.write_all(&central)?;
+    writer.write_all(&0x06054b50_u32.to_le_bytes())?;
+    writer.write_all(&0_u16.to_le_bytes())?; // the number of this disk
+    writer.write_all(&0_u16.to_le_bytes())?; // the disk of the central directory
+    writer.write_all(&(files.len() as u16).to_le_bytes())?;
+    writer.write_all(&(files.len() as u16).to_le_bytes())?;
+    writer.write_all(&zip_field(central.len())?.to_le_bytes())?;
+    writer.write_all(&zip_field(offset)?.to_le_bytes())?;
+    writer.write_all(&0_u16.to_le_bytes())?; // the length of the comment
+    Ok(())
+}
+
+/// Writes the model to 3MF file.
+///
+/// The package consists of the content types, the relationships and the model part
+/// `3D/3dmodel.model`, and is stored without compression. The colors of the objects are written
+/// as the base materials. The solids are written by their tessellations, i.e. the polygon
+/// meshes whose normals are outward.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use threemf::{Model, Unit};
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+/// ];
+/// let faces = Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]]);
+/// let tetrahedron = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+///
+/// let mut model = Model::new(Unit::Centimeter);
+/// model.push_metadata("Title", "tetrahedron");
+/// model.push_object("tetrahedron", &tetrahedron, Some(Vector4::new(1.0, 0.0, 0.0, 1.0)));
+/// let mut buffer = Vec::new();
+/// threemf::write(&model, &mut buffer).unwrap();
+/// assert_eq!(&buffer[..4], b"PK\x03\x04");
+/// ```
+pub fn write<W: Write>(model: &Model<'_>, writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    let mut model_part = Vec::new();
+    write_model(model, &mut model_part)?;
+    let files = [
+        ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+        ("_rels/.rels", RELATIONSHIPS.as_bytes()),
+        ("3D/3dmodel.model", model_part.as_slice()),
+    ];
+    write_zip(&files, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
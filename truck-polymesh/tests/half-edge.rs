@@ -0,0 +1,90 @@
+use truck_polymesh::{half_edge::HalfEdgeMesh, *};
+
+fn cube() -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect();
+    let faces = Faces::from_iter([
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn closed_cube() {
+    let mesh = cube();
+    let half_edges = HalfEdgeMesh::from(&mesh);
+    assert_eq!(half_edges.vertex_count(), 8);
+    assert_eq!(half_edges.face_count(), 6);
+    assert_eq!(half_edges.half_edge_count(), 24);
+    (0..24).for_each(|edge| {
+        let twin = half_edges.twin(edge).unwrap();
+        assert_eq!(half_edges.twin(twin), Some(edge));
+        assert_eq!(half_edges.origin(twin), half_edges.target(edge));
+        assert_ne!(half_edges.face(twin), half_edges.face(edge));
+        assert_eq!(half_edges.next(half_edges.prev(edge)), edge);
+    });
+    (0..8).for_each(|vertex| {
+        let ring: Vec<usize> = half_edges.vertex_ring(vertex).collect();
+        assert_eq!(ring.len(), 3);
+        assert!(ring.iter().all(|edge| half_edges.origin(*edge) == vertex));
+        let mut adjacent = half_edges.adjacent_vertices(vertex);
+        adjacent.sort();
+        let mut expected: Vec<usize> = (0..3).map(|k| vertex ^ (1 << k)).collect();
+        expected.sort();
+        assert_eq!(adjacent, expected);
+    });
+    (0..6).for_each(|face| {
+        let edges: Vec<usize> = half_edges.face_loop(face).collect();
+        assert_eq!(edges.len(), 4);
+        assert!(edges.iter().all(|edge| half_edges.face(*edge) == face));
+    });
+    assert_eq!(PolygonMesh::from(half_edges), mesh);
+}
+
+#[test]
+fn boundaries_and_non_manifold_edges() {
+    // the three triangles sharing the edge between 0 and 1
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.5, 1.0, 0.0),
+        Point3::new(0.5, -1.0, 0.0),
+        Point3::new(0.5, 0.0, 1.0),
+        Point3::new(2.0, 2.0, 2.0),
+    ];
+    let faces = Faces::from_iter([[0, 1, 2], [1, 0, 3], [0, 1, 4]]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let half_edges = HalfEdgeMesh::from(&mesh);
+    assert!((0..9).all(|edge| half_edges.is_boundary(edge)));
+    assert_eq!(half_edges.outgoing(5), None);
+    assert_eq!(half_edges.vertex_ring(5).count(), 0);
+    assert_eq!(half_edges.adjacent_vertices(2), vec![0, 1]);
+
+    // the two triangles without the third
+    let faces = Faces::from_iter([[0, 1, 2], [1, 0, 3]]);
+    let mesh = PolygonMesh::new(mesh.attributes().clone(), faces);
+    let half_edges = HalfEdgeMesh::from(&mesh);
+    let shared = (0..6).filter(|edge| !half_edges.is_boundary(*edge));
+    assert_eq!(shared.count(), 2);
+    let boundary = half_edges.outgoing(0).unwrap();
+    assert!(half_edges.is_boundary(boundary));
+    assert_eq!(half_edges.adjacent_vertices(0), vec![3, 1, 2]);
+}
@@ -270,3 +270,104 @@ fn incomplete_obj_oi_test() {
     let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
     assert_eq!(mesh, read_mesh);
 }
+
+#[test]
+fn write_vec_obj_oi_test() {
+    let mesh0 = obj::read(TEAPOT_POSITION_OBJ).unwrap();
+    let mesh1 = obj::read(TEAPOT_WITHNORMALS_OBJ).unwrap();
+    let mut gened_obj: Vec<u8> = Vec::new();
+    obj::write_vec(&[mesh0.clone(), mesh1.clone()], &mut gened_obj).unwrap();
+    let model = obj::read_model(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
+    let names: Vec<&str> = model.groups.iter().map(|g| g.name.as_str()).collect();
+    assert_eq!(names, vec!["0", "1"]);
+
+    let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
+    let mut merged = mesh0;
+    merged.merge(mesh1);
+    assert_eq!(read_mesh, merged);
+}
+
+#[test]
+fn negative_indices_and_materials_obj_i_test() {
+    let obj = b"mtllib a.mtl b.mtl
+v 0 0 0
+v 1 0 0
+v 1 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+g square
+usemtl red
+f -3/-3 -2/-2 -1/-1
+v 0 1 0
+vt 0 1
+f 1/1 3/3 -1/-1
+usemtl blue
+f 1 2 4
+g
+f 2 3 4 # comment
+";
+    let model = obj::read_model(&obj[..]).unwrap();
+    assert_eq!(model.material_libraries, vec!["a.mtl", "b.mtl"]);
+    let groups: Vec<_> = model
+        .groups
+        .iter()
+        .map(|g| (g.name.as_str(), g.material.as_deref(), g.mesh.faces().len()))
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            ("square", Some("red"), 2),
+            ("square", Some("blue"), 1),
+            ("", Some("blue"), 1),
+        ]
+    );
+    let red = &model.groups[0].mesh;
+    assert_eq!(red.positions().len(), 4);
+    assert_eq!(red.uv_coords().len(), 4);
+    assert_eq!(red.faces()[1][2].pos, 3);
+    assert_eq!(red.faces()[1][2].uv, Some(3));
+
+    let mesh = obj::read(&obj[..]).unwrap();
+    assert_eq!(mesh.positions().len(), 4);
+    assert_eq!(mesh.faces().len(), 4);
+
+    let mtl = b"newmtl red
+Ka 0.1
+Kd 1.0 0.0 0.0
+Tr 0.25
+map_Kd textures/red brick.png
+newmtl blue
+Kd 0 0 1
+Ns 10
+";
+    let materials = obj::read_mtl(&mtl[..]).unwrap();
+    assert_eq!(materials.len(), 2);
+    assert_eq!(materials[0].ambient, Vector3::new(0.1, 0.1, 0.1));
+    assert_eq!(materials[0].diffuse, Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(materials[0].dissolve, 0.75);
+    assert_eq!(
+        materials[0].diffuse_map.as_deref(),
+        Some("textures/red brick.png")
+    );
+    assert_eq!(materials[1].name, "blue");
+    assert_eq!(materials[1].shininess, 10.0);
+    assert_eq!(materials[1].ambient, Vector3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn streaming_obj_ioi_test() {
+    let mut read_bytes = 0;
+    let reader = stream::ProgressReader::new(PONY_COMPLETE_OBJ, |bytes| read_bytes = bytes);
+    let mut gened_obj: Vec<u8> = Vec::new();
+    let mut writer = obj::ObjWriter::new(&mut gened_obj);
+    for element in obj::ObjReader::new(reader) {
+        writer.write_element(&element.unwrap()).unwrap();
+    }
+    writer.flush().unwrap();
+    drop(writer);
+    assert_eq!(read_bytes, PONY_COMPLETE_OBJ.len() as u64);
+    let read_mesh0 = obj::read(PONY_COMPLETE_OBJ).unwrap();
+    let read_mesh1 = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
+    assert_eq!(read_mesh0, read_mesh1);
+}
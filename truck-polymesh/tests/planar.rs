@@ -0,0 +1,96 @@
+use truck_polymesh::*;
+
+fn regular_polygon(center: Point2, radius: f64, division: usize) -> Vec<Point2> {
+    (0..division)
+        .map(|i| {
+            let t = 2.0 * std::f64::consts::PI * i as f64 / division as f64;
+            center + Vector2::new(t.cos(), t.sin()) * radius
+        })
+        .collect()
+}
+
+fn area(contours: &[Vec<Point2>]) -> f64 {
+    contours.iter().map(|contour| planar::signed_area(contour)).sum()
+}
+
+#[test]
+fn inclusion_exclusion() {
+    let a = vec![regular_polygon(Point2::new(0.0, 0.0), 1.0, 100)];
+    let b = vec![regular_polygon(Point2::new(1.0, 0.2), 0.8, 77)];
+    let union = planar::union(&a, &b);
+    let intersection = planar::intersection(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert_eq!(intersection.len(), 1);
+    assert!((area(&union) + area(&intersection)).near(&(area(&a) + area(&b))));
+
+    let difference0 = planar::difference(&a, &b);
+    let difference1 = planar::difference(&b, &a);
+    assert!((area(&difference0) + area(&intersection)).near(&area(&a)));
+    assert!((area(&difference1) + area(&intersection)).near(&area(&b)));
+    let xor = planar::xor(&a, &b);
+    assert!(area(&xor).near(&(area(&difference0) + area(&difference1))));
+}
+
+#[test]
+fn touching_squares() {
+    let square = |x: f64, y: f64| {
+        vec![vec![
+            Point2::new(x, y),
+            Point2::new(x + 1.0, y),
+            Point2::new(x + 1.0, y + 1.0),
+            Point2::new(x, y + 1.0),
+        ]]
+    };
+    // sharing an edge: merged into a rectangle
+    let union = planar::union(&square(0.0, 0.0), &square(1.0, 0.0));
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].len(), 4);
+    assert!(area(&union).near(&2.0));
+    // sharing a vertex: separated contours
+    let union = planar::union(&square(0.0, 0.0), &square(1.0, 1.0));
+    assert_eq!(union.len(), 2);
+    assert!(area(&union).near(&2.0));
+    assert!(planar::intersection(&square(0.0, 0.0), &square(1.0, 0.0)).is_empty());
+}
+
+#[test]
+fn offset_with_hole() {
+    let outer = regular_polygon(Point2::new(0.0, 0.0), 1.0, 4);
+    let mut hole = regular_polygon(Point2::new(0.0, 0.0), 0.5, 4);
+    hole.reverse();
+    let region = vec![outer, hole];
+    let side = f64::sqrt(2.0);
+
+    let offset = planar::offset(&region, 0.1, planar::JoinType::Miter);
+    assert_eq!(offset.len(), 2);
+    let expected = (side + 0.2).powi(2) - (side / 2.0 - 0.2).powi(2);
+    assert!(area(&offset).near(&expected));
+
+    // the hole gets larger than the outer contour
+    let offset = planar::offset(&region, -0.3, planar::JoinType::Round);
+    assert!(offset.is_empty());
+}
+
+#[test]
+fn self_intersecting_offset() {
+    // L-shape
+    let region = vec![vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ]];
+    let offset = planar::offset(&region, -0.1, planar::JoinType::Miter);
+    assert_eq!(offset.len(), 1);
+    assert_eq!(offset[0].len(), 6);
+    assert!(area(&offset).near(&2.24));
+    let offset = planar::offset(&region, 0.1, planar::JoinType::Miter);
+    assert_eq!(offset.len(), 1);
+    assert!(area(&offset).near(&3.84));
+    // thin arms remain connected
+    let offset = planar::offset(&region, -0.45, planar::JoinType::Miter);
+    assert_eq!(offset.len(), 1);
+    region[0].iter().for_each(|p| assert!(!planar::contains(&offset, *p)));
+}
@@ -0,0 +1,100 @@
+use truck_polymesh::*;
+
+#[test]
+fn ply_binary_read() {
+    let mut ply = b"ply
+format binary_little_endian 1.0
+comment made by hand
+element vertex 2
+property float x
+property float y
+property float z
+property float nx
+property float ny
+property float nz
+element face 1
+property list uchar int vertex_indices
+end_header
+"
+    .to_vec();
+    let values: [[f32; 6]; 2] = [
+        [0.0, 1.0, 2.0, 1.0, 0.0, 0.0],
+        [3.0, 4.0, 5.0, 0.0, 0.0, 1.0],
+    ];
+    values
+        .iter()
+        .flatten()
+        .for_each(|x| ply.extend(x.to_le_bytes()));
+    ply.push(3);
+    [0i32, 1, 1].iter().for_each(|i| ply.extend(i.to_le_bytes()));
+
+    let cloud = ply::read(ply.as_slice()).unwrap();
+    assert_eq!(
+        cloud.positions(),
+        &vec![Point3::new(0.0, 1.0, 2.0), Point3::new(3.0, 4.0, 5.0)]
+    );
+    assert_eq!(
+        cloud.normals().unwrap(),
+        &vec![Vector3::unit_x(), Vector3::unit_z()]
+    );
+    assert!(cloud.colors().is_none());
+}
+
+#[test]
+fn ply_io() {
+    let cloud = PointCloud::new(
+        vec![Point3::new(0.0, 1.0, 2.0), Point3::new(-1.5, 1.0e-7, 2.25)],
+        Some(vec![Vector3::unit_x(), Vector3::unit_y()]),
+        Some(vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0)]),
+    );
+    let mut buffer = Vec::new();
+    ply::write(&cloud, &mut buffer).unwrap();
+    assert_eq!(ply::read(buffer.as_slice()).unwrap(), cloud);
+    assert!(ply::read(&b"obj\n"[..]).is_err());
+}
+
+fn las_buffer(points: &[([i32; 3], [u16; 3])]) -> Vec<u8> {
+    let mut header = vec![0u8; 227];
+    header[0..4].copy_from_slice(b"LASF");
+    header[24] = 1;
+    header[25] = 2;
+    header[94..96].copy_from_slice(&227u16.to_le_bytes());
+    header[96..100].copy_from_slice(&227u32.to_le_bytes());
+    header[104] = 2;
+    header[105..107].copy_from_slice(&26u16.to_le_bytes());
+    header[107..111].copy_from_slice(&(points.len() as u32).to_le_bytes());
+    header[131..139].copy_from_slice(&0.01f64.to_le_bytes());
+    header[139..147].copy_from_slice(&0.01f64.to_le_bytes());
+    header[147..155].copy_from_slice(&0.01f64.to_le_bytes());
+    header[155..163].copy_from_slice(&100.0f64.to_le_bytes());
+    header[163..171].copy_from_slice(&200.0f64.to_le_bytes());
+    header[171..179].copy_from_slice(&0.0f64.to_le_bytes());
+    points.iter().for_each(|(pos, col)| {
+        let mut record = vec![0u8; 26];
+        pos.iter().enumerate().for_each(|(i, x)| {
+            record[4 * i..4 * i + 4].copy_from_slice(&x.to_le_bytes());
+        });
+        col.iter().enumerate().for_each(|(i, x)| {
+            record[20 + 2 * i..22 + 2 * i].copy_from_slice(&x.to_le_bytes());
+        });
+        header.extend(record);
+    });
+    header
+}
+
+#[test]
+fn las_read() {
+    let buffer = las_buffer(&[([100, -200, 300], [65535, 0, 0]), ([0, 0, 0], [0, 0, 65535])]);
+    let header = las::read_header_only(buffer.as_slice()).unwrap();
+    assert_eq!(header.version, (1, 2));
+    assert_eq!(header.point_format, 2);
+    assert_eq!(header.number_of_points, 2);
+
+    let cloud = las::read(buffer.as_slice()).unwrap();
+    assert_eq!(cloud.len(), 2);
+    assert!(cloud.positions()[0].near(&Point3::new(101.0, 198.0, 3.0)));
+    assert!(cloud.positions()[1].near(&Point3::new(100.0, 200.0, 0.0)));
+    assert_eq!(cloud.colors().unwrap()[0], Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(cloud.colors().unwrap()[1], Vector3::new(0.0, 0.0, 1.0));
+    assert!(las::read(&buffer[..100]).is_err());
+}
@@ -0,0 +1,67 @@
+use threemf::{Model, Unit};
+use truck_polymesh::*;
+
+fn unit_square() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2, 3]]),
+    )
+}
+
+/// the entries of the stored zip archive by the local file headers
+fn zip_entries(buffer: &[u8]) -> Vec<(String, String)> {
+    let u16_at = |i: usize| u16::from_le_bytes([buffer[i], buffer[i + 1]]) as usize;
+    let u32_at = |i: usize| u32::from_le_bytes(buffer[i..i + 4].try_into().unwrap()) as usize;
+    let mut entries = Vec::new();
+    let mut idx = 0;
+    while u32_at(idx) == 0x04034b50 {
+        let (size, name_len) = (u32_at(idx + 22), u16_at(idx + 26));
+        let name = &buffer[idx + 30..idx + 30 + name_len];
+        let data = &buffer[idx + 30 + name_len..idx + 30 + name_len + size];
+        entries.push((
+            String::from_utf8(name.to_vec()).unwrap(),
+            String::from_utf8(data.to_vec()).unwrap(),
+        ));
+        idx += 30 + name_len + size;
+    }
+    entries
+}
+
+#[test]
+fn threemf_output() {
+    let square = unit_square();
+    let mut model = Model::new(Unit::Inch);
+    model.push_metadata("Title", "squares & colors");
+    model.push_object("red", &square, Some(Vector4::new(1.0, 0.0, 0.0, 1.0)));
+    model.push_object("plain", &square, None);
+    model.push_object("blue", &square, Some(Vector4::new(0.0, 0.0, 1.0, 0.5)));
+    let mut buffer = Vec::new();
+    threemf::write(&model, &mut buffer).unwrap();
+
+    let entries = zip_entries(&buffer);
+    let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["[Content_Types].xml", "_rels/.rels", "3D/3dmodel.model"]
+    );
+    let part = &entries[2].1;
+    assert!(part.contains("unit=\"inch\""));
+    assert!(part.contains("<metadata name=\"Title\">squares &amp; colors</metadata>"));
+    assert!(part.contains("displaycolor=\"#FF0000FF\""));
+    assert!(part.contains("displaycolor=\"#0000FF80\""));
+    assert!(part.contains("name=\"red\" pid=\"1\" pindex=\"0\">"));
+    assert!(part.contains("name=\"plain\">"));
+    assert!(part.contains("name=\"blue\" pid=\"1\" pindex=\"1\">"));
+    // the quadrangle is divided into two triangles.
+    assert_eq!(part.matches("<triangle ").count(), 6);
+    assert_eq!(part.matches("<item objectid=").count(), 3);
+}
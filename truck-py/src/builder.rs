@@ -0,0 +1,181 @@
+use crate::{to_pyerr, AnyShape, Edge, Face, Solid, Vertex, Wire};
+use pyo3::{exceptions::PyTypeError, prelude::*};
+use truck_modeling::*;
+
+/// Creates and returns a vertex by a three dimensional point.
+#[pyfunction]
+pub fn vertex(point: [f64; 3]) -> Vertex { builder::vertex(point.into()).into() }
+/// Returns a line from `vertex0` to `vertex1`.
+#[pyfunction]
+pub fn line(vertex0: &Vertex, vertex1: &Vertex) -> PyResult<Edge> {
+    builder::try_line(vertex0, vertex1)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+/// Returns a circle arc from `vertex0` to `vertex1` via `transit`.
+#[pyfunction]
+pub fn circle_arc(vertex0: &Vertex, vertex1: &Vertex, transit: [f64; 3]) -> PyResult<Edge> {
+    builder::try_circle_arc(vertex0, vertex1, transit.into())
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+/// Returns a Bezier curve from `vertex0` to `vertex1` with inter control points `inter_points`.
+#[pyfunction]
+pub fn bezier(vertex0: &Vertex, vertex1: &Vertex, inter_points: Vec<[f64; 3]>) -> PyResult<Edge> {
+    let inter_points = inter_points.into_iter().map(Point3::from).collect();
+    builder::try_bezier(vertex0, vertex1, inter_points)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+/// Returns a homotopic face from `edge0` to `edge1`.
+#[pyfunction]
+pub fn homotopy(edge0: &Edge, edge1: &Edge) -> PyResult<Face> {
+    builder::try_homotopy(edge0, edge1)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+/// Try attatiching a plane whose boundaries are `wires`.
+#[pyfunction]
+pub fn try_attach_plane(wires: Vec<Wire>) -> PyResult<Face> {
+    let wires = wires.into_iter().map(Into::into).collect::<Vec<_>>();
+    builder::try_attach_plane(&wires)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+
+macro_rules! derive_all_shape {
+    ($shape: expr, $function: expr, ($($arg: expr),*)) => {
+        match $shape {
+            AnyShape::Vertex(x) => $function(x.as_ref(), $($arg),*).into(),
+            AnyShape::Edge(x) => $function(x.as_ref(), $($arg),*).into(),
+            AnyShape::Wire(x) => $function(x.as_ref(), $($arg),*).into(),
+            AnyShape::Face(x) => $function(x.as_ref(), $($arg),*).into(),
+            AnyShape::Shell(x) => $function(x.as_ref(), $($arg),*).into(),
+            AnyShape::Solid(x) => $function(x.as_ref(), $($arg),*).into(),
+        }
+    };
+}
+
+/// Returns a translated vertex, edge, wire, face, shell or solid.
+#[pyfunction]
+pub fn translated(shape: AnyShape, vector: [f64; 3]) -> AnyShape {
+    let vector = Vector3::from(vector);
+    derive_all_shape!(&shape, builder::translated, (vector))
+}
+
+/// Returns a rotated vertex, edge, wire, face, shell or solid.
+#[pyfunction]
+pub fn rotated(shape: AnyShape, origin: [f64; 3], axis: [f64; 3], angle: f64) -> AnyShape {
+    let (origin, axis) = (Point3::from(origin), Vector3::from(axis));
+    derive_all_shape!(&shape, builder::rotated, (origin, axis, Rad(angle)))
+}
+
+/// Returns a scaled vertex, edge, wire, face, shell or solid.
+///
+/// `scalars` is a float or a sequence of three floats.
+#[pyfunction]
+pub fn scaled(shape: AnyShape, origin: [f64; 3], scalars: &PyAny) -> PyResult<AnyShape> {
+    let origin = Point3::from(origin);
+    let scalars = match scalars.extract::<f64>() {
+        Ok(s) => Vector3::new(s, s, s),
+        Err(_) => Vector3::from(scalars.extract::<[f64; 3]>()?),
+    };
+    let shape: AnyShape = derive_all_shape!(&shape, builder::scaled, (origin, scalars));
+    Ok(shape)
+}
+
+macro_rules! derive_all_sweepable {
+    ($shape: expr, $function: expr, ($($arg: expr),*)) => {
+        match $shape {
+            AnyShape::Vertex(x) => $function(x.as_ref(), $($arg),*).map(Into::into),
+            AnyShape::Edge(x) => $function(x.as_ref(), $($arg),*).map(Into::into),
+            AnyShape::Wire(x) => $function(x.as_ref(), $($arg),*).map(Into::into),
+            AnyShape::Face(x) => $function(x.as_ref(), $($arg),*).map(Into::into),
+            _ => {
+                let msg = "sweep is only implemented to Vertex, Edge, Wire and Face.";
+                return Err(PyTypeError::new_err(msg));
+            }
+        }
+    };
+}
+
+/// Sweeps a vertex, an edge, a wire, or a face by a vector.
+#[pyfunction]
+pub fn tsweep(shape: AnyShape, vector: [f64; 3]) -> PyResult<AnyShape> {
+    let vector = Vector3::from(vector);
+    derive_all_sweepable!(&shape, builder::try_tsweep, (vector)).map_err(to_pyerr)
+}
+
+/// Sweeps a vertex, an edge, a wire, or a face by the rotation.
+#[pyfunction]
+pub fn rsweep(shape: AnyShape, origin: [f64; 3], axis: [f64; 3], angle: f64) -> PyResult<AnyShape> {
+    let (origin, axis) = (Point3::from(origin), Vector3::from(axis));
+    derive_all_sweepable!(&shape, builder::try_rsweep, (origin, axis, Rad(angle))).map_err(to_pyerr)
+}
+
+/// Returns the cuboid whose diagonal corners are `corner0` and `corner1`.
+#[pyfunction]
+pub fn cuboid(corner0: [f64; 3], corner1: [f64; 3]) -> PyResult<Solid> {
+    builder::try_cuboid(corner0.into(), corner1.into())
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+
+/// Returns the sphere with `center` and `radius`.
+#[pyfunction]
+pub fn sphere(center: [f64; 3], radius: f64) -> PyResult<Solid> {
+    builder::try_sphere(center.into(), radius)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+
+/// Returns the cylinder standing on `bottom` along `axis` with `radius`.
+#[pyfunction]
+pub fn cylinder(bottom: [f64; 3], axis: [f64; 3], radius: f64) -> PyResult<Solid> {
+    builder::try_cylinder(bottom.into(), axis.into(), radius)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+
+/// Returns the cone standing on `base` along `axis` with the base `radius`.
+#[pyfunction]
+pub fn circular_cone(base: [f64; 3], axis: [f64; 3], radius: f64) -> PyResult<Solid> {
+    builder::try_circular_cone(base.into(), axis.into(), radius)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+
+/// Returns the torus around `axis` through `center`.
+#[pyfunction]
+pub fn torus(
+    center: [f64; 3],
+    axis: [f64; 3],
+    major_radius: f64,
+    minor_radius: f64,
+) -> PyResult<Solid> {
+    builder::try_torus(center.into(), axis.into(), major_radius, minor_radius)
+        .map(Into::into)
+        .map_err(to_pyerr)
+}
+
+/// the submodule `truck.builder`
+pub fn module(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "builder")?;
+    m.add_function(wrap_pyfunction!(vertex, m)?)?;
+    m.add_function(wrap_pyfunction!(line, m)?)?;
+    m.add_function(wrap_pyfunction!(circle_arc, m)?)?;
+    m.add_function(wrap_pyfunction!(bezier, m)?)?;
+    m.add_function(wrap_pyfunction!(homotopy, m)?)?;
+    m.add_function(wrap_pyfunction!(try_attach_plane, m)?)?;
+    m.add_function(wrap_pyfunction!(translated, m)?)?;
+    m.add_function(wrap_pyfunction!(rotated, m)?)?;
+    m.add_function(wrap_pyfunction!(scaled, m)?)?;
+    m.add_function(wrap_pyfunction!(tsweep, m)?)?;
+    m.add_function(wrap_pyfunction!(rsweep, m)?)?;
+    m.add_function(wrap_pyfunction!(cuboid, m)?)?;
+    m.add_function(wrap_pyfunction!(sphere, m)?)?;
+    m.add_function(wrap_pyfunction!(cylinder, m)?)?;
+    m.add_function(wrap_pyfunction!(circular_cone, m)?)?;
+    m.add_function(wrap_pyfunction!(torus, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,63 @@
+//! Python wrapper API for truck
+//!
+//! The extension module `truck` is built by [maturin](https://github.com/PyO3/maturin) with the
+//! feature `extension-module`. Points and vectors are passed as sequences of three floats.
+
+#![cfg_attr(not(debug_assertions), deny(warnings))]
+#![deny(clippy::all, rust_2018_idioms)]
+#![warn(
+    missing_docs,
+    missing_debug_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unsafe_code,
+    unstable_features,
+    unused_import_braces,
+    unused_qualifications
+)]
+
+use derive_more::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// truck struct wrapped by python
+pub trait IntoPython: Sized {
+    /// python wrapper struct
+    type PythonWrapper: From<Self>;
+    /// Into python wrapper
+    fn into_python(self) -> Self::PythonWrapper { self.into() }
+}
+
+/// Converts the errors of truck into `ValueError` of python.
+#[inline(always)]
+pub fn to_pyerr(error: impl std::fmt::Display) -> PyErr { PyValueError::new_err(error.to_string()) }
+
+mod shape;
+pub use shape::{AnyShape, Edge, Face, Shell, Solid, StepHeaderDescriptor, Vertex, Wire};
+/// the building model utility API
+pub mod builder;
+mod polygon;
+/// the boolean operators: `and_`, `or_`, `not_`.
+pub mod shapeops;
+pub use polygon::{PolygonMesh, StlType};
+/// STEP IO
+pub mod step_in;
+
+/// the extension module `truck`
+#[pymodule]
+#[pyo3(name = "truck")]
+pub fn truck_py(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<Vertex>()?;
+    m.add_class::<Edge>()?;
+    m.add_class::<Wire>()?;
+    m.add_class::<Face>()?;
+    m.add_class::<Shell>()?;
+    m.add_class::<Solid>()?;
+    m.add_class::<StepHeaderDescriptor>()?;
+    m.add_class::<PolygonMesh>()?;
+    m.add_class::<StlType>()?;
+    m.add_submodule(builder::module(py)?)?;
+    m.add_submodule(shapeops::module(py)?)?;
+    m.add_submodule(step_in::module(py)?)?;
+    Ok(())
+}
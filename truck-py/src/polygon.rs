@@ -0,0 +1,98 @@
+use crate::*;
+use pyo3::types::PyBytes;
+use truck_meshalgo::prelude::*;
+
+/// Python wrapper by Polygonmesh
+#[pyclass(module = "truck")]
+#[derive(Clone, Debug, Into, From, Deref, DerefMut)]
+pub struct PolygonMesh(truck_meshalgo::prelude::PolygonMesh);
+
+impl IntoPython for truck_meshalgo::prelude::PolygonMesh {
+    type PythonWrapper = PolygonMesh;
+}
+
+/// STL type.
+#[pyclass(module = "truck")]
+#[derive(Copy, Clone, Debug)]
+pub enum StlType {
+    /// Determine STL type automatically.
+    ///
+    /// # Reading
+    /// If the first 5 bytes are..
+    /// - "solid" => ascii format
+    /// - otherwise => binary format
+    ///
+    /// # Writing
+    /// Always binary format.
+    Automatic,
+    /// ASCII format.
+    Ascii,
+    /// Binary format.
+    Binary,
+}
+
+impl From<StlType> for stl::StlType {
+    fn from(stl_type: StlType) -> stl::StlType {
+        match stl_type {
+            StlType::Automatic => stl::StlType::Automatic,
+            StlType::Ascii => stl::StlType::Ascii,
+            StlType::Binary => stl::StlType::Binary,
+        }
+    }
+}
+
+#[pymethods]
+impl PolygonMesh {
+    /// input from obj format
+    #[staticmethod]
+    pub fn from_obj(data: &[u8]) -> PyResult<PolygonMesh> {
+        obj::read::<&[u8]>(data)
+            .map(IntoPython::into_python)
+            .map_err(to_pyerr)
+    }
+    /// input from STL format
+    #[staticmethod]
+    #[pyo3(signature = (data, stl_type = StlType::Automatic))]
+    pub fn from_stl(data: &[u8], stl_type: StlType) -> PyResult<PolygonMesh> {
+        stl::read::<&[u8]>(data, stl_type.into())
+            .map(IntoPython::into_python)
+            .map_err(to_pyerr)
+    }
+    /// output obj format
+    pub fn to_obj<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let mut res = Vec::new();
+        obj::write(&self.0, &mut res).map_err(to_pyerr)?;
+        Ok(PyBytes::new(py, &res))
+    }
+    /// output stl format
+    #[pyo3(signature = (stl_type = StlType::Binary))]
+    pub fn to_stl<'py>(&self, py: Python<'py>, stl_type: StlType) -> PyResult<&'py PyBytes> {
+        let mut res = Vec::new();
+        stl::write(&self.0, &mut res, stl_type.into()).map_err(to_pyerr)?;
+        Ok(PyBytes::new(py, &res))
+    }
+    /// meshing shell
+    #[staticmethod]
+    pub fn from_shell(shell: &Shell, tol: f64) -> PolygonMesh { shell.to_polygon(tol) }
+    /// meshing solid
+    #[staticmethod]
+    pub fn from_solid(solid: &Solid, tol: f64) -> PolygonMesh { solid.to_polygon(tol) }
+    /// the positions of the vertices
+    #[getter]
+    pub fn positions(&self) -> Vec<[f64; 3]> {
+        self.0.positions().iter().map(|&p| p.into()).collect()
+    }
+    /// the triangles, the triples of the indices of the positions
+    #[getter]
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let faces = self.0.faces().triangle_iter();
+        faces.map(|tri| tri.map(|v| v.pos)).collect()
+    }
+    /// Returns the bonding box `[xmin, ymin, zmin, xmax, ymax, zmax]`
+    pub fn bounding_box(&self) -> [f64; 6] {
+        let bdd = self.0.bounding_box();
+        let min = bdd.min();
+        let max = bdd.max();
+        [min[0], min[1], min[2], max[0], max[1], max[2]]
+    }
+}
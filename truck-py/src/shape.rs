@@ -0,0 +1,226 @@
+use crate::*;
+use pyo3::types::PyBytes;
+use truck_meshalgo::tessellation::*;
+
+macro_rules! toporedef {
+    ($($type: ident),*) => {
+        $(
+        /// python shape wrapper
+        #[pyclass(module = "truck")]
+        #[derive(Clone, Debug, From, Into, Deref, DerefMut, AsRef)]
+        pub struct $type(truck_modeling::$type);
+
+        impl IntoPython for truck_modeling::$type {
+            type PythonWrapper = $type;
+        }
+
+        impl From<$type> for AnyShape {
+            #[inline(always)]
+            fn from(shape: $type) -> AnyShape { AnyShape::$type(shape) }
+        }
+
+        impl From<truck_modeling::$type> for AnyShape {
+            #[inline(always)]
+            fn from(shape: truck_modeling::$type) -> AnyShape { AnyShape::$type(shape.into()) }
+        }
+        )*
+
+        impl IntoPy<PyObject> for AnyShape {
+            fn into_py(self, py: Python<'_>) -> PyObject {
+                match self {
+                    $(AnyShape::$type(shape) => shape.into_py(py),)*
+                }
+            }
+        }
+    }
+}
+
+toporedef!(Vertex, Edge, Wire, Face, Shell, Solid);
+
+/// Any of the vertex, the edge, the wire, the face, the shell and the solid.
+///
+/// Python functions accepting `AnyShape` take the instance of any wrapped shape.
+#[derive(Clone, Debug, FromPyObject)]
+pub enum AnyShape {
+    /// vertex
+    Vertex(Vertex),
+    /// edge
+    Edge(Edge),
+    /// wire
+    Wire(Wire),
+    /// face
+    Face(Face),
+    /// shell
+    Shell(Shell),
+    /// solid
+    Solid(Solid),
+}
+
+#[pymethods]
+impl Vertex {
+    /// the point of the vertex
+    #[getter]
+    pub fn point(&self) -> [f64; 3] { self.0.point().into() }
+}
+
+#[pymethods]
+impl Edge {
+    /// the front vertex
+    #[getter]
+    pub fn front(&self) -> Vertex { self.0.front().clone().into() }
+    /// the back vertex
+    #[getter]
+    pub fn back(&self) -> Vertex { self.0.back().clone().into() }
+    /// Returns the inverse edge.
+    pub fn inverse(&self) -> Edge { self.0.inverse().into() }
+}
+
+#[pymethods]
+impl Wire {
+    /// Creates a wire by edges.
+    #[new]
+    pub fn new(edges: Vec<Edge>) -> Wire { edges.into_iter().map(Into::into).collect() }
+    /// the edges of the wire
+    #[getter]
+    pub fn edges(&self) -> Vec<Edge> { self.0.edge_iter().cloned().map(Into::into).collect() }
+    /// Returns the inverse wire.
+    pub fn inverse(&self) -> Wire { self.0.inverse().into() }
+}
+
+impl FromIterator<truck_modeling::Edge> for Wire {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = truck_modeling::Edge>>(iter: I) -> Wire {
+        Wire(iter.into_iter().collect())
+    }
+}
+
+#[pymethods]
+impl Face {
+    /// the boundaries of the face
+    #[getter]
+    pub fn boundaries(&self) -> Vec<Wire> {
+        self.0.boundaries().into_iter().map(Into::into).collect()
+    }
+    /// Returns the inverse face.
+    pub fn inverse(&self) -> Face { self.0.inverse().into() }
+}
+
+#[pymethods]
+impl Shell {
+    /// Creates a shell by faces.
+    #[new]
+    pub fn new(faces: Vec<Face>) -> Shell {
+        Shell(faces.into_iter().map(Into::into).collect())
+    }
+    /// the faces of the shell
+    #[getter]
+    pub fn faces(&self) -> Vec<Face> { self.0.face_iter().cloned().map(Into::into).collect() }
+    /// Creates Solid if `self` is a closed shell.
+    pub fn into_solid(&self) -> PyResult<Solid> {
+        truck_modeling::Solid::try_new(vec![self.0.clone()])
+            .map(IntoPython::into_python)
+            .map_err(to_pyerr)
+    }
+}
+
+#[pymethods]
+impl Solid {
+    /// the boundary shells of the solid
+    #[getter]
+    pub fn boundaries(&self) -> Vec<Shell> {
+        let boundaries = self.0.boundaries().iter().cloned();
+        boundaries.map(Into::into).collect()
+    }
+}
+
+/// Describe STEP file header
+#[pyclass(module = "truck")]
+#[derive(Clone, Debug, Default, AsRef, Deref, DerefMut, From, Into)]
+pub struct StepHeaderDescriptor(truck_stepio::out::StepHeaderDescriptor);
+
+#[pymethods]
+impl StepHeaderDescriptor {
+    /// Creates the default header.
+    #[new]
+    pub fn new() -> StepHeaderDescriptor { Default::default() }
+    /// the file name
+    #[getter]
+    pub fn filename(&self) -> String { self.file_name.clone() }
+    /// the file name
+    #[setter]
+    pub fn set_filename(&mut self, filename: String) { self.file_name = filename }
+    /// the time stamp
+    #[getter]
+    pub fn time_stamp(&self) -> String { self.time_stamp.clone() }
+    /// the time stamp
+    #[setter]
+    pub fn set_time_stamp(&mut self, time_stamp: String) { self.time_stamp = time_stamp }
+    /// the authors
+    #[getter]
+    pub fn authors(&self) -> Vec<String> { self.authors.clone() }
+    /// the authors
+    #[setter]
+    pub fn set_authors(&mut self, authors: Vec<String>) { self.authors = authors }
+    /// the organizations
+    #[getter]
+    pub fn organization(&self) -> Vec<String> { self.organization.clone() }
+    /// the organizations
+    #[setter]
+    pub fn set_organization(&mut self, organization: Vec<String>) {
+        self.organization = organization
+    }
+    /// the organization system
+    #[getter]
+    pub fn organization_system(&self) -> String { self.organization_system.clone() }
+    /// the organization system
+    #[setter]
+    pub fn set_organization_system(&mut self, organization_system: String) {
+        self.organization_system = organization_system
+    }
+    /// the authorization
+    #[getter]
+    pub fn authorization(&self) -> String { self.authorization.clone() }
+    /// the authorization
+    #[setter]
+    pub fn set_authorization(&mut self, authorization: String) {
+        self.authorization = authorization
+    }
+}
+
+macro_rules! impl_shape {
+    ($type: ident) => {
+        #[pymethods]
+        impl $type {
+            /// meshing shape
+            pub fn to_polygon(&self, tol: f64) -> PolygonMesh {
+                self.triangulation(tol).to_polygon().into_python()
+            }
+            /// read shape from json
+            #[staticmethod]
+            pub fn from_json(data: &[u8]) -> PyResult<$type> {
+                serde_json::from_reader::<_, truck_modeling::$type>(data)
+                    .map(IntoPython::into_python)
+                    .map_err(to_pyerr)
+            }
+            /// write shape to json
+            pub fn to_json<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+                let json = serde_json::to_vec_pretty(&self.0).map_err(to_pyerr)?;
+                Ok(PyBytes::new(py, &json))
+            }
+            /// write shape to STEP
+            #[pyo3(signature = (header = None))]
+            pub fn to_step(&self, header: Option<StepHeaderDescriptor>) -> String {
+                use truck_stepio::out;
+                let compressed = self.0.compress();
+                out::CompleteStepDisplay::new(
+                    out::StepModel::from(&compressed),
+                    header.unwrap_or_default().into(),
+                )
+                .to_string()
+            }
+        }
+    };
+    ($a: ident, $($b: ident),*) => { impl_shape!($a); impl_shape!($($b),*); }
+}
+
+impl_shape!(Shell, Solid);
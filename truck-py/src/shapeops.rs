@@ -0,0 +1,39 @@
+use crate::{IntoPython, Solid};
+use pyo3::prelude::*;
+use truck_shapeops as shapeops;
+
+const SHAPEOPS_TOLERANCE: f64 = 0.05;
+
+/// and operator
+#[pyfunction]
+#[pyo3(name = "and_", signature = (solid0, solid1, tol = None))]
+pub fn and(solid0: &Solid, solid1: &Solid, tol: Option<f64>) -> Option<Solid> {
+    let tol = tol.unwrap_or(SHAPEOPS_TOLERANCE);
+    shapeops::and(solid0, solid1, tol).map(IntoPython::into_python)
+}
+
+/// or operator
+#[pyfunction]
+#[pyo3(name = "or_", signature = (solid0, solid1, tol = None))]
+pub fn or(solid0: &Solid, solid1: &Solid, tol: Option<f64>) -> Option<Solid> {
+    let tol = tol.unwrap_or(SHAPEOPS_TOLERANCE);
+    shapeops::or(solid0, solid1, tol).map(IntoPython::into_python)
+}
+
+/// not operator
+#[pyfunction]
+#[pyo3(name = "not_")]
+pub fn not(solid: &Solid) -> Solid {
+    let mut solid = solid.clone();
+    solid.not();
+    solid
+}
+
+/// the submodule `truck.shapeops`
+pub fn module(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "shapeops")?;
+    m.add_function(wrap_pyfunction!(and, m)?)?;
+    m.add_function(wrap_pyfunction!(or, m)?)?;
+    m.add_function(wrap_pyfunction!(not, m)?)?;
+    Ok(m)
+}
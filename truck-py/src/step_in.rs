@@ -0,0 +1,40 @@
+use crate::*;
+use truck_meshalgo::tessellation::{MeshableShape, MeshedShape};
+use truck_stepio::r#in::{alias::*, Table};
+use truck_topology::compress::*;
+
+/// Shell parsed from step
+#[pyclass(module = "truck.step_in")]
+#[derive(Clone, Debug, From, Into)]
+pub struct ShapeFromStep(CompressedShell<Point3, Curve3D, Surface>);
+
+#[pymethods]
+impl ShapeFromStep {
+    /// meshing shape from step
+    pub fn to_polygon(&self, tol: f64) -> PolygonMesh {
+        self.0.triangulation(tol).to_polygon().into_python()
+    }
+}
+
+/// Reads the shells in the STEP file.
+#[pyfunction]
+pub fn read(step_str: &str) -> PyResult<Vec<ShapeFromStep>> {
+    let table =
+        Table::from_step(step_str).ok_or_else(|| to_pyerr("failed to parse the STEP file."))?;
+    table
+        .shell
+        .values()
+        .map(|shell| {
+            let shell = table.to_compressed_shell(shell).map_err(to_pyerr)?;
+            Ok(shell.into())
+        })
+        .collect()
+}
+
+/// the submodule `truck.step_in`
+pub fn module(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "step_in")?;
+    m.add_class::<ShapeFromStep>()?;
+    m.add_function(wrap_pyfunction!(read, m)?)?;
+    Ok(m)
+}
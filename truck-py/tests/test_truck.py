@@ -0,0 +1,46 @@
+import pathlib
+
+import truck
+
+SHAPE_DIR = pathlib.Path(__file__).parents[2] / "resources" / "shape"
+
+
+def test_tsweep_cube():
+    v = truck.builder.vertex([-0.5, -0.5, -0.5])
+    e = truck.builder.tsweep(v, [1.0, 0.0, 0.0])
+    f = truck.builder.tsweep(e, [0.0, 1.0, 0.0])
+    solid = truck.builder.tsweep(f, [0.0, 0.0, 1.0])
+    assert solid.to_json() == (SHAPE_DIR / "cube.json").read_bytes()
+
+
+def test_rsweep_torus():
+    v = truck.builder.vertex([0.75, 0.0, 0.25])
+    w = truck.builder.rsweep(v, [0.75, 0.0, 0.0], [0.0, 1.0, 0.0], 7.0)
+    shell = truck.builder.rsweep(w, [0.0, 0.0, 0.0], [0.0, 0.0, 1.0], 7.0)
+    solid = shell.into_solid()
+    assert solid.to_json() == (SHAPE_DIR / "torus.json").read_bytes()
+
+
+def test_punched_cube_stl():
+    cube = truck.builder.cuboid([0.0, 0.0, 0.0], [1.0, 1.0, 1.0])
+    cylinder = truck.builder.cylinder([0.5, 0.5, -0.5], [0.0, 0.0, 2.0], 0.25)
+    punched = truck.shapeops.and_(cube, truck.shapeops.not_(cylinder))
+    assert punched is not None
+    mesh = punched.to_polygon(0.01)
+    read = truck.PolygonMesh.from_stl(mesh.to_stl(truck.StlType.Binary))
+    assert len(read.triangles) == len(mesh.triangles)
+    assert read.bounding_box() == [0.0, 0.0, 0.0, 1.0, 1.0, 1.0]
+
+
+def test_invalid_operations():
+    v = truck.builder.vertex([0.0, 0.0, 0.0])
+    try:
+        truck.builder.line(v, v)
+        assert False
+    except ValueError:
+        pass
+    try:
+        truck.builder.sphere([0.0, 0.0, 0.0], -1.0)
+        assert False
+    except ValueError:
+        pass
@@ -130,6 +130,7 @@ impl MyApp {
                         vec![*bdb.max(), *bdb.min()].into_iter().collect()
                     }
                     Curve::NurbsCurve(curve) => curve.roughly_bounding_box(),
+                    Curve::Polyline(curve) => curve.iter().collect(),
                     Curve::IntersectionCurve(_) => BoundingBox::new(),
                 };
             });
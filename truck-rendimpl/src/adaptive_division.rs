@@ -0,0 +1,117 @@
+use crate::*;
+use std::f64::consts::PI;
+
+impl ScreenSpaceDivision {
+    /// Creates the division for the view of `camera` on the render texture of `size` pixels with
+    /// the default tolerances.
+    #[inline(always)]
+    pub fn new(camera: Camera, size: (u32, u32)) -> Self {
+        ScreenSpaceDivision {
+            camera,
+            size,
+            pixel_tolerance: 0.5,
+            max_angle: Rad(PI / 8.0),
+            max_depth: 12,
+        }
+    }
+
+    /// Discretizes `curve` into the polyline.
+    ///
+    /// A segment is bisected while the middle point of the curve is apart from the middle point of
+    /// the chord by more than `pixel_tolerance` on the screen, or the tangents at its ends make
+    /// an angle more than `max_angle`. Only the angle is considered for the segments behind the
+    /// camera. The curve is divided into four segments at first, so that closed curves do not
+    /// degenerate.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_modeling::*;
+    /// use truck_platform::Camera;
+    /// use truck_rendimpl::ScreenSpaceDivision;
+    ///
+    /// let circle = UnitCircle::<Point3>::new();
+    /// let camera_at = |distance: f64| {
+    ///     let matrix = Matrix4::look_at_rh(
+    ///         Point3::new(0.0, 0.0, distance),
+    ///         Point3::origin(),
+    ///         Vector3::unit_y(),
+    ///     );
+    ///     Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 100.0)
+    /// };
+    /// let near = ScreenSpaceDivision::new(camera_at(3.0), (800, 600)).polyline(&circle);
+    /// let far = ScreenSpaceDivision::new(camera_at(50.0), (800, 600)).polyline(&circle);
+    /// assert!(near.len() > far.len());
+    /// // the angular criterion keeps the circle round
+    /// assert!(far.len() > 16);
+    /// assert!(near[0].near(&near[near.len() - 1]));
+    /// ```
+    pub fn polyline<C>(&self, curve: &C) -> PolylineCurve<Point3>
+    where C: ParametricCurve3D + BoundedCurve {
+        let (t0, t1) = curve.parameter_range();
+        let projection = self.projection();
+        let mut points = vec![curve.subs(t0)];
+        (0..4).for_each(|i| {
+            let s0 = t0 + (t1 - t0) * i as f64 / 4.0;
+            let s1 = t0 + (t1 - t0) * (i + 1) as f64 / 4.0;
+            self.divide(curve, &projection, (s0, s1), 0, &mut points);
+        });
+        PolylineCurve(points)
+    }
+
+    /// Discretizes each curve of `curves`.
+    #[inline(always)]
+    pub fn polylines<'a, C>(
+        &self,
+        curves: impl IntoIterator<Item = &'a C>,
+    ) -> Vec<PolylineCurve<Point3>>
+    where
+        C: ParametricCurve3D + BoundedCurve + 'a,
+    {
+        curves.into_iter().map(|c| self.polyline(c)).collect()
+    }
+
+    /// the projection from the world into the pixel coordinates
+    fn projection(&self) -> Matrix4 {
+        let (width, height) = (self.size.0 as f64, self.size.1 as f64);
+        let scale = Matrix4::from_nonuniform_scale(width / 2.0, height / 2.0, 1.0);
+        scale * self.camera.projection(width / height)
+    }
+
+    /// Pushes the points of the segment of `range` except the first one.
+    fn divide<C>(
+        &self,
+        curve: &C,
+        projection: &Matrix4,
+        (t0, t1): (f64, f64),
+        depth: usize,
+        points: &mut Vec<Point3>,
+    ) where
+        C: ParametricCurve3D,
+    {
+        let t = (t0 + t1) / 2.0;
+        let (p0, p1, p) = (curve.subs(t0), curve.subs(t1), curve.subs(t));
+        let (d0, d1) = (curve.der(t0), curve.der(t1));
+        let angle = match d0.so_small() || d1.so_small() {
+            true => 0.0,
+            false => d0.angle(d1).0,
+        };
+        let pixels = |p: Point3| {
+            let h = projection * p.to_homogeneous();
+            match h.w > 0.0 {
+                true => Some(Point2::new(h.x / h.w, h.y / h.w)),
+                false => None,
+            }
+        };
+        let deviation = match (pixels(p), pixels(p0.midpoint(p1))) {
+            (Some(q), Some(r)) => q.distance(r),
+            _ => 0.0,
+        };
+        let subdivide = deviation > self.pixel_tolerance || angle > self.max_angle.0;
+        if subdivide && depth < self.max_depth {
+            self.divide(curve, projection, (t0, t), depth + 1, points);
+            self.divide(curve, projection, (t, t1), depth + 1, points);
+        } else {
+            points.push(p1);
+        }
+    }
+}
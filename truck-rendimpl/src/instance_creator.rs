@@ -1,4 +1,5 @@
 use crate::*;
+use truck_meshalgo::tessellation::{IsolineShape, MeshingPreferences, PreferredMeshableShape};
 
 impl PolygonShaders {
     /// Constructor
@@ -25,18 +26,136 @@ impl PolygonShaders {
             fragment_entry,
             tex_fragment_module,
             tex_fragment_entry,
+            face_vertex_entry: None,
+            gbuffer_fragment: None,
         }
     }
 
+    /// Sets the entry point of the vertex shader module for the meshes of faces, e.g.
+    /// [`FacePolygon`].
+    ///
+    /// The vertex shader receives the vertex attributes of the default vertex shader and the
+    /// information of the face:
+    /// - `location(0)`: the position, `vec3<f32>`
+    /// - `location(1)`: the uv coordinate, `vec2<f32>`
+    /// - `location(2)`: the normal vector, `vec3<f32>`
+    /// - `location(3)`: the parameter range of the face `(u_min, u_max, v_min, v_max)`, `vec4<f32>`
+    /// - `location(4)`: `1u` if the face is trimmed, and `0u` otherwise, `u32`
+    ///
+    /// The meshes of faces are drawn by `vertex_entry` without this entry point, which ignores
+    /// the information of the faces. The standard shaders of truck have this entry point.
+    #[inline(always)]
+    pub fn with_face_vertex_entry(mut self, face_vertex_entry: &'static str) -> Self {
+        self.face_vertex_entry = Some(face_vertex_entry);
+        self
+    }
+
+    /// Sets the fragment shader writing the G-buffer.
+    ///
+    /// The fragment shader receives the outputs of the vertex shader, the same as
+    /// [`PolygonShaders::custom_fragment`], and writes the outputs described in [`GBuffer`].
+    /// The render id is bound in `set = 2`. The instances are not rendered in the G-buffer
+    /// without this shader. The standard shaders of truck have this shader.
+    #[inline(always)]
+    pub fn with_gbuffer_fragment(
+        mut self,
+        fragment_module: Arc<ShaderModule>,
+        fragment_entry: &'static str,
+    ) -> Self {
+        self.gbuffer_fragment = Some((fragment_module, fragment_entry));
+        self
+    }
+
     /// Creates default polygon shaders.
     #[inline(always)]
-    pub fn default(device: &Device) -> Self {
-        let source = include_str!("shaders/microfacet-module.wgsl").to_string()
-            + include_str!("shaders/polygon.wgsl");
-        let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
-            source: ShaderSource::Wgsl(source.into()),
+    pub fn default(device: &Device) -> Self { Self::standard(polygon_shader_module(device)) }
+
+    /// Creates shaders for the zebra analysis.
+    ///
+    /// The polygons are rendered by the reflections of the parallel stripes. The texture of
+    /// the instance is ignored.
+    #[inline(always)]
+    pub fn zebra(device: &Device) -> Self { Self::zebra_stripes(polygon_shader_module(device)) }
+
+    /// Creates shaders for drawing the isoparametric lines.
+    ///
+    /// The lines divide the parameter range of each face into ten parts, which is given by
+    /// [`FacePolygon::parameter_range`]. The parameter range of the plain polygon meshes is the
+    /// unit square. The lines of the trimmed faces are red. The texture of the instance is ignored.
+    #[inline(always)]
+    pub fn isolines(device: &Device) -> Self {
+        let shader_module = polygon_shader_module(device);
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_main",
+            Arc::clone(&shader_module),
+            "isoline_main",
+            Arc::clone(&shader_module),
+            "isoline_main",
+        )
+        .with_face_vertex_entry("vs_face_main")
+        .with_gbuffer_fragment(shader_module, "gbuffer_main")
+    }
+
+    /// Creates shaders with the default vertex shader and a custom fragment shader.
+    ///
+    /// The fragment shader receives the outputs of the default vertex shader:
+    /// - `location(0)`: the position in the world coordinate, `vec3<f32>`
+    /// - `location(1)`: the uv coordinate, `vec2<f32>`
+    /// - `location(2)`: the normal vector in the world coordinate, `vec3<f32>`
+    /// - `location(3)`: the parameter range of the face `(u_min, u_max, v_min, v_max)`, `vec4<f32>`
+    /// - `location(4)`: `1u` if the face is trimmed, and `0u` otherwise, `u32`
+    ///
+    /// The parameter range of the plain polygon meshes is the unit square, and they are not
+    /// trimmed. The entry point `fragment_entry` is used regardless of whether the instance has a
+    /// texture.
+    /// The additional resources of each instance are given by [`PolygonInstance::set_custom_bindings`].
+    /// # Shader Examples
+    /// ```wgsl
+    /// struct ColorMap {
+    ///     min_value: f32,
+    ///     max_value: f32,
+    /// }
+    ///
+    /// @group(1)
+    /// @binding(1)
+    /// var<uniform> color_map: ColorMap;
+    ///
+    /// @fragment
+    /// fn fs_main(
+    ///     @location(0) position: vec3<f32>,
+    ///     @location(1) uv: vec2<f32>,
+    ///     @location(2) normal: vec3<f32>,
+    /// ) -> @location(0) vec4<f32> {
+    ///     let t = (position.z - color_map.min_value) / (color_map.max_value - color_map.min_value);
+    ///     return vec4<f32>(t, 0.0, 1.0 - t, 1.0);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn custom_fragment(
+        device: &Device,
+        fragment_source: ShaderSource<'_>,
+        fragment_entry: &'static str,
+    ) -> Self {
+        let fragment_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
+            source: fragment_source,
             label: None,
         }));
+        let shader_module = polygon_shader_module(device);
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_main",
+            Arc::clone(&fragment_module),
+            fragment_entry,
+            fragment_module,
+            fragment_entry,
+        )
+        .with_face_vertex_entry("vs_face_main")
+        .with_gbuffer_fragment(shader_module, "gbuffer_main")
+    }
+
+    #[inline(always)]
+    fn standard(shader_module: Arc<ShaderModule>) -> Self {
         Self::new(
             Arc::clone(&shader_module),
             "vs_main",
@@ -45,7 +164,32 @@ impl PolygonShaders {
             Arc::clone(&shader_module),
             "tex_main",
         )
+        .with_face_vertex_entry("vs_face_main")
+        .with_gbuffer_fragment(shader_module, "gbuffer_main")
     }
+
+    #[inline(always)]
+    fn zebra_stripes(shader_module: Arc<ShaderModule>) -> Self {
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_main",
+            Arc::clone(&shader_module),
+            "zebra_main",
+            Arc::clone(&shader_module),
+            "zebra_main",
+        )
+        .with_face_vertex_entry("vs_face_main")
+        .with_gbuffer_fragment(shader_module, "gbuffer_main")
+    }
+}
+
+fn polygon_shader_module(device: &Device) -> Arc<ShaderModule> {
+    let source = include_str!("shaders/microfacet-module.wgsl").to_string()
+        + include_str!("shaders/polygon.wgsl");
+    Arc::new(device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(source.into()),
+        label: None,
+    }))
 }
 
 impl WireShaders {
@@ -67,9 +211,26 @@ impl WireShaders {
             vertex_entry,
             fragment_module,
             fragment_entry,
+            gbuffer_shaders: None,
         }
     }
 
+    /// Sets the shaders writing the G-buffer.
+    ///
+    /// The fragment shader writes the outputs described in [`GBuffer`], and the render id is
+    /// bound in `set = 2`. The instances are not rendered in the G-buffer without these shaders.
+    /// The standard shaders of truck have these shaders.
+    #[inline(always)]
+    pub fn with_gbuffer_shaders(
+        mut self,
+        module: Arc<ShaderModule>,
+        vertex_entry: &'static str,
+        fragment_entry: &'static str,
+    ) -> Self {
+        self.gbuffer_shaders = Some((module, vertex_entry, fragment_entry));
+        self
+    }
+
     /// Creates default wireframe shaders
     #[inline(always)]
     fn default(device: &Device) -> Self {
@@ -77,22 +238,32 @@ impl WireShaders {
             source: ShaderSource::Wgsl(include_str!("shaders/line.wgsl").into()),
             label: None,
         }));
+        Self::standard(shader_module)
+    }
+
+    #[inline(always)]
+    fn standard(shader_module: Arc<ShaderModule>) -> Self {
         Self::new(
             Arc::clone(&shader_module),
             "vs_main",
-            shader_module,
+            Arc::clone(&shader_module),
             "fs_main",
         )
+        .with_gbuffer_shaders(shader_module, "gbuffer_vs_main", "gbuffer_fs_main")
     }
 }
 
 impl CreatorCreator for DeviceHandler {
     #[inline(always)]
     fn instance_creator(&self) -> InstanceCreator {
+        let polygon_module = polygon_shader_module(self.device());
         InstanceCreator {
             handler: self.clone(),
-            polygon_shaders: PolygonShaders::default(self.device()),
+            polygon_shaders: PolygonShaders::standard(Arc::clone(&polygon_module)),
+            zebra_shaders: PolygonShaders::zebra_stripes(polygon_module),
             wire_shaders: WireShaders::default(self.device()),
+            #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+            shader_watcher: None,
         }
     }
 }
@@ -111,9 +282,136 @@ impl InstanceCreator {
         I: Instance, {
         object.to_instance(&self.handler, &I::standard_shaders(self), state)
     }
+    /// Tessellates `shape` with the densities given by `preferences`, and creates the shape
+    /// instance.
+    ///
+    /// The isolines requested by [`ShapeState::isolines`] are created from the surfaces with the
+    /// chordal tolerance of the global density of `preferences`. The tessellation is done on the
+    /// calling thread. Use [`PreparedPolygon::from_shape`] to tessellate shapes in background.
+    ///
+    /// # Panics
+    ///
+    /// The chordal tolerances of the densities must be more than `TOLERANCE`.
+    pub fn create_shape_instance<K, T>(
+        &self,
+        shape: &T,
+        preferences: &MeshingPreferences<K>,
+        state: &ShapeState,
+    ) -> ShapeInstance
+    where
+        T: PreferredMeshableShape<K> + IsolineShape, {
+        let prepared = PreparedPolygon::from_shape(shape, preferences);
+        let polygon = self.create_instance(&prepared, &state.polygon);
+        let isolines = state.isolines.as_ref().map(|(division, state)| {
+            let tol = preferences.density.chordal_tolerance;
+            self.create_instance(&shape.isolines(*division, tol), state)
+        });
+        ShapeInstance { polygon, isolines }
+    }
+    /// Discretizes `curves` by `division`, and creates the wireframe instance.
+    ///
+    /// The instance should be recreated by the new division when the camera has zoomed in.
+    #[inline(always)]
+    pub fn create_curves_instance<'a, C>(
+        &self,
+        curves: impl IntoIterator<Item = &'a C>,
+        division: &ScreenSpaceDivision,
+        state: &WireFrameState,
+    ) -> WireFrameInstance
+    where
+        C: ParametricCurve3D + BoundedCurve + 'a,
+    {
+        self.create_instance(&PreparedWireFrame::from_curves(curves, division), state)
+    }
+    /// Creates polygon instance rendered by the zebra stripes from object.
+    ///
+    /// The zebra analysis shows the reflections of parallel light tubes, which is useful for
+    /// inspecting the smoothness of surfaces.
+    #[inline(always)]
+    pub fn create_zebra_instance<T>(&self, object: &T, state: &T::State) -> PolygonInstance
+    where T: ToInstance<PolygonInstance> {
+        object.to_instance(&self.handler, &self.zebra_shaders, state)
+    }
     /// Creates `Texture` for attaching faces.
     #[inline(always)]
     pub fn create_texture(&self, image: &DynamicImage) -> Arc<Texture> {
         Arc::new(image2texture::image2texture(&self.handler, image))
     }
 }
+
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+impl InstanceCreator {
+    /// Starts watching the sources of the standard shaders in `dir` for the development.
+    ///
+    /// The directory must contain the files `microfacet-module.wgsl`, `polygon.wgsl` and
+    /// `line.wgsl`, the same as `src/shaders` of this crate, i.e. contributors of the shaders can
+    /// give `concat!(env!("CARGO_MANIFEST_DIR"), "/src/shaders")`.
+    pub fn watch_shaders(&mut self, dir: impl Into<std::path::PathBuf>) {
+        let dir = dir.into();
+        let mut watcher = hot_reload::ShaderWatcher::new();
+        for file in SHADER_FILES {
+            watcher.watch(dir.join(file));
+        }
+        self.shader_watcher = Some((dir, watcher));
+    }
+
+    /// Recompiles the standard shaders if their sources have been changed since the last call.
+    ///
+    /// Returns `Ok(true)` if the shaders have been replaced. The new shaders are applied to
+    /// the instances created afterwards, the existing instances are updated by
+    /// `set_shaders` and `Scene::update_pipelines`, preserving the scene.
+    /// If the new sources are invalid, the previous shaders are kept and the error is returned.
+    /// # Examples
+    /// ```no_run
+    /// use truck_platform::*;
+    /// use truck_rendimpl::*;
+    /// let mut scene = pollster::block_on(Scene::from_default_device(&Default::default()));
+    /// let mut creator = scene.instance_creator();
+    /// creator.watch_shaders(concat!(env!("CARGO_MANIFEST_DIR"), "/src/shaders"));
+    /// let mut instance: PolygonInstance = creator.create_instance(
+    ///     &PolygonMesh::default(),
+    ///     &Default::default(),
+    /// );
+    /// scene.add_object(&instance);
+    /// // in the main loop
+    /// match creator.reload_shaders() {
+    ///     Ok(true) => {
+    ///         instance.set_shaders(PolygonInstance::standard_shaders(&creator));
+    ///         scene.update_pipeline(&instance);
+    ///         scene.device_handler().pipeline_cache().remove_unused();
+    ///     }
+    ///     Ok(false) => {}
+    ///     Err(error) => eprintln!("{error}"),
+    /// }
+    /// ```
+    pub fn reload_shaders(&mut self) -> std::io::Result<bool> {
+        let (dir, watcher) = match &mut self.shader_watcher {
+            Some((dir, watcher)) => (dir, watcher),
+            None => return Ok(false),
+        };
+        let changed = watcher.poll();
+        let is_changed = |file: &str| changed.iter().any(|path| path.ends_with(file));
+        let device = self.handler.device();
+        if is_changed(SHADER_FILES[0]) || is_changed(SHADER_FILES[1]) {
+            let source = std::fs::read_to_string(dir.join(SHADER_FILES[0]))?
+                + &std::fs::read_to_string(dir.join(SHADER_FILES[1]))?;
+            let module = Arc::new(shader_util::create_wgsl_module(device, &source)?);
+            self.polygon_shaders = PolygonShaders::standard(Arc::clone(&module));
+            self.zebra_shaders = PolygonShaders::zebra_stripes(module);
+        }
+        if is_changed(SHADER_FILES[2]) {
+            let source = std::fs::read_to_string(dir.join(SHADER_FILES[2]))?;
+            let module = Arc::new(shader_util::create_wgsl_module(device, &source)?);
+            self.wire_shaders = WireShaders::standard(module);
+        }
+        Ok(!changed.is_empty())
+    }
+
+    /// Returns the shaders for the zebra analysis.
+    #[inline(always)]
+    pub const fn zebra_shaders(&self) -> &PolygonShaders { &self.zebra_shaders }
+}
+
+/// the sources of the standard shaders
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+const SHADER_FILES: [&str; 3] = ["microfacet-module.wgsl", "polygon.wgsl", "line.wgsl"];
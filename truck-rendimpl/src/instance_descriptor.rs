@@ -15,22 +15,52 @@ impl Default for Material {
     }
 }
 
+impl From<&truck_polymesh::obj::Material> for Material {
+    /// Converts the Phong parameters of MTL file into the microfacet parameters: the roughness
+    /// by `sqrt(2 / (Ns + 2))`, the reflectance and the ambient ratio by the means of `Ks` and
+    /// `Ka`. The texture `map_Kd` is not loaded.
+    fn from(material: &truck_polymesh::obj::Material) -> Material {
+        let mean = |v: Vector3| f64::clamp((v.x + v.y + v.z) / 3.0, 0.0, 1.0);
+        let alpha = f64::clamp(material.dissolve, 0.0, 1.0);
+        Material {
+            albedo: material.diffuse.extend(alpha),
+            roughness: f64::sqrt(2.0 / (f64::max(material.shininess, 0.0) + 2.0)),
+            reflectance: mean(material.specular),
+            ambient_ratio: mean(material.ambient),
+            background_ratio: 0.0,
+            alpha_blend: alpha < 1.0,
+        }
+    }
+}
+
 impl Material {
     /// Creates a `UNIFORM` buffer of material.
     ///
-    /// The bind group provided by the instances holds this uniform buffer.
+    /// The instances write the materials in the slots of the uniform ring instead, see
+    /// [`PolygonInstance`] for the bindings in the shaders.
     /// # Shader Examples
-    /// ```glsl
-    /// layout(set = 1, binding = 1) uniform Material {
-    ///     vec4 albedo;
-    ///     float roughness;
-    ///     float reflectance;
-    ///     float ambient_ratio;
-    /// };
+    /// ```wgsl
+    /// struct Material {
+    ///     albedo: vec4<f32>,
+    ///     roughness: f32,
+    ///     reflectance: f32,
+    ///     ambient_ratio: f32,
+    ///     background_ratio: f32,
+    /// }
+    ///
+    /// @group(1)
+    /// @binding(0)
+    /// var<uniform> material: Material;
     /// ```
     #[inline(always)]
     pub fn buffer(&self, device: &Device) -> BufferHandler {
-        let material_data: [f32; 8] = [
+        BufferHandler::from_slice(&self.uniform_data(), device, BufferUsages::UNIFORM)
+    }
+
+    /// the contents of the uniform buffer
+    #[inline(always)]
+    pub(crate) fn uniform_data(&self) -> [f32; 8] {
+        [
             self.albedo[0] as f32,
             self.albedo[1] as f32,
             self.albedo[2] as f32,
@@ -39,8 +69,7 @@ impl Material {
             self.reflectance as f32,
             self.ambient_ratio as f32,
             self.background_ratio as f32,
-        ];
-        BufferHandler::from_slice(&material_data, device, BufferUsages::UNIFORM)
+        ]
     }
 
     #[doc(hidden)]
@@ -58,6 +87,124 @@ impl Material {
     }
 }
 
+impl Material {
+    /// polished steel
+    pub const STEEL: Material = Material {
+        albedo: Vector4::new(0.56, 0.57, 0.58, 1.0),
+        roughness: 0.3,
+        reflectance: 0.9,
+        ambient_ratio: 0.02,
+        background_ratio: 0.0,
+        alpha_blend: false,
+    };
+    /// brushed aluminum
+    pub const ALUMINUM: Material = Material {
+        albedo: Vector4::new(0.91, 0.92, 0.92, 1.0),
+        roughness: 0.4,
+        reflectance: 0.85,
+        ambient_ratio: 0.02,
+        background_ratio: 0.0,
+        alpha_blend: false,
+    };
+    /// ivory ABS resin
+    pub const ABS: Material = Material {
+        albedo: Vector4::new(0.78, 0.74, 0.62, 1.0),
+        roughness: 0.6,
+        reflectance: 0.04,
+        ambient_ratio: 0.02,
+        background_ratio: 0.0,
+        alpha_blend: false,
+    };
+    /// clear glass, rendered with the alpha blending
+    pub const GLASS: Material = Material {
+        albedo: Vector4::new(0.9, 0.95, 0.95, 0.2),
+        roughness: 0.05,
+        reflectance: 0.08,
+        ambient_ratio: 0.02,
+        background_ratio: 0.0,
+        alpha_blend: true,
+    };
+    /// the named presets of materials
+    pub const PRESETS: [(&'static str, Material); 4] = [
+        ("steel", Material::STEEL),
+        ("aluminum", Material::ALUMINUM),
+        ("abs", Material::ABS),
+        ("glass", Material::GLASS),
+    ];
+
+    /// Returns the preset of `name` in [`Material::PRESETS`], ignoring the case.
+    /// # Examples
+    /// ```
+    /// use truck_rendimpl::*;
+    /// let steel = Material::preset("Steel").unwrap();
+    /// assert_eq!(steel.albedo, Material::STEEL.albedo);
+    /// assert!(Material::preset("wood").is_none());
+    /// ```
+    pub fn preset(name: &str) -> Option<Material> {
+        Self::PRESETS
+            .iter()
+            .find(|(preset, _)| preset.eq_ignore_ascii_case(name))
+            .map(|(_, material)| *material)
+    }
+
+    /// Converts a component of sRGB color into the linear one.
+    /// # Examples
+    /// ```
+    /// use truck_rendimpl::*;
+    /// assert_eq!(Material::srgb_to_linear(0.0), 0.0);
+    /// assert!(f64::abs(Material::srgb_to_linear(0.5) - 0.21404) < 1.0e-5);
+    /// assert!(f64::abs(Material::srgb_to_linear(1.0) - 1.0) < 1.0e-10);
+    /// ```
+    #[inline(always)]
+    pub fn srgb_to_linear(c: f64) -> f64 {
+        match c <= 0.04045 {
+            true => c / 12.92,
+            false => f64::powf((c + 0.055) / 1.055, 2.4),
+        }
+    }
+
+    /// Converts a component of linear color into the sRGB one.
+    /// # Examples
+    /// ```
+    /// use truck_rendimpl::*;
+    /// let c = Material::linear_to_srgb(Material::srgb_to_linear(0.3));
+    /// assert!(f64::abs(c - 0.3) < 1.0e-10);
+    /// ```
+    #[inline(always)]
+    pub fn linear_to_srgb(c: f64) -> f64 {
+        match c <= 0.0031308 {
+            true => c * 12.92,
+            false => 1.055 * f64::powf(c, 1.0 / 2.4) - 0.055,
+        }
+    }
+
+    /// Returns the material with the albedo given by sRGB color, e.g. picked by a color picker.
+    ///
+    /// The albedo of the material is in the linear color space, and the alpha is not converted.
+    /// # Examples
+    /// ```
+    /// use truck_rendimpl::*;
+    /// let material = Material::default().with_srgb_albedo(Vector4::new(0.5, 0.5, 0.5, 0.5));
+    /// assert!(material.albedo[0] < 0.5);
+    /// assert_eq!(material.albedo[3], 0.5);
+    /// ```
+    #[inline(always)]
+    pub fn with_srgb_albedo(self, srgb: Vector4) -> Material {
+        let rgb = srgb.truncate().map(Self::srgb_to_linear);
+        Material {
+            albedo: rgb.extend(srgb[3]),
+            ..self
+        }
+    }
+
+    /// Returns the albedo in sRGB color.
+    #[inline(always)]
+    pub fn srgb_albedo(&self) -> Vector4 {
+        let rgb = self.albedo.truncate().map(Self::linear_to_srgb);
+        rgb.extend(self.albedo[3])
+    }
+}
+
 impl Default for PolygonState {
     #[inline(always)]
     fn default() -> PolygonState {
@@ -73,12 +220,17 @@ impl Default for PolygonState {
 impl PolygonState {
     /// Creates a `UNIFORM` buffer of instance matrix.
     ///
-    /// The bind group provided by the instances holds this uniform buffer.
+    /// The instances write the matrices in the slots of the uniform ring instead, see
+    /// [`PolygonInstance`] for the bindings in the shaders.
     /// # Shader Examples
-    /// ```glsl
-    /// layout(set = 1, binding = 0) uniform ModelMatrix {
-    ///     mat4 uniform_matrix;
-    /// };
+    /// ```wgsl
+    /// struct ModelMatrix {
+    ///     model_matrix: mat4x4<f32>,
+    /// }
+    ///
+    /// @group(1)
+    /// @binding(0)
+    /// var<uniform> model_matrix: ModelMatrix;
     /// ```
     #[inline(always)]
     pub fn matrix_buffer(&self, device: &Device) -> BufferHandler {
@@ -102,15 +254,21 @@ impl PolygonState {
 
     /// Creates a `UNIFORM` buffer of material.
     ///
-    /// The bind group provided by the instances holds this uniform buffer.
+    /// The instances write the materials in the slots of the uniform ring instead, see
+    /// [`PolygonInstance`] for the bindings in the shaders.
     /// # Shader Examples
-    /// ```glsl
-    /// layout(set = 1, binding = 1) uniform Material {
-    ///     vec4 albedo;
-    ///     float roughness;
-    ///     float reflectance;
-    ///     float ambient_ratio;
-    /// };
+    /// ```wgsl
+    /// struct Material {
+    ///     albedo: vec4<f32>,
+    ///     roughness: f32,
+    ///     reflectance: f32,
+    ///     ambient_ratio: f32,
+    ///     background_ratio: f32,
+    /// }
+    ///
+    /// @group(1)
+    /// @binding(0)
+    /// var<uniform> material: Material;
     /// ```
     #[inline(always)]
     pub fn material_buffer(&self, device: &Device) -> BufferHandler { self.material.buffer(device) }
@@ -121,24 +279,19 @@ impl PolygonState {
 
     /// Creates texture view and sampler of the instance's texture image.
     ///
-    /// The bind group provided by the instances holds this uniform buffer.
+    /// The bind group provided by the instances holds this texture view and sampler.
     /// # Shader Examples
-    /// ```glsl
-    /// layout(set = 1, binding = 2) uniform texture2D texture_view;
-    /// layout(set = 1, binding = 3) uniform sampler texture_sampler;
+    /// ```wgsl
+    /// @group(1)
+    /// @binding(1)
+    /// var texture_view: texture_2d<f32>;
+    ///
+    /// @group(1)
+    /// @binding(2)
+    /// var texture_sampler: sampler;
     /// ```
     pub fn textureview_and_sampler(&self, device: &Device) -> (TextureView, Sampler) {
-        let texture = self.texture.as_ref().unwrap();
-        let view = texture.create_view(&Default::default());
-        let sampler = device.create_sampler(&SamplerDescriptor {
-            mag_filter: FilterMode::Linear,
-            min_filter: FilterMode::Nearest,
-            mipmap_filter: FilterMode::Nearest,
-            lod_min_clamp: 0.0,
-            lod_max_clamp: 100.0,
-            ..Default::default()
-        });
-        (view, sampler)
+        textureview_and_sampler(self.texture.as_ref().unwrap(), device)
     }
 
     #[doc(hidden)]
@@ -165,3 +318,64 @@ impl PolygonState {
         }
     }
 }
+
+impl CustomBindings {
+    /// Sets the contents of the uniform buffer by the plain old data.
+    /// # Shader Examples
+    /// ```wgsl
+    /// struct ColorMap {
+    ///     min_value: f32,
+    ///     max_value: f32,
+    /// }
+    ///
+    /// @group(1)
+    /// @binding(1)
+    /// var<uniform> color_map: ColorMap;
+    /// ```
+    #[inline(always)]
+    pub fn set_uniform<T: Pod>(&mut self, data: &T) {
+        self.uniform = Some(bytemuck::bytes_of(data).to_vec());
+    }
+
+    /// Creates a `UNIFORM` buffer of the contents.
+    #[inline(always)]
+    pub fn uniform_buffer(&self, device: &Device) -> Option<BufferHandler> {
+        let uniform = self.uniform.as_ref()?;
+        let buffer = BufferHandler::from_slice(uniform, device, BufferUsages::UNIFORM);
+        Some(buffer)
+    }
+
+    /// Creates texture views and samplers of the texture slots.
+    #[inline(always)]
+    pub fn textureviews_and_samplers(&self, device: &Device) -> Vec<(TextureView, Sampler)> {
+        self.textures
+            .iter()
+            .map(|texture| textureview_and_sampler(texture, device))
+            .collect()
+    }
+
+    #[doc(hidden)]
+    pub fn bgl_entries(&self) -> Vec<PreBindGroupLayoutEntry> {
+        let uniform = self.uniform.as_ref().map(|_| Material::bgl_entry());
+        let textures = self.textures.iter().flat_map(|_| {
+            [
+                PolygonState::textureview_bgl_entry(),
+                PolygonState::sampler_bgl_entry(),
+            ]
+        });
+        uniform.into_iter().chain(textures).collect()
+    }
+}
+
+fn textureview_and_sampler(texture: &Texture, device: &Device) -> (TextureView, Sampler) {
+    let view = texture.create_view(&Default::default());
+    let sampler = device.create_sampler(&SamplerDescriptor {
+        mag_filter: FilterMode::Linear,
+        min_filter: FilterMode::Nearest,
+        mipmap_filter: FilterMode::Nearest,
+        lod_min_clamp: 0.0,
+        lod_max_clamp: 100.0,
+        ..Default::default()
+    });
+    (view, sampler)
+}
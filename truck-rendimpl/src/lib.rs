@@ -27,10 +27,12 @@ pub use polymesh::*;
 /// Material information.
 ///
 /// Each instance is rendered based on the microfacet theory.
+/// Some presets of industrial materials are in [`Material::PRESETS`].
 #[derive(Debug, Clone, Copy)]
 pub struct Material {
-    /// albedo, base color, [0, 1]-normalized rgba. Default is `Vector4::new(1.0, 1.0, 1.0, 1.0)`.  
-    /// Transparent by alpha is not yet supported in the current standard shader.
+    /// albedo, base color, [0, 1]-normalized rgba in the linear color space.
+    /// Default is `Vector4::new(1.0, 1.0, 1.0, 1.0)`.  
+    /// Use [`Material::with_srgb_albedo`] for sRGB colors, e.g. the colors picked in paint tools.
     pub albedo: Vector4,
     /// roughness of the surface: [0, 1]. Default is 0.5.
     pub roughness: f64,
@@ -57,6 +59,55 @@ pub struct PolygonState {
     pub backface_culling: bool,
 }
 
+/// Additional resources bound to the custom shaders of `PolygonInstance`.
+///
+/// The resources are bound in `set = 1` following the standard bindings, i.e. the first binding
+/// is `1` if the instance has no texture, and `3` otherwise. The uniform buffer precedes the
+/// texture slots, and each texture slot is a pair of the texture view and the sampler.
+#[derive(Clone, Debug, Default)]
+pub struct CustomBindings {
+    /// the contents of the uniform buffer
+    pub uniform: Option<Vec<u8>>,
+    /// the textures of the slots
+    pub textures: Vec<Arc<Texture>>,
+}
+
+/// Line types of wireframes following the conventions of the engineering drawings.
+///
+/// The lengths of the patterns are in the coordinates of the vertices before the instance
+/// matrix. The patterns continue along the polylines, and restart at each edge of meshes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineType {
+    /// continuous line
+    Continuous,
+    /// dashes separated by gaps
+    Dashed {
+        /// the length of dashes
+        dash: f64,
+        /// the length of gaps
+        gap: f64,
+    },
+    /// chain line of long and short dashes for center lines
+    Center {
+        /// the length of long dashes
+        long: f64,
+        /// the length of short dashes
+        short: f64,
+        /// the length of gaps
+        gap: f64,
+    },
+    /// dashed line drawn only where the line is hidden behind the other objects
+    ///
+    /// Render a continuous copy of the instance together for the hidden line drawings.
+    /// The hidden lines are not written in the G-buffer.
+    Hidden {
+        /// the length of dashes
+        dash: f64,
+        /// the length of gaps
+        gap: f64,
+    },
+}
+
 /// Configures of `WireFrameInstance`.
 #[derive(Clone, Debug)]
 pub struct WireFrameState {
@@ -75,6 +126,8 @@ pub struct PolygonShaders {
     fragment_entry: &'static str,
     tex_fragment_module: Arc<ShaderModule>,
     tex_fragment_entry: &'static str,
+    face_vertex_entry: Option<&'static str>,
+    gbuffer_fragment: Option<(Arc<ShaderModule>, &'static str)>,
 }
 
 /// shaders for rendering wireframes
@@ -84,6 +137,7 @@ pub struct WireShaders {
     vertex_entry: &'static str,
     fragment_module: Arc<ShaderModule>,
     fragment_entry: &'static str,
+    gbuffer_shaders: Option<(Arc<ShaderModule>, &'static str, &'static str)>,
 }
 
 /// Instance of polygon
@@ -94,30 +148,133 @@ pub struct WireShaders {
 ///
 /// The duplicated polygon by `Clone::clone` has the same mesh data and descriptor
 /// with original, however, its render id is different from the one of original.
+///
+/// The matrix and the material are written in the slot of the instance in
+/// [`DeviceHandler::object_uniforms`], bound in `set = 1` with the dynamic offset. The instances
+/// without textures and custom bindings share the bind groups of the chunks of the slots, so
+/// the scenes of many instances do not create a bind group for each instance. The slot is
+/// released when the instance is dropped.
+/// # Shader Examples
+/// ```wgsl
+/// struct Material {
+///     albedo: vec4<f32>,
+///     roughness: f32,
+///     reflectance: f32,
+///     ambient_ratio: f32,
+///     background_ratio: f32,
+/// }
+///
+/// struct ModelUniform {
+///     model_matrix: mat4x4<f32>,
+///     material: Material,
+/// }
+///
+/// @group(1)
+/// @binding(0)
+/// var<uniform> model: ModelUniform;
+///
+/// // only if the instance has a texture
+/// @group(1)
+/// @binding(1)
+/// var texture_view: texture_2d<f32>;
+///
+/// @group(1)
+/// @binding(2)
+/// var texture_sampler: sampler;
+/// ```
 #[derive(Debug)]
 pub struct PolygonInstance {
     polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
     state: PolygonState,
     shaders: PolygonShaders,
+    group: Option<PolygonGroup>,
+    custom_bindings: CustomBindings,
+    uniforms: Arc<ObjectUniforms>,
+    // whether the vertices are `FaceVertex`
+    face_vertices: bool,
+    visible: bool,
+    layers: u32,
+    id: RenderID,
+}
+
+/// The matrix and the material shared by polygon instances
+///
+/// The instances joined by [`PolygonGroup::join`] are bound to the slot of the group in
+/// [`DeviceHandler::object_uniforms`] instead of their own slots. Hence, the matrices and the
+/// materials of hundreds of instances are updated by one write of [`PolygonGroup::set_matrix`]
+/// or [`PolygonGroup::set_material`], without recreating the bind groups. The visibility of the
+/// instances is updated at once by the group of the scene, [`Scene::create_group`].
+#[derive(Clone, Debug)]
+pub struct PolygonGroup {
+    slot: Arc<GroupSlot>,
+}
+
+/// the slot of a polygon group, released when the last clone of the group is dropped
+#[derive(Debug)]
+struct GroupSlot {
     id: RenderID,
+    uniform: std::sync::Mutex<PolygonUniform>,
+    handler: DeviceHandler,
+}
+
+/// the data of a polygon instance in its slot of the uniform ring
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct PolygonUniform {
+    matrix: [[f32; 4]; 4],
+    material: [f32; 8],
 }
 
 /// Wire frame rendering
+///
+/// The matrix, the color and the line style are written in the slot of the instance in
+/// [`DeviceHandler::object_uniforms`], and all the wireframes share the bind groups of the chunks
+/// of the slots. The slot is released when the instance is dropped.
 #[derive(Debug)]
 pub struct WireFrameInstance {
     vertices: Arc<BufferHandler>,
     strips: Arc<BufferHandler>,
     state: WireFrameState,
     shaders: WireShaders,
+    line_type: LineType,
+    depth_bias: f64,
+    uniforms: Arc<ObjectUniforms>,
+    visible: bool,
+    layers: u32,
     id: RenderID,
 }
 
+/// Configures of `ShapeInstance`.
+#[derive(Clone, Debug, Default)]
+pub struct ShapeState {
+    /// the state of the polygon of the faces
+    pub polygon: PolygonState,
+    /// the numbers of the divisions of the parameter range of each face in the u- and
+    /// v-directions by the isolines, and the state of the wireframe of the isolines.
+    /// Default is `None`, i.e. the isolines are not overlaid.
+    pub isolines: Option<((usize, usize), WireFrameState)>,
+}
+
+/// Polygon instance of a shape with the isolines of the faces overlaid on it
+///
+/// The isolines are created from the surfaces and trimmed by the boundaries of the faces, see
+/// [`IsolineShape::isolines`](truck_meshalgo::tessellation::IsolineShape::isolines). Both of the
+/// instances are added to the scene separately.
+#[derive(Debug)]
+pub struct ShapeInstance {
+    polygon: PolygonInstance,
+    isolines: Option<WireFrameInstance>,
+}
+
 /// Constroctor for instances
 #[derive(Debug, Clone)]
 pub struct InstanceCreator {
     handler: DeviceHandler,
     polygon_shaders: PolygonShaders,
+    zebra_shaders: PolygonShaders,
     wire_shaders: WireShaders,
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    shader_watcher: Option<(std::path::PathBuf, hot_reload::ShaderWatcher)>,
 }
 
 /// for creating `InstanceCreator`
@@ -162,10 +319,106 @@ struct AttrVertex {
     pub normal: [f32; 3],
 }
 
+/// the vertex of the meshes of faces, with the information of the face
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct FaceVertex {
+    pub attr: AttrVertex,
+    pub parameter_range: [f32; 4],
+    pub trimmed: u32,
+}
+
+/// Polygon mesh of a face with the information of the face in the parameter space.
+///
+/// The instance created from `Vec<FacePolygon>` provides the parameter range and the trimming
+/// classification of each face to the shaders, so that the shading in the parameter space, e.g.
+/// by [`PolygonShaders::isolines`], is aligned with the faces rather than the whole mesh. The
+/// information is held only by the vertices of such instances, and the instances of the plain
+/// polygon meshes have the vertices without it. The face polygons of the tessellated shapes are
+/// given by [`PreparedPolygon::from_shape`] and [`InstanceCreator::create_shape_instance`].
+#[derive(Clone, Debug)]
+pub struct FacePolygon {
+    /// polygon mesh of the face, whose uv coordinates are the parameters of the surface
+    pub polygon: PolygonMesh,
+    /// the parameter range of the surface: `((u_min, u_max), (v_min, v_max))`
+    pub parameter_range: ((f64, f64), (f64, f64)),
+    /// If this parameter is true, the face is bounded by the trimming curves inside the
+    /// parameter range.
+    pub trimmed: bool,
+}
+
+/// The vertices and the indices of a polygon instance prepared on CPU.
+///
+/// The preparation, e.g. `PreparedPolygon::from(&mesh)`, does not touch GPU, so it can be done on
+/// a worker thread together with the tessellation. The instance is created on the render thread
+/// by [`InstanceCreator::create_instance`], which only uploads the buffers.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// use truck_rendimpl::PreparedPolygon;
+///
+/// // the tessellation and the preparation in background
+/// let worker = std::thread::spawn(|| {
+///     let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+///     let mesh = cube.triangulation(0.01).to_polygon();
+///     PreparedPolygon::from(&mesh)
+/// });
+/// let prepared = worker.join().unwrap();
+/// assert!(prepared.vertex_count() >= 8);
+/// assert_eq!(prepared.index_count() % 3, 0);
+/// // `creator.create_instance::<PolygonInstance, _>(&prepared, &state)` on the render thread
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedPolygon {
+    vertices: PreparedVertices,
+    indices: Vec<u32>,
+}
+
+/// the vertices of the plain polygons, or the ones of the faces
+#[derive(Clone, Debug)]
+enum PreparedVertices {
+    Attr(Vec<AttrVertex>),
+    Face(Vec<FaceVertex>),
+}
+
+/// The vertices and the line segments of a wireframe instance prepared on CPU.
+///
+/// As [`PreparedPolygon`], the instance is created by [`InstanceCreator::create_instance`].
+#[derive(Clone, Debug)]
+pub struct PreparedWireFrame {
+    vertices: Vec<[f32; 4]>,
+    strips: Vec<u32>,
+}
+
+/// Adaptive discretization of curves for wireframes by the screen-space error.
+///
+/// The curves are bisected by [`ScreenSpaceDivision::polyline`] until the polylines look smooth in
+/// the view of the camera, so the circles are round at any zoom level with a few segments. The
+/// wireframes are created by [`PreparedWireFrame::from_curves`] or
+/// [`InstanceCreator::create_curves_instance`].
+#[derive(Clone, Debug)]
+pub struct ScreenSpaceDivision {
+    /// the camera of the view
+    pub camera: Camera,
+    /// the size of the render texture in pixels
+    pub size: (u32, u32),
+    /// the maximum deviation of the polylines from the curves on the screen in pixels.
+    /// Default is 0.5.
+    pub pixel_tolerance: f64,
+    /// the maximum angle between the tangents at the ends of a segment. Default is `PI / 8`.
+    pub max_angle: Rad<f64>,
+    /// the maximum depth of the bisection of each quarter of the curve. Default is 12.
+    pub max_depth: usize,
+}
+
+mod adaptive_division;
 /// utility for creating `Texture`
 pub mod image2texture;
 mod instance_creator;
 mod instance_descriptor;
+mod polygon_group;
 mod polygon_instance;
 mod polyrend;
+mod shape_instance;
 mod wireframe_instance;
@@ -0,0 +1,87 @@
+use crate::*;
+
+impl PolygonGroup {
+    /// Creates the group with the initial `matrix` and `material`.
+    pub fn new(handler: &DeviceHandler, matrix: Matrix4, material: &Material) -> Self {
+        let (id, uniform) = (RenderID::gen(), PolygonUniform::new(matrix, material));
+        handler.object_uniforms().write(handler, id, &uniform);
+        let slot = GroupSlot {
+            id,
+            uniform: std::sync::Mutex::new(uniform),
+            handler: handler.clone(),
+        };
+        Self {
+            slot: Arc::new(slot),
+        }
+    }
+    /// Binds `instance` to the slot of the group.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`, and the later updates
+    /// of the group do not need it.
+    #[inline(always)]
+    pub fn join(&self, instance: &mut PolygonInstance) { instance.group = Some(self.clone()) }
+    /// Sets the matrix of all the instances in the group by one write.
+    #[inline(always)]
+    pub fn set_matrix(&self, matrix: Matrix4) {
+        let matrix = matrix.cast::<f32>().unwrap().into();
+        self.slot.update(|uniform| uniform.matrix = matrix);
+    }
+    /// Sets the material of all the instances in the group by one write.
+    ///
+    /// The alpha blend flag is not reflected, since it is the state of the pipelines.
+    #[inline(always)]
+    pub fn set_material(&self, material: &Material) {
+        let material = material.uniform_data();
+        self.slot.update(|uniform| uniform.material = material);
+    }
+    /// Returns the id of the slot of the group in the uniform ring.
+    #[inline(always)]
+    pub(crate) fn slot_id(&self) -> RenderID { self.slot.id }
+}
+
+impl GroupSlot {
+    /// Modifies the data by `f` and writes it to the slot.
+    fn update(&self, f: impl FnOnce(&mut PolygonUniform)) {
+        let mut uniform = self.uniform.lock().unwrap();
+        f(&mut uniform);
+        let uniforms = self.handler.object_uniforms();
+        uniforms.write(&self.handler, self.id, &*uniform);
+    }
+}
+
+impl Drop for GroupSlot {
+    #[inline(always)]
+    fn drop(&mut self) { self.handler.object_uniforms().remove(self.id); }
+}
+
+impl InstanceCreator {
+    /// Creates the group of polygon instances with the initial `matrix` and `material`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// use truck_rendimpl::*;
+    /// let handler = pollster::block_on(DeviceHandler::default_device());
+    /// let mut scene = Scene::new(handler, &Default::default());
+    /// let creator = scene.instance_creator();
+    ///
+    /// let mesh = PolygonMesh::default();
+    /// let mut instances: Vec<PolygonInstance> = (0..100)
+    ///     .map(|_| creator.create_instance(&mesh, &Default::default()))
+    ///     .collect();
+    /// let group = creator.create_polygon_group(Matrix4::identity(), &Material::default());
+    /// instances.iter_mut().for_each(|instance| group.join(instance));
+    /// scene.add_objects(&instances);
+    /// let scene_group = scene.create_group(&instances);
+    ///
+    /// // bulk updates
+    /// group.set_matrix(Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0)));
+    /// group.set_material(&Material::STEEL);
+    /// assert!(scene.set_group_visibility(scene_group, false));
+    /// assert_eq!(scene.group_members(scene_group).unwrap().len(), 100);
+    /// ```
+    #[inline(always)]
+    pub fn create_polygon_group(&self, matrix: Matrix4, material: &Material) -> PolygonGroup {
+        PolygonGroup::new(&self.handler, matrix, material)
+    }
+}
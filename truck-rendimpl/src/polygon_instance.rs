@@ -8,6 +8,12 @@ impl PolygonInstance {
             polygon: self.polygon.clone(),
             state: self.state.clone(),
             shaders: self.shaders.clone(),
+            group: self.group.clone(),
+            custom_bindings: self.custom_bindings.clone(),
+            uniforms: Arc::clone(&self.uniforms),
+            face_vertices: self.face_vertices,
+            visible: self.visible,
+            layers: self.layers,
             id: RenderID::gen(),
         }
     }
@@ -17,6 +23,54 @@ impl PolygonInstance {
     /// Returns the mutable reference to instance descriptor.
     #[inline(always)]
     pub fn instance_state_mut(&mut self) -> &mut PolygonState { &mut self.state }
+    /// Returns the shaders of the instance.
+    #[inline(always)]
+    pub const fn shaders(&self) -> &PolygonShaders { &self.shaders }
+    /// Replaces the shaders, e.g. by the reloaded ones.
+    ///
+    /// The change is reflected in the scene by `Scene::update_pipeline`.
+    #[inline(always)]
+    pub fn set_shaders(&mut self, shaders: PolygonShaders) { self.shaders = shaders }
+    /// Returns the additional resources bound to the custom shaders.
+    #[inline(always)]
+    pub const fn custom_bindings(&self) -> &CustomBindings { &self.custom_bindings }
+    /// Sets the additional resources bound to the custom shaders created by
+    /// [`PolygonShaders::custom_fragment`]. No resources are bound by default.
+    ///
+    /// The layout of the bind group is changed by the resources, so the change is reflected in
+    /// the scene by adding the instance again by `Scene::add_object`.
+    #[inline(always)]
+    pub fn set_custom_bindings(&mut self, custom_bindings: CustomBindings) {
+        self.custom_bindings = custom_bindings
+    }
+    /// Returns whether the instance is drawn. Default is `true`.
+    #[inline(always)]
+    pub const fn visible(&self) -> bool { self.visible }
+    /// Sets whether the instance is drawn, e.g. in order to hide the construction geometries.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn set_visible(&mut self, visible: bool) { self.visible = visible }
+    /// Returns the bit mask of the layers which the instance belongs to. Default is `1`.
+    ///
+    /// The instance is drawn only if one of its layers is visible in the scene.
+    #[inline(always)]
+    pub const fn layers(&self) -> u32 { self.layers }
+    /// Sets the bit mask of the layers which the instance belongs to.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn set_layers(&mut self, layers: u32) { self.layers = layers }
+
+    /// Returns the group whose buffers the instance is bound to.
+    #[inline(always)]
+    pub const fn group(&self) -> Option<&PolygonGroup> { self.group.as_ref() }
+    /// Leaves the group, and binds the slot of the matrix and the material of the instance state
+    /// again.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn leave_group(&mut self) { self.group = None }
 
     /// swap vertex buffers and index buffers
     #[inline(always)]
@@ -24,56 +78,95 @@ impl PolygonInstance {
         std::mem::swap(&mut self.polygon, &mut other.polygon);
     }
 
+    /// the entries of the bind group layout, with or without the texture
+    fn bgl_entries(&self) -> Vec<PreBindGroupLayoutEntry> {
+        let mut entries = vec![self.uniforms.bgl_entry()];
+        if self.state.texture.is_some() {
+            entries.push(PolygonState::textureview_bgl_entry());
+            entries.push(PolygonState::sampler_bgl_entry());
+        }
+        entries.extend(self.custom_bindings.bgl_entries());
+        entries
+    }
+
+    /// Returns `true` if the instance binds only the slot, i.e. the bind group of the chunk of
+    /// the slot is shared.
+    #[inline(always)]
+    fn binds_only_slot(&self) -> bool {
+        let custom = &self.custom_bindings;
+        self.state.texture.is_none() && custom.uniform.is_none() && custom.textures.is_empty()
+    }
+
+    /// the id of the slot of the matrix and the material, shared with the group if it exists
+    #[inline(always)]
+    fn slot_id(&self) -> RenderID {
+        match &self.group {
+            Some(group) => group.slot_id(),
+            None => self.id,
+        }
+    }
+
+    /// Writes the matrix and the material to the slot, unless the instance is in the group.
     #[inline(always)]
-    fn non_textured_bdl(&self, device: &Device) -> BindGroupLayout {
-        bind_group_util::create_bind_group_layout(device, {
-            &[
-                PolygonState::matrix_bgl_entry(),
-                PolygonState::material_bgl_entry(),
+    fn write_uniform(&self, handler: &DeviceHandler) {
+        if self.group.is_none() {
+            let uniform = PolygonUniform::new(self.state.matrix, &self.state.material);
+            self.uniforms.write(handler, self.id, &uniform);
+        }
+    }
+
+    fn bind_group_with_resources(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
+        let texture = self
+            .state
+            .texture
+            .as_ref()
+            .map(|_| self.state.textureview_and_sampler(device));
+        let uniform = self.custom_bindings.uniform_buffer(device);
+        let textures = self.custom_bindings.textureviews_and_samplers(device);
+        let texture_resources = texture.iter().flat_map(|(view, sampler)| {
+            [
+                BindingResource::TextureView(view),
+                BindingResource::Sampler(sampler),
             ]
-        })
-    }
-
-    #[inline(always)]
-    fn textured_bdl(&self, device: &Device) -> BindGroupLayout {
-        bind_group_util::create_bind_group_layout(
-            device,
-            &[
-                PolygonState::matrix_bgl_entry(),
-                PolygonState::material_bgl_entry(),
-                PolygonState::textureview_bgl_entry(),
-                PolygonState::sampler_bgl_entry(),
-            ],
-        )
-    }
-
-    #[inline(always)]
-    fn non_textured_bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
-        bind_group_util::create_bind_group(
-            device,
-            layout,
-            vec![
-                self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
-            ],
-        )
-    }
-    #[inline(always)]
-    fn textured_bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
-        let (view, sampler) = self.state.textureview_and_sampler(device);
-        bind_group_util::create_bind_group(
-            device,
-            layout,
-            vec![
-                self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
-                BindingResource::TextureView(&view),
-                BindingResource::Sampler(&sampler),
-            ],
-        )
+        });
+        let resources = texture_resources.chain(custom_resources(&uniform, &textures));
+        // the slot has been written by `write_uniform`, or by the group.
+        let bind_group = self
+            .uniforms
+            .create_bind_group(device, self.slot_id(), layout, resources);
+        bind_group.unwrap()
+    }
+}
+
+impl PolygonUniform {
+    #[inline(always)]
+    pub(crate) fn new(matrix: Matrix4, material: &Material) -> Self {
+        Self {
+            matrix: matrix.cast::<f32>().unwrap().into(),
+            material: material.uniform_data(),
+        }
     }
 }
 
+impl Drop for PolygonInstance {
+    #[inline(always)]
+    fn drop(&mut self) { self.uniforms.remove(self.id); }
+}
+
+fn custom_resources<'a>(
+    uniform: &'a Option<BufferHandler>,
+    textures: &'a [(TextureView, Sampler)],
+) -> impl Iterator<Item = BindingResource<'a>> {
+    let uniform = uniform.iter().map(BufferHandler::binding_resource);
+    let textures = textures.iter().flat_map(|(view, sampler)| {
+        [
+            BindingResource::TextureView(view),
+            BindingResource::Sampler(sampler),
+        ]
+    });
+    uniform.chain(textures)
+}
+
 impl Rendered for PolygonInstance {
     impl_render_id!(id);
 
@@ -84,10 +177,13 @@ impl Rendered for PolygonInstance {
     }
     #[inline(always)]
     fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bdl(device_handler.device()),
-            false => self.non_textured_bdl(device_handler.device()),
-        })
+        match self.binds_only_slot() {
+            true => Arc::clone(self.uniforms.layout()),
+            false => Arc::new(bind_group_util::create_bind_group_layout(
+                device_handler.device(),
+                &self.bgl_entries(),
+            )),
+        }
     }
     #[inline(always)]
     fn bind_group(
@@ -95,12 +191,23 @@ impl Rendered for PolygonInstance {
         device_handler: &DeviceHandler,
         layout: &BindGroupLayout,
     ) -> Arc<BindGroup> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bg(device_handler.device(), layout),
-            false => self.non_textured_bg(device_handler.device(), layout),
-        })
+        self.write_uniform(device_handler);
+        let device = device_handler.device();
+        match (self.binds_only_slot(), self.uniforms.slot(self.slot_id())) {
+            (true, Some((bind_group, _))) => bind_group,
+            _ => Arc::new(self.bind_group_with_resources(device, layout)),
+        }
     }
     #[inline(always)]
+    fn dynamic_offsets(&self) -> Vec<u32> {
+        let slot = self.uniforms.slot(self.slot_id());
+        vec![slot.map_or(0, |(_, offset)| offset)]
+    }
+    #[inline(always)]
+    fn visible(&self) -> bool { self.visible }
+    #[inline(always)]
+    fn layers(&self) -> u32 { self.layers }
+    #[inline(always)]
     fn pipeline(
         &self,
         device_handler: &DeviceHandler,
@@ -115,10 +222,7 @@ impl Rendered for PolygonInstance {
             ),
             false => (&self.shaders.fragment_module, self.shaders.fragment_entry),
         };
-        let cull_mode = match self.state.backface_culling {
-            true => Some(wgpu::Face::Back),
-            false => None,
-        };
+        let cull_mode = self.cull_mode();
         let blend = match self.state.material.alpha_blend {
             true => Some(BlendState::ALPHA_BLENDING),
             false => Some(BlendState::REPLACE),
@@ -134,58 +238,162 @@ impl Rendered for PolygonInstance {
             false => None,
         };
         let sample_count = scene_desc.backend_buffer.sample_count;
-        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
-            layout: Some(layout),
-            vertex: VertexState {
-                module: &self.shaders.vertex_module,
-                entry_point: self.shaders.vertex_entry,
-                buffers: &[VertexBufferLayout {
-                    array_stride: std::mem::size_of::<AttrVertex>() as BufferAddress,
-                    step_mode: VertexStepMode::Vertex,
-                    attributes: &[
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 0,
-                            shader_location: 0,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: 3 * 4,
-                            shader_location: 1,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 2 * 4 + 3 * 4,
-                            shader_location: 2,
-                        },
-                    ],
-                }],
-            },
-            fragment: Some(FragmentState {
-                module: fragment_module,
-                entry_point: fragment_entry,
-                targets: &[Some(ColorTargetState {
-                    format: scene_desc.render_texture.format,
-                    blend,
-                    write_mask: ColorWrites::ALL,
-                })],
-            }),
-            primitive: PrimitiveState {
-                topology: PrimitiveTopology::TriangleList,
-                front_face: FrontFace::Ccw,
-                cull_mode,
-                polygon_mode: PolygonMode::Fill,
-                ..Default::default()
-            },
-            depth_stencil,
-            multisample: MultisampleState {
-                count: sample_count,
-                mask: !0,
-                alpha_to_coverage_enabled: sample_count > 1,
-            },
-            label: None,
-            multiview: None,
-        });
-        Arc::new(pipeline)
+        let variant = self.state.texture.is_some() as u64
+            | (self.state.backface_culling as u64) << 1
+            | (self.state.material.alpha_blend as u64) << 2
+            | (self.face_vertices as u64) << 4;
+        let shaders = [
+            (&self.shaders.vertex_module, self.vertex_entry()),
+            (fragment_module, fragment_entry),
+        ];
+        let key = PipelineKey::new(&shaders, &self.bgl_entries(), variant, scene_desc);
+        let create = || {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                layout: Some(layout),
+                vertex: self.vertex_state(),
+                fragment: Some(FragmentState {
+                    module: fragment_module,
+                    entry_point: fragment_entry,
+                    targets: &[Some(ColorTargetState {
+                        format: scene_desc.render_texture.format,
+                        blend,
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                primitive: primitive_state(cull_mode),
+                depth_stencil,
+                multisample: MultisampleState {
+                    count: sample_count,
+                    mask: !0,
+                    alpha_to_coverage_enabled: sample_count > 1,
+                },
+                label: None,
+                multiview: None,
+            })
+        };
+        device_handler.pipeline_cache().get_or_create(key, create)
+    }
+    fn gbuffer_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        let device = device_handler.device();
+        let (fragment_module, fragment_entry) = self.shaders.gbuffer_fragment.as_ref()?;
+        // The G-buffer pipelines are distinguished by the fourth bit, and the vertex layouts of
+        // the faces by the fifth.
+        let variant =
+            (self.state.backface_culling as u64) << 1 | 1 << 3 | (self.face_vertices as u64) << 4;
+        let shaders = [
+            (&self.shaders.vertex_module, self.vertex_entry()),
+            (fragment_module, *fragment_entry),
+        ];
+        let key = PipelineKey::new(&shaders, &self.bgl_entries(), variant, scene_desc);
+        let create = || {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                layout: Some(layout),
+                vertex: self.vertex_state(),
+                fragment: Some(FragmentState {
+                    module: fragment_module,
+                    entry_point: fragment_entry,
+                    targets: &GBuffer::color_targets(),
+                }),
+                primitive: primitive_state(self.cull_mode()),
+                depth_stencil: Some(GBuffer::depth_stencil_state()),
+                multisample: Default::default(),
+                label: None,
+                multiview: None,
+            })
+        };
+        Some(device_handler.pipeline_cache().get_or_create(key, create))
+    }
+}
+
+const VERTEX_ATTRIBUTES: [VertexAttribute; 3] = [
+    VertexAttribute {
+        format: VertexFormat::Float32x3,
+        offset: 0,
+        shader_location: 0,
+    },
+    VertexAttribute {
+        format: VertexFormat::Float32x2,
+        offset: 3 * 4,
+        shader_location: 1,
+    },
+    VertexAttribute {
+        format: VertexFormat::Float32x3,
+        offset: 2 * 4 + 3 * 4,
+        shader_location: 2,
+    },
+];
+
+const VERTEX_BUFFER_LAYOUT: VertexBufferLayout<'static> = VertexBufferLayout {
+    array_stride: std::mem::size_of::<AttrVertex>() as BufferAddress,
+    step_mode: VertexStepMode::Vertex,
+    attributes: &VERTEX_ATTRIBUTES,
+};
+
+/// the layout of `FaceVertex`, whose first attributes are the same as `AttrVertex`
+const FACE_VERTEX_BUFFER_LAYOUT: VertexBufferLayout<'static> = VertexBufferLayout {
+    array_stride: std::mem::size_of::<FaceVertex>() as BufferAddress,
+    step_mode: VertexStepMode::Vertex,
+    attributes: &[
+        VERTEX_ATTRIBUTES[0],
+        VERTEX_ATTRIBUTES[1],
+        VERTEX_ATTRIBUTES[2],
+        VertexAttribute {
+            format: VertexFormat::Float32x4,
+            offset: 2 * 4 + 6 * 4,
+            shader_location: 3,
+        },
+        VertexAttribute {
+            format: VertexFormat::Uint32,
+            offset: 6 * 4 + 6 * 4,
+            shader_location: 4,
+        },
+    ],
+};
+
+impl PolygonInstance {
+    #[inline(always)]
+    fn vertex_state(&self) -> VertexState<'_> {
+        let buffers: &[VertexBufferLayout<'static>] = match self.face_vertices {
+            true => &[FACE_VERTEX_BUFFER_LAYOUT],
+            false => &[VERTEX_BUFFER_LAYOUT],
+        };
+        VertexState {
+            module: &self.shaders.vertex_module,
+            entry_point: self.vertex_entry(),
+            buffers,
+        }
+    }
+
+    /// the entry point of the vertex shader for the vertices of the instance
+    #[inline(always)]
+    fn vertex_entry(&self) -> &'static str {
+        match (self.face_vertices, self.shaders.face_vertex_entry) {
+            (true, Some(entry)) => entry,
+            _ => self.shaders.vertex_entry,
+        }
+    }
+
+    #[inline(always)]
+    fn cull_mode(&self) -> Option<wgpu::Face> {
+        match self.state.backface_culling {
+            true => Some(wgpu::Face::Back),
+            false => None,
+        }
+    }
+}
+
+#[inline(always)]
+fn primitive_state(cull_mode: Option<wgpu::Face>) -> PrimitiveState {
+    PrimitiveState {
+        topology: PrimitiveTopology::TriangleList,
+        front_face: FrontFace::Ccw,
+        cull_mode,
+        polygon_mode: PolygonMode::Fill,
+        ..Default::default()
     }
 }
@@ -1,118 +1,162 @@
+use crate::wireframe_instance::{polyline_lengths, wire_vertices};
 use crate::*;
+use truck_meshalgo::tessellation::{MeshedShape, MeshingPreferences, PreferredMeshableShape};
 
-impl CreateBuffers for PolygonMesh {
-    #[inline(always)]
-    fn buffers(
-        &self,
-        vertex_usage: BufferUsages,
-        index_usage: BufferUsages,
-        device: &Device,
-    ) -> (BufferHandler, BufferHandler) {
-        let expanded = self.expands(|attr| AttrVertex {
-            position: attr.position.cast().unwrap().into(),
-            uv_coord: attr
-                .uv_coord
-                .and_then(|v| Some(v.cast()?.into()))
-                .unwrap_or([0.0, 0.0]),
-            normal: attr
-                .normal
-                .and_then(|v| Some(v.cast()?.into()))
-                .unwrap_or([0.0, 0.0, 0.0]),
-        });
-        let indices = expanded
-            .faces()
-            .triangle_iter()
-            .flatten()
-            .map(|x| x as u32)
-            .collect::<Vec<_>>();
-        (
-            BufferHandler::from_slice(expanded.attributes(), device, vertex_usage),
-            BufferHandler::from_slice(&indices, device, index_usage),
-        )
+type ParameterRange = ((f64, f64), (f64, f64));
+
+/// Returns the bounding box of the uv coordinates, or the unit square if there are no ones.
+fn uv_range(uv_coords: &[Vector2]) -> ParameterRange {
+    match uv_coords.is_empty() {
+        true => ((0.0, 1.0), (0.0, 1.0)),
+        false => {
+            let bdd: BoundingBox<Vector2> = uv_coords.iter().collect();
+            let (min, max) = (bdd.min(), bdd.max());
+            ((min[0], max[0]), (min[1], max[1]))
+        }
     }
 }
 
-impl Instance for PolygonInstance {
-    type Shaders = PolygonShaders;
-    fn standard_shaders(creator: &InstanceCreator) -> PolygonShaders {
-        creator.polygon_shaders.clone()
+#[inline(always)]
+fn range_data(((u0, u1), (v0, v1)): ParameterRange) -> [f32; 4] {
+    [u0 as f32, u1 as f32, v0 as f32, v1 as f32]
+}
+
+fn attr_vertices(polygon: &PolygonMesh) -> (Vec<AttrVertex>, Vec<u32>) {
+    let expanded = polygon.expands(|attr| AttrVertex {
+        position: attr.position.cast().unwrap().into(),
+        uv_coord: attr
+            .uv_coord
+            .and_then(|v| Some(v.cast()?.into()))
+            .unwrap_or([0.0, 0.0]),
+        normal: attr
+            .normal
+            .and_then(|v| Some(v.cast()?.into()))
+            .unwrap_or([0.0, 0.0, 0.0]),
+    });
+    let indices = expanded
+        .faces()
+        .triangle_iter()
+        .flatten()
+        .map(|x| x as u32)
+        .collect::<Vec<_>>();
+    (expanded.attributes().clone(), indices)
+}
+
+impl From<PolygonMesh> for FacePolygon {
+    /// The parameter range is the bounding box of the uv coordinates, and the face is trimmed
+    /// if an edge of the boundary of the mesh is not on the sides of the range, e.g. the face has
+    /// holes.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// use truck_rendimpl::FacePolygon;
+    ///
+    /// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    /// let meshed = cube.triangulation(0.01);
+    /// let faces: Vec<FacePolygon> = meshed
+    ///     .to_face_polygons()
+    ///     .into_iter()
+    ///     .map(FacePolygon::from)
+    ///     .collect();
+    /// assert_eq!(faces.len(), 6);
+    /// assert!(faces.iter().all(|face| !face.trimmed));
+    /// ```
+    fn from(polygon: PolygonMesh) -> Self {
+        let parameter_range = uv_range(polygon.uv_coords());
+        let ((u0, u1), (v0, v1)) = parameter_range;
+        // whether the uv coordinate is on the sides `u = u0`, `u = u1`, `v = v0` and `v = v1`
+        let sides = |uv: usize| {
+            let [u, v] = polygon.uv_coords()[uv].into();
+            [u.near(&u0), u.near(&u1), v.near(&v0), v.near(&v1)]
+        };
+        let on_range_boundary = |a: usize, b: usize| {
+            let (a, b) = (sides(a), sides(b));
+            (0..4).any(|k| a[k] && b[k])
+        };
+        // The edges of the triangles used once are the boundary of the mesh.
+        let mut counter = std::collections::HashMap::<(usize, usize), usize>::new();
+        polygon.faces().triangle_iter().for_each(|tri| {
+            (0..3).for_each(|i| {
+                if let (Some(a), Some(b)) = (tri[i].uv, tri[(i + 1) % 3].uv) {
+                    let edge = (usize::min(a, b), usize::max(a, b));
+                    *counter.entry(edge).or_insert(0) += 1;
+                }
+            })
+        });
+        let trimmed = counter
+            .into_iter()
+            .filter(|(_, count)| *count == 1)
+            .any(|((a, b), _)| !on_range_boundary(a, b));
+        Self {
+            polygon,
+            parameter_range,
+            trimmed,
+        }
     }
 }
 
-impl ToInstance<PolygonInstance> for PolygonMesh {
-    type State = PolygonState;
+impl From<&PolygonMesh> for PreparedPolygon {
     #[inline(always)]
-    fn to_instance(
-        &self,
-        handler: &DeviceHandler,
-        shaders: &PolygonShaders,
-        state: &PolygonState,
-    ) -> PolygonInstance {
-        let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
-        PolygonInstance {
-            polygon: (Arc::new(vb), Arc::new(ib)),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
+    fn from(polygon: &PolygonMesh) -> Self {
+        let (vertices, indices) = attr_vertices(polygon);
+        Self {
+            vertices: PreparedVertices::Attr(vertices),
+            indices,
         }
     }
 }
 
-impl ToInstance<WireFrameInstance> for PolygonMesh {
-    type State = WireFrameState;
-    fn to_instance(
-        &self,
-        handler: &DeviceHandler,
-        shaders: &WireShaders,
-        state: &WireFrameState,
-    ) -> WireFrameInstance {
-        let device = handler.device();
-        let positions: Vec<[f32; 3]> = self
-            .positions()
-            .iter()
-            .map(|p| p.cast().unwrap().into())
-            .collect();
-        let mut strips = Vec::<u32>::new();
-        self.faces().face_iter().for_each(|face| {
-            for i in 0..face.len() {
-                strips.push(face[i].pos as u32);
-                strips.push(face[(i + 1) % face.len()].pos as u32);
-            }
+impl From<&[FacePolygon]> for PreparedPolygon {
+    fn from(faces: &[FacePolygon]) -> Self {
+        let (mut vertices, mut indices) = (Vec::new(), Vec::new());
+        faces.iter().for_each(|face| {
+            let (face_vertices, face_indices) = attr_vertices(&face.polygon);
+            let offset = vertices.len() as u32;
+            indices.extend(face_indices.into_iter().map(|i| i + offset));
+            let (parameter_range, trimmed) = (range_data(face.parameter_range), face.trimmed);
+            vertices.extend(face_vertices.into_iter().map(|attr| FaceVertex {
+                attr,
+                parameter_range,
+                trimmed: trimmed as u32,
+            }));
         });
-        let vb = BufferHandler::from_slice(&positions, device, BufferUsages::VERTEX);
-        let ib = BufferHandler::from_slice(&strips, device, BufferUsages::INDEX);
-        WireFrameInstance {
-            vertices: Arc::new(vb),
-            strips: Arc::new(ib),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
+        Self {
+            vertices: PreparedVertices::Face(vertices),
+            indices,
         }
     }
 }
 
-impl CreateBuffers for StructuredMesh {
-    #[inline(always)]
-    fn buffers(
-        &self,
-        vertex_usage: BufferUsages,
-        index_usage: BufferUsages,
-        device: &Device,
-    ) -> (BufferHandler, BufferHandler) {
+impl From<&StructuredMesh> for PreparedPolygon {
+    /// The mesh is one face in the parameter range of the uv division.
+    fn from(mesh: &StructuredMesh) -> Self {
         let mut vertices = Vec::new();
-        let (m, n) = (self.positions().len(), self.positions()[0].len());
+        let (m, n) = (mesh.positions().len(), mesh.positions()[0].len());
+        let parameter_range = match mesh.uv_division() {
+            Some((udiv, vdiv)) => range_data((
+                (udiv[0], udiv[udiv.len() - 1]),
+                (vdiv[0], vdiv[vdiv.len() - 1]),
+            )),
+            None => [0.0, 1.0, 0.0, 1.0],
+        };
         for i in 0..m {
             for j in 0..n {
-                vertices.push(AttrVertex {
-                    position: self.positions()[i][j].cast().unwrap().into(),
-                    uv_coord: match self.uv_division() {
+                let attr = AttrVertex {
+                    position: mesh.positions()[i][j].cast().unwrap().into(),
+                    uv_coord: match mesh.uv_division() {
                         Some(uv_division) => [uv_division.0[i] as f32, uv_division.1[j] as f32],
                         None => [0.0, 0.0],
                     },
-                    normal: match self.normals() {
+                    normal: match mesh.normals() {
                         Some(normals) => normals[i][j].cast().unwrap().into(),
                         None => [0.0, 0.0, 0.0],
                     },
+                };
+                vertices.push(FaceVertex {
+                    attr,
+                    parameter_range,
+                    trimmed: 0,
                 });
             }
         }
@@ -129,14 +173,127 @@ impl CreateBuffers for StructuredMesh {
                 ]);
             }
         }
-        (
-            BufferHandler::from_slice(&vertices, device, vertex_usage),
-            BufferHandler::from_slice(&indices, device, index_usage),
-        )
+        Self {
+            vertices: PreparedVertices::Face(vertices),
+            indices,
+        }
     }
 }
 
-impl ToInstance<PolygonInstance> for StructuredMesh {
+impl PreparedPolygon {
+    /// Returns the number of the vertices.
+    #[inline(always)]
+    pub fn vertex_count(&self) -> usize {
+        match &self.vertices {
+            PreparedVertices::Attr(vertices) => vertices.len(),
+            PreparedVertices::Face(vertices) => vertices.len(),
+        }
+    }
+    /// Returns the number of the indices of the triangles.
+    #[inline(always)]
+    pub fn index_count(&self) -> usize { self.indices.len() }
+    /// Returns whether the vertices have the information of the faces, given by [`FacePolygon`].
+    #[inline(always)]
+    pub fn has_face_vertices(&self) -> bool { matches!(self.vertices, PreparedVertices::Face(_)) }
+    /// Tessellates `shape` with the densities given by `preferences`, and prepares the polygon.
+    ///
+    /// # Panics
+    ///
+    /// The chordal tolerances of the densities must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// use truck_rendimpl::PreparedPolygon;
+    ///
+    /// let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    /// let side = cylinder.boundaries()[0][1].id();
+    /// let preferences = MeshingPreferences::new(MeshingDensity::coarse());
+    /// let coarse = PreparedPolygon::from_shape(&cylinder, &preferences);
+    ///
+    /// // the side is dense, and the others are the same as `coarse`.
+    /// let dense = MeshingDensity {
+    ///     max_edge_length: Some(0.05),
+    ///     ..MeshingDensity::coarse()
+    /// };
+    /// let preferences = preferences.with_face(side, dense);
+    /// let overridden = PreparedPolygon::from_shape(&cylinder, &preferences);
+    /// assert!(overridden.vertex_count() > 10 * coarse.vertex_count());
+    /// ```
+    pub fn from_shape<K, T>(shape: &T, preferences: &MeshingPreferences<K>) -> Self
+    where T: PreferredMeshableShape<K> {
+        let meshed = shape.preferred_triangulation(preferences);
+        let faces = meshed.to_face_polygons().into_iter().map(FacePolygon::from);
+        Self::from(faces.collect::<Vec<_>>().as_slice())
+    }
+}
+
+impl CreateBuffers for PreparedPolygon {
+    #[inline(always)]
+    fn buffers(
+        &self,
+        vertex_usage: BufferUsages,
+        index_usage: BufferUsages,
+        device: &Device,
+    ) -> (BufferHandler, BufferHandler) {
+        let vertices = match &self.vertices {
+            PreparedVertices::Attr(vertices) => {
+                BufferHandler::from_slice(vertices, device, vertex_usage)
+            }
+            PreparedVertices::Face(vertices) => {
+                BufferHandler::from_slice(vertices, device, vertex_usage)
+            }
+        };
+        let indices = BufferHandler::from_slice(&self.indices, device, index_usage);
+        (vertices, indices)
+    }
+}
+
+impl CreateBuffers for PolygonMesh {
+    #[inline(always)]
+    fn buffers(
+        &self,
+        vertex_usage: BufferUsages,
+        index_usage: BufferUsages,
+        device: &Device,
+    ) -> (BufferHandler, BufferHandler) {
+        PreparedPolygon::from(self).buffers(vertex_usage, index_usage, device)
+    }
+}
+
+impl CreateBuffers for Vec<FacePolygon> {
+    #[inline(always)]
+    fn buffers(
+        &self,
+        vertex_usage: BufferUsages,
+        index_usage: BufferUsages,
+        device: &Device,
+    ) -> (BufferHandler, BufferHandler) {
+        PreparedPolygon::from(self.as_slice()).buffers(vertex_usage, index_usage, device)
+    }
+}
+
+impl CreateBuffers for StructuredMesh {
+    #[inline(always)]
+    fn buffers(
+        &self,
+        vertex_usage: BufferUsages,
+        index_usage: BufferUsages,
+        device: &Device,
+    ) -> (BufferHandler, BufferHandler) {
+        PreparedPolygon::from(self).buffers(vertex_usage, index_usage, device)
+    }
+}
+
+impl Instance for PolygonInstance {
+    type Shaders = PolygonShaders;
+    fn standard_shaders(creator: &InstanceCreator) -> PolygonShaders {
+        creator.polygon_shaders.clone()
+    }
+}
+
+impl ToInstance<PolygonInstance> for PreparedPolygon {
     type State = PolygonState;
     #[inline(always)]
     fn to_instance(
@@ -150,29 +307,77 @@ impl ToInstance<PolygonInstance> for StructuredMesh {
             polygon: (Arc::new(vb), Arc::new(ib)),
             state: state.clone(),
             shaders: shaders.clone(),
+            group: None,
+            custom_bindings: Default::default(),
+            uniforms: Arc::clone(handler.object_uniforms()),
+            face_vertices: self.has_face_vertices(),
+            visible: true,
+            layers: 1,
             id: RenderID::gen(),
         }
     }
 }
 
-impl ToInstance<WireFrameInstance> for StructuredMesh {
-    type State = WireFrameState;
+impl ToInstance<PolygonInstance> for PolygonMesh {
+    type State = PolygonState;
+    #[inline(always)]
     fn to_instance(
         &self,
         handler: &DeviceHandler,
-        shaders: &WireShaders,
-        state: &WireFrameState,
-    ) -> WireFrameInstance {
-        let device = handler.device();
-        let positions: Vec<[f32; 3]> = self
-            .positions()
-            .iter()
-            .flatten()
-            .map(|p| p.cast().unwrap().into())
-            .collect();
+        shaders: &PolygonShaders,
+        state: &PolygonState,
+    ) -> PolygonInstance {
+        PreparedPolygon::from(self).to_instance(handler, shaders, state)
+    }
+}
+
+impl ToInstance<PolygonInstance> for Vec<FacePolygon> {
+    type State = PolygonState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &PolygonShaders,
+        state: &PolygonState,
+    ) -> PolygonInstance {
+        PreparedPolygon::from(self.as_slice()).to_instance(handler, shaders, state)
+    }
+}
+
+impl ToInstance<PolygonInstance> for StructuredMesh {
+    type State = PolygonState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &PolygonShaders,
+        state: &PolygonState,
+    ) -> PolygonInstance {
+        PreparedPolygon::from(self).to_instance(handler, shaders, state)
+    }
+}
+
+impl From<&PolygonMesh> for PreparedWireFrame {
+    fn from(polygon: &PolygonMesh) -> Self {
+        let vertices = wire_vertices(polygon.positions(), |_| 0.0);
         let mut strips = Vec::<u32>::new();
-        let len = self.positions()[0].len() as u32;
-        for i in 1..positions.len() as u32 {
+        polygon.faces().face_iter().for_each(|face| {
+            for i in 0..face.len() {
+                strips.push(face[i].pos as u32);
+                strips.push(face[(i + 1) % face.len()].pos as u32);
+            }
+        });
+        Self { vertices, strips }
+    }
+}
+
+impl From<&StructuredMesh> for PreparedWireFrame {
+    fn from(mesh: &StructuredMesh) -> Self {
+        let points: Vec<Point3> = mesh.positions().iter().flatten().copied().collect();
+        let vertices = wire_vertices(&points, |_| 0.0);
+        let mut strips = Vec::<u32>::new();
+        let len = mesh.positions()[0].len() as u32;
+        for i in 1..vertices.len() as u32 {
             strips.push((i - 1) * len);
             strips.push(i * len);
         }
@@ -180,7 +385,7 @@ impl ToInstance<WireFrameInstance> for StructuredMesh {
             strips.push(j - 1);
             strips.push(j);
         }
-        for i in 1..self.positions().len() as u32 {
+        for i in 1..mesh.positions().len() as u32 {
             for j in 1..len {
                 strips.push((i - 1) * len + j);
                 strips.push(i * len + j);
@@ -188,19 +393,83 @@ impl ToInstance<WireFrameInstance> for StructuredMesh {
                 strips.push(i * len + j);
             }
         }
-        let vb = BufferHandler::from_slice(&positions, device, BufferUsages::VERTEX);
-        let ib = BufferHandler::from_slice(&strips, device, BufferUsages::INDEX);
-        WireFrameInstance {
-            vertices: Arc::new(vb),
-            strips: Arc::new(ib),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
-        }
+        Self { vertices, strips }
     }
 }
 
-impl ToInstance<WireFrameInstance> for PolylineCurve<Point3> {
+impl From<&PolylineCurve<Point3>> for PreparedWireFrame {
+    fn from(polyline: &PolylineCurve<Point3>) -> Self {
+        let lengths = polyline_lengths(polyline);
+        let vertices = wire_vertices(polyline, |i| lengths[i]);
+        let strips: Vec<u32> = (1..vertices.len())
+            .flat_map(|i| vec![i as u32 - 1, i as u32])
+            .collect();
+        Self { vertices, strips }
+    }
+}
+
+impl From<&[PolylineCurve<Point3>]> for PreparedWireFrame {
+    fn from(polylines: &[PolylineCurve<Point3>]) -> Self {
+        let points = polylines.iter().flat_map(|poly| poly.iter());
+        let points: Vec<Point3> = points.copied().collect();
+        let lengths: Vec<f64> = polylines.iter().flat_map(|c| polyline_lengths(c)).collect();
+        let vertices = wire_vertices(&points, |i| lengths[i]);
+        let mut counter = 0;
+        let strips: Vec<u32> = polylines
+            .iter()
+            .flat_map(|poly| {
+                let len = counter as u32;
+                counter += poly.len();
+                (1..poly.len()).flat_map(move |i| vec![len + i as u32 - 1, len + i as u32])
+            })
+            .collect();
+        Self { vertices, strips }
+    }
+}
+
+impl PreparedWireFrame {
+    /// Returns the number of the vertices.
+    #[inline(always)]
+    pub fn vertex_count(&self) -> usize { self.vertices.len() }
+    /// Returns the number of the indices of the line segments.
+    #[inline(always)]
+    pub fn index_count(&self) -> usize { self.strips.len() }
+    /// Discretizes `curves`, e.g. the curves of the edges of a shape, by `division` and prepares
+    /// the wireframe.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_modeling::*;
+    /// use truck_platform::Camera;
+    /// use truck_rendimpl::{PreparedWireFrame, ScreenSpaceDivision};
+    ///
+    /// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    /// let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    /// let curves = |solid: &Solid| solid.edge_iter().map(|edge| edge.curve()).collect::<Vec<_>>();
+    /// let eye = Point3::new(0.0, 0.0, 5.0);
+    /// let matrix = Matrix4::look_at_rh(eye, Point3::origin(), Vector3::unit_y());
+    /// let camera =
+    ///     Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 100.0);
+    /// let division = ScreenSpaceDivision::new(camera, (800, 600));
+    ///
+    /// // The lines are not divided, and the circles are round.
+    /// let lines = PreparedWireFrame::from_curves(&curves(&cube), &division);
+    /// assert_eq!(lines.index_count(), 12 * 4 * 2);
+    /// let circles = PreparedWireFrame::from_curves(&curves(&cylinder), &division);
+    /// assert!(circles.index_count() > 12 * 4 * 2);
+    /// ```
+    pub fn from_curves<'a, C>(
+        curves: impl IntoIterator<Item = &'a C>,
+        division: &ScreenSpaceDivision,
+    ) -> Self
+    where
+        C: ParametricCurve3D + BoundedCurve + 'a,
+    {
+        Self::from(division.polylines(curves).as_slice())
+    }
+}
+
+impl ToInstance<WireFrameInstance> for PreparedWireFrame {
     type State = WireFrameState;
     fn to_instance(
         &self,
@@ -209,53 +478,71 @@ impl ToInstance<WireFrameInstance> for PolylineCurve<Point3> {
         state: &WireFrameState,
     ) -> WireFrameInstance {
         let device = handler.device();
-        let positions: Vec<[f32; 3]> = self.iter().map(|p| p.cast().unwrap().into()).collect();
-        let strips: Vec<u32> = (1..positions.len())
-            .flat_map(|i| vec![i as u32 - 1, i as u32])
-            .collect();
-        let vb = BufferHandler::from_slice(&positions, device, BufferUsages::VERTEX);
-        let ib = BufferHandler::from_slice(&strips, device, BufferUsages::INDEX);
+        let vb = BufferHandler::from_slice(&self.vertices, device, BufferUsages::VERTEX);
+        let ib = BufferHandler::from_slice(&self.strips, device, BufferUsages::INDEX);
         WireFrameInstance {
             vertices: Arc::new(vb),
             strips: Arc::new(ib),
             state: state.clone(),
             shaders: shaders.clone(),
+            line_type: LineType::Continuous,
+            depth_bias: 1.0e-4,
+            uniforms: Arc::clone(handler.object_uniforms()),
+            visible: true,
+            layers: 1,
             id: RenderID::gen(),
         }
     }
 }
 
+impl ToInstance<WireFrameInstance> for PolygonMesh {
+    type State = WireFrameState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &WireShaders,
+        state: &WireFrameState,
+    ) -> WireFrameInstance {
+        PreparedWireFrame::from(self).to_instance(handler, shaders, state)
+    }
+}
+
+impl ToInstance<WireFrameInstance> for StructuredMesh {
+    type State = WireFrameState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &WireShaders,
+        state: &WireFrameState,
+    ) -> WireFrameInstance {
+        PreparedWireFrame::from(self).to_instance(handler, shaders, state)
+    }
+}
+
+impl ToInstance<WireFrameInstance> for PolylineCurve<Point3> {
+    type State = WireFrameState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &WireShaders,
+        state: &WireFrameState,
+    ) -> WireFrameInstance {
+        PreparedWireFrame::from(self).to_instance(handler, shaders, state)
+    }
+}
+
 impl ToInstance<WireFrameInstance> for Vec<PolylineCurve<Point3>> {
     type State = WireFrameState;
+    #[inline(always)]
     fn to_instance(
         &self,
         handler: &DeviceHandler,
         shaders: &WireShaders,
         state: &WireFrameState,
     ) -> WireFrameInstance {
-        let device = handler.device();
-        let positions: Vec<[f32; 3]> = self
-            .iter()
-            .flat_map(|poly| poly.iter())
-            .map(|p| p.cast().unwrap().into())
-            .collect();
-        let mut counter = 0;
-        let strips: Vec<u32> = self
-            .iter()
-            .flat_map(|poly| {
-                let len = counter as u32;
-                counter += poly.len();
-                (1..poly.len()).flat_map(move |i| vec![len + i as u32 - 1, len + i as u32])
-            })
-            .collect();
-        let vb = BufferHandler::from_slice(&positions, device, BufferUsages::VERTEX);
-        let ib = BufferHandler::from_slice(&strips, device, BufferUsages::INDEX);
-        WireFrameInstance {
-            vertices: Arc::new(vb),
-            strips: Arc::new(ib),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
-        }
+        PreparedWireFrame::from(self.as_slice()).to_instance(handler, shaders, state)
     }
 }
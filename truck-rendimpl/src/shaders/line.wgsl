@@ -1,5 +1,5 @@
 struct Camera {
-    _matrix: mat4x4<f32>,
+    camera_matrix: mat4x4<f32>,
     projection: mat4x4<f32>,
 }
 
@@ -7,30 +7,109 @@ struct Camera {
 @binding(0)
 var<uniform> camera: Camera;
 
-struct ModelMatrix {
+// line_type: 0 = continuous, 1 = dashed, 2 = center, 3 = hidden
+struct LineStyle {
+    pattern: vec4<f32>,
+    line_type: u32,
+    depth_bias: f32,
+}
+
+struct WireUniform {
     model_matrix: mat4x4<f32>,
+    color: vec4<f32>,
+    line_style: LineStyle,
 }
 
 @group(1)
 @binding(0)
-var<uniform> model_matrix: ModelMatrix;
+var<uniform> model: WireUniform;
 
-struct Color {
-    color: vec4<f32>,
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) local_position: vec3<f32>,
+    // the position and the length of the first vertex of the segment
+    @location(1) @interpolate(flat) segment_start: vec4<f32>,
 }
 
-@group(1)
-@binding(1)
-var<uniform> color: Color;
+@vertex
+fn vs_main(
+    @location(0) position: vec3<f32>,
+    @location(1) arc_length: f32,
+) -> VertexOutput {
+    var res: vec4<f32> = camera.projection * model.model_matrix * vec4<f32>(position, 1.0);
+    res.z = res.z - model.line_style.depth_bias;
+    return VertexOutput(res, position, vec4<f32>(position, arc_length));
+}
+
+// Returns true if the point at the length along the line is in a gap of the pattern.
+fn in_gap(in: VertexOutput) -> bool {
+    let length = in.segment_start.w + distance(in.local_position, in.segment_start.xyz);
+    let pattern = model.line_style.pattern;
+    switch model.line_style.line_type {
+        case 1u, 3u: {
+            let t = length % (pattern.x + pattern.y);
+            return t > pattern.x;
+        }
+        case 2u: {
+            let t = length % (pattern.x + pattern.y + 2.0 * pattern.z);
+            let short_start = pattern.x + pattern.z;
+            return t > pattern.x && (t < short_start || t > short_start + pattern.y);
+        }
+        default: {
+            return false;
+        }
+    }
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if in_gap(in) {
+        discard;
+    }
+    return vec4<f32>(pow(model.color.rgb, vec3<f32>(0.4545)), model.color.a);
+}
+
+struct ObjectId {
+    id: u32,
+}
+
+@group(2)
+@binding(0)
+var<uniform> object_id: ObjectId;
+
+struct GBufferVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) world_position: vec3<f32>,
+    @location(1) local_position: vec3<f32>,
+    @location(2) @interpolate(flat) segment_start: vec4<f32>,
+}
+
+struct GBufferOutput {
+    @location(0) normal: vec4<f32>,
+    @location(1) depth: f32,
+    @location(2) object_id: u32,
+}
 
 @vertex
-fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
-    var res: vec4<f32> = camera.projection * model_matrix.model_matrix * vec4<f32>(position, 1.0);
-    res.z = res.z - 1.0e-4;
-    return res;
+fn gbuffer_vs_main(
+    @location(0) position: vec3<f32>,
+    @location(1) arc_length: f32,
+) -> GBufferVertexOutput {
+    let world_position = model.model_matrix * vec4<f32>(position, 1.0);
+    var res: vec4<f32> = camera.projection * world_position;
+    res.z = res.z - model.line_style.depth_bias;
+    let segment_start = vec4<f32>(position, arc_length);
+    return GBufferVertexOutput(res, world_position.xyz, position, segment_start);
 }
 
+// The normals of the lines are zero vectors.
 @fragment
-fn fs_main() -> @location(0) vec4<f32> {
-    return vec4<f32>(pow(color.color.rgb, vec3<f32>(0.4545)), color.color.a);
+fn gbuffer_fs_main(in: GBufferVertexOutput) -> GBufferOutput {
+    if in_gap(VertexOutput(in.position, in.local_position, in.segment_start)) {
+        discard;
+    }
+    let camera_position = camera.camera_matrix[3].xyz;
+    let camera_direction = -normalize(camera.camera_matrix[2].xyz);
+    let depth = dot(in.world_position - camera_position, camera_direction);
+    return GBufferOutput(vec4<f32>(0.0, 0.0, 0.0, 1.0), depth, object_id.id);
 }
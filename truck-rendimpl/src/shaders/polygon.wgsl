@@ -4,6 +4,15 @@ struct VertexInput {
     @location(2) normal: vec3<f32>, 
 }
 
+// the vertex of the meshes of faces
+struct FaceVertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) normal: vec3<f32>, 
+    @location(3) parameter_range: vec4<f32>,
+    @location(4) trimmed: u32,
+}
+
 struct Camera {
     camera_matrix: mat4x4<f32>,
     projection: mat4x4<f32>,
@@ -31,28 +40,21 @@ struct SceneInfo {
 @binding(2)
 var<uniform> info: SceneInfo;
 
-struct ModelMatrix {
+struct ModelUniform {
     model_matrix: mat4x4<f32>,
+    material: Material,
 }
 
 @group(1)
 @binding(0)
-var<uniform> model_matrix: ModelMatrix;
-
-struct ModelMaterial {
-    material: Material,
-}
+var<uniform> model: ModelUniform;
 
 @group(1)
 @binding(1)
-var<uniform> material: ModelMaterial;
-
-@group(1)
-@binding(2)
 var r_color: texture_2d<f32>;
 
 @group(1)
-@binding(3)
+@binding(2)
 var r_sampler: sampler;
 
 struct VertexOutput {
@@ -60,20 +62,33 @@ struct VertexOutput {
     @location(0) position: vec3<f32>,
     @location(1) uv: vec2<f32>,
     @location(2) normal: vec3<f32>, 
+    @location(3) parameter_range: vec4<f32>,
+    @location(4) @interpolate(flat) trimmed: u32,
 }
 
-@vertex
-fn vs_main(in: VertexInput) -> VertexOutput {
-    let world_position = model_matrix.model_matrix * vec4<f32>(in.position, 1.0);
-    let world_normal = model_matrix.model_matrix * vec4<f32>(in.normal, 0.0);
+fn vertex_output(in: FaceVertexInput) -> VertexOutput {
+    let world_position = model.model_matrix * vec4<f32>(in.position, 1.0);
+    let world_normal = model.model_matrix * vec4<f32>(in.normal, 0.0);
     return VertexOutput(
         camera.projection * world_position,
         world_position.xyz,
         in.uv,
-        normalize(world_normal.xyz)
+        normalize(world_normal.xyz),
+        in.parameter_range,
+        in.trimmed
     );
 }
 
+// The plain polygons are not trimmed in the unit square.
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    let range = vec4<f32>(0.0, 1.0, 0.0, 1.0);
+    return vertex_output(FaceVertexInput(in.position, in.uv, in.normal, range, 0u));
+}
+
+@vertex
+fn vs_face_main(in: FaceVertexInput) -> VertexOutput { return vertex_output(in); }
+
 const e: vec2<f32> = vec2<f32>(1.0, 0.0);
 
 @fragment
@@ -87,19 +102,19 @@ fn nontex_main(in: VertexInput) -> @location(0) vec4<f32> {
             normal,
             lights.lights[i],
             camera_dir,
-            material.material,
+            model.material,
         );
     }
     pre_color = clamp(pre_color, vec3<f32>(0.0), vec3<f32>(1.0));
-    pre_color = background_correction(pre_color, info.bk_color.xyz, material.material);
-    pre_color = ambient_correction(pre_color, material.material);
+    pre_color = background_correction(pre_color, info.bk_color.xyz, model.material);
+    pre_color = ambient_correction(pre_color, model.material);
 
-    return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), material.material.albedo.a);
+    return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), model.material.albedo.a);
 }
 
 @fragment
 fn tex_main(in: VertexInput) -> @location(0) vec4<f32> {
-    var matr: Material = material.material;
+    var matr: Material = model.material;
     matr.albedo = textureSample(r_color, r_sampler, in.uv);
     matr.albedo = vec4<f32>(pow(matr.albedo.rgb, vec3<f32>(2.2)), matr.albedo.a);
     let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
@@ -115,8 +130,78 @@ fn tex_main(in: VertexInput) -> @location(0) vec4<f32> {
         );
     }
     pre_color = clamp(pre_color, vec3<f32>(0.0), vec3<f32>(1.0));
-    pre_color = background_correction(pre_color, info.bk_color.xyz, material.material);
+    pre_color = background_correction(pre_color, info.bk_color.xyz, model.material);
     pre_color = ambient_correction(pre_color, matr);
 
     return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), matr.albedo.a);
 }
+
+const ZEBRA_STRIPES: f32 = 12.0;
+
+@fragment
+fn zebra_main(in: VertexInput) -> @location(0) vec4<f32> {
+    let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
+    let normal = normalize(in.normal);
+    let reflected = reflect(-camera_dir, normal);
+    // the light tubes are parallel to the horizontal axis of the camera
+    let up = normalize(camera.camera_matrix[1].xyz);
+    let phase = asin(clamp(dot(reflected, up), -1.0, 1.0)) * ZEBRA_STRIPES / 3.14159265;
+    let stripe = step(0.5, fract(phase));
+    let color = model.material.albedo.rgb * mix(0.05, 1.0, stripe);
+    return vec4<f32>(pow(color, vec3<f32>(0.4545)), model.material.albedo.a);
+}
+
+const ISOLINES: f32 = 10.0;
+
+@fragment
+fn isoline_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
+    let normal = normalize(in.normal);
+    var pre_color: vec3<f32> = vec3<f32>(0.0);
+    for (var i: u32 = 0u; i < info.nlights; i = i + 1u) {
+        pre_color = pre_color + microfacet_color(
+            in.position,
+            normal,
+            lights.lights[i],
+            camera_dir,
+            model.material,
+        );
+    }
+    pre_color = clamp(pre_color, vec3<f32>(0.0), vec3<f32>(1.0));
+    pre_color = background_correction(pre_color, info.bk_color.xyz, model.material);
+    pre_color = ambient_correction(pre_color, model.material);
+
+    // the parameter normalized in the range of the face
+    let range = in.parameter_range;
+    let size = max(vec2<f32>(range.y - range.x, range.w - range.z), vec2<f32>(1.0e-6));
+    let parameter = (in.uv - range.xz) / size * ISOLINES;
+    let width = max(fwidth(parameter), vec2<f32>(1.0e-6));
+    let grid = abs(fract(parameter - 0.5) - 0.5) / width;
+    let line = 1.0 - min(min(grid.x, grid.y), 1.0);
+    // the isolines of the trimmed faces are red
+    let line_color = select(vec3<f32>(0.0), vec3<f32>(0.8, 0.0, 0.0), in.trimmed != 0u);
+    let color = mix(pre_color, line_color, line);
+    return vec4<f32>(pow(color, vec3<f32>(0.4545)), model.material.albedo.a);
+}
+
+struct ObjectId {
+    id: u32,
+}
+
+@group(2)
+@binding(0)
+var<uniform> object_id: ObjectId;
+
+struct GBufferOutput {
+    @location(0) normal: vec4<f32>,
+    @location(1) depth: f32,
+    @location(2) object_id: u32,
+}
+
+@fragment
+fn gbuffer_main(in: VertexInput) -> GBufferOutput {
+    let camera_position = (camera.camera_matrix * e.yyyx).xyz;
+    let camera_direction = -normalize(camera.camera_matrix[2].xyz);
+    let depth = dot(in.position - camera_position, camera_direction);
+    return GBufferOutput(vec4<f32>(normalize(in.normal), 1.0), depth, object_id.id);
+}
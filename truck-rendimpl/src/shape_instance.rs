@@ -0,0 +1,25 @@
+use crate::*;
+
+impl ShapeInstance {
+    /// Returns the polygon instance of the faces.
+    #[inline(always)]
+    pub const fn polygon(&self) -> &PolygonInstance { &self.polygon }
+    /// Returns the mutable reference to the polygon instance of the faces.
+    #[inline(always)]
+    pub fn polygon_mut(&mut self) -> &mut PolygonInstance { &mut self.polygon }
+    /// Returns the wireframe instance of the isolines if they are overlaid.
+    #[inline(always)]
+    pub fn isolines(&self) -> Option<&WireFrameInstance> { self.isolines.as_ref() }
+    /// Returns the mutable reference to the wireframe instance of the isolines.
+    #[inline(always)]
+    pub fn isolines_mut(&mut self) -> Option<&mut WireFrameInstance> { self.isolines.as_mut() }
+    /// Sets the matrices of both the faces and the isolines.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group` of both instances.
+    pub fn set_matrix(&mut self, matrix: Matrix4) {
+        self.polygon.instance_state_mut().matrix = matrix;
+        if let Some(isolines) = &mut self.isolines {
+            isolines.instance_state_mut().matrix = matrix;
+        }
+    }
+}
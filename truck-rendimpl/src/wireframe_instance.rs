@@ -10,6 +10,58 @@ impl Default for WireFrameState {
     }
 }
 
+/// the ids of the line types in `line.wgsl`
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ShaderLineType {
+    Continuous = 0,
+    Dashed = 1,
+    Center = 2,
+    Hidden = 3,
+}
+
+impl LineType {
+    /// the id of the line type in the shader and the lengths of the pattern
+    fn shader_data(self) -> (ShaderLineType, [f64; 3]) {
+        match self {
+            LineType::Continuous => (ShaderLineType::Continuous, [0.0; 3]),
+            LineType::Dashed { dash, gap } => (ShaderLineType::Dashed, [dash, gap, 0.0]),
+            LineType::Center { long, short, gap } => (ShaderLineType::Center, [long, short, gap]),
+            LineType::Hidden { dash, gap } => (ShaderLineType::Hidden, [dash, gap, 0.0]),
+        }
+    }
+}
+
+/// the variants of the pipelines of wireframes in the pipeline cache
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PipelineVariant {
+    /// the lines drawn in front of the other objects
+    Standard,
+    /// the lines written in the G-buffer
+    GBuffer,
+    /// the hidden lines drawn behind the other objects
+    Hidden,
+}
+
+/// the uniform of the line style in the shader
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct LineStyle {
+    pattern: [f32; 4],
+    line_type: u32,
+    depth_bias: f32,
+    padding: [u32; 2],
+}
+
+/// the data of a wireframe instance in its slot of the uniform ring
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct WireFrameUniform {
+    matrix: [[f32; 4]; 4],
+    color: [f32; 4],
+    style: LineStyle,
+}
+
 impl WireFrameInstance {
     /// Clone the instance as another drawn element.
     #[inline(always)]
@@ -19,6 +71,11 @@ impl WireFrameInstance {
             strips: Arc::clone(&self.strips),
             state: self.state.clone(),
             shaders: self.shaders.clone(),
+            line_type: self.line_type,
+            depth_bias: self.depth_bias,
+            uniforms: Arc::clone(&self.uniforms),
+            visible: self.visible,
+            layers: self.layers,
             id: RenderID::gen(),
         }
     }
@@ -28,12 +85,80 @@ impl WireFrameInstance {
     /// Returns the mutable reference to wireframe state
     #[inline(always)]
     pub fn instance_state_mut(&mut self) -> &mut WireFrameState { &mut self.state }
+    /// Returns the shaders of the instance.
+    #[inline(always)]
+    pub const fn shaders(&self) -> &WireShaders { &self.shaders }
+    /// Replaces the shaders, e.g. by the reloaded ones.
+    ///
+    /// The change is reflected in the scene by `Scene::update_pipeline`.
+    #[inline(always)]
+    pub fn set_shaders(&mut self, shaders: WireShaders) { self.shaders = shaders }
+    /// Returns the line type. Default is `LineType::Continuous`.
+    #[inline(always)]
+    pub const fn line_type(&self) -> LineType { self.line_type }
+    /// Sets the line type.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group` and,
+    /// if the line becomes or ceases to be `LineType::Hidden`, by `Scene::update_pipeline`.
+    #[inline(always)]
+    pub fn set_line_type(&mut self, line_type: LineType) { self.line_type = line_type }
+    /// Returns the offset subtracted from the depth in the clip coordinates, so that the lines
+    /// are drawn over the faces on them. Default is `1.0e-4`.
+    #[inline(always)]
+    pub const fn depth_bias(&self) -> f64 { self.depth_bias }
+    /// Sets the offset subtracted from the depth in the clip coordinates.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn set_depth_bias(&mut self, depth_bias: f64) { self.depth_bias = depth_bias }
+    /// Returns whether the instance is drawn. Default is `true`.
+    #[inline(always)]
+    pub const fn visible(&self) -> bool { self.visible }
+    /// Sets whether the instance is drawn, e.g. in order to hide the construction geometries.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn set_visible(&mut self, visible: bool) { self.visible = visible }
+    /// Returns the bit mask of the layers which the instance belongs to. Default is `1`.
+    ///
+    /// The instance is drawn only if one of its layers is visible in the scene.
+    #[inline(always)]
+    pub const fn layers(&self) -> u32 { self.layers }
+    /// Sets the bit mask of the layers which the instance belongs to.
+    ///
+    /// The change is reflected in the scene by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn set_layers(&mut self, layers: u32) { self.layers = layers }
     /// swap vertex buffers and index buffers
     #[inline(always)]
     pub fn swap_vertex(&mut self, other: &mut WireFrameInstance) {
         std::mem::swap(&mut self.vertices, &mut other.vertices);
         std::mem::swap(&mut self.strips, &mut other.strips);
     }
+
+    /// the matrix, the color and the line style written to the slot
+    fn uniform(&self) -> WireFrameUniform {
+        let (line_type, [a, b, c]) = self.line_type.shader_data();
+        let style = LineStyle {
+            pattern: [a as f32, b as f32, c as f32, 0.0],
+            line_type: line_type as u32,
+            depth_bias: self.depth_bias as f32,
+            padding: [0; 2],
+        };
+        WireFrameUniform {
+            matrix: self.state.matrix.cast::<f32>().unwrap().into(),
+            color: self.state.color.cast::<f32>().unwrap().into(),
+            style,
+        }
+    }
+
+    /// the entries of the bind group layout
+    fn bgl_entries(&self) -> [PreBindGroupLayoutEntry; 1] { [self.uniforms.bgl_entry()] }
+}
+
+impl Drop for WireFrameInstance {
+    #[inline(always)]
+    fn drop(&mut self) { self.uniforms.remove(self.id); }
 }
 
 impl Instance for WireFrameInstance {
@@ -46,48 +171,20 @@ impl Rendered for WireFrameInstance {
     fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
         (self.vertices.clone(), Some(self.strips.clone()))
     }
-    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
-        Arc::new(bind_group_util::create_bind_group_layout(
-            handler.device(),
-            &[
-                // matrix
-                PreBindGroupLayoutEntry {
-                    visibility: ShaderStages::VERTEX,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
-                // color
-                PreBindGroupLayoutEntry {
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
-            ],
-        ))
+    fn bind_group_layout(&self, _: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::clone(self.uniforms.layout())
     }
-    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
-        let device = handler.device();
-        let matrix_data: [[f32; 4]; 4] = self.state.matrix.cast::<f32>().unwrap().into();
-        let matrix_buffer = BufferHandler::from_slice(&matrix_data, device, BufferUsages::UNIFORM);
-        let color_data: [f32; 4] = self.state.color.cast::<f32>().unwrap().into();
-        let color_buffer = BufferHandler::from_slice(&color_data, device, BufferUsages::UNIFORM);
-        Arc::new(bind_group_util::create_bind_group(
-            device,
-            layout,
-            vec![
-                matrix_buffer.binding_resource(),
-                color_buffer.binding_resource(),
-            ],
-        ))
+    fn bind_group(&self, handler: &DeviceHandler, _: &BindGroupLayout) -> Arc<BindGroup> {
+        let (bind_group, _) = self.uniforms.write(handler, self.id, &self.uniform());
+        bind_group
+    }
+    fn dynamic_offsets(&self) -> Vec<u32> {
+        let slot = self.uniforms.slot(self.id);
+        vec![slot.map_or(0, |(_, offset)| offset)]
     }
+    fn visible(&self) -> bool { self.visible }
+    fn layers(&self) -> u32 { self.layers }
+    fn primitive_topology(&self) -> PrimitiveTopology { PrimitiveTopology::LineList }
     fn pipeline(
         &self,
         handler: &DeviceHandler,
@@ -96,76 +193,160 @@ impl Rendered for WireFrameInstance {
     ) -> Arc<RenderPipeline> {
         let device = handler.device();
         let sample_count = scene_desc.backend_buffer.sample_count;
-        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
-            layout: Some(layout),
-            vertex: VertexState {
-                module: &self.shaders.vertex_module,
-                entry_point: self.shaders.vertex_entry,
-                buffers: &[VertexBufferLayout {
-                    array_stride: std::mem::size_of::<[f32; 3]>() as BufferAddress,
-                    step_mode: VertexStepMode::Vertex,
-                    attributes: &[VertexAttribute {
-                        format: VertexFormat::Float32x3,
-                        offset: 0,
-                        shader_location: 0,
-                    }],
-                }],
-            },
-            fragment: Some(FragmentState {
-                module: &self.shaders.fragment_module,
-                entry_point: self.shaders.fragment_entry,
-                targets: &[Some(ColorTargetState {
-                    format: scene_desc.render_texture.format,
-                    blend: Some(BlendState::REPLACE),
-                    write_mask: ColorWrites::ALL,
-                })],
-            }),
-            primitive: PrimitiveState {
-                topology: PrimitiveTopology::LineList,
-                ..Default::default()
-            },
-            depth_stencil: Some(DepthStencilState {
-                format: TextureFormat::Depth32Float,
-                depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::Less,
-                stencil: Default::default(),
-                bias: Default::default(),
-            }),
-            multisample: MultisampleState {
-                count: sample_count,
-                mask: !0,
-                alpha_to_coverage_enabled: sample_count > 1,
-            },
-            label: None,
-            multiview: None,
+        let shaders = [
+            (&self.shaders.vertex_module, self.shaders.vertex_entry),
+            (&self.shaders.fragment_module, self.shaders.fragment_entry),
+        ];
+        let variant = match self.line_type {
+            LineType::Hidden { .. } => PipelineVariant::Hidden,
+            _ => PipelineVariant::Standard,
+        };
+        let key = PipelineKey::new(&shaders, &self.bgl_entries(), variant as u64, scene_desc);
+        // The hidden lines are drawn behind the other objects without writing the depth.
+        let (depth_write_enabled, depth_compare) = match variant {
+            PipelineVariant::Hidden => (false, CompareFunction::Greater),
+            _ => (true, CompareFunction::Less),
+        };
+        let create = || {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                layout: Some(layout),
+                vertex: VertexState {
+                    module: &self.shaders.vertex_module,
+                    entry_point: self.shaders.vertex_entry,
+                    buffers: &[VERTEX_BUFFER_LAYOUT],
+                },
+                fragment: Some(FragmentState {
+                    module: &self.shaders.fragment_module,
+                    entry_point: self.shaders.fragment_entry,
+                    targets: &[Some(ColorTargetState {
+                        format: scene_desc.render_texture.format,
+                        blend: Some(BlendState::REPLACE),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                primitive: PrimitiveState {
+                    topology: PrimitiveTopology::LineList,
+                    ..Default::default()
+                },
+                depth_stencil: Some(DepthStencilState {
+                    format: TextureFormat::Depth32Float,
+                    depth_write_enabled,
+                    depth_compare,
+                    stencil: Default::default(),
+                    bias: Default::default(),
+                }),
+                multisample: MultisampleState {
+                    count: sample_count,
+                    mask: !0,
+                    alpha_to_coverage_enabled: sample_count > 1,
+                },
+                label: None,
+                multiview: None,
+            })
+        };
+        handler.pipeline_cache().get_or_create(key, create)
+    }
+    fn gbuffer_pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        if matches!(self.line_type, LineType::Hidden { .. }) {
+            return None;
+        }
+        let device = handler.device();
+        let (module, vertex_entry, fragment_entry) = self.shaders.gbuffer_shaders.as_ref()?;
+        let shaders = [(module, *vertex_entry), (module, *fragment_entry)];
+        let variant = PipelineVariant::GBuffer as u64;
+        let key = PipelineKey::new(&shaders, &self.bgl_entries(), variant, scene_desc);
+        let create = || {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                layout: Some(layout),
+                vertex: VertexState {
+                    module,
+                    entry_point: vertex_entry,
+                    buffers: &[VERTEX_BUFFER_LAYOUT],
+                },
+                fragment: Some(FragmentState {
+                    module,
+                    entry_point: fragment_entry,
+                    targets: &GBuffer::color_targets(),
+                }),
+                primitive: PrimitiveState {
+                    topology: PrimitiveTopology::LineList,
+                    ..Default::default()
+                },
+                depth_stencil: Some(GBuffer::depth_stencil_state()),
+                multisample: Default::default(),
+                label: None,
+                multiview: None,
+            })
+        };
+        Some(handler.pipeline_cache().get_or_create(key, create))
+    }
+}
+
+/// The vertex is the position and the length along the polyline from its start.
+const VERTEX_BUFFER_LAYOUT: VertexBufferLayout<'static> = VertexBufferLayout {
+    array_stride: std::mem::size_of::<[f32; 4]>() as BufferAddress,
+    step_mode: VertexStepMode::Vertex,
+    attributes: &[
+        VertexAttribute {
+            format: VertexFormat::Float32x3,
+            offset: 0,
+            shader_location: 0,
+        },
+        VertexAttribute {
+            format: VertexFormat::Float32,
+            offset: 3 * 4,
+            shader_location: 1,
+        },
+    ],
+};
+
+/// Creates the vertices of wireframes: the positions with the lengths along the polylines.
+pub(super) fn wire_vertices(positions: &[Point3], lengths: impl Fn(usize) -> f64) -> Vec<[f32; 4]> {
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| [p.x as f32, p.y as f32, p.z as f32, lengths(i) as f32])
+        .collect()
+}
+
+/// the lengths along the polyline from its start
+pub(super) fn polyline_lengths(points: &[Point3]) -> Vec<f64> {
+    let mut length = 0.0;
+    let mut lengths = vec![0.0];
+    points.windows(2).for_each(|w| {
+        length += w[0].distance(w[1]);
+        lengths.push(length);
+    });
+    lengths.truncate(points.len());
+    lengths
+}
+
+impl From<&[(Point3, Point3)]> for PreparedWireFrame {
+    fn from(lines: &[(Point3, Point3)]) -> Self {
+        let points: Vec<Point3> = lines.iter().flat_map(|p| vec![p.0, p.1]).collect();
+        let vertices = wire_vertices(&points, |i| match i % 2 {
+            0 => 0.0,
+            _ => points[i - 1].distance(points[i]),
         });
-        Arc::new(pipeline)
+        let strips: Vec<u32> = (0..2 * lines.len()).map(|i| i as u32).collect();
+        Self { vertices, strips }
     }
 }
 
 impl ToInstance<WireFrameInstance> for Vec<(Point3, Point3)> {
     type State = WireFrameState;
+    #[inline(always)]
     fn to_instance(
         &self,
         handler: &DeviceHandler,
         shaders: &WireShaders,
         state: &WireFrameState,
     ) -> WireFrameInstance {
-        let device = handler.device();
-        let positions: Vec<[f32; 3]> = self
-            .iter()
-            .flat_map(|p| vec![p.0, p.1])
-            .map(|p| p.cast().unwrap().into())
-            .collect();
-        let strips: Vec<u32> = (0..2 * self.len()).map(|i| i as u32).collect();
-        let vb = BufferHandler::from_slice(&positions, device, BufferUsages::VERTEX);
-        let ib = BufferHandler::from_slice(&strips, device, BufferUsages::INDEX);
-        WireFrameInstance {
-            vertices: Arc::new(vb),
-            strips: Arc::new(ib),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
-        }
+        PreparedWireFrame::from(self.as_slice()).to_instance(handler, shaders, state)
     }
 }
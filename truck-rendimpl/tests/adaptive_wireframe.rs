@@ -0,0 +1,31 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+use truck_platform::Camera;
+use truck_rendimpl::*;
+
+fn division(distance: f64) -> ScreenSpaceDivision {
+    let eye = Point3::new(0.0, 0.0, distance);
+    let matrix = Matrix4::look_at_rh(eye, Point3::origin(), Vector3::unit_y());
+    let camera = Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 100.0);
+    ScreenSpaceDivision::new(camera, (800, 600))
+}
+
+#[test]
+fn edges_are_divided_by_zoom() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    let curves: Vec<Curve> = cylinder.edge_iter().map(|edge| edge.curve()).collect();
+    let near = PreparedWireFrame::from_curves(&curves, &division(3.0));
+    let far = PreparedWireFrame::from_curves(&curves, &division(50.0));
+    assert!(near.index_count() > far.index_count());
+    // each curve is one strip
+    let polylines = division(3.0).polylines(&curves);
+    assert_eq!(
+        near.vertex_count(),
+        polylines.iter().map(|p| p.len()).sum::<usize>()
+    );
+    curves.iter().zip(&polylines).for_each(|(curve, polyline)| {
+        let (t0, t1) = curve.parameter_range();
+        assert_near!(polyline[0], curve.subs(t0));
+        assert_near!(polyline[polyline.len() - 1], curve.subs(t1));
+    });
+}
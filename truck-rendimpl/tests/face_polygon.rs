@@ -0,0 +1,43 @@
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_rendimpl::*;
+
+#[test]
+fn trimmed_faces_of_cylinder() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::unit_z(), 1.0);
+    let meshed = cylinder.triangulation(0.01);
+    let faces: Vec<FacePolygon> = meshed
+        .to_face_polygons()
+        .into_iter()
+        .map(FacePolygon::from)
+        .collect();
+    let (disks, sides) = (
+        [&faces[0], &faces[faces.len() - 1]],
+        &faces[1..faces.len() - 1],
+    );
+    // The disks are trimmed by the circles, and the sides are the whole surfaces.
+    assert!(disks.iter().all(|face| face.trimmed));
+    assert!(!sides.is_empty());
+    assert!(sides.iter().all(|face| !face.trimmed));
+}
+
+#[test]
+fn shape_is_prepared_with_faces() {
+    let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let preferences = MeshingPreferences::new(MeshingDensity::coarse());
+    let prepared = PreparedPolygon::from_shape(&cube, &preferences);
+    assert!(prepared.has_face_vertices());
+    let polygon = cube.preferred_triangulation(&preferences).to_polygon();
+    assert_eq!(
+        prepared.index_count(),
+        3 * polygon.faces().triangle_iter().count()
+    );
+
+    // The plain polygon has no information of the faces.
+    let prepared = PreparedPolygon::from(&polygon);
+    assert!(!prepared.has_face_vertices());
+    assert_eq!(
+        prepared.index_count(),
+        3 * polygon.faces().triangle_iter().count()
+    );
+}
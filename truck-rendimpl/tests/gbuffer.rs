@@ -0,0 +1,119 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_modeling::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const CUBE_OBJ: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../resources/obj/cube.obj",
+));
+
+const PICTURE_SIZE: (u32, u32) = (256, 192);
+
+fn exec_gbuffer_test(backend: Backends, _: &str) {
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        dx12_shader_compiler: Default::default(),
+    });
+    let handler = common::init_device(&instance);
+    let camera_position = Point3::new(-1.0, 2.5, 2.0);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            studio: StudioConfig {
+                camera: Camera::perspective_camera(
+                    Matrix4::look_at_rh(
+                        camera_position,
+                        Point3::new(0.5, 0.5, 0.5),
+                        Vector3::unit_y(),
+                    )
+                    .invert()
+                    .unwrap(),
+                    Rad(std::f64::consts::PI / 4.0),
+                    0.1,
+                    100.0,
+                ),
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let mut cube: PolygonInstance =
+        creator.create_instance(&obj::read(CUBE_OBJ).unwrap(), &Default::default());
+    scene.add_object(&cube);
+
+    let gbuffer = scene.create_gbuffer();
+    scene.render_gbuffer(&gbuffer);
+    let handler = scene.device_handler();
+    let ids = pollster::block_on(gbuffer.read_object_ids(handler));
+    let normals = pollster::block_on(gbuffer.read_normals(handler));
+    let depths = pollster::block_on(gbuffer.read_depths(handler));
+    let len = (PICTURE_SIZE.0 * PICTURE_SIZE.1) as usize;
+    assert_eq!((ids.len(), normals.len(), depths.len()), (len, len, len));
+
+    // the cube is placed on the center of the picture.
+    let center = (PICTURE_SIZE.1 / 2 * PICTURE_SIZE.0 + PICTURE_SIZE.0 / 2) as usize;
+    assert_eq!(ids[center], Some(cube.render_id()));
+    assert_eq!(ids[0], None);
+    let iter = ids.iter().zip(&normals).zip(&depths);
+    iter.for_each(|((id, normal), depth)| match id {
+        Some(_) => {
+            assert_eq!(normal[3], 1.0);
+            let normal = Vector3::new(normal[0] as f64, normal[1] as f64, normal[2] as f64);
+            assert!(f64::abs(normal.magnitude() - 1.0) < 1.0e-3);
+            // between the near and the far clipping planes
+            assert!(0.1 < *depth && *depth < 100.0);
+        }
+        None => {
+            assert_eq!(normal[3], 0.0);
+            assert_eq!(*depth, f32::MAX);
+        }
+    });
+
+    let (x, y) = (PICTURE_SIZE.0 / 2, PICTURE_SIZE.1 / 2);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), Some(cube.render_id()));
+    assert_eq!(pollster::block_on(scene.pick(0, 0)), None);
+    assert_eq!(pollster::block_on(scene.pick(PICTURE_SIZE.0, 0)), None);
+    scene.set_visibility(&cube, false);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), None);
+
+    // the visibility set by the scene is kept through the updates of the instance.
+    scene.update_bind_group(&cube);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), None);
+    scene.set_visibility(&cube, true);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), Some(cube.render_id()));
+    scene.set_layer_visibility(1, false);
+    assert_eq!(scene.visible_layers(), !1);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), None);
+    cube.set_layers(0b110);
+    scene.update_bind_group(&cube);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), Some(cube.render_id()));
+    cube.set_visible(false);
+    scene.update_bind_group(&cube);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), None);
+    cube.set_visible(true);
+    scene.update_bind_group(&cube);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), Some(cube.render_id()));
+
+    // the layers of the group precede the ones of the instance.
+    let group = scene.create_group([&cube]);
+    assert!(scene.set_group_layers(group, 1));
+    scene.update_bind_group(&cube);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), None);
+    assert!(scene.set_group_visibility(group, false));
+    scene.set_layer_visibility(1, true);
+    scene.update_bind_group(&cube);
+    assert_eq!(pollster::block_on(scene.pick(x, y)), None);
+    assert!(scene.set_group_visibility(group, true));
+    assert_eq!(pollster::block_on(scene.pick(x, y)), Some(cube.render_id()));
+}
+
+#[test]
+fn gbuffer_test() { common::os_alt_exec_test(exec_gbuffer_test) }
@@ -168,3 +168,103 @@ fn exec_tex_render_test(backend: Backends, out_dir: &str) {
 
 #[test]
 fn tex_render_test() { common::os_alt_exec_test(exec_tex_render_test) }
+
+fn exec_zebra_render_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let mesh = obj::read(CUBE_OBJ).unwrap();
+    let shifted = PolygonState {
+        matrix: Matrix4::from_translation(Vector3::new(-1.2, 0.0, 0.0)),
+        ..Default::default()
+    };
+    let standard: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    let shifted_standard: PolygonInstance = creator.create_instance(&mesh, &shifted);
+    // the zebra instances share the shader module with the standard ones.
+    let zebra = creator.create_zebra_instance(&mesh, &shifted);
+    let background = pollster::block_on(scene.render_to_buffer());
+    let buffer0 = common::render_one(&mut scene, &standard);
+    let buffer1 = common::render_one(&mut scene, &shifted_standard);
+    let buffer2 = common::render_one(&mut scene, &zebra);
+    scene.add_object(&standard);
+    scene.add_object(&zebra);
+    let buffer3 = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    let filename = out_dir.clone() + "zebra-polygon.png";
+    common::save_buffer(filename, &buffer2, PICTURE_SIZE);
+    let filename = out_dir + "zebra-and-standard.png";
+    common::save_buffer(filename, &buffer3, PICTURE_SIZE);
+
+    // the zebra instance is not drawn by the cached pipeline of the standard one.
+    let diff = common::count_difference(&buffer1, &buffer2);
+    println!("{diff} pixel difference: standard and zebra");
+    assert!(diff > 1000);
+    // each instance keeps its shading next to the other one.
+    let pixels = |buffer: &[u8]| buffer.chunks(4).map(<[u8]>::to_vec).collect::<Vec<_>>();
+    let (background, buffer0) = (pixels(&background), pixels(&buffer0));
+    let (buffer2, buffer3) = (pixels(&buffer2), pixels(&buffer3));
+    let zebra_region = (0..background.len())
+        .filter(|i| buffer0[*i] == background[*i] && buffer2[*i] != background[*i]);
+    let zebra_diff = zebra_region.filter(|i| buffer3[*i] != buffer2[*i]).count();
+    let standard_region = (0..background.len())
+        .filter(|i| buffer2[*i] == background[*i] && buffer0[*i] != background[*i]);
+    let standard_diff = standard_region.filter(|i| buffer3[*i] != buffer0[*i]);
+    let standard_diff = standard_diff.count();
+    println!("{zebra_diff} and {standard_diff} pixel differences: rendered together");
+    assert!(zebra_diff < 10 && standard_diff < 10);
+    assert_eq!(scene.device_handler().pipeline_cache().len(), 2);
+}
+
+#[test]
+fn zebra_render_test() { common::os_alt_exec_test(exec_zebra_render_test) }
+
+fn exec_line_type_render_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let mesh = obj::read(CUBE_OBJ).unwrap();
+    let polygon: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    let continuous: WireFrameInstance = creator.create_instance(&mesh, &Default::default());
+    let mut dashed = continuous.clone_instance();
+    dashed.set_line_type(LineType::Dashed {
+        dash: 0.05,
+        gap: 0.05,
+    });
+    let mut hidden = continuous.clone_instance();
+    hidden.set_line_type(LineType::Hidden {
+        dash: 0.05,
+        gap: 0.05,
+    });
+    let background = pollster::block_on(scene.render_to_buffer());
+    let buffer0 = common::render_one(&mut scene, &continuous);
+    let buffer1 = common::render_one(&mut scene, &dashed);
+    let buffer2 = common::render_one(&mut scene, &hidden);
+    let buffer3 = common::render_one(&mut scene, &polygon);
+    scene.add_object(&polygon);
+    scene.add_object(&hidden);
+    let buffer4 = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    let filename = out_dir.clone() + "dashed-wireframe.png";
+    common::save_buffer(filename, &buffer1, PICTURE_SIZE);
+    let filename = out_dir + "hidden-wireframe.png";
+    common::save_buffer(filename, &buffer4, PICTURE_SIZE);
+
+    // the gaps of the dashed lines are not drawn.
+    let continuous_pixels = common::count_difference(&background, &buffer0);
+    let dashed_pixels = common::count_difference(&background, &buffer1);
+    println!("{continuous_pixels} and {dashed_pixels} pixels: continuous and dashed lines");
+    assert!(dashed_pixels > continuous_pixels / 4);
+    assert!(dashed_pixels < continuous_pixels * 3 / 4);
+    // the hidden lines are drawn only behind the other objects.
+    assert!(common::same_buffer(&background, &buffer2));
+    let hidden_pixels = common::count_difference(&buffer3, &buffer4);
+    println!("{hidden_pixels} pixels: hidden lines behind the polygon");
+    assert!(hidden_pixels > 0 && hidden_pixels < dashed_pixels);
+    // the hidden lines need their own pipeline.
+    assert_eq!(scene.device_handler().pipeline_cache().len(), 3);
+}
+
+#[test]
+fn line_type_render_test() { common::os_alt_exec_test(exec_line_type_render_test) }
@@ -1,7 +1,3 @@
-struct ModelMatrix {
-    model_matrix: mat4x4<f32>,
-}
-
 struct Material {
     albedo: vec4<f32>,
     roughness: f32,
@@ -10,20 +6,21 @@ struct Material {
     background_ratio: f32,
 }
 
+struct ModelUniform {
+    model_matrix: mat4x4<f32>,
+    material: Material,
+}
+
 @group(1)
 @binding(0)
-var<uniform> model_matrix: ModelMatrix;
+var<uniform> model: ModelUniform;
 
 @group(1)
 @binding(1)
-var<uniform> material: Material;
-
-@group(1)
-@binding(2)
 var r_color: texture_2d<f32>;
 
 @group(1)
-@binding(3)
+@binding(2)
 var r_sampler: sampler;
 
 struct VertexInput {
@@ -48,13 +45,13 @@ fn vs_main(in: VertexInput) -> VertexOutput {
         out.position = vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (distance(in.normal, vec3<f32>(in.uv.y, 0.2, in.uv.x)) > EPS) {
         out.position = vec4<f32>(0.0, 0.0, 0.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
         out.position = vec4<f32>(0.0, 0.0, 0.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
         out.position = vec4<f32>(0.0, 0.0, 0.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
         out.position = vec4<f32>(0.0, 0.0, 0.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
         out.position = vec4<f32>(0.0, 0.0, 0.0, 1.0);  
     } else {
         out.position = vec4<f32>(in.uv, 0.0, 1.0);
@@ -65,23 +62,23 @@ fn vs_main(in: VertexInput) -> VertexOutput {
 
 @fragment
 fn nontex_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    if (distance(model_matrix.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
+    if (distance(model.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0); 
-    } else if (distance(material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
+    } else if (distance(model.material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
         return vec4<f32>(1.0, 1.0, 0.0, 1.0);
-    } else if (abs(material.roughness - 0.31415) > EPS) {
+    } else if (abs(model.material.roughness - 0.31415) > EPS) {
         return vec4<f32>(1.0, 0.0, 1.0, 1.0);
-    } else if (abs(material.reflectance - 0.29613) > EPS) {
+    } else if (abs(model.material.reflectance - 0.29613) > EPS) {
         return vec4<f32>(0.0, 1.0, 1.0, 1.0);
-    } else if (abs(material.ambient_ratio - 0.92) > EPS) {
+    } else if (abs(model.material.ambient_ratio - 0.92) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
-    } else if (abs(material.background_ratio - 0.32) > EPS) {
+    } else if (abs(model.material.background_ratio - 0.32) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
     } else {
         return vec4<f32>(0.2, 0.4, 0.6, 0.8);
@@ -90,23 +87,23 @@ fn nontex_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
 @fragment
 fn nontex_main_anti(in: VertexOutput) -> @location(0) vec4<f32> {
-    if (distance(model_matrix.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
+    if (distance(model.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0); 
-    } else if (distance(material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
+    } else if (distance(model.material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
         return vec4<f32>(1.0, 1.0, 0.0, 1.0);
-    } else if (abs(material.roughness - 0.31415) > EPS) {
+    } else if (abs(model.material.roughness - 0.31415) > EPS) {
         return vec4<f32>(1.0, 0.0, 1.0, 1.0);
-    } else if (abs(material.reflectance - 0.29613) < EPS) {
+    } else if (abs(model.material.reflectance - 0.29613) < EPS) {
         return vec4<f32>(0.0, 1.0, 1.0, 1.0);
-    } else if (abs(material.ambient_ratio - 0.92) > EPS) {
+    } else if (abs(model.material.ambient_ratio - 0.92) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
-    } else if (abs(material.background_ratio - 0.32) > EPS) {
+    } else if (abs(model.material.background_ratio - 0.32) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
     } else {
         return vec4<f32>(0.2, 0.4, 0.6, 0.8);
@@ -115,23 +112,23 @@ fn nontex_main_anti(in: VertexOutput) -> @location(0) vec4<f32> {
 
 @fragment
 fn tex_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    if (distance(model_matrix.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
+    if (distance(model.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0); 
-    } else if (distance(material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
+    } else if (distance(model.material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
         return vec4<f32>(1.0, 1.0, 0.0, 1.0);
-    } else if (abs(material.roughness - 0.31415) > EPS) {
+    } else if (abs(model.material.roughness - 0.31415) > EPS) {
         return vec4<f32>(1.0, 0.0, 1.0, 1.0);
-    } else if (abs(material.reflectance - 0.29613) > EPS) {
+    } else if (abs(model.material.reflectance - 0.29613) > EPS) {
         return vec4<f32>(0.0, 1.0, 1.0, 1.0);
-    } else if (abs(material.ambient_ratio - 0.92) > EPS) {
+    } else if (abs(model.material.ambient_ratio - 0.92) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
-    } else if (abs(material.background_ratio - 0.32) > EPS) {
+    } else if (abs(model.material.background_ratio - 0.32) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
     } else {
         let uv = vec2<f32>(1.0 + in.uv.x, 1.0 - in.uv.y) / 2.0;
@@ -141,23 +138,23 @@ fn tex_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
 @fragment
 fn tex_main_anti(in: VertexOutput) -> @location(0) vec4<f32> {
-    if (distance(model_matrix.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
+    if (distance(model.model_matrix * e.xyyy, vec4<f32>(1.0, 2.0, 3.0, 4.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yxyy, vec4<f32>(5.0, 6.0, 7.0, 8.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyxy, vec4<f32>(9.0, 10.0, 11.0, 12.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0);
-    } else if (distance(model_matrix.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
+    } else if (distance(model.model_matrix * e.yyyx, vec4<f32>(13.0, 14.0, 15.0, 16.0)) > EPS) {
         return vec4<f32>(0.0, 0.0, 1.0, 1.0); 
-    } else if (distance(material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
+    } else if (distance(model.material.albedo, vec4<f32>(0.2, 0.4, 0.6, 1.0)) > EPS) {
         return vec4<f32>(1.0, 1.0, 0.0, 1.0);
-    } else if (abs(material.roughness - 0.31415) > EPS) {
+    } else if (abs(model.material.roughness - 0.31415) > EPS) {
         return vec4<f32>(1.0, 0.0, 1.0, 1.0);
-    } else if (abs(material.reflectance - 0.29613) > EPS) {
+    } else if (abs(model.material.reflectance - 0.29613) > EPS) {
         return vec4<f32>(0.0, 1.0, 1.0, 1.0);
-    } else if (abs(material.ambient_ratio - 0.92) > EPS) {
+    } else if (abs(model.material.ambient_ratio - 0.92) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
-    } else if (abs(material.background_ratio - 0.32) > EPS) {
+    } else if (abs(model.material.background_ratio - 0.32) > EPS) {
         return vec4<f32>(0.25, 0.25, 0.25, 1.0);
     } else {
         let uv = vec2<f32>(1.0 + in.uv.x, 1.0 + in.uv.y) / 2.0;
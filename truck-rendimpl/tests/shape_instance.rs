@@ -0,0 +1,69 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 192);
+
+fn exec_shape_instance_test(backend: Backends, _: &str) {
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        dx12_shader_compiler: Default::default(),
+    });
+    let handler = common::init_device(&instance);
+    let camera_matrix = Matrix4::look_at_rh(
+        Point3::new(-1.0, 2.5, 2.0),
+        Point3::new(0.5, 0.5, 0.5),
+        Vector3::unit_y(),
+    );
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            studio: StudioConfig {
+                camera: Camera::perspective_camera(
+                    camera_matrix.invert().unwrap(),
+                    Rad(std::f64::consts::PI / 4.0),
+                    0.1,
+                    100.0,
+                ),
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let preferences = MeshingPreferences::new(MeshingDensity::coarse());
+
+    let plain = creator.create_shape_instance(&cube, &preferences, &Default::default());
+    assert!(plain.isolines().is_none());
+    let state = ShapeState {
+        isolines: Some((
+            (4, 4),
+            WireFrameState {
+                color: Vector4::new(1.0, 0.0, 0.0, 1.0),
+                ..Default::default()
+            },
+        )),
+        ..Default::default()
+    };
+    let overlaid = creator.create_shape_instance(&cube, &preferences, &state);
+    let isolines = overlaid.isolines().unwrap();
+
+    let faces = common::render_one(&mut scene, plain.polygon());
+    scene.add_object(overlaid.polygon());
+    scene.add_object(isolines);
+    let lines = pollster::block_on(scene.render_to_buffer());
+    assert_eq!(scene.number_of_objects(), 2);
+    // the isolines are drawn over the faces.
+    assert!(common::count_difference(&faces, &lines) > 0);
+}
+
+#[test]
+fn shape_instance_test() { common::os_alt_exec_test(exec_shape_instance_test) }
@@ -77,6 +77,8 @@ where
     derive_method!(uuder, S0::Vector, u: f64, v: f64);
     derive_method!(uvder, S0::Vector, u: f64, v: f64);
     derive_method!(vvder, S0::Vector, u: f64, v: f64);
+    derive_method!(der_mn, S0::Vector, m: usize, n: usize, u: f64, v: f64);
+    derive_method!(try_range, (Option<(f64, f64)>, Option<(f64, f64)>),);
 }
 
 impl<S0, S1> ParametricSurface3D for Alternative<S0, S1>
@@ -85,6 +87,9 @@ where
     S1: ParametricSurface3D,
 {
     derive_method!(normal, Vector3, u: f64, v: f64);
+    derive_method!(first_fundamental_form, (f64, f64, f64), u: f64, v: f64);
+    derive_method!(second_fundamental_form, (f64, f64, f64), u: f64, v: f64);
+    derive_method!(shape_operator, Option<Matrix2>, u: f64, v: f64);
 }
 
 impl<C0, C1> Cut for Alternative<C0, C1>
@@ -4,6 +4,7 @@ use crate::faces_classification::FacesClassification;
 use crate::loops_store::*;
 use rustc_hash::FxHashMap as HashMap;
 use std::ops::Deref;
+use truck_base::predicates::orient2d;
 use truck_meshalgo::prelude::*;
 use truck_topology::*;
 
@@ -20,14 +21,13 @@ impl PolylineBoundary for PolylineCurve<Point2> {
     }
     fn include(&self, c: Point2) -> bool {
         let t = 2.0 * std::f64::consts::PI * HashGen::hash1(c);
-        let r = Vector2::new(f64::cos(t), f64::sin(t));
+        let d = c + Vector2::new(f64::cos(t), f64::sin(t));
         self.windows(2)
             .try_fold(0_i32, |counter, p| {
-                let a = p[0] - c;
-                let b = p[1] - c;
-                let s0 = r[0] * a[1] - r[1] * a[0];
-                let s1 = r[0] * b[1] - r[1] * b[0];
-                let s2 = a[0] * b[1] - a[1] * b[0];
+                let (a, b) = (p[0], p[1]);
+                let s0 = orient2d(c, d, a);
+                let s1 = orient2d(c, d, b);
+                let s2 = orient2d(c, a, b);
                 let x = s2 / (s1 - s0);
                 if x.so_small() && s0 * s1 < 0.0 {
                     None
@@ -154,11 +154,11 @@ where
                 .iter()
                 .all(|wire| wire.status() == ShapesOpStatus::Unknown)
             {
-                res.push(face.clone(), ShapesOpStatus::Unknown);
+                res.push(face.clone(), face.id(), ShapesOpStatus::Unknown);
             } else {
                 let vec = divide_one_face(face, loops, tol)?;
                 vec.into_iter()
-                    .for_each(|(face, status)| res.push(face, status));
+                    .for_each(|(divided, status)| res.push(divided, face.id(), status));
             }
             Some(())
         })?;
@@ -1,4 +1,6 @@
 use crate::*;
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
 use truck_geometry::prelude::IntersectionCurve;
 use truck_meshalgo::prelude::*;
 use truck_topology::*;
@@ -47,22 +49,40 @@ impl<C, S: ShapeOpsSurface> ShapeOpsCurve<S> for C where C: ParametricCurve3D
 {
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(shell0, shell1)))]
 fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     shell0: &Shell<Point3, C, S>,
     shell1: &Shell<Point3, C, S>,
     tol: f64,
-) -> Option<[Shell<Point3, C, S>; 2]> {
+) -> Option<([Shell<Point3, C, S>; 2], FxHashMap<FaceID<S>, FaceID<S>>)> {
     nonpositive_tolerance!(tol);
     let poly_shell0 = shell0.triangulation(tol);
     let poly_shell1 = shell1.triangulation(tol);
+    let quadruple =
+        loops_store::create_loops_stores(shell0, &poly_shell0, shell1, &poly_shell1, tol);
+    #[cfg(feature = "tracing")]
+    if quadruple.is_none() {
+        tracing::warn!("failed to create the intersection loops");
+    }
     let loops_store::LoopsStoreQuadruple {
         geom_loops_store0: loops_store0,
         geom_loops_store1: loops_store1,
         ..
-    } = loops_store::create_loops_stores(shell0, &poly_shell0, shell1, &poly_shell1, tol)?;
-    let mut cls0 = divide_face::divide_faces(shell0, &loops_store0, tol)?;
+    } = quadruple?;
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        loops0 = loops_store0.iter().map(|loops| loops.len()).sum::<usize>(),
+        loops1 = loops_store1.iter().map(|loops| loops.len()).sum::<usize>(),
+        "created the loops of the faces"
+    );
+    let cls0 = divide_face::divide_faces(shell0, &loops_store0, tol);
+    let cls1 = divide_face::divide_faces(shell1, &loops_store1, tol);
+    #[cfg(feature = "tracing")]
+    if cls0.is_none() || cls1.is_none() {
+        tracing::warn!("failed to divide the faces by the intersection loops");
+    }
+    let (mut cls0, mut cls1) = (cls0?, cls1?);
     cls0.integrate_by_component();
-    let mut cls1 = divide_face::divide_faces(shell1, &loops_store1, tol)?;
     cls1.integrate_by_component();
     let [mut and0, mut or0, unknown0] = cls0.and_or_unknown();
     unknown0.into_iter().try_for_each(|face| {
@@ -96,53 +116,113 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     })?;
     and0.append(&mut and1);
     or0.append(&mut or1);
-    Some([and0, or0])
+    let mut origins = cls0.origins().clone();
+    origins.extend(cls1.origins());
+    Some(([and0, or0], origins))
+}
+
+/// Maps the faces of `shell` to the faces of the operands through `origins` of the passes.
+fn face_origins<P, C, S>(
+    shell: &Shell<P, C, S>,
+    origins: &[FxHashMap<FaceID<S>, FaceID<S>>],
+) -> HashMap<FaceID<S>, FaceID<S>> {
+    let origin = |face: &Face<P, C, S>| {
+        let id = origins
+            .iter()
+            .rev()
+            .fold(face.id(), |id, map| match map.get(&id) {
+                Some(origin) => *origin,
+                None => id,
+            });
+        (face.id(), id)
+    };
+    shell.face_iter().map(origin).collect()
 }
 
 /// AND operation between two solids.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(solid0, solid1)))]
 pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
+    and_with_origins(solid0, solid1, tol).map(|(solid, _)| solid)
+}
+
+/// AND operation between two solids, with the origins of the faces.
+///
+/// The returned map sends each face of the result to the face of `solid0` or `solid1` which the
+/// face is a piece of, so that the attributes of the faces can be tracked through the operation.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+/// let mut tool = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+/// tool.not();
+///
+/// let (solid, origins) = truck_shapeops::and_with_origins(&cube, &tool, 0.05).unwrap();
+/// let faces = solid.boundaries()[0].face_iter();
+/// let from_tool = faces.filter(|face| tool.face_iter().any(|f| f.id() == origins[&face.id()]));
+/// assert_eq!(from_tool.count(), 3);
+/// ```
+pub fn and_with_origins<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<(Solid<Point3, C, S>, HashMap<FaceID<S>, FaceID<S>>)> {
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [mut and_shell, _] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
-    }
-    for shell in iter1 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
+    let ([mut and_shell, _], origins) = process_one_pair_of_shells(shell0, shell1, tol)?;
+    let mut passes = vec![origins];
+    for shell in iter0.chain(iter1) {
+        let ([res, _], origins) = process_one_pair_of_shells(&and_shell, shell, tol)?;
         and_shell = res;
+        passes.push(origins);
     }
+    let origins = face_origins(&and_shell, &passes);
     let boundaries = and_shell.connected_components();
-    Some(Solid::new(boundaries))
+    Some((Solid::new(boundaries), origins))
 }
 
 /// OR operation between two solids.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(solid0, solid1)))]
 pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
+    or_with_origins(solid0, solid1, tol).map(|(solid, _)| solid)
+}
+
+/// OR operation between two solids, with the origins of the faces.
+///
+/// The returned map sends each face of the result to the face of `solid0` or `solid1` which the
+/// face is a piece of, as [`and_with_origins`].
+pub fn or_with_origins<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<(Solid<Point3, C, S>, HashMap<FaceID<S>, FaceID<S>>)> {
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [_, mut or_shell] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    for shell in iter1 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
+    let ([_, mut or_shell], origins) = process_one_pair_of_shells(shell0, shell1, tol)?;
+    let mut passes = vec![origins];
+    for shell in iter0.chain(iter1) {
+        let ([_, res], origins) = process_one_pair_of_shells(&or_shell, shell, tol)?;
         or_shell = res;
+        passes.push(origins);
     }
+    let origins = face_origins(&or_shell, &passes);
     let boundaries = or_shell.connected_components();
-    Some(Solid::new(boundaries))
+    Some((Solid::new(boundaries), origins))
 }
 
 #[cfg(test)]
@@ -19,6 +19,6 @@ mod integrate;
 mod intersection_curve;
 mod loops_store;
 mod polyline_construction;
-pub use integrate::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use integrate::{and, and_with_origins, or, or_with_origins, ShapeOpsCurve, ShapeOpsSurface};
 
 mod alternative;
@@ -299,13 +299,67 @@ impl StepLength for Leader {
     }
 }
 
+/// the circle of the NURBS arcs, exported as `CIRCLE` which the edges trim by their vertices
+#[derive(Clone, Copy, Debug)]
+struct ExactCircle {
+    center: Point3,
+    normal: Vector3,
+    x_axis: Vector3,
+    radius: f64,
+}
+
+impl ExactCircle {
+    fn recognize(curve: &NurbsCurve<Vector4>) -> Option<Self> {
+        let arc = CircleArc::recognize(curve, TOLERANCE)?;
+        let matrix = arc.transform();
+        let radius = matrix.x.truncate().magnitude();
+        let normal = match arc.orientation() {
+            true => matrix.z.truncate(),
+            false => -matrix.z.truncate(),
+        };
+        Some(Self {
+            center: Point3::from_homogeneous(matrix.w),
+            normal,
+            x_axis: matrix.x.truncate() / radius,
+            radius,
+        })
+    }
+}
+
+impl Display for StepDisplay<ExactCircle> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let idx = self.idx;
+        let axis2_placement_idx = idx + 1;
+        let location_idx = idx + 2;
+        let z_axis_idx = idx + 3;
+        let x_axis_idx = idx + 4;
+        f.write_fmt(format_args!(
+            "#{idx} = CIRCLE('', #{axis2_placement_idx}, {radius:?});
+#{axis2_placement_idx} = AXIS2_PLACEMENT_3D('', #{location_idx}, #{z_axis_idx}, #{x_axis_idx});
+{location}{z_axis}{x_axis}",
+            radius = self.entity.radius,
+            location = StepDisplay::new(self.entity.center, location_idx),
+            z_axis = StepDisplay::new(VectorAsDirection(self.entity.normal), z_axis_idx),
+            x_axis = StepDisplay::new(VectorAsDirection(self.entity.x_axis), x_axis_idx),
+        ))
+    }
+}
+
+impl ConstStepLength for ExactCircle {
+    const LENGTH: usize = 5;
+}
+
 impl<'a> Display for StepDisplay<&'a ModelingCurve> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self.entity {
             ModelingCurve::Line(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
             ModelingCurve::BSplineCurve(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
-            ModelingCurve::NurbsCurve(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
+            ModelingCurve::NurbsCurve(x) => match ExactCircle::recognize(x) {
+                Some(circle) => Display::fmt(&StepDisplay::new(circle, self.idx), f),
+                None => Display::fmt(&StepDisplay::new(x, self.idx), f),
+            },
             ModelingCurve::IntersectionCurve(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
+            ModelingCurve::Polyline(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
         }
     }
 }
@@ -321,8 +375,12 @@ impl StepLength for ModelingCurve {
         match self {
             ModelingCurve::Line(_) => Line::<Point3>::LENGTH,
             ModelingCurve::BSplineCurve(x) => x.step_length(),
-            ModelingCurve::NurbsCurve(x) => x.step_length(),
+            ModelingCurve::NurbsCurve(x) => match ExactCircle::recognize(x) {
+                Some(_) => ExactCircle::LENGTH,
+                None => x.step_length(),
+            },
             ModelingCurve::IntersectionCurve(x) => x.step_length(),
+            ModelingCurve::Polyline(x) => x.step_length(),
         }
     }
 }
@@ -177,3 +177,43 @@ fn geometry() {
     13
     );
 }
+
+#[test]
+fn exact_circle() {
+    use truck_modeling::{builder, Curve};
+    let vertex0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let vertex1 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+    let curve =
+        builder::circle_arc(&vertex0, &vertex1, Point3::new(0.0, 1.0, 0.0)).oriented_curve();
+    assert!(matches!(curve, Curve::NurbsCurve(_)));
+
+    let display = StepDisplay::new(&curve, 1);
+    let string = display.to_string();
+    let lines = string.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 5, "{string}");
+    assert!(lines[0].starts_with("#1 = CIRCLE('', #2, "), "{string}");
+    assert_eq!(lines[1], "#2 = AXIS2_PLACEMENT_3D('', #3, #4, #5);");
+    assert_eq!(lines[2], "#3 = CARTESIAN_POINT('', (0.0, 0.0, 0.0));");
+    assert_eq!(lines[3], "#4 = DIRECTION('', (0.0, 0.0, 1.0));");
+    assert!(lines[4].starts_with("#5 = DIRECTION('', ("), "{string}");
+    assert_eq!(curve.step_length(), 5);
+    let step = CompleteStepDisplay::new(display, Default::default()).to_string();
+    ruststep::parser::parse(&step).unwrap();
+
+    // the free curves are exported as they are.
+    let ctrl_pts = vec![
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(1.0, 1.0, 0.0, 1.0),
+        Vector4::new(0.0, 1.0, 0.0, 1.0),
+    ];
+    let curve = Curve::NurbsCurve(NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        ctrl_pts,
+    )));
+    let string = StepDisplay::new(&curve, 1).to_string();
+    assert!(
+        string.starts_with("#1 = (\n    BOUNDED_CURVE()"),
+        "{string}"
+    );
+    assert_eq!(curve.step_length(), 4);
+}
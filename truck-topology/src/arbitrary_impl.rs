@@ -0,0 +1,108 @@
+//! `Arbitrary` implementations for fuzzers and property-based tests.
+//!
+//! The generated shells are quadrangulated grids: a disk, an annulus, or a torus. Each of them
+//! satisfies [`Shell::invariants_hold`], so one can check that a custom operation keeps the
+//! invariants by applying it to the generated shells. The geometries of vertices, edges, and faces
+//! are also generated by `Arbitrary`, e.g. `()` for the pure topological tests.
+//! # Examples
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use truck_topology::*;
+//!
+//! let data = (0..=255).collect::<Vec<u8>>();
+//! let mut u = Unstructured::new(&data);
+//! let shell = Shell::<(), (), ()>::arbitrary(&mut u).unwrap();
+//! assert!(shell.invariants_hold());
+//! let solid = Solid::<(), (), ()>::arbitrary(&mut u).unwrap();
+//! assert!(solid.invariants_hold());
+//! ```
+
+use crate::*;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, P, C, S> Arbitrary<'a> for Shell<P, C, S>
+where
+    P: Arbitrary<'a>,
+    C: Arbitrary<'a>,
+    S: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (closed_u, closed_v) = (bool::arbitrary(u)?, bool::arbitrary(u)?);
+        grid_shell(u, closed_u, closed_v)
+    }
+}
+
+impl<'a, P, C, S> Arbitrary<'a> for Solid<P, C, S>
+where
+    P: Arbitrary<'a>,
+    C: Arbitrary<'a>,
+    S: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Solid::new_unchecked(vec![grid_shell(u, true, true)?]))
+    }
+}
+
+/// The number of faces in one direction.
+/// The closed directions have at least three faces so that the boundaries of faces are simple.
+fn division(u: &mut Unstructured<'_>, closed: bool) -> Result<usize> {
+    match closed {
+        true => u.int_in_range(3..=6),
+        false => u.int_in_range(1..=6),
+    }
+}
+
+/// the grid of quadrangles, whose opposite sides are glued if `closed_u` or `closed_v`.
+fn grid_shell<'a, P, C, S>(
+    u: &mut Unstructured<'a>,
+    closed_u: bool,
+    closed_v: bool,
+) -> Result<Shell<P, C, S>>
+where
+    P: Arbitrary<'a>,
+    C: Arbitrary<'a>,
+    S: Arbitrary<'a>,
+{
+    let (udiv, vdiv) = (division(u, closed_u)?, division(u, closed_v)?);
+    let ulen = if closed_u { udiv } else { udiv + 1 };
+    let vlen = if closed_v { vdiv } else { vdiv + 1 };
+    let vertices = (0..ulen)
+        .map(|_| {
+            (0..vlen)
+                .map(|_| Ok(Vertex::new(P::arbitrary(u)?)))
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<_>>>>()?;
+    let uedges = (0..udiv)
+        .map(|i| {
+            (0..vlen)
+                .map(|j| {
+                    let (v0, v1) = (&vertices[i][j], &vertices[(i + 1) % ulen][j]);
+                    Ok(Edge::new_unchecked(v0, v1, C::arbitrary(u)?))
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<_>>>>()?;
+    let vedges = (0..ulen)
+        .map(|i| {
+            (0..vdiv)
+                .map(|j| {
+                    let (v0, v1) = (&vertices[i][j], &vertices[i][(j + 1) % vlen]);
+                    Ok(Edge::new_unchecked(v0, v1, C::arbitrary(u)?))
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<_>>>>()?;
+    (0..udiv)
+        .flat_map(|i| (0..vdiv).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let wire = Wire::from(vec![
+                uedges[i][j].clone(),
+                vedges[(i + 1) % ulen][j].clone(),
+                uedges[i][(j + 1) % vlen].inverse(),
+                vedges[i][j].inverse(),
+            ]);
+            Ok(Face::new_unchecked(vec![wire], S::arbitrary(u)?))
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
 use crate::{errors::Error, *};
 use thiserror::Error;
+use truck_base::cgmath64::InnerSpace;
 
 impl<P, C> Edge<P, C> {
     /// Generates the edge from `front` to `back`.  
@@ -398,21 +399,56 @@ impl<P, C> Edge<P, C> {
         geom_front.near(&*top_front) && geom_back.near(&*top_back)
     }
 
+    /// Returns the length of the curve, integrated by the adaptive Gauss-Legendre quadrature
+    /// within `TOLERANCE`.
+    #[inline(always)]
+    pub fn length(&self) -> f64
+    where
+        C: BoundedCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        let curve = &*self.curve.lock();
+        algo::curve::arc_length(curve, curve.parameter_range(), TOLERANCE)
+    }
+
     /// Cuts the edge at `vertex`.
     /// # Failures
     /// Returns `None` if:
-    /// - cannot find the parameter `t` such that `edge.curve().subs(t) == vertex.point()`, or
-    /// - the found parameter is not in the parameter range without end points.
+    /// - cannot find the parameter `t` such that `edge.curve().subs(t) == vertex.point()` in the
+    ///   parameter range without end points, or
+    /// - there are several such parameters, i.e. the curve passes `vertex` several times. Use
+    ///   [`Edge::cut_with_parameter`] in order to specify the parameter in this case.
     pub fn cut(&self, vertex: &Vertex<P>) -> Option<(Self, Self)>
     where
         P: Clone,
         C: Cut<Point = P> + SearchParameter<D1, Point = P>, {
         let mut curve0 = self.curve();
-        let t = curve0.search_parameter(vertex.point(), None, SEARCH_PARAMETER_TRIALS)?;
         let (t0, t1) = curve0.parameter_range();
-        if t < t0 + TOLERANCE || t1 - TOLERANCE < t {
-            return None;
-        }
+        let interior = |t: &f64| t0 + TOLERANCE <= *t && *t <= t1 - TOLERANCE;
+        let search = |hint: SPHint1D| {
+            let t = curve0.search_parameter(vertex.point(), hint, SEARCH_PARAMETER_TRIALS);
+            t.filter(interior)
+        };
+        let t = match search(SPHint1D::None) {
+            // check that the curve does not pass `vertex` again on either side of `t`
+            Some(t) => {
+                let front = search(SPHint1D::Range(t0, t - TOLERANCE));
+                let back = search(SPHint1D::Range(t + TOLERANCE, t1));
+                match [front, back].into_iter().flatten().all(|s| s.near(&t)) {
+                    true => t,
+                    false => return None,
+                }
+            }
+            // the single search may miss the solution, then search all the solutions
+            None => {
+                let trials = SEARCH_PARAMETER_TRIALS;
+                let ts = curve0.search_parameters(vertex.point(), (t0, t1), trials);
+                let mut ts = ts.into_iter().filter(interior);
+                match (ts.next(), ts.next()) {
+                    (Some(t), None) => t,
+                    _ => return None,
+                }
+            }
+        };
         let curve1 = curve0.cut(t);
         let edge0 = Edge {
             vertices: (self.absolute_front().clone(), vertex.clone()),
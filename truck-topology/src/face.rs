@@ -1,5 +1,10 @@
 use crate::{errors::Error, wire::EdgeIter, *};
 use rustc_hash::FxHashMap as HashMap;
+use truck_base::cgmath64::{InnerSpace, Point3, Vector3};
+use truck_geotrait::algo::{gauss_legendre, gauss_legendre_nodes};
+
+const EDGE_DIVISION: usize = 16;
+const LINE_DIVISION: usize = 8;
 
 impl<P, C, S> Face<P, C, S> {
     /// Creates a new face by a wire.
@@ -986,6 +991,124 @@ where
     }
 }
 
+impl<P, C, S> Face<P, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the area of the face, the integral of `|S_u x S_v|` on the trimmed domain.
+    ///
+    /// The boundaries are projected to the parameter domain by [`SearchParameter`], and the
+    /// integral on the domain is converted into the integral along the projected boundaries by
+    /// Green's theorem. Both the integrals along the boundaries and along the parameter lines
+    /// are by the Gauss-Legendre quadrature. The boundaries may wrap around a period of the
+    /// surface, e.g. the boundaries of a band of a cylinder, and the boundaries degenerated to
+    /// points, e.g. at the poles of spheres, are closed by the segments in the domain.
+    /// # Failures
+    /// Returns `None` if a point of the boundaries is not found on the surface.
+    pub fn area(&self) -> Option<f64> {
+        let surface = &*self.surface.lock();
+        let periods = (surface.u_period(), surface.v_period());
+        let project = |point: Point3, hint: Option<(f64, f64)>| {
+            let (u, v) = surface.search_parameter(point, hint, SEARCH_PARAMETER_TRIALS)?;
+            Some(match hint {
+                Some((u0, v0)) => (unwrap(u, u0, periods.0), unwrap(v, v0, periods.1)),
+                None => (u, v),
+            })
+        };
+        let velocity = |(u, v): (f64, f64), der: Vector3| {
+            let (su, sv) = (surface.uder(u, v), surface.vder(u, v));
+            let (a, b, c) = (su.dot(su), su.dot(sv), sv.dot(sv));
+            let (e, f) = (su.dot(der), sv.dot(der));
+            let det = a * c - b * b;
+            match det.so_small2() {
+                true => (0.0, 0.0),
+                false => ((c * e - b * f) / det, (a * f - b * e) / det),
+            }
+        };
+        // the points in the domain and the derivatives multiplied by the weights
+        let mut samples = Vec::<((f64, f64), (f64, f64))>::new();
+        let segment = |samples: &mut Vec<_>, (u0, v0): (f64, f64), (u1, v1): (f64, f64)| {
+            gauss_legendre_nodes((0.0, 1.0), 1).for_each(|(s, w)| {
+                let uv = (u0 + (u1 - u0) * s, v0 + (v1 - v0) * s);
+                samples.push((uv, ((u1 - u0) * w, (v1 - v0) * w)));
+            })
+        };
+        let mut wrapping = (false, false);
+        for wire in &self.boundaries {
+            let mut first = None;
+            let mut previous: Option<(f64, f64)> = None;
+            for edge in wire {
+                let curve = &*edge.curve.lock();
+                let range = match edge.orientation() {
+                    true => curve.parameter_range(),
+                    false => {
+                        let (t0, t1) = curve.parameter_range();
+                        (t1, t0)
+                    }
+                };
+                let mut hint = previous;
+                let mut edge_samples = Vec::new();
+                let mut nodes = Vec::new();
+                for (t, w) in gauss_legendre_nodes(range, EDGE_DIVISION) {
+                    let uv = project(curve.subs(t), hint)?;
+                    let (du, dv) = velocity(uv, curve.der(t));
+                    edge_samples.push((uv, (du * w, dv * w)));
+                    nodes.push((t, uv, (du, dv)));
+                    hint = Some(uv);
+                }
+                // the ends on the sides of the edge, even if the ends are singular points
+                let end_point = |(t, uv, (du, dv)): (f64, (f64, f64), (f64, f64)), end: f64| {
+                    let extrapolated = (uv.0 + du * (end - t), uv.1 + dv * (end - t));
+                    project(curve.subs(end), Some(uv)).unwrap_or(extrapolated)
+                };
+                let (start, end) = match (nodes.first(), nodes.last()) {
+                    (Some(front), Some(back)) => {
+                        (end_point(*front, range.0), end_point(*back, range.1))
+                    }
+                    _ => continue,
+                };
+                if let Some(previous) = previous {
+                    segment(&mut samples, previous, start);
+                }
+                samples.extend(edge_samples);
+                first = first.or(Some(start));
+                previous = Some(end);
+            }
+            if let (Some((u0, v0)), Some((u1, v1))) = (first, previous) {
+                let closed = (unwrap(u0, u1, periods.0), unwrap(v0, v1, periods.1));
+                segment(&mut samples, (u1, v1), closed);
+                wrapping.0 |= !closed.0.near(&u0);
+                wrapping.1 |= !closed.1.near(&v0);
+            }
+        }
+
+        let jacobian = |u: f64, v: f64| surface.uder(u, v).cross(surface.vder(u, v)).magnitude();
+        let first = samples.first().map(|(uv, _)| *uv)?;
+        let area = match wrapping.0 {
+            // the integral of `J du` from `first.0` to `u` along `v = const`
+            false => samples.iter().fold(0.0, |sum, ((u, v), (_, dv))| {
+                let range = (first.0, *u);
+                sum + gauss_legendre(|s| jacobian(s, *v), range, LINE_DIVISION) * dv
+            }),
+            // the integral of `J dv` from `first.1` to `v` along `u = const`
+            true => samples.iter().fold(0.0, |sum, ((u, v), (du, _))| {
+                let range = (first.1, *v);
+                sum - gauss_legendre(|t| jacobian(*u, t), range, LINE_DIVISION) * du
+            }),
+        };
+        Some(f64::abs(area))
+    }
+}
+
+/// the representative of `x` modulo `period` nearest to `previous`
+fn unwrap(x: f64, previous: f64, period: Option<f64>) -> f64 {
+    match period {
+        Some(period) => x - period * f64::round((x - previous) / period),
+        None => x,
+    }
+}
+
 impl<P, C, S> Clone for Face<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Face<P, C, S> {
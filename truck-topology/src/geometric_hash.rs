@@ -0,0 +1,100 @@
+use crate::*;
+use rustc_hash::FxHasher;
+use truck_base::cgmath64::{Array, EuclideanSpace, MetricSpace};
+
+impl<P, C, S> Solid<P, C, S> {
+    /// Returns the hash of the geometry of the solid.
+    ///
+    /// The hash does not depend on the order of the shells, faces, wires, and edges, on the
+    /// orientations of the edges, or on the ids of the entities, so the copies of one part created
+    /// by the different importers have the same hash. The hash is made from the points of the
+    /// vertices and the middle points of the edges quantized by `tolerance`. If `rigid_invariant`
+    /// is `true`, the points are replaced by the distances from the centroid of the vertices and
+    /// the lengths of the chords, and the hash is also invariant under the rigid transformations
+    /// and the reflections.
+    ///
+    /// The hash is the key for deduplicating parts and caching tessellations. Two points within
+    /// `tolerance` may fall into neighboring cells of the quantization, and the surfaces are
+    /// distinguished only by their boundaries, so compare the solids with the same hash before
+    /// sharing them.
+    pub fn geometric_hash(&self, tolerance: f64, rigid_invariant: bool) -> u64
+    where
+        P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+        C: BoundedCurve<Point = P> + Clone, {
+        let points = self.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+        let centroid = match points.is_empty() {
+            true => P::origin(),
+            false => P::centroid(&points),
+        };
+        let hasher = PointHasher {
+            tolerance,
+            centroid: rigid_invariant.then_some(centroid),
+        };
+        let shells = self
+            .boundaries()
+            .iter()
+            .map(|shell| {
+                let faces = shell.face_iter().map(|face| hasher.face(face));
+                sorted_hash(faces)
+            })
+            .collect::<Vec<_>>();
+        sorted_hash(shells)
+    }
+}
+
+struct PointHasher<P> {
+    tolerance: f64,
+    centroid: Option<P>,
+}
+
+impl<P> PointHasher<P>
+where P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>
+{
+    fn quantize(&self, x: f64) -> i64 { (x / self.tolerance).round() as i64 }
+
+    fn point(&self, point: P) -> u64 {
+        match self.centroid {
+            Some(centroid) => hash(&self.quantize(point.distance(centroid))),
+            None => {
+                let coords = (0..P::len()).map(|i| self.quantize(point[i]));
+                hash(&coords.collect::<Vec<_>>())
+            }
+        }
+    }
+
+    fn edge<C>(&self, edge: &Edge<P, C>) -> u64
+    where C: BoundedCurve<Point = P> + Clone {
+        let curve = edge.curve();
+        let (t0, t1) = curve.parameter_range();
+        let (p, q, r) = (curve.front(), curve.subs((t0 + t1) / 2.0), curve.back());
+        let mut ends = [self.point(p), self.point(r)];
+        let mut chords = [self.quantize(p.distance(q)), self.quantize(q.distance(r))];
+        ends.sort_unstable();
+        chords.sort_unstable();
+        match self.centroid {
+            Some(_) => hash(&(ends, self.point(q), chords, self.quantize(p.distance(r)))),
+            None => hash(&(ends, self.point(q))),
+        }
+    }
+
+    fn face<C, S>(&self, face: &Face<P, C, S>) -> u64
+    where C: BoundedCurve<Point = P> + Clone {
+        let wires = face.absolute_boundaries().iter().map(|wire| {
+            let edges = wire.edge_iter().map(|edge| self.edge(edge));
+            sorted_hash(edges)
+        });
+        sorted_hash(wires)
+    }
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn sorted_hash(hashes: impl IntoIterator<Item = u64>) -> u64 {
+    let mut hashes = hashes.into_iter().collect::<Vec<_>>();
+    hashes.sort_unstable();
+    hash(&hashes)
+}
@@ -66,6 +66,9 @@
 //! * `nightly` – Use features available only in a `nightly` toolchain.
 //! * `rclite` – Use of `rclite::Arc` instead of `std::syn::Arc`. The latter
 //!   uses more memory and is potentially slower than the former. On by default.
+//! * `arbitrary` – Implements `arbitrary::Arbitrary` for `Shell` and `Solid`. The generated shells
+//!   satisfy `Shell::invariants_hold`, so fuzzers and property-based tests can check that custom
+//!   operations keep the invariants.
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 #![deny(clippy::all, rust_2018_idioms)]
@@ -383,12 +386,15 @@ pub enum SolidDisplayFormat {
     },
 }
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
 pub mod compress;
 mod edge;
 /// classifies the errors that can occur in this crate.
 pub mod errors;
 /// Defines the boundary iterator.
 pub mod face;
+mod geometric_hash;
 /// classifies shell conditions and defines the face iterators.
 pub mod shell;
 mod solid;
@@ -2,6 +2,7 @@ use crate::*;
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::vec::Vec;
+use truck_base::cgmath64::{Array, MetricSpace};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 type FaceAdjacencyMap<'a, P, C, S> = HashMap<&'a Face<P, C, S>, Vec<&'a Face<P, C, S>>>;
@@ -455,6 +456,59 @@ impl<P, C, S> Shell<P, C, S> {
             .collect()
     }
 
+    /// Returns whether the topological invariants of the shell hold, i.e.
+    /// - the shell is not empty and connected,
+    /// - the shell is regular, i.e. each edge is shared by at most two faces,
+    /// - the boundaries of each face are non-empty, closed, simple, and disjoint, and
+    /// - the edges with the same id have the same end vertices.
+    ///
+    /// The shells created by the checked constructors always satisfy the last two conditions.
+    /// This method is the entry point to test the results of the operations which use the unchecked
+    /// constructors, e.g. by property-based tests or fuzzers.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 4]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    ///     Edge::new(&v[1], &v[3], ()),
+    ///     Edge::new(&v[3], &v[2], ()),
+    /// ];
+    /// let wire0 = Wire::from_iter(vec![&edge[0], &edge[1], &edge[2]]);
+    /// let wire1 = Wire::from_iter(vec![&edge[1].inverse(), &edge[3], &edge[4]]);
+    /// let faces = vec![Face::new(vec![wire0], ()), Face::new(vec![wire1], ())];
+    /// let shell: Shell<_, _, _> = faces.into();
+    /// assert!(shell.invariants_hold());
+    ///
+    /// // the boundary is not closed
+    /// let wire = Wire::from_iter(vec![&edge[0], &edge[1]]);
+    /// let shell: Shell<_, _, _> = vec![Face::new_unchecked(vec![wire], ())].into();
+    /// assert!(!shell.invariants_hold());
+    /// ```
+    pub fn invariants_hold(&self) -> bool {
+        let faces_hold = self.face_iter().all(|face| {
+            let boundaries = face.absolute_boundaries();
+            !boundaries.is_empty()
+                && boundaries
+                    .iter()
+                    .all(|wire| !wire.is_empty() && wire.is_closed() && wire.is_simple())
+                && Wire::disjoint_wires(boundaries)
+        });
+        let mut ends = HashMap::<EdgeID<C>, (VertexID<P>, VertexID<P>)>::default();
+        let edges_hold = self.edge_iter().all(|edge| {
+            let (v0, v1) = edge.absolute_ends();
+            let pair = (v0.id(), v1.id());
+            *ends.entry(edge.id()).or_insert(pair) == pair
+        });
+        !self.is_empty()
+            && faces_hold
+            && edges_hold
+            && self.is_connected()
+            && self.shell_condition() != ShellCondition::Irregular
+    }
+
     /// Returns a new shell whose surfaces are mapped by `surface_mapping`,
     /// curves are mapped by `curve_mapping` and points are mapped by `point_mapping`.
     /// # Remarks
@@ -691,6 +745,90 @@ impl<P, C, S> Shell<P, C, S> {
         }
     }
 
+    /// Merges the vertices whose points are nearly identical, and returns the number of the
+    /// removed vertices.
+    ///
+    /// Two points are identified if each of their components differs by at most `tolerance`, and
+    /// the candidates are searched by the kd-tree. Each cluster is represented by the vertex which
+    /// appears first in [`Shell::vertex_iter`].
+    /// The edges incident to the removed vertices are rebuilt on the representatives with the
+    /// same curves, and the edges shared before merging are still shared after. The edges whose
+    /// both ends are merged into one vertex are removed from the boundaries, and so are the
+    /// empty boundaries and the faces without boundaries.
+    /// # Examples
+    /// ```
+    /// use truck_base::cgmath64::Point2;
+    /// use truck_topology::*;
+    /// // two triangles whose common side consists of distinct vertices
+    /// let v = Vertex::news([
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(1.0e-8, 0.0),
+    ///     Point2::new(0.0, 1.0 - 1.0e-8),
+    ///     Point2::new(-1.0, 0.0),
+    /// ]);
+    /// let wire0 = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let wire1 = Wire::from(vec![
+    ///     Edge::new(&v[3], &v[4], ()),
+    ///     Edge::new(&v[4], &v[5], ()),
+    ///     Edge::new(&v[5], &v[3], ()),
+    /// ]);
+    /// let mut shell: Shell<_, _, _> = vec![
+    ///     Face::new(vec![wire0], ()),
+    ///     Face::new(vec![wire1], ()),
+    /// ].into();
+    /// assert_eq!(shell.merge_vertices(1.0e-6), 2);
+    ///
+    /// let wire = &shell[1].boundaries()[0];
+    /// assert_eq!(wire[0].front(), &v[0]);
+    /// assert_eq!(wire[0].back(), &v[2]);
+    /// assert_eq!(wire[2].back(), &v[0]);
+    /// assert!(wire.is_closed());
+    /// ```
+    pub fn merge_vertices(&mut self, tolerance: f64) -> usize
+    where
+        P: Tolerance + Array<Element = f64> + MetricSpace<Metric = f64> + Copy,
+        C: Clone, {
+        let (vertex_map, num_vertices) = vertex::tolerant_vertex_map(self.vertex_iter(), tolerance);
+        let removed = vertex_map.len() - num_vertices;
+        if removed == 0 {
+            return 0;
+        }
+        let mut edge_map = HashMap::<EdgeID<C>, Option<Edge<P, C>>>::default();
+        self.iter_mut().for_each(|face| {
+            face.boundaries.iter_mut().for_each(|wire| {
+                *wire = wire
+                    .iter()
+                    .filter_map(|edge| {
+                        let new_edge = edge_map.entry(edge.id()).or_insert_with(|| {
+                            let (v0, v1) = &edge.vertices;
+                            let (w0, w1) = (&vertex_map[&v0.id()], &vertex_map[&v1.id()]);
+                            if w0 == w1 {
+                                None
+                            } else if v0 == w0 && v1 == w1 {
+                                Some(edge.absolute_clone())
+                            } else {
+                                Some(Edge::debug_new(w0, w1, edge.curve()))
+                            }
+                        });
+                        new_edge.as_ref().map(|new_edge| match edge.orientation() {
+                            true => new_edge.clone(),
+                            false => new_edge.inverse(),
+                        })
+                    })
+                    .collect();
+            });
+            face.boundaries.retain(|wire| !wire.is_empty());
+        });
+        self.retain(|face| !face.boundaries.is_empty());
+        removed
+    }
+
     /// Creates display struct for debugging the shell.
     /// # Examples
     /// ```
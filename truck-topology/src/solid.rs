@@ -1,6 +1,7 @@
 use crate::errors::Error;
 use crate::shell::ShellCondition;
 use crate::*;
+use rustc_hash::FxHashMap as HashMap;
 use std::vec::Vec;
 
 impl<P, C, S> Solid<P, C, S> {
@@ -133,6 +134,171 @@ impl<P, C, S> Solid<P, C, S> {
         )
     }
 
+    /// Returns whether the topological invariants of the solid hold, i.e. the solid has at least
+    /// one boundary, and every boundary satisfies [`Shell::invariants_hold`] and is a closed
+    /// manifold.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 4]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[0], &v[2], ()),
+    ///     Edge::new(&v[0], &v[3], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[1], &v[3], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    /// ];
+    /// let wire = vec![
+    ///     Wire::from_iter(vec![&edge[0], &edge[3], &edge[1].inverse()]),
+    ///     Wire::from_iter(vec![&edge[1], &edge[5], &edge[2].inverse()]),
+    ///     Wire::from_iter(vec![&edge[2], &edge[4].inverse(), &edge[0].inverse()]),
+    ///     Wire::from_iter(vec![&edge[3], &edge[5], &edge[4].inverse()]),
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|w| Face::new(vec![w], ())).collect();
+    /// shell[3].invert();
+    /// let solid = Solid::new(vec![shell.clone()]);
+    /// assert!(solid.invariants_hold());
+    ///
+    /// // the boundary is not closed
+    /// shell.pop();
+    /// assert!(!Solid::new_unchecked(vec![shell]).invariants_hold());
+    /// ```
+    pub fn invariants_hold(&self) -> bool {
+        !self.boundaries.is_empty()
+            && self.boundaries.iter().all(|shell| {
+                shell.invariants_hold()
+                    && shell.shell_condition() == ShellCondition::Closed
+                    && shell.singular_vertices().is_empty()
+            })
+    }
+
+    /// Splits the solid into the solids of the connected components of the boundaries, e.g. the
+    /// lumps of the bodies joined by the boolean operations or the imports.
+    ///
+    /// Each component becomes the only boundary of a solid, in the order of the first faces.
+    /// Use [`Solid::split_lumps_with`] to keep the cavities in the solids.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let tetrahedron = |v: &[Vertex<()>]| {
+    ///     let edge = [
+    ///         Edge::new(&v[0], &v[1], ()),
+    ///         Edge::new(&v[0], &v[2], ()),
+    ///         Edge::new(&v[0], &v[3], ()),
+    ///         Edge::new(&v[1], &v[2], ()),
+    ///         Edge::new(&v[2], &v[3], ()),
+    ///         Edge::new(&v[3], &v[1], ()),
+    ///     ];
+    ///     let wire = vec![
+    ///         Wire::from_iter(vec![&edge[0].inverse(), &edge[1], &edge[3].inverse()]),
+    ///         Wire::from_iter(vec![&edge[1].inverse(), &edge[2], &edge[4].inverse()]),
+    ///         Wire::from_iter(vec![&edge[2].inverse(), &edge[0], &edge[5].inverse()]),
+    ///         Wire::from_iter(vec![&edge[3], &edge[4], &edge[5]]),
+    ///     ];
+    ///     wire.into_iter().map(|w| Face::new(vec![w], ())).collect::<Shell<_, _, _>>()
+    /// };
+    /// let v = Vertex::news(&[(); 8]);
+    /// // the lumped shell of the two tetrahedra
+    /// let mut shell = tetrahedron(&v[..4]);
+    /// shell.append(&mut tetrahedron(&v[4..]));
+    /// let solid = Solid::new_unchecked(vec![shell]);
+    /// assert!(Solid::try_new(solid.boundaries().clone()).is_err());
+    ///
+    /// let lumps = solid.split_lumps();
+    /// assert_eq!(lumps.len(), 2);
+    /// assert!(lumps.into_iter().all(|lump| Solid::try_new(lump.into_boundaries()).is_ok()));
+    /// ```
+    #[inline(always)]
+    pub fn split_lumps(&self) -> Vec<Solid<P, C, S>> { self.split_lumps_with(|_, _| false) }
+
+    /// Splits the solid into the solids of the connected components of the boundaries, keeping
+    /// the cavities in the solids of their outer shells.
+    ///
+    /// `is_cavity(shell, outer)` returns whether `shell` is a cavity of `outer`, e.g. by the
+    /// orientations and the inclusions of the geometries. The components which are not the
+    /// cavities of any others are the outer shells, and each cavity belongs to the solid of the
+    /// first outer shell containing it. The other cavities, e.g. the cavities of the cavities,
+    /// are the outer shells of their own solids.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let tetrahedron = |v: &[Vertex<()>]| {
+    ///     let edge = [
+    ///         Edge::new(&v[0], &v[1], ()),
+    ///         Edge::new(&v[0], &v[2], ()),
+    ///         Edge::new(&v[0], &v[3], ()),
+    ///         Edge::new(&v[1], &v[2], ()),
+    ///         Edge::new(&v[2], &v[3], ()),
+    ///         Edge::new(&v[3], &v[1], ()),
+    ///     ];
+    ///     let wire = vec![
+    ///         Wire::from_iter(vec![&edge[0].inverse(), &edge[1], &edge[3].inverse()]),
+    ///         Wire::from_iter(vec![&edge[1].inverse(), &edge[2], &edge[4].inverse()]),
+    ///         Wire::from_iter(vec![&edge[2].inverse(), &edge[0], &edge[5].inverse()]),
+    ///         Wire::from_iter(vec![&edge[3], &edge[4], &edge[5]]),
+    ///     ];
+    ///     wire.into_iter().map(|w| Face::new(vec![w], ())).collect::<Shell<_, _, _>>()
+    /// };
+    /// let v = Vertex::news(&[(); 12]);
+    /// let mut shell = tetrahedron(&v[..4]);
+    /// shell.append(&mut tetrahedron(&v[4..8]));
+    /// shell.append(&mut tetrahedron(&v[8..]));
+    /// let solid = Solid::new_unchecked(vec![shell]);
+    ///
+    /// // the second tetrahedron is the cavity of the third one.
+    /// let lumps = solid.split_lumps_with(|shell, outer| {
+    ///     shell.vertex_iter().any(|vertex| vertex == v[4])
+    ///         && outer.vertex_iter().any(|vertex| vertex == v[8])
+    /// });
+    /// assert_eq!(lumps.len(), 2);
+    /// assert_eq!(lumps[0].boundaries().len(), 1);
+    /// assert_eq!(lumps[1].boundaries().len(), 2);
+    /// assert!(lumps[1].boundaries()[0].vertex_iter().any(|vertex| vertex == v[8]));
+    /// ```
+    pub fn split_lumps_with(
+        &self,
+        mut is_cavity: impl FnMut(&Shell<P, C, S>, &Shell<P, C, S>) -> bool,
+    ) -> Vec<Solid<P, C, S>> {
+        // the components and their faces in the original order
+        let shells: Vec<Shell<P, C, S>> = self
+            .boundaries
+            .iter()
+            .flat_map(|shell| {
+                let faces = shell.face_iter().enumerate();
+                let index: HashMap<FaceID<S>, usize> =
+                    faces.map(|(i, face)| (face.id(), i)).collect();
+                let mut components = shell.connected_components();
+                for component in &mut components {
+                    component.sort_by_key(|face| index[&face.id()]);
+                }
+                components.sort_by_key(|component| index[&component[0].id()]);
+                components
+            })
+            .collect();
+        let len = shells.len();
+        let containers: Vec<Vec<usize>> = (0..len)
+            .map(|i| {
+                (0..len)
+                    .filter(|j| i != *j && is_cavity(&shells[i], &shells[*j]))
+                    .collect()
+            })
+            .collect();
+        let mut boundaries: Vec<Vec<Shell<P, C, S>>> = (0..len).map(|_| Vec::new()).collect();
+        shells.into_iter().enumerate().for_each(|(i, shell)| {
+            let outer = containers[i].iter().find(|j| containers[**j].is_empty());
+            match outer {
+                Some(j) => boundaries[*j].push(shell),
+                None => boundaries[i].insert(0, shell),
+            }
+        });
+        boundaries
+            .into_iter()
+            .filter(|boundaries| !boundaries.is_empty())
+            .map(Solid::new_unchecked)
+            .collect()
+    }
+
     /// Returns the consistence of the geometry of end vertices
     /// and the geometry of edge.
     #[inline(always)]
@@ -1,4 +1,6 @@
 use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_base::{cgmath64::*, kd_tree::KdTree};
 
 impl<P> Vertex<P> {
     /// constructor
@@ -151,6 +153,39 @@ impl<P> Vertex<P> {
     }
 }
 
+/// Maps the vertices to the representatives of the vertices whose points are nearly identical,
+/// and returns the map and the number of the representatives.
+///
+/// Two points are identified if each of their components differs by at most `tolerance`, and
+/// the representative is the vertex appearing first in `vertices`. The candidates are searched
+/// by the kd-tree in the ball circumscribing the cube of the tolerance.
+pub(super) fn tolerant_vertex_map<P>(
+    vertices: impl IntoIterator<Item = Vertex<P>>,
+    tolerance: f64,
+) -> (HashMap<VertexID<P>, Vertex<P>>, usize)
+where P: Tolerance + Array<Element = f64> + MetricSpace<Metric = f64> + Copy {
+    let mut ids = HashSet::<VertexID<P>>::default();
+    let vertices = vertices.into_iter().filter(|v| ids.insert(v.id()));
+    let vertices: Vec<Vertex<P>> = vertices.collect();
+    let tree: KdTree<P> = vertices.iter().map(Vertex::point).collect();
+    let radius = tolerance * f64::sqrt(P::len() as f64);
+    let mut vertex_map = HashMap::<VertexID<P>, Vertex<P>>::default();
+    let mut representatives = 0;
+    vertices.iter().zip(tree.points()).for_each(|(v, point)| {
+        if vertex_map.contains_key(&v.id()) {
+            return;
+        }
+        representatives += 1;
+        let near = tree.within_radius(*point, radius).into_iter();
+        near.filter(|i| tree.points()[*i].abs_diff_eq(point, tolerance))
+            .for_each(|i| {
+                let entry = vertex_map.entry(vertices[i].id());
+                entry.or_insert_with(|| v.clone());
+            });
+    });
+    (vertex_map, representatives)
+}
+
 impl<P> Clone for Vertex<P> {
     #[inline(always)]
     fn clone(&self) -> Vertex<P> {
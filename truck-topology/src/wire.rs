@@ -1,8 +1,9 @@
 use crate::*;
 use rayon::prelude::*;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::collections::{vec_deque, VecDeque};
 use std::iter::Peekable;
+use truck_base::cgmath64::{Array, EuclideanSpace, InnerSpace, MetricSpace};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 impl<P, C> Wire<P, C> {
@@ -18,6 +19,98 @@ impl<P, C> Wire<P, C> {
         }
     }
 
+    /// Chains the unordered edges end-to-end into wires.
+    ///
+    /// The ends of the edges are identified if their points are nearly identical up to
+    /// `tolerance` in each coordinate, searched by the kd-tree, and the edges are rebuilt on the
+    /// identified vertices with the same curves. The edges are inverted if necessary. The open
+    /// wires are chained first from the ends of paths, and the remaining edges are chained into
+    /// closed wires. If more than two edges meet at a vertex, the way of chaining is not unique.
+    /// # Examples
+    /// ```
+    /// use truck_base::cgmath64::Point1;
+    /// use truck_topology::*;
+    /// let v = [0.0, 1.0, 2.0, 1.0 + 1.0e-8, 2.0 + 1.0e-8, 0.0 - 1.0e-8];
+    /// let v = Vertex::news(v.map(Point1::new));
+    /// let w = Vertex::news([10.0, 11.0, 12.0].map(Point1::new));
+    /// // a triangle whose vertices are duplicated and an open path, in random order.
+    /// let edges = vec![
+    ///     Edge::new(&w[1], &w[2], ()),
+    ///     Edge::new(&v[4], &v[5], ()),
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&w[1], &w[0], ()),
+    ///     Edge::new(&v[2], &v[3], ()).inverse(),
+    /// ];
+    /// let wires = Wire::from_edge_soup(edges, 1.0e-6);
+    /// assert_eq!(wires.len(), 2);
+    /// assert!(!wires[0].is_closed() && wires[0].is_continuous());
+    /// assert_eq!(wires[0].len(), 2);
+    /// assert!(wires[1].is_closed());
+    /// assert_eq!(wires[1].len(), 3);
+    /// ```
+    pub fn from_edge_soup<I>(edges: I, tolerance: f64) -> Vec<Wire<P, C>>
+    where
+        I: IntoIterator<Item = Edge<P, C>>,
+        P: Tolerance + Array<Element = f64> + MetricSpace<Metric = f64> + Copy,
+        C: Clone, {
+        let edges: Vec<Edge<P, C>> = edges.into_iter().collect();
+        let vertices = edges
+            .iter()
+            .flat_map(|edge| [edge.absolute_front().clone(), edge.absolute_back().clone()]);
+        let (vertex_map, _) = vertex::tolerant_vertex_map(vertices, tolerance);
+        let mut edge_map = HashMap::<EdgeID<C>, Edge<P, C>>::default();
+        let edges: Vec<Edge<P, C>> = edges
+            .into_iter()
+            .map(|edge| {
+                let new_edge = edge_map.entry(edge.id()).or_insert_with(|| {
+                    let (v0, v1) = (edge.absolute_front(), edge.absolute_back());
+                    let (w0, w1) = (&vertex_map[&v0.id()], &vertex_map[&v1.id()]);
+                    match v0 == w0 && v1 == w1 {
+                        true => edge.absolute_clone(),
+                        false => Edge::new_unchecked(w0, w1, edge.curve()),
+                    }
+                });
+                match edge.orientation() {
+                    true => new_edge.clone(),
+                    false => new_edge.inverse(),
+                }
+            })
+            .collect();
+        let mut adjacency = HashMap::<VertexID<P>, Vec<usize>>::default();
+        edges.iter().enumerate().for_each(|(i, edge)| {
+            adjacency.entry(edge.front().id()).or_default().push(i);
+            adjacency.entry(edge.back().id()).or_default().push(i);
+        });
+        let path_ends = edges
+            .iter()
+            .flat_map(|edge| [edge.front().id(), edge.back().id()])
+            .filter(|id| adjacency[id].len() % 2 == 1)
+            .collect::<Vec<_>>();
+        let others = edges.iter().map(|edge| edge.front().id());
+        let mut used = vec![false; edges.len()];
+        path_ends
+            .into_iter()
+            .chain(others)
+            .filter_map(|start| {
+                let mut wire = Wire::new();
+                let mut current = start;
+                while let Some(i) = adjacency[&current].iter().copied().find(|i| !used[*i]) {
+                    used[i] = true;
+                    let edge = match edges[i].front().id() == current {
+                        true => edges[i].clone(),
+                        false => edges[i].inverse(),
+                    };
+                    current = edge.back().id();
+                    wire.push_back(edge);
+                }
+                match wire.is_empty() {
+                    true => None,
+                    false => Some(wire),
+                }
+            })
+            .collect()
+    }
+
     /// Returns an iterator over the edges. Practically, an alias of `iter()`.
     #[inline(always)]
     pub fn edge_iter(&self) -> EdgeIter<'_, P, C> { self.iter() }
@@ -497,6 +590,178 @@ impl<P, C> Wire<P, C> {
         self.iter().all(|edge| edge.is_geometric_consistent())
     }
 
+    /// Returns the sum of the lengths of the edges by [`Edge::length`].
+    #[inline(always)]
+    pub fn length(&self) -> f64
+    where
+        C: BoundedCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        self.iter().map(Edge::length).sum()
+    }
+
+    /// Returns the crossings of the edges of the wire.
+    ///
+    /// Each crossing is returned as the pair of `(index of edge, parameter)`, where the parameter
+    /// is of the absolute curve [`Edge::curve`]. The pairs are sorted so that the first edge
+    /// index is not more than the second one. The points shared as vertices by the edges, e.g.
+    /// the ends of adjacent edges, are not regarded as crossings.
+    ///
+    /// The candidates are found by the polylines given by `ParameterDivision1D` with `tol`, and
+    /// refined by Newton's method. The tangential contacts and the overlaps of edges may not be
+    /// detected.
+    pub fn self_intersections(&self, tol: f64) -> Vec<((usize, f64), (usize, f64))>
+    where
+        P: EuclideanSpace<Scalar = f64> + Tolerance,
+        P::Diff: InnerSpace<Scalar = f64>,
+        C: Clone
+            + ParametricCurve<Point = P, Vector = P::Diff>
+            + BoundedCurve
+            + ParameterDivision1D<Point = P>, {
+        let curves: Vec<C> = self.edge_iter().map(|edge| edge.curve()).collect();
+        let divisions: Vec<(Vec<f64>, Vec<P>)> = curves
+            .iter()
+            .map(|curve| curve.parameter_division(curve.parameter_range(), tol))
+            .collect();
+        // the pairs of the segments of the polylines whose ranges of the first coordinates
+        // overlap, found by sweeping the sorted segments
+        let mut segments: Vec<(usize, usize)> = divisions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (_, pts))| (0..pts.len().saturating_sub(1)).map(move |a| (i, a)))
+            .collect();
+        let range = |(i, a): (usize, usize)| {
+            let (x0, x1) = (divisions[i].1[a][0], divisions[i].1[a + 1][0]);
+            (f64::min(x0, x1) - tol, f64::max(x0, x1) + tol)
+        };
+        segments.sort_by(|x, y| {
+            let (x, y) = (range(*x).0, range(*y).0);
+            x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let mut pairs = Vec::<((usize, usize), (usize, usize))>::new();
+        segments.iter().enumerate().for_each(|(k, x)| {
+            let end = range(*x).1;
+            segments[k + 1..]
+                .iter()
+                .take_while(|y| range(**y).0 <= end)
+                .for_each(|y| {
+                    let (x, y) = match x < y {
+                        true => (*x, *y),
+                        false => (*y, *x),
+                    };
+                    // the adjacent segments of the same edge always share the end point
+                    if x.0 != y.0 || x.1 + 1 < y.1 {
+                        pairs.push((x, y));
+                    }
+                });
+        });
+        pairs.sort();
+        let mut res = Vec::<((usize, f64), (usize, f64))>::new();
+        for ((i, a), (j, b)) in pairs {
+            let ((params0, pts0), (params1, pts1)) = (&divisions[i], &divisions[j]);
+            let shared_vertices: Vec<P> = [self[i].absolute_front(), self[i].absolute_back()]
+                .into_iter()
+                .filter(|v| *v == self[j].absolute_front() || *v == self[j].absolute_back())
+                .map(|v| v.point())
+                .collect();
+            let (sigma, tau) =
+                closest_parameters_of_segments([pts0[a], pts0[a + 1]], [pts1[b], pts1[b + 1]]);
+            let p = pts0[a] + (pts0[a + 1] - pts0[a]) * sigma;
+            let q = pts1[b] + (pts1[b + 1] - pts1[b]) * tau;
+            if (p - q).magnitude() > 2.0 * tol {
+                continue;
+            }
+            let hint = (
+                params0[a] + (params0[a + 1] - params0[a]) * sigma,
+                params1[b] + (params1[b + 1] - params1[b]) * tau,
+            );
+            let (s, t) = match newton_crossing(&curves[i], &curves[j], hint) {
+                Some(got) => got,
+                None => continue,
+            };
+            let (s, t) = match i == j && t < s {
+                true => (t, s),
+                false => (s, t),
+            };
+            let point = curves[i].subs(s);
+            let trivial = (i == j && s.near(&t)) || shared_vertices.iter().any(|v| v.near(&point));
+            let duplicated = res
+                .iter()
+                .any(|((i0, s0), (j0, t0))| *i0 == i && *j0 == j && s0.near(&s) && t0.near(&t));
+            if !trivial && !duplicated {
+                res.push(((i, s), (j, t)));
+            }
+        }
+        res
+    }
+
+    /// Splits the edges at the crossings given by [`Wire::self_intersections`], and returns the
+    /// vertices at the crossings.
+    ///
+    /// Each crossing becomes one vertex shared by the split edges. If a crossing is at an end of
+    /// an edge, the end vertex is used and the edge is not split. The edges of the wire are
+    /// replaced by new edges, so the faces or the other wires sharing the split edges are not
+    /// changed.
+    pub fn resolve_self_intersections(&mut self, tol: f64) -> Vec<Vertex<P>>
+    where
+        P: EuclideanSpace<Scalar = f64> + Tolerance,
+        P::Diff: InnerSpace<Scalar = f64>,
+        C: Clone
+            + Cut<Point = P, Vector = P::Diff>
+            + BoundedCurve
+            + ParameterDivision1D<Point = P>, {
+        let intersections = self.self_intersections(tol);
+        let end_vertex = |edge: &Edge<P, C>, t: f64| {
+            let (t0, t1) = edge.curve().parameter_range();
+            if t < t0 + TOLERANCE {
+                Some(edge.absolute_front().clone())
+            } else if t1 - TOLERANCE < t {
+                Some(edge.absolute_back().clone())
+            } else {
+                None
+            }
+        };
+        let mut cuts = vec![Vec::<(f64, Vertex<P>)>::new(); self.len()];
+        let vertices: Vec<Vertex<P>> = intersections
+            .into_iter()
+            .map(|((i, s), (j, t))| {
+                let vertex = end_vertex(&self[i], s)
+                    .or_else(|| end_vertex(&self[j], t))
+                    .unwrap_or_else(|| Vertex::new(self[i].curve().subs(s)));
+                cuts[i].push((s, vertex.clone()));
+                cuts[j].push((t, vertex.clone()));
+                vertex
+            })
+            .collect();
+        *self = self
+            .edge_iter()
+            .zip(cuts)
+            .flat_map(|(edge, mut cuts)| {
+                cuts.sort_by(|x, y| x.0.total_cmp(&y.0));
+                let mut pieces = Vec::new();
+                let rest = cuts
+                    .into_iter()
+                    .fold(edge.absolute_clone(), |rest, (t, v)| {
+                        match rest.cut_with_parameter(&v, t) {
+                            Some((edge0, edge1)) => {
+                                pieces.push(edge0);
+                                edge1
+                            }
+                            None => rest,
+                        }
+                    });
+                pieces.push(rest);
+                if !edge.orientation() {
+                    pieces.reverse();
+                    pieces.iter_mut().for_each(|piece| {
+                        piece.invert();
+                    });
+                }
+                pieces
+            })
+            .collect();
+        vertices
+    }
+
     /// Creates display struct for debugging the wire.
     /// # Examples
     /// ```
@@ -534,6 +799,53 @@ impl<P, C> Wire<P, C> {
     }
 }
 
+/// the parameters in `[0, 1]` of the nearest points on two segments
+fn closest_parameters_of_segments<P>(p: [P; 2], q: [P; 2]) -> (f64, f64)
+where
+    P: EuclideanSpace<Scalar = f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let (u, v, w) = (p[1] - p[0], q[1] - q[0], p[0] - q[0]);
+    let (a, b, c) = (u.dot(u), u.dot(v), v.dot(v));
+    let (d, e) = (u.dot(w), v.dot(w));
+    let det = a * c - b * b;
+    let project = |x: f64, y: f64| match y.so_small() {
+        true => 0.0,
+        false => f64::clamp(x / y, 0.0, 1.0),
+    };
+    let s = project(b * e - c * d, det);
+    let t = project(b * s + e, c);
+    (project(b * t - d, a), t)
+}
+
+/// the parameters of the crossing of two curves by Newton's method
+fn newton_crossing<P, C>(curve0: &C, curve1: &C, (mut s, mut t): (f64, f64)) -> Option<(f64, f64)>
+where
+    P: EuclideanSpace<Scalar = f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+    C: ParametricCurve<Point = P, Vector = P::Diff> + BoundedCurve, {
+    let ((s0, s1), (t0, t1)) = (curve0.parameter_range(), curve1.parameter_range());
+    for _ in 0..SEARCH_PARAMETER_TRIALS {
+        let diff = curve0.subs(s) - curve1.subs(t);
+        let (der0, der1) = (curve0.der(s), curve1.der(t));
+        let (a, b, c) = (der0.dot(der0), der0.dot(der1), der1.dot(der1));
+        let (e, f) = (der0.dot(diff), der1.dot(diff));
+        let det = a * c - b * b;
+        if det.so_small() {
+            return None;
+        }
+        let (ds, dt) = ((b * f - c * e) / det, (a * f - b * e) / det);
+        s = f64::clamp(s + ds, s0, s1);
+        t = f64::clamp(t + dt, t0, t1);
+        if ds.so_small2() && dt.so_small2() {
+            break;
+        }
+    }
+    match curve0.subs(s).near(&curve1.subs(t)) {
+        true => Some((s, t)),
+        false => None,
+    }
+}
+
 type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Option<Edge<Q, D>>, KF, KV, &'a Edge<P, C>>;
 type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
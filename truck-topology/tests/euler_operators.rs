@@ -98,6 +98,17 @@ impl SearchParameter<D1> for Segment {
     }
 }
 
+impl ParameterDivision1D for Segment {
+    type Point = Point3;
+    #[inline(always)]
+    fn parameter_division(&self, range: (f64, f64), _: f64) -> (Vec<f64>, Vec<Point3>) {
+        (
+            vec![range.0, range.1],
+            vec![self.subs(range.0), self.subs(range.1)],
+        )
+    }
+}
+
 impl Invertible for Segment {
     #[inline(always)]
     fn invert(&mut self) {
@@ -199,3 +210,35 @@ fn solid_cut_edge() {
     let count = tri.edge_iter().count();
     assert_eq!(count, 12);
 }
+
+#[test]
+fn resolve_bowtie() {
+    let v = Vertex::news([
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ]);
+    let mut wire: Wire<Point3, Segment> = (0..4)
+        .map(|i| {
+            let (v0, v1) = (&v[i], &v[(i + 1) % 4]);
+            Edge::new(v0, v1, Segment::new(v0.point(), v1.point()))
+        })
+        .collect();
+    let intersections = wire.self_intersections(0.01);
+    assert_eq!(intersections.len(), 1);
+    let ((i, s), (j, t)) = intersections[0];
+    assert_eq!((i, j), (0, 2));
+    assert_near!(s, 0.5);
+    assert_near!(t, 0.5);
+
+    let vertices = wire.resolve_self_intersections(0.01);
+    assert_eq!(vertices.len(), 1);
+    assert_near!(vertices[0].point(), Point3::new(0.5, 0.5, 0.0));
+    assert_eq!(wire.len(), 6);
+    assert!(wire.is_closed());
+    assert!(wire.is_geometric_consistent());
+    assert_eq!(wire[0].back(), &vertices[0]);
+    assert_eq!(wire[4].front(), &vertices[0]);
+    assert!(wire.self_intersections(0.01).is_empty());
+}
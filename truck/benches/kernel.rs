@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use truck::meshalgo::prelude::*;
+
+fn tessellation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tessellation");
+    group.sample_size(10);
+    for (name, solid) in truck::bench::models() {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &solid, |b, solid| {
+            b.iter(|| solid.triangulation(0.01).to_polygon())
+        });
+    }
+    group.finish();
+}
+
+fn shell_mapping(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shell mapping");
+    for (name, solid) in truck::bench::models() {
+        let shell = &solid.boundaries()[0];
+        group.bench_with_input(BenchmarkId::from_parameter(name), shell, |b, shell| {
+            b.iter(|| shell.mapped(Clone::clone, Clone::clone, Clone::clone))
+        });
+    }
+    group.finish();
+}
+
+fn surface_evaluation(c: &mut Criterion) {
+    c.bench_function("surface evaluation", |b| {
+        b.iter_custom(|iters| truck::bench::surface_evaluation(iters as usize, 100).total())
+    });
+}
+
+fn boolean(c: &mut Criterion) {
+    let mut group = c.benchmark_group("boolean");
+    group.sample_size(10);
+    group.bench_function("and and or", |b| {
+        b.iter_custom(|iters| {
+            let timings = truck::bench::boolean(iters as usize, 0.05);
+            timings.iter().map(truck::bench::Timing::total).sum()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    surface_evaluation,
+    tessellation,
+    shell_mapping,
+    boolean
+);
+criterion_main!(benches);
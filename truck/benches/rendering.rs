@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use truck::platform::{DeviceHandler, Scene};
+
+fn rendering(c: &mut Criterion) {
+    let handler = pollster::block_on(DeviceHandler::default_device());
+    let mut scene = Scene::new(handler, &Default::default());
+    let mut group = c.benchmark_group("rendering");
+    group.sample_size(10);
+    group.bench_function("models", |b| {
+        b.iter_custom(|iters| {
+            let timings = truck::bench::rendering(&mut scene, iters as usize, 0.01);
+            timings.iter().map(truck::bench::Timing::total).sum()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, rendering);
+criterion_main!(benches);
@@ -0,0 +1,242 @@
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use truck_meshalgo::prelude::*;
+use truck_modeling::{builder, datasets, BoundingBox, Point3, Solid, Surface};
+#[cfg(feature = "rendimpl")]
+use truck_platform::{wgpu::Maintain, Scene};
+#[cfg(feature = "rendimpl")]
+use truck_rendimpl::{CreatorCreator, PolygonInstance};
+
+/// The timing of a benchmark
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timing {
+    name: String,
+    durations: Vec<Duration>,
+}
+
+impl Timing {
+    /// Returns the name of the benchmark.
+    #[inline(always)]
+    pub fn name(&self) -> &str { &self.name }
+    /// Returns the duration of each iteration.
+    #[inline(always)]
+    pub fn durations(&self) -> &[Duration] { &self.durations }
+    /// Returns the number of iterations.
+    #[inline(always)]
+    pub fn iterations(&self) -> usize { self.durations.len() }
+    /// Returns the total duration of the iterations.
+    #[inline(always)]
+    pub fn total(&self) -> Duration { self.durations.iter().sum() }
+    /// Returns the mean duration of the iterations. Returns zero if there is no iteration.
+    pub fn mean(&self) -> Duration {
+        match self.durations.len() {
+            0 => Duration::ZERO,
+            len => self.total() / len as u32,
+        }
+    }
+    /// Returns the shortest duration of the iterations.
+    #[inline(always)]
+    pub fn min(&self) -> Option<Duration> { self.durations.iter().min().copied() }
+    /// Returns the longest duration of the iterations.
+    #[inline(always)]
+    pub fn max(&self) -> Option<Duration> { self.durations.iter().max().copied() }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: mean {:?}, min {:?}, max {:?} ({} iterations)",
+            self.name,
+            self.mean(),
+            self.min().unwrap_or_default(),
+            self.max().unwrap_or_default(),
+            self.iterations(),
+        )
+    }
+}
+
+/// Runs `routine` `iterations` times and measures each run.
+///
+/// The result of `routine` is passed to [`black_box`], so the computation is not optimized out.
+/// # Examples
+/// ```
+/// let timing = truck::bench::measure("sum", 3, || (0..1000).sum::<usize>());
+/// assert_eq!(timing.name(), "sum");
+/// assert_eq!(timing.iterations(), 3);
+/// assert!(timing.min() <= timing.max());
+/// ```
+pub fn measure<T>(
+    name: impl Into<String>,
+    iterations: usize,
+    mut routine: impl FnMut() -> T,
+) -> Timing {
+    let durations = (0..iterations)
+        .map(|_| {
+            let instant = Instant::now();
+            black_box(routine());
+            instant.elapsed()
+        })
+        .collect();
+    Timing {
+        name: name.into(),
+        durations,
+    }
+}
+
+/// The models of [`truck_modeling::datasets`] at the scale used by the benchmarks
+pub fn models() -> Vec<(&'static str, Solid)> {
+    vec![
+        ("gear", datasets::gear(48)),
+        ("turbine blade", datasets::turbine_blade(16)),
+        ("lattice bracket", datasets::lattice_bracket(8)),
+    ]
+}
+
+/// Measures the evaluation of the points, the derivatives, and the normals of the free-form
+/// surfaces of the turbine blade on the `division` x `division` grid.
+/// # Examples
+/// ```
+/// let timing = truck::bench::surface_evaluation(2, 10);
+/// assert_eq!(timing.iterations(), 2);
+/// ```
+pub fn surface_evaluation(iterations: usize, division: usize) -> Timing {
+    let blade = datasets::turbine_blade(16);
+    let surfaces = blade
+        .face_iter()
+        .filter_map(|face| match face.oriented_surface() {
+            Surface::NurbsSurface(surface) => Some(surface),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let params = (0..=division)
+        .flat_map(|i| (0..=division).map(move |j| (i, j)))
+        .map(|(i, j)| (i as f64 / division as f64, j as f64 / division as f64))
+        .collect::<Vec<_>>();
+    measure("surface evaluation", iterations, || {
+        surfaces.iter().fold(0.0, |sum, surface| {
+            let ((u0, u1), (v0, v1)) = surface.parameter_range();
+            params.iter().fold(sum, |sum, (s, t)| {
+                let (u, v) = (u0 + (u1 - u0) * s, v0 + (v1 - v0) * t);
+                let pt = surface.subs(u, v);
+                let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+                sum + pt.x + uder.y + vder.z + surface.normal(u, v).x
+            })
+        })
+    })
+}
+
+/// Measures the tessellation of each model of [`models`] with tolerance `tol`.
+/// # Examples
+/// ```
+/// let timings = truck::bench::tessellation(1, 0.05);
+/// assert_eq!(timings.len(), 3);
+/// assert_eq!(timings[0].name(), "tessellation: gear");
+/// ```
+pub fn tessellation(iterations: usize, tol: f64) -> Vec<Timing> {
+    models()
+        .into_iter()
+        .map(|(name, solid)| {
+            measure(format!("tessellation: {name}"), iterations, || {
+                solid.triangulation(tol).to_polygon()
+            })
+        })
+        .collect()
+}
+
+/// Measures the mapping of the boundary shells of each model of [`models`], which clones all the
+/// geometries and reconstructs the topology.
+/// # Examples
+/// ```
+/// let timings = truck::bench::shell_mapping(1);
+/// assert_eq!(timings.len(), 3);
+/// ```
+pub fn shell_mapping(iterations: usize) -> Vec<Timing> {
+    models()
+        .into_iter()
+        .map(|(name, solid)| {
+            let shell = &solid.boundaries()[0];
+            measure(format!("shell mapping: {name}"), iterations, || {
+                shell.mapped(Clone::clone, Clone::clone, Clone::clone)
+            })
+        })
+        .collect()
+}
+
+/// Measures the AND and the OR of each model of [`models`] and the box which covers the part of
+/// the model on the negative side of the plane `x = x0 + 0.37 * (x1 - x0)`, where `x0` and `x1`
+/// are the bounds of the model.
+///
+/// The booleans which fail are also measured, and the harness does not check the results.
+/// # Examples
+/// ```
+/// let timings = truck::bench::boolean(1, 0.05);
+/// assert_eq!(timings.len(), 6);
+/// assert_eq!(timings[0].name(), "and: gear");
+/// assert_eq!(timings[1].name(), "or: gear");
+/// ```
+pub fn boolean(iterations: usize, tol: f64) -> Vec<Timing> {
+    models()
+        .into_iter()
+        .flat_map(|(name, solid)| {
+            let bdd = solid.boundaries()[0]
+                .vertex_iter()
+                .map(|v| v.point())
+                .collect::<BoundingBox<Point3>>();
+            let (min, max) = (*bdd.min(), *bdd.max());
+            let x = min.x + 0.37 * (max.x - min.x);
+            let margin = 0.1 * bdd.diameter();
+            let corner0 = Point3::new(min.x - margin, min.y - margin, min.z - margin);
+            let corner1 = Point3::new(x, max.y + margin, max.z + margin);
+            let tool = builder::cuboid(corner0, corner1);
+            [
+                measure(format!("and: {name}"), iterations, || {
+                    truck_shapeops::and(&solid, &tool, tol)
+                }),
+                measure(format!("or: {name}"), iterations, || {
+                    truck_shapeops::or(&solid, &tool, tol)
+                }),
+            ]
+        })
+        .collect()
+}
+
+/// Measures the rendering of each model of [`models`] tessellated with tolerance `tol`.
+///
+/// Each model is rendered alone in `scene`, and each iteration renders one frame to the texture
+/// compatible with the scene and waits until the device finishes. The objects of `scene` are
+/// cleared before and after each model.
+#[cfg(feature = "rendimpl")]
+pub fn rendering(scene: &mut Scene, iterations: usize, tol: f64) -> Vec<Timing> {
+    let creator = scene.instance_creator();
+    let texture = scene.compatible_texture();
+    let view = texture.create_view(&Default::default());
+    models()
+        .into_iter()
+        .map(|(name, solid)| {
+            let mesh = solid.triangulation(tol).to_polygon();
+            let instance: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+            scene.clear_objects();
+            scene.add_object(&instance);
+            let timing = measure(format!("rendering: {name}"), iterations, || {
+                scene.render(&view);
+                scene.device().poll(Maintain::Wait)
+            });
+            scene.clear_objects();
+            timing
+        })
+        .collect()
+}
+
+/// Runs all benchmarks of this module with the default settings, except for `rendering` which
+/// needs a scene on the device.
+///
+/// The timings can be printed by `Display` and attached to performance reports.
+pub fn run_all(iterations: usize) -> Vec<Timing> {
+    let mut timings = vec![surface_evaluation(iterations, 100)];
+    timings.extend(tessellation(iterations, 0.01));
+    timings.extend(shell_mapping(iterations));
+    timings.extend(boolean(iterations, 0.05));
+    timings
+}
@@ -0,0 +1,206 @@
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+/// the cosine of the maximum angle between the normal of a triangle and the reversed normal of
+/// the triangle touching it
+const PARALLEL_COS: f64 = 0.9;
+
+/// The kind of the contact of the faces
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContactKind {
+    /// the parallel planar faces touching each other
+    Parallel,
+    /// the coincident faces of the curved surfaces, e.g. a shaft in a hole
+    Coincident,
+}
+
+/// The pair of the touching faces of two solids
+///
+/// The contacts are the inputs of the assembly constraints: a parallel contact implies a planar
+/// mate in the direction of `normal`, and a coincident one implies e.g. a concentric mate.
+#[derive(Clone, Debug)]
+pub struct Contact {
+    /// the faces of the first and the second solids
+    pub faces: (Face, Face),
+    /// the kind of the contact
+    pub kind: ContactKind,
+    /// the area of the contact region
+    pub area: f64,
+    /// the centroid of the contact region
+    pub centroid: Point3,
+    /// the area-weighted average of the unit normals of the contact region, pointing from the
+    /// first solid to the second. The length is less than one on the curved contacts.
+    pub normal: Vector3,
+}
+
+/// a triangle of the tessellation of a face
+#[derive(Clone, Copy, Debug)]
+struct Triangle {
+    points: [Point3; 3],
+    normal: Vector3,
+}
+
+/// The tessellated face
+#[derive(Clone, Debug)]
+struct MeshedFace {
+    face: Face,
+    triangles: Vec<Triangle>,
+    bounding_box: BoundingBox<Point3>,
+}
+
+fn meshed_faces(shell: &Shell, tol: f64) -> Vec<MeshedFace> {
+    let meshed = shell.triangulation(tol);
+    shell
+        .face_iter()
+        .zip(meshed.face_iter())
+        .filter_map(|(face, meshed)| {
+            let mesh = meshed.surface()?;
+            let positions = mesh.positions();
+            let triangles = mesh
+                .faces()
+                .triangle_iter()
+                .filter_map(|tri| {
+                    let points = tri.map(|v| positions[v.pos]);
+                    let normal = (points[1] - points[0]).cross(points[2] - points[0]);
+                    match normal.so_small() {
+                        true => None,
+                        false => Some(Triangle {
+                            points,
+                            normal: normal.normalize(),
+                        }),
+                    }
+                })
+                .collect();
+            Some(MeshedFace {
+                face: face.clone(),
+                triangles,
+                bounding_box: mesh.bounding_box(),
+            })
+        })
+        .collect()
+}
+
+/// Clips the convex `polygon` on the plane with the normal `normal` by the half space bounded by
+/// the line `p`-`q` and containing `r`.
+fn clip(polygon: &[Point3], (p, q, r): (Point3, Point3, Point3), normal: Vector3) -> Vec<Point3> {
+    let side = |x: Point3| (q - p).cross(x - p).dot(normal);
+    let sign = side(r).signum();
+    let len = polygon.len();
+    let mut res = Vec::with_capacity(len + 1);
+    (0..len).for_each(|i| {
+        let (x, y) = (polygon[i], polygon[(i + 1) % len]);
+        let (dx, dy) = (side(x) * sign, side(y) * sign);
+        if dx >= 0.0 {
+            res.push(x);
+        }
+        if (dx >= 0.0) != (dy >= 0.0) {
+            res.push(x + (y - x) * (dx / (dx - dy)));
+        }
+    });
+    res
+}
+
+/// Returns the area and the centroid of the region of `a` touched by `b`.
+fn overlap(a: &Triangle, b: &Triangle, tol: f64) -> Option<(f64, Point3)> {
+    if a.normal.dot(b.normal) > -PARALLEL_COS {
+        return None;
+    }
+    let height = |x: &Point3| (*x - a.points[0]).dot(a.normal);
+    if b.points.iter().any(|x| f64::abs(height(x)) > 2.0 * tol) {
+        return None;
+    }
+    let projected = b.points.map(|x| x - a.normal * height(&x));
+    let edges = [(0, 1, 2), (1, 2, 0), (2, 0, 1)];
+    let polygon = edges.iter().fold(a.points.to_vec(), |polygon, (i, j, k)| {
+        let edge = (projected[*i], projected[*j], projected[*k]);
+        clip(&polygon, edge, a.normal)
+    });
+    let fan = polygon.windows(2).skip(1);
+    let (area, moment) = fan.fold((0.0, Vector3::zero()), |(area, moment), w| {
+        let (p, q, r) = (polygon[0], w[0], w[1]);
+        let tri_area = (q - p).cross(r - p).magnitude() / 2.0;
+        let centroid = (p.to_vec() + q.to_vec() + r.to_vec()) / 3.0;
+        (area + tri_area, moment + centroid * tri_area)
+    });
+    match area.so_small() {
+        true => None,
+        false => Some((area, Point3::from_vec(moment / area))),
+    }
+}
+
+/// Returns the contact of the faces if they touch in an area larger than `tol * tol`.
+fn face_contact(a: &MeshedFace, b: &MeshedFace, tol: f64) -> Option<Contact> {
+    let (bdb0, bdb1) = (&a.bounding_box, &b.bounding_box);
+    let separated = (0..3).any(|k| {
+        bdb0.min()[k] > bdb1.max()[k] + 2.0 * tol || bdb1.min()[k] > bdb0.max()[k] + 2.0 * tol
+    });
+    if separated {
+        return None;
+    }
+    let mut area = 0.0;
+    let (mut moment, mut normal) = (Vector3::zero(), Vector3::zero());
+    a.triangles.iter().for_each(|tri0| {
+        b.triangles.iter().for_each(|tri1| {
+            if let Some((tri_area, centroid)) = overlap(tri0, tri1, tol) {
+                area += tri_area;
+                moment += centroid.to_vec() * tri_area;
+                normal += tri0.normal * tri_area;
+            }
+        })
+    });
+    if area <= tol * tol {
+        return None;
+    }
+    let planar = |face: &Face| matches!(face.surface(), Surface::Plane(_));
+    let kind = match planar(&a.face) && planar(&b.face) {
+        true => ContactKind::Parallel,
+        false => ContactKind::Coincident,
+    };
+    Some(Contact {
+        faces: (a.face.clone(), b.face.clone()),
+        kind,
+        area,
+        centroid: Point3::from_vec(moment / area),
+        normal: normal / area,
+    })
+}
+
+/// Returns the contacts of the faces of the shells.
+pub(crate) fn shell_contacts(a: &Shell, b: &Shell, tol: f64) -> Vec<Contact> {
+    let (faces0, faces1) = (meshed_faces(a, tol), meshed_faces(b, tol));
+    faces0
+        .iter()
+        .flat_map(|a| faces1.iter().filter_map(move |b| face_contact(a, b, tol)))
+        .collect()
+}
+
+/// Returns the pairs of the faces of `a` and `b` touching each other.
+///
+/// The faces are tessellated with `tol`, and the triangles are touching if their normals are
+/// opposite and their distance is at most `2 * tol`. The contact regions are the overlaps of
+/// the touching triangles.
+/// # Examples
+/// ```
+/// use truck::contact::{self, ContactKind};
+/// use truck::modeling::*;
+///
+/// // a block on a plate
+/// let plate = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 1.0));
+/// let block = builder::cuboid(Point3::new(3.0, 1.0, 1.0), Point3::new(5.0, 3.0, 2.0));
+///
+/// let contacts = contact::find_contacts(&plate, &block, 0.01);
+/// assert_eq!(contacts.len(), 1);
+/// assert_eq!(contacts[0].kind, ContactKind::Parallel);
+/// // the block overhangs the plate.
+/// assert_near!(contacts[0].area, 2.0);
+/// assert_near!(contacts[0].centroid, Point3::new(3.5, 2.0, 1.0));
+/// assert_near!(contacts[0].normal, Vector3::unit_z());
+/// ```
+pub fn find_contacts(a: &Solid, b: &Solid, tol: f64) -> Vec<Contact> {
+    let mut contacts = Vec::new();
+    a.boundaries().iter().for_each(|a| {
+        let shells = b.boundaries().iter();
+        shells.for_each(|b| contacts.extend(shell_contacts(a, b, tol)));
+    });
+    contacts
+}
@@ -0,0 +1,242 @@
+use std::fmt::{Debug, Display, Formatter};
+use thiserror::Error;
+use truck_base::id::ID;
+
+/// The unified error of truck crates
+#[derive(Debug, Error)]
+pub enum Error {
+    /// wrapper of topological error
+    #[error(transparent)]
+    Topology(#[from] truck_topology::errors::Error),
+    /// wrapper of geometrical error
+    #[error(transparent)]
+    Geometry(#[from] truck_geometry::errors::Error),
+    /// wrapper of modeling error. The topological errors in the modeling errors are unwrapped
+    /// into [`Error::Topology`].
+    #[error(transparent)]
+    Modeling(truck_modeling::errors::Error),
+    /// wrapper of polygon mesh error
+    #[error(transparent)]
+    Mesh(#[from] truck_polymesh::errors::Error),
+    /// failed to concat curves. The points in the error are formatted by `Debug`.
+    #[error("{0}")]
+    Concat(String),
+    /// the node is not in the graph
+    #[error("the node {0} is not in the graph.")]
+    UnknownNode(usize),
+    /// the input of the node does not match the operation
+    #[error("the operation {operation} requires {expected}.")]
+    InvalidInput {
+        /// the name of the operation
+        operation: String,
+        /// the description of the required input
+        expected: String,
+    },
+    /// the boolean operation failed
+    #[error("the boolean operation failed.")]
+    BooleanOperationFailed,
+    /// the profile of the rib is not an open wire of lines
+    #[error("the profile of the rib must be an open wire of lines.")]
+    InvalidRibProfile,
+    /// the material preset is not found
+    #[error("the material preset \"{0}\" is not found.")]
+    UnknownMaterial(String),
+    /// the measurement is not defined for the entities
+    #[error("invalid measurement: {0}.")]
+    InvalidMeasurement(String),
+    /// the sheet metal part or its operation is invalid
+    #[error("invalid sheet metal: {0}.")]
+    InvalidSheetMetal(String),
+    /// the face cannot be flattened
+    #[error("the face cannot be flattened: {0}.")]
+    InvalidFlattening(String),
+    /// the mesh cannot be fitted by the surfaces
+    #[error("the mesh cannot be fitted: {0}.")]
+    InvalidFitting(String),
+    /// wrapper of STEP error
+    #[cfg(feature = "stepio")]
+    #[error(transparent)]
+    Step(#[from] ruststep::error::Error),
+    /// failed to parse the entities of STEP. The original error is formatted by `Display`.
+    #[cfg(feature = "stepio")]
+    #[error("failed to parse STEP: {0}")]
+    StepParse(String),
+    /// wrapper of JSON error
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// wrapper of IO error
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// the error with the context where it occurred
+    #[error("{context}: {source}")]
+    WithContext {
+        /// the context of the error
+        context: Context,
+        /// the original error
+        source: Box<Error>,
+    },
+}
+
+/// The context of an error: the entity or the parameter of the failed operation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Context {
+    /// the operation on the vertex with the id
+    Vertex(String),
+    /// the operation on the edge with the id
+    Edge(String),
+    /// the operation on the face with the id
+    Face(String),
+    /// the operation at the parameter of a curve
+    Parameter(f64),
+    /// the operation at the parameters of a surface
+    Parameters(f64, f64),
+    /// the operation described by the message
+    Message(String),
+}
+
+impl Context {
+    /// Creates the context of the vertex with `id`.
+    /// # Examples
+    /// ```
+    /// use truck::{modeling::*, Context};
+    /// let v = builder::vertex(Point3::origin());
+    /// let w = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let edge = builder::line(&v, &w);
+    /// assert!(matches!(Context::vertex(v.id()), Context::Vertex(_)));
+    /// assert!(matches!(Context::edge(edge.id()), Context::Edge(_)));
+    /// ```
+    #[inline(always)]
+    pub fn vertex<T>(id: ID<T>) -> Self { Context::Vertex(format!("{id:?}")) }
+    /// Creates the context of the edge with `id`.
+    #[inline(always)]
+    pub fn edge<T>(id: ID<T>) -> Self { Context::Edge(format!("{id:?}")) }
+    /// Creates the context of the face with `id`.
+    #[inline(always)]
+    pub fn face<T>(id: ID<T>) -> Self { Context::Face(format!("{id:?}")) }
+    /// Creates the context described by `message`.
+    #[inline(always)]
+    pub fn message(message: impl Into<String>) -> Self { Context::Message(message.into()) }
+}
+
+impl Display for Context {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Context::Vertex(id) => write!(f, "at the vertex {id}"),
+            Context::Edge(id) => write!(f, "at the edge {id}"),
+            Context::Face(id) => write!(f, "at the face {id}"),
+            Context::Parameter(t) => write!(f, "at the parameter t = {t}"),
+            Context::Parameters(u, v) => write!(f, "at the parameters (u, v) = ({u}, {v})"),
+            Context::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error {
+    /// Adds `context` to the error.
+    #[inline(always)]
+    pub fn context(self, context: Context) -> Self {
+        Error::WithContext {
+            context,
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the original error without contexts.
+    pub fn root(&self) -> &Error {
+        let mut error = self;
+        while let Error::WithContext { source, .. } = error {
+            error = source;
+        }
+        error
+    }
+
+    /// Returns the contexts from the outermost one to the innermost one.
+    /// # Examples
+    /// ```
+    /// use truck::{Context, Error};
+    /// let error = Error::Concat("disconnected".to_string())
+    ///     .context(Context::Parameter(0.5))
+    ///     .context(Context::message("concatenating the profile"));
+    /// assert_eq!(
+    ///     error.contexts(),
+    ///     vec![&Context::message("concatenating the profile"), &Context::Parameter(0.5)],
+    /// );
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "concatenating the profile: at the parameter t = 0.5: disconnected",
+    /// );
+    /// ```
+    pub fn contexts(&self) -> Vec<&Context> {
+        let mut contexts = Vec::new();
+        let mut error = self;
+        while let Error::WithContext { context, source } = error {
+            contexts.push(context);
+            error = source;
+        }
+        contexts
+    }
+}
+
+impl From<truck_modeling::errors::Error> for Error {
+    fn from(error: truck_modeling::errors::Error) -> Self {
+        match error {
+            truck_modeling::errors::Error::FromTopology(error) => Error::Topology(error),
+            _ => Error::Modeling(error),
+        }
+    }
+}
+
+#[cfg(feature = "stepio")]
+impl From<truck_stepio::r#in::alias::ExpressParseError> for Error {
+    #[inline(always)]
+    fn from(error: truck_stepio::r#in::alias::ExpressParseError) -> Self {
+        Error::StepParse(error.to_string())
+    }
+}
+
+impl<P: Debug> From<truck_geotrait::ConcatError<P>> for Error {
+    #[inline(always)]
+    fn from(error: truck_geotrait::ConcatError<P>) -> Self { Error::Concat(error.to_string()) }
+}
+
+/// Conversion of the results of truck crates into the unified result with contexts
+pub trait ResultExt<T> {
+    /// Converts the error into [`Error`] and adds `context`.
+    fn context(self, context: Context) -> crate::Result<T>;
+    /// Converts the error into [`Error`] and adds the context lazily created by `f`.
+    fn with_context<F: FnOnce() -> Context>(self, f: F) -> crate::Result<T>;
+}
+
+impl<T, E: Into<Error>> ResultExt<T> for std::result::Result<T, E> {
+    #[inline(always)]
+    fn context(self, context: Context) -> crate::Result<T> {
+        self.map_err(|e| e.into().context(context))
+    }
+    #[inline(always)]
+    fn with_context<F: FnOnce() -> Context>(self, f: F) -> crate::Result<T> {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+#[test]
+fn print_messages() {
+    use std::io::Write;
+    writeln!(
+        &mut std::io::stderr(),
+        "****** test of the expressions of error messages ******\n"
+    )
+    .unwrap();
+    let error = Error::from(truck_modeling::errors::Error::FromTopology(
+        truck_topology::errors::Error::SameVertex,
+    ));
+    assert!(matches!(error, Error::Topology(_)));
+    let error = error
+        .context(Context::Parameters(0.25, 0.5))
+        .context(Context::message("creating the fillet"));
+    writeln!(&mut std::io::stderr(), "{error}\n").unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "*******************************************************"
+    )
+    .unwrap();
+}
@@ -0,0 +1,197 @@
+use crate::contact;
+use std::collections::VecDeque;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+/// The method computing the explosion vectors
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExplosionMethod {
+    /// Each shell is moved away from the center of the assembly, by the vector from the center
+    /// of the bounding box of the assembly to that of the shell.
+    Radial,
+    /// The shells touching each other are pushed apart along the axis of the least overlap of
+    /// their bounding boxes, by the sizes of the moved shells. The largest shell of each group
+    /// of touching shells is moved radially, and the others follow the shells they touch.
+    Contact {
+        /// the maximum gap between the bounding boxes of the touching shells
+        tol: f64,
+    },
+    /// The touching shells are pushed apart in the average normals of their contact faces found
+    /// by [`find_contacts`](crate::contact::find_contacts), by the sizes of the moved shells.
+    /// The shells without contact faces, or whose normals cancel out like a shaft in a hole, are
+    /// separated by the bounding boxes as [`ExplosionMethod::Contact`].
+    MatingFaces {
+        /// the maximum gap between the touching faces
+        tol: f64,
+    },
+}
+
+/// The exploded view of an assembly: the explosion vectors of the shells
+///
+/// The transforms of the animation are given by [`ExplodedView::matrices`] and
+/// [`ExplodedView::keyframes`], which can be set to the matrices of the instance states of
+/// the rendered shells.
+/// # Examples
+/// ```
+/// use truck::explode::{ExplodedView, ExplosionMethod};
+/// use truck::modeling::*;
+///
+/// // a plate, a block on the plate, and a cap on the block
+/// let plate = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 1.0));
+/// let block = builder::cuboid(Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 3.0, 2.0));
+/// let cap = builder::cuboid(Point3::new(1.5, 1.5, 2.0), Point3::new(2.5, 2.5, 2.5));
+/// let shells: Vec<Shell> = [plate, block, cap]
+///     .into_iter()
+///     .map(|solid| solid.into_boundaries().pop().unwrap())
+///     .collect();
+///
+/// let method = ExplosionMethod::Contact { tol: 1.0e-3 };
+/// let view = ExplodedView::new(&shells, method, 0.01);
+/// let vectors = view.vectors();
+/// assert_near!(vectors[1] - vectors[0], Vector3::new(0.0, 0.0, 1.0));
+/// assert_near!(vectors[2] - vectors[1], Vector3::new(0.0, 0.0, 0.5));
+///
+/// // the half way of the explosion
+/// let matrices = view.matrices(0.5);
+/// let moved = matrices[2].transform_point(Point3::new(2.0, 2.0, 2.0));
+/// assert_near!(moved, Point3::new(2.0, 2.0, 2.0) + vectors[2] / 2.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplodedView {
+    vectors: Vec<Vector3>,
+}
+
+/// Returns the average unit normal of the contact faces from `a` to `b`, if the normals do not
+/// cancel out.
+fn mating_direction(a: &Shell, b: &Shell, tol: f64) -> Option<Vector3> {
+    let contacts = contact::shell_contacts(a, b, tol);
+    let area: f64 = contacts.iter().map(|contact| contact.area).sum();
+    let normal: Vector3 = contacts.iter().map(|c| c.normal * c.area).sum();
+    match normal.magnitude() > area / 2.0 {
+        true => Some(normal.normalize()),
+        false => None,
+    }
+}
+
+/// Returns the unit vector of the axis from `a` to `b` along which the bounding boxes overlap
+/// least, if the gap between them is at most `tol` in all the axes.
+fn separation(a: &BoundingBox<Point3>, b: &BoundingBox<Point3>, tol: f64) -> Option<Vector3> {
+    let overlap = |k: usize| f64::min(a.max()[k], b.max()[k]) - f64::max(a.min()[k], b.min()[k]);
+    let overlaps = [0, 1, 2].map(overlap);
+    if overlaps.iter().any(|overlap| *overlap < -tol) {
+        return None;
+    }
+    let axis = (0..3).min_by(|i, j| overlaps[*i].total_cmp(&overlaps[*j]))?;
+    let mut direction = Vector3::zero();
+    direction[axis] = match b.center()[axis] < a.center()[axis] {
+        true => -1.0,
+        false => 1.0,
+    };
+    Some(direction)
+}
+
+/// Returns the explosion vectors: the largest shell of each group of the touching shells is
+/// moved by `radial`, and the others are moved from the shells they touch by `separate`.
+fn follow_contacts(
+    boxes: &[BoundingBox<Point3>],
+    radial: impl Fn(&BoundingBox<Point3>) -> Vector3,
+    separate: impl Fn(usize, usize) -> Option<Vector3>,
+) -> Vec<Vector3> {
+    let mut vectors = vec![None; boxes.len()];
+    let volume = |bdb: &BoundingBox<Point3>| {
+        let diagonal = bdb.diagonal();
+        diagonal.x * diagonal.y * diagonal.z
+    };
+    while let Some(root) = (0..boxes.len())
+        .filter(|i| vectors[*i].is_none())
+        .max_by(|i, j| volume(&boxes[*i]).total_cmp(&volume(&boxes[*j])))
+    {
+        vectors[root] = Some(radial(&boxes[root]));
+        let mut queue = VecDeque::from([root]);
+        while let Some(i) = queue.pop_front() {
+            let vector = vectors[i].unwrap();
+            (0..boxes.len()).for_each(|j| {
+                if vectors[j].is_some() {
+                    return;
+                }
+                if let Some(dir) = separate(i, j) {
+                    let diagonal = boxes[j].diagonal();
+                    let size = (0..3).map(|k| f64::abs(diagonal[k] * dir[k])).sum::<f64>();
+                    vectors[j] = Some(vector + dir * size);
+                    queue.push_back(j);
+                }
+            });
+        }
+    }
+    vectors.into_iter().map(Option::unwrap).collect()
+}
+
+impl ExplodedView {
+    /// Computes the explosion vectors of `shells` by `method`. The bounding boxes of the shells
+    /// are computed from their tessellations with the tolerance `tol`.
+    pub fn new(shells: &[Shell], method: ExplosionMethod, tol: f64) -> Self {
+        let boxes: Vec<BoundingBox<Point3>> = shells
+            .iter()
+            .map(|shell| shell.triangulation(tol).to_polygon().bounding_box())
+            .collect();
+        let union = boxes.iter().fold(BoundingBox::new(), |sum, bdb| sum + bdb);
+        let center = union.center();
+        let radial = |bdb: &BoundingBox<Point3>| bdb.center() - center;
+        let vectors = match method {
+            ExplosionMethod::Radial => boxes.iter().map(radial).collect(),
+            ExplosionMethod::Contact { tol } => {
+                let separate = |i: usize, j: usize| separation(&boxes[i], &boxes[j], tol);
+                follow_contacts(&boxes, radial, separate)
+            }
+            ExplosionMethod::MatingFaces { tol } => {
+                let separate = |i: usize, j: usize| {
+                    let direction = separation(&boxes[i], &boxes[j], tol)?;
+                    Some(mating_direction(&shells[i], &shells[j], tol).unwrap_or(direction))
+                };
+                follow_contacts(&boxes, radial, separate)
+            }
+        };
+        Self { vectors }
+    }
+
+    /// Returns the explosion vectors in the order of the shells.
+    #[inline(always)]
+    pub fn vectors(&self) -> &[Vector3] { &self.vectors }
+
+    /// Returns the translations of the shells at the ratio `t` of the explosion: the shells are
+    /// assembled at `t = 0` and exploded at `t = 1`.
+    #[inline(always)]
+    pub fn matrices(&self, t: f64) -> Vec<Matrix4> {
+        let translation = |vector: &Vector3| Matrix4::from_translation(*vector * t);
+        self.vectors.iter().map(translation).collect()
+    }
+
+    /// Returns the matrices of `count` frames from the assembled shells to the exploded ones,
+    /// whose ratios are eased by the smoothstep. A single frame is the assembled one.
+    pub fn keyframes(&self, count: usize) -> Vec<Vec<Matrix4>> {
+        let last = usize::max(count, 2) - 1;
+        (0..count)
+            .map(|i| {
+                let t = i as f64 / last as f64;
+                self.matrices(t * t * (3.0 - 2.0 * t))
+            })
+            .collect()
+    }
+
+    /// Returns the shells moved at the ratio `t` of the explosion.
+    /// # Panics
+    /// Panic occurs if the number of `shells` is not the one of the explosion vectors.
+    pub fn exploded(&self, shells: &[Shell], t: f64) -> Vec<Shell> {
+        assert_eq!(
+            shells.len(),
+            self.vectors.len(),
+            "The shells must be the ones of the exploded view."
+        );
+        let matrices = self.matrices(t);
+        shells
+            .iter()
+            .zip(matrices)
+            .map(|(shell, matrix)| builder::transformed(shell, matrix))
+            .collect()
+    }
+}
@@ -0,0 +1,498 @@
+use crate::{Error, Result};
+use std::collections::HashMap;
+use truck_modeling::{errors::Error as ModelingError, *};
+
+/// The solid made by a feature, and the names of the faces created by the feature
+///
+/// The faces of the result coming from the faces of the tool are named after the tool faces,
+/// e.g. the faces of the wall and the bottom of a drilled hole, or the top of a boss. The origins
+/// of the faces are tracked through the boolean operation, so that a tool face divided by the
+/// operation gives the same name to all the pieces.
+#[derive(Clone, Debug)]
+pub struct Feature {
+    solid: Solid,
+    names: HashMap<FaceID, String>,
+}
+
+impl Feature {
+    /// Returns the result solid.
+    #[inline(always)]
+    pub fn solid(&self) -> &Solid { &self.solid }
+
+    /// Returns the result solid, dropping the names.
+    #[inline(always)]
+    pub fn into_solid(self) -> Solid { self.solid }
+
+    /// Returns the name of `face`, if it is created by the feature.
+    #[inline(always)]
+    pub fn name(&self, face: &Face) -> Option<&str> {
+        self.names.get(&face.id()).map(String::as_str)
+    }
+
+    /// Returns the faces of the result solid named `name`.
+    pub fn faces(&self, name: &str) -> Vec<&Face> {
+        let named = |face: &&Face| self.name(face) == Some(name);
+        let faces = self.solid.boundaries().iter().flat_map(Shell::face_iter);
+        faces.filter(named).collect()
+    }
+}
+
+/// the depth of the tools sunk into the solids to be united, in the tolerances of the boolean
+/// operations
+const SINK_RATIO: f64 = 4.0;
+
+/// Returns the modeling error of the degenerate profile.
+#[inline(always)]
+fn degenerate_profile() -> Error { ModelingError::DegenerateProfile.into() }
+
+/// The origin of a face of the solid swept from a base face
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Swept {
+    /// the base face itself
+    Base,
+    /// the wall swept from the edge of the base with the index in the boundaries
+    Wall(usize),
+    /// the base moved by the sweep
+    Top,
+}
+
+/// Returns the origin of `face` of the solid swept from `base`.
+///
+/// The sweeps keep the base and its edges in the solid, so that the walls are found by the edges.
+fn swept(base: &Face, face: &Face) -> Swept {
+    if face.id() == base.id() {
+        return Swept::Base;
+    }
+    let edges: Vec<_> = base.edge_iter().map(|edge| edge.id()).collect();
+    let mut wall = face
+        .edge_iter()
+        .filter_map(|edge| edges.iter().position(|id| *id == edge.id()));
+    wall.next().map_or(Swept::Top, Swept::Wall)
+}
+
+/// Names the faces of `tool` swept from `base` by the names of their origins.
+fn name_swept<F>(tool: &Solid, base: &Face, name: F) -> HashMap<FaceID, String>
+where F: Fn(Swept) -> Option<String> {
+    let faces = tool.boundaries().iter().flat_map(Shell::face_iter);
+    faces
+        .filter_map(|face| Some((face.id(), name(swept(base, face))?)))
+        .collect()
+}
+
+/// Names the faces of `solid` after the faces of the tool which they come from.
+fn named_feature(
+    solid: Solid,
+    origins: &HashMap<FaceID, FaceID>,
+    names: &HashMap<FaceID, String>,
+) -> Feature {
+    let faces = solid.boundaries().iter().flat_map(Shell::face_iter);
+    let names = faces
+        .filter_map(|face| {
+            let name = names.get(origins.get(&face.id())?)?;
+            Some((face.id(), name.clone()))
+        })
+        .collect();
+    Feature { solid, names }
+}
+
+/// Subtracts `tool` from `solid`, and names the faces of the result after the faces of `tool`.
+fn subtract(
+    solid: &Solid,
+    mut tool: Solid,
+    names: &HashMap<FaceID, String>,
+    tol: f64,
+) -> Result<Feature> {
+    tool.not();
+    let subtracted = truck_shapeops::and_with_origins(solid, &tool, tol);
+    let (solid, origins) = subtracted.ok_or(Error::BooleanOperationFailed)?;
+    Ok(named_feature(solid, &origins, names))
+}
+
+/// Unites `tool` with `solid`, and names the faces of the result after the faces of `tool`.
+fn unite(
+    solid: &Solid,
+    tool: &Solid,
+    names: &HashMap<FaceID, String>,
+    tol: f64,
+) -> Result<Feature> {
+    let united = truck_shapeops::or_with_origins(solid, tool, tol);
+    let (solid, origins) = united.ok_or(Error::BooleanOperationFailed)?;
+    Ok(named_feature(solid, &origins, names))
+}
+
+/// Drills the hole from `origin` on the surface of `solid` in `direction`.
+///
+/// The faces of the hole are named `"wall"` and `"bottom"`. The tool cylinder sticks out of
+/// `origin` by `depth`, so that the entrance is cut through.
+/// # Failures
+/// - Returns [`Error::Modeling`] if `direction` is zero or `diameter` is not positive.
+/// - Returns [`Error::BooleanOperationFailed`] if the subtraction fails.
+/// # Examples
+/// ```
+/// use truck::features;
+/// use truck::modeling::*;
+///
+/// let block = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 2.0));
+/// let origin = Point3::new(2.0, 2.0, 2.0);
+/// let feature = features::drill(&block, origin, -Vector3::unit_z(), 1.0, 1.0, 0.05).unwrap();
+/// assert!(feature.solid().is_geometric_consistent());
+/// let bottom = feature.faces("bottom");
+/// assert_eq!(bottom.len(), 1);
+/// bottom[0].vertex_iter().for_each(|v| assert_near!(v.point().z, 1.0));
+/// assert!(!feature.faces("wall").is_empty());
+/// ```
+pub fn drill(
+    solid: &Solid,
+    origin: Point3,
+    direction: Vector3,
+    diameter: f64,
+    depth: f64,
+    tol: f64,
+) -> Result<Feature> {
+    let axis = match direction.so_small() {
+        true => direction,
+        false => direction.normalize() * depth,
+    };
+    if axis.so_small() {
+        return Err(ModelingError::ZeroLengthAxis.into());
+    }
+    let radius = diameter / 2.0;
+    if radius < TOLERANCE {
+        return Err(degenerate_profile());
+    }
+    // the disk at the bottom of the hole, facing the entrance
+    let (bottom, normal) = (origin + axis, -axis.normalize());
+    let axis0 = match normal.x.abs() < 0.5 {
+        true => normal.cross(Vector3::unit_x()).normalize(),
+        false => normal.cross(Vector3::unit_y()).normalize(),
+    };
+    let v = builder::vertex(bottom + axis0 * radius);
+    let circle = builder::rsweep(&v, bottom, normal, Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle])?;
+    let tool = builder::try_tsweep(&disk, -2.0 * axis)?;
+    let names = name_swept(&tool, &disk, |origin| match origin {
+        Swept::Base => Some("bottom".to_string()),
+        Swept::Wall(_) => Some("wall".to_string()),
+        Swept::Top => None,
+    });
+    subtract(solid, tool, &names, tol)
+}
+
+/// Cuts the pocket bounded by the planar closed `sketch` on the surface of `solid` by `depth`.
+///
+/// The pocket is cut against the normal of the sketch, i.e. `sketch` is counterclockwise seen
+/// from the outside of `solid`. The floor is named `"floor"`, and the wall swept from the `i`th
+/// edge of `sketch` is named `"wall{i}"`.
+/// # Failures
+/// - Returns [`Error::Modeling`] if `sketch` is not planar.
+/// - Returns [`Error::BooleanOperationFailed`] if the subtraction fails.
+/// # Examples
+/// ```
+/// use truck::features;
+/// use truck::modeling::*;
+///
+/// let block = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 2.0));
+/// let points = [(1.0, 1.0), (3.0, 1.0), (3.0, 2.0), (1.0, 2.0)];
+/// let v = points.map(|(x, y)| builder::vertex(Point3::new(x, y, 2.0)));
+/// let sketch: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+///
+/// let feature = features::pocket(&block, &sketch, 0.5, 0.05).unwrap();
+/// assert!(feature.solid().is_geometric_consistent());
+/// let floor = feature.faces("floor");
+/// assert_eq!(floor.len(), 1);
+/// floor[0].vertex_iter().for_each(|v| assert_near!(v.point().z, 1.5));
+/// // the wall swept from the edge from (1, 1) to (3, 1)
+/// let wall = feature.faces("wall0");
+/// assert_eq!(wall.len(), 1);
+/// wall[0].vertex_iter().for_each(|v| assert_near!(v.point().y, 1.0));
+/// ```
+pub fn pocket(solid: &Solid, sketch: &Wire, depth: f64, tol: f64) -> Result<Feature> {
+    let face = builder::try_attach_plane(&[sketch.clone()])?;
+    let normal = face.oriented_surface().normal(0.0, 0.0);
+    let floor = builder::translated(&face, -normal * depth);
+    let tool = builder::tsweep(&floor, normal * 2.0 * depth);
+    let names = name_swept(&tool, &floor, |origin| match origin {
+        Swept::Base => Some("floor".to_string()),
+        Swept::Wall(i) => Some(format!("wall{i}")),
+        Swept::Top => None,
+    });
+    subtract(solid, tool, &names, tol)
+}
+
+/// Cuts the slot of `width` from `start` to `end` on the plane with `normal` by `depth`.
+///
+/// The slot is the pocket of the stadium whose round ends are centered at `start` and `end`.
+/// The faces are named as [`pocket`]: the walls are `"wall0"` along the right side seen from
+/// `normal`, `"wall1"` around `end`, `"wall2"` along the left side, and `"wall3"` around `start`.
+/// # Failures
+/// - Returns [`Error::Modeling`] if `start` and `end` coincide, or `width` is not positive.
+/// - Returns [`Error::BooleanOperationFailed`] if the subtraction fails.
+/// # Examples
+/// ```
+/// use truck::features;
+/// use truck::modeling::*;
+///
+/// let block = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 2.0));
+/// let (start, end) = (Point3::new(1.0, 2.0, 2.0), Point3::new(3.0, 2.0, 2.0));
+/// let feature = features::slot(&block, start, end, Vector3::unit_z(), 1.0, 0.5, 0.05).unwrap();
+/// assert!(feature.solid().is_geometric_consistent());
+/// feature.faces("wall0")[0]
+///     .vertex_iter()
+///     .for_each(|v| assert_near!(v.point().y, 1.5));
+/// ```
+pub fn slot(
+    solid: &Solid,
+    start: Point3,
+    end: Point3,
+    normal: Vector3,
+    width: f64,
+    depth: f64,
+    tol: f64,
+) -> Result<Feature> {
+    let sketch = stadium(start, end, normal, width)?;
+    pocket(solid, &sketch, depth, tol)
+}
+
+/// Returns the counterclockwise stadium around `normal`, whose round ends are centered at
+/// `start` and `end`.
+fn stadium(start: Point3, end: Point3, normal: Vector3, width: f64) -> Result<Wire> {
+    let radius = width / 2.0;
+    if radius < TOLERANCE || (end - start).so_small() || normal.so_small() {
+        return Err(degenerate_profile());
+    }
+    let dir = (end - start).normalize();
+    let side = normal.normalize().cross(dir) * radius;
+    let v = [start - side, end - side, end + side, start + side].map(builder::vertex);
+    let wire = vec![
+        builder::try_line(&v[0], &v[1])?,
+        builder::try_circle_arc(&v[1], &v[2], end + dir * radius)?,
+        builder::try_line(&v[2], &v[3])?,
+        builder::try_circle_arc(&v[3], &v[0], start - dir * radius)?,
+    ];
+    Ok(wire.into())
+}
+
+/// Returns the slab of the thickness `2 * half` around the face swept by `profile` along
+/// `direction`, and the names of its faces.
+fn planar_rib(
+    profile: &Wire,
+    half: f64,
+    direction: Vector3,
+) -> Result<(Solid, HashMap<FaceID, String>)> {
+    let far = builder::translated(profile, direction);
+    let front = profile.front_vertex().unwrap();
+    let back = profile.back_vertex().unwrap();
+    let end0 = builder::line(back, far.back_vertex().unwrap());
+    let end1 = builder::line(far.front_vertex().unwrap(), front);
+    let mut wire = profile.clone();
+    wire.push_back(end0);
+    wire.extend(far.inverse());
+    wire.push_back(end1);
+    let face = builder::try_attach_plane(&[wire])?;
+    let normal = face.oriented_surface().normal(0.0, 0.0);
+    let bottom = builder::translated(&face, -normal * half);
+    let tool = builder::tsweep(&bottom, normal * 2.0 * half);
+    // the boundary of the bottom: `profile`, the end, `far` and the end
+    let len = profile.len();
+    let names = name_swept(&tool, &bottom, |origin| {
+        let name = match origin {
+            Swept::Base | Swept::Top => "side",
+            Swept::Wall(i) if i < len => "top",
+            Swept::Wall(i) if len < i && i <= 2 * len => "bottom",
+            Swept::Wall(_) => "end",
+        };
+        Some(name.to_string())
+    });
+    Ok((tool, names))
+}
+
+/// Returns the extrusion along `direction` of `profile` thickened by `half` on both sides in the
+/// unit vectors `sides` orthogonal to the edges, and the names of its faces.
+fn thick_rib(
+    profile: &Wire,
+    sides: &[Vector3],
+    half: f64,
+    direction: Vector3,
+) -> Result<(Solid, HashMap<FaceID, String>)> {
+    let len = sides.len();
+    let miters = (0..=len)
+        .map(|i| match i {
+            0 => Ok(sides[0]),
+            _ if i == len => Ok(sides[len - 1]),
+            _ => {
+                let denom = 1.0 + sides[i - 1].dot(sides[i]);
+                match denom.so_small() {
+                    true => Err(degenerate_profile()),
+                    false => Ok((sides[i - 1] + sides[i]) / denom),
+                }
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let fronts = profile.edge_iter().map(|edge| edge.front().point());
+    let back = profile.back_vertex().map(Vertex::point);
+    let points: Vec<Point3> = fronts.chain(back).collect();
+    let offset = |sign: f64| -> Vec<Vertex> {
+        let pairs = points.iter().zip(&miters);
+        let offset = pairs.map(|(pt, m)| *pt + *m * (sign * half));
+        offset.map(builder::vertex).collect()
+    };
+    let (left, right) = (offset(1.0), offset(-1.0));
+    let line = |v0: &Vertex, v1: &Vertex| builder::line(v0, v1);
+    let mut wire: Wire = (0..len).map(|i| line(&left[i], &left[i + 1])).collect();
+    wire.push_back(line(&left[len], &right[len]));
+    wire.extend((0..len).rev().map(|i| line(&right[i + 1], &right[i])));
+    wire.push_back(line(&right[0], &left[0]));
+    let face = builder::try_attach_plane(&[wire])?;
+    let dot = face.oriented_surface().normal(0.0, 0.0).dot(direction);
+    if dot.so_small() {
+        return Err(degenerate_profile());
+    }
+    let (base, vector, caps) = match dot > 0.0 {
+        true => (face, direction, ["top", "bottom"]),
+        false => {
+            let far = builder::translated(&face, direction);
+            (far, -direction, ["bottom", "top"])
+        }
+    };
+    let tool = builder::tsweep(&base, vector);
+    // the boundary of the base: the left side, the end, the right side and the end
+    let names = name_swept(&tool, &base, |origin| {
+        let name = match origin {
+            Swept::Base => caps[0],
+            Swept::Top => caps[1],
+            Swept::Wall(i) if i == len || i == 2 * len + 1 => "end",
+            Swept::Wall(_) => "side",
+        };
+        Some(name.to_string())
+    });
+    Ok((tool, names))
+}
+
+/// Adds the rib of `thickness` along the open `profile` extruded by `direction` to `solid`.
+///
+/// If `profile` is on a plane parallel to `direction`, e.g. a gusset, the rib is the face swept
+/// by `profile` along `direction` thickened on both sides of the plane. Otherwise, `profile`
+/// must be on a plane orthogonal to `direction`, and the rib is `profile` thickened on both sides
+/// in the plane and extruded along `direction`. The rib is not extended, so that the end of the
+/// extrusion must be inside `solid`.
+///
+/// The faces of the rib are named `"top"` on `profile`, `"side"` on both sides, and `"end"`
+/// at the ends of `profile`.
+/// # Failures
+/// - Returns [`Error::InvalidRibProfile`] if `profile` is not an open wire of lines.
+/// - Returns [`Error::Modeling`] if `thickness` is not positive, an edge of `profile` is parallel
+/// to `direction`, or `profile` is not on the planes above.
+/// - Returns [`Error::BooleanOperationFailed`] if the union fails.
+/// # Examples
+/// ```
+/// use truck::features;
+/// use truck::modeling::*;
+///
+/// let plate = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 1.0));
+/// // the L-shaped rib seen from the top
+/// let points = [(1.0, 1.0), (3.0, 1.0), (3.0, 3.0)];
+/// let v = points.map(|(x, y)| builder::vertex(Point3::new(x, y, 2.0)));
+/// let profile: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+///
+/// let direction = Vector3::new(0.0, 0.0, -1.5);
+/// let feature = features::rib(&plate, &profile, 0.2, direction, 0.05).unwrap();
+/// assert!(feature.solid().is_geometric_consistent());
+/// let top = feature.faces("top");
+/// assert_eq!(top.len(), 1);
+/// top[0].vertex_iter().for_each(|v| assert_near!(v.point().z, 2.0));
+/// assert_eq!(feature.faces("end").len(), 2);
+/// ```
+pub fn rib(
+    solid: &Solid,
+    profile: &Wire,
+    thickness: f64,
+    direction: Vector3,
+    tol: f64,
+) -> Result<Feature> {
+    let is_line = |edge: &Edge| matches!(edge.curve(), Curve::Line(_));
+    let lines = profile.edge_iter().all(is_line);
+    if profile.is_empty() || profile.is_closed() || !profile.is_continuous() || !lines {
+        return Err(Error::InvalidRibProfile);
+    }
+    let half = thickness / 2.0;
+    let sides: Vec<Vector3> = profile
+        .edge_iter()
+        .map(|edge| (edge.back().point() - edge.front().point()).cross(direction))
+        .collect();
+    if half < TOLERANCE || sides.iter().any(|side| side.so_small()) {
+        return Err(degenerate_profile());
+    }
+    let sides: Vec<Vector3> = sides.into_iter().map(Vector3::normalize).collect();
+    let planar = sides.iter().all(|side| side.cross(sides[0]).so_small());
+    let (tool, names) = match planar {
+        true => planar_rib(profile, half, direction)?,
+        false => thick_rib(profile, &sides, half, direction)?,
+    };
+    unite(solid, &tool, &names, tol)
+}
+
+/// Adds the boss extruded from the planar closed `sketch` on the surface of `solid` by `height`.
+///
+/// The boss is extruded in the normal of the sketch, i.e. `sketch` is counterclockwise seen from
+/// the outside of `solid`, and the walls are tapered toward the top by `draft`. The boss is sunk
+/// into `solid` by `4 * tol` not to be united on the coplanar faces, so that the solid must be
+/// thicker than it under `sketch`. The top is named `"top"`, and the walls are named `"wall"`.
+/// # Failures
+/// - Returns [`Error::Modeling`] if `sketch` is not planar, `height` is not positive, or the
+/// draft is not supported by [`builder::try_tsweep_with_options`].
+/// - Returns [`Error::BooleanOperationFailed`] if the union fails.
+/// # Examples
+/// ```
+/// use truck::features;
+/// use truck::modeling::*;
+///
+/// let block = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 2.0));
+/// let points = [(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)];
+/// let v = points.map(|(x, y)| builder::vertex(Point3::new(x, y, 2.0)));
+/// let sketch: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+///
+/// let feature = features::boss(&block, &sketch, 1.0, Deg(30.0), 0.05).unwrap();
+/// assert!(feature.solid().is_geometric_consistent());
+/// let top = feature.faces("top");
+/// assert_eq!(top.len(), 1);
+/// // the top is shrunk by the draft.
+/// let inset = 1.0 - f64::tan(std::f64::consts::PI / 6.0);
+/// top[0].vertex_iter().for_each(|v| {
+///     let pt = v.point();
+///     assert_near!(pt.z, 3.0);
+///     assert_near!(f64::abs(pt.x - 2.0), inset);
+/// });
+/// ```
+pub fn boss<R: Into<Rad<f64>>>(
+    solid: &Solid,
+    sketch: &Wire,
+    height: f64,
+    draft: R,
+    tol: f64,
+) -> Result<Feature> {
+    if height < TOLERANCE {
+        return Err(ModelingError::ZeroLengthSweepVector.into());
+    }
+    let draft = draft.into();
+    let face = builder::try_attach_plane(&[sketch.clone()])?;
+    let normal = face.oriented_surface().normal(0.0, 0.0);
+    let options = |draft: Rad<f64>| builder::ExtrusionOptions {
+        draft: Some(draft),
+        ..Default::default()
+    };
+    // the base enlarged by the draft under the sketch
+    let sink = SINK_RATIO * tol;
+    let sketch_face = face.inverse();
+    let sunk = builder::try_tsweep_with_options(&sketch_face, -normal * sink, options(draft))?;
+    let mut faces = sunk.boundaries().iter().flat_map(Shell::face_iter);
+    let sunk_top = faces.find(|face| swept(&sketch_face, face) == Swept::Top);
+    let base = sunk_top.unwrap().inverse();
+    let vector = normal * (height + sink);
+    let tool = builder::try_tsweep_with_options(&base, vector, options(-draft))?;
+    let names = name_swept(&tool, &base, |origin| match origin {
+        Swept::Base => None,
+        Swept::Wall(_) => Some("wall".to_string()),
+        Swept::Top => Some("top".to_string()),
+    });
+    unite(solid, &tool, &names, tol)
+}
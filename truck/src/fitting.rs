@@ -0,0 +1,541 @@
+use crate::{Error, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+use truck_geotrait::algo;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+/// the degree of the fitted surfaces and the maximum degree of the fitted edges
+const DEGREE: usize = 3;
+/// the weight of the points and the cross derivatives on the edges in the least squares of the
+/// surfaces, relative to the points of the mesh
+const BOUNDARY_WEIGHT: f64 = 100.0;
+/// the label of the outside of the mesh
+const OUTSIDE: usize = usize::MAX;
+
+/// The options of [`fit`]
+#[derive(Clone, Copy, Debug)]
+pub struct FittingOptions {
+    /// the maximum angle between the normals of the adjacent triangles in a region, and the
+    /// maximum turning angle of the boundaries in an edge
+    pub feature_angle: Rad<f64>,
+    /// the number of the control points of the surfaces in each direction, and the maximum number
+    /// of the control points of the edges, at least 4
+    pub control_points: usize,
+    /// the weight of the second differences of the control points smoothing the fitted
+    /// surfaces, relative to the points of the mesh
+    pub smoothness: f64,
+    /// the maximum difference of the curvatures in a region, given to
+    /// [`Splitting::region_segmentation`]. Default is infinity, i.e. the regions are divided
+    /// only by the feature angle.
+    pub curvature_tolerance: f64,
+}
+
+impl Default for FittingOptions {
+    fn default() -> Self {
+        Self {
+            feature_angle: Rad(std::f64::consts::PI / 6.0),
+            control_points: 8,
+            smoothness: 1.0e-4,
+            curvature_tolerance: f64::INFINITY,
+        }
+    }
+}
+
+/// The shell of the B-spline surfaces fitted to a mesh
+#[derive(Clone, Debug)]
+pub struct FittedShell {
+    /// the fitted shell, whose adjacent faces share the edges
+    pub shell: Shell,
+    /// the indices of the triangles of the regions fitted by the faces of the shell, in the
+    /// order of `faces().triangle_iter()` of the mesh
+    pub regions: Vec<Vec<usize>>,
+    /// the maximum distance from the points of the mesh and the edges to the fitted surfaces
+    pub deviation: f64,
+}
+
+#[inline(always)]
+fn invalid(message: String) -> Error { Error::InvalidFitting(message) }
+
+#[inline(always)]
+fn edge_key(i: usize, j: usize) -> [usize; 2] { [usize::min(i, j), usize::max(i, j)] }
+
+/// The normal equation of the weighted least squares of the control points
+struct LeastSquares {
+    matrix: Vec<Vec<f64>>,
+    rhs: Vec<Vector3>,
+}
+
+impl LeastSquares {
+    fn new(len: usize) -> Self {
+        Self {
+            matrix: vec![vec![0.0; len]; len],
+            rhs: vec![Vector3::zero(); len],
+        }
+    }
+
+    /// Adds the equation `sum of a * x[i] for (i, a) in row = target` with `weight`.
+    fn add_row(&mut self, row: &[(usize, f64)], target: Vector3, weight: f64) {
+        row.iter().for_each(|(i, a)| {
+            self.rhs[*i] += target * (weight * a);
+            let line = &mut self.matrix[*i];
+            row.iter().for_each(|(j, b)| line[*j] += weight * a * b);
+        })
+    }
+
+    #[inline(always)]
+    fn solve(self) -> Option<Vec<Vector3>> { algo::solve_linear(self.matrix, self.rhs) }
+}
+
+/// Returns the rows of the second differences of the control points `index(0)..index(len)`.
+fn second_differences(len: usize, index: impl Fn(usize) -> usize) -> Vec<[(usize, f64); 3]> {
+    (1..len.saturating_sub(1))
+        .map(|i| [(index(i - 1), 1.0), (index(i), -2.0), (index(i + 1), 1.0)])
+        .collect()
+}
+
+/// Returns the chord length parameters of `points` in `[0, 1]`.
+fn chord_parameters(points: &[Point3]) -> Vec<f64> {
+    let mut params = vec![0.0];
+    points.windows(2).for_each(|p| {
+        let last = params[params.len() - 1];
+        params.push(last + p[0].distance(p[1]));
+    });
+    let length = params[params.len() - 1];
+    params.iter().map(|t| t / length).collect()
+}
+
+/// Returns the derivatives of the B-spline basis functions of `degree` at `t`.
+fn basis_derivatives(knot_vec: &KnotVec, degree: usize, t: f64) -> Vec<f64> {
+    let lower = knot_vec.bspline_basis_functions(degree - 1, t);
+    let coef = |i: usize| match knot_vec[i + degree] - knot_vec[i] {
+        delta if delta > 0.0 => degree as f64 / delta,
+        _ => 0.0,
+    };
+    (1..lower.len())
+        .map(|i| lower[i - 1] * coef(i - 1) - lower[i] * coef(i))
+        .collect()
+}
+
+/// Returns the B-spline curve fitted to `points` at `params`, interpolating the end points.
+fn fit_curve(points: &[Point3], params: &[f64], options: &FittingOptions) -> Option<Curve> {
+    let len = usize::min(options.control_points, points.len());
+    let degree = usize::min(DEGREE, len - 1);
+    let knot_vec = KnotVec::uniform_knot(degree, len - degree);
+    let mut lsq = LeastSquares::new(len);
+    points.iter().zip(params).for_each(|(p, t)| {
+        let basis = knot_vec.bspline_basis_functions(degree, *t);
+        let row: Vec<(usize, f64)> = basis.into_iter().enumerate().collect();
+        lsq.add_row(&row, p.to_vec(), 1.0);
+    });
+    let weight = options.smoothness * points.len() as f64 / len as f64;
+    for row in second_differences(len, |i| i) {
+        lsq.add_row(&row, Vector3::zero(), weight);
+    }
+    let controls = lsq.solve()?.into_iter();
+    let mut control_points: Vec<Point3> = controls.map(Point3::from_vec).collect();
+    // the clamped curve passes the end control points, which are the shared vertices.
+    control_points[0] = points[0];
+    control_points[len - 1] = points[points.len() - 1];
+    Some(BSplineCurve::new(knot_vec, control_points).into())
+}
+
+/// Returns the B-spline surface fitted to the points of the mesh `points` and the points on the
+/// edges `boundary` at their parameters, whose derivatives across the edges are `tangents`.
+///
+/// Each tangent `(uv, direction, vector)` is the derivative `vector` at `uv` in `direction`.
+fn fit_surface(
+    points: &[(Vector2, Point3)],
+    boundary: &[(Vector2, Point3)],
+    tangents: &[(Vector2, Vector2, Vector3)],
+    options: &FittingOptions,
+) -> Option<BSplineSurface<Point3>> {
+    let len = options.control_points;
+    let knot_vec = KnotVec::uniform_knot(DEGREE, len - DEGREE);
+    let index = |i: usize, j: usize| i * len + j;
+    let mut lsq = LeastSquares::new(len * len);
+    let mut add = |(uv, point): &(Vector2, Point3), weight: f64| {
+        let bu = knot_vec.bspline_basis_functions(DEGREE, uv.x);
+        let bv = knot_vec.bspline_basis_functions(DEGREE, uv.y);
+        let bu = bu.into_iter().enumerate().filter(|(_, b)| *b != 0.0);
+        let row: Vec<(usize, f64)> = bu
+            .flat_map(|(i, a)| {
+                let bv = bv.iter().enumerate();
+                bv.map(move |(j, b)| (index(i, j), a * b))
+            })
+            .filter(|(_, c)| *c != 0.0)
+            .collect();
+        lsq.add_row(&row, point.to_vec(), weight);
+    };
+    points.iter().for_each(|x| add(x, 1.0));
+    boundary.iter().for_each(|x| add(x, BOUNDARY_WEIGHT));
+    tangents.iter().for_each(|(uv, dir, vector)| {
+        let (bu, bv) = (
+            knot_vec.bspline_basis_functions(DEGREE, uv.x),
+            knot_vec.bspline_basis_functions(DEGREE, uv.y),
+        );
+        let (du, dv) = (
+            basis_derivatives(&knot_vec, DEGREE, uv.x),
+            basis_derivatives(&knot_vec, DEGREE, uv.y),
+        );
+        let row: Vec<(usize, f64)> = (0..len)
+            .flat_map(|i| (0..len).map(move |j| (i, j)))
+            .map(|(i, j)| (index(i, j), du[i] * bv[j] * dir.x + bu[i] * dv[j] * dir.y))
+            .filter(|(_, c)| *c != 0.0)
+            .collect();
+        lsq.add_row(&row, *vector, BOUNDARY_WEIGHT);
+    });
+    let weight = options.smoothness * points.len() as f64 / (len * len) as f64;
+    (0..len).for_each(|k| {
+        let mut rows = second_differences(len, |i| index(i, k));
+        rows.extend(second_differences(len, |j| index(k, j)));
+        for row in rows {
+            lsq.add_row(&row, Vector3::zero(), weight);
+        }
+    });
+    let controls = lsq.solve()?;
+    let point = |i: usize, j: usize| Point3::from_vec(controls[index(i, j)]);
+    let row = |i: usize| -> Vec<Point3> { (0..len).map(|j| point(i, j)).collect() };
+    let control_points = (0..len).map(row).collect();
+    let knot_vecs = (knot_vec.clone(), knot_vec);
+    Some(BSplineSurface::new(knot_vecs, control_points))
+}
+
+/// Returns the submesh of the triangles `region`, the indices of its vertices in `positions`,
+/// and the inverse map of the indices.
+fn region_mesh(
+    positions: &[Point3],
+    triangles: &[[usize; 3]],
+    region: &[usize],
+) -> (PolygonMesh, Vec<usize>, HashMap<usize, usize>) {
+    let mut local = HashMap::<usize, usize>::new();
+    let mut global = Vec::new();
+    let tris: Vec<[usize; 3]> = region
+        .iter()
+        .map(|t| {
+            triangles[*t].map(|i| {
+                *local.entry(i).or_insert_with(|| {
+                    global.push(i);
+                    global.len() - 1
+                })
+            })
+        })
+        .collect();
+    let submesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: global.iter().map(|i| positions[*i]).collect(),
+            ..Default::default()
+        },
+        Faces::from_iter(tris),
+    );
+    (submesh, global, local)
+}
+
+/// Splits the annular region by two disjoint paths between the boundaries `loops`, and returns
+/// the triangles on the one side. Returns `None` if the paths are not found.
+fn split_annulus(
+    triangles: &[[usize; 3]],
+    edge_map: &HashMap<[usize; 2], Vec<usize>>,
+    region: &[usize],
+    loops: [&[usize]; 2],
+) -> Option<Vec<usize>> {
+    let mut adjacency = HashMap::<usize, Vec<usize>>::new();
+    region.iter().for_each(|t| {
+        let tri = triangles[*t];
+        (0..3).for_each(|n| {
+            let (i, j) = (tri[n], tri[(n + 1) % 3]);
+            adjacency.entry(i).or_default().push(j);
+            adjacency.entry(j).or_default().push(i);
+        })
+    });
+    let [inner, outer] = loops.map(|l| l.iter().copied().collect::<HashSet<usize>>());
+    // the shortest path from `start` to the outer boundary through the interior vertices
+    let path = |start: usize, blocked: &HashSet<usize>| {
+        let mut prev = HashMap::from([(start, start)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(v) = queue.pop_front() {
+            for w in &adjacency[&v] {
+                if prev.contains_key(w) || blocked.contains(w) || inner.contains(w) {
+                    continue;
+                }
+                prev.insert(*w, v);
+                if outer.contains(w) {
+                    let mut path = vec![*w];
+                    while path[path.len() - 1] != start {
+                        path.push(prev[&path[path.len() - 1]]);
+                    }
+                    return Some(path);
+                }
+                queue.push_back(*w);
+            }
+        }
+        None
+    };
+    let path0 = path(loops[0][0], &HashSet::new())?;
+    let blocked: HashSet<usize> = path0.iter().copied().collect();
+    let path1 = path(loops[0][loops[0].len() / 2], &blocked)?;
+    let cut: HashSet<[usize; 2]> = path0
+        .windows(2)
+        .chain(path1.windows(2))
+        .map(|e| edge_key(e[0], e[1]))
+        .collect();
+    // the triangles connected to the first one without crossing the paths
+    let members: HashSet<usize> = region.iter().copied().collect();
+    let mut side = HashSet::from([region[0]]);
+    let mut stack = vec![region[0]];
+    while let Some(t) = stack.pop() {
+        let tri = triangles[t];
+        (0..3).for_each(|n| {
+            let key = edge_key(tri[n], tri[(n + 1) % 3]);
+            if !cut.contains(&key) {
+                let adjacent = edge_map[&key].iter().filter(|a| members.contains(*a));
+                adjacent.for_each(|a| {
+                    if side.insert(*a) {
+                        stack.push(*a);
+                    }
+                });
+            }
+        })
+    }
+    let other = region.iter().filter(|t| !side.contains(*t));
+    let other: Vec<usize> = other.copied().collect();
+    match other.is_empty() {
+        true => None,
+        false => Some(other),
+    }
+}
+
+/// Divides the boundary loop of a region into the chains of the edges at the vertices where the
+/// adjacent region changes or the boundary turns more than `angle`.
+fn chains(
+    positions: &[Point3],
+    boundary: &[usize],
+    neighbor: impl Fn(usize, usize) -> usize,
+    angle: Rad<f64>,
+) -> Vec<Vec<usize>> {
+    let len = boundary.len();
+    let cos = f64::cos(angle.0);
+    let at = |k: usize| boundary[k % len];
+    let corners: Vec<usize> = (0..len)
+        .filter(|k| {
+            let (prev, this, next) = (at(k + len - 1), at(*k), at(k + 1));
+            let dir0 = (positions[this] - positions[prev]).normalize();
+            let dir1 = (positions[next] - positions[this]).normalize();
+            neighbor(prev, this) != neighbor(this, next) || dir0.dot(dir1) < cos
+        })
+        .collect();
+    match corners.is_empty() {
+        // the smooth loop is one closed edge from the vertex of the minimum index.
+        true => {
+            let start = (0..len).min_by_key(|k| boundary[*k]).unwrap();
+            vec![(start..=start + len).map(at).collect()]
+        }
+        false => {
+            let ends = corners.iter().zip(corners.iter().cycle().skip(1));
+            ends.map(|(k0, k1)| {
+                let k1 = if k1 > k0 { *k1 } else { k1 + len };
+                (*k0..=k1).map(at).collect()
+            })
+            .collect()
+        }
+    }
+}
+
+/// Fits the shell of B-spline surfaces to the triangles of `mesh`, e.g. of the scanned parts.
+///
+/// The mesh is segmented into the regions by [`Splitting::region_segmentation`] with the feature
+/// angle and the curvature tolerance, and each region is fitted by a face. The annular regions,
+/// e.g. the sides of the cylinders, are split into two faces by two paths between their
+/// boundaries. The boundaries of the regions are divided at the junctions of the regions and at
+/// the corners into the chains, which are fitted by the edges shared by the adjacent faces. Each
+/// surface is the least squares of the points of the region parameterized by LSCM, with the
+/// points on the edges weighted, so that the adjacent faces are continuous in
+/// [`FittedShell::deviation`]. Across the smooth junctions, whose dihedral angles are less than
+/// the feature angle, the derivatives of the surfaces are also fitted to the tangent plane of
+/// the mesh, so that the adjacent faces are tangent continuous. The faces should be meshed by the
+/// robust triangulation, since the edges are on the surfaces only in the deviation.
+/// # Failures
+/// - Returns [`Error::InvalidFitting`] if `options.control_points` is less than 4, the mesh has
+/// no triangles, a region is neither a topological disk nor an annulus, or a least squares is
+/// singular.
+/// - Returns [`Error::Topology`] if the boundary of a face is not simple.
+/// # Examples
+/// ```
+/// use truck::fitting::{self, FittingOptions};
+/// use truck::meshalgo::prelude::*;
+/// use truck::modeling::*;
+///
+/// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+/// let mut mesh = cube.triangulation(0.01).to_polygon();
+/// mesh.put_together_same_attrs();
+///
+/// let fitted = fitting::fit(&mesh, &FittingOptions::default()).unwrap();
+/// assert_eq!(fitted.shell.len(), 6);
+/// assert_eq!(fitted.shell.shell_condition(), ShellCondition::Closed);
+/// assert!(fitted.deviation < 1.0e-6);
+/// ```
+pub fn fit(mesh: &PolygonMesh, options: &FittingOptions) -> Result<FittedShell> {
+    if options.control_points <= DEGREE {
+        return Err(invalid(format!(
+            "the surfaces require {} control points",
+            DEGREE + 1
+        )));
+    }
+    let positions = mesh.positions();
+    let (ids, triangles): (Vec<usize>, Vec<[usize; 3]>) = mesh
+        .faces()
+        .triangle_iter()
+        .map(|tri| tri.map(|v| v.pos))
+        .enumerate()
+        .filter(|(_, [i, j, k])| {
+            let normal = (positions[*j] - positions[*i]).cross(positions[*k] - positions[*i]);
+            !normal.so_small()
+        })
+        .unzip();
+    if triangles.is_empty() {
+        return Err(invalid("the mesh has no triangles".to_string()));
+    }
+    let normals: Vec<Vector3> = triangles
+        .iter()
+        .map(|[i, j, k]| {
+            let normal = (positions[*j] - positions[*i]).cross(positions[*k] - positions[*i]);
+            normal.normalize()
+        })
+        .collect();
+    let mut edge_map = HashMap::<[usize; 2], Vec<usize>>::new();
+    triangles.iter().enumerate().for_each(|(t, tri)| {
+        (0..3).for_each(|n| {
+            let entry = edge_map.entry(edge_key(tri[n], tri[(n + 1) % 3]));
+            entry.or_default().push(t);
+        })
+    });
+    let trimesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: positions.clone(),
+            ..Default::default()
+        },
+        Faces::from_iter(&triangles),
+    );
+    let (angle, tol) = (options.feature_angle, options.curvature_tolerance);
+    let mut labels = trimesh.region_segmentation(angle, tol);
+    let mut regions = vec![Vec::new(); labels.iter().max().unwrap() + 1];
+    let labeled = labels.iter().enumerate();
+    labeled.for_each(|(t, r)| regions[*r].push(t));
+    for r in 0..regions.len() {
+        let (submesh, global, _) = region_mesh(positions, &triangles, &regions[r]);
+        let loops = submesh.extract_boundaries();
+        if let [loop0, loop1] = &loops[..] {
+            let to_global =
+                |l: &Vec<usize>| -> Vec<usize> { l.iter().map(|i| global[*i]).collect() };
+            let [loop0, loop1] = [loop0, loop1].map(to_global);
+            let loops: [&[usize]; 2] = [&loop0, &loop1];
+            let other = split_annulus(&triangles, &edge_map, &regions[r], loops);
+            let other = other.ok_or_else(|| invalid(format!("the annulus {r} cannot be split")))?;
+            other.iter().for_each(|t| labels[*t] = regions.len());
+            regions[r].retain(|t| labels[*t] == r);
+            regions.push(other);
+        }
+    }
+
+    let mut vertices = HashMap::<usize, Vertex>::new();
+    let mut edges = HashMap::<[usize; 2], (Edge, [usize; 2])>::new();
+    let mut deviation = 0.0;
+    let mut faces = Vec::new();
+    for (r, region) in regions.iter().enumerate() {
+        let (submesh, global, local) = region_mesh(positions, &triangles, region);
+        let loops = submesh.extract_boundaries();
+        if loops.len() != 1 {
+            return Err(invalid(format!("the region {r} is not a disk")));
+        }
+        let uv = submesh.parameterization(ParameterizationMethod::Lscm);
+        let uv = uv.ok_or_else(|| invalid(format!("the region {r} cannot be parameterized")))?;
+        // the parameters are normalized into the unit square.
+        let bdb: BoundingBox<Point2> = uv.iter().map(|uv| Point2::from_vec(*uv)).collect();
+        let (min, diag) = (bdb.min(), bdb.diagonal());
+        let normalize = |uv: Vector2| {
+            let uv = uv - min.to_vec();
+            Vector2::new(uv.x / diag.x, uv.y / diag.y)
+        };
+        let uv: Vec<Vector2> = uv.into_iter().map(normalize).collect();
+
+        let neighbor = |i: usize, j: usize| {
+            let adjacent = edge_map[&edge_key(i, j)].iter();
+            let mut others = adjacent.map(|t| labels[*t]).filter(|s| *s != r);
+            others.next().unwrap_or(OUTSIDE)
+        };
+        // the cross derivative at the middle of the boundary segment from `i` to `j`, on the
+        // tangent plane of the smooth junction
+        let tangent = |i: usize, j: usize, middle: Point3| match edge_map[&edge_key(i, j)][..] {
+            [a, b] if labels[a] != labels[b] => {
+                let (inside, outside) = match labels[a] == r {
+                    true => (a, b),
+                    false => (b, a),
+                };
+                if normals[inside].angle(normals[outside]) >= options.feature_angle {
+                    return None;
+                }
+                let normal = (normals[inside] + normals[outside]).normalize();
+                let opposite = triangles[inside].into_iter().find(|k| *k != i && *k != j)?;
+                let uv_middle = (uv[local[&i]] + uv[local[&j]]) / 2.0;
+                let vector = positions[opposite] - middle;
+                let vector = vector - normal * vector.dot(normal);
+                Some((uv_middle, uv[local[&opposite]] - uv_middle, vector))
+            }
+            _ => None,
+        };
+        let boundary: Vec<usize> = loops[0].iter().map(|i| global[*i]).collect();
+        let mut wire = Wire::new();
+        let mut samples = Vec::new();
+        let mut tangents = Vec::new();
+        for chain in chains(positions, &boundary, neighbor, options.feature_angle) {
+            let points: Vec<Point3> = chain.iter().map(|i| positions[*i]).collect();
+            let params = chord_parameters(&points);
+            let [n0, n1] = [chain.len() - 1, chain.len() - 2];
+            let key = edge_key(chain[0], chain[1]).min(edge_key(chain[n0], chain[n1]));
+            let edge = match edges.get(&key) {
+                Some((edge, head)) => match *head == [chain[0], chain[1]] {
+                    true => edge.clone(),
+                    false => edge.inverse(),
+                },
+                None => {
+                    let curve = fit_curve(&points, &params, options);
+                    let singular = || invalid(format!("an edge of the region {r} is singular"));
+                    let curve = curve.ok_or_else(singular)?;
+                    let mut vertex = |i: usize| {
+                        let vertex = vertices.entry(i);
+                        vertex.or_insert_with(|| Vertex::new(positions[i])).clone()
+                    };
+                    let (v0, v1) = (vertex(chain[0]), vertex(chain[n0]));
+                    let edge = Edge::new(&v0, &v1, curve);
+                    edges.insert(key, (edge.clone(), [chain[0], chain[1]]));
+                    edge
+                }
+            };
+            let curve = edge.oriented_curve();
+            chain.windows(2).zip(params.windows(2)).for_each(|(i, t)| {
+                let [uv0, uv1] = [uv[local[&i[0]]], uv[local[&i[1]]]];
+                let middle = curve.subs((t[0] + t[1]) / 2.0);
+                samples.push((uv0, curve.subs(t[0])));
+                samples.push(((uv0 + uv1) / 2.0, middle));
+                tangents.extend(tangent(i[0], i[1], middle));
+            });
+            wire.push_back(edge);
+        }
+
+        let points = uv.iter().copied().zip(submesh.positions().clone());
+        let points: Vec<(Vector2, Point3)> = points.collect();
+        let surface = fit_surface(&points, &samples, &tangents, options);
+        let singular = || invalid(format!("the surface of the region {r} is singular"));
+        let surface = surface.ok_or_else(singular)?;
+        let distance = |(uv, point): &(Vector2, Point3)| surface.subs(uv.x, uv.y).distance(*point);
+        let points = points.iter().chain(&samples);
+        deviation = points.map(distance).fold(deviation, f64::max);
+        faces.push(Face::try_new(vec![wire], Surface::BSplineSurface(surface))?);
+    }
+    let original = |region: Vec<usize>| region.into_iter().map(|t| ids[t]).collect();
+    let regions = regions.into_iter().map(original);
+    Ok(FittedShell {
+        shell: faces.into(),
+        regions: regions.collect(),
+        deviation,
+    })
+}
@@ -0,0 +1,110 @@
+use crate::{Error, Result};
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+/// the number of the ARAP iterations relaxing the unfolded meshes
+const ARAP_ITERATIONS: usize = 50;
+
+/// The flattening of a face onto the plane
+#[derive(Clone, Debug)]
+pub struct FlatFace {
+    /// the mesh of the face on the xy-plane, keeping the orientation of the face
+    pub mesh: PolygonMesh,
+    /// the boundaries of the flattening as the closed polylines
+    pub boundaries: Vec<Vec<Point2>>,
+    /// the maximum relative error of the lengths of the edges of the mesh
+    pub strain: f64,
+    /// `true` if the face is developable, i.e. the strain is only by the tessellation
+    pub developable: bool,
+}
+
+impl FlatFace {
+    /// Returns the boundaries as the wires of the lines on the xy-plane, e.g. the contours of
+    /// the laser cutting.
+    pub fn wires(&self) -> Vec<Wire> {
+        let wire = |boundary: &Vec<Point2>| {
+            let points = boundary.iter().map(|p| Point3::new(p.x, p.y, 0.0));
+            let v: Vec<Vertex> = points.map(builder::vertex).collect();
+            let edges = (0..v.len()).map(|i| builder::line(&v[i], &v[(i + 1) % v.len()]));
+            edges.collect()
+        };
+        self.boundaries.iter().map(wire).collect()
+    }
+}
+
+#[inline(always)]
+fn invalid(message: &str) -> Error { Error::InvalidFlattening(message.to_string()) }
+
+/// Returns `true` if the Gaussian curvature of `surface` vanishes at the parameters of `mesh`.
+fn is_developable(surface: &Surface, mesh: &PolygonMesh) -> bool {
+    let bdb: BoundingBox<Point3> = mesh.positions().iter().collect();
+    let scale = bdb.diameter() * bdb.diameter();
+    mesh.uv_coords().iter().all(|uv| {
+        let (u, v) = (uv.x, uv.y);
+        let normal = surface.uder(u, v).cross(surface.vder(u, v));
+        let area2 = normal.magnitude2();
+        // the singular points, e.g. the apices of the cones
+        if area2.so_small2() {
+            return true;
+        }
+        let normal = normal / f64::sqrt(area2);
+        let l = surface.uuder(u, v).dot(normal);
+        let m = surface.uvder(u, v).dot(normal);
+        let n = surface.vvder(u, v).dot(normal);
+        let curvature = (l * n - m * m) / area2;
+        (curvature * scale).so_small()
+    })
+}
+
+/// Flattens `face` onto the xy-plane, e.g. for the patterns of the laser cutting or the fabric.
+///
+/// The mesh of the face is unfolded triangle by triangle, and relaxed with the minimal strain by
+/// the as-rigid-as-possible iterations. The developable faces, e.g. the planes, the cylinders
+/// and the cones, are flattened isometrically up to the tessellation, and the other faces have
+/// the strain in [`FlatFace::strain`].
+/// # Failures
+/// Returns [`Error::InvalidFlattening`] if the face cannot be tessellated with `tol`, or its mesh
+/// is not connected.
+/// # Examples
+/// ```
+/// use std::f64::consts::PI;
+/// use truck::flatten;
+/// use truck::modeling::*;
+///
+/// // the quarter of the cylinder of the radius 1 and the height 2
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let arc = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0));
+/// let face = builder::tsweep(&arc[0], Vector3::new(0.0, 0.0, 2.0));
+///
+/// let flat = flatten::flatten(&face, 0.01).unwrap();
+/// assert!(flat.developable);
+/// assert!(flat.strain < 0.01);
+/// // the rectangle of the length of the arc and the height
+/// let bdb: BoundingBox<Point2> = flat.boundaries[0].iter().collect();
+/// assert!(f64::abs(bdb.diameter() - f64::sqrt(PI * PI / 4.0 + 4.0)) < 0.05);
+/// ```
+pub fn flatten(face: &Face, tol: f64) -> Result<FlatFace> {
+    let meshed = Shell::from(vec![face.clone()]).triangulation(tol);
+    let mesh = meshed.face_iter().next().and_then(|face| face.surface());
+    let mesh = mesh.ok_or_else(|| invalid("the face cannot be tessellated"))?;
+    let developable = is_developable(&face.surface(), &mesh);
+    let coords = mesh.arap_flattened(ARAP_ITERATIONS);
+    let coords = coords.ok_or_else(|| invalid("the mesh of the face is not connected"))?;
+    let strain = mesh.strain(&coords);
+    let boundaries = mesh.extract_boundaries().into_iter();
+    let boundaries = boundaries.map(|b| b.into_iter().map(|i| coords[i]).collect::<Vec<_>>());
+    let triangles = mesh.faces().triangle_iter().map(|tri| tri.map(|v| v.pos));
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: coords.iter().map(|p| Point3::new(p.x, p.y, 0.0)).collect(),
+            ..Default::default()
+        },
+        Faces::from_iter(triangles),
+    );
+    Ok(FlatFace {
+        mesh,
+        boundaries: boundaries.collect(),
+        strain,
+        developable,
+    })
+}
@@ -0,0 +1,348 @@
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use truck_modeling::*;
+
+/// The value flowing along the edges of the [`Graph`]
+#[derive(Clone, Debug)]
+pub enum Value {
+    /// wire
+    Wire(Wire),
+    /// face
+    Face(Face),
+    /// shell
+    Shell(Shell),
+    /// solid
+    Solid(Solid),
+}
+
+macro_rules! value_conversions {
+    ($($type: ident, $as: ident),*) => {
+        $(
+        impl From<$type> for Value {
+            #[inline(always)]
+            fn from(value: $type) -> Value { Value::$type(value) }
+        }
+        )*
+        impl Value {
+            $(
+            /// Returns the reference to the inner shape if `self` is the variant.
+            #[inline(always)]
+            pub fn $as(&self) -> Option<&$type> {
+                match self {
+                    Value::$type(value) => Some(value),
+                    _ => None,
+                }
+            }
+            )*
+        }
+    };
+}
+
+#[rustfmt::skip]
+value_conversions!(Wire, as_wire, Face, as_face, Shell, as_shell, Solid, as_solid);
+
+/// The operation of a node: evaluation from the values of the input nodes
+///
+/// Implement this trait to add a custom node, e.g. a fillet by an external algorithm.
+pub trait Operation: Debug {
+    /// the name of the operation used in the error messages
+    fn name(&self) -> &str;
+    /// Evaluates the operation. `inputs` are the values of the input nodes in order.
+    fn evaluate(&self, inputs: &[Value]) -> Result<Value>;
+}
+
+fn input<'a, T>(
+    operation: &dyn Operation,
+    inputs: &'a [Value],
+    idx: usize,
+    cast: impl Fn(&Value) -> Option<&T>,
+    expected: &str,
+) -> Result<&'a T> {
+    inputs
+        .get(idx)
+        .and_then(cast)
+        .ok_or_else(|| Error::InvalidInput {
+            operation: operation.name().to_string(),
+            expected: format!("{expected} as the input {idx}"),
+        })
+}
+
+/// The planar face bounded by the closed polygon, without inputs
+#[derive(Clone, Debug)]
+pub struct Sketch {
+    /// the vertices of the polygon
+    pub points: Vec<Point3>,
+}
+
+impl Operation for Sketch {
+    fn name(&self) -> &str { "sketch" }
+    fn evaluate(&self, _: &[Value]) -> Result<Value> {
+        let vertices = self.points.iter().copied().map(builder::vertex);
+        let vertices = vertices.collect::<Vec<_>>();
+        let len = vertices.len();
+        let wire = (0..len)
+            .map(|i| builder::try_line(&vertices[i], &vertices[(i + 1) % len]))
+            .collect::<truck_modeling::Result<Wire>>()?;
+        Ok(builder::try_attach_plane(&[wire])?.into())
+    }
+}
+
+/// The solid swept from the input face by `vector`
+#[derive(Clone, Debug)]
+pub struct Extrude {
+    /// the sweep vector
+    pub vector: Vector3,
+}
+
+impl Operation for Extrude {
+    fn name(&self) -> &str { "extrude" }
+    fn evaluate(&self, inputs: &[Value]) -> Result<Value> {
+        let face = input(self, inputs, 0, Value::as_face, "a face")?;
+        Ok(builder::try_tsweep(face, self.vector)?.into())
+    }
+}
+
+/// The solid swept from the input face around the axis
+#[derive(Clone, Debug)]
+pub struct Revolve {
+    /// a point on the axis
+    pub origin: Point3,
+    /// the direction of the axis
+    pub axis: Vector3,
+    /// the angle of the rotation
+    pub angle: Rad<f64>,
+}
+
+impl Operation for Revolve {
+    fn name(&self) -> &str { "revolve" }
+    fn evaluate(&self, inputs: &[Value]) -> Result<Value> {
+        let face = input(self, inputs, 0, Value::as_face, "a face")?;
+        Ok(builder::try_rsweep(face, self.origin, self.axis, self.angle)?.into())
+    }
+}
+
+/// The kind of the boolean operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BooleanKind {
+    /// the intersection of the two inputs
+    And,
+    /// the union of the two inputs
+    Or,
+    /// the first input minus the second input
+    Difference,
+}
+
+/// The boolean operation of the two input solids
+#[derive(Clone, Copy, Debug)]
+pub struct Boolean {
+    /// the kind of the operation
+    pub kind: BooleanKind,
+    /// the tolerance of the intersection curves
+    pub tol: f64,
+}
+
+impl Operation for Boolean {
+    fn name(&self) -> &str { "boolean" }
+    fn evaluate(&self, inputs: &[Value]) -> Result<Value> {
+        let solid0 = input(self, inputs, 0, Value::as_solid, "a solid")?;
+        let solid1 = input(self, inputs, 1, Value::as_solid, "a solid")?;
+        let res = match self.kind {
+            BooleanKind::And => truck_shapeops::and(solid0, solid1, self.tol),
+            BooleanKind::Or => truck_shapeops::or(solid0, solid1, self.tol),
+            BooleanKind::Difference => {
+                let mut solid1 = solid1.clone();
+                solid1.not();
+                truck_shapeops::and(solid0, &solid1, self.tol)
+            }
+        };
+        res.map(Into::into).ok_or(Error::BooleanOperationFailed)
+    }
+}
+
+/// The input shape transformed by `matrix`
+#[derive(Clone, Copy, Debug)]
+pub struct Transform {
+    /// the transformation matrix
+    pub matrix: Matrix4,
+}
+
+impl Operation for Transform {
+    fn name(&self) -> &str { "transform" }
+    fn evaluate(&self, inputs: &[Value]) -> Result<Value> {
+        let value = inputs.first().ok_or_else(|| Error::InvalidInput {
+            operation: self.name().to_string(),
+            expected: "a shape as the input 0".to_string(),
+        })?;
+        Ok(match value {
+            Value::Wire(x) => builder::transformed(x, self.matrix).into(),
+            Value::Face(x) => builder::transformed(x, self.matrix).into(),
+            Value::Shell(x) => builder::transformed(x, self.matrix).into(),
+            Value::Solid(x) => builder::transformed(x, self.matrix).into(),
+        })
+    }
+}
+
+/// The identifier of the node in the [`Graph`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug)]
+struct Node {
+    operation: Box<dyn Operation>,
+    inputs: Vec<NodeId>,
+    cache: Option<Value>,
+}
+
+/// The dataflow graph of the procedural modeling
+///
+/// Each node has an [`Operation`] and the nodes whose values are the inputs of the operation.
+/// The inputs must be added before the node, so the graph is always acyclic.
+/// The evaluated values are cached, and replacing an operation invalidates only the node and the
+/// nodes depending on it.
+/// # Examples
+/// ```
+/// use truck::graph::*;
+/// use truck::modeling::*;
+///
+/// let mut graph = Graph::new();
+/// let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+/// let points = square.map(|(x, y)| Point3::new(x, y, 0.0)).to_vec();
+/// let sketch = graph.add_node(Sketch { points }, &[]).unwrap();
+/// let extrude = graph.add_node(Extrude { vector: Vector3::unit_z() }, &[sketch]).unwrap();
+/// let solid = graph.evaluate(extrude).unwrap();
+/// assert_eq!(solid.as_solid().unwrap().boundaries()[0].len(), 6);
+/// assert_eq!(graph.evaluation_count(), 2);
+///
+/// // Only the extrusion is evaluated again.
+/// graph.set_operation(extrude, Extrude { vector: 2.0 * Vector3::unit_z() }).unwrap();
+/// assert!(graph.is_cached(sketch) && !graph.is_cached(extrude));
+/// graph.evaluate(extrude).unwrap();
+/// assert_eq!(graph.evaluation_count(), 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct Graph {
+    nodes: Vec<Node>,
+    evaluation_count: usize,
+}
+
+impl Graph {
+    /// Creates an empty graph.
+    #[inline(always)]
+    pub fn new() -> Graph { Graph::default() }
+
+    /// Returns the number of the nodes.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.nodes.len() }
+
+    /// Returns `true` if the graph has no nodes.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.nodes.is_empty() }
+
+    /// Returns the number of the evaluations of the operations since the graph was created.
+    #[inline(always)]
+    pub fn evaluation_count(&self) -> usize { self.evaluation_count }
+
+    fn check(&self, id: NodeId) -> Result<()> {
+        match id.0 < self.nodes.len() {
+            true => Ok(()),
+            false => Err(Error::UnknownNode(id.0)),
+        }
+    }
+
+    /// Adds the node with `operation` whose inputs are the values of `inputs`.
+    /// # Failures
+    /// Returns [`Error::UnknownNode`] if one of `inputs` is not in the graph.
+    pub fn add_node(
+        &mut self,
+        operation: impl Operation + 'static,
+        inputs: &[NodeId],
+    ) -> Result<NodeId> {
+        inputs.iter().try_for_each(|id| self.check(*id))?;
+        self.nodes.push(Node {
+            operation: Box::new(operation),
+            inputs: inputs.to_vec(),
+            cache: None,
+        });
+        Ok(NodeId(self.nodes.len() - 1))
+    }
+
+    /// Returns the inputs of the node.
+    pub fn inputs(&self, id: NodeId) -> Result<&[NodeId]> {
+        self.check(id)?;
+        Ok(&self.nodes[id.0].inputs)
+    }
+
+    /// Returns the nodes depending on `id` directly or indirectly, in the order of evaluation.
+    pub fn dependents(&self, id: NodeId) -> Result<Vec<NodeId>> {
+        self.check(id)?;
+        let mut dependent = vec![false; self.nodes.len()];
+        dependent[id.0] = true;
+        let ids = (id.0 + 1..self.nodes.len()).filter(|&i| {
+            dependent[i] = self.nodes[i].inputs.iter().any(|input| dependent[input.0]);
+            dependent[i]
+        });
+        Ok(ids.map(NodeId).collect())
+    }
+
+    /// Replaces the operation of the node and invalidates the caches of the node and its
+    /// dependents.
+    pub fn set_operation(&mut self, id: NodeId, operation: impl Operation + 'static) -> Result<()> {
+        self.check(id)?;
+        self.nodes[id.0].operation = Box::new(operation);
+        self.invalidate(id)
+    }
+
+    /// Invalidates the caches of the node and its dependents, e.g. after the state of a custom
+    /// operation is changed by interior mutability.
+    pub fn invalidate(&mut self, id: NodeId) -> Result<()> {
+        let dependents = self.dependents(id)?;
+        self.nodes[id.0].cache = None;
+        for id in dependents {
+            self.nodes[id.0].cache = None;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the value of the node is cached.
+    #[inline(always)]
+    pub fn is_cached(&self, id: NodeId) -> bool {
+        let node = self.nodes.get(id.0);
+        node.map_or(false, |node| node.cache.is_some())
+    }
+
+    /// Returns the value of the node, evaluating the node and its inputs which are not cached.
+    /// # Failures
+    /// Returns the error of the first failed operation, with the context of the node.
+    pub fn evaluate(&mut self, id: NodeId) -> Result<Value> {
+        self.check(id)?;
+        let mut required = vec![false; id.0 + 1];
+        required[id.0] = true;
+        for i in (0..=id.0).rev() {
+            if required[i] && self.nodes[i].cache.is_none() {
+                for input in &self.nodes[i].inputs {
+                    required[input.0] = true;
+                }
+            }
+        }
+        for i in (0..=id.0).filter(|&i| required[i]) {
+            if self.nodes[i].cache.is_some() {
+                continue;
+            }
+            let node = &self.nodes[i];
+            let inputs = node
+                .inputs
+                .iter()
+                .map(|input| self.nodes[input.0].cache.clone().unwrap())
+                .collect::<Vec<_>>();
+            let value = node.operation.evaluate(&inputs).map_err(|e| {
+                let message = format!("evaluating the node {i} ({})", node.operation.name());
+                e.context(crate::Context::Message(message))
+            })?;
+            self.evaluation_count += 1;
+            self.nodes[i].cache = Some(value);
+        }
+        Ok(self.nodes[id.0].cache.clone().unwrap())
+    }
+}
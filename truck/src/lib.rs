@@ -0,0 +1,76 @@
+//! Integrated entry point of the truck crates
+//!
+//! This crate re-exports the crates of truck and provides the unified error type [`Error`],
+//! so that applications embedding several truck crates have one error path.
+//! # Examples
+//! ```
+//! use truck::{modeling::*, Context, ResultExt};
+//!
+//! fn make_edge(v0: &Vertex, v1: &Vertex) -> truck::Result<Edge> {
+//!     builder::try_line(v0, v1).context(Context::vertex(v0.id()))
+//! }
+//!
+//! let v = builder::vertex(Point3::origin());
+//! let error = make_edge(&v, &v).unwrap_err();
+//! assert!(matches!(error.root(), truck::Error::Topology(_)));
+//! assert_eq!(error.contexts(), vec![&Context::vertex(v.id())]);
+//! ```
+//! # Features
+//! - `rendimpl`: re-exports `truck-platform` and `truck-rendimpl`, and enables creating the
+//!   rendering instances of the [`scene`] descriptions and the rendering benchmarks of [`bench`].
+//! - `stepio`: re-exports `truck-stepio`, and wraps the errors of reading STEP into [`Error`].
+
+#![cfg_attr(not(debug_assertions), deny(warnings))]
+#![deny(clippy::all, rust_2018_idioms)]
+#![warn(
+    missing_docs,
+    missing_debug_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unsafe_code,
+    unstable_features,
+    unused_import_braces,
+    unused_qualifications
+)]
+
+pub use truck_base as base;
+pub use truck_geometry as geometry;
+pub use truck_geotrait as geotrait;
+pub use truck_meshalgo as meshalgo;
+pub use truck_modeling as modeling;
+#[cfg(feature = "rendimpl")]
+pub use truck_platform as platform;
+pub use truck_polymesh as polymesh;
+#[cfg(feature = "rendimpl")]
+pub use truck_rendimpl as rendimpl;
+pub use truck_shapeops as shapeops;
+#[cfg(feature = "stepio")]
+pub use truck_stepio as stepio;
+pub use truck_topology as topology;
+
+/// benchmark harnesses measuring truck on the running hardware
+pub mod bench;
+/// the contact faces between the solids
+pub mod contact;
+/// the unified error type and its contexts
+pub mod errors;
+pub use errors::{Context, Error, ResultExt};
+/// the exploded views of the assemblies
+pub mod explode;
+/// the features of the holes, the pockets, the ribs, and the bosses made by the boolean operations
+pub mod features;
+/// the reverse engineering of the meshes into the shells of the B-spline surfaces
+pub mod fitting;
+/// the flattening of the faces onto the plane for the cutting patterns
+pub mod flatten;
+/// the dataflow graph of the procedural modeling
+pub mod graph;
+/// the measurements of the distances, the angles and the radii between the picked entities
+pub mod measure;
+/// the JSON description of the models and the materials
+pub mod scene;
+/// the sheet metal parts of the flanges and the bends, and their flat patterns
+pub mod sheet_metal;
+
+/// `Result` with the unified error.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,589 @@
+use crate::{Error, Result};
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+/// the number of the trials of the searches of the nearest parameters
+const TRIALS: usize = 100;
+/// the maximum number of the alternating projections refining the nearest points
+const MAX_REFINEMENT: usize = 32;
+/// the number of the segments of the arcs of the angle annotations
+const ARC_DIVISION: usize = 16;
+
+/// The entity picked for the measurement
+#[derive(Clone, Debug)]
+pub enum Entity {
+    /// a point in the space, e.g. unprojected from the depth buffer
+    Point(Point3),
+    /// a vertex of a shape
+    Vertex(Vertex),
+    /// an edge of a shape
+    Edge(Edge),
+    /// a face of a shape
+    Face(Face),
+}
+
+/// The result of the measurement
+#[derive(Clone, Debug, PartialEq)]
+pub enum Measurement {
+    /// the minimum distance between the entities
+    Distance {
+        /// the distance
+        distance: f64,
+        /// the nearest points on the entities
+        points: (Point3, Point3),
+    },
+    /// the angle between the directions of the entities
+    Angle {
+        /// the angle in `[0, PI]`
+        angle: Rad<f64>,
+        /// the point from which the directions are drawn
+        origin: Point3,
+        /// the unit directions of the entities
+        directions: (Vector3, Vector3),
+    },
+    /// the radius of a circular edge or a cylindrical or spherical face
+    Radius {
+        /// the radius
+        radius: f64,
+        /// the center of the circle or the sphere, on the axis of the cylinder
+        center: Point3,
+        /// the point on the entity at which the radius is measured
+        point: Point3,
+        /// the axis of the circle or the cylinder, `None` for the spheres
+        axis: Option<Vector3>,
+    },
+}
+
+/// The annotation of a measurement: the dimension lines and the label
+#[derive(Clone, Debug, PartialEq)]
+pub struct Annotation {
+    /// the line segments of the dimension lines
+    pub segments: Vec<(Point3, Point3)>,
+    /// the text of the measured value
+    pub label: String,
+    /// the anchor of the label
+    pub label_position: Point3,
+}
+
+/// the parameter of a point on an entity
+#[derive(Clone, Copy, Debug)]
+enum Param {
+    Point,
+    Curve(f64),
+    Surface(f64, f64),
+}
+
+#[derive(Clone, Debug)]
+enum Geometry {
+    Point(Point3),
+    Curve(Curve, (f64, f64)),
+    Surface {
+        surface: Surface,
+        orientation: bool,
+        range: ((f64, f64), (f64, f64)),
+    },
+}
+
+/// The geometry of an entity with the sample points
+#[derive(Clone, Debug)]
+struct Probe {
+    geometry: Geometry,
+    samples: Vec<(Point3, Param)>,
+}
+
+impl Probe {
+    fn new(entity: &Entity, tol: f64) -> Result<Self> {
+        match entity {
+            Entity::Point(point) => Ok(Self::point(*point)),
+            Entity::Vertex(vertex) => Ok(Self::point(vertex.point())),
+            Entity::Edge(edge) => Ok(Self::edge(edge, tol)),
+            Entity::Face(face) => {
+                let meshed = Shell::from(vec![face.clone()]).triangulation(tol);
+                let mesh = meshed.face_iter().next().and_then(|face| face.surface());
+                let mesh = mesh.ok_or_else(|| invalid("the face cannot be tessellated"))?;
+                Self::face(face, &mesh)
+            }
+        }
+    }
+
+    fn point(point: Point3) -> Self {
+        Self {
+            geometry: Geometry::Point(point),
+            samples: vec![(point, Param::Point)],
+        }
+    }
+
+    fn edge(edge: &Edge, tol: f64) -> Self {
+        let curve = edge.oriented_curve();
+        let range = curve.parameter_range();
+        let (division, points) = curve.parameter_division(range, tol);
+        let samples = points
+            .into_iter()
+            .zip(division)
+            .map(|(point, t)| (point, Param::Curve(t)))
+            .collect();
+        Self {
+            geometry: Geometry::Curve(curve, range),
+            samples,
+        }
+    }
+
+    /// The samples are the vertices of `mesh`, the tessellation of `face`.
+    fn face(face: &Face, mesh: &PolygonMesh) -> Result<Self> {
+        let uv_coords = mesh.uv_coords();
+        if uv_coords.len() != mesh.positions().len() || uv_coords.is_empty() {
+            return Err(invalid("the tessellation of the face has no parameters"));
+        }
+        let samples: Vec<_> = mesh
+            .positions()
+            .iter()
+            .zip(uv_coords)
+            .map(|(point, uv)| (*point, Param::Surface(uv.x, uv.y)))
+            .collect();
+        let bounds = |i: usize| {
+            let values = uv_coords.iter().map(|uv| uv[i]);
+            let min = values.clone().fold(f64::INFINITY, f64::min);
+            (min, values.fold(f64::NEG_INFINITY, f64::max))
+        };
+        Ok(Self {
+            geometry: Geometry::Surface {
+                surface: face.surface(),
+                orientation: face.orientation(),
+                range: (bounds(0), bounds(1)),
+            },
+            samples,
+        })
+    }
+
+    /// Returns the nearest point to `point` around the parameter `hint`.
+    ///
+    /// The parameters of the faces are bounded by the ones of the samples, since the nearest
+    /// points of the untrimmed surfaces may be out of the faces.
+    fn project(&self, point: Point3, hint: Param) -> Option<(Point3, Param)> {
+        match (&self.geometry, hint) {
+            (Geometry::Point(p), _) => Some((*p, Param::Point)),
+            (Geometry::Curve(curve, (t0, t1)), Param::Curve(t)) => {
+                let t = curve.search_nearest_parameter(point, t, TRIALS)?;
+                let t = f64::clamp(t, *t0, *t1);
+                Some((curve.subs(t), Param::Curve(t)))
+            }
+            (Geometry::Surface { surface, range, .. }, Param::Surface(u, v)) => {
+                let (u, v) = surface.search_nearest_parameter(point, (u, v), TRIALS)?;
+                let ((u0, u1), (v0, v1)) = *range;
+                let inside = u0 - TOLERANCE <= u && u <= u1 + TOLERANCE;
+                let inside = inside && v0 - TOLERANCE <= v && v <= v1 + TOLERANCE;
+                inside.then(|| (surface.subs(u, v), Param::Surface(u, v)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the nearest points of `self` and `other`.
+    ///
+    /// The nearest pair of the samples is refined by the alternating projections.
+    fn nearest_points(&self, other: &Self) -> (Point3, Param, Point3, Param) {
+        let pairs = self
+            .samples
+            .iter()
+            .flat_map(|a| other.samples.iter().map(move |b| (a, b)));
+        let closure = |(a, b): &(&(Point3, Param), &(Point3, Param))| a.0.distance2(b.0);
+        let (a, b) = pairs
+            .min_by(|x, y| closure(x).total_cmp(&closure(y)))
+            .unwrap();
+        let ((mut p, mut s), (mut q, mut t)) = (*a, *b);
+        for _ in 0..MAX_REFINEMENT {
+            let dist2 = p.distance2(q);
+            let refined = other.project(p, t).and_then(|(q_new, t_new)| {
+                let (p_new, s_new) = self.project(q_new, s)?;
+                Some((p_new, s_new, q_new, t_new))
+            });
+            match refined {
+                Some((p_new, s_new, q_new, t_new)) if p_new.distance2(q_new) < dist2 => {
+                    (p, s, q, t) = (p_new, s_new, q_new, t_new);
+                    if dist2 - p.distance2(q) < TOLERANCE2 {
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+        (p, s, q, t)
+    }
+
+    /// Returns the direction of the straight edge or the normal of the planar face.
+    fn direction(&self, tol: f64) -> Option<Direction> {
+        match &self.geometry {
+            Geometry::Point(_) => None,
+            Geometry::Curve(..) => {
+                let (p0, p1) = (self.samples.first()?.0, self.samples.last()?.0);
+                let dir = (p1 - p0).normalize();
+                let straight = self.samples.iter().all(|(p, _)| {
+                    let v = p - p0;
+                    (v - dir * v.dot(dir)).magnitude() < tol
+                });
+                (straight && !(p1 - p0).so_small()).then_some(Direction::Line(p0, p1))
+            }
+            Geometry::Surface {
+                surface,
+                orientation,
+                ..
+            } => {
+                let normals = self.samples.iter().filter_map(|(_, param)| match param {
+                    Param::Surface(u, v) => Some(surface.normal(*u, *v)),
+                    _ => None,
+                });
+                let normals: Vec<Vector3> = normals.collect();
+                let n0 = *normals.first()?;
+                let planar = normals.iter().all(|n| (n - n0).magnitude() < tol);
+                let n0 = if *orientation { n0 } else { -n0 };
+                planar.then_some(Direction::Plane(n0))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Line(Point3, Point3),
+    Plane(Vector3),
+}
+
+#[inline(always)]
+fn invalid(message: &str) -> Error { Error::InvalidMeasurement(message.to_string()) }
+
+/// a unit vector perpendicular to `v`
+fn perpendicular(v: Vector3) -> Vector3 {
+    let axis = match v.x.abs() < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    v.cross(axis).normalize()
+}
+
+/// Measures the minimum distance between the entities.
+///
+/// The edges are sampled by the polylines and the faces by the tessellations with the tolerance
+/// `tol`, and the nearest samples are refined on the exact curves and surfaces.
+/// # Examples
+/// ```
+/// use truck::measure::{self, Entity, Measurement};
+/// use truck::modeling::*;
+/// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+/// let point = Entity::Point(Point3::new(0.5, 0.5, 3.0));
+/// let top = cube.boundaries()[0]
+///     .face_iter()
+///     .find(|face| face.boundaries()[0].vertex_iter().all(|v| v.point().z == 1.0))
+///     .unwrap();
+///
+/// let measurement = measure::distance(&point, &Entity::Face(top.clone()), 0.01).unwrap();
+/// match measurement {
+///     Measurement::Distance { distance, points } => {
+///         assert!((distance - 2.0).abs() < 1.0e-6);
+///         assert!(points.1.near(&Point3::new(0.5, 0.5, 1.0)));
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn distance(a: &Entity, b: &Entity, tol: f64) -> Result<Measurement> {
+    let (probe0, probe1) = (Probe::new(a, tol)?, Probe::new(b, tol)?);
+    let (p, _, q, _) = probe0.nearest_points(&probe1);
+    Ok(Measurement::Distance {
+        distance: p.distance(q),
+        points: (p, q),
+    })
+}
+
+/// Measures the angle between the directions of straight edges and the normals of planar faces.
+///
+/// The directions of the edges point from the origin, the nearest point between the entities,
+/// to the farther ends. The angle between an edge and a face is the one between the edge and
+/// its projection onto the face.
+/// # Failures
+/// Returns [`Error::InvalidMeasurement`] if an entity is a point, a curved edge or a curved
+/// face.
+pub fn angle(a: &Entity, b: &Entity, tol: f64) -> Result<Measurement> {
+    let (probe0, probe1) = (Probe::new(a, tol)?, Probe::new(b, tol)?);
+    let error = || invalid("the angle is defined only for straight edges and planar faces");
+    let dir0 = probe0.direction(tol).ok_or_else(error)?;
+    let dir1 = probe1.direction(tol).ok_or_else(error)?;
+    let (origin, _, _, _) = probe0.nearest_points(&probe1);
+    let from_origin = |p0: Point3, p1: Point3| match p0.distance2(origin) < p1.distance2(origin) {
+        true => (p1 - p0).normalize(),
+        false => (p0 - p1).normalize(),
+    };
+    let on_plane = |d: Vector3, n: Vector3| {
+        let projected = d - n * d.dot(n);
+        match projected.so_small() {
+            true => perpendicular(n),
+            false => projected.normalize(),
+        }
+    };
+    let directions = match (dir0, dir1) {
+        (Direction::Line(p0, p1), Direction::Line(q0, q1)) => {
+            (from_origin(p0, p1), from_origin(q0, q1))
+        }
+        (Direction::Plane(n0), Direction::Plane(n1)) => (n0, n1),
+        (Direction::Line(p0, p1), Direction::Plane(n)) => {
+            let d = from_origin(p0, p1);
+            (d, on_plane(d, n))
+        }
+        (Direction::Plane(n), Direction::Line(p0, p1)) => {
+            let d = from_origin(p0, p1);
+            (on_plane(d, n), d)
+        }
+    };
+    Ok(Measurement::Angle {
+        angle: directions.0.angle(directions.1),
+        origin,
+        directions,
+    })
+}
+
+/// Measures the radius of a circular edge, or a cylindrical or spherical face.
+///
+/// The curvatures are evaluated at the samples, and the radius is accepted if the radii and
+/// the centers agree within `tol`.
+/// # Failures
+/// Returns [`Error::InvalidMeasurement`] if the entity is a point, or does not have a constant
+/// radius.
+pub fn radius(entity: &Entity, tol: f64) -> Result<Measurement> {
+    let probe = Probe::new(entity, tol)?;
+    let circles: Vec<(Point3, f64, Point3, Option<Vector3>)> = match &probe.geometry {
+        Geometry::Point(_) => return Err(invalid("the radius is not defined for points")),
+        Geometry::Curve(curve, _) => probe
+            .samples
+            .iter()
+            .filter_map(|(point, param)| match param {
+                Param::Curve(t) => {
+                    let circle = curve_circle(curve, *t);
+                    circle.map(|(c, r, a)| (*point, r, c, Some(a)))
+                }
+                _ => None,
+            })
+            .collect(),
+        Geometry::Surface { surface, .. } => probe
+            .samples
+            .iter()
+            .filter_map(|(point, param)| match param {
+                Param::Surface(u, v) => {
+                    let circle = surface_circle(surface, *u, *v, tol);
+                    circle.map(|(c, r, a)| (*point, r, c, a))
+                }
+                _ => None,
+            })
+            .collect(),
+    };
+    let error = || invalid("the entity does not have a constant radius");
+    let (point, radius, center, axis) = *circles.get(circles.len() / 2).ok_or_else(error)?;
+    let is_face = matches!(probe.geometry, Geometry::Surface { .. });
+    let consistent = circles.iter().all(|(_, r, c, a)| {
+        let same_center = match (axis, a) {
+            // the centers of the cylinders are on the axis
+            (Some(axis), Some(_)) if is_face => {
+                let v = c - center;
+                (v - axis * v.dot(axis)).magnitude() < tol
+            }
+            _ => c.distance(center) < tol,
+        };
+        (r - radius).abs() < tol && same_center && a.is_some() == axis.is_some()
+    });
+    match consistent {
+        true => Ok(Measurement::Radius {
+            radius,
+            center,
+            point,
+            axis,
+        }),
+        false => Err(error()),
+    }
+}
+
+/// Returns the center, the radius and the axis of the osculating circle of `curve` at `t`.
+fn curve_circle(curve: &Curve, t: f64) -> Option<(Point3, f64, Vector3)> {
+    let (der, der2) = (curve.der(t), curve.der2(t));
+    if der.so_small() {
+        return None;
+    }
+    let tangent = der.normalize();
+    let normal = der2 - tangent * der2.dot(tangent);
+    if normal.so_small() {
+        return None;
+    }
+    let curvature = normal.magnitude() / der.magnitude2();
+    let center = curve.subs(t) + normal.normalize() / curvature;
+    Some((center, 1.0 / curvature, der.cross(der2).normalize()))
+}
+
+/// Returns the center, the radius and the axis of the sphere or the cylinder osculating
+/// `surface` at `(u, v)`, by the normal curvatures along the parameter lines.
+fn surface_circle(
+    surface: &Surface,
+    u: f64,
+    v: f64,
+    tol: f64,
+) -> Option<(Point3, f64, Option<Vector3>)> {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    if uder.so_small() || vder.so_small() {
+        return None;
+    }
+    let normal = surface.normal(u, v);
+    let ucurv = surface.uuder(u, v).dot(normal) / uder.magnitude2();
+    let vcurv = surface.vvder(u, v).dot(normal) / vder.magnitude2();
+    let (curvature, other, axis) = match ucurv.abs() > vcurv.abs() {
+        true => (ucurv, vcurv, vder),
+        false => (vcurv, ucurv, uder),
+    };
+    if curvature.so_small() {
+        return None;
+    }
+    let radius = 1.0 / curvature.abs();
+    let center = surface.subs(u, v) + normal / curvature;
+    if (1.0 / other.abs() - radius).abs() < tol {
+        Some((center, radius, None))
+    } else if other.abs() * radius < tol {
+        Some((center, radius, Some(axis.normalize())))
+    } else {
+        None
+    }
+}
+
+/// Returns the entity of `shell` at `point`, e.g. the point on the picked object unprojected from
+/// the depth buffer.
+///
+/// The vertices are preferred to the edges, and the edges to the faces, within the distance
+/// `tol`. The faces are sampled by the tessellation with the tolerance `tol`.
+/// # Examples
+/// ```
+/// use truck::measure::{self, Entity};
+/// use truck::modeling::*;
+/// let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+/// let shell = &cube.boundaries()[0];
+/// let picked = measure::pick_entity(shell, Point3::new(1.0, 1.0, 1.0), 0.01);
+/// assert!(matches!(picked, Some(Entity::Vertex(_))));
+/// let picked = measure::pick_entity(shell, Point3::new(0.5, 0.0, 0.0), 0.01);
+/// assert!(matches!(picked, Some(Entity::Edge(_))));
+/// let picked = measure::pick_entity(shell, Point3::new(0.5, 0.5, 1.0), 0.01);
+/// assert!(matches!(picked, Some(Entity::Face(_))));
+/// ```
+pub fn pick_entity(shell: &Shell, point: Point3, tol: f64) -> Option<Entity> {
+    let target = Probe::point(point);
+    let distance = |probe: &Probe| {
+        let (p, _, q, _) = probe.nearest_points(&target);
+        p.distance(q)
+    };
+    let nearest = |entities: Vec<(Entity, f64)>| {
+        let entities = entities.into_iter().filter(|(_, dist)| *dist < tol);
+        let entity = entities.min_by(|(_, d0), (_, d1)| d0.total_cmp(d1));
+        entity.map(|(entity, _)| entity)
+    };
+    let vertices = shell
+        .vertex_iter()
+        .map(|vertex| {
+            let dist = vertex.point().distance(point);
+            (Entity::Vertex(vertex), dist)
+        })
+        .collect();
+    if let Some(vertex) = nearest(vertices) {
+        return Some(vertex);
+    }
+    let edges = shell
+        .edge_iter()
+        .map(|edge| {
+            let dist = distance(&Probe::edge(&edge, tol));
+            (Entity::Edge(edge), dist)
+        })
+        .collect();
+    if let Some(edge) = nearest(edges) {
+        return Some(edge);
+    }
+    let meshed = shell.triangulation(tol);
+    let faces = shell
+        .face_iter()
+        .zip(meshed.face_iter())
+        .filter_map(|(face, meshed)| {
+            let probe = Probe::face(face, &meshed.surface()?).ok()?;
+            Some((Entity::Face(face.clone()), distance(&probe)))
+        })
+        .collect();
+    nearest(faces)
+}
+
+impl Measurement {
+    /// Returns the measured value: the distance, the angle in radians, or the radius.
+    #[inline(always)]
+    pub fn value(&self) -> f64 {
+        match self {
+            Measurement::Distance { distance, .. } => *distance,
+            Measurement::Angle { angle, .. } => angle.0,
+            Measurement::Radius { radius, .. } => *radius,
+        }
+    }
+
+    /// Returns the annotation for the rendering.
+    ///
+    /// `size` is the length of the arms of the angles, and is not used by the other
+    /// measurements.
+    /// # Examples
+    /// ```
+    /// use truck::measure::Measurement;
+    /// use truck::modeling::*;
+    /// let measurement = Measurement::Angle {
+    ///     angle: Rad(std::f64::consts::FRAC_PI_2),
+    ///     origin: Point3::origin(),
+    ///     directions: (Vector3::unit_x(), Vector3::unit_y()),
+    /// };
+    /// let annotation = measurement.annotation(2.0);
+    /// assert_eq!(annotation.label, "90.00°");
+    /// // two arms and the arc
+    /// assert_eq!(annotation.segments.len(), 2 + 16);
+    /// ```
+    pub fn annotation(&self, size: f64) -> Annotation {
+        match self {
+            Measurement::Distance { distance, points } => Annotation {
+                segments: vec![*points],
+                label: format!("{distance:.3}"),
+                label_position: points.0.midpoint(points.1),
+            },
+            Measurement::Angle {
+                angle,
+                origin,
+                directions: (d0, d1),
+            } => {
+                let w = d1 - d0 * d0.dot(*d1);
+                let w = match w.so_small() {
+                    true => perpendicular(*d0),
+                    false => w.normalize(),
+                };
+                let arc = |s: f64| origin + (d0 * f64::cos(s) + w * f64::sin(s)) * size;
+                let arms = [(*origin, origin + d0 * size), (*origin, origin + d1 * size)];
+                let mut segments = arms.to_vec();
+                segments.extend((0..ARC_DIVISION).map(|i| {
+                    let s0 = angle.0 * i as f64 / ARC_DIVISION as f64;
+                    let s1 = angle.0 * (i + 1) as f64 / ARC_DIVISION as f64;
+                    (arc(s0), arc(s1))
+                }));
+                Annotation {
+                    segments,
+                    label: format!("{:.2}°", Deg::from(*angle).0),
+                    label_position: arc(angle.0 / 2.0),
+                }
+            }
+            Measurement::Radius {
+                radius,
+                center,
+                point,
+                ..
+            } => Annotation {
+                segments: vec![(*center, *point)],
+                label: format!("R{radius:.3}"),
+                label_position: center.midpoint(*point),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "rendimpl")]
+impl From<&Annotation> for truck_rendimpl::PreparedWireFrame {
+    #[inline(always)]
+    fn from(annotation: &Annotation) -> Self { Self::from(annotation.segments.as_slice()) }
+}
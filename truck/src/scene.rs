@@ -0,0 +1,409 @@
+use crate::graph::{self, BooleanKind, Operation, Value};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use truck_modeling::*;
+
+/// The declarative description of the models
+///
+/// The description is read from JSON by [`SceneDescription::from_json`].
+/// # Examples
+/// ```
+/// use truck::scene::SceneDescription;
+///
+/// let json = r#"{
+///     "models": [
+///         {
+///             "name": "punched cube",
+///             "shape": {
+///                 "type": "boolean",
+///                 "kind": "difference",
+///                 "base": { "type": "cuboid", "corner0": [0, 0, 0], "corner1": [1, 1, 1] },
+///                 "tool": {
+///                     "type": "cylinder",
+///                     "bottom": [0.5, 0.5, -0.5],
+///                     "axis": [0, 0, 2],
+///                     "radius": 0.25
+///                 }
+///             },
+///             "material": { "preset": "steel" }
+///         },
+///         {
+///             "shape": { "type": "sphere", "center": [0, 0, 0], "radius": 0.5 },
+///             "transforms": [{ "type": "translate", "vector": [3, 0, 0] }],
+///             "material": { "srgb_albedo": [0.8, 0.2, 0.2, 1.0], "roughness": 0.3 }
+///         }
+///     ]
+/// }"#;
+/// let scene = SceneDescription::from_json(json).unwrap();
+/// let solids = scene.build_solids().unwrap();
+/// assert_eq!(solids.len(), 2);
+/// assert!(solids.iter().all(|solid| solid.is_geometric_consistent()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SceneDescription {
+    /// the models in the scene
+    pub models: Vec<ModelDescription>,
+}
+
+/// The description of a model: the shape, the transformations and the material
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModelDescription {
+    /// the name of the model used in the error messages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// the shape of the model
+    pub shape: ShapeDescription,
+    /// the transformations applied to the shape in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<TransformDescription>,
+    /// the material of the model
+    #[serde(default)]
+    pub material: MaterialDescription,
+}
+
+/// The description of a solid
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ShapeDescription {
+    /// [`builder::cuboid`]
+    Cuboid {
+        /// a corner
+        corner0: [f64; 3],
+        /// the diagonal corner
+        corner1: [f64; 3],
+    },
+    /// [`builder::sphere`]
+    Sphere {
+        /// the center
+        center: [f64; 3],
+        /// the radius
+        radius: f64,
+    },
+    /// [`builder::cylinder`]
+    Cylinder {
+        /// the center of the bottom
+        bottom: [f64; 3],
+        /// the vector from the bottom to the top
+        axis: [f64; 3],
+        /// the radius
+        radius: f64,
+    },
+    /// [`builder::circular_cone`]
+    Cone {
+        /// the center of the base
+        base: [f64; 3],
+        /// the vector from the base to the apex
+        axis: [f64; 3],
+        /// the radius of the base
+        radius: f64,
+    },
+    /// [`builder::torus`]
+    Torus {
+        /// the center
+        center: [f64; 3],
+        /// the axis of the rotation
+        axis: [f64; 3],
+        /// the distance from the center to the center of the tube
+        major_radius: f64,
+        /// the radius of the tube
+        minor_radius: f64,
+    },
+    /// the planar polygon swept along `vector`
+    Extrude {
+        /// the vertices of the polygon
+        profile: Vec<[f64; 3]>,
+        /// the sweep vector
+        vector: [f64; 3],
+    },
+    /// the planar polygon revolved around the axis
+    Revolve {
+        /// the vertices of the polygon
+        profile: Vec<[f64; 3]>,
+        /// a point on the axis
+        origin: [f64; 3],
+        /// the direction of the axis
+        axis: [f64; 3],
+        /// the angle of the rotation in degrees
+        degrees: f64,
+    },
+    /// the boolean operation of two shapes
+    Boolean {
+        /// the kind of the operation
+        kind: BooleanKind,
+        /// the first operand
+        base: Box<ShapeDescription>,
+        /// the second operand
+        tool: Box<ShapeDescription>,
+        /// the tolerance of the intersection curves. Default is 0.05.
+        #[serde(default = "default_boolean_tolerance")]
+        tol: f64,
+    },
+}
+
+fn default_boolean_tolerance() -> f64 { 0.05 }
+
+/// The description of a transformation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransformDescription {
+    /// the translation by `vector`
+    Translate {
+        /// the vector of the translation
+        vector: [f64; 3],
+    },
+    /// the rotation around the axis
+    Rotate {
+        /// a point on the axis. Default is the origin.
+        #[serde(default)]
+        origin: [f64; 3],
+        /// the direction of the axis
+        axis: [f64; 3],
+        /// the angle of the rotation in degrees
+        degrees: f64,
+    },
+    /// the scaling with the center `origin`
+    Scale {
+        /// the center of the scaling. Default is the origin.
+        #[serde(default)]
+        origin: [f64; 3],
+        /// the scale factors in the directions of the axes
+        scalars: [f64; 3],
+    },
+}
+
+/// The description of a material
+///
+/// The material is the preset of the name `preset`, or the default material if `preset` is
+/// `None`, overwritten by the other specified parameters.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaterialDescription {
+    /// the name of the preset material, e.g. "steel"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+    /// the albedo in sRGB with the alpha
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srgb_albedo: Option<[f64; 4]>,
+    /// the roughness
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roughness: Option<f64>,
+    /// the reflectance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reflectance: Option<f64>,
+    /// the alpha blending flag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha_blend: Option<bool>,
+}
+
+fn polygon_face(profile: &[[f64; 3]]) -> Result<Face> {
+    let points = profile.iter().map(|p| Point3::from(*p)).collect();
+    let face = graph::Sketch { points }.evaluate(&[])?;
+    Ok(face.as_face().unwrap().clone())
+}
+
+fn rotation(origin: Point3, axis: Vector3, angle: Rad<f64>) -> Result<Matrix4> {
+    if axis.so_small() {
+        return Err(truck_modeling::errors::Error::ZeroLengthAxis.into());
+    }
+    let mat0 = Matrix4::from_translation(-origin.to_vec());
+    let mat1 = Matrix4::from_axis_angle(axis.normalize(), angle);
+    Ok(Matrix4::from_translation(origin.to_vec()) * mat1 * mat0)
+}
+
+impl ShapeDescription {
+    /// Builds the solid.
+    pub fn build(&self) -> Result<Solid> {
+        use ShapeDescription as SD;
+        let solid = match self {
+            SD::Cuboid { corner0, corner1 } => {
+                builder::try_cuboid((*corner0).into(), (*corner1).into())?
+            }
+            SD::Sphere { center, radius } => builder::try_sphere((*center).into(), *radius)?,
+            SD::Cylinder {
+                bottom,
+                axis,
+                radius,
+            } => builder::try_cylinder((*bottom).into(), (*axis).into(), *radius)?,
+            SD::Cone { base, axis, radius } => {
+                builder::try_circular_cone((*base).into(), (*axis).into(), *radius)?
+            }
+            SD::Torus {
+                center,
+                axis,
+                major_radius,
+                minor_radius,
+            } => {
+                let (center, axis) = ((*center).into(), (*axis).into());
+                builder::try_torus(center, axis, *major_radius, *minor_radius)?
+            }
+            SD::Extrude { profile, vector } => {
+                let points = profile.iter().map(|p| Point3::from(*p)).collect::<Vec<_>>();
+                builder::try_prism(&points, (*vector).into())?
+            }
+            SD::Revolve {
+                profile,
+                origin,
+                axis,
+                degrees,
+            } => {
+                let (origin, axis) = (Point3::from(*origin), Vector3::from(*axis));
+                let face = polygon_face(profile)?;
+                // The normal of the profile must be the direction of the motion.
+                let sum: Vector3 = profile.iter().map(|p| Vector3::from(*p)).sum();
+                let center = Point3::from_vec(sum / profile.len() as f64);
+                let motion = axis.cross(center - origin) * degrees.signum();
+                let face = match face.oriented_surface().normal(0.0, 0.0).dot(motion) < 0.0 {
+                    true => face.inverse(),
+                    false => face,
+                };
+                let angle = Rad::from(Deg(*degrees));
+                builder::try_rsweep(&face, origin, axis, angle)?
+            }
+            SD::Boolean {
+                kind,
+                base,
+                tool,
+                tol,
+            } => {
+                let inputs = [base.build()?.into(), tool.build()?.into()];
+                let boolean = graph::Boolean {
+                    kind: *kind,
+                    tol: *tol,
+                };
+                match boolean.evaluate(&inputs)? {
+                    Value::Solid(solid) => solid,
+                    _ => unreachable!(),
+                }
+            }
+        };
+        Ok(solid)
+    }
+}
+
+impl TransformDescription {
+    /// Returns the matrix of the transformation.
+    pub fn matrix(&self) -> Result<Matrix4> {
+        match self {
+            TransformDescription::Translate { vector } => {
+                Ok(Matrix4::from_translation((*vector).into()))
+            }
+            TransformDescription::Rotate {
+                origin,
+                axis,
+                degrees,
+            } => rotation((*origin).into(), (*axis).into(), Deg(*degrees).into()),
+            TransformDescription::Scale { origin, scalars } => {
+                let origin = Point3::from(*origin).to_vec();
+                let [x, y, z] = *scalars;
+                let mat = Matrix4::from_nonuniform_scale(x, y, z);
+                Ok(Matrix4::from_translation(origin) * mat * Matrix4::from_translation(-origin))
+            }
+        }
+    }
+}
+
+impl ModelDescription {
+    /// Builds the solid and applies the transformations.
+    /// # Failures
+    /// Returns the error of the building with the context of the name of the model.
+    pub fn build(&self) -> Result<Solid> {
+        let build = || -> Result<Solid> {
+            let solid = self.shape.build()?;
+            let matrix = self
+                .transforms
+                .iter()
+                .try_fold(Matrix4::identity(), |mat, transform| {
+                    Ok::<_, Error>(transform.matrix()? * mat)
+                })?;
+            Ok(builder::transformed(&solid, matrix))
+        };
+        build().map_err(|e| match &self.name {
+            Some(name) => e.context(crate::Context::message(format!("building {name}"))),
+            None => e,
+        })
+    }
+}
+
+impl SceneDescription {
+    /// Reads the description from JSON.
+    #[inline(always)]
+    pub fn from_json(json: &str) -> Result<Self> { Ok(serde_json::from_str(json)?) }
+
+    /// Writes the description to JSON.
+    #[inline(always)]
+    pub fn to_json(&self) -> Result<String> { Ok(serde_json::to_string_pretty(self)?) }
+
+    /// Builds the solids of the models in order.
+    #[inline(always)]
+    pub fn build_solids(&self) -> Result<Vec<Solid>> {
+        self.models.iter().map(ModelDescription::build).collect()
+    }
+}
+
+#[cfg(feature = "rendimpl")]
+mod rendimpl_impl {
+    use super::*;
+    use truck_meshalgo::prelude::*;
+    use truck_rendimpl::{
+        InstanceCreator, Material, PolygonInstance, PolygonState, PreparedPolygon,
+    };
+
+    impl MaterialDescription {
+        /// Returns the material of the rendering.
+        /// # Failures
+        /// Returns [`Error::UnknownMaterial`] if there is no preset of the name.
+        pub fn material(&self) -> Result<Material> {
+            let material = match &self.preset {
+                Some(name) => {
+                    Material::preset(name).ok_or_else(|| Error::UnknownMaterial(name.clone()))?
+                }
+                None => Material::default(),
+            };
+            let material = match self.srgb_albedo {
+                Some(srgb) => material.with_srgb_albedo(Vector4::from(srgb)),
+                None => material,
+            };
+            Ok(Material {
+                roughness: self.roughness.unwrap_or(material.roughness),
+                reflectance: self.reflectance.unwrap_or(material.reflectance),
+                alpha_blend: self.alpha_blend.unwrap_or(material.alpha_blend),
+                ..material
+            })
+        }
+    }
+
+    impl SceneDescription {
+        /// Builds the solids and tessellates them with the tolerance `tol`, and prepares the
+        /// buffers of the polygon instances with the states.
+        ///
+        /// This method does not touch GPU, so it can be called on a worker thread. The instances
+        /// are created on the render thread by [`InstanceCreator::create_instance`].
+        pub fn prepare_instances(&self, tol: f64) -> Result<Vec<(PreparedPolygon, PolygonState)>> {
+            self.models
+                .iter()
+                .map(|model| {
+                    let state = PolygonState {
+                        material: model.material.material()?,
+                        ..Default::default()
+                    };
+                    let mesh = model.build()?.triangulation(tol).to_polygon();
+                    Ok((PreparedPolygon::from(&mesh), state))
+                })
+                .collect()
+        }
+
+        /// Builds the solids, tessellates them with the tolerance `tol`, and creates the
+        /// polygon instances with the materials.
+        pub fn create_instances(
+            &self,
+            creator: &InstanceCreator,
+            tol: f64,
+        ) -> Result<Vec<PolygonInstance>> {
+            let prepared = self.prepare_instances(tol)?;
+            let instances = prepared
+                .iter()
+                .map(|(prepared, state)| creator.create_instance(prepared, state))
+                .collect();
+            Ok(instances)
+        }
+    }
+}
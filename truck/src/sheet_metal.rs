@@ -0,0 +1,334 @@
+use crate::{Error, Result};
+use truck_modeling::*;
+
+/// The bend between a panel of a sheet metal part and its flange
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bend {
+    /// the bend angle. The flange is bent toward the normal of the panel by positive angles, and
+    /// against it by negative ones.
+    pub angle: Rad<f64>,
+    /// the inner radius of the bend
+    pub radius: f64,
+}
+
+impl Bend {
+    /// Returns the bend allowance: the length of the neutral line of the bend, which is at the
+    /// ratio `k_factor` of `thickness` from the inner surface.
+    /// # Examples
+    /// ```
+    /// use truck::sheet_metal::Bend;
+    /// use truck::modeling::*;
+    ///
+    /// let bend = Bend {
+    ///     angle: Deg(90.0).into(),
+    ///     radius: 1.0,
+    /// };
+    /// assert_near!(bend.allowance(0.5, 0.5), std::f64::consts::PI / 2.0 * 1.25);
+    /// ```
+    #[inline(always)]
+    pub fn allowance(&self, thickness: f64, k_factor: f64) -> f64 {
+        self.angle.0.abs() * (self.radius + k_factor * thickness)
+    }
+}
+
+/// The flat pattern of a sheet metal part on the xy-plane
+#[derive(Clone, Debug)]
+pub struct FlatPattern {
+    /// the counterclockwise outline
+    pub outline: Wire,
+    /// the start and the end lines of the bend regions, in the order of the flanges
+    pub bend_lines: Vec<[Line<Point3>; 2]>,
+}
+
+/// a planar panel of a sheet metal part: the base or a flange
+#[derive(Clone, Debug)]
+struct Panel {
+    /// the unit normal of the mid surface
+    normal: Vector3,
+    /// the boundary edges of the mid surface, counterclockwise around `normal`
+    edges: Vec<Edge>,
+    /// the vertices of the flat pattern
+    flat: Vec<Point2>,
+    /// the indices of the flanges bent from the edges
+    flanges: Vec<Option<usize>>,
+}
+
+/// The sheet metal part of a constant thickness: the planar base and the flanges bent from the
+/// edges of the panels
+///
+/// The part is modeled by the mid surface, i.e. the sheet occupies the half of the thickness on
+/// each side of [`SheetMetal::shell`]. The base is on the xy-plane, and the panels are indexed
+/// in the order of creation from the base of index 0. The edges of a flange are indexed
+/// counterclockwise from the bent edge of index 0, so that the far edge is of index 2.
+/// # Examples
+/// ```
+/// use truck::sheet_metal::{Bend, SheetMetal};
+/// use truck::modeling::*;
+///
+/// // the channel of the square base
+/// let base = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].map(|(x, y)| Point2::new(x, y));
+/// let mut part = SheetMetal::new(&base, 0.2, 0.4).unwrap();
+/// let bend = Bend {
+///     angle: Deg(90.0).into(),
+///     radius: 0.5,
+/// };
+/// let flange = part.flange(0, 0, 1.0, bend).unwrap();
+/// part.flange(0, 2, 1.0, bend).unwrap();
+/// assert_eq!(flange, 1);
+///
+/// let shell = part.shell();
+/// assert_eq!(shell.len(), 5);
+/// assert!(shell.is_geometric_consistent());
+/// // the far edge of the flange is over the bend of the mid radius 0.6.
+/// let far = &part.panel_edges(flange)[2];
+/// assert_near!(far.front().point(), Point3::new(0.0, -0.6, 1.6));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SheetMetal {
+    thickness: f64,
+    k_factor: f64,
+    panels: Vec<Panel>,
+    faces: Vec<Face>,
+    bend_lines: Vec<[Point2; 4]>,
+}
+
+/// Returns whether the segments `p0`-`p1` and `q0`-`q1` cross each other.
+fn segments_cross((p0, p1): (Point2, Point2), (q0, q1): (Point2, Point2)) -> bool {
+    let cross = |a: Vector2, b: Vector2| a.x * b.y - a.y * b.x;
+    let (d0, d1) = (cross(p1 - p0, q0 - p0), cross(p1 - p0, q1 - p0));
+    let (d2, d3) = (cross(q1 - q0, p0 - q0), cross(q1 - q0, p1 - q0));
+    d0 * d1 < 0.0 && d2 * d3 < 0.0
+}
+
+impl SheetMetal {
+    /// Creates the sheet metal part of the base bounded by the counterclockwise polygon `base`.
+    ///
+    /// The neutral line of the bends is at the ratio `k_factor` of `thickness` from the inner
+    /// surfaces.
+    /// # Failures
+    /// Returns [`Error::InvalidSheetMetal`] if `thickness` is not positive, `k_factor` is not in
+    /// `[0, 1]`, or `base` is not a counterclockwise polygon.
+    pub fn new(base: &[Point2], thickness: f64, k_factor: f64) -> Result<Self> {
+        let invalid = |message: &str| Err(Error::InvalidSheetMetal(message.to_string()));
+        if thickness < TOLERANCE {
+            return invalid("the thickness must be positive");
+        }
+        if !(0.0..=1.0).contains(&k_factor) {
+            return invalid("the k-factor must be in [0, 1]");
+        }
+        let len = base.len();
+        let area: f64 = (0..len)
+            .map(|i| {
+                let (p, q) = (base[i], base[(i + 1) % len]);
+                p.x * q.y - p.y * q.x
+            })
+            .sum();
+        if len < 3 || area < TOLERANCE {
+            return invalid("the base must be a counterclockwise polygon");
+        }
+        let vertices: Vec<Vertex> = base
+            .iter()
+            .map(|p| builder::vertex(Point3::new(p.x, p.y, 0.0)))
+            .collect();
+        let edges: Vec<Edge> = (0..len)
+            .map(|i| builder::try_line(&vertices[i], &vertices[(i + 1) % len]))
+            .collect::<std::result::Result<_, _>>()?;
+        let face = builder::try_attach_plane(&[edges.iter().cloned().collect()])?;
+        let panel = Panel {
+            normal: Vector3::unit_z(),
+            edges,
+            flat: base.to_vec(),
+            flanges: vec![None; len],
+        };
+        Ok(Self {
+            thickness,
+            k_factor,
+            panels: vec![panel],
+            faces: vec![face],
+            bend_lines: Vec::new(),
+        })
+    }
+
+    /// Returns the thickness.
+    #[inline(always)]
+    pub fn thickness(&self) -> f64 { self.thickness }
+
+    /// Returns the k-factor: the ratio of the thickness from the inner surfaces to the neutral
+    /// line of the bends.
+    #[inline(always)]
+    pub fn k_factor(&self) -> f64 { self.k_factor }
+
+    /// Returns the number of the panels: the base and the flanges.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.panels.len() }
+
+    /// Returns `false`, since the part has the base.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.panels.is_empty() }
+
+    /// Returns the boundary edges of the mid surface of the panel `panel`.
+    /// # Panics
+    /// Panic occurs if the panel does not exist.
+    #[inline(always)]
+    pub fn panel_edges(&self, panel: usize) -> &[Edge] { &self.panels[panel].edges }
+
+    /// Adds the flange of `length` bent by `bend` from the edge `edge` of the panel `panel`, and
+    /// returns the index of the new panel.
+    ///
+    /// The flange covers the whole edge, and `length` is measured from the end of the bend. The
+    /// flanges of the base are bent upward by positive angles.
+    /// # Failures
+    /// Returns [`Error::InvalidSheetMetal`] if the edge does not exist or has been bent, the
+    /// bend angle is zero or larger than 180 degrees, the bend radius is negative, or `length`
+    /// is not positive.
+    pub fn flange(&mut self, panel: usize, edge: usize, length: f64, bend: Bend) -> Result<usize> {
+        let invalid = |message: &str| Err(Error::InvalidSheetMetal(message.to_string()));
+        let parent = match self.panels.get(panel) {
+            Some(parent) if edge < parent.edges.len() => parent,
+            _ => return invalid("the edge does not exist"),
+        };
+        if parent.flanges[edge].is_some() || (panel != 0 && edge == 0) {
+            return invalid("the edge has been bent");
+        }
+        let angle = bend.angle.0.abs();
+        if angle < TOLERANCE || angle > std::f64::consts::PI + TOLERANCE {
+            return invalid("the bend angle must be in (0, 180] degrees");
+        }
+        if bend.radius < 0.0 {
+            return invalid("the bend radius must not be negative");
+        }
+        if length < TOLERANCE {
+            return invalid("the length must be positive");
+        }
+
+        // the bend of the mid surface around the axis parallel to the edge
+        let bent_edge = &parent.edges[edge];
+        let (p0, p1) = (bent_edge.front().point(), bent_edge.back().point());
+        let dir = (p1 - p0).normalize();
+        let outward = dir.cross(parent.normal);
+        let sign = bend.angle.0.signum();
+        let radius = bend.radius + self.thickness / 2.0;
+        let origin = p0 + parent.normal * (sign * radius);
+        let axis = -dir * sign;
+        let bend_faces = builder::rsweep(&bent_edge.inverse(), origin, axis, Rad(angle));
+        let rotation = Matrix3::from_axis_angle(axis, Rad(angle));
+        let (normal, outward) = (rotation * parent.normal, rotation * outward);
+
+        // the flange from the end of the bend
+        let root = bend_faces[bend_faces.len() - 1].boundaries()[0][2].inverse();
+        let (v0, v1) = (root.back().clone(), root.front().clone());
+        let u0 = builder::vertex(v0.point() + outward * length);
+        let u1 = builder::vertex(v1.point() + outward * length);
+        let edges = vec![
+            root,
+            builder::line(&v0, &u0),
+            builder::line(&u0, &u1),
+            builder::line(&u1, &v1),
+        ];
+        let face = builder::try_attach_plane(&[edges.iter().cloned().collect()])?;
+
+        // the flat pattern unrolled by the bend allowance
+        let len = parent.flat.len();
+        let (q0, q1) = (parent.flat[edge], parent.flat[(edge + 1) % len]);
+        let dir = (q1 - q0).normalize();
+        let outward2d = Vector2::new(dir.y, -dir.x);
+        let allowance = bend.allowance(self.thickness, self.k_factor);
+        let (r0, r1) = (q0 + outward2d * allowance, q1 + outward2d * allowance);
+        let (s0, s1) = (r0 + outward2d * length, r1 + outward2d * length);
+
+        let index = self.panels.len();
+        self.panels[panel].flanges[edge] = Some(index);
+        self.panels.push(Panel {
+            normal,
+            edges,
+            flat: vec![r1, r0, s0, s1],
+            flanges: vec![None; 4],
+        });
+        self.faces.extend(bend_faces.face_iter().cloned());
+        self.faces.push(face);
+        self.bend_lines.push([q0, q1, r0, r1]);
+        Ok(index)
+    }
+
+    /// Returns the mid surface: the base, and the bend faces and the flat face of each flange in
+    /// order.
+    #[inline(always)]
+    pub fn shell(&self) -> Shell { self.faces.iter().cloned().collect() }
+
+    /// Pushes the vertices of the flat pattern of the panel `index` and its flanges: all the
+    /// vertices of the base, or the ones of a flange from the root through the far edge.
+    fn trace(&self, index: usize, points: &mut Vec<Point2>) {
+        let panel = &self.panels[index];
+        let start = usize::from(index != 0);
+        (start..panel.flat.len()).for_each(|i| {
+            points.push(panel.flat[i]);
+            if let Some(flange) = panel.flanges[i] {
+                self.trace(flange, points);
+            }
+        });
+        if index != 0 {
+            points.push(panel.flat[0]);
+        }
+    }
+
+    /// Unfolds the part into the flat pattern on the xy-plane.
+    ///
+    /// The base stays in place, and each flange is unrolled outward from its edge by the bend
+    /// allowance of [`Bend::allowance`].
+    /// # Failures
+    /// Returns [`Error::InvalidSheetMetal`] if the flat pattern overlaps itself, e.g. by the
+    /// flanges at a concave corner.
+    /// # Examples
+    /// ```
+    /// use truck::sheet_metal::{Bend, SheetMetal};
+    /// use truck::modeling::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let base = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].map(|(x, y)| Point2::new(x, y));
+    /// let mut part = SheetMetal::new(&base, 0.2, 0.5).unwrap();
+    /// let bend = Bend {
+    ///     angle: Deg(90.0).into(),
+    ///     radius: 0.4,
+    /// };
+    /// part.flange(0, 1, 1.0, bend).unwrap();
+    ///
+    /// let pattern = part.flat_pattern().unwrap();
+    /// // the outline has the vertices at the ends of the bend lines.
+    /// assert_eq!(pattern.outline.len(), 8);
+    /// // the flange is unrolled by the length of the neutral line of the bend.
+    /// let width = 2.0 + PI / 4.0 + 1.0;
+    /// let far = pattern.outline.vertex_iter().map(|v| v.point().x).fold(0.0, f64::max);
+    /// assert_near!(far, width);
+    /// let bend_lines = &pattern.bend_lines[0];
+    /// assert_near!(bend_lines[1].0.x - bend_lines[0].0.x, PI / 4.0);
+    /// ```
+    pub fn flat_pattern(&self) -> Result<FlatPattern> {
+        let mut points = Vec::new();
+        self.trace(0, &mut points);
+        let len = points.len();
+        let segment = |i: usize| (points[i], points[(i + 1) % len]);
+        let overlapped = (0..len).any(|i| {
+            let others = (i + 2..len).filter(|j| (j + 1) % len != i);
+            others.any(|j| segments_cross(segment(i), segment(j)))
+        });
+        if overlapped {
+            return Err(Error::InvalidSheetMetal(
+                "the flat pattern overlaps itself".to_string(),
+            ));
+        }
+        let lift = |p: Point2| Point3::new(p.x, p.y, 0.0);
+        let vertices: Vec<Vertex> = points.iter().map(|p| builder::vertex(lift(*p))).collect();
+        let outline = (0..len)
+            .map(|i| builder::line(&vertices[i], &vertices[(i + 1) % len]))
+            .collect();
+        let bend_lines = self
+            .bend_lines
+            .iter()
+            .map(|[q0, q1, r0, r1]| [Line(lift(*q0), lift(*q1)), Line(lift(*r0), lift(*r1))])
+            .collect();
+        Ok(FlatPattern {
+            outline,
+            bend_lines,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use std::f64::consts::PI;
+use truck::contact::{self, ContactKind};
+use truck::modeling::*;
+
+#[test]
+fn shaft_in_hole() {
+    let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let (bottom, axis) = (Point3::new(0.5, 0.5, -0.5), Vector3::new(0.0, 0.0, 2.0));
+    let shaft = builder::cylinder(bottom, axis, 0.25);
+    let mut tool = shaft.clone();
+    tool.not();
+    let plate = truck::shapeops::and(&cube, &tool, 0.05).unwrap();
+
+    let contacts = contact::find_contacts(&plate, &shaft, 0.01);
+    assert!(!contacts.is_empty());
+    assert!(contacts.iter().all(|c| c.kind == ContactKind::Coincident));
+    let area: f64 = contacts.iter().map(|c| c.area).sum();
+    assert!(f64::abs(area - PI * 0.5) < 0.05, "{area}");
+    // the normals of the cylinder cancel out.
+    let normal: Vector3 = contacts.iter().map(|c| c.normal * c.area).sum();
+    assert!(normal.magnitude() < 0.05 * area);
+}
+
+#[test]
+fn separated_and_edge_touching() {
+    let cube0 = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let cube1 = builder::cuboid(Point3::new(1.5, 0.0, 0.0), Point3::new(2.5, 1.0, 1.0));
+    assert!(contact::find_contacts(&cube0, &cube1, 0.01).is_empty());
+    // only the edges touch.
+    let cube2 = builder::cuboid(Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, 2.0, 1.0));
+    assert!(contact::find_contacts(&cube0, &cube2, 0.01).is_empty());
+    // the faces touch in the reversed order.
+    let cube3 = builder::cuboid(Point3::new(0.5, 0.5, -1.0), Point3::new(1.5, 1.5, 0.0));
+    let contacts = contact::find_contacts(&cube3, &cube0, 0.01);
+    assert_eq!(contacts.len(), 1);
+    assert_near!(contacts[0].area, 0.25);
+    assert_near!(contacts[0].normal, Vector3::unit_z());
+}
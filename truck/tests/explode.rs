@@ -0,0 +1,77 @@
+use truck::explode::{ExplodedView, ExplosionMethod};
+use truck::modeling::*;
+
+fn shells(solids: Vec<Solid>) -> Vec<Shell> {
+    let shell = |solid: Solid| solid.into_boundaries().pop().unwrap();
+    solids.into_iter().map(shell).collect()
+}
+
+#[test]
+fn radial_explosion() {
+    let shells = shells(vec![
+        builder::cuboid(Point3::new(-2.0, -1.0, -1.0), Point3::new(0.0, 1.0, 1.0)),
+        builder::cuboid(Point3::new(0.0, -1.0, -1.0), Point3::new(2.0, 1.0, 1.0)),
+        builder::sphere(Point3::new(0.0, 0.0, 3.0), 1.0),
+    ]);
+    let view = ExplodedView::new(&shells, ExplosionMethod::Radial, 0.01);
+    let vectors = view.vectors();
+    assert_near!(vectors[0], Vector3::new(-1.0, 0.0, -1.5));
+    assert_near!(vectors[1], Vector3::new(1.0, 0.0, -1.5));
+    assert!(vectors[2].distance(Vector3::new(0.0, 0.0, 1.5)) < 0.01);
+
+    let exploded = view.exploded(&shells, 1.0);
+    let vertex = exploded[1].vertex_iter().next().unwrap();
+    let original = shells[1].vertex_iter().next().unwrap();
+    assert_near!(vertex.point(), original.point() + vectors[1]);
+}
+
+#[test]
+fn contact_explosion() {
+    // the separated box moves radially, and the touching ones follow them.
+    let shells = shells(vec![
+        builder::cuboid(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0)),
+        builder::cuboid(Point3::new(2.0, 0.5, 0.5), Point3::new(3.0, 1.5, 1.5)),
+        builder::cuboid(Point3::new(-5.0, 0.0, 0.0), Point3::new(-4.0, 1.0, 1.0)),
+        builder::cuboid(Point3::new(-5.0, 0.0, 1.0), Point3::new(-4.0, 1.0, 1.5)),
+    ]);
+    let method = ExplosionMethod::Contact { tol: 1.0e-3 };
+    let view = ExplodedView::new(&shells, method, 0.01);
+    let vectors = view.vectors();
+    assert_near!(vectors[1] - vectors[0], Vector3::new(1.0, 0.0, 0.0));
+    assert_near!(vectors[3] - vectors[2], Vector3::new(0.0, 0.0, 0.5));
+    assert!(vectors[2].x < 0.0);
+
+    let keyframes = view.keyframes(5);
+    assert_eq!(keyframes.len(), 5);
+    assert_near!(keyframes[0][1], Matrix4::identity());
+    assert_near!(keyframes[2][1], view.matrices(0.5)[1]);
+    assert_near!(keyframes[4][1], Matrix4::from_translation(vectors[1]));
+}
+
+#[test]
+fn mating_faces_explosion() {
+    // the block on the tilted plate
+    let rotation = Matrix4::from_angle_y(Deg(30.0));
+    let shells = shells(vec![
+        builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 1.0)),
+        builder::cuboid(Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0)),
+    ]);
+    let shells: Vec<Shell> = shells
+        .iter()
+        .map(|shell| builder::transformed(shell, rotation))
+        .collect();
+    let normal = rotation.transform_vector(Vector3::unit_z());
+
+    let method = ExplosionMethod::MatingFaces { tol: 1.0e-3 };
+    let view = ExplodedView::new(&shells, method, 0.01);
+    let vectors = view.vectors();
+    let moved = vectors[1] - vectors[0];
+    assert!(moved.magnitude() > 1.0);
+    assert_near!(moved.normalize(), normal);
+
+    // the bounding boxes separate the shells in an axis.
+    let method = ExplosionMethod::Contact { tol: 1.0e-3 };
+    let view = ExplodedView::new(&shells, method, 0.01);
+    let moved = view.vectors()[1] - view.vectors()[0];
+    assert_eq!(moved.x * moved.y, 0.0);
+}
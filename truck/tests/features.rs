@@ -0,0 +1,97 @@
+use truck::features;
+use truck::modeling::*;
+
+#[test]
+fn drill_through_block() {
+    let block = builder::cuboid(Point3::origin(), Point3::new(2.0, 2.0, 1.0));
+    let origin = Point3::new(1.0, 1.0, 1.0);
+    let feature = features::drill(&block, origin, -Vector3::unit_z(), 0.5, 2.0, 0.05).unwrap();
+    let solid = feature.solid();
+    assert!(solid.is_geometric_consistent());
+    // the bottom of the tool is under the block.
+    assert!(feature.faces("bottom").is_empty());
+    let walls = feature.faces("wall");
+    assert!(!walls.is_empty());
+    let vertices = walls.iter().flat_map(|face| face.vertex_iter());
+    vertices.for_each(|v| {
+        let pt = v.point();
+        assert_near!(Point3::new(pt.x, pt.y, 1.0).distance(origin), 0.25);
+    });
+    // the faces of the block are not named.
+    let faces = solid.boundaries()[0].face_iter();
+    assert_eq!(faces.filter(|f| feature.name(f).is_none()).count(), 6);
+}
+
+#[test]
+fn pocket_and_slot() {
+    let block = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 2.0));
+    let points = [(1.0, 1.0), (2.0, 1.0), (2.0, 3.0), (1.0, 3.0)];
+    let v = points.map(|(x, y)| builder::vertex(Point3::new(x, y, 2.0)));
+    let edges = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4]));
+    let sketch: Wire = edges.collect();
+    let pocket = features::pocket(&block, &sketch, 1.0, 0.05).unwrap();
+    assert!(pocket.solid().is_geometric_consistent());
+    (0..4).for_each(|i| assert_eq!(pocket.faces(&format!("wall{i}")).len(), 1));
+    let points = pocket.faces("wall1")[0].vertex_iter().map(|v| v.point());
+    points.for_each(|pt| assert_near!(pt.x, 2.0));
+
+    let (start, end) = (Point3::new(3.0, 1.0, 2.0), Point3::new(3.0, 3.0, 2.0));
+    let normal = Vector3::unit_z();
+    let slot = features::slot(pocket.solid(), start, end, normal, 0.5, 0.5, 0.05).unwrap();
+    assert!(slot.solid().is_geometric_consistent());
+    let floor = slot.faces("floor");
+    assert_eq!(floor.len(), 1);
+    let points = floor[0].vertex_iter().map(|v| v.point());
+    points.for_each(|pt| assert_near!(pt.z, 1.5));
+    // the right side seen from the top is on the larger x.
+    let points = slot.faces("wall0")[0].vertex_iter().map(|v| v.point());
+    points.for_each(|pt| assert_near!(pt.x, 3.25));
+    let points = slot.faces("wall1")[0].vertex_iter().map(|v| v.point());
+    points.for_each(|pt| assert!(pt.y >= 3.0 - TOLERANCE));
+
+    // the slot of the zero width
+    assert!(features::slot(&block, start, end, normal, 0.0, 0.5, 0.05).is_err());
+}
+
+#[test]
+fn ribs_on_plate() {
+    let plate = builder::cuboid(Point3::origin(), Point3::new(4.0, 2.0, 1.0));
+    let v = [0.5, 3.5].map(|x| builder::vertex(Point3::new(x, 1.0, 2.0)));
+    let profile: Wire = vec![builder::line(&v[0], &v[1])].into();
+    let direction = Vector3::new(0.0, 0.0, -1.5);
+    let fin = features::rib(&plate, &profile, 0.2, direction, 0.05).unwrap();
+    assert!(fin.solid().is_geometric_consistent());
+    let points = fin.faces("top")[0].vertex_iter().map(|v| v.point());
+    points.for_each(|pt| assert_near!(pt.z, 2.0));
+    let sides = fin.faces("side");
+    assert_eq!(sides.len(), 2);
+    let vertices = sides.iter().flat_map(|face| face.vertex_iter());
+    vertices.for_each(|v| assert_near!(f64::abs(v.point().y - 1.0), 0.1));
+    assert_eq!(fin.faces("end").len(), 2);
+
+    // the closed profile
+    let v = builder::vertex(Point3::new(1.0, 1.0, 2.0));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 1.0, 2.0), Vector3::unit_z(), Rad(7.0));
+    let res = features::rib(&plate, &circle, 0.2, direction, 0.05);
+    assert!(matches!(res, Err(truck::Error::InvalidRibProfile)));
+}
+
+#[test]
+fn cylindrical_boss() {
+    let block = builder::cuboid(Point3::origin(), Point3::new(4.0, 4.0, 2.0));
+    let center = Point3::new(2.0, 2.0, 2.0);
+    let v = builder::vertex(Point3::new(3.0, 2.0, 2.0));
+    let sketch = builder::rsweep(&v, center, Vector3::unit_z(), Rad(7.0));
+    let feature = features::boss(&block, &sketch, 1.5, Rad(0.0), 0.05).unwrap();
+    assert!(feature.solid().is_geometric_consistent());
+    let points = feature.faces("top")[0].vertex_iter().map(|v| v.point());
+    points.for_each(|pt| assert_near!(pt.z, 3.5));
+    let walls = feature.faces("wall");
+    assert!(!walls.is_empty());
+    let vertices = walls.iter().flat_map(|face| face.vertex_iter());
+    vertices.for_each(|v| {
+        let pt = v.point();
+        assert_near!(Point3::new(pt.x, pt.y, 2.0).distance(center), 1.0);
+    });
+    assert!(features::boss(&block, &sketch, 0.0, Rad(0.0), 0.05).is_err());
+}
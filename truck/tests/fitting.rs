@@ -0,0 +1,83 @@
+use std::f64::consts::PI;
+use truck::fitting::{self, FittingOptions};
+use truck::meshalgo::prelude::*;
+use truck::modeling::*;
+
+// the fixtures shared with the tests of the meshing algorithms
+#[path = "../../truck-meshalgo/tests/common/mod.rs"]
+mod common;
+use common::shapes::grid_mesh;
+
+#[test]
+fn fit_cube() {
+    let cube = builder::cuboid(Point3::origin(), Point3::new(2.0, 1.0, 1.0));
+    let mut mesh = cube.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs();
+    let fitted = fitting::fit(&mesh, &FittingOptions::default()).unwrap();
+    assert_eq!(fitted.shell.len(), 6);
+    assert_eq!(fitted.shell.shell_condition(), ShellCondition::Closed);
+    assert_eq!(fitted.shell.edge_iter().count(), 24);
+    assert!(fitted.deviation < 1.0e-6, "{}", fitted.deviation);
+    let triangles: usize = fitted.regions.iter().map(Vec::len).sum();
+    assert_eq!(triangles, mesh.faces().triangle_iter().count());
+}
+
+#[test]
+fn fit_height_field() {
+    let mesh = grid_mesh(16, |x, y| {
+        Point3::new(x, y, 0.1 * f64::sin(PI * x) * f64::sin(PI * y))
+    });
+    let fitted = fitting::fit(&mesh, &FittingOptions::default()).unwrap();
+    assert_eq!(fitted.shell.len(), 1);
+    // the boundary is divided at the four corners.
+    assert_eq!(fitted.shell[0].boundaries()[0].len(), 4);
+    assert_eq!(fitted.shell.shell_condition(), ShellCondition::Oriented);
+    assert!(fitted.deviation < 0.01, "{}", fitted.deviation);
+}
+
+#[test]
+fn fit_tube() {
+    // the open tube, whose only region is the annulus
+    let mut tube = grid_mesh(16, |u, v| {
+        let t = 2.0 * PI * u;
+        Point3::new(f64::cos(t), f64::sin(t), v)
+    });
+    tube.put_together_same_attrs();
+    let fitted = fitting::fit(&tube, &FittingOptions::default()).unwrap();
+    // the annulus is split into two faces.
+    assert_eq!(fitted.shell.len(), 2);
+    assert_eq!(fitted.shell.shell_condition(), ShellCondition::Oriented);
+    assert!(fitted.deviation < 0.01, "{}", fitted.deviation);
+    let triangles: usize = fitted.regions.iter().map(Vec::len).sum();
+    assert_eq!(triangles, tube.faces().triangle_iter().count());
+
+    // the faces are tangent continuous along the shared edges.
+    let (face0, face1) = (&fitted.shell[0], &fitted.shell[1]);
+    let ids: Vec<_> = face1.edge_iter().map(|edge| edge.id()).collect();
+    let shared = face0.edge_iter().filter(|edge| ids.contains(&edge.id()));
+    let normal = |face: &Face, point: Point3| {
+        let surface = face.oriented_surface();
+        let (u, v) = surface.search_parameter(point, None, 100).unwrap();
+        surface.normal(u, v)
+    };
+    let mut count = 0;
+    shared.for_each(|edge| {
+        let curve = edge.curve();
+        let (t0, t1) = curve.parameter_range();
+        let point = curve.subs((t0 + t1) / 2.0);
+        let angle = normal(face0, point).angle(normal(face1, point));
+        assert!(angle < Rad(0.1), "{angle:?}");
+        count += 1;
+    });
+    assert!(count >= 2);
+}
+
+#[test]
+fn invalid_fittings() {
+    let mesh = grid_mesh(4, |x, y| Point3::new(x, y, 0.0));
+    let options = FittingOptions {
+        control_points: 3,
+        ..Default::default()
+    };
+    assert!(fitting::fit(&mesh, &options).is_err());
+}
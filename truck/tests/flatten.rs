@@ -0,0 +1,38 @@
+use std::f64::consts::PI;
+use truck::flatten;
+use truck::modeling::*;
+
+#[test]
+fn flatten_cone() {
+    // the quarter of the cone of the apex at the origin
+    let v = [(1.0, 1.0), (2.0, 2.0)].map(|(x, z)| builder::vertex(Point3::new(x, 0.0, z)));
+    let line = builder::line(&v[0], &v[1]);
+    let shell = builder::rsweep(&line, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0));
+    let flat = flatten::flatten(&shell[0], 0.01).unwrap();
+    assert!(flat.developable);
+    assert!(flat.strain < 0.01, "{}", flat.strain);
+    // the annular sector of the radii sqrt(2) and 2 sqrt(2) around the flattened apex
+    let area = flat.mesh.faces().triangle_iter().fold(0.0, |sum, tri| {
+        let p = tri.map(|v| flat.mesh.positions()[v.pos]);
+        sum + (p[1] - p[0]).cross(p[2] - p[0]).z / 2.0
+    });
+    let angle = PI / 2.0 / f64::sqrt(2.0);
+    assert!(f64::abs(area - angle * 3.0) < 0.1, "{area}");
+
+    let wires = flat.wires();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+}
+
+#[test]
+fn flatten_spherical_zone() {
+    // the zone of the unit sphere between the latitudes 0 and 60 degrees
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let arc = builder::rsweep(&v, Point3::origin(), -Vector3::unit_y(), Rad(PI / 3.0));
+    let shell = builder::rsweep(&arc[0], Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0));
+    let flat = flatten::flatten(&shell[0], 0.01).unwrap();
+    assert!(!flat.developable);
+    assert!(flat.strain > 1.0e-3);
+    assert!(flat.strain < 0.5, "{}", flat.strain);
+    assert_eq!(flat.boundaries.len(), 1);
+}
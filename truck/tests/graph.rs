@@ -0,0 +1,64 @@
+use truck::graph::*;
+use truck::modeling::*;
+
+fn square(size: f64) -> Sketch {
+    let points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    let points = points.map(|(x, y)| Point3::new(x * size, y * size, 0.0));
+    Sketch {
+        points: points.to_vec(),
+    }
+}
+
+fn extrude(z: f64) -> Extrude {
+    Extrude {
+        vector: Vector3::new(0.0, 0.0, z),
+    }
+}
+
+#[test]
+fn partial_reevaluation() {
+    let mut graph = Graph::new();
+    let outer = graph.add_node(square(1.0), &[]).unwrap();
+    let box0 = graph.add_node(extrude(1.0), &[outer]).unwrap();
+    let inner = graph.add_node(square(0.5), &[]).unwrap();
+    let matrix = Matrix4::from_translation(Vector3::new(0.25, 0.25, -0.5));
+    let moved = graph.add_node(Transform { matrix }, &[inner]).unwrap();
+    let box1 = graph.add_node(extrude(2.0), &[moved]).unwrap();
+    let boolean = Boolean {
+        kind: BooleanKind::Difference,
+        tol: 0.05,
+    };
+    let punched = graph.add_node(boolean, &[box0, box1]).unwrap();
+    assert_eq!(graph.dependents(inner).unwrap(), vec![moved, box1, punched]);
+
+    let solid = graph.evaluate(punched).unwrap();
+    let shell = &solid.as_solid().unwrap().boundaries()[0];
+    assert_eq!(shell.len(), 10);
+    assert_eq!(graph.evaluation_count(), 6);
+
+    let matrix = Matrix4::from_translation(Vector3::new(0.4, 0.4, -0.5));
+    graph.set_operation(moved, Transform { matrix }).unwrap();
+    assert!(graph.is_cached(box0) && graph.is_cached(inner));
+    assert!(!graph.is_cached(box1) && !graph.is_cached(punched));
+    graph.evaluate(punched).unwrap();
+    assert_eq!(graph.evaluation_count(), 9);
+}
+
+#[test]
+fn failure_with_context() {
+    let mut graph = Graph::new();
+    let sketch = graph.add_node(square(1.0), &[]).unwrap();
+    let solid = graph.add_node(extrude(1.0), &[sketch]).unwrap();
+    let invalid = graph.add_node(extrude(1.0), &[solid]).unwrap();
+    let error = graph.evaluate(invalid).unwrap_err();
+    assert!(matches!(error.root(), truck::Error::InvalidInput { .. }));
+    assert_eq!(
+        error.to_string(),
+        "evaluating the node 2 (extrude): the operation extrude requires a face as the input 0.",
+    );
+    assert!(graph.is_cached(solid) && !graph.is_cached(invalid));
+    assert!(matches!(
+        Graph::new().add_node(extrude(1.0), &[invalid]),
+        Err(truck::Error::UnknownNode(2)),
+    ));
+}
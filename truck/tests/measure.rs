@@ -0,0 +1,116 @@
+use std::f64::consts::PI;
+use truck::measure::{self, Entity, Measurement};
+use truck::modeling::*;
+
+/// the radius, the center and the axis of the radius measurement
+fn radius_of(measurement: Measurement) -> (f64, Point3, Option<Vector3>) {
+    match measurement {
+        Measurement::Radius {
+            radius,
+            center,
+            axis,
+            ..
+        } => (radius, center, axis),
+        _ => panic!("{measurement:?}"),
+    }
+}
+
+/// the face of `solid` whose vertices are all on the plane `coord = value`
+fn face_on(solid: &Solid, coord: usize, value: f64) -> Face {
+    let on_plane = |face: &Face| {
+        let wire = &face.boundaries()[0];
+        let on = |v: Vertex| f64::abs(v.point()[coord] - value) < TOLERANCE;
+        wire.vertex_iter().all(on)
+    };
+    let mut faces = solid.boundaries()[0].face_iter();
+    faces.find(|face| on_plane(face)).unwrap().clone()
+}
+
+#[test]
+fn distance_between_cubes() {
+    let cube0 = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let cube1 = builder::cuboid(Point3::new(2.0, 0.5, 0.0), Point3::new(3.0, 1.5, 1.0));
+    let face0 = Entity::Face(face_on(&cube0, 0, 1.0));
+    let face1 = Entity::Face(face_on(&cube1, 0, 2.0));
+    let measurement = measure::distance(&face0, &face1, 0.01).unwrap();
+    assert!(f64::abs(measurement.value() - 1.0) < 1.0e-6);
+    let points = match measurement {
+        Measurement::Distance { points, .. } => points,
+        _ => panic!("{measurement:?}"),
+    };
+    assert!(f64::abs(points.0.x - 1.0) < 1.0e-6 && f64::abs(points.1.x - 2.0) < 1.0e-6);
+
+    let annotation = measurement.annotation(1.0);
+    assert_eq!(annotation.segments, vec![points]);
+    assert_eq!(annotation.label, "1.000");
+}
+
+#[test]
+fn angles_of_cube() {
+    let cube = builder::cuboid(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let bottom = Entity::Face(face_on(&cube, 2, 0.0));
+    let side = Entity::Face(face_on(&cube, 0, 1.0));
+    let top = Entity::Face(face_on(&cube, 2, 1.0));
+    let angle = measure::angle(&bottom, &side, 0.01).unwrap();
+    assert!(f64::abs(angle.value() - PI / 2.0) < 1.0e-6);
+    let angle = measure::angle(&bottom, &top, 0.01).unwrap();
+    assert!(f64::abs(angle.value() - PI) < 1.0e-6);
+
+    let shell = &cube.boundaries()[0];
+    let edge = |p: Point3, q: Point3| {
+        let mut edges = shell.edge_iter();
+        let found = edges.find(|e| {
+            let (f, b) = (e.front().point(), e.back().point());
+            (f.near(&p) && b.near(&q)) || (f.near(&q) && b.near(&p))
+        });
+        Entity::Edge(found.unwrap())
+    };
+    let edge0 = edge(Point3::origin(), Point3::new(1.0, 0.0, 0.0));
+    let edge1 = edge(Point3::origin(), Point3::new(0.0, 0.0, 1.0));
+    let (angle, origin) = match measure::angle(&edge0, &edge1, 0.01).unwrap() {
+        Measurement::Angle { angle, origin, .. } => (angle, origin),
+        measurement => panic!("{measurement:?}"),
+    };
+    assert!(f64::abs(angle.0 - PI / 2.0) < 1.0e-6);
+    assert!(origin.near(&Point3::origin()));
+    // the edge on the face
+    let angle = measure::angle(&edge0, &bottom, 0.01).unwrap();
+    assert!(angle.value().so_small());
+
+    let point = Entity::Point(Point3::origin());
+    assert!(measure::angle(&point, &edge0, 0.01).is_err());
+}
+
+#[test]
+fn radii_of_cylinder_and_sphere() {
+    let cylinder = builder::cylinder(Point3::origin(), Vector3::new(0.0, 0.0, 2.0), 1.5);
+    let shell = &cylinder.boundaries()[0];
+    let side = shell
+        .face_iter()
+        .find(|face| !matches!(face.surface(), Surface::Plane(_)))
+        .unwrap();
+    let measurement = measure::radius(&Entity::Face(side.clone()), 0.01).unwrap();
+    let (radius, _, axis) = radius_of(measurement);
+    assert!(f64::abs(radius - 1.5) < 0.01);
+    assert!(axis.unwrap().cross(Vector3::unit_z()).so_small());
+
+    let circle = side.boundaries()[0]
+        .edge_iter()
+        .find(|edge| edge.front().point().z.so_small() && edge.back().point().z.so_small())
+        .unwrap();
+    let measurement = measure::radius(&Entity::Edge(circle), 0.01).unwrap();
+    let (radius, center, _) = radius_of(measurement);
+    assert!(f64::abs(radius - 1.5) < 0.01);
+    assert!(center.distance(Point3::origin()) < 0.01);
+
+    let bottom = face_on(&cylinder, 2, 0.0);
+    assert!(measure::radius(&Entity::Face(bottom), 0.01).is_err());
+
+    let sphere = builder::sphere(Point3::new(1.0, 2.0, 3.0), 0.5);
+    let face = sphere.boundaries()[0].face_iter().next().unwrap().clone();
+    let measurement = measure::radius(&Entity::Face(face), 0.01).unwrap();
+    let (radius, center, axis) = radius_of(measurement);
+    assert!(f64::abs(radius - 0.5) < 0.01);
+    assert!(center.distance(Point3::new(1.0, 2.0, 3.0)) < 0.01);
+    assert!(axis.is_none());
+}
@@ -0,0 +1,83 @@
+use truck::modeling::*;
+use truck::scene::*;
+
+const SCENE: &str = r#"{
+    "models": [
+        {
+            "name": "ring",
+            "shape": {
+                "type": "revolve",
+                "profile": [[1, 0, 0], [1.5, 0, 0], [1.5, 0, 0.5], [1, 0, 0.5]],
+                "origin": [0, 0, 0],
+                "axis": [0, 0, 1],
+                "degrees": 360
+            }
+        },
+        {
+            "name": "block",
+            "shape": {
+                "type": "extrude",
+                "profile": [[0, 0, 0], [1, 0, 0], [1, 1, 0], [0, 1, 0]],
+                "vector": [0, 0, 1]
+            },
+            "transforms": [
+                { "type": "scale", "scalars": [2, 2, 2] },
+                { "type": "translate", "vector": [5, 0, 0] }
+            ]
+        }
+    ]
+}"#;
+
+#[test]
+fn build_scene() {
+    let scene = SceneDescription::from_json(SCENE).unwrap();
+    let solids = scene.build_solids().unwrap();
+    assert_eq!(solids.len(), 2);
+    assert!(solids.iter().all(|solid| solid.is_geometric_consistent()));
+
+    let block = &solids[1];
+    let points = block.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+    assert!(points.contains(&Point3::new(5.0, 0.0, 0.0)));
+    assert!(points.contains(&Point3::new(7.0, 2.0, 2.0)));
+
+    let json = scene.to_json().unwrap();
+    assert_eq!(SceneDescription::from_json(&json).unwrap(), scene);
+}
+
+#[test]
+fn scene_errors() {
+    let error = SceneDescription::from_json(r#"{ "models": [{ "shape": { "type": "cube" } }] }"#)
+        .unwrap_err();
+    assert!(matches!(error, truck::Error::Json(_)));
+
+    let model = ModelDescription {
+        name: Some("tilted".to_string()),
+        shape: ShapeDescription::Sphere {
+            center: [0.0; 3],
+            radius: 1.0,
+        },
+        transforms: vec![TransformDescription::Rotate {
+            origin: [0.0; 3],
+            axis: [0.0; 3],
+            degrees: 30.0,
+        }],
+        material: Default::default(),
+    };
+    let error = model.build().unwrap_err();
+    assert!(matches!(error.root(), truck::Error::Modeling(_)));
+    let context = truck::Context::message("building tilted");
+    assert_eq!(error.contexts(), vec![&context]);
+}
+
+#[test]
+#[cfg(feature = "rendimpl")]
+fn prepare_instances_in_background() {
+    let scene = SceneDescription::from_json(SCENE).unwrap();
+    let worker = std::thread::spawn(move || scene.prepare_instances(0.01).unwrap());
+    let prepared = worker.join().unwrap();
+    assert_eq!(prepared.len(), 2);
+    prepared.iter().for_each(|(polygon, _)| {
+        assert!(polygon.vertex_count() > 0);
+        assert_eq!(polygon.index_count() % 3, 0);
+    });
+}
@@ -0,0 +1,94 @@
+use std::f64::consts::PI;
+use truck::modeling::*;
+use truck::sheet_metal::{Bend, SheetMetal};
+
+fn square(size: f64) -> [Point2; 4] {
+    [(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)].map(|(x, y)| Point2::new(x, y))
+}
+
+#[test]
+fn tray_with_hem() {
+    let mut part = SheetMetal::new(&square(4.0), 0.1, 0.5).unwrap();
+    let bend = Bend {
+        angle: Rad(PI / 2.0),
+        radius: 0.2,
+    };
+    (0..4).for_each(|edge| {
+        part.flange(0, edge, 1.0, bend).unwrap();
+    });
+    // the hem folded back outward on the far edge of the first wall
+    let hem = Bend {
+        angle: Rad(-PI),
+        radius: 0.05,
+    };
+    let hemmed = part.flange(1, 2, 0.5, hem).unwrap();
+    assert_eq!(part.len(), 6);
+
+    let shell = part.shell();
+    assert!(shell.is_geometric_consistent());
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+    // the hem of the mid radius 0.1 goes down outside the wall.
+    let edges = part.panel_edges(hemmed);
+    let root = edges[0].back().point();
+    assert_near!(root, Point3::new(0.0, -0.45, 1.25));
+    assert_near!(edges[2].front().point(), Point3::new(0.0, -0.45, 0.75));
+
+    let pattern = part.flat_pattern().unwrap();
+    assert_eq!(pattern.bend_lines.len(), 5);
+    let wall = bend.allowance(0.1, 0.5) + 1.0;
+    let bdb: BoundingBox<Point3> = pattern.outline.vertex_iter().map(|v| v.point()).collect();
+    assert_near!(bdb.max(), Point3::new(4.0 + wall, 4.0 + wall, 0.0));
+    let hemmed = wall + hem.allowance(0.1, 0.5) + 0.5;
+    assert_near!(bdb.min(), Point3::new(-wall, -hemmed, 0.0));
+    let [start, end] = &pattern.bend_lines[4];
+    assert_near!(start.0.y, -wall);
+    assert_near!(end.0.y, -wall - PI * 0.1);
+}
+
+#[test]
+fn invalid_sheet_metals() {
+    let cw: Vec<Point2> = square(1.0).into_iter().rev().collect();
+    assert!(SheetMetal::new(&cw, 0.1, 0.5).is_err());
+    assert!(SheetMetal::new(&square(1.0), 0.0, 0.5).is_err());
+    assert!(SheetMetal::new(&square(1.0), 0.1, 1.5).is_err());
+
+    let mut part = SheetMetal::new(&square(1.0), 0.1, 0.5).unwrap();
+    let bend = Bend {
+        angle: Rad(PI / 2.0),
+        radius: 0.1,
+    };
+    let flange = part.flange(0, 0, 1.0, bend).unwrap();
+    // the bent edges and the edges out of the panels
+    assert!(part.flange(0, 0, 1.0, bend).is_err());
+    assert!(part.flange(flange, 0, 1.0, bend).is_err());
+    assert!(part.flange(0, 4, 1.0, bend).is_err());
+    assert!(part.flange(3, 0, 1.0, bend).is_err());
+    let flat = Bend {
+        angle: Rad(0.0),
+        radius: 0.1,
+    };
+    assert!(part.flange(0, 1, 1.0, flat).is_err());
+}
+
+#[test]
+fn overlapped_flat_pattern() {
+    // the flanges at the concave corner of the L-shaped base
+    let base = [
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (0.0, 2.0),
+    ];
+    let base = base.map(|(x, y)| Point2::new(x, y));
+    let mut part = SheetMetal::new(&base, 0.1, 0.5).unwrap();
+    let bend = Bend {
+        angle: Rad(PI / 2.0),
+        radius: 0.1,
+    };
+    part.flange(0, 2, 0.5, bend).unwrap();
+    assert!(part.flat_pattern().is_ok());
+    part.flange(0, 3, 0.5, bend).unwrap();
+    assert!(part.flat_pattern().is_err());
+}
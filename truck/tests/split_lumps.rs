@@ -0,0 +1,33 @@
+use truck::modeling::*;
+
+#[test]
+fn split_lumps_of_subtraction() {
+    let bar = builder::cuboid(Point3::origin(), Point3::new(3.0, 1.0, 1.0));
+    let mut slab = builder::cuboid(Point3::new(1.0, -1.0, -1.0), Point3::new(2.0, 2.0, 2.0));
+    slab.not();
+    let subtracted = truck::shapeops::and(&bar, &slab, 0.05).unwrap();
+    assert_eq!(subtracted.boundaries().len(), 2);
+    assert_eq!(subtracted.split_lumps().len(), 2);
+
+    // the lumps imported in one shell
+    let faces = subtracted.boundaries().iter().flat_map(Shell::face_iter);
+    let lumped = Solid::new_unchecked(vec![faces.cloned().collect()]);
+    let lumps = lumped.split_lumps();
+    assert_eq!(lumps.len(), 2);
+    lumps.iter().for_each(|lump| {
+        assert_eq!(lump.boundaries().len(), 1);
+        let condition = lump.boundaries()[0].shell_condition();
+        assert_eq!(condition, ShellCondition::Closed);
+        assert!(lump.is_geometric_consistent());
+    });
+    // the lumps are on both sides of the slab.
+    let mut sides = lumps.iter().map(|lump| {
+        let mut xs = lump.boundaries()[0].vertex_iter().map(|v| v.point().x);
+        match xs.all(|x| x < 1.0 + TOLERANCE) {
+            true => 0,
+            false => 1,
+        }
+    });
+    let (side0, side1) = (sides.next().unwrap(), sides.next().unwrap());
+    assert_ne!(side0, side1);
+}